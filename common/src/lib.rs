@@ -42,6 +42,7 @@ mod href;
 mod idents;
 mod jsonify;
 mod namespace;
+mod span;
 mod uri;
 
 pub use ascii_ctrl::*;
@@ -51,4 +52,5 @@ pub use href::HRef;
 pub use idents::gen_id;
 pub use jsonify::Jsonify;
 pub use namespace::to_rdnn;
+pub use span::{Position, Span};
 pub use uri::{to_uri, Uri};
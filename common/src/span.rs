@@ -0,0 +1,144 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! # Source positions and spans
+
+use std::fmt;
+
+/// Position of a character in source text.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position {
+  /// Zero-based index of the character in source text.
+  pub offset: usize,
+  /// One-based line number.
+  pub line: usize,
+  /// One-based column number, counted in characters.
+  pub column: usize,
+}
+
+impl Position {
+  /// Creates a position with specified offset, line and column.
+  pub fn new(offset: usize, line: usize, column: usize) -> Self {
+    Self { offset, line, column }
+  }
+
+  /// Returns the position of the character at specified offset in source text.
+  pub fn of_offset(chars: &[char], offset: usize) -> Self {
+    let mut line = 1;
+    let mut column = 1;
+    for ch in chars.iter().take(offset) {
+      if *ch == '\n' {
+        line += 1;
+        column = 1;
+      } else {
+        column += 1;
+      }
+    }
+    Self { offset, line, column }
+  }
+}
+
+impl fmt::Display for Position {
+  /// Converts [Position] into text in format `line:column`.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}:{}", self.line, self.column)
+  }
+}
+
+/// Span of characters in source text, the end position is exclusive.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Span {
+  /// Position of the first character.
+  pub start: Position,
+  /// Position just after the last character.
+  pub end: Position,
+}
+
+impl Span {
+  /// Creates a span between specified positions.
+  pub fn new(start: Position, end: Position) -> Self {
+    Self { start, end }
+  }
+
+  /// Creates an empty span at specified position.
+  pub fn empty(position: Position) -> Self {
+    Self { start: position, end: position }
+  }
+
+  /// Returns `true` when this span contains no characters.
+  pub fn is_empty(&self) -> bool {
+    self.start.offset == self.end.offset
+  }
+
+  /// Returns `true` when the character at specified offset is covered by this span.
+  pub fn contains(&self, offset: usize) -> bool {
+    self.start.offset <= offset && offset < self.end.offset
+  }
+
+  /// Returns the smallest span covering both this and the other span.
+  pub fn union(&self, other: &Span) -> Span {
+    Span {
+      start: self.start.min(other.start),
+      end: self.end.max(other.end),
+    }
+  }
+}
+
+impl fmt::Display for Span {
+  /// Converts [Span] into text in format `line:column..line:column`.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}..{}", self.start, self.end)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_position_of_offset() {
+    let chars = "ab\ncd".chars().collect::<Vec<char>>();
+    assert_eq!(Position::new(0, 1, 1), Position::of_offset(&chars, 0));
+    assert_eq!(Position::new(2, 1, 3), Position::of_offset(&chars, 2));
+    assert_eq!(Position::new(4, 2, 2), Position::of_offset(&chars, 4));
+  }
+
+  #[test]
+  fn test_span_union() {
+    let a = Span::new(Position::new(0, 1, 1), Position::new(2, 1, 3));
+    let b = Span::new(Position::new(4, 1, 5), Position::new(6, 1, 7));
+    assert_eq!("1:1..1:7", a.union(&b).to_string());
+    assert!(a.union(&b).contains(5));
+    assert!(!a.contains(2));
+    assert!(Span::empty(Position::new(2, 1, 3)).is_empty());
+  }
+}
@@ -33,14 +33,22 @@
 //! # Command-line actions
 
 use crate::examples::*;
+use crate::graph::{GRAPH_FORMAT_DOT, GRAPH_FORMAT_MERMAID};
+use crate::lint::{LINT_FORMAT_HUMAN, LINT_FORMAT_SARIF};
 use clap::{arg, command, crate_description, crate_version, ArgAction, ArgMatches, Command};
 use difference::Changeset;
 use dmntk_common::*;
+use dmntk_evaluator::RecordFormat;
 use dmntk_feel::values::Value;
 use dmntk_feel::FeelScope;
-use dmntk_feel_parser::ast_tree;
+use dmntk_feel_parser::{ast_tree, FeelFormatter};
+use dmntk_model::Definitions;
+use dmntk_workspace::LoadedModel;
 use once_cell::sync::Lazy;
 use std::fs;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter};
+use std::path::PathBuf;
 use std::string::ToString;
 
 /// Automatic color selection flag.
@@ -73,6 +81,30 @@ static DEFAULT_INVOCABLE: Lazy<String> = Lazy::new(|| "unknown".to_string());
 ///
 static DEFAULT_COLOR: Lazy<String> = Lazy::new(|| "auto".to_string());
 
+///
+static DEFAULT_RECORDS: Lazy<String> = Lazy::new(|| "-".to_string());
+
+///
+static DEFAULT_OUTPUT_FORMAT: Lazy<String> = Lazy::new(|| RECORD_FORMAT_NDJSON.to_string());
+
+///
+static DEFAULT_LINT_FORMAT: Lazy<String> = Lazy::new(|| LINT_FORMAT_HUMAN.to_string());
+
+///
+static DEFAULT_GRAPH_FORMAT: Lazy<String> = Lazy::new(|| GRAPH_FORMAT_DOT.to_string());
+
+/// Name of the CSV record format.
+const RECORD_FORMAT_CSV: &str = "csv";
+
+/// Name of the NDJSON record format.
+const RECORD_FORMAT_NDJSON: &str = "ndjson";
+
+/// Default number of spaces used for indentation of formatted FEEL expressions.
+const DEFAULT_INDENT: usize = 2;
+
+/// Default maximum line width of formatted FEEL expressions.
+const DEFAULT_WIDTH: usize = 80;
+
 /// Command-line actions.
 enum Action {
   /// Parse FEEL expression.
@@ -91,6 +123,17 @@ enum Action {
     /// Name of the file containing FEEL expression to be evaluated.
     String,
   ),
+  /// Evaluate FEEL expression for streamed records.
+  StreamFeelExpression(
+    /// Name of the file containing records.
+    String,
+    /// Name of the file containing FEEL expression to be evaluated.
+    String,
+    /// Optional format of records.
+    Option<String>,
+    /// Format of results.
+    String,
+  ),
   /// Test FEEL expression.
   TestFeelExpression(
     /// Name of the file containing tests.
@@ -111,6 +154,17 @@ enum Action {
     /// Output HTML file name.
     String,
   ),
+  /// Format FEEL expression.
+  FormatFeelExpression(
+    /// Name of the file containing parsing context.
+    String,
+    /// Name of the file containing FEEL expression to be formatted.
+    String,
+    /// Number of spaces used for indentation.
+    usize,
+    /// Maximum line width.
+    usize,
+  ),
   /// Parse decision table.
   ParseDecisionTable(
     /// Name of the file containing decision table definitions (Unicode format).
@@ -162,6 +216,19 @@ enum Action {
     /// Name of the invocable to be evaluated.
     String,
   ),
+  /// Evaluate DMN model for streamed records.
+  StreamDmnModel(
+    /// Name of the file containing records.
+    String,
+    /// Name of the file containing DMN model to be evaluated.
+    String,
+    /// Name of the invocable to be evaluated.
+    String,
+    /// Optional format of records.
+    Option<String>,
+    /// Format of results.
+    String,
+  ),
   /// Test DMN model.
   TestDmnModel(
     /// Test file name.
@@ -194,9 +261,43 @@ enum Action {
     ColorMode,
     /// Flag indicating if more detailed information should be displayed during startup.
     bool,
+    /// Flag indicating if models should be validated against XML schema before loading.
+    bool,
+  ),
+  /// Lint DMN models.
+  LintDmnModels(
+    /// Names of the files containing DMN models.
+    Vec<String>,
+    /// Output format.
+    String,
+  ),
+  /// Compare DMN models.
+  DiffDmnModels(
+    /// Name of the file containing the old version of DMN model.
+    String,
+    /// Name of the file containing the new version of DMN model.
+    String,
+    /// Flag indicating if changes of diagrams should be reported.
+    bool,
+    /// Requested color mode.
+    ColorMode,
+  ),
+  /// Export decision requirements graph.
+  ExportDrgGraph(
+    /// Name of the file containing DMN model.
+    String,
+    /// Output format.
+    String,
+    /// Optional name of the invocable to which the graph is limited.
+    Option<String>,
   ),
   /// Generate examples.
   GenerateExamples,
+  /// Run interactive FEEL session.
+  Repl(
+    /// Requested color mode.
+    ColorMode,
+  ),
   /// Do nothing, no action was specified.
   DoNothing,
 }
@@ -214,6 +315,11 @@ pub async fn do_action() -> std::io::Result<()> {
       evaluate_feel_expression(&input_file_name, &feel_file_name);
       Ok(())
     }
+    Action::StreamFeelExpression(records_file_name, feel_file_name, input_format, output_format) => {
+      //
+      stream_feel_expression(&records_file_name, &feel_file_name, input_format.as_deref(), &output_format);
+      Ok(())
+    }
     Action::TestFeelExpression(test_file_name, feel_file_name, summary_only, color) => {
       //
       test_feel_expression(&test_file_name, &feel_file_name, summary_only, color);
@@ -224,6 +330,11 @@ pub async fn do_action() -> std::io::Result<()> {
       export_feel_expression(&ctx_file_name, &feel_file_name, &html_file_name);
       Ok(())
     }
+    Action::FormatFeelExpression(ctx_file_name, feel_file_name, indent, width) => {
+      //
+      format_feel_expression(&ctx_file_name, &feel_file_name, indent, width);
+      Ok(())
+    }
     Action::ParseDecisionTable(dectab_file_name) => {
       //
       parse_decision_table(&dectab_file_name);
@@ -259,6 +370,11 @@ pub async fn do_action() -> std::io::Result<()> {
       evaluate_dmn_model(&dmn_file_name, &ctx_file_name, &invocable_name);
       Ok(())
     }
+    Action::StreamDmnModel(records_file_name, dmn_file_name, invocable_name, input_format, output_format) => {
+      //
+      stream_dmn_model(&records_file_name, &dmn_file_name, &invocable_name, input_format.as_deref(), &output_format);
+      Ok(())
+    }
     Action::TestDmnModel(test_file_name, dmn_file_name, invocable_name, summary_only, color) => {
       //
       test_dmn_model(&test_file_name, &dmn_file_name, &invocable_name, summary_only, color);
@@ -269,14 +385,35 @@ pub async fn do_action() -> std::io::Result<()> {
       export_dmn_model(&dmn_file_name, &html_file_name);
       Ok(())
     }
-    Action::StartService(opt_host, opt_port, opt_dir, color, verbose) => {
+    Action::StartService(opt_host, opt_port, opt_dir, color, verbose, validate) => {
       // start DMNTK as a service (REST server)
-      dmntk_server::start_server(opt_host, opt_port, opt_dir, color, verbose).await
+      dmntk_server::start_server(opt_host, opt_port, opt_dir, color, verbose, validate).await
+    }
+    Action::LintDmnModels(dmn_file_names, format) => {
+      // lint DMN models, exit with non-zero status when errors were detected
+      if crate::lint::lint_dmn_models(&dmn_file_names, &format) > 0 {
+        std::process::exit(1);
+      }
+      Ok(())
+    }
+    Action::DiffDmnModels(old_file_name, new_file_name, include_diagrams, color) => {
+      // compare DMN models
+      crate::diff::diff_dmn_models(&old_file_name, &new_file_name, include_diagrams, color);
+      Ok(())
+    }
+    Action::ExportDrgGraph(dmn_file_name, format, opt_invocable_name) => {
+      // export decision requirements graph
+      crate::graph::export_graph(&dmn_file_name, &format, opt_invocable_name.as_deref());
+      Ok(())
     }
     Action::GenerateExamples => {
       // generate and save the examples
       generate_examples()
     }
+    Action::Repl(color) => {
+      // run interactive FEEL session
+      crate::repl::run_repl(color)
+    }
     Action::DoNothing => {
       // no specific action was requested
       Ok(())
@@ -310,6 +447,33 @@ fn get_matches() -> ArgMatches {
         .arg(arg!(<INPUT_FILE>).help("File containing input data for evaluated FEEL expression").required(true).index(1))
         .arg(arg!(<FEEL_FILE>).help("File containing FEEL expression to be evaluated").required(true).index(2)),
     )
+    // sfe
+    .subcommand(
+      Command::new("sfe")
+        .about("Stream records through FEEL Expression")
+        .display_order(18)
+        .arg(
+          arg!(-f --format <FORMAT>)
+            .help("Format of input records, detected from file extension when not specified")
+            .value_parser([RECORD_FORMAT_CSV, RECORD_FORMAT_NDJSON])
+            .action(ArgAction::Set)
+            .display_order(1),
+        )
+        .arg(
+          arg!(-o --output <FORMAT>)
+            .help("Format of evaluation results")
+            .value_parser([RECORD_FORMAT_CSV, RECORD_FORMAT_NDJSON])
+            .action(ArgAction::Set)
+            .display_order(2),
+        )
+        .arg(
+          arg!(<RECORDS_FILE>)
+            .help("File containing input records, '-' reads from standard input")
+            .required(true)
+            .index(1),
+        )
+        .arg(arg!(<FEEL_FILE>).help("File containing FEEL expression to be evaluated").required(true).index(2)),
+    )
     // tfe
     .subcommand(
       Command::new("tfe")
@@ -345,6 +509,28 @@ fn get_matches() -> ArgMatches {
         .arg(arg!(<FEEL_FILE>).help("File containing FEEL expression to be exported to HTML").required(true).index(2))
         .arg(arg!(<HTML_FILE>).help("Output HTML file").required(true).index(3)),
     )
+    // ffe
+    .subcommand(
+      Command::new("ffe")
+        .about("Format FEEL Expression")
+        .display_order(16)
+        .arg(
+          arg!(-i --indent <SPACES>)
+            .help("Number of spaces used for indentation")
+            .value_parser(clap::value_parser!(usize))
+            .action(ArgAction::Set)
+            .display_order(1),
+        )
+        .arg(
+          arg!(-w --width <COLUMNS>)
+            .help("Maximum line width")
+            .value_parser(clap::value_parser!(usize))
+            .action(ArgAction::Set)
+            .display_order(2),
+        )
+        .arg(arg!(<CONTEXT_FILE>).help("File containing context for formatted FEEL expression").required(true).index(1))
+        .arg(arg!(<FEEL_FILE>).help("File containing FEEL expression to be formatted").required(true).index(2)),
+    )
     // pdm
     .subcommand(
       Command::new("pdm")
@@ -374,6 +560,40 @@ fn get_matches() -> ArgMatches {
         .arg(arg!(<INPUT_FILE>).help("File containing input data for evaluated DMN model").required(true).index(1))
         .arg(arg!(<DMN_FILE>).help("File containing DMN model to be evaluated").required(true).index(2)),
     )
+    // sdm
+    .subcommand(
+      Command::new("sdm")
+        .about("Stream records through DMN Model")
+        .display_order(19)
+        .arg(
+          arg!(-i --invocable <NAME>)
+            .help("Name of the invocable (decision, bkm, decision service) to be evaluated")
+            .action(ArgAction::Set)
+            .required(true)
+            .display_order(1),
+        )
+        .arg(
+          arg!(-f --format <FORMAT>)
+            .help("Format of input records, detected from file extension when not specified")
+            .value_parser([RECORD_FORMAT_CSV, RECORD_FORMAT_NDJSON])
+            .action(ArgAction::Set)
+            .display_order(2),
+        )
+        .arg(
+          arg!(-o --output <FORMAT>)
+            .help("Format of evaluation results")
+            .value_parser([RECORD_FORMAT_CSV, RECORD_FORMAT_NDJSON])
+            .action(ArgAction::Set)
+            .display_order(3),
+        )
+        .arg(
+          arg!(<RECORDS_FILE>)
+            .help("File containing input records, '-' reads from standard input")
+            .required(true)
+            .index(1),
+        )
+        .arg(arg!(<DMN_FILE>).help("File containing DMN model to be evaluated").required(true).index(2)),
+    )
     // tdm
     .subcommand(
       Command::new("tdm")
@@ -475,16 +695,82 @@ fn get_matches() -> ArgMatches {
             .action(ArgAction::SetTrue)
             .display_order(4),
         )
+        .arg(
+          arg!(--validate)
+            .help("Validates models against DMN XML schema before loading")
+            .action(ArgAction::SetTrue)
+            .display_order(5),
+        )
         .arg(
           arg!(-c --color <WHEN>)
             .help("Control when colored output is used")
             .value_parser([COLOR_MODE_AUTO, COLOR_MODE_ALWAYS, COLOR_MODE_NEVER])
             .action(ArgAction::Set)
-            .display_order(4),
+            .display_order(6),
         ),
     )
+    // lint
+    .subcommand(
+      Command::new("lint")
+        .about("Lint DMN Models")
+        .display_order(20)
+        .arg(
+          arg!(-f --format <FORMAT>)
+            .help("Output format")
+            .value_parser([LINT_FORMAT_HUMAN, LINT_FORMAT_SARIF])
+            .action(ArgAction::Set)
+            .display_order(1),
+        )
+        .arg(arg!(<DMN_FILES>).help("Files containing DMN models to be linted").required(true).num_args(1..).index(1)),
+    )
+    // diff
+    .subcommand(
+      Command::new("diff")
+        .about("Compare DMN Models")
+        .display_order(21)
+        .arg(arg!(--diagrams).help("Reports changes of diagrams").action(ArgAction::SetTrue).display_order(1))
+        .arg(
+          arg!(-c --color <WHEN>)
+            .help("Control when colored output is used")
+            .value_parser([COLOR_MODE_AUTO, COLOR_MODE_ALWAYS, COLOR_MODE_NEVER])
+            .action(ArgAction::Set)
+            .display_order(2),
+        )
+        .arg(arg!(<OLD_DMN_FILE>).help("File containing the old version of DMN model").required(true).index(1))
+        .arg(arg!(<NEW_DMN_FILE>).help("File containing the new version of DMN model").required(true).index(2)),
+    )
+    // graph
+    .subcommand(
+      Command::new("graph")
+        .about("Export Decision Requirements Graph")
+        .display_order(22)
+        .arg(
+          arg!(-f --format <FORMAT>)
+            .help("Output format")
+            .value_parser([GRAPH_FORMAT_DOT, GRAPH_FORMAT_MERMAID])
+            .action(ArgAction::Set)
+            .display_order(1),
+        )
+        .arg(
+          arg!(-i --invocable <NAME>)
+            .help("Name of the invocable to limit the graph to")
+            .action(ArgAction::Set)
+            .display_order(2),
+        )
+        .arg(arg!(<DMN_FILE>).help("File containing DMN model").required(true).index(1)),
+    )
     // exs
     .subcommand(Command::new("exs").about("Generate examples in current directory").display_order(15))
+    // repl
+    .subcommand(
+      Command::new("repl").about("Run interactive FEEL session").display_order(17).arg(
+        arg!(-c --color <WHEN>)
+          .help("Control when colored output is used")
+          .value_parser([COLOR_MODE_AUTO, COLOR_MODE_ALWAYS, COLOR_MODE_NEVER])
+          .action(ArgAction::Set)
+          .display_order(1),
+      ),
+    )
     .get_matches()
 }
 
@@ -507,6 +793,15 @@ fn get_cli_action() -> Action {
         matches.get_one::<String>("FEEL_FILE").unwrap_or(&DEFAULT_FEEL).to_string(),
       );
     }
+    // stream records through FEEL expression subcommand
+    Some(("sfe", matches)) => {
+      return Action::StreamFeelExpression(
+        matches.get_one::<String>("RECORDS_FILE").unwrap_or(&DEFAULT_RECORDS).to_string(),
+        matches.get_one::<String>("FEEL_FILE").unwrap_or(&DEFAULT_FEEL).to_string(),
+        matches.get_one::<String>("format").map(|format| format.to_string()),
+        matches.get_one::<String>("output").unwrap_or(&DEFAULT_OUTPUT_FORMAT).to_string(),
+      );
+    }
     // test FEEL expression subcommand
     Some(("tfe", matches)) => {
       return Action::TestFeelExpression(
@@ -524,6 +819,15 @@ fn get_cli_action() -> Action {
         matches.get_one::<String>("HTML_FILE").unwrap_or(&DEFAULT_HTML).to_string(),
       );
     }
+    // format FEEL expression subcommand
+    Some(("ffe", matches)) => {
+      return Action::FormatFeelExpression(
+        matches.get_one::<String>("CONTEXT_FILE").unwrap_or(&DEFAULT_CTX).to_string(),
+        matches.get_one::<String>("FEEL_FILE").unwrap_or(&DEFAULT_FEEL).to_string(),
+        *matches.get_one::<usize>("indent").unwrap_or(&DEFAULT_INDENT),
+        *matches.get_one::<usize>("width").unwrap_or(&DEFAULT_WIDTH),
+      );
+    }
     // parse decision table subcommand
     Some(("pdt", matches)) => {
       return Action::ParseDecisionTable(matches.get_one::<String>("DECTAB_FILE").unwrap_or(&DEFAULT_DTB).to_string());
@@ -570,6 +874,16 @@ fn get_cli_action() -> Action {
         matches.get_one::<String>("invocable").unwrap_or(&DEFAULT_INVOCABLE).to_string(),
       );
     }
+    // stream records through DMN model subcommand
+    Some(("sdm", matches)) => {
+      return Action::StreamDmnModel(
+        matches.get_one::<String>("RECORDS_FILE").unwrap_or(&DEFAULT_RECORDS).to_string(),
+        matches.get_one::<String>("DMN_FILE").unwrap_or(&DEFAULT_DMN).to_string(),
+        matches.get_one::<String>("invocable").unwrap_or(&DEFAULT_INVOCABLE).to_string(),
+        matches.get_one::<String>("format").map(|format| format.to_string()),
+        matches.get_one::<String>("output").unwrap_or(&DEFAULT_OUTPUT_FORMAT).to_string(),
+      );
+    }
     // test DMN model subcommand
     Some(("tdm", matches)) => {
       return Action::TestDmnModel(
@@ -595,12 +909,46 @@ fn get_cli_action() -> Action {
         matches.get_one::<String>("dir").map(|dir| dir.to_string()),
         matches.get_one::<String>("color").unwrap_or(&DEFAULT_COLOR).to_string().into(),
         matches.get_flag("verbose"),
+        matches.get_flag("validate"),
+      );
+    }
+    // lint DMN models subcommand
+    Some(("lint", matches)) => {
+      return Action::LintDmnModels(
+        matches
+          .get_many::<String>("DMN_FILES")
+          .into_iter()
+          .flatten()
+          .map(|file_name| file_name.to_string())
+          .collect(),
+        matches.get_one::<String>("format").unwrap_or(&DEFAULT_LINT_FORMAT).to_string(),
+      );
+    }
+    // compare DMN models subcommand
+    Some(("diff", matches)) => {
+      return Action::DiffDmnModels(
+        matches.get_one::<String>("OLD_DMN_FILE").unwrap_or(&DEFAULT_DMN).to_string(),
+        matches.get_one::<String>("NEW_DMN_FILE").unwrap_or(&DEFAULT_DMN).to_string(),
+        matches.get_flag("diagrams"),
+        matches.get_one::<String>("color").unwrap_or(&DEFAULT_COLOR).to_string().into(),
+      );
+    }
+    // export decision requirements graph subcommand
+    Some(("graph", matches)) => {
+      return Action::ExportDrgGraph(
+        matches.get_one::<String>("DMN_FILE").unwrap_or(&DEFAULT_DMN).to_string(),
+        matches.get_one::<String>("format").unwrap_or(&DEFAULT_GRAPH_FORMAT).to_string(),
+        matches.get_one::<String>("invocable").cloned(),
       );
     }
     // generate examples
     Some(("exs", _)) => {
       return Action::GenerateExamples;
     }
+    // interactive FEEL session
+    Some(("repl", matches)) => {
+      return Action::Repl(matches.get_one::<String>("color").unwrap_or(&DEFAULT_COLOR).to_string().into());
+    }
     _ => {}
   }
   println!("dmntk {}", crate_version!());
@@ -651,6 +999,33 @@ fn evaluate_feel_expression(ctx_file_name: &str, feel_file_name: &str) {
   }
 }
 
+/// Evaluates `FEEL` expression loaded from file for every record
+/// and prints the results to standard output.
+fn stream_feel_expression(records_file_name: &str, feel_file_name: &str, input_format: Option<&str>, output_format: &str) {
+  let Some((input_format, output_format)) = record_formats(records_file_name, input_format, output_format) else {
+    return;
+  };
+  let textual_expression = match fs::read_to_string(feel_file_name) {
+    Ok(textual_expression) => textual_expression,
+    Err(reason) => {
+      eprintln!("loading expression file `{feel_file_name}` failed with reason: {reason}");
+      return;
+    }
+  };
+  let reader = match open_records(records_file_name) {
+    Ok(reader) => reader,
+    Err(reason) => {
+      eprintln!("loading records file `{records_file_name}` failed with reason: {reason}");
+      return;
+    }
+  };
+  let writer = BufWriter::new(io::stdout().lock());
+  match dmntk_evaluator::evaluate_expression_records(&textual_expression, reader, input_format, writer, output_format) {
+    Ok(summary) => eprintln!("evaluated records: {}, failed: {}", summary.records, summary.failures),
+    Err(reason) => eprintln!("evaluating records failed with reason: {reason}"),
+  }
+}
+
 /// Tests `FEEL` expression loaded from file and prints the test result to standard output.
 fn test_feel_expression(test_file_name: &str, feel_file_name: &str, summary_only: bool, color_mode: ColorMode) {
   match fs::read_to_string(feel_file_name) {
@@ -684,6 +1059,25 @@ fn export_feel_expression(_ctx_file_name: &str, _feel_file_name: &str, html_file
   let _ = fs::write(html_file_name, "not implemented yet\n");
 }
 
+/// Formats `FEEL` expression loaded from file and prints the canonical form to standard output.
+fn format_feel_expression(ctx_file_name: &str, feel_file_name: &str, indent: usize, width: usize) {
+  match fs::read_to_string(feel_file_name) {
+    Ok(feel_expression) => match fs::read_to_string(ctx_file_name) {
+      Ok(context_definition) => match dmntk_evaluator::evaluate_context(&FeelScope::default(), &context_definition) {
+        Ok(ctx) => match dmntk_feel_parser::parse_expression(&ctx.into(), &feel_expression, false) {
+          Ok(ast_root_node) => {
+            println!("{}", FeelFormatter::new(indent, width).format(&ast_root_node));
+          }
+          Err(reason) => eprintln!("parsing expression failed with reason: {reason}"),
+        },
+        Err(reason) => eprintln!("evaluating context failed with reason: {reason}"),
+      },
+      Err(reason) => eprintln!("loading context file `{ctx_file_name}` failed with reason: {reason}"),
+    },
+    Err(reason) => eprintln!("loading expression file `{feel_file_name}` failed with reason: {reason}"),
+  }
+}
+
 /// Parses decision table loaded from text file.
 fn parse_decision_table(dectab_file_name: &str) {
   match fs::read_to_string(dectab_file_name) {
@@ -836,7 +1230,10 @@ fn evaluate_dmn_model(input_file_name: &str, dmn_file_name: &str, invocable_name
         Ok(input_data) => match dmntk_model::parse(&dmn_file_content) {
           Ok(definitions) => {
             let namespace = definitions.namespace().to_string();
-            match dmntk_evaluator::ModelEvaluator::new(&[definitions]) {
+            let Some(definitions) = with_imported_models(dmn_file_name, definitions) else {
+              return;
+            };
+            match dmntk_evaluator::ModelEvaluator::new(&definitions) {
               Ok(model_evaluator) => {
                 let result = model_evaluator.evaluate_invocable_by_name(&namespace, invocable_name, &input_data);
                 println!("{}", result.jsonify())
@@ -854,6 +1251,96 @@ fn evaluate_dmn_model(input_file_name: &str, dmn_file_name: &str, invocable_name
   }
 }
 
+/// Returns the model loaded from file followed by all models it imports. Imported models are searched
+/// by location relative to the model file or by namespace in the directory containing the model file.
+/// Returns `None` when any of the imports could not be resolved.
+fn with_imported_models(dmn_file_name: &str, definitions: Definitions) -> Option<Vec<Definitions>> {
+  let path = PathBuf::from(dmn_file_name);
+  let search_dir = match path.parent() {
+    Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+    _ => PathBuf::from("."),
+  };
+  let (models, errors) = dmntk_workspace::resolve_imports(vec![LoadedModel { path, definitions }], &[search_dir], false);
+  if !errors.is_empty() {
+    for (_, reason) in errors {
+      eprintln!("resolving imports of model file `{dmn_file_name}` failed with reason: {reason}");
+    }
+    return None;
+  }
+  Some(models.into_iter().map(|model| model.definitions).collect())
+}
+
+/// Evaluates invocable from DMN model loaded from XML file for every record
+/// and prints the results to standard output.
+fn stream_dmn_model(records_file_name: &str, dmn_file_name: &str, invocable_name: &str, input_format: Option<&str>, output_format: &str) {
+  let Some((input_format, output_format)) = record_formats(records_file_name, input_format, output_format) else {
+    return;
+  };
+  let dmn_file_content = match fs::read_to_string(dmn_file_name) {
+    Ok(dmn_file_content) => dmn_file_content,
+    Err(reason) => {
+      eprintln!("loading model file `{dmn_file_name}` failed with reason: {reason}");
+      return;
+    }
+  };
+  let definitions = match dmntk_model::parse(&dmn_file_content) {
+    Ok(definitions) => definitions,
+    Err(reason) => {
+      eprintln!("parsing model file failed with reason: {reason}");
+      return;
+    }
+  };
+  let namespace = definitions.namespace().to_string();
+  let Some(definitions) = with_imported_models(dmn_file_name, definitions) else {
+    return;
+  };
+  let model_evaluator = match dmntk_evaluator::ModelEvaluator::new(&definitions) {
+    Ok(model_evaluator) => model_evaluator,
+    Err(reason) => {
+      eprintln!("building model evaluator failed with reason: {reason}");
+      return;
+    }
+  };
+  let reader = match open_records(records_file_name) {
+    Ok(reader) => reader,
+    Err(reason) => {
+      eprintln!("loading records file `{records_file_name}` failed with reason: {reason}");
+      return;
+    }
+  };
+  let writer = BufWriter::new(io::stdout().lock());
+  match dmntk_evaluator::evaluate_invocable_records(&model_evaluator, &namespace, invocable_name, reader, input_format, writer, output_format) {
+    Ok(summary) => eprintln!("evaluated records: {}, failed: {}", summary.records, summary.failures),
+    Err(reason) => eprintln!("evaluating records failed with reason: {reason}"),
+  }
+}
+
+/// Returns formats of input records and results, the format of input records
+/// is detected from file extension when not specified explicitly.
+fn record_formats(records_file_name: &str, input_format: Option<&str>, output_format: &str) -> Option<(RecordFormat, RecordFormat)> {
+  let input_format = input_format.unwrap_or(if records_file_name.to_lowercase().ends_with(".csv") {
+    RECORD_FORMAT_CSV
+  } else {
+    RECORD_FORMAT_NDJSON
+  });
+  match (input_format.parse::<RecordFormat>(), output_format.parse::<RecordFormat>()) {
+    (Ok(input_format), Ok(output_format)) => Some((input_format, output_format)),
+    (Err(reason), _) | (_, Err(reason)) => {
+      eprintln!("{reason}");
+      None
+    }
+  }
+}
+
+/// Opens the file containing records, `-` stands for standard input.
+fn open_records(records_file_name: &str) -> io::Result<Box<dyn BufRead>> {
+  if records_file_name == "-" {
+    Ok(Box::new(io::stdin().lock()))
+  } else {
+    Ok(Box::new(BufReader::new(File::open(records_file_name)?)))
+  }
+}
+
 /// Tests DMN model loaded from XML file.
 fn test_dmn_model(test_file_name: &str, dmn_file_name: &str, invocable_name: &str, summary_only: bool, color_mode: ColorMode) {
   let dmn_file_content = match fs::read_to_string(dmn_file_name) {
@@ -871,7 +1358,10 @@ fn test_dmn_model(test_file_name: &str, dmn_file_name: &str, invocable_name: &st
     }
   };
   let namespace = definitions.namespace().to_string();
-  let model_evaluator = match dmntk_evaluator::ModelEvaluator::new(&[definitions]) {
+  let Some(definitions) = with_imported_models(dmn_file_name, definitions) else {
+    return;
+  };
+  let model_evaluator = match dmntk_evaluator::ModelEvaluator::new(&definitions) {
     Ok(model_evaluator) => model_evaluator,
     Err(reason) => {
       eprintln!("building model evaluator failed with reason: {reason}");
@@ -0,0 +1,114 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! # Comparing DMN models
+//!
+//! Changes between two versions of a model are reported element by element,
+//! decision table rules are reported one per line with changed cells highlighted.
+
+use dmntk_common::{color_green, color_red, color_reset, color_yellow, ColorMode};
+use dmntk_model::{ChangeKind, Definitions, ModelChange, RuleChange};
+use std::fs;
+
+/// Compares DMN models loaded from files and prints changes to standard output.
+pub fn diff_dmn_models(old_file_name: &str, new_file_name: &str, include_diagrams: bool, color_mode: ColorMode) {
+  let (Some(old), Some(new)) = (load_model(old_file_name), load_model(new_file_name)) else {
+    return;
+  };
+  print!("{}", format_changes(&dmntk_model::diff(&old, &new, include_diagrams), color_mode));
+}
+
+/// Loads and parses the model from file, reports failures to standard error.
+fn load_model(dmn_file_name: &str) -> Option<Definitions> {
+  match fs::read_to_string(dmn_file_name) {
+    Ok(dmn_file_content) => match dmntk_model::parse(&dmn_file_content) {
+      Ok(definitions) => Some(definitions),
+      Err(reason) => {
+        eprintln!("parsing model file `{dmn_file_name}` failed with reason: {reason}");
+        None
+      }
+    },
+    Err(reason) => {
+      eprintln!("loading model file `{dmn_file_name}` failed with reason: {reason}");
+      None
+    }
+  }
+}
+
+/// Formats changes in human-readable format followed by a summary.
+pub fn format_changes(changes: &[ModelChange], color_mode: ColorMode) -> String {
+  let color_reset = color_reset!(color_mode);
+  let mut output = String::new();
+  for change in changes {
+    output.push_str(&format!("{}{change}{color_reset}\n", kind_color(change.kind(), color_mode)));
+    for detail in change.details() {
+      output.push_str(&format!("  {detail}\n"));
+    }
+    for rule_change in change.rule_changes() {
+      output.push_str(&format!("  {}\n", format_rule_change(rule_change, color_mode)));
+    }
+  }
+  output.push_str(&format!("found {} change(s)\n", changes.len()));
+  output
+}
+
+/// Formats the rule change in a single line, cells are separated with vertical bars.
+/// Changed cells of changed rules are highlighted and present both old and new text.
+fn format_rule_change(rule_change: &RuleChange, color_mode: ColorMode) -> String {
+  let color_red = color_red!(color_mode);
+  let color_green = color_green!(color_mode);
+  let color_reset = color_reset!(color_mode);
+  let cells = rule_change
+    .cells()
+    .iter()
+    .map(|cell| {
+      let old_text = cell.old_text().unwrap_or_default();
+      let new_text = cell.new_text().unwrap_or_default();
+      match rule_change.kind() {
+        ChangeKind::Changed if cell.is_changed() => format!("[{color_red}{old_text}{color_reset} -> {color_green}{new_text}{color_reset}]"),
+        ChangeKind::Removed => old_text.to_string(),
+        _ => new_text.to_string(),
+      }
+    })
+    .collect::<Vec<String>>()
+    .join(" | ");
+  format!("{}{rule_change}{color_reset}: {cells}", kind_color(rule_change.kind(), color_mode))
+}
+
+/// Returns the color of the change of specified kind.
+fn kind_color(kind: ChangeKind, color_mode: ColorMode) -> String {
+  match kind {
+    ChangeKind::Added => color_green!(color_mode),
+    ChangeKind::Removed => color_red!(color_mode),
+    ChangeKind::Changed => color_yellow!(color_mode),
+  }
+}
@@ -0,0 +1,75 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! # Exporting decision requirements graphs
+//!
+//! Decision requirements graph is built from the model definitions only,
+//! so it can be exported also for models without diagram interchange section.
+
+use dmntk_model::DrgGraph;
+use std::fs;
+
+/// Name of the Graphviz DOT output format.
+pub const GRAPH_FORMAT_DOT: &str = "dot";
+
+/// Name of the Mermaid output format.
+pub const GRAPH_FORMAT_MERMAID: &str = "mermaid";
+
+/// Exports the decision requirements graph of the DMN model loaded from file and prints it to standard output.
+///
+/// When the name of the invocable is specified, only the part of the graph
+/// required to evaluate this invocable is exported.
+pub fn export_graph(dmn_file_name: &str, format: &str, invocable_name: Option<&str>) {
+  match fs::read_to_string(dmn_file_name) {
+    Ok(dmn_file_content) => match dmntk_model::parse(&dmn_file_content) {
+      Ok(definitions) => {
+        let graph = match invocable_name {
+          Some(invocable_name) => match DrgGraph::for_invocable(&definitions, invocable_name) {
+            Ok(graph) => graph,
+            Err(reason) => {
+              eprintln!("{reason}");
+              return;
+            }
+          },
+          None => DrgGraph::new(&definitions),
+        };
+        if format == GRAPH_FORMAT_MERMAID {
+          print!("{}", graph.to_mermaid());
+        } else {
+          print!("{}", graph.to_dot());
+        }
+      }
+      Err(reason) => eprintln!("parsing model file `{dmn_file_name}` failed with reason: {reason}"),
+    },
+    Err(reason) => eprintln!("loading model file `{dmn_file_name}` failed with reason: {reason}"),
+  }
+}
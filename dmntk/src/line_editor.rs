@@ -0,0 +1,351 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! # Minimal line editor for the interactive REPL
+
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+
+/// Maximum number of lines kept in the history.
+const MAX_HISTORY_LENGTH: usize = 1000;
+
+/// Name of the file where the history is persisted, placed in the home directory.
+const HISTORY_FILE_NAME: &str = ".dmntk_history";
+
+/// Keys recognized by the line editor.
+#[derive(Debug, PartialEq)]
+pub(crate) enum Key {
+  /// Printable character.
+  Char(char),
+  /// Accept the line.
+  Enter,
+  /// Delete the character before the cursor.
+  Backspace,
+  /// Delete the character under the cursor.
+  Delete,
+  /// Move the cursor left.
+  Left,
+  /// Move the cursor right.
+  Right,
+  /// Previous line from the history.
+  Up,
+  /// Next line from the history.
+  Down,
+  /// Move the cursor to the beginning of the line.
+  Home,
+  /// Move the cursor to the end of the line.
+  End,
+  /// Discard the line (Ctrl-C).
+  Interrupt,
+  /// End of input (Ctrl-D).
+  EndOfFile,
+  /// Unsupported key.
+  Ignored,
+}
+
+/// Edited line with the cursor position.
+#[derive(Debug, Default)]
+pub struct LineBuffer {
+  /// Characters of the edited line.
+  chars: Vec<char>,
+  /// Cursor position, counted in characters.
+  cursor: usize,
+}
+
+impl LineBuffer {
+  /// Returns the text of the edited line.
+  pub fn text(&self) -> String {
+    self.chars.iter().collect()
+  }
+
+  /// Returns the cursor position.
+  pub fn cursor(&self) -> usize {
+    self.cursor
+  }
+
+  /// Returns `true` when the line is empty.
+  pub fn is_empty(&self) -> bool {
+    self.chars.is_empty()
+  }
+
+  /// Replaces the edited line and moves the cursor to the end.
+  pub fn set(&mut self, text: &str) {
+    self.chars = text.chars().collect();
+    self.cursor = self.chars.len();
+  }
+
+  /// Inserts a character at the cursor position.
+  pub fn insert(&mut self, ch: char) {
+    self.chars.insert(self.cursor, ch);
+    self.cursor += 1;
+  }
+
+  /// Deletes the character before the cursor.
+  pub fn backspace(&mut self) {
+    if self.cursor > 0 {
+      self.cursor -= 1;
+      self.chars.remove(self.cursor);
+    }
+  }
+
+  /// Deletes the character under the cursor.
+  pub fn delete(&mut self) {
+    if self.cursor < self.chars.len() {
+      self.chars.remove(self.cursor);
+    }
+  }
+
+  /// Moves the cursor one character left.
+  pub fn left(&mut self) {
+    self.cursor = self.cursor.saturating_sub(1);
+  }
+
+  /// Moves the cursor one character right.
+  pub fn right(&mut self) {
+    self.cursor = (self.cursor + 1).min(self.chars.len());
+  }
+
+  /// Moves the cursor to the beginning of the line.
+  pub fn home(&mut self) {
+    self.cursor = 0;
+  }
+
+  /// Moves the cursor to the end of the line.
+  pub fn end(&mut self) {
+    self.cursor = self.chars.len();
+  }
+}
+
+/// Line editor with history.
+///
+/// When the standard input is a terminal, lines are edited in raw mode
+/// supporting cursor movement and history navigation. In any other case lines are read
+/// from the standard input without editing.
+pub struct LineEditor {
+  /// Previously entered lines, the most recent at the end.
+  history: Vec<String>,
+  /// Optional file where the history is persisted.
+  history_file: Option<PathBuf>,
+}
+
+impl LineEditor {
+  /// Creates a line editor with the history loaded from the default history file.
+  pub fn new() -> Self {
+    let history_file = std::env::var_os("HOME").map(|home| PathBuf::from(home).join(HISTORY_FILE_NAME));
+    let history = history_file
+      .as_ref()
+      .and_then(|file_name| fs::read_to_string(file_name).ok())
+      .map(|content| content.lines().filter(|line| !line.trim().is_empty()).map(|line| line.to_string()).collect())
+      .unwrap_or_default();
+    Self { history, history_file }
+  }
+
+  /// Adds a line to the history, empty lines and repeated lines are skipped.
+  pub fn add_history(&mut self, line: &str) {
+    if !line.trim().is_empty() && self.history.last().map(|last| last.as_str()) != Some(line) {
+      self.history.push(line.to_string());
+      if self.history.len() > MAX_HISTORY_LENGTH {
+        self.history.remove(0);
+      }
+    }
+  }
+
+  /// Saves the history to the history file.
+  pub fn save_history(&self) {
+    if let Some(file_name) = &self.history_file {
+      let mut content = self.history.join("\n");
+      content.push('\n');
+      let _ = fs::write(file_name, content);
+    }
+  }
+
+  /// Reads a single line, returns `None` when the input is closed.
+  pub fn read_line(&mut self, prompt: &str) -> io::Result<Option<String>> {
+    if let Some(_raw_mode) = raw_mode::RawMode::enable() {
+      return self.edit_line(prompt);
+    }
+    print!("{prompt}");
+    io::stdout().flush()?;
+    let mut line = String::new();
+    if io::stdin().lock().read_line(&mut line)? == 0 {
+      println!();
+      return Ok(None);
+    }
+    Ok(Some(line.trim_end_matches(['\r', '\n']).to_string()))
+  }
+
+  /// Edits a single line in terminal raw mode.
+  fn edit_line(&mut self, prompt: &str) -> io::Result<Option<String>> {
+    let mut stdout = io::stdout().lock();
+    let mut buffer = LineBuffer::default();
+    let mut history_index = self.history.len();
+    let mut draft = String::new();
+    refresh(&mut stdout, prompt, &buffer)?;
+    loop {
+      match read_key()? {
+        Key::Char(ch) => buffer.insert(ch),
+        Key::Enter => {
+          write!(stdout, "\r\n")?;
+          stdout.flush()?;
+          return Ok(Some(buffer.text()));
+        }
+        Key::Interrupt => {
+          write!(stdout, "^C\r\n")?;
+          stdout.flush()?;
+          return Ok(Some(String::new()));
+        }
+        Key::EndOfFile => {
+          if buffer.is_empty() {
+            write!(stdout, "\r\n")?;
+            stdout.flush()?;
+            return Ok(None);
+          }
+          buffer.delete();
+        }
+        Key::Backspace => buffer.backspace(),
+        Key::Delete => buffer.delete(),
+        Key::Left => buffer.left(),
+        Key::Right => buffer.right(),
+        Key::Home => buffer.home(),
+        Key::End => buffer.end(),
+        Key::Up => {
+          if history_index > 0 {
+            if history_index == self.history.len() {
+              draft = buffer.text();
+            }
+            history_index -= 1;
+            buffer.set(&self.history[history_index]);
+          }
+        }
+        Key::Down => {
+          if history_index < self.history.len() {
+            history_index += 1;
+            buffer.set(self.history.get(history_index).unwrap_or(&draft));
+          }
+        }
+        Key::Ignored => {}
+      }
+      refresh(&mut stdout, prompt, &buffer)?;
+    }
+  }
+}
+
+/// Redraws the prompt and the edited line, and places the cursor.
+fn refresh(stdout: &mut impl Write, prompt: &str, buffer: &LineBuffer) -> io::Result<()> {
+  let text = buffer.text();
+  write!(stdout, "\r{prompt}{text}\u{1b}[K")?;
+  let back = text.chars().count() - buffer.cursor();
+  if back > 0 {
+    write!(stdout, "\u{1b}[{back}D")?;
+  }
+  stdout.flush()
+}
+
+/// Reads the next key pressed in the terminal, other terminal events are skipped.
+fn read_key() -> io::Result<Key> {
+  loop {
+    if let Event::Key(key_event) = event::read()? {
+      if key_event.kind != KeyEventKind::Release {
+        return Ok(key_from_event(key_event));
+      }
+    }
+  }
+}
+
+/// Converts the key event reported by the terminal into the key recognized by the line editor.
+///
+/// Escape sequences (including sequences of keys pressed with modifiers) are decoded by the terminal
+/// library, so the lone `Esc` and any unsupported sequence are reported as a single ignored key.
+pub(crate) fn key_from_event(key_event: KeyEvent) -> Key {
+  let modifiers = key_event.modifiers - KeyModifiers::SHIFT;
+  if modifiers == KeyModifiers::CONTROL {
+    return match key_event.code {
+      KeyCode::Char('a') => Key::Home,
+      KeyCode::Char('b') => Key::Left,
+      KeyCode::Char('c') => Key::Interrupt,
+      KeyCode::Char('d') => Key::EndOfFile,
+      KeyCode::Char('e') => Key::End,
+      KeyCode::Char('f') => Key::Right,
+      KeyCode::Char('h') => Key::Backspace,
+      KeyCode::Char('n') => Key::Down,
+      KeyCode::Char('p') => Key::Up,
+      _ => Key::Ignored,
+    };
+  }
+  if !modifiers.is_empty() {
+    return Key::Ignored;
+  }
+  match key_event.code {
+    KeyCode::Char(ch) => Key::Char(ch),
+    KeyCode::Enter => Key::Enter,
+    KeyCode::Backspace => Key::Backspace,
+    KeyCode::Delete => Key::Delete,
+    KeyCode::Left => Key::Left,
+    KeyCode::Right => Key::Right,
+    KeyCode::Up => Key::Up,
+    KeyCode::Down => Key::Down,
+    KeyCode::Home => Key::Home,
+    KeyCode::End => Key::End,
+    _ => Key::Ignored,
+  }
+}
+
+mod raw_mode {
+  //! Switching the terminal into raw mode.
+
+  use crossterm::terminal;
+  use crossterm::tty::IsTty;
+  use std::io;
+
+  /// Terminal in raw mode, the original mode is restored when dropped.
+  pub struct RawMode;
+
+  impl RawMode {
+    /// Switches the terminal attached to the standard input into raw mode,
+    /// returns `None` when the standard input is not a terminal.
+    pub fn enable() -> Option<Self> {
+      if !io::stdin().is_tty() {
+        return None;
+      }
+      terminal::enable_raw_mode().ok().map(|_| Self)
+    }
+  }
+
+  impl Drop for RawMode {
+    /// Restores the original terminal mode.
+    fn drop(&mut self) {
+      let _ = terminal::disable_raw_mode();
+    }
+  }
+}
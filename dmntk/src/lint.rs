@@ -0,0 +1,353 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! # Linting DMN models
+//!
+//! Issues detected in models are reported in human-readable format,
+//! or in [SARIF](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html) format
+//! consumed by code analysis and code review tools.
+
+use clap::crate_version;
+use dmntk_model::{LintDiagnostic, LintRule, LintSeverity};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+
+/// Name of the human-readable output format.
+pub const LINT_FORMAT_HUMAN: &str = "human";
+
+/// Name of the SARIF output format.
+pub const LINT_FORMAT_SARIF: &str = "sarif";
+
+/// URI of the SARIF 2.1.0 schema.
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Version of the SARIF format.
+const SARIF_VERSION: &str = "2.1.0";
+
+/// Identifier of the SARIF rule reported for models that could not be loaded or parsed.
+const INVALID_MODEL_RULE_ID: &str = "invalid-model";
+
+/// Issues detected in a single model file.
+pub struct LintReport {
+  /// Name of the file containing the model.
+  pub file_name: String,
+  /// Detected issues.
+  pub diagnostics: Vec<LintDiagnostic>,
+  /// Positions (line and column) of model elements, indexed by element identifiers.
+  pub positions: HashMap<String, (u32, u32)>,
+  /// Reason why the model could not be loaded or parsed.
+  pub failure: Option<String>,
+}
+
+impl LintReport {
+  /// Parses the model and lints it, parsing failure is reported instead of issues.
+  pub fn new(file_name: &str, xml: &str) -> Self {
+    match dmntk_model::parse(xml) {
+      Ok(definitions) => Self {
+        file_name: file_name.to_string(),
+        diagnostics: dmntk_model::lint(&definitions),
+        positions: dmntk_model::element_positions(xml),
+        failure: None,
+      },
+      Err(reason) => Self::failed(file_name, &format!("parsing model failed with reason: {reason}")),
+    }
+  }
+
+  /// Creates a report of the model that could not be loaded or parsed.
+  pub fn failed(file_name: &str, reason: &str) -> Self {
+    Self {
+      file_name: file_name.to_string(),
+      diagnostics: vec![],
+      positions: HashMap::new(),
+      failure: Some(reason.to_string()),
+    }
+  }
+
+  /// Returns the number of errors, failure to load or parse the model is counted as an error.
+  pub fn error_count(&self) -> usize {
+    self.failure.iter().count() + self.diagnostics.iter().filter(|diagnostic| diagnostic.severity() == LintSeverity::Error).count()
+  }
+}
+
+/// Lints DMN models loaded from files and prints detected issues to standard output.
+/// Returns the number of detected errors.
+pub fn lint_dmn_models(dmn_file_names: &[String], format: &str) -> usize {
+  let mut reports = vec![];
+  for dmn_file_name in dmn_file_names {
+    match fs::read_to_string(dmn_file_name) {
+      Ok(dmn_file_content) => reports.push(LintReport::new(dmn_file_name, &dmn_file_content)),
+      Err(reason) => reports.push(LintReport::failed(dmn_file_name, &format!("loading model failed with reason: {reason}"))),
+    }
+  }
+  if format == LINT_FORMAT_SARIF {
+    println!("{}", format_sarif(&reports));
+  } else {
+    print!("{}", format_human(&reports));
+  }
+  reports.iter().map(LintReport::error_count).sum()
+}
+
+/// Returns the position (line and column) reported in the reason of parsing failure.
+/// Positions are reported as `at [line:column]` by model parser and as `at line:column` by XML parser.
+fn failure_position(reason: &str) -> Option<(u32, u32)> {
+  reason.rmatch_indices(" at ").find_map(|(index, separator)| {
+    let (line, rest) = reason[index + separator.len()..].trim_start_matches('[').split_once(':')?;
+    let column = rest.split(|ch: char| !ch.is_ascii_digit()).next()?;
+    Some((line.parse().ok()?, column.parse().ok()?))
+  })
+}
+
+/// Formats detected issues in human-readable format, one issue per line followed by a summary.
+pub fn format_human(reports: &[LintReport]) -> String {
+  let mut output = String::new();
+  let (mut errors, mut warnings) = (0, 0);
+  for report in reports {
+    if let Some(reason) = &report.failure {
+      errors += 1;
+      output.push_str(&format!("{}: error[{INVALID_MODEL_RULE_ID}]: {reason}\n", report.file_name));
+    }
+    for diagnostic in &report.diagnostics {
+      match diagnostic.severity() {
+        LintSeverity::Error => errors += 1,
+        LintSeverity::Warning => warnings += 1,
+      }
+      output.push_str(&format!("{}: {diagnostic}\n", report.file_name));
+    }
+  }
+  output.push_str(&format!("found {errors} error(s) and {warnings} warning(s)\n"));
+  output
+}
+
+/// SARIF log.
+#[derive(Serialize)]
+struct SarifLog {
+  #[serde(rename = "$schema")]
+  schema: &'static str,
+  version: &'static str,
+  runs: Vec<SarifRun>,
+}
+
+/// Single run of the analysis tool.
+#[derive(Serialize)]
+struct SarifRun {
+  tool: SarifTool,
+  results: Vec<SarifResult>,
+}
+
+/// Analysis tool.
+#[derive(Serialize)]
+struct SarifTool {
+  driver: SarifDriver,
+}
+
+/// Component of the analysis tool, containing the rules.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver {
+  name: &'static str,
+  version: &'static str,
+  information_uri: &'static str,
+  rules: Vec<SarifRule>,
+}
+
+/// Rule checked by the analysis tool.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRule {
+  id: &'static str,
+  short_description: SarifMessage,
+  default_configuration: SarifConfiguration,
+}
+
+/// Default configuration of the rule.
+#[derive(Serialize)]
+struct SarifConfiguration {
+  level: &'static str,
+}
+
+/// Text message.
+#[derive(Serialize)]
+struct SarifMessage {
+  text: String,
+}
+
+/// Detected issue.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+  rule_id: &'static str,
+  rule_index: usize,
+  level: &'static str,
+  message: SarifMessage,
+  locations: Vec<SarifLocation>,
+}
+
+/// Location of the detected issue, the file and the element of the model.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+  physical_location: SarifPhysicalLocation,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  logical_locations: Vec<SarifLogicalLocation>,
+}
+
+/// File containing the model and the region of the file, where the issue was detected.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+  artifact_location: SarifArtifactLocation,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  region: Option<SarifRegion>,
+}
+
+/// Region of the file, where the issue was detected.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+  start_line: u32,
+  start_column: u32,
+}
+
+impl From<(u32, u32)> for SarifRegion {
+  fn from((start_line, start_column): (u32, u32)) -> Self {
+    Self { start_line, start_column }
+  }
+}
+
+/// URI of the file containing the model.
+#[derive(Serialize)]
+struct SarifArtifactLocation {
+  uri: String,
+}
+
+/// Element of the model.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLogicalLocation {
+  name: String,
+  fully_qualified_name: String,
+  kind: &'static str,
+}
+
+/// Returns the SARIF level of issues with specified severity.
+fn sarif_level(severity: LintSeverity) -> &'static str {
+  match severity {
+    LintSeverity::Warning => "warning",
+    LintSeverity::Error => "error",
+  }
+}
+
+/// Formats detected issues as SARIF log. Models that could not be loaded or parsed
+/// are reported as results of the rule following all rules checked by the linter.
+pub fn format_sarif(reports: &[LintReport]) -> String {
+  let mut rules = LintRule::ALL
+    .iter()
+    .map(|rule| SarifRule {
+      id: rule.id(),
+      short_description: SarifMessage {
+        text: rule.description().to_string(),
+      },
+      default_configuration: SarifConfiguration {
+        level: sarif_level(rule.severity()),
+      },
+    })
+    .collect::<Vec<SarifRule>>();
+  rules.push(SarifRule {
+    id: INVALID_MODEL_RULE_ID,
+    short_description: SarifMessage {
+      text: "model can not be loaded or parsed".to_string(),
+    },
+    default_configuration: SarifConfiguration {
+      level: sarif_level(LintSeverity::Error),
+    },
+  });
+  let mut results = vec![];
+  for report in reports {
+    if let Some(reason) = &report.failure {
+      results.push(SarifResult {
+        rule_id: INVALID_MODEL_RULE_ID,
+        rule_index: LintRule::ALL.len(),
+        level: sarif_level(LintSeverity::Error),
+        message: SarifMessage { text: reason.to_string() },
+        locations: vec![SarifLocation {
+          physical_location: SarifPhysicalLocation {
+            artifact_location: SarifArtifactLocation {
+              uri: report.file_name.replace('\\', "/"),
+            },
+            region: failure_position(reason).map(SarifRegion::from),
+          },
+          logical_locations: vec![],
+        }],
+      });
+    }
+    for diagnostic in &report.diagnostics {
+      results.push(SarifResult {
+        rule_id: diagnostic.rule().id(),
+        rule_index: LintRule::ALL.iter().position(|rule| *rule == diagnostic.rule()).unwrap_or_default(),
+        level: sarif_level(diagnostic.severity()),
+        message: SarifMessage {
+          text: diagnostic.message().to_string(),
+        },
+        locations: vec![SarifLocation {
+          physical_location: SarifPhysicalLocation {
+            artifact_location: SarifArtifactLocation {
+              uri: report.file_name.replace('\\', "/"),
+            },
+            region: report.positions.get(diagnostic.element_id()).copied().map(SarifRegion::from),
+          },
+          logical_locations: vec![SarifLogicalLocation {
+            name: diagnostic.element_name().to_string(),
+            fully_qualified_name: diagnostic.element_id().to_string(),
+            kind: "element",
+          }],
+        }],
+      });
+    }
+  }
+  let log = SarifLog {
+    schema: SARIF_SCHEMA,
+    version: SARIF_VERSION,
+    runs: vec![SarifRun {
+      tool: SarifTool {
+        driver: SarifDriver {
+          name: "dmntk",
+          version: crate_version!(),
+          information_uri: "https://dmntk.io",
+          rules,
+        },
+      },
+      results,
+    }],
+  };
+  serde_json::to_string_pretty(&log).unwrap_or("conversion to SARIF failed".to_string())
+}
@@ -33,7 +33,15 @@
 //! # **DMNTK** | Decision Model and Notation Toolkit
 
 mod actions;
+mod diff;
 mod examples;
+mod graph;
+mod line_editor;
+mod lint;
+mod repl;
+
+#[cfg(test)]
+mod tests;
 
 /// Main entrypoint of **DMNTK**.
 #[actix_web::main]
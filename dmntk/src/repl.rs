@@ -0,0 +1,255 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! # Interactive FEEL read-eval-print loop
+
+use crate::line_editor::LineEditor;
+use clap::crate_version;
+use dmntk_common::*;
+use dmntk_evaluator::ModelEvaluator;
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::values::Value;
+use dmntk_feel::FeelScope;
+use dmntk_model::Definitions;
+use std::fs;
+use std::sync::Arc;
+
+/// Prompt displayed before each input line.
+const PROMPT: &str = "feel> ";
+
+/// Help displayed by `:help` command.
+const HELP: &str = r#"Enter a FEEL expression to evaluate it, or `name := expression` to bind the result to a name.
+Commands:
+  :load <FILE>     load entries of a context from a .ctx file
+  :model <FILE>    load a DMN model from a .dmn file
+  :invocables      list invocables of loaded models
+  :invoke <NAME>   evaluate an invocable with current bindings as input data
+  :scope           list current bindings
+  :clear           remove all bindings
+  :help            display this help
+  :quit            exit the REPL"#;
+
+/// Interactive FEEL session.
+pub struct Repl {
+  /// Scope holding the bindings made during the session.
+  scope: FeelScope,
+  /// Definitions of loaded DMN models.
+  definitions: Vec<Definitions>,
+  /// Evaluator of loaded DMN models.
+  model_evaluator: Option<Arc<ModelEvaluator>>,
+  /// Color mode used when displaying results.
+  color_mode: ColorMode,
+}
+
+impl Repl {
+  /// Creates a new session with empty scope.
+  pub fn new(color_mode: ColorMode) -> Self {
+    Self {
+      scope: FeelScope::default(),
+      definitions: vec![],
+      model_evaluator: None,
+      color_mode,
+    }
+  }
+
+  /// Executes a single input line and returns the text to be displayed,
+  /// `None` is returned when the session should be finished.
+  pub fn execute(&mut self, line: &str) -> Option<String> {
+    let line = line.trim();
+    if line.is_empty() {
+      return Some(String::new());
+    }
+    if let Some(command) = line.strip_prefix(':') {
+      let (command, argument) = command.split_once(char::is_whitespace).unwrap_or((command, ""));
+      return self.execute_command(command, argument.trim());
+    }
+    Some(match self.execute_expression(line) {
+      Ok(output) => output,
+      Err(reason) => self.error(reason),
+    })
+  }
+
+  /// Executes REPL command.
+  fn execute_command(&mut self, command: &str, argument: &str) -> Option<String> {
+    let result = match command {
+      "q" | "quit" | "exit" => return None,
+      "help" | "h" => Ok(HELP.to_string()),
+      "load" => self.load_context(argument),
+      "model" => self.load_model(argument),
+      "invocables" => self.list_invocables(),
+      "invoke" => self.invoke(argument),
+      "scope" => Ok(self.list_bindings()),
+      "clear" => {
+        self.scope = FeelScope::default();
+        Ok(String::new())
+      }
+      other => Err(format!("unknown command `:{other}`, type :help for available commands")),
+    };
+    Some(result.unwrap_or_else(|reason| self.error(reason)))
+  }
+
+  /// Evaluates an expression, or a binding in the form `name := expression`.
+  fn execute_expression(&mut self, line: &str) -> Result<String, String> {
+    if let Some((lhs, rhs)) = split_binding(line) {
+      let name = dmntk_feel_parser::parse_name(&self.scope, lhs.trim(), false).map_err(|reason| reason.to_string())?;
+      let value = self.evaluate(rhs)?;
+      let output = format!("{} = {}", name, self.show(&value));
+      self.scope.set_value(&name, value);
+      return Ok(output);
+    }
+    let value = self.evaluate(line)?;
+    Ok(self.show(&value))
+  }
+
+  /// Evaluates a textual expression in the session scope.
+  fn evaluate(&self, text: &str) -> Result<Value, String> {
+    let node = dmntk_feel_parser::parse_expression(&self.scope, text, false).map_err(|reason| reason.to_string())?;
+    dmntk_evaluator::evaluate(&self.scope, &node).map_err(|reason| reason.to_string())
+  }
+
+  /// Loads entries of a context from file and binds them in the session scope.
+  fn load_context(&mut self, file_name: &str) -> Result<String, String> {
+    let content = fs::read_to_string(file_name).map_err(|reason| format!("loading context file `{file_name}` failed with reason: {reason}"))?;
+    let ctx = dmntk_evaluator::evaluate_context(&self.scope, &content).map_err(|reason| reason.to_string())?;
+    for (name, value) in ctx.iter() {
+      self.scope.set_value(name, value.clone());
+    }
+    Ok(format!("loaded {} entries from `{file_name}`", ctx.len()))
+  }
+
+  /// Loads DMN model from file and rebuilds the model evaluator.
+  fn load_model(&mut self, file_name: &str) -> Result<String, String> {
+    let content = fs::read_to_string(file_name).map_err(|reason| format!("loading model file `{file_name}` failed with reason: {reason}"))?;
+    let definitions = dmntk_model::parse(&content).map_err(|reason| reason.to_string())?;
+    let namespace = definitions.namespace().to_string();
+    let mut all_definitions = self.definitions.clone();
+    all_definitions.retain(|loaded| loaded.namespace() != namespace);
+    all_definitions.push(definitions);
+    let model_evaluator = ModelEvaluator::new(&all_definitions).map_err(|reason| reason.to_string())?;
+    let count = model_evaluator.invocables().namespace_name().iter().filter(|(ns, _)| *ns == namespace).count();
+    self.definitions = all_definitions;
+    self.model_evaluator = Some(model_evaluator);
+    Ok(format!("loaded model `{namespace}` with {count} invocables"))
+  }
+
+  /// Lists the names of invocables in loaded models.
+  fn list_invocables(&self) -> Result<String, String> {
+    let model_evaluator = self.model_evaluator()?;
+    Ok(
+      model_evaluator
+        .invocables()
+        .namespace_name()
+        .iter()
+        .map(|(namespace, name)| format!("{name} {}({namespace}){}", color_blue!(self.color_mode), color_reset!(self.color_mode)))
+        .collect::<Vec<String>>()
+        .join("\n"),
+    )
+  }
+
+  /// Evaluates an invocable using current bindings as input data.
+  fn invoke(&self, invocable_name: &str) -> Result<String, String> {
+    let model_evaluator = self.model_evaluator()?;
+    let namespaces = model_evaluator
+      .invocables()
+      .namespace_name()
+      .into_iter()
+      .filter_map(|(namespace, name)| (name == invocable_name).then_some(namespace))
+      .collect::<Vec<String>>();
+    match namespaces.as_slice() {
+      [namespace] => {
+        let input_data = self.scope.peek().unwrap_or_default();
+        let value = model_evaluator.evaluate_invocable_by_name(namespace, invocable_name, &input_data);
+        Ok(self.show(&value))
+      }
+      [] => Err(format!("invocable `{invocable_name}` not found")),
+      _ => Err(format!("invocable `{invocable_name}` is defined in more than one model")),
+    }
+  }
+
+  /// Lists bindings made in the session.
+  fn list_bindings(&self) -> String {
+    let ctx: FeelContext = self.scope.peek().unwrap_or_default();
+    ctx
+      .iter()
+      .map(|(name, value)| format!("{} = {}", name, self.show(value)))
+      .collect::<Vec<String>>()
+      .join("\n")
+  }
+
+  /// Returns the model evaluator or an error when no model was loaded.
+  fn model_evaluator(&self) -> Result<&Arc<ModelEvaluator>, String> {
+    self
+      .model_evaluator
+      .as_ref()
+      .ok_or_else(|| "no model loaded, use :model <FILE> to load a model".to_string())
+  }
+
+  /// Formats a value followed by its type.
+  fn show(&self, value: &Value) -> String {
+    format!("{} {}: {}{}", value, color_blue!(self.color_mode), value.type_of(), color_reset!(self.color_mode))
+  }
+
+  /// Formats an error message.
+  fn error(&self, reason: String) -> String {
+    format!("{}error: {}{}", color_red!(self.color_mode), reason, color_reset!(self.color_mode))
+  }
+}
+
+/// Splits the binding in the form `name := expression` into the name and the expression.
+/// The line is split at the first `:=`, unless the text before it contains a quotation mark,
+/// so `:=` placed in string literals of an expression does not start a binding.
+/// The text before `:=` is not checked here, it is parsed as a name when the binding is executed.
+fn split_binding(line: &str) -> Option<(&str, &str)> {
+  let (lhs, rhs) = line.split_once(":=")?;
+  if lhs.contains('"') {
+    None
+  } else {
+    Some((lhs, rhs))
+  }
+}
+
+/// Runs the interactive FEEL session until the input is closed or `:quit` is entered.
+pub fn run_repl(color_mode: ColorMode) -> std::io::Result<()> {
+  println!("dmntk {} | FEEL REPL, type :help for help", crate_version!());
+  let mut line_editor = LineEditor::new();
+  let mut repl = Repl::new(color_mode);
+  while let Some(line) = line_editor.read_line(PROMPT)? {
+    line_editor.add_history(&line);
+    match repl.execute(&line) {
+      Some(output) if output.is_empty() => {}
+      Some(output) => println!("{output}"),
+      None => break,
+    }
+  }
+  line_editor.save_history();
+  Ok(())
+}
@@ -0,0 +1,108 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::diff::format_changes;
+use dmntk_common::ColorMode;
+
+/// Old version of the model.
+const OLD_MODEL: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<definitions namespace="https://dmntk.io/diff" name="diff" id="_diff" xmlns="https://www.omg.org/spec/DMN/20191111/MODEL/">
+    <decision name="Fee" id="_fee">
+        <variable typeRef="number" name="Fee"/>
+        <decisionTable>
+            <input><inputExpression><text>Amount</text></inputExpression></input>
+            <output/>
+            <rule><inputEntry><text>&lt; 100</text></inputEntry><outputEntry><text>5</text></outputEntry></rule>
+            <rule><inputEntry><text>&gt;= 100</text></inputEntry><outputEntry><text>10</text></outputEntry></rule>
+        </decisionTable>
+    </decision>
+    <inputData name="Amount" id="_amount">
+        <variable typeRef="number" name="Amount"/>
+    </inputData>
+</definitions>"#;
+
+/// New version of the model.
+const NEW_MODEL: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<definitions namespace="https://dmntk.io/diff" name="diff" id="_diff" xmlns="https://www.omg.org/spec/DMN/20191111/MODEL/">
+    <inputData id="_amount" name="Amount">
+        <variable name="Amount" typeRef="number"/>
+    </inputData>
+    <decision name="Fee" id="_fee">
+        <variable typeRef="number" name="Fee"/>
+        <decisionTable>
+            <input><inputExpression><text>Amount</text></inputExpression></input>
+            <output/>
+            <rule><inputEntry><text>&lt; 100</text></inputEntry><outputEntry><text>5</text></outputEntry></rule>
+            <rule><inputEntry><text>[100..1000]</text></inputEntry><outputEntry><text>10</text></outputEntry></rule>
+            <rule><inputEntry><text>&gt; 1000</text></inputEntry><outputEntry><text>0</text></outputEntry></rule>
+        </decisionTable>
+    </decision>
+    <inputData name="Currency" id="_currency">
+        <variable typeRef="string" name="Currency"/>
+    </inputData>
+</definitions>"#;
+
+/// Compares the models and formats the changes.
+fn format(old: &str, new: &str, color_mode: ColorMode) -> String {
+  let changes = dmntk_model::diff(&dmntk_model::parse(old).unwrap(), &dmntk_model::parse(new).unwrap(), false);
+  format_changes(&changes, color_mode)
+}
+
+#[test]
+fn _0001() {
+  assert_eq!("found 0 change(s)\n", format(OLD_MODEL, OLD_MODEL, ColorMode::Off));
+}
+
+#[test]
+fn _0002() {
+  assert_eq!(
+    r#"added input data 'Currency'
+changed decision 'Fee'
+  changed rule 2: [>= 100 -> [100..1000]] | 10
+  added rule 3: > 1000 | 0
+found 2 change(s)
+"#,
+    format(OLD_MODEL, NEW_MODEL, ColorMode::Off)
+  );
+}
+
+#[test]
+fn _0003() {
+  assert_eq!(
+    "\u{1b}[31mremoved input data 'Currency'\u{1b}[0m\n\
+     \u{1b}[33mchanged decision 'Fee'\u{1b}[0m\n  \
+     \u{1b}[33mchanged rule 2\u{1b}[0m: [\u{1b}[31m[100..1000]\u{1b}[0m -> \u{1b}[32m>= 100\u{1b}[0m] | 10\n  \
+     \u{1b}[31mremoved rule 3\u{1b}[0m: > 1000 | 0\n\
+     found 2 change(s)\n",
+    format(NEW_MODEL, OLD_MODEL, ColorMode::On)
+  );
+}
@@ -0,0 +1,130 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::line_editor::{key_from_event, Key, LineBuffer};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Converts key codes pressed with specified modifiers into keys recognized by the line editor.
+fn keys(modifiers: KeyModifiers, codes: &[KeyCode]) -> Vec<Key> {
+  codes.iter().map(|code| key_from_event(KeyEvent::new(*code, modifiers))).collect()
+}
+
+#[test]
+fn _0001() {
+  let mut buffer = LineBuffer::default();
+  for ch in "1 + 3".chars() {
+    buffer.insert(ch);
+  }
+  buffer.backspace();
+  buffer.insert('2');
+  assert_eq!("1 + 2", buffer.text());
+  buffer.home();
+  buffer.delete();
+  buffer.insert('4');
+  assert_eq!("4 + 2", buffer.text());
+  assert_eq!(1, buffer.cursor());
+  buffer.left();
+  buffer.left();
+  assert_eq!(0, buffer.cursor());
+  buffer.end();
+  buffer.right();
+  assert_eq!(5, buffer.cursor());
+  buffer.set("ab");
+  assert_eq!(2, buffer.cursor());
+  assert!(!buffer.is_empty());
+}
+
+#[test]
+fn _0002() {
+  assert_eq!(
+    vec![Key::Char('a'), Key::Char('ż'), Key::Backspace, Key::Enter],
+    keys(KeyModifiers::NONE, &[KeyCode::Char('a'), KeyCode::Char('ż'), KeyCode::Backspace, KeyCode::Enter])
+  );
+  assert_eq!(vec![Key::Char('A')], keys(KeyModifiers::SHIFT, &[KeyCode::Char('A')]));
+  assert_eq!(
+    vec![
+      Key::Interrupt,
+      Key::EndOfFile,
+      Key::Home,
+      Key::End,
+      Key::Left,
+      Key::Right,
+      Key::Up,
+      Key::Down,
+      Key::Backspace,
+      Key::Ignored
+    ],
+    keys(
+      KeyModifiers::CONTROL,
+      &[
+        KeyCode::Char('c'),
+        KeyCode::Char('d'),
+        KeyCode::Char('a'),
+        KeyCode::Char('e'),
+        KeyCode::Char('b'),
+        KeyCode::Char('f'),
+        KeyCode::Char('p'),
+        KeyCode::Char('n'),
+        KeyCode::Char('h'),
+        KeyCode::Char('x')
+      ]
+    )
+  );
+}
+
+#[test]
+fn _0003() {
+  assert_eq!(
+    vec![Key::Up, Key::Down, Key::Right, Key::Left, Key::Home, Key::End, Key::Delete, Key::Ignored],
+    keys(
+      KeyModifiers::NONE,
+      &[
+        KeyCode::Up,
+        KeyCode::Down,
+        KeyCode::Right,
+        KeyCode::Left,
+        KeyCode::Home,
+        KeyCode::End,
+        KeyCode::Delete,
+        KeyCode::PageUp
+      ]
+    )
+  );
+}
+
+#[test]
+fn _0004() {
+  // keys pressed with modifiers and the lone escape are ignored
+  assert_eq!(vec![Key::Ignored, Key::Ignored], keys(KeyModifiers::CONTROL, &[KeyCode::Right, KeyCode::Left]));
+  assert_eq!(vec![Key::Ignored, Key::Ignored], keys(KeyModifiers::ALT, &[KeyCode::Char('b'), KeyCode::Right]));
+  assert_eq!(vec![Key::Ignored], keys(KeyModifiers::NONE, &[KeyCode::Esc]));
+}
@@ -0,0 +1,143 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::examples::E2_DMN;
+use crate::lint::{format_human, format_sarif, LintReport};
+
+/// Model with unused input data and decision variable named differently than the decision.
+const MODEL: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<definitions namespace="https://dmntk.io/lint" name="lint" id="_lint" xmlns="https://www.omg.org/spec/DMN/20191111/MODEL/">
+    <decision name="Greeting" id="_greeting">
+        <variable typeRef="string" name="Message"/>
+        <literalExpression>
+            <text>"Hello"</text>
+        </literalExpression>
+    </decision>
+    <inputData name="Full Name" id="_full_name">
+        <variable typeRef="string" name="Full Name"/>
+    </inputData>
+</definitions>"#;
+
+/// Lints the model and returns the report for specified file name.
+fn report(file_name: &str, xml: &str) -> LintReport {
+  LintReport::new(file_name, xml)
+}
+
+#[test]
+fn _0001() {
+  assert_eq!("found 0 error(s) and 0 warning(s)\n", format_human(&[report("e2.dmn", E2_DMN)]));
+}
+
+#[test]
+fn _0002() {
+  assert_eq!(
+    concat!(
+      "lint.dmn: warning[unreferenced-input-data]: input data 'Full Name' is not required by any decision, decision service or knowledge source\n",
+      "lint.dmn: error[variable-name-mismatch]: variable name 'Message' differs from the name of decision 'Greeting'\n",
+      "found 1 error(s) and 1 warning(s)\n"
+    ),
+    format_human(&[report("e2.dmn", E2_DMN), report("lint.dmn", MODEL)])
+  );
+}
+
+#[test]
+fn _0003() {
+  let sarif: serde_json::Value = serde_json::from_str(&format_sarif(&[report("models/lint.dmn", MODEL)])).unwrap();
+  assert_eq!("2.1.0", sarif["version"]);
+  let run = &sarif["runs"][0];
+  assert_eq!("dmntk", run["tool"]["driver"]["name"]);
+  assert_eq!(8, run["tool"]["driver"]["rules"].as_array().unwrap().len());
+  assert_eq!("unreferenced-input-data", run["tool"]["driver"]["rules"][0]["id"]);
+  assert_eq!("warning", run["tool"]["driver"]["rules"][0]["defaultConfiguration"]["level"]);
+  let results = run["results"].as_array().unwrap();
+  assert_eq!(2, results.len());
+  assert_eq!("unreferenced-input-data", results[0]["ruleId"]);
+  assert_eq!(0, results[0]["ruleIndex"]);
+  assert_eq!("warning", results[0]["level"]);
+  assert_eq!(
+    "input data 'Full Name' is not required by any decision, decision service or knowledge source",
+    results[0]["message"]["text"]
+  );
+  assert_eq!("models/lint.dmn", results[0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"]);
+  assert_eq!(9, results[0]["locations"][0]["physicalLocation"]["region"]["startLine"]);
+  assert_eq!(5, results[0]["locations"][0]["physicalLocation"]["region"]["startColumn"]);
+  assert_eq!("Full Name", results[0]["locations"][0]["logicalLocations"][0]["name"]);
+  assert_eq!("_full_name", results[0]["locations"][0]["logicalLocations"][0]["fullyQualifiedName"]);
+  assert_eq!("variable-name-mismatch", results[1]["ruleId"]);
+  assert_eq!(4, results[1]["ruleIndex"]);
+  assert_eq!("error", results[1]["level"]);
+  assert_eq!(3, results[1]["locations"][0]["physicalLocation"]["region"]["startLine"]);
+}
+
+#[test]
+fn _0004() {
+  let sarif: serde_json::Value = serde_json::from_str(&format_sarif(&[])).unwrap();
+  assert_eq!(0, sarif["runs"][0]["results"].as_array().unwrap().len());
+}
+
+#[test]
+fn _0005() {
+  // model that can not be parsed is reported as an error located at the position given by the parser
+  let xml = MODEL.replace("definitions", "definition");
+  let sarif: serde_json::Value = serde_json::from_str(&format_sarif(&[report("invalid.dmn", &xml)])).unwrap();
+  let run = &sarif["runs"][0];
+  assert_eq!("invalid-model", run["tool"]["driver"]["rules"][7]["id"]);
+  let results = run["results"].as_array().unwrap();
+  assert_eq!(1, results.len());
+  assert_eq!("invalid-model", results[0]["ruleId"]);
+  assert_eq!(7, results[0]["ruleIndex"]);
+  assert_eq!("error", results[0]["level"]);
+  assert_eq!("invalid.dmn", results[0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"]);
+  assert_eq!(2, results[0]["locations"][0]["physicalLocation"]["region"]["startLine"]);
+  assert_eq!(1, results[0]["locations"][0]["physicalLocation"]["region"]["startColumn"]);
+}
+
+#[test]
+fn _0006() {
+  // malformed XML is reported at the position given by the XML parser
+  let sarif: serde_json::Value = serde_json::from_str(&format_sarif(&[report("malformed.dmn", "<definitions>\n  <decision>\n</definitions>")])).unwrap();
+  let results = sarif["runs"][0]["results"].as_array().unwrap();
+  assert_eq!(1, results.len());
+  assert_eq!(3, results[0]["locations"][0]["physicalLocation"]["region"]["startLine"]);
+}
+
+#[test]
+fn _0007() {
+  // failures to parse models and errors are counted, warnings are not
+  assert_eq!(0, report("e2.dmn", E2_DMN).error_count());
+  assert_eq!(1, report("lint.dmn", MODEL).error_count());
+  assert_eq!(1, LintReport::failed("missing.dmn", "loading model failed").error_count());
+  assert_eq!(
+    "missing.dmn: error[invalid-model]: loading model failed\nfound 1 error(s) and 0 warning(s)\n",
+    format_human(&[LintReport::failed("missing.dmn", "loading model failed")])
+  );
+}
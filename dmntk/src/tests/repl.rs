@@ -0,0 +1,122 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::examples::{E2_CTX, E2_DMN};
+use crate::repl::Repl;
+use dmntk_common::ColorMode;
+use std::fs;
+use std::path::PathBuf;
+
+/// Writes the content to a file in temporary directory and returns the file name.
+fn temp_file(name: &str, content: &str) -> String {
+  let file_name: PathBuf = std::env::temp_dir().join(format!("dmntk-repl-{}-{name}", std::process::id()));
+  fs::write(&file_name, content).unwrap();
+  file_name.to_string_lossy().to_string()
+}
+
+fn eq(repl: &mut Repl, line: &str, expected: &str) {
+  assert_eq!(expected, repl.execute(line).unwrap());
+}
+
+#[test]
+fn _0001() {
+  let mut repl = Repl::new(ColorMode::Off);
+  eq(&mut repl, "1 + 2", "3 : number");
+  eq(&mut repl, "\"a\" + \"b\"", r#""ab" : string"#);
+  eq(&mut repl, "", "");
+}
+
+#[test]
+fn _0002() {
+  let mut repl = Repl::new(ColorMode::Off);
+  eq(&mut repl, "x := 10", "x = 10 : number");
+  eq(&mut repl, "y := x * 2", "y = 20 : number");
+  eq(&mut repl, "[x, y]", "[10, 20] : list<number>");
+  eq(&mut repl, ":scope", "x = 10 : number\ny = 20 : number");
+  eq(&mut repl, ":clear", "");
+  eq(&mut repl, ":scope", "");
+}
+
+#[test]
+fn _0003() {
+  let mut repl = Repl::new(ColorMode::Off);
+  let file_name = temp_file("0003.ctx", "{a: 1, b: a + 1}");
+  eq(&mut repl, &format!(":load {file_name}"), &format!("loaded 2 entries from `{file_name}`"));
+  eq(&mut repl, "a + b", "3 : number");
+  let _ = fs::remove_file(file_name);
+}
+
+#[test]
+fn _0004() {
+  let mut repl = Repl::new(ColorMode::Off);
+  eq(&mut repl, ":invocables", "error: no model loaded, use :model <FILE> to load a model");
+  let dmn_file_name = temp_file("0004.dmn", E2_DMN);
+  let ctx_file_name = temp_file("0004.ctx", E2_CTX);
+  eq(&mut repl, &format!(":model {dmn_file_name}"), "loaded model `https://dmntk.io/2_0001` with 1 invocables");
+  eq(&mut repl, ":invocables", "Greeting Message (https://dmntk.io/2_0001)");
+  eq(&mut repl, &format!(":load {ctx_file_name}"), &format!("loaded 1 entries from `{ctx_file_name}`"));
+  eq(&mut repl, ":invoke Greeting Message", r#""Hello John Doe" : string"#);
+  eq(&mut repl, ":invoke Unknown", "error: invocable `Unknown` not found");
+  let _ = fs::remove_file(dmn_file_name);
+  let _ = fs::remove_file(ctx_file_name);
+}
+
+#[test]
+fn _0005() {
+  let mut repl = Repl::new(ColorMode::Off);
+  eq(&mut repl, ":unknown", "error: unknown command `:unknown`, type :help for available commands");
+  assert!(repl.execute(":help").unwrap().contains(":invoke <NAME>"));
+  assert!(repl.execute(":quit").is_none());
+  assert!(repl.execute(":q").is_none());
+}
+
+#[test]
+fn _0006() {
+  let mut repl = Repl::new(ColorMode::On);
+  eq(&mut repl, "1", "1 \u{1b}[34m: number\u{1b}[0m");
+}
+
+#[test]
+fn _0007() {
+  let mut repl = Repl::new(ColorMode::Off);
+  eq(&mut repl, r#"x := ":=""#, r#"x = ":=" : string"#);
+  eq(&mut repl, r#"":=" + x"#, r#"":=:=" : string"#);
+  eq(&mut repl, r#"y := "a := b""#, r#"y = "a := b" : string"#);
+}
+
+#[test]
+fn _0008() {
+  let mut repl = Repl::new(ColorMode::Off);
+  eq(&mut repl, r#"x := "a""#, r#"x = "a" : string"#);
+  eq(&mut repl, r#"y := x + ":=" + x"#, r#"y = "a:=a" : string"#);
+  eq(&mut repl, r#"x + ":=" + y"#, r#""a:=a:=a" : string"#);
+}
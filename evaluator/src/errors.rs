@@ -0,0 +1,84 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! # Error definitions
+
+use dmntk_common::{DmntkError, ToErrorMessage};
+
+/// Errors reported while evaluating streamed records.
+#[derive(ToErrorMessage)]
+struct RecordsError(String);
+
+/// Creates an error indicating unknown format of records.
+pub fn err_unknown_record_format(format: &str) -> DmntkError {
+  RecordsError(format!("unknown record format '{format}', expected 'csv' or 'ndjson'")).into()
+}
+
+/// Creates an error indicating that reading records failed.
+pub fn err_reading_records(reason: &str) -> DmntkError {
+  RecordsError(format!("reading records failed with reason: {reason}")).into()
+}
+
+/// Creates an error indicating that writing results failed.
+pub fn err_writing_results(reason: &str) -> DmntkError {
+  RecordsError(format!("writing results failed with reason: {reason}")).into()
+}
+
+/// Creates an error indicating that the CSV input has no header line.
+pub fn err_missing_csv_header() -> DmntkError {
+  RecordsError("missing header line in CSV input".to_string()).into()
+}
+
+/// Creates an error indicating that CSV record has invalid number of fields.
+pub fn err_invalid_csv_field_count(expected: usize, actual: usize) -> DmntkError {
+  RecordsError(format!("expected {expected} fields, found {actual}")).into()
+}
+
+/// Creates an error indicating that CSV record contains unterminated quoted field.
+pub fn err_unterminated_csv_field() -> DmntkError {
+  RecordsError("unterminated quoted field".to_string()).into()
+}
+
+/// Creates an error indicating that NDJSON record is not valid.
+pub fn err_invalid_json_record(reason: &str) -> DmntkError {
+  RecordsError(format!("invalid JSON record: {reason}")).into()
+}
+
+/// Creates an error indicating that NDJSON record contains an entry not present in the first record.
+pub fn err_unexpected_json_entry(name: &str) -> DmntkError {
+  RecordsError(format!("unexpected entry '{name}', entries of records are defined by the first record")).into()
+}
+
+/// Creates an error indicating that JSON number is not a valid `FEEL` number.
+pub fn err_invalid_json_number(number: &str) -> DmntkError {
+  RecordsError(format!("'{number}' is not a valid number")).into()
+}
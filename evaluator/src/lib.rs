@@ -30,8 +30,17 @@
  * limitations under the License.
  */
 
+#[macro_use]
+extern crate dmntk_macros;
+
+mod errors;
+mod records;
 mod test_files;
 
+#[cfg(test)]
+mod tests;
+
 pub use dmntk_feel_evaluator::{evaluate, evaluate_context, evaluate_equals, evaluate_max, evaluate_min, evaluate_sum};
-pub use dmntk_model_evaluator::{build_decision_table_evaluator, ModelEvaluator};
+pub use dmntk_model_evaluator::{build_decision_table_evaluator, BundleEvaluator, ModelEvaluator};
+pub use records::{evaluate_expression_records, evaluate_invocable_records, RecordFormat, RecordReader, RecordSummary, RecordWriter};
 pub use test_files::evaluate_test_cases;
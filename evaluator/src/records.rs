@@ -0,0 +1,470 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Implementation of the evaluator for streamed records.
+//!
+//! A single `FEEL` expression or DMN invocable is evaluated for every record read
+//! from the input, records are processed one by one, so the memory usage does not
+//! depend on the number of records. The expression is parsed and prepared only once,
+//! names available during parsing are taken from the CSV header or from the first
+//! NDJSON record.
+//!
+//! Supported record formats:
+//! - **CSV** - the first line contains column names, every following line is a record;
+//!   unquoted values are converted into `null` (empty value), booleans (`true`, `false`)
+//!   and numbers when possible, all other values and all quoted values are strings,
+//! - **NDJSON** - every line contains a JSON object, objects are converted into contexts,
+//!   arrays into lists; like the CSV header, the first valid record determines the entries
+//!   of all records, entries missing in following records are `null`, and records with
+//!   entries not present in the first record are reported as invalid.
+//!
+//! Every evaluated record produces a single result record containing the record number,
+//! the result of the evaluation and the error message, when the record could not be read
+//! or the evaluation resulted in `null` with a reason:
+//! ```text
+//! record,result,error
+//! 1,0.15,
+//! 2,,"<RecordsError> expected 2 fields, found 3"
+//! ```
+//! ```text
+//! {"record":1,"result":0.15,"error":null}
+//! {"record":2,"result":null,"error":"<RecordsError> expected 2 fields, found 3"}
+//! ```
+
+use crate::errors::*;
+use dmntk_common::{DmntkError, Result};
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::values::Value;
+use dmntk_feel::{value_null, FeelNumber, FeelScope, Name};
+use dmntk_model_evaluator::ModelEvaluator;
+use std::io::{BufRead, Write};
+use std::str::FromStr;
+
+/// Format of streamed records.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RecordFormat {
+  /// Comma separated values with header line.
+  Csv,
+  /// Newline delimited JSON objects.
+  Ndjson,
+}
+
+impl FromStr for RecordFormat {
+  type Err = DmntkError;
+  /// Converts the name of the format into [RecordFormat].
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s.to_lowercase().as_str() {
+      "csv" => Ok(Self::Csv),
+      "ndjson" | "jsonl" => Ok(Self::Ndjson),
+      other => Err(err_unknown_record_format(other)),
+    }
+  }
+}
+
+/// Summary of evaluated records.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct RecordSummary {
+  /// Number of processed records.
+  pub records: usize,
+  /// Number of records reported with an error.
+  pub failures: usize,
+}
+
+/// Reader of records in specified format.
+pub struct RecordReader<R: BufRead> {
+  /// Source of records.
+  reader: R,
+  /// Format of records.
+  format: RecordFormat,
+  /// Names of columns read from CSV header or from the first valid NDJSON record.
+  header: Vec<Name>,
+  /// The first NDJSON record, read in advance to collect names.
+  peeked: Option<Result<FeelContext>>,
+}
+
+impl<R: BufRead> RecordReader<R> {
+  /// Creates a new reader, reads the CSV header or the first NDJSON record.
+  pub fn new(reader: R, format: RecordFormat) -> Result<Self> {
+    let mut record_reader = Self {
+      reader,
+      format,
+      header: vec![],
+      peeked: None,
+    };
+    match format {
+      RecordFormat::Csv => {
+        let line = record_reader.read_csv_line()?.ok_or_else(err_missing_csv_header)?;
+        record_reader.header = parse_csv_line(&line)?.into_iter().map(|(field, _)| Name::from(field)).collect();
+      }
+      RecordFormat::Ndjson => {
+        record_reader.peeked = record_reader.read_ndjson_record()?;
+      }
+    }
+    Ok(record_reader)
+  }
+
+  /// Returns the context containing all names available in records,
+  /// used while parsing the evaluated expression.
+  pub fn parsing_context(&self) -> FeelContext {
+    if let Some(Ok(ctx)) = &self.peeked {
+      return ctx.clone();
+    }
+    let mut ctx = FeelContext::default();
+    for name in &self.header {
+      ctx.set_null(name.clone());
+    }
+    ctx
+  }
+
+  /// Reads the next record.
+  ///
+  /// Returns an error when reading the input failed, `None` when there are no more records,
+  /// otherwise the record converted into context or the reason why the record is invalid.
+  pub fn next_record(&mut self) -> Result<Option<Result<FeelContext>>> {
+    if let Some(record) = self.peeked.take() {
+      return Ok(Some(record));
+    }
+    match self.format {
+      RecordFormat::Csv => self.read_csv_record(),
+      RecordFormat::Ndjson => self.read_ndjson_record(),
+    }
+  }
+
+  /// Reads a single line without line terminator.
+  fn read_line(&mut self) -> Result<Option<String>> {
+    let mut line = String::new();
+    match self.reader.read_line(&mut line) {
+      Ok(0) => Ok(None),
+      Ok(_) => {
+        let length = line.trim_end_matches(['\n', '\r']).len();
+        line.truncate(length);
+        Ok(Some(line))
+      }
+      Err(reason) => Err(err_reading_records(&reason.to_string())),
+    }
+  }
+
+  /// Reads a non-empty CSV line, quoted fields may span multiple lines.
+  fn read_csv_line(&mut self) -> Result<Option<String>> {
+    loop {
+      let Some(mut line) = self.read_line()? else {
+        return Ok(None);
+      };
+      if line.trim().is_empty() {
+        continue;
+      }
+      while line.matches('"').count() % 2 == 1 {
+        let Some(next_line) = self.read_line()? else {
+          break;
+        };
+        line.push('\n');
+        line.push_str(&next_line);
+      }
+      return Ok(Some(line));
+    }
+  }
+
+  /// Reads the next CSV record.
+  fn read_csv_record(&mut self) -> Result<Option<Result<FeelContext>>> {
+    let Some(line) = self.read_csv_line()? else {
+      return Ok(None);
+    };
+    Ok(Some(parse_csv_line(&line).and_then(|fields| {
+      if fields.len() != self.header.len() {
+        return Err(err_invalid_csv_field_count(self.header.len(), fields.len()));
+      }
+      let mut ctx = FeelContext::default();
+      for (name, (field, quoted)) in self.header.iter().zip(fields) {
+        ctx.set_entry(name, csv_field_value(field, quoted));
+      }
+      Ok(ctx)
+    })))
+  }
+
+  /// Reads the next NDJSON record.
+  fn read_ndjson_record(&mut self) -> Result<Option<Result<FeelContext>>> {
+    loop {
+      let Some(line) = self.read_line()? else {
+        return Ok(None);
+      };
+      if !line.trim().is_empty() {
+        return Ok(Some(parse_json_record(&line).and_then(|ctx| self.conform_ndjson_record(ctx))));
+      }
+    }
+  }
+
+  /// Returns NDJSON record with the same entries as the first valid record,
+  /// missing entries are set to `null`, entries not present in the first record are reported as an error.
+  fn conform_ndjson_record(&mut self, mut ctx: FeelContext) -> Result<FeelContext> {
+    if self.header.is_empty() {
+      self.header = ctx.iter().map(|(name, _)| name.clone()).collect();
+      return Ok(ctx);
+    }
+    if let Some((name, _)) = ctx.iter().find(|(name, _)| !self.header.contains(name)) {
+      return Err(err_unexpected_json_entry(&name.to_string()));
+    }
+    for name in &self.header {
+      if !ctx.contains_entry(name) {
+        ctx.set_null(name.clone());
+      }
+    }
+    Ok(ctx)
+  }
+}
+
+/// Writer of evaluation results in specified format.
+pub struct RecordWriter<W: Write> {
+  /// Destination of results.
+  writer: W,
+  /// Format of results.
+  format: RecordFormat,
+  /// Number of the last written record.
+  record_no: usize,
+}
+
+impl<W: Write> RecordWriter<W> {
+  /// Creates a new writer, writes the CSV header when needed.
+  pub fn new(mut writer: W, format: RecordFormat) -> Result<Self> {
+    if format == RecordFormat::Csv {
+      writeln!(writer, "record,result,error").map_err(|reason| err_writing_results(&reason.to_string()))?;
+    }
+    Ok(Self { writer, format, record_no: 0 })
+  }
+
+  /// Writes the result of evaluating the next record, with optional error message.
+  pub fn write(&mut self, result: &Value, error: Option<&str>) -> Result<()> {
+    self.record_no += 1;
+    let written = match self.format {
+      RecordFormat::Csv => writeln!(
+        self.writer,
+        "{},{},{}",
+        self.record_no,
+        csv_escape(&csv_cell(result)),
+        error.map(csv_escape).unwrap_or_default()
+      ),
+      RecordFormat::Ndjson => writeln!(
+        self.writer,
+        r#"{{"record":{},"result":{},"error":{}}}"#,
+        self.record_no,
+        json_text(result),
+        error.map(json_string).unwrap_or_else(|| "null".to_string())
+      ),
+    };
+    written.map_err(|reason| err_writing_results(&reason.to_string()))
+  }
+
+  /// Flushes all written results.
+  pub fn flush(&mut self) -> Result<()> {
+    self.writer.flush().map_err(|reason| err_writing_results(&reason.to_string()))
+  }
+}
+
+/// Evaluates `FEEL` expression for every record read from `reader`,
+/// results are written to `writer`.
+pub fn evaluate_expression_records<R: BufRead, W: Write>(expression: &str, reader: R, input_format: RecordFormat, writer: W, output_format: RecordFormat) -> Result<RecordSummary> {
+  let mut records = RecordReader::new(reader, input_format)?;
+  let scope = records.parsing_context().into();
+  let node = dmntk_feel_parser::parse_expression(&scope, expression, false)?;
+  let evaluator = dmntk_feel_evaluator::prepare(&node)?;
+  let mut results = RecordWriter::new(writer, output_format)?;
+  process_records(&mut records, &mut results, |ctx| evaluator(&FeelScope::from(ctx)))
+}
+
+/// Evaluates invocable (decision, business knowledge model or decision service)
+/// for every record read from `reader`, results are written to `writer`.
+pub fn evaluate_invocable_records<R: BufRead, W: Write>(
+  model_evaluator: &ModelEvaluator,
+  namespace: &str,
+  invocable_name: &str,
+  reader: R,
+  input_format: RecordFormat,
+  writer: W,
+  output_format: RecordFormat,
+) -> Result<RecordSummary> {
+  let mut records = RecordReader::new(reader, input_format)?;
+  let mut results = RecordWriter::new(writer, output_format)?;
+  process_records(&mut records, &mut results, |ctx| {
+    model_evaluator.evaluate_invocable_by_name(namespace, invocable_name, &ctx)
+  })
+}
+
+/// Evaluates all records and writes the results.
+fn process_records<R: BufRead, W: Write>(records: &mut RecordReader<R>, results: &mut RecordWriter<W>, evaluate: impl Fn(FeelContext) -> Value) -> Result<RecordSummary> {
+  let mut summary = RecordSummary::default();
+  while let Some(record) = records.next_record()? {
+    summary.records += 1;
+    let (result, error) = match record {
+      Ok(ctx) => {
+        let result = evaluate(ctx);
+        let error = if let Value::Null(Some(reason)) = &result { Some(reason.clone()) } else { None };
+        (result, error)
+      }
+      Err(reason) => (value_null!(), Some(reason.to_string())),
+    };
+    if error.is_some() {
+      summary.failures += 1;
+    }
+    results.write(&result, error.as_deref())?;
+  }
+  results.flush()?;
+  Ok(summary)
+}
+
+/// Splits CSV line into fields, every field is accompanied with a flag indicating if it was quoted.
+fn parse_csv_line(line: &str) -> Result<Vec<(String, bool)>> {
+  let mut fields = vec![];
+  let mut field = String::new();
+  let mut quoted = false;
+  let mut in_quotes = false;
+  let mut chars = line.chars().peekable();
+  while let Some(ch) = chars.next() {
+    if in_quotes {
+      if ch == '"' {
+        if chars.peek() == Some(&'"') {
+          chars.next();
+          field.push('"');
+        } else {
+          in_quotes = false;
+        }
+      } else {
+        field.push(ch);
+      }
+    } else {
+      match ch {
+        '"' => {
+          in_quotes = true;
+          quoted = true;
+        }
+        ',' => {
+          fields.push((std::mem::take(&mut field), quoted));
+          quoted = false;
+        }
+        other => field.push(other),
+      }
+    }
+  }
+  if in_quotes {
+    return Err(err_unterminated_csv_field());
+  }
+  fields.push((field, quoted));
+  Ok(fields)
+}
+
+/// Converts the text of CSV field into value.
+fn csv_field_value(field: String, quoted: bool) -> Value {
+  if quoted {
+    return Value::String(field);
+  }
+  match field.trim() {
+    "" => value_null!(),
+    "true" => Value::Boolean(true),
+    "false" => Value::Boolean(false),
+    text => FeelNumber::from_str(text).map(Value::Number).unwrap_or_else(|_| Value::String(text.to_string())),
+  }
+}
+
+/// Parses NDJSON line into context.
+fn parse_json_record(line: &str) -> Result<FeelContext> {
+  match serde_json::from_str::<serde_json::Value>(line) {
+    Ok(serde_json::Value::Object(object)) => json_object_to_context(object),
+    Ok(_) => Err(err_invalid_json_record("expected JSON object")),
+    Err(reason) => Err(err_invalid_json_record(&reason.to_string())),
+  }
+}
+
+/// Converts JSON object into context.
+fn json_object_to_context(object: serde_json::Map<String, serde_json::Value>) -> Result<FeelContext> {
+  let mut ctx = FeelContext::default();
+  for (key, value) in object {
+    ctx.set_entry(&Name::from(key), json_to_value(value)?);
+  }
+  Ok(ctx)
+}
+
+/// Converts JSON value into `FEEL` value.
+fn json_to_value(json: serde_json::Value) -> Result<Value> {
+  Ok(match json {
+    serde_json::Value::Null => value_null!(),
+    serde_json::Value::Bool(value) => Value::Boolean(value),
+    serde_json::Value::Number(number) => {
+      let text = number.to_string();
+      Value::Number(FeelNumber::from_str(&text).map_err(|_| err_invalid_json_number(&text))?)
+    }
+    serde_json::Value::String(value) => Value::String(value),
+    serde_json::Value::Array(items) => Value::List(items.into_iter().map(json_to_value).collect::<Result<Vec<Value>>>()?),
+    serde_json::Value::Object(object) => Value::Context(json_object_to_context(object)?),
+  })
+}
+
+/// Converts the value into the text of CSV field.
+fn csv_cell(value: &Value) -> String {
+  match value {
+    Value::Null(_) => "".to_string(),
+    Value::String(s) => s.clone(),
+    Value::List(_) | Value::Context(_) => json_text(value),
+    other => other.to_string(),
+  }
+}
+
+/// Quotes CSV field when needed.
+fn csv_escape(text: &str) -> String {
+  if text.contains([',', '"', '\n', '\r']) {
+    format!(r#""{}""#, text.replace('"', r#""""#))
+  } else {
+    text.to_string()
+  }
+}
+
+/// Converts the value into JSON text.
+fn json_text(value: &Value) -> String {
+  match value {
+    Value::Null(_) => "null".to_string(),
+    Value::Boolean(value) => value.to_string(),
+    Value::Number(value) => value.to_string(),
+    Value::String(value) => json_string(value),
+    Value::List(items) => format!("[{}]", items.iter().map(json_text).collect::<Vec<String>>().join(",")),
+    Value::Context(ctx) => format!(
+      "{{{}}}",
+      ctx
+        .iter()
+        .map(|(name, value)| format!("{}:{}", json_string(&name.to_string()), json_text(value)))
+        .collect::<Vec<String>>()
+        .join(",")
+    ),
+    other => json_string(&other.to_string()),
+  }
+}
+
+/// Converts text into JSON string.
+fn json_string(text: &str) -> String {
+  serde_json::Value::String(text.to_string()).to_string()
+}
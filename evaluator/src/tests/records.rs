@@ -0,0 +1,149 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{evaluate_expression_records, RecordFormat, RecordReader, RecordSummary};
+use dmntk_feel::context::FeelContext;
+
+/// Evaluates the expression for every input record and returns the output and the summary.
+fn evaluate(expression: &str, input: &str, input_format: RecordFormat, output_format: RecordFormat) -> (String, RecordSummary) {
+  let mut output = vec![];
+  let summary = evaluate_expression_records(expression, input.as_bytes(), input_format, &mut output, output_format).unwrap();
+  (String::from_utf8(output).unwrap(), summary)
+}
+
+/// Reads all records from the input, every record is converted into text.
+fn records(input: &str, format: RecordFormat) -> Vec<String> {
+  let mut reader = RecordReader::new(input.as_bytes(), format).unwrap();
+  let mut records = vec![];
+  while let Some(record) = reader.next_record().unwrap() {
+    records.push(record.map(|ctx: FeelContext| ctx.to_string()).unwrap_or_else(|reason| reason.to_string()));
+  }
+  records
+}
+
+#[test]
+fn _0001() {
+  assert_eq!(RecordFormat::Csv, "CSV".parse::<RecordFormat>().unwrap());
+  assert_eq!(RecordFormat::Ndjson, "ndjson".parse::<RecordFormat>().unwrap());
+  assert_eq!(RecordFormat::Ndjson, "jsonl".parse::<RecordFormat>().unwrap());
+  assert_eq!(
+    "<RecordsError> unknown record format 'xml', expected 'csv' or 'ndjson'",
+    "xml".parse::<RecordFormat>().unwrap_err().to_string()
+  );
+}
+
+#[test]
+fn _0002() {
+  let input = "Monthly Income,Repayments\n6000,2000\n\n5000,\n";
+  let (output, summary) = evaluate("Monthly Income - Repayments", input, RecordFormat::Csv, RecordFormat::Csv);
+  assert_eq!("record,result,error\n1,4000,\n2,,[subtraction] incompatible types: 5000 - null\n", output);
+  assert_eq!(RecordSummary { records: 2, failures: 1 }, summary);
+}
+
+#[test]
+fn _0003() {
+  let input = "name,age\n\"Smith, John\",49\n\"Kowalski\",17,x\n";
+  let (output, summary) = evaluate("name + \" is \" + string(age)", input, RecordFormat::Csv, RecordFormat::Ndjson);
+  assert_eq!(
+    "{\"record\":1,\"result\":\"Smith, John is 49\",\"error\":null}\n{\"record\":2,\"result\":null,\"error\":\"<RecordsError> expected 2 fields, found 3\"}\n",
+    output
+  );
+  assert_eq!(RecordSummary { records: 2, failures: 1 }, summary);
+}
+
+#[test]
+fn _0004() {
+  let input = "{\"applicant\":{\"monthly income\":6000},\"items\":[1,2.5]}\n\n{\"applicant\":{\"monthly income\":100},\"items\":[]}\n[1]\n";
+  let (output, summary) = evaluate("{income: applicant.monthly income, total: count(items)}", input, RecordFormat::Ndjson, RecordFormat::Ndjson);
+  assert_eq!(
+    "{\"record\":1,\"result\":{\"income\":6000,\"total\":2},\"error\":null}\n{\"record\":2,\"result\":{\"income\":100,\"total\":0},\"error\":null}\n{\"record\":3,\"result\":null,\"error\":\"<RecordsError> invalid JSON record: expected JSON object\"}\n",
+    output
+  );
+  assert_eq!(RecordSummary { records: 3, failures: 1 }, summary);
+}
+
+#[test]
+fn _0005() {
+  let input = "{\"a\":\"x\\\"y\",\"b\":true}\n";
+  let (output, summary) = evaluate("[a, b]", input, RecordFormat::Ndjson, RecordFormat::Csv);
+  assert_eq!("record,result,error\n1,\"[\"\"x\\\"\"y\"\",true]\",\n", output);
+  assert_eq!(RecordSummary { records: 1, failures: 0 }, summary);
+}
+
+#[test]
+fn _0006() {
+  assert_eq!(vec![r#"{a: "a", b: "b,"c"", c: null}"#], records("a,b,c\na,\"b,\"\"c\"\"\",", RecordFormat::Csv));
+  assert_eq!(vec!["<RecordsError> unterminated quoted field"], records("a,b\na,\"b", RecordFormat::Csv));
+}
+
+#[test]
+fn _0007() {
+  let input = "text\n\"line 1\nline 2\"\n";
+  let (output, _) = evaluate("string length(text)", input, RecordFormat::Csv, RecordFormat::Csv);
+  assert_eq!("record,result,error\n1,13,\n", output);
+}
+
+#[test]
+fn _0008() {
+  assert_eq!(
+    "<RecordsError> missing header line in CSV input",
+    evaluate_expression_records("1", "".as_bytes(), RecordFormat::Csv, vec![], RecordFormat::Csv)
+      .unwrap_err()
+      .to_string()
+  );
+}
+
+#[test]
+fn _0009() {
+  // entries of all NDJSON records are defined by the first valid record
+  let input = "{\"x\":1}x\n{\"a\":1,\"b\":2}\n{\"b\":3}\n{\"a\":4,\"c\":5}\n";
+  assert_eq!(
+    vec![
+      "<RecordsError> invalid JSON record: trailing characters at line 1 column 8",
+      "{a: 1, b: 2}",
+      "{a: null, b: 3}",
+      "<RecordsError> unexpected entry 'c', entries of records are defined by the first record",
+    ],
+    records(input, RecordFormat::Ndjson)
+  );
+}
+
+#[test]
+fn _0010() {
+  let input = "{\"a\":1,\"b\":2}\n{\"b\":3}\n{\"a\":4,\"c\":5}\n";
+  let (output, summary) = evaluate("if a = null then b else a + b", input, RecordFormat::Ndjson, RecordFormat::Csv);
+  assert_eq!(
+    "record,result,error\n1,3,\n2,3,\n3,,\"<RecordsError> unexpected entry 'c', entries of records are defined by the first record\"\n",
+    output
+  );
+  assert_eq!(RecordSummary { records: 3, failures: 1 }, summary);
+}
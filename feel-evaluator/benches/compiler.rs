@@ -0,0 +1,210 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#![feature(test)]
+
+extern crate test;
+
+use dmntk_feel::values::Value;
+use dmntk_feel::{scope, value_number, Evaluator, FeelNumber, FeelScope};
+use dmntk_feel_evaluator::{prepare, prepare_uncompiled};
+use dmntk_feel_parser::AstNode;
+use dmntk_feel_temporal::FeelDate;
+use test::Bencher;
+
+/// Creates the scope with input values of the decision table.
+fn input_scope() -> FeelScope {
+  let scope = scope!();
+  scope.set_value(&"age".into(), value_number!(42));
+  scope.set_value(&"income".into(), value_number!(5_250));
+  scope.set_value(&"debt".into(), value_number!(1_200));
+  scope.set_value(&"category".into(), Value::String("C".to_string()));
+  scope
+}
+
+/// Prepares the evaluator of the input entry, the same way as decision tables do.
+fn input_entry(scope: &FeelScope, input_expression: &str, input_entry: &str, compiled: bool) -> Evaluator {
+  let input_expression = dmntk_feel_parser::parse_expression(scope, input_expression, false).unwrap();
+  let unary_tests = dmntk_feel_parser::parse_unary_tests(scope, input_entry, false).unwrap();
+  let node = AstNode::In(Box::new(input_expression), Box::new(unary_tests));
+  if compiled {
+    prepare(&node).unwrap()
+  } else {
+    prepare_uncompiled(&node).unwrap()
+  }
+}
+
+/// Prepares the evaluator of the textual expression.
+fn expression(scope: &FeelScope, input: &str, compiled: bool) -> Evaluator {
+  let node = dmntk_feel_parser::parse_expression(scope, input, false).unwrap();
+  if compiled {
+    prepare(&node).unwrap()
+  } else {
+    prepare_uncompiled(&node).unwrap()
+  }
+}
+
+#[bench]
+fn feel_evaluator_compiler_0001(b: &mut Bencher) {
+  let scope = input_scope();
+  let evaluator = input_entry(&scope, "age", "[18..65]", true);
+  assert_eq!(Value::Boolean(true), evaluator(&scope));
+  b.iter(|| evaluator(&scope));
+}
+
+#[bench]
+fn feel_evaluator_compiler_0002(b: &mut Bencher) {
+  let scope = input_scope();
+  let evaluator = input_entry(&scope, "age", "[18..65]", false);
+  assert_eq!(Value::Boolean(true), evaluator(&scope));
+  b.iter(|| evaluator(&scope));
+}
+
+#[bench]
+fn feel_evaluator_compiler_0003(b: &mut Bencher) {
+  let scope = input_scope();
+  let evaluator = input_entry(&scope, "category", r#""A","B","C""#, true);
+  assert_eq!(Value::Boolean(true), evaluator(&scope));
+  b.iter(|| evaluator(&scope));
+}
+
+#[bench]
+fn feel_evaluator_compiler_0004(b: &mut Bencher) {
+  let scope = input_scope();
+  let evaluator = input_entry(&scope, "category", r#""A","B","C""#, false);
+  assert_eq!(Value::Boolean(true), evaluator(&scope));
+  b.iter(|| evaluator(&scope));
+}
+
+#[bench]
+fn feel_evaluator_compiler_0005(b: &mut Bencher) {
+  let scope = input_scope();
+  let evaluator = input_entry(&scope, "age", "-", true);
+  assert_eq!(Value::Boolean(true), evaluator(&scope));
+  b.iter(|| evaluator(&scope));
+}
+
+#[bench]
+fn feel_evaluator_compiler_0006(b: &mut Bencher) {
+  let scope = input_scope();
+  let evaluator = input_entry(&scope, "age", "-", false);
+  assert_eq!(Value::Boolean(true), evaluator(&scope));
+  b.iter(|| evaluator(&scope));
+}
+
+#[bench]
+fn feel_evaluator_compiler_0007(b: &mut Bencher) {
+  let scope = input_scope();
+  let evaluator = input_entry(&scope, "income - debt", "> 3000", true);
+  assert_eq!(Value::Boolean(true), evaluator(&scope));
+  b.iter(|| evaluator(&scope));
+}
+
+#[bench]
+fn feel_evaluator_compiler_0008(b: &mut Bencher) {
+  let scope = input_scope();
+  let evaluator = input_entry(&scope, "income - debt", "> 3000", false);
+  assert_eq!(Value::Boolean(true), evaluator(&scope));
+  b.iter(|| evaluator(&scope));
+}
+
+#[bench]
+fn feel_evaluator_compiler_0009(b: &mut Bencher) {
+  let scope = input_scope();
+  let evaluator = expression(&scope, r#"date("2023-01-31")"#, true);
+  assert_eq!(Value::Date(FeelDate::new(2023, 1, 31)), evaluator(&scope));
+  b.iter(|| evaluator(&scope));
+}
+
+#[bench]
+fn feel_evaluator_compiler_0010(b: &mut Bencher) {
+  let scope = input_scope();
+  let evaluator = expression(&scope, r#"date("2023-01-31")"#, false);
+  assert_eq!(Value::Date(FeelDate::new(2023, 1, 31)), evaluator(&scope));
+  b.iter(|| evaluator(&scope));
+}
+
+#[bench]
+fn feel_evaluator_compiler_0011(b: &mut Bencher) {
+  let scope = input_scope();
+  let evaluator = expression(&scope, "debt / income < 0.5 and debt * 12 < income * 3", true);
+  assert_eq!(Value::Boolean(true), evaluator(&scope));
+  b.iter(|| evaluator(&scope));
+}
+
+#[bench]
+fn feel_evaluator_compiler_0012(b: &mut Bencher) {
+  let scope = input_scope();
+  let evaluator = expression(&scope, "debt / income < 0.5 and debt * 12 < income * 3", false);
+  assert_eq!(Value::Boolean(true), evaluator(&scope));
+  b.iter(|| evaluator(&scope));
+}
+
+#[bench]
+fn feel_evaluator_compiler_0013(b: &mut Bencher) {
+  let scope = input_scope();
+  let evaluator = expression(&scope, "for x in 1..100 return x * debt + income", true);
+  assert!(matches!(evaluator(&scope), Value::List(_)));
+  b.iter(|| evaluator(&scope));
+}
+
+#[bench]
+fn feel_evaluator_compiler_0014(b: &mut Bencher) {
+  let scope = input_scope();
+  let evaluator = expression(&scope, "for x in 1..100 return x * debt + income", false);
+  assert!(matches!(evaluator(&scope), Value::List(_)));
+  b.iter(|| evaluator(&scope));
+}
+
+#[bench]
+fn feel_evaluator_compiler_0015(b: &mut Bencher) {
+  let scope = input_scope();
+  let evaluator = expression(
+    &scope,
+    "{limits: [{age: 18, limit: 1000}, {age: 40, limit: 5000}], limit: limits[age <= 42].limit}.limit",
+    true,
+  );
+  assert!(matches!(evaluator(&scope), Value::List(_)));
+  b.iter(|| evaluator(&scope));
+}
+
+#[bench]
+fn feel_evaluator_compiler_0016(b: &mut Bencher) {
+  let scope = input_scope();
+  let evaluator = expression(
+    &scope,
+    "{limits: [{age: 18, limit: 1000}, {age: 40, limit: 5000}], limit: limits[age <= 42].limit}.limit",
+    false,
+  );
+  assert!(matches!(evaluator(&scope), Value::List(_)));
+  b.iter(|| evaluator(&scope));
+}
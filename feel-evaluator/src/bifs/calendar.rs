@@ -0,0 +1,481 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Business calendar extension functions.
+//!
+//! Functions implemented in this module are not defined in FEEL specification,
+//! their names are prefixed with `dmntk.`, so they are never mistaken for standard
+//! built-in functions. Business days are all days except Saturdays, Sundays
+//! and holidays defined in [HolidayCalendars] made available during evaluation using [with_calendars].
+//! Holiday calendars may be loaded from iCalendar or CSV files.
+//!
+//! This module is available only when `calendar` feature is enabled.
+
+use crate::errors::*;
+use crate::macros::invalid_argument_type;
+use dmntk_common::{DmntkError, Result};
+use dmntk_feel::values::Value;
+use dmntk_feel::{value_null, value_number, FeelNumber};
+use dmntk_feel_temporal::{Day, FeelDate, FeelDateTime, Month, Year};
+use std::cell::RefCell;
+use std::collections::{BTreeSet, HashMap};
+use std::str::FromStr;
+use std::sync::Arc;
+
+thread_local! {
+  /// Holiday calendars available to the evaluation running in the current thread.
+  static CALENDARS: RefCell<Option<Arc<HolidayCalendars>>> = const { RefCell::new(None) };
+}
+
+/// Holiday repeated every year on the same day.
+#[derive(Debug, Clone)]
+struct YearlyHoliday {
+  /// Month of the holiday.
+  month: Month,
+  /// Day of the holiday.
+  day: Day,
+  /// The first year the holiday occurs.
+  since: Year,
+  /// The last year the holiday occurs, `None` when the holiday repeats forever.
+  until: Option<Year>,
+}
+
+impl YearlyHoliday {
+  /// Returns `true` when the holiday occurs on specified date.
+  fn occurs_on(&self, date: &FeelDate) -> bool {
+    self.month == date.month() && self.day == date.day() && self.since <= date.year() && !matches!(self.until, Some(until) if date.year() > until)
+  }
+}
+
+/// Holiday calendar.
+#[derive(Debug, Clone, Default)]
+pub struct HolidayCalendar {
+  /// Holidays occurring once.
+  dates: BTreeSet<FeelDate>,
+  /// Holidays repeated every year.
+  yearly: Vec<YearlyHoliday>,
+}
+
+impl HolidayCalendar {
+  /// Loads holiday calendar from CSV file content.
+  ///
+  /// The first column of each line contains the date of a holiday in `YYYY-MM-DD` format,
+  /// other columns (like the name of the holiday) are ignored. Empty lines and lines
+  /// starting with `#` are skipped, the first line may be a header.
+  pub fn from_csv(text: &str) -> Result<Self> {
+    let mut calendar = HolidayCalendar::default();
+    let mut first = true;
+    for (index, line) in text.lines().enumerate() {
+      let line = line.trim();
+      if line.is_empty() || line.starts_with('#') {
+        continue;
+      }
+      let field = line.split([',', ';']).next().unwrap_or_default().trim().trim_matches('"');
+      match FeelDate::from_str(field) {
+        Ok(date) => calendar.add_holiday(date),
+        Err(_) if first => {}
+        Err(_) => return Err(err_invalid_calendar_line(index + 1, line)),
+      }
+      first = false;
+    }
+    Ok(calendar)
+  }
+
+  /// Loads holiday calendar from iCalendar (RFC 5545) file content.
+  ///
+  /// Every event is a holiday, lasting from the date of its start (`DTSTART`)
+  /// to the date of its end (`DTEND`, exclusive for whole day events).
+  /// Events repeated every year (`RRULE:FREQ=YEARLY`, optionally limited
+  /// with `COUNT` or `UNTIL`) are supported, other recurrence rules are reported as errors.
+  pub fn from_icalendar(text: &str) -> Result<Self> {
+    let mut calendar = HolidayCalendar::default();
+    let mut event: Option<CalendarEvent> = None;
+    for (name, value) in unfold_lines(text).iter().filter_map(|line| line.split_once(':')) {
+      let name = name.split(';').next().unwrap_or_default().trim().to_uppercase();
+      let value = value.trim();
+      if name == "BEGIN" && value.eq_ignore_ascii_case("VEVENT") {
+        event = Some(CalendarEvent::default());
+        continue;
+      }
+      if name == "END" && value.eq_ignore_ascii_case("VEVENT") {
+        if let Some(event) = event.take() {
+          event.add_to(&mut calendar)?;
+        }
+        continue;
+      }
+      let Some(event) = event.as_mut() else {
+        continue;
+      };
+      match name.as_str() {
+        "DTSTART" => event.start = Some(parse_icalendar_date(value)?),
+        "DTEND" => {
+          let end = parse_icalendar_date(value)?;
+          // the end of the event is exclusive, unless the event ends during the day
+          event.end = Some(if value.get(8..).is_some_and(|time| !time.is_empty() && !time.starts_with("T000000")) {
+            end.add_days(1).ok_or_else(|| err_invalid_calendar_date(value))?
+          } else {
+            end
+          });
+        }
+        "RRULE" => event.recurrence = Some(parse_icalendar_rule(value)?),
+        _ => {}
+      }
+    }
+    Ok(calendar)
+  }
+
+  /// Adds a holiday occurring once on specified date.
+  pub fn add_holiday(&mut self, date: FeelDate) {
+    self.dates.insert(date);
+  }
+
+  /// Returns `true` when specified date is a holiday.
+  pub fn is_holiday(&self, date: &FeelDate) -> bool {
+    self.dates.contains(date) || self.yearly.iter().any(|holiday| holiday.occurs_on(date))
+  }
+
+  /// Returns the number of holidays falling on weekdays
+  /// from the date `first` to the date `last` (both inclusive).
+  fn count_weekday_holidays(&self, first: &FeelDate, last: &FeelDate) -> u64 {
+    let mut count = self.dates.range(first..=last).filter(|date| !is_weekend(date)).count() as u64;
+    for (index, holiday) in self.yearly.iter().enumerate() {
+      let since = holiday.since.max(first.year());
+      let until = holiday.until.map_or(last.year(), |until| until.min(last.year()));
+      for year in since..=until {
+        let Some(date) = FeelDate::new_opt(year, holiday.month, holiday.day) else {
+          continue;
+        };
+        // holidays already counted as single dates or earlier yearly holidays are skipped
+        if first <= &date && &date <= last && !is_weekend(&date) && !self.dates.contains(&date) && !self.yearly[..index].iter().any(|other| other.occurs_on(&date)) {
+          count += 1;
+        }
+      }
+    }
+    count
+  }
+
+  /// Returns `true` when specified date is a business day,
+  /// i.e. neither a weekend day, nor a holiday.
+  pub fn is_business_day(&self, date: &FeelDate) -> bool {
+    !is_weekend(date) && !self.is_holiday(date)
+  }
+}
+
+/// Yearly recurrence of the event read from iCalendar file.
+#[derive(Debug, Clone, Copy)]
+enum Recurrence {
+  /// Event repeated every year forever.
+  Forever,
+  /// Event repeated every year until specified year (inclusive).
+  Until(Year),
+  /// Event repeated every year specified number of times.
+  Count(Year),
+}
+
+/// Event read from iCalendar file.
+#[derive(Default)]
+struct CalendarEvent {
+  /// Date of the event start.
+  start: Option<FeelDate>,
+  /// Date of the event end (exclusive).
+  end: Option<FeelDate>,
+  /// Yearly recurrence of the event.
+  recurrence: Option<Recurrence>,
+}
+
+impl CalendarEvent {
+  /// Adds all days of the event as holidays to specified calendar.
+  fn add_to(self, calendar: &mut HolidayCalendar) -> Result<()> {
+    let start = self.start.ok_or_else(err_missing_calendar_event_start)?;
+    let until = self.recurrence.map(|recurrence| match recurrence {
+      Recurrence::Forever => None,
+      Recurrence::Until(year) => Some(year),
+      Recurrence::Count(count) => Some(start.year() + count - 1),
+    });
+    let end = match self.end {
+      Some(end) => end,
+      None => start.add_days(1).ok_or_else(|| err_invalid_calendar_date(&start.to_string()))?,
+    };
+    let mut date = start;
+    loop {
+      match until {
+        Some(until) => calendar.yearly.push(YearlyHoliday {
+          month: date.month(),
+          day: date.day(),
+          since: date.year(),
+          until,
+        }),
+        None => calendar.add_holiday(date.clone()),
+      }
+      date = date.add_days(1).ok_or_else(|| err_invalid_calendar_date(&date.to_string()))?;
+      if date >= end {
+        break;
+      }
+    }
+    Ok(())
+  }
+}
+
+/// Holiday calendars indexed by their names.
+#[derive(Debug, Clone, Default)]
+pub struct HolidayCalendars(HashMap<String, HolidayCalendar>);
+
+impl HolidayCalendars {
+  /// Adds the holiday calendar under specified name,
+  /// replacing the calendar previously added under the same name.
+  pub fn insert(&mut self, name: &str, calendar: HolidayCalendar) {
+    self.0.insert(name.to_string(), calendar);
+  }
+
+  /// Returns the holiday calendar with specified name.
+  pub fn get(&self, name: &str) -> Option<&HolidayCalendar> {
+    self.0.get(name)
+  }
+
+  /// Returns the number of holiday calendars.
+  pub fn len(&self) -> usize {
+    self.0.len()
+  }
+
+  /// Returns `true` when there are no holiday calendars.
+  pub fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+}
+
+/// Restores calendars available before [with_calendars] was called, also when the evaluation panics.
+struct CalendarsGuard(Option<Arc<HolidayCalendars>>);
+
+impl Drop for CalendarsGuard {
+  fn drop(&mut self) {
+    let previous = self.0.take();
+    CALENDARS.with(|calendars| *calendars.borrow_mut() = previous);
+  }
+}
+
+/// Runs the function with specified holiday calendars available to business calendar functions.
+/// Calendars are available only in the current thread and only until the function returns,
+/// so evaluations using different calendars do not interfere with each other.
+pub fn with_calendars<R>(calendars: &Arc<HolidayCalendars>, function: impl FnOnce() -> R) -> R {
+  let _guard = CalendarsGuard(CALENDARS.with(|current| current.replace(Some(Arc::clone(calendars)))));
+  function()
+}
+
+/// Returns the date shifted by specified number of business days.
+/// When the calendar is `null`, only weekends are skipped.
+pub fn add_business_days(date_value: &Value, days_value: &Value, calendar_value: &Value) -> Value {
+  let Value::Number(days_number) = days_value else {
+    return invalid_argument_type!("dmntk.add business days", "number", days_value.type_of());
+  };
+  let Some(days) = integer(days_number) else {
+    return value_null!("[dmntk.add business days] expected integer number of days, actual value is {}", days_number);
+  };
+  with_calendar("dmntk.add business days", calendar_value, |calendar| {
+    let shift = |date: &FeelDate| -> Option<FeelDate> {
+      // weekends are skipped arithmetically, then the date is shifted further
+      // by the number of holidays passed, until no more holidays are passed
+      let mut date = date.clone();
+      let mut remaining = days.unsigned_abs();
+      while remaining > 0 {
+        let target = shift_weekdays(&date, remaining, days < 0)?;
+        remaining = if days < 0 {
+          calendar.count_weekday_holidays(&target, &date.sub_days(1)?)
+        } else {
+          calendar.count_weekday_holidays(&date.add_days(1)?, &target)
+        };
+        date = target;
+      }
+      Some(date)
+    };
+    match date_value {
+      Value::Date(date) => shift(date).map(Value::Date),
+      Value::DateTime(date_time) => shift(&date_time.date()).map(|date| Value::DateTime(FeelDateTime::new(date, date_time.time()))),
+      _ => return invalid_argument_type!("dmntk.add business days", "date, date and time", date_value.type_of()),
+    }
+    .unwrap_or_else(|| value_null!("[dmntk.add business days] date is out of range"))
+  })
+}
+
+/// Returns the number of business days starting from the date `from` (inclusive)
+/// to the date `to` (exclusive). When `to` precedes `from`, the result is negative.
+/// When the calendar is `null`, only weekends are skipped.
+pub fn business_days_between(from_value: &Value, to_value: &Value, calendar_value: &Value) -> Value {
+  let Some(from) = date_part(from_value) else {
+    return invalid_argument_type!("dmntk.business days between", "date, date and time", from_value.type_of());
+  };
+  let Some(to) = date_part(to_value) else {
+    return invalid_argument_type!("dmntk.business days between", "date, date and time", to_value.type_of());
+  };
+  with_calendar("dmntk.business days between", calendar_value, |calendar| {
+    let (first, end, sign) = if from <= to { (from, to, 1) } else { (to, from, -1) };
+    let days = day_number(&end) - day_number(&first);
+    if days == 0 {
+      return value_number!(0);
+    }
+    let (Some(weekday), Some(last)) = (weekday_index(&first), end.sub_days(1)) else {
+      return value_null!("[dmntk.business days between] date is out of range");
+    };
+    let weekdays = days / 7 * 5 + (0..days % 7).filter(|offset| (weekday as i64 + offset) % 7 < 5).count() as i64;
+    let holidays = calendar.count_weekday_holidays(&first, &last) as i64;
+    value_number!(sign * (weekdays - holidays))
+  })
+}
+
+/// Returns `true` when the date is a holiday in specified calendar.
+pub fn is_holiday(date_value: &Value, calendar_value: &Value) -> Value {
+  let Some(date) = date_part(date_value) else {
+    return invalid_argument_type!("dmntk.is holiday", "date, date and time", date_value.type_of());
+  };
+  if let Value::Null(_) = calendar_value {
+    return value_null!("[dmntk.is holiday] calendar name must be specified");
+  }
+  with_calendar("dmntk.is holiday", calendar_value, |calendar| Value::Boolean(calendar.is_holiday(&date)))
+}
+
+/// Evaluates the function using the calendar registered under the name given as a string value.
+/// When the calendar value is `null`, the calendar without holidays is used.
+fn with_calendar(function_name: &str, calendar_value: &Value, function: impl FnOnce(&HolidayCalendar) -> Value) -> Value {
+  match calendar_value {
+    Value::Null(_) => function(&HolidayCalendar::default()),
+    Value::String(calendar_name) => {
+      // the calendars are cloned out of the thread local storage, so the function may evaluate nested calls
+      let calendars = CALENDARS.with(|calendars| calendars.borrow().clone());
+      match calendars.as_ref().and_then(|calendars| calendars.get(calendar_name)) {
+        Some(calendar) => function(calendar),
+        None => value_null!("[{}] calendar '{}' not found", function_name, calendar_name),
+      }
+    }
+    other => value_null!("[{}] invalid argument type, expected string, actual type is {}", function_name, other.type_of()),
+  }
+}
+
+/// Returns the date part of the date or date and time value.
+fn date_part(value: &Value) -> Option<FeelDate> {
+  match value {
+    Value::Date(date) => Some(date.clone()),
+    Value::DateTime(date_time) => Some(date_time.date()),
+    _ => None,
+  }
+}
+
+/// Converts the number into integer, returns `None` when the number has a fractional part.
+fn integer(number: &FeelNumber) -> Option<i64> {
+  if number.is_integer() {
+    number.try_into().ok()
+  } else {
+    None
+  }
+}
+
+/// Returns the date shifted by specified number of weekdays (Monday to Friday) forward or backward.
+/// Whole weeks are counted arithmetically, so the number of iterations is limited to a single week.
+fn shift_weekdays(date: &FeelDate, weekdays: u64, backward: bool) -> Option<FeelDate> {
+  let mut weekday = weekday_index(date)?;
+  let weeks = (weekdays - 1) / 5;
+  let mut remaining = weekdays - weeks * 5;
+  let mut offset = weeks.checked_mul(7)?;
+  while remaining > 0 {
+    offset += 1;
+    weekday = if backward { (weekday + 6) % 7 } else { (weekday + 1) % 7 };
+    if weekday < 5 {
+      remaining -= 1;
+    }
+  }
+  if backward {
+    date.sub_days(offset)
+  } else {
+    date.add_days(offset)
+  }
+}
+
+/// Returns the index of the day of the week, starting from 0 for Monday.
+fn weekday_index(date: &FeelDate) -> Option<u64> {
+  date.day_of_week().map(|(_, day)| day as u64 - 1)
+}
+
+/// Returns the number of days elapsed since 0000-03-01 in proleptic Gregorian calendar,
+/// negative for earlier dates.
+fn day_number(date: &FeelDate) -> i64 {
+  let (year, month, day) = date.as_tuple();
+  let (year, month, day) = (year as i64 - if month <= 2 { 1 } else { 0 }, month as i64, day as i64);
+  let era = year.div_euclid(400);
+  let year_of_era = year - era * 400;
+  let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+  era * 146_097 + year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year
+}
+
+/// Returns `true` when specified date is Saturday or Sunday.
+fn is_weekend(date: &FeelDate) -> bool {
+  matches!(date.day_of_week(), Some((_, 6 | 7)))
+}
+
+/// Joins lines folded according to RFC 5545, continuation lines start with a space or a tab.
+fn unfold_lines(text: &str) -> Vec<String> {
+  let mut lines: Vec<String> = vec![];
+  for line in text.lines() {
+    match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+      (Some(continuation), Some(last)) => last.push_str(continuation),
+      _ => lines.push(line.to_string()),
+    }
+  }
+  lines
+}
+
+/// Parses the date given in iCalendar format `YYYYMMDD`, optionally followed by time.
+fn parse_icalendar_date(value: &str) -> Result<FeelDate, DmntkError> {
+  let date = value.get(0..8).ok_or_else(|| err_invalid_calendar_date(value))?;
+  let year = date[0..4].parse::<Year>().map_err(|_| err_invalid_calendar_date(value))?;
+  let month = date[4..6].parse::<Month>().map_err(|_| err_invalid_calendar_date(value))?;
+  let day = date[6..8].parse::<Day>().map_err(|_| err_invalid_calendar_date(value))?;
+  FeelDate::new_opt(year, month, day).ok_or_else(|| err_invalid_calendar_date(value))
+}
+
+/// Parses the recurrence rule, only events repeated every year are supported.
+fn parse_icalendar_rule(value: &str) -> Result<Recurrence, DmntkError> {
+  let mut frequency = "";
+  let mut interval = "1";
+  let mut recurrence = Recurrence::Forever;
+  for (name, part) in value.split(';').filter_map(|part| part.split_once('=')) {
+    match name.to_uppercase().as_str() {
+      "FREQ" => frequency = part,
+      "INTERVAL" => interval = part,
+      "COUNT" => recurrence = Recurrence::Count(part.parse::<Year>().map_err(|_| err_unsupported_recurrence_rule(value))?),
+      "UNTIL" => recurrence = Recurrence::Until(parse_icalendar_date(part)?.year()),
+      _ => return Err(err_unsupported_recurrence_rule(value)),
+    }
+  }
+  if frequency.eq_ignore_ascii_case("YEARLY") && interval == "1" {
+    Ok(recurrence)
+  } else {
+    Err(err_unsupported_recurrence_rule(value))
+  }
+}
@@ -32,15 +32,14 @@
 
 //! Core implementation of build-in functions.
 
+use crate::bifs::xpath_regex::XPathRegex;
 use crate::evaluate_equals;
-use crate::macros::invalid_argument_type;
+use crate::macros::{invalid_argument_type, number_or_null};
 use dmntk_common::DmntkError;
 use dmntk_feel::context::FeelContext;
 use dmntk_feel::values::{Value, Values, VALUE_FALSE, VALUE_TRUE};
-use dmntk_feel::{value_null, value_number, value_string, FeelNumber, FeelScope, Name, ToFeelString};
+use dmntk_feel::{value_null, value_number, value_string, FeelNumber, FeelNumberStatus, FeelScope, Name, ToFeelString};
 use dmntk_feel_temporal::{DayOfWeek, DayOfYear, FeelDate, FeelDateTime, FeelDaysAndTimeDuration, FeelTime, FeelYearsAndMonthsDuration, MonthOfYear, WeekOfYear};
-use once_cell::sync::Lazy;
-use regex::Regex;
 use std::borrow::Borrow;
 use std::cmp::Ordering;
 use std::convert::TryFrom;
@@ -355,7 +354,7 @@ pub fn before(value1: &Value, value2: &Value) -> Value {
 /// Returns the smallest integer >= argument.
 pub fn ceiling(value: &Value) -> Value {
   if let Value::Number(v) = value {
-    Value::Number(v.ceiling())
+    number_or_null!("core::ceiling", v.ceiling_with_status())
   } else {
     invalid_argument_type!("ceiling", "number", value.type_of())
   }
@@ -591,7 +590,7 @@ pub fn decimal(number_value: &Value, scale_value: &Value) -> Value {
     if let Value::Number(scale) = scale_value {
       let scale = &scale.trunc();
       if (-6111..6176).contains(scale) {
-        Value::Number((*number).round(scale))
+        number_or_null!("core::decimal", number.round_with_status(scale))
       } else {
         value_null!("[core::decimal] scale is out of range: {}", scale)
       }
@@ -761,9 +760,7 @@ pub fn even(number_value: &Value) -> Value {
 /// Returns the Euler’s number e raised to the power of **value** given as a parameter.
 pub fn exp(value: &Value) -> Value {
   if let Value::Number(num) = value {
-    if let Some(n) = num.exp() {
-      return Value::Number(n);
-    }
+    return number_or_null!("core::exp", num.exp_with_status());
   }
   value_null!("exp")
 }
@@ -943,7 +940,7 @@ fn flatten_value(value: &Value, flattened: &mut Vec<Value>) {
 /// Returns greatest **integer** <= **value** specified as a parameter.
 pub fn floor(value: &Value) -> Value {
   if let Value::Number(v) = value {
-    Value::Number(v.floor())
+    number_or_null!("core::floor", v.floor_with_status())
   } else {
     invalid_argument_type!("floor", "number", value.type_of())
   }
@@ -1167,9 +1164,7 @@ pub fn list_contains(list: &Value, element: &Value) -> Value {
 pub fn log(number: &Value) -> Value {
   if let Value::Number(num) = number {
     if *num > FeelNumber::zero() {
-      if let Some(num_log) = num.ln() {
-        return Value::Number(num_log);
-      }
+      return number_or_null!("core::log", num.ln_with_status());
     }
   }
   value_null!()
@@ -1188,13 +1183,15 @@ pub fn lower_case(input_string_value: &Value) -> Value {
 pub fn matches(input_string_value: &Value, pattern_string_value: &Value, flags_string_value: &Value) -> Value {
   if let Value::String(input_string) = input_string_value {
     if let Value::String(pattern_string) = pattern_string_value {
-      if let Value::String(flags_string) = flags_string_value {
-        if let Ok(re) = Regex::new(format!("(?{flags_string}){pattern_string}").as_str()) {
-          return Value::Boolean(re.is_match(input_string));
-        }
-      } else if let Ok(re) = Regex::new(pattern_string) {
-        return Value::Boolean(re.is_match(input_string));
-      }
+      let flags = match flags_string_value {
+        Value::String(flags_string) => Some(flags_string.as_str()),
+        Value::Null(_) => None,
+        _ => return value_null!("[core::matches] flags must be a string"),
+      };
+      return match XPathRegex::new(pattern_string, flags) {
+        Ok(re) => Value::Boolean(re.is_match(input_string)),
+        Err(reason) => value_null!("[core::matches] {}", reason),
+      };
     }
   }
   value_null!("matches")
@@ -1246,14 +1243,18 @@ pub fn mean(values: &[Value]) -> Value {
     return value_null!();
   }
   let mut sum = FeelNumber::zero();
+  let mut status = FeelNumberStatus::default();
   for value in values {
     if let Value::Number(n) = value {
-      sum += *n;
+      let (result, result_status) = sum.add_with_status(n);
+      sum = result;
+      status |= result_status;
     } else {
       return invalid_argument_type!("mean", "number", value.type_of());
     }
   }
-  Value::Number(sum / values.len().into())
+  let (mean, mean_status) = sum.div_with_status(&values.len().into());
+  number_or_null!("core::mean", (mean, status | mean_status))
 }
 
 /// Returns `true` when range1 `meets` range2.
@@ -1324,7 +1325,9 @@ pub fn median(values: &[Value]) -> Value {
   list.sort_by(|x, y| x.partial_cmp(y).unwrap_or(Ordering::Equal));
   let index = values.len() / 2;
   if list.len() % 2 == 0 {
-    Value::Number((list[index - 1] + list[index]) / FeelNumber::two())
+    let (sum, status) = list[index - 1].add_with_status(&list[index]);
+    let (median, median_status) = sum.div_with_status(&FeelNumber::two());
+    number_or_null!("core::median", (median, status | median_status))
   } else {
     Value::Number(list[index])
   }
@@ -1463,7 +1466,7 @@ pub fn modulo(dividend_value: &Value, divisor_value: &Value) -> Value {
       if divisor.abs() == FeelNumber::zero() {
         value_null!("[core::modulo] division by zero")
       } else {
-        Value::Number(dividend - divisor * (dividend / divisor).floor())
+        number_or_null!("core::modulo", dividend.rem_with_status(&divisor))
       }
     } else {
       invalid_argument_type!("modulo", "number", divisor_value.type_of())
@@ -1764,7 +1767,11 @@ pub fn product(values: &[Value]) -> Value {
       return invalid_argument_type!("product", "number", value.type_of());
     }
   }
-  Value::Number(list.iter().fold(FeelNumber::one(), |acc, n| acc * (*n)))
+  let (product, status) = list.iter().fold((FeelNumber::one(), FeelNumberStatus::default()), |(acc, status), n| {
+    let (result, result_status) = acc.mul_with_status(n);
+    (result, status | result_status)
+  });
+  number_or_null!("core::product", (product, status))
 }
 
 /// ???
@@ -1793,57 +1800,19 @@ pub fn remove(list: &Value, position_value: &Value) -> Value {
   value_null!("probably index is out of range")
 }
 
-// Rust implementation is eager when parsing matching groups, so place numbers in square brackets.
-static RG_REPLACE_NUM: Lazy<Regex> = Lazy::new(|| Regex::new("\\$([1-9][0-9]*)").unwrap());
-
-/// ???
+/// Returns the input string with all matches of the pattern replaced with the replacement string.
 pub fn replace(input_string_value: &Value, pattern_string_value: &Value, replacement_string_value: &Value, flags_string_value: &Value) -> Value {
   if let Value::String(input_string) = input_string_value {
     if let Value::String(pattern_string) = pattern_string_value {
       if let Value::String(replacement_string) = replacement_string_value {
-        let repl = RG_REPLACE_NUM.replace_all(replacement_string.as_str(), "$${${1}}").to_string();
-        // check and use flags
-        if let Value::String(flags_string) = flags_string_value {
-          let mut flags = "".to_string();
-          let mut flag_q = false;
-          let mut clear_flag_q = false;
-          for ch in flags_string.chars() {
-            if ch == 'q' {
-              flag_q = true;
-            }
-            if matches!(ch, 's' | 'm' | 'i' | 'x') {
-              flags.push(ch);
-              if ch != 'i' {
-                clear_flag_q = true;
-              }
-            }
-          }
-          if clear_flag_q {
-            flag_q = false;
-          }
-          let mut patt = "".to_string();
-          for ch in pattern_string.chars() {
-            if flag_q {
-              patt.push('\\');
-            }
-            patt.push(ch);
-          }
-          if flags.is_empty() {
-            if let Ok(re) = Regex::new(&patt) {
-              let result = re.replace_all(input_string.as_str(), repl.as_str()).to_string();
-              return Value::String(result);
-            }
-          } else if let Ok(re) = Regex::new(format!("(?{flags}){patt}").as_str()) {
-            let result = re.replace_all(input_string.as_str(), repl.as_str()).to_string();
-            return Value::String(result);
-          }
-        }
-        // replace without any flags
-        if let Ok(re) = Regex::new(pattern_string) {
-          let result = re.replace_all(input_string.as_str(), repl.as_str()).to_string();
-          Value::String(result)
-        } else {
-          value_null!("replace: invalid pattern")
+        let flags = match flags_string_value {
+          Value::String(flags_string) => Some(flags_string.as_str()),
+          Value::Null(_) => None,
+          _ => return value_null!("replace: flags must be a string"),
+        };
+        match XPathRegex::new_non_empty(pattern_string, flags).and_then(|re| re.replace_all(input_string, replacement_string)) {
+          Ok(result) => Value::String(result),
+          Err(reason) => value_null!("[core::replace] {}", reason),
         }
       } else {
         value_null!("replace: replacement must be a string")
@@ -1899,14 +1868,13 @@ pub fn sort(list: &Value, ordering_function: &Value) -> Value {
   }
 }
 
-///
+/// Splits the input string into a list of substrings, breaking at each match of the delimiter pattern.
 pub fn split(input_string_value: &Value, delimiter_string_value: &Value) -> Value {
   if let Value::String(input_string) = input_string_value {
     if let Value::String(delimiter_string) = delimiter_string_value {
-      if let Ok(re) = Regex::new(delimiter_string) {
-        return Value::List(re.split(input_string).map(|s| Value::String(s.to_string())).collect());
-      } else {
-        value_null!("split: invalid delimiter")
+      match XPathRegex::new_non_empty(delimiter_string, None) {
+        Ok(re) => Value::List(re.split(input_string).into_iter().map(Value::String).collect()),
+        Err(reason) => value_null!("[core::split] {}", reason),
       }
     } else {
       value_null!("split: delimiter must be a string")
@@ -1922,11 +1890,7 @@ pub fn split(input_string_value: &Value, delimiter_string_value: &Value) -> Valu
 pub fn sqrt(value: &Value) -> Value {
   if let Value::Number(v) = value {
     if *v >= FeelNumber::zero() {
-      if let Some(result) = v.sqrt() {
-        Value::Number(result)
-      } else {
-        value_null!("sqrt: result is not a finite number")
-      }
+      number_or_null!("core::sqrt", v.sqrt_with_status())
     } else {
       value_null!("sqrt: argument must be positive number or zero")
     }
@@ -2200,14 +2164,17 @@ pub fn sum(values: &[Value]) -> Value {
   }
   if let Value::Number(n) = values[0] {
     let mut sum = n;
+    let mut status = FeelNumberStatus::default();
     for value in values.iter().skip(1) {
-      if let Value::Number(v) = *value {
-        sum += v;
+      if let Value::Number(v) = value {
+        let (result, result_status) = sum.add_with_status(v);
+        sum = result;
+        status |= result_status;
       } else {
         return invalid_argument_type!("sum", "number", value.type_of());
       }
     }
-    Value::Number(sum)
+    number_or_null!("core::sum", (sum, status))
   } else {
     invalid_argument_type!("sum", "number", values[0].type_of())
   }
@@ -30,6 +30,9 @@
  * limitations under the License.
  */
 
+#[cfg(feature = "calendar")]
+pub mod calendar;
 pub mod core;
 pub mod named;
 pub mod positional;
+pub mod xpath_regex;
@@ -30,18 +30,26 @@
  * limitations under the License.
  */
 
+#[cfg(feature = "calendar")]
+use crate::bifs::calendar;
 use crate::bifs::core;
+#[cfg(not(feature = "calendar"))]
+use crate::macros::extension_not_enabled;
 use dmntk_feel::bif::Bif;
 use dmntk_feel::values::Value;
 use dmntk_feel::{value_null, Name};
 
 use once_cell::sync::Lazy;
 
+#[cfg(feature = "calendar")]
+static NAME_CALENDAR: Lazy<Name> = Lazy::new(|| Name::from("calendar"));
 static NAME_DATE: Lazy<Name> = Lazy::new(|| Name::from("date"));
 static NAME_DECIMAL_SEPARATOR: Lazy<Name> = Lazy::new(|| Name::new(&["decimal", "separator"]));
 static NAME_DELIMITER: Lazy<Name> = Lazy::new(|| Name::from("delimiter"));
 static NAME_GROUPING_SEPARATOR: Lazy<Name> = Lazy::new(|| Name::new(&["grouping", "separator"]));
 static NAME_DAY: Lazy<Name> = Lazy::new(|| Name::from("day"));
+#[cfg(feature = "calendar")]
+static NAME_DAYS: Lazy<Name> = Lazy::new(|| Name::from("days"));
 static NAME_DIVIDEND: Lazy<Name> = Lazy::new(|| Name::from("dividend"));
 static NAME_DIVISOR: Lazy<Name> = Lazy::new(|| Name::from("divisor"));
 static NAME_FLAGS: Lazy<Name> = Lazy::new(|| Name::from("flags"));
@@ -173,6 +181,14 @@ pub fn evaluate_bif(bif: Bif, parameters: &NamedParameters) -> Value {
     Bif::UpperCase => bif_upper_case(parameters),
     Bif::WeekOfYear => bif_week_of_year(parameters),
     Bif::YearsAndMonthsDuration => bif_years_and_months_duration(parameters),
+    #[cfg(feature = "calendar")]
+    Bif::AddBusinessDays => bif_add_business_days(parameters),
+    #[cfg(feature = "calendar")]
+    Bif::BusinessDaysBetween => bif_business_days_between(parameters),
+    #[cfg(feature = "calendar")]
+    Bif::IsHoliday => bif_is_holiday(parameters),
+    #[cfg(not(feature = "calendar"))]
+    Bif::AddBusinessDays | Bif::BusinessDaysBetween | Bif::IsHoliday => extension_not_enabled!(bif),
   }
 }
 
@@ -966,6 +982,53 @@ fn bif_years_and_months_duration(parameters: &NamedParameters) -> Value {
   }
 }
 
+#[cfg(feature = "calendar")]
+fn bif_add_business_days(parameters: &NamedParameters) -> Value {
+  if let Some((date_value, _)) = get_param(parameters, &NAME_DATE) {
+    if let Some((days_value, _)) = get_param(parameters, &NAME_DAYS) {
+      if let Some((calendar_value, _)) = get_param(parameters, &NAME_CALENDAR) {
+        calendar::add_business_days(date_value, days_value, calendar_value)
+      } else {
+        calendar::add_business_days(date_value, days_value, &value_null!())
+      }
+    } else {
+      parameter_not_found!(NAME_DAYS)
+    }
+  } else {
+    parameter_not_found!(NAME_DATE)
+  }
+}
+
+#[cfg(feature = "calendar")]
+fn bif_business_days_between(parameters: &NamedParameters) -> Value {
+  if let Some((from_value, _)) = get_param(parameters, &NAME_FROM) {
+    if let Some((to_value, _)) = get_param(parameters, &NAME_TO) {
+      if let Some((calendar_value, _)) = get_param(parameters, &NAME_CALENDAR) {
+        calendar::business_days_between(from_value, to_value, calendar_value)
+      } else {
+        calendar::business_days_between(from_value, to_value, &value_null!())
+      }
+    } else {
+      parameter_not_found!(NAME_TO)
+    }
+  } else {
+    parameter_not_found!(NAME_FROM)
+  }
+}
+
+#[cfg(feature = "calendar")]
+fn bif_is_holiday(parameters: &NamedParameters) -> Value {
+  if let Some((date_value, _)) = get_param(parameters, &NAME_DATE) {
+    if let Some((calendar_value, _)) = get_param(parameters, &NAME_CALENDAR) {
+      calendar::is_holiday(date_value, calendar_value)
+    } else {
+      parameter_not_found!(NAME_CALENDAR)
+    }
+  } else {
+    parameter_not_found!(NAME_DATE)
+  }
+}
+
 /// Returns reference to the value and position of the parameter with specified name.
 /// The position of the named parameter is counted from 1.
 /// Additionally the total number of parameters is returned.
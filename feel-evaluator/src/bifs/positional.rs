@@ -30,7 +30,11 @@
  * limitations under the License.
  */
 
+#[cfg(feature = "calendar")]
+use crate::bifs::calendar;
 use crate::bifs::core;
+#[cfg(not(feature = "calendar"))]
+use crate::macros::extension_not_enabled;
 use crate::macros::invalid_number_of_parameters;
 use dmntk_feel::bif::Bif;
 use dmntk_feel::value_null;
@@ -113,6 +117,14 @@ pub fn evaluate_bif(bif: Bif, parameters: &[Value]) -> Value {
     Bif::UpperCase => bif_upper_case(parameters),
     Bif::WeekOfYear => bif_week_of_year(parameters),
     Bif::YearsAndMonthsDuration => bif_years_and_months_duration(parameters),
+    #[cfg(feature = "calendar")]
+    Bif::AddBusinessDays => bif_add_business_days(parameters),
+    #[cfg(feature = "calendar")]
+    Bif::BusinessDaysBetween => bif_business_days_between(parameters),
+    #[cfg(feature = "calendar")]
+    Bif::IsHoliday => bif_is_holiday(parameters),
+    #[cfg(not(feature = "calendar"))]
+    Bif::AddBusinessDays | Bif::BusinessDaysBetween | Bif::IsHoliday => extension_not_enabled!(bif),
   }
 }
 
@@ -684,3 +696,29 @@ fn bif_years_and_months_duration(parameters: &[Value]) -> Value {
     n => invalid_number_of_parameters!(2, n),
   }
 }
+
+#[cfg(feature = "calendar")]
+fn bif_add_business_days(parameters: &[Value]) -> Value {
+  match parameters.len() {
+    2 => calendar::add_business_days(&parameters[0], &parameters[1], &value_null!()),
+    3 => calendar::add_business_days(&parameters[0], &parameters[1], &parameters[2]),
+    n => invalid_number_of_parameters!("2,3", n),
+  }
+}
+
+#[cfg(feature = "calendar")]
+fn bif_business_days_between(parameters: &[Value]) -> Value {
+  match parameters.len() {
+    2 => calendar::business_days_between(&parameters[0], &parameters[1], &value_null!()),
+    3 => calendar::business_days_between(&parameters[0], &parameters[1], &parameters[2]),
+    n => invalid_number_of_parameters!("2,3", n),
+  }
+}
+
+#[cfg(feature = "calendar")]
+fn bif_is_holiday(parameters: &[Value]) -> Value {
+  match parameters.len() {
+    2 => calendar::is_holiday(&parameters[0], &parameters[1]),
+    n => invalid_number_of_parameters!(2, n),
+  }
+}
@@ -0,0 +1,498 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Translation of `XML Schema` / `XPath` regular expressions into Rust regular expressions.
+//!
+//! `FEEL` functions `matches`, `replace` and `split` use the regular expression syntax
+//! defined in **XQuery 1.0 and XPath 2.0 Functions and Operators**, chapter 7.6.
+//! This syntax is based on `XML Schema` regular expressions with a few extensions
+//! (anchors, reluctant quantifiers, non-capturing groups and back-references).
+//! The `regex` crate uses a different dialect, so the patterns are translated here
+//! before being compiled.
+
+use crate::errors::*;
+use dmntk_common::{DmntkError, Result};
+use regex::Regex;
+
+/// Characters allowed as the initial character of an `XML` name (`NameStartChar` without the colon).
+const NAME_START_CHARS: &str = r"A-Z_a-z\x{C0}-\x{D6}\x{D8}-\x{F6}\x{F8}-\x{2FF}\x{370}-\x{37D}\x{37F}-\x{1FFF}\x{200C}-\x{200D}\x{2070}-\x{218F}\x{2C00}-\x{2FEF}\x{3001}-\x{D7FF}\x{F900}-\x{FDCF}\x{FDF0}-\x{FFFD}\x{10000}-\x{EFFFF}";
+
+/// Characters allowed in an `XML` name in addition to [NAME_START_CHARS].
+const NAME_EXTRA_CHARS: &str = r"\-.0-9\x{B7}\x{300}-\x{36F}\x{203F}-\x{2040}";
+
+/// Unicode general categories recognized by `XML Schema` regular expressions.
+const CATEGORIES: [&str; 36] = [
+  "L", "Lu", "Ll", "Lt", "Lm", "Lo", "M", "Mn", "Mc", "Me", "N", "Nd", "Nl", "No", "P", "Pc", "Pd", "Ps", "Pe", "Pi", "Pf", "Po", "Z", "Zs", "Zl", "Zp", "S", "Sm", "Sc", "Sk",
+  "So", "C", "Cc", "Cf", "Co", "Cn",
+];
+
+/// Flags controlling the interpretation of the regular expression.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RegexFlags {
+  /// Flag `s`, the dot matches any character including newline and carriage return.
+  pub dot_all: bool,
+  /// Flag `m`, anchors `^` and `$` match at the start and end of each line.
+  pub multi_line: bool,
+  /// Flag `i`, matching is case-insensitive.
+  pub case_insensitive: bool,
+  /// Flag `x`, whitespace characters outside character classes are removed from the pattern.
+  pub extended: bool,
+  /// Flag `q`, all characters in the pattern represent themselves.
+  pub literal: bool,
+}
+
+impl RegexFlags {
+  /// Parses the flags string, only `s`, `m`, `i`, `x` and `q` are allowed.
+  pub fn parse(flags: &str) -> Result<Self> {
+    let mut regex_flags = Self::default();
+    for ch in flags.chars() {
+      match ch {
+        's' => regex_flags.dot_all = true,
+        'm' => regex_flags.multi_line = true,
+        'i' => regex_flags.case_insensitive = true,
+        'x' => regex_flags.extended = true,
+        'q' => regex_flags.literal = true,
+        other => return Err(err_invalid_regex_flag(other)),
+      }
+    }
+    Ok(regex_flags)
+  }
+}
+
+/// Regular expression compiled from `XPath` pattern.
+pub struct XPathRegex {
+  /// Compiled Rust regular expression.
+  regex: Regex,
+  /// Flags used to compile the pattern.
+  flags: RegexFlags,
+}
+
+impl XPathRegex {
+  /// Compiles the `XPath` pattern with optional flags.
+  pub fn new(pattern: &str, flags: Option<&str>) -> Result<Self> {
+    let flags = RegexFlags::parse(flags.unwrap_or_default())?;
+    let translated = translate(pattern, &flags)?;
+    let regex = Regex::new(&translated).map_err(|_| err_invalid_regex_pattern(pattern, "not supported by the regular expression engine"))?;
+    Ok(Self { regex, flags })
+  }
+
+  /// Compiles the `XPath` pattern that is not allowed to match a zero-length string,
+  /// like patterns used in `replace` and `split` functions.
+  pub fn new_non_empty(pattern: &str, flags: Option<&str>) -> Result<Self> {
+    let xpath_regex = Self::new(pattern, flags)?;
+    if xpath_regex.regex.is_match("") {
+      return Err(err_regex_matches_zero_length_string(pattern));
+    }
+    Ok(xpath_regex)
+  }
+
+  /// Returns `true` when the input string matches this regular expression.
+  pub fn is_match(&self, input: &str) -> bool {
+    self.regex.is_match(input)
+  }
+
+  /// Replaces all matches in the input string with the `XPath` replacement string.
+  pub fn replace_all(&self, input: &str, replacement: &str) -> Result<String> {
+    let replacement = if self.flags.literal {
+      replacement.replace('$', "$$")
+    } else {
+      translate_replacement(replacement, self.regex.captures_len() - 1)?
+    };
+    Ok(self.regex.replace_all(input, replacement.as_str()).to_string())
+  }
+
+  /// Splits the input string using this regular expression as a delimiter.
+  pub fn split(&self, input: &str) -> Vec<String> {
+    self.regex.split(input).map(|s| s.to_string()).collect()
+  }
+}
+
+/// Translates `XPath` pattern into the pattern accepted by `regex` crate.
+pub fn translate(pattern: &str, flags: &RegexFlags) -> Result<String> {
+  let mut translated = String::new();
+  if flags.case_insensitive {
+    translated.push_str("(?i)");
+  }
+  if flags.literal {
+    // flags `m`, `s` and `x` have no effect when combined with flag `q`
+    translated.push_str(&regex::escape(pattern));
+    return Ok(translated);
+  }
+  if flags.multi_line {
+    translated.push_str("(?m)");
+  }
+  let chars = if flags.extended { remove_whitespace(pattern) } else { pattern.chars().collect() };
+  let mut translator = Translator {
+    pattern,
+    chars,
+    position: 0,
+    dot_all: flags.dot_all,
+    group_count: 0,
+  };
+  translated.push_str(&translator.translate()?);
+  Ok(translated)
+}
+
+/// Translates `XPath` replacement string into replacement string accepted by `regex` crate.
+///
+/// Only `\\` and `\$` escapes are allowed, and `$N` references the captured group number `N`.
+/// The first digit after `$` always belongs to the reference, next digits are included
+/// only when the resulting number does not exceed the number of capturing groups.
+pub fn translate_replacement(replacement: &str, group_count: usize) -> Result<String> {
+  let chars = replacement.chars().collect::<Vec<char>>();
+  let mut translated = String::new();
+  let mut position = 0;
+  while position < chars.len() {
+    match chars[position] {
+      '\\' => match chars.get(position + 1) {
+        Some('\\') => {
+          translated.push('\\');
+          position += 2;
+        }
+        Some('$') => {
+          translated.push_str("$$");
+          position += 2;
+        }
+        _ => return Err(err_invalid_regex_replacement(replacement, "'\\' must be followed by '\\' or '$'")),
+      },
+      '$' => {
+        let Some(mut group) = chars.get(position + 1).and_then(|ch| ch.to_digit(10)) else {
+          return Err(err_invalid_regex_replacement(replacement, "'$' must be followed by a digit"));
+        };
+        position += 2;
+        while let Some(digit) = chars.get(position).and_then(|ch| ch.to_digit(10)) {
+          let extended = group * 10 + digit;
+          if extended as usize > group_count {
+            break;
+          }
+          group = extended;
+          position += 1;
+        }
+        if group as usize <= group_count {
+          translated.push_str(&format!("${{{group}}}"));
+        }
+      }
+      other => {
+        translated.push(other);
+        position += 1;
+      }
+    }
+  }
+  Ok(translated)
+}
+
+/// Removes whitespace characters placed outside character classes (flag `x`).
+fn remove_whitespace(pattern: &str) -> Vec<char> {
+  let mut chars = vec![];
+  let mut class_depth = 0_usize;
+  let mut escaped = false;
+  for ch in pattern.chars() {
+    if escaped {
+      escaped = false;
+    } else {
+      match ch {
+        '\\' => escaped = true,
+        '[' => class_depth += 1,
+        ']' => class_depth = class_depth.saturating_sub(1),
+        '\u{9}' | '\u{A}' | '\u{D}' | '\u{20}' if class_depth == 0 => continue,
+        _ => {}
+      }
+    }
+    chars.push(ch);
+  }
+  chars
+}
+
+/// Single element of the pattern produced by an escape sequence.
+enum Atom {
+  /// Single character.
+  Char(char),
+  /// Set of characters, expressed as a Rust character class that may be nested in another class.
+  Set(String),
+}
+
+/// Translator of the `XPath` patterns.
+struct Translator<'a> {
+  /// Original pattern, used in error messages.
+  pattern: &'a str,
+  /// Characters of the pattern to be translated.
+  chars: Vec<char>,
+  /// Current position in translated characters.
+  position: usize,
+  /// Flag indicating if the dot matches all characters.
+  dot_all: bool,
+  /// Number of capturing groups encountered so far.
+  group_count: usize,
+}
+
+impl Translator<'_> {
+  /// Translates the whole pattern.
+  fn translate(&mut self) -> Result<String> {
+    let mut translated = String::new();
+    let mut group_depth = 0_usize;
+    while let Some(ch) = self.next() {
+      match ch {
+        '\\' => {
+          if let Some(digit) = self.peek().filter(|ch| ch.is_ascii_digit()) {
+            if digit == '0' || digit.to_digit(10).unwrap_or_default() as usize > self.group_count {
+              return Err(self.error("back-reference to a non-existing group"));
+            }
+            return Err(self.error("back-references are not supported"));
+          }
+          let atom = self.escape()?;
+          push_atom(&mut translated, atom, false);
+        }
+        '[' => {
+          let class = self.class()?;
+          translated.push_str(&class);
+        }
+        '.' => translated.push_str(if self.dot_all { "(?s:.)" } else { r"[^\n\r]" }),
+        '(' => {
+          if self.peek() == Some('?') {
+            if self.peek_at(1) != Some(':') {
+              return Err(self.error("only non-capturing groups '(?:' are allowed after '(?'"));
+            }
+            self.position += 2;
+            translated.push_str("(?:");
+          } else {
+            self.group_count += 1;
+            translated.push('(');
+          }
+          group_depth += 1;
+        }
+        ')' => {
+          if group_depth == 0 {
+            return Err(self.error("unmatched ')'"));
+          }
+          group_depth -= 1;
+          translated.push(')');
+        }
+        '{' => translated.push_str(&self.quantity()?),
+        '}' => return Err(self.error("unescaped '}'")),
+        ']' => return Err(self.error("unescaped ']'")),
+        '*' | '+' | '?' | '|' | '^' | '$' => translated.push(ch),
+        other => push_literal(&mut translated, other, false),
+      }
+    }
+    if group_depth > 0 {
+      return Err(self.error("unmatched '('"));
+    }
+    Ok(translated)
+  }
+
+  /// Translates quantity placed between curly brackets, the opening bracket is already consumed.
+  fn quantity(&mut self) -> Result<String> {
+    let min = self.digits();
+    if min.is_empty() {
+      return Err(self.error("quantifier must start with a number"));
+    }
+    let mut quantity = format!("{{{min}");
+    if self.peek() == Some(',') {
+      self.position += 1;
+      quantity.push(',');
+      let max = self.digits();
+      if !max.is_empty() {
+        if max.parse::<u64>().ok() < min.parse::<u64>().ok() {
+          return Err(self.error("quantifier maximum is less than minimum"));
+        }
+        quantity.push_str(&max);
+      }
+    }
+    if self.next() != Some('}') {
+      return Err(self.error("unterminated quantifier"));
+    }
+    quantity.push('}');
+    Ok(quantity)
+  }
+
+  /// Consumes consecutive ASCII digits.
+  fn digits(&mut self) -> String {
+    let mut digits = String::new();
+    while let Some(ch) = self.peek().filter(|ch| ch.is_ascii_digit()) {
+      digits.push(ch);
+      self.position += 1;
+    }
+    digits
+  }
+
+  /// Translates character class expression, the opening square bracket is already consumed.
+  /// Supports character class subtraction like `[a-z-[aeiou]]`.
+  fn class(&mut self) -> Result<String> {
+    let negated = self.peek() == Some('^');
+    if negated {
+      self.position += 1;
+    }
+    let mut group = String::new();
+    let mut first = true;
+    let mut subtracted = None;
+    loop {
+      let Some(ch) = self.next() else {
+        return Err(self.error("unterminated character class"));
+      };
+      match ch {
+        ']' if first => return Err(self.error("empty character class")),
+        ']' => break,
+        '-' if self.peek() == Some('[') && !first => {
+          self.position += 1;
+          subtracted = Some(self.class()?);
+          if self.next() != Some(']') {
+            return Err(self.error("character class subtraction must be the last element of the class"));
+          }
+          break;
+        }
+        '-' if !first && self.peek() != Some(']') => return Err(self.error("unescaped '-' in character class")),
+        '[' => return Err(self.error("unescaped '[' in character class")),
+        _ => {
+          let start = if ch == '\\' { self.escape()? } else { Atom::Char(ch) };
+          if self.peek() == Some('-') && !matches!(self.peek_at(1), Some(']') | Some('[') | None) {
+            self.position += 1;
+            let Atom::Char(start_char) = start else {
+              return Err(self.error("multi-character escape can not start a range"));
+            };
+            let end_char = match self.next() {
+              Some('\\') => match self.escape()? {
+                Atom::Char(end_char) => end_char,
+                Atom::Set(_) => return Err(self.error("multi-character escape can not end a range")),
+              },
+              Some('[') => return Err(self.error("unescaped '[' in character class")),
+              Some(end_char) => end_char,
+              None => return Err(self.error("unterminated character class")),
+            };
+            if end_char < start_char {
+              return Err(self.error("invalid range in character class"));
+            }
+            push_literal(&mut group, start_char, true);
+            group.push('-');
+            push_literal(&mut group, end_char, true);
+          } else {
+            push_atom(&mut group, start, true);
+          }
+        }
+      }
+      first = false;
+    }
+    let class = format!("[{}{}]", if negated { "^" } else { "" }, group);
+    Ok(match subtracted {
+      Some(subtracted) => format!("[{class}--{subtracted}]"),
+      None => class,
+    })
+  }
+
+  /// Translates an escape sequence, the backslash is already consumed.
+  fn escape(&mut self) -> Result<Atom> {
+    let Some(ch) = self.next() else {
+      return Err(self.error("pattern ends with '\\'"));
+    };
+    Ok(match ch {
+      'n' => Atom::Char('\n'),
+      'r' => Atom::Char('\r'),
+      't' => Atom::Char('\t'),
+      '\\' | '|' | '.' | '?' | '*' | '+' | '(' | ')' | '{' | '}' | '-' | '[' | ']' | '^' | '$' => Atom::Char(ch),
+      's' => Atom::Set(r"[ \t\n\r]".to_string()),
+      'S' => Atom::Set(r"[^ \t\n\r]".to_string()),
+      'i' => Atom::Set(format!("[:{NAME_START_CHARS}]")),
+      'I' => Atom::Set(format!("[^:{NAME_START_CHARS}]")),
+      'c' => Atom::Set(format!("[:{NAME_START_CHARS}{NAME_EXTRA_CHARS}]")),
+      'C' => Atom::Set(format!("[^:{NAME_START_CHARS}{NAME_EXTRA_CHARS}]")),
+      'd' => Atom::Set(r"\p{Nd}".to_string()),
+      'D' => Atom::Set(r"\P{Nd}".to_string()),
+      'w' => Atom::Set(r"[^\p{P}\p{Z}\p{C}]".to_string()),
+      'W' => Atom::Set(r"[\p{P}\p{Z}\p{C}]".to_string()),
+      'p' | 'P' => {
+        if self.next() != Some('{') {
+          return Err(self.error("expected '{' after category escape"));
+        }
+        let mut name = String::new();
+        loop {
+          match self.next() {
+            Some('}') => break,
+            Some(ch) => name.push(ch),
+            None => return Err(self.error("unterminated category escape")),
+          }
+        }
+        if name.starts_with("Is") {
+          return Err(self.error(&format!("unicode block escape '{name}' is not supported")));
+        }
+        if !CATEGORIES.contains(&name.as_str()) {
+          return Err(self.error(&format!("unknown unicode category '{name}'")));
+        }
+        Atom::Set(format!(r"\{ch}{{{name}}}"))
+      }
+      other => return Err(self.error(&format!("invalid escape '\\{other}'"))),
+    })
+  }
+
+  /// Returns the next character and advances the position.
+  fn next(&mut self) -> Option<char> {
+    let ch = self.chars.get(self.position).cloned();
+    self.position += 1;
+    ch
+  }
+
+  /// Returns the character at current position.
+  fn peek(&self) -> Option<char> {
+    self.peek_at(0)
+  }
+
+  /// Returns the character at specified offset from current position.
+  fn peek_at(&self, offset: usize) -> Option<char> {
+    self.chars.get(self.position + offset).cloned()
+  }
+
+  /// Creates an invalid pattern error with specified reason.
+  fn error(&self, reason: &str) -> DmntkError {
+    err_invalid_regex_pattern(self.pattern, reason)
+  }
+}
+
+/// Appends the atom to translated pattern.
+fn push_atom(translated: &mut String, atom: Atom, in_class: bool) {
+  match atom {
+    Atom::Char(ch) => push_literal(translated, ch, in_class),
+    Atom::Set(set) => translated.push_str(&set),
+  }
+}
+
+/// Appends a literal character to translated pattern, escaping it when needed.
+fn push_literal(translated: &mut String, ch: char, in_class: bool) {
+  match ch {
+    '\n' => translated.push_str(r"\n"),
+    '\r' => translated.push_str(r"\r"),
+    '\t' => translated.push_str(r"\t"),
+    '\\' | '[' | ']' | '^' | '-' | '&' | '~' if in_class => {
+      translated.push('\\');
+      translated.push(ch);
+    }
+    _ if in_class => translated.push(ch),
+    _ => translated.push_str(&regex::escape(&ch.to_string())),
+  }
+}
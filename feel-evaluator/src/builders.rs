@@ -33,9 +33,10 @@
 use crate::bifs;
 use crate::errors::*;
 use crate::iterations::{EveryExpressionEvaluator, ForExpressionEvaluator, SomeExpressionEvaluator};
-use crate::macros::invalid_argument_type;
+use crate::macros::{invalid_argument_type, number_or_null};
 use dmntk_common::Result;
 use dmntk_feel::bif::Bif;
+use dmntk_feel::closure::Closure;
 use dmntk_feel::context::FeelContext;
 use dmntk_feel::values::{Value, Values, VALUE_FALSE, VALUE_TRUE};
 use dmntk_feel::{value_null, Evaluator, FeelNumber, FeelScope, FeelType, FunctionBody, Name, QualifiedName};
@@ -130,106 +131,107 @@ pub fn build_evaluator(node: &AstNode) -> Result<Evaluator> {
 fn build_add(lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
   let lhe = build_evaluator(lhs)?;
   let rhe = build_evaluator(rhs)?;
-  Ok(Box::new(move |scope: &FeelScope| {
-    let lhv = lhe(scope) as Value;
-    let rhv = rhe(scope) as Value;
-    match lhv {
-      Value::Number(lh) => match rhv {
-        Value::Number(rh) => Value::Number(lh + rh),
-        value @ Value::Null(_) => value,
-        _ => value_null!("incompatible types in addition: {}({}) + {}({})", lhv, lhv.type_of(), rhv, rhv.type_of()),
-      },
-      Value::String(mut lh) => {
-        if let Value::String(rh) = rhv {
-          lh.push_str(&rh);
-          Value::String(lh)
+  Ok(Box::new(move |scope: &FeelScope| eval_add(lhe(scope), rhe(scope))))
+}
+
+/// Evaluates addition.
+pub(crate) fn eval_add(lhv: Value, rhv: Value) -> Value {
+  match lhv {
+    Value::Number(lh) => match rhv {
+      Value::Number(rh) => number_or_null!("addition", lh.add_with_status(&rh)),
+      value @ Value::Null(_) => value,
+      _ => value_null!("incompatible types in addition: {}({}) + {}({})", lhv, lhv.type_of(), rhv, rhv.type_of()),
+    },
+    Value::String(mut lh) => {
+      if let Value::String(rh) = rhv {
+        lh.push_str(&rh);
+        Value::String(lh)
+      } else {
+        value_null!("expected string as a second argument in addition")
+      }
+    }
+    Value::Date(lh) => match rhv {
+      Value::DaysAndTimeDuration(rh) => {
+        if let Some(result) = lh + rh {
+          Value::Date(result)
         } else {
-          value_null!("expected string as a second argument in addition")
+          value_null!("invalid result while adding days and time duration to date")
         }
       }
-      Value::Date(lh) => match rhv {
-        Value::DaysAndTimeDuration(rh) => {
-          if let Some(result) = lh + rh {
-            Value::Date(result)
-          } else {
-            value_null!("invalid result while adding days and time duration to date")
-          }
-        }
-        Value::YearsAndMonthsDuration(rh) => {
-          if let Some(a) = lh + rh {
-            Value::Date(a)
-          } else {
-            value_null!("invalid result while adding years and months duration to date")
-          }
+      Value::YearsAndMonthsDuration(rh) => {
+        if let Some(a) = lh + rh {
+          Value::Date(a)
+        } else {
+          value_null!("invalid result while adding years and months duration to date")
         }
-        other => invalid_argument_type!("add", "years and months duration", other.type_of()),
-      },
-      Value::DateTime(lh) => match rhv {
-        Value::DaysAndTimeDuration(rh) => {
-          if let Some(a) = lh + rh {
-            Value::DateTime(a)
-          } else {
-            value_null!("invalid result while adding days and time duration to date and time")
-          }
+      }
+      other => invalid_argument_type!("add", "years and months duration", other.type_of()),
+    },
+    Value::DateTime(lh) => match rhv {
+      Value::DaysAndTimeDuration(rh) => {
+        if let Some(a) = lh + rh {
+          Value::DateTime(a)
+        } else {
+          value_null!("invalid result while adding days and time duration to date and time")
         }
-        Value::YearsAndMonthsDuration(rh) => {
-          if let Some(a) = lh + rh {
-            Value::DateTime(a)
-          } else {
-            value_null!("invalid result while adding years and months duration to date and time")
-          }
+      }
+      Value::YearsAndMonthsDuration(rh) => {
+        if let Some(a) = lh + rh {
+          Value::DateTime(a)
+        } else {
+          value_null!("invalid result while adding years and months duration to date and time")
         }
-        other => invalid_argument_type!("add", "days and time duration, years and months duration", other.type_of()),
-      },
-      Value::Time(lh) => match rhv {
-        Value::DaysAndTimeDuration(rh) => Value::Time(lh + rh),
-        other => invalid_argument_type!("add", "days and time duration", other.type_of()),
-      },
-      Value::DaysAndTimeDuration(lh) => match rhv {
-        Value::DaysAndTimeDuration(rh) => Value::DaysAndTimeDuration(lh + rh),
-        Value::Date(rh) => {
-          if let Some(result) = rh + lh {
-            Value::Date(result)
-          } else {
-            value_null!("invalid result while adding date to days and time duration")
-          }
+      }
+      other => invalid_argument_type!("add", "days and time duration, years and months duration", other.type_of()),
+    },
+    Value::Time(lh) => match rhv {
+      Value::DaysAndTimeDuration(rh) => Value::Time(lh + rh),
+      other => invalid_argument_type!("add", "days and time duration", other.type_of()),
+    },
+    Value::DaysAndTimeDuration(lh) => match rhv {
+      Value::DaysAndTimeDuration(rh) => Value::DaysAndTimeDuration(lh + rh),
+      Value::Date(rh) => {
+        if let Some(result) = rh + lh {
+          Value::Date(result)
+        } else {
+          value_null!("invalid result while adding date to days and time duration")
         }
-        Value::DateTime(rh) => {
-          if let Some(a) = rh + lh {
-            Value::DateTime(a)
-          } else {
-            value_null!("invalid result while adding date and time to days and time duration")
-          }
+      }
+      Value::DateTime(rh) => {
+        if let Some(a) = rh + lh {
+          Value::DateTime(a)
+        } else {
+          value_null!("invalid result while adding date and time to days and time duration")
         }
-        Value::Time(rh) => Value::Time(rh + lh),
-        other => invalid_argument_type!("add", "days and time duration, date and time", other.type_of()),
-      },
-      Value::YearsAndMonthsDuration(lh) => match rhv {
-        Value::Date(rh) => {
-          if let Some(a) = rh + lh {
-            Value::Date(a)
-          } else {
-            value_null!("invalid result while adding date to years and months duration")
-          }
+      }
+      Value::Time(rh) => Value::Time(rh + lh),
+      other => invalid_argument_type!("add", "days and time duration, date and time", other.type_of()),
+    },
+    Value::YearsAndMonthsDuration(lh) => match rhv {
+      Value::Date(rh) => {
+        if let Some(a) = rh + lh {
+          Value::Date(a)
+        } else {
+          value_null!("invalid result while adding date to years and months duration")
         }
-        Value::DateTime(rh) => {
-          if let Some(a) = rh + lh {
-            Value::DateTime(a)
-          } else {
-            value_null!("invalid result while adding date and time to years and months duration")
-          }
+      }
+      Value::DateTime(rh) => {
+        if let Some(a) = rh + lh {
+          Value::DateTime(a)
+        } else {
+          value_null!("invalid result while adding date and time to years and months duration")
         }
-        Value::YearsAndMonthsDuration(rh) => Value::YearsAndMonthsDuration(lh + rh),
-        other => invalid_argument_type!("add", "years and months duration, date and time", other.type_of()),
-      },
-      value @ Value::Null(_) => value,
-      other => invalid_argument_type!(
-        "add",
-        "number, string, date and time, days and time duration, years and months duration, null",
-        other.type_of()
-      ),
-    }
-  }))
+      }
+      Value::YearsAndMonthsDuration(rh) => Value::YearsAndMonthsDuration(lh + rh),
+      other => invalid_argument_type!("add", "years and months duration, date and time", other.type_of()),
+    },
+    value @ Value::Null(_) => value,
+    other => invalid_argument_type!(
+      "add",
+      "number, string, date and time, days and time duration, years and months duration, null",
+      other.type_of()
+    ),
+  }
 }
 
 /// Builds evaluator of temporal expression after `@` (at) literal.
@@ -277,91 +279,91 @@ fn build_between(lhs: &AstNode, mhs: &AstNode, rhs: &AstNode) -> Result<Evaluato
   let lhe = build_evaluator(lhs)?;
   let mhe = build_evaluator(mhs)?;
   let rhe = build_evaluator(rhs)?;
-  Ok(Box::new(move |scope: &FeelScope| {
-    let lhv = lhe(scope) as Value;
-    let mhv = mhe(scope) as Value;
-    let rhv = rhe(scope) as Value;
-    match lhv {
-      Value::Number(lh) => {
-        if let Value::Number(mh) = mhv {
-          if let Value::Number(rh) = rhv {
-            Value::Boolean(mh <= lh && lh <= rh)
-          } else {
-            between_null3!("number", rhv.type_of())
-          }
+  Ok(Box::new(move |scope: &FeelScope| eval_between(lhe(scope), mhe(scope), rhe(scope))))
+}
+
+/// Evaluates `between` operator.
+pub(crate) fn eval_between(lhv: Value, mhv: Value, rhv: Value) -> Value {
+  match lhv {
+    Value::Number(lh) => {
+      if let Value::Number(mh) = mhv {
+        if let Value::Number(rh) = rhv {
+          Value::Boolean(mh <= lh && lh <= rh)
         } else {
-          between_null2!("number", mhv.type_of())
+          between_null3!("number", rhv.type_of())
         }
+      } else {
+        between_null2!("number", mhv.type_of())
       }
-      Value::String(lh) => {
-        if let Value::String(mh) = mhv {
-          if let Value::String(rh) = rhv {
-            Value::Boolean(mh <= lh && lh <= rh)
-          } else {
-            between_null3!("string", rhv.type_of())
-          }
+    }
+    Value::String(lh) => {
+      if let Value::String(mh) = mhv {
+        if let Value::String(rh) = rhv {
+          Value::Boolean(mh <= lh && lh <= rh)
         } else {
-          between_null2!("string", mhv.type_of())
+          between_null3!("string", rhv.type_of())
         }
+      } else {
+        between_null2!("string", mhv.type_of())
       }
-      Value::Date(lh) => {
-        if let Value::Date(mh) = mhv {
-          if let Value::Date(rh) = rhv {
-            Value::Boolean(mh <= lh && lh <= rh)
-          } else {
-            between_null3!("date", rhv.type_of())
-          }
+    }
+    Value::Date(lh) => {
+      if let Value::Date(mh) = mhv {
+        if let Value::Date(rh) = rhv {
+          Value::Boolean(mh <= lh && lh <= rh)
         } else {
-          between_null2!("date", mhv.type_of())
+          between_null3!("date", rhv.type_of())
         }
+      } else {
+        between_null2!("date", mhv.type_of())
       }
-      Value::Time(lh) => {
-        if let Value::Time(mh) = mhv {
-          if let Value::Time(rh) = rhv {
-            Value::Boolean(mh <= lh && lh <= rh)
-          } else {
-            between_null3!("time", rhv.type_of())
-          }
+    }
+    Value::Time(lh) => {
+      if let Value::Time(mh) = mhv {
+        if let Value::Time(rh) = rhv {
+          Value::Boolean(mh <= lh && lh <= rh)
         } else {
-          between_null2!("time", mhv.type_of())
+          between_null3!("time", rhv.type_of())
         }
+      } else {
+        between_null2!("time", mhv.type_of())
       }
-      Value::DateTime(lh) => {
-        if let Value::DateTime(mh) = mhv {
-          if let Value::DateTime(rh) = rhv {
-            Value::Boolean(mh <= lh && lh <= rh)
-          } else {
-            between_null3!("date and time", rhv.type_of())
-          }
+    }
+    Value::DateTime(lh) => {
+      if let Value::DateTime(mh) = mhv {
+        if let Value::DateTime(rh) = rhv {
+          Value::Boolean(mh <= lh && lh <= rh)
         } else {
-          between_null2!("date and time", mhv.type_of())
+          between_null3!("date and time", rhv.type_of())
         }
+      } else {
+        between_null2!("date and time", mhv.type_of())
       }
-      Value::DaysAndTimeDuration(lh) => {
-        if let Value::DaysAndTimeDuration(mh) = mhv {
-          if let Value::DaysAndTimeDuration(rh) = rhv {
-            Value::Boolean(mh <= lh && lh <= rh)
-          } else {
-            between_null3!("days and time duration", rhv.type_of())
-          }
+    }
+    Value::DaysAndTimeDuration(lh) => {
+      if let Value::DaysAndTimeDuration(mh) = mhv {
+        if let Value::DaysAndTimeDuration(rh) = rhv {
+          Value::Boolean(mh <= lh && lh <= rh)
         } else {
-          between_null2!("days and time duration", mhv.type_of())
+          between_null3!("days and time duration", rhv.type_of())
         }
+      } else {
+        between_null2!("days and time duration", mhv.type_of())
       }
-      Value::YearsAndMonthsDuration(lh) => {
-        if let Value::YearsAndMonthsDuration(mh) = mhv {
-          if let Value::YearsAndMonthsDuration(rh) = rhv {
-            Value::Boolean(mh <= lh && lh <= rh)
-          } else {
-            between_null3!("years and months duration", rhv.type_of())
-          }
+    }
+    Value::YearsAndMonthsDuration(lh) => {
+      if let Value::YearsAndMonthsDuration(mh) = mhv {
+        if let Value::YearsAndMonthsDuration(rh) = rhv {
+          Value::Boolean(mh <= lh && lh <= rh)
         } else {
-          between_null2!("years and months duration", mhv.type_of())
+          between_null3!("years and months duration", rhv.type_of())
         }
+      } else {
+        between_null2!("years and months duration", mhv.type_of())
       }
-      other => value_null!("unexpected value type in 'between' operator: {}", other.type_of()),
     }
-  }))
+    other => value_null!("unexpected value type in 'between' operator: {}", other.type_of()),
+  }
 }
 
 ///
@@ -386,32 +388,33 @@ fn build_boolean(lhs: bool) -> Result<Evaluator> {
 fn build_and(lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
   let lhe = build_evaluator(lhs)?;
   let rhe = build_evaluator(rhs)?;
-  Ok(Box::new(move |scope: &FeelScope| {
-    let lhv = lhe(scope) as Value;
-    let rhv = rhe(scope) as Value;
-    match lhv {
-      Value::Boolean(lh) => match rhv {
-        Value::Boolean(rh) => Value::Boolean(lh && rh),
-        _ => {
-          if lh {
-            value_null!()
-          } else {
-            Value::Boolean(false)
-          }
+  Ok(Box::new(move |scope: &FeelScope| eval_and(lhe(scope), rhe(scope))))
+}
+
+/// Evaluates conjunction.
+pub(crate) fn eval_and(lhv: Value, rhv: Value) -> Value {
+  match lhv {
+    Value::Boolean(lh) => match rhv {
+      Value::Boolean(rh) => Value::Boolean(lh && rh),
+      _ => {
+        if lh {
+          value_null!()
+        } else {
+          Value::Boolean(false)
         }
-      },
-      _ => match rhv {
-        Value::Boolean(rh) => {
-          if rh {
-            value_null!()
-          } else {
-            Value::Boolean(false)
-          }
+      }
+    },
+    _ => match rhv {
+      Value::Boolean(rh) => {
+        if rh {
+          value_null!()
+        } else {
+          Value::Boolean(false)
         }
-        _ => value_null!(),
-      },
-    }
-  }))
+      }
+      _ => value_null!(),
+    },
+  }
 }
 
 ///
@@ -519,71 +522,72 @@ fn build_context_type_entry_key(lhs: &Name) -> Result<Evaluator> {
 fn build_div(lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
   let lhe = build_evaluator(lhs)?;
   let rhe = build_evaluator(rhs)?;
-  Ok(Box::new(move |scope: &FeelScope| {
-    let lhv = lhe(scope) as Value;
-    let rhv = rhe(scope) as Value;
-    match lhv {
-      Value::Number(lh) => match rhv {
-        Value::Number(rh) => {
-          if rh.abs() == FeelNumber::zero() {
-            value_null!("[division] division by zero")
-          } else {
-            Value::Number(lh / rh)
-          }
+  Ok(Box::new(move |scope: &FeelScope| eval_div(lhe(scope), rhe(scope))))
+}
+
+/// Evaluates division.
+pub(crate) fn eval_div(lhv: Value, rhv: Value) -> Value {
+  match lhv {
+    Value::Number(lh) => match rhv {
+      Value::Number(rh) => {
+        if rh.abs() == FeelNumber::zero() {
+          value_null!("[division] division by zero")
+        } else {
+          number_or_null!("division", lh.div_with_status(&rh))
         }
-        _ => value_null!("[division] incompatible types: {} / {}", lhv, rhv),
-      },
-      Value::DaysAndTimeDuration(ref lh) => match rhv {
-        Value::Number(rh) => {
-          if rh.is_zero() {
-            value_null!("[division] division by zero")
-          } else {
-            let lv = FeelNumber::from(lh.as_nanos()) / rh;
-            if let Ok(v) = FeelNumber::try_into(lv) {
-              Value::DaysAndTimeDuration(FeelDaysAndTimeDuration::from_n(v))
-            } else {
-              value_null!("[division] error: {} / {}", lhv, rhv)
-            }
+      }
+      _ => value_null!("[division] incompatible types: {} / {}", lhv, rhv),
+    },
+    Value::DaysAndTimeDuration(ref lh) => match rhv {
+      Value::Number(rh) => {
+        if rh.is_zero() {
+          value_null!("[division] division by zero")
+        } else {
+          let (lv, status) = FeelNumber::from(lh.as_nanos()).div_with_status(&rh);
+          match status.check().map(|_| FeelNumber::try_into(lv)) {
+            Ok(Ok(v)) => Value::DaysAndTimeDuration(FeelDaysAndTimeDuration::from_n(v)),
+            Ok(Err(_)) => value_null!("[division] error: {} / {}", lhv, rhv),
+            Err(reason) => value_null!("[division] {}", reason),
           }
         }
-        Value::DaysAndTimeDuration(rh) => {
-          if rh.as_nanos() == 0 {
-            value_null!("[division] division by zero")
-          } else {
-            let lvl = FeelNumber::from(lh.as_nanos());
-            let rvl = FeelNumber::from(rh.as_nanos());
-            Value::Number(lvl / rvl)
-          }
+      }
+      Value::DaysAndTimeDuration(rh) => {
+        if rh.as_nanos() == 0 {
+          value_null!("[division] division by zero")
+        } else {
+          let lvl = FeelNumber::from(lh.as_nanos());
+          let rvl = FeelNumber::from(rh.as_nanos());
+          number_or_null!("division", lvl.div_with_status(&rvl))
         }
-        _ => value_null!("[division] incompatible types: {} / {}", lhv, rhv),
-      },
-      Value::YearsAndMonthsDuration(ref lh) => match rhv {
-        Value::Number(rh) => {
-          if rh.is_zero() {
-            value_null!("[division] division by zero")
-          } else {
-            let vl = FeelNumber::from(lh.as_months()) / rh;
-            if let Ok(v) = FeelNumber::try_into(vl) {
-              Value::YearsAndMonthsDuration(FeelYearsAndMonthsDuration::from_m(v))
-            } else {
-              value_null!("[division] error: {} / {}", lhv, rhv)
-            }
+      }
+      _ => value_null!("[division] incompatible types: {} / {}", lhv, rhv),
+    },
+    Value::YearsAndMonthsDuration(ref lh) => match rhv {
+      Value::Number(rh) => {
+        if rh.is_zero() {
+          value_null!("[division] division by zero")
+        } else {
+          let (vl, status) = FeelNumber::from(lh.as_months()).div_with_status(&rh);
+          match status.check().map(|_| FeelNumber::try_into(vl)) {
+            Ok(Ok(v)) => Value::YearsAndMonthsDuration(FeelYearsAndMonthsDuration::from_m(v)),
+            Ok(Err(_)) => value_null!("[division] error: {} / {}", lhv, rhv),
+            Err(reason) => value_null!("[division] {}", reason),
           }
         }
-        Value::YearsAndMonthsDuration(rh) => {
-          if rh.as_months() == 0 {
-            value_null!("[division] division by zero")
-          } else {
-            let lvl = FeelNumber::from(lh.as_months());
-            let rvl = FeelNumber::from(rh.as_months());
-            Value::Number(lvl / rvl)
-          }
+      }
+      Value::YearsAndMonthsDuration(rh) => {
+        if rh.as_months() == 0 {
+          value_null!("[division] division by zero")
+        } else {
+          let lvl = FeelNumber::from(lh.as_months());
+          let rvl = FeelNumber::from(rh.as_months());
+          number_or_null!("division", lvl.div_with_status(&rvl))
         }
-        _ => value_null!("[division] incompatible types: {} / {}", lhv, rhv),
-      },
+      }
       _ => value_null!("[division] incompatible types: {} / {}", lhv, rhv),
-    }
-  }))
+    },
+    _ => value_null!("[division] incompatible types: {} / {}", lhv, rhv),
+  }
 }
 
 ///
@@ -605,23 +609,27 @@ fn build_expression_list(lhs: &[AstNode]) -> Result<Evaluator> {
 fn build_exp(lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
   let lhe = build_evaluator(lhs)?;
   let rhe = build_evaluator(rhs)?;
-  Ok(Box::new(move |scope: &FeelScope| {
-    let lhv = lhe(scope) as Value;
-    let rhv = rhe(scope) as Value;
-    if let Value::Number(lh) = lhv {
-      if let Value::Number(rh) = rhv {
-        if let Some(result) = lh.pow(&rh) {
-          Value::Number(result)
-        } else {
-          value_null!("exponentiation result is not a finite number")
-        }
+  Ok(Box::new(move |scope: &FeelScope| eval_exp(lhe(scope), rhe(scope))))
+}
+
+/// Evaluates exponentiation.
+pub(crate) fn eval_exp(lhv: Value, rhv: Value) -> Value {
+  if let Value::Number(lh) = lhv {
+    if let Value::Number(rh) = rhv {
+      let (result, status) = lh.pow_with_status(&rh);
+      if let Err(reason) = status.check() {
+        value_null!("[exponentiation] {}", reason)
+      } else if result.is_finite() {
+        Value::Number(result)
       } else {
-        value_null!("exponentiation exponent is not a number")
+        value_null!("exponentiation result is not a finite number")
       }
     } else {
-      value_null!("exponentiation base is not a number")
+      value_null!("exponentiation exponent is not a number")
     }
-  }))
+  } else {
+    value_null!("exponentiation base is not a number")
+  }
 }
 
 ///
@@ -634,120 +642,131 @@ fn build_feel_type(lhs: &FeelType) -> Result<Evaluator> {
 fn build_filter(lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
   let lhe = build_evaluator(lhs)?;
   let rhe = build_evaluator(rhs)?;
-  let name_item: Name = "item".into();
   Ok(Box::new(move |scope: &FeelScope| {
-    let lhv = lhe(scope) as Value;
-    match lhv {
-      Value::List(values) => {
-        let mut filtered_values = vec![];
-        for value in &values {
-          let (added_local_context, has_item_entry) = if let Value::Context(local_context) = value {
-            scope.push(local_context.clone());
-            if local_context.contains_entry(&name_item) {
-              (true, true)
-            } else {
-              (true, false)
-            }
-          } else {
-            (false, false)
-          };
-          if !has_item_entry {
-            let mut special_context = FeelContext::default();
-            special_context.set_entry(&name_item, value.clone());
-            scope.push(special_context);
-          }
-          let rhv = rhe(scope) as Value;
-          if let Value::Boolean(true) = rhv {
-            filtered_values.push(value.clone());
-          }
-          if !has_item_entry {
-            scope.pop();
-          }
-          if added_local_context {
-            scope.pop();
-          }
-        }
+    eval_filter(lhe(scope), |item| {
+      if let Some(value) = item {
+        let count = push_item(scope, value);
         let rhv = rhe(scope) as Value;
-        match rhv {
-          Value::Number(index) => {
-            if index.is_integer() {
-              let list_size = values.len();
-              if list_size > 0 {
-                if !index.is_negative() {
-                  let n = {
-                    if let Ok(u_index) = usize::try_from(index) {
-                      u_index
-                    } else {
-                      return value_null!("index is out of range 1..2⁶⁴: {}", index.to_string());
-                    }
-                  };
-                  if n > 0 && n <= list_size {
-                    // unwrap below is safe, index `n` is checked above, `values` variable is immutable
-                    values.get(n - 1).unwrap().to_owned()
+        for _ in 0..count {
+          scope.pop();
+        }
+        rhv
+      } else {
+        rhe(scope)
+      }
+    })
+  }))
+}
+
+/// Pushes the contexts with names available in filter condition for specified list item,
+/// returns the number of contexts pushed to scope.
+pub(crate) fn push_item(scope: &FeelScope, item: &Value) -> usize {
+  let name_item: Name = "item".into();
+  let mut count = 0;
+  if let Value::Context(local_context) = item {
+    scope.push(local_context.clone());
+    count += 1;
+    if local_context.contains_entry(&name_item) {
+      return count;
+    }
+  }
+  let mut special_context = FeelContext::default();
+  special_context.set_entry(&name_item, item.clone());
+  scope.push(special_context);
+  count + 1
+}
+
+/// Evaluates a filter on specified value, the condition is evaluated for every list item,
+/// and once without an item, to check if it is an index.
+pub(crate) fn eval_filter(lhv: Value, mut condition: impl FnMut(Option<&Value>) -> Value) -> Value {
+  match lhv {
+    Value::List(values) => {
+      let mut filtered_values = vec![];
+      for value in &values {
+        if let Value::Boolean(true) = condition(Some(value)) {
+          filtered_values.push(value.clone());
+        }
+      }
+      let rhv = condition(None);
+      match rhv {
+        Value::Number(index) => {
+          if index.is_integer() {
+            let list_size = values.len();
+            if list_size > 0 {
+              if !index.is_negative() {
+                let n = {
+                  if let Ok(u_index) = usize::try_from(index) {
+                    u_index
                   } else {
-                    value_null!("index in filter is out of range [1..{}], actual index is {}", list_size, n)
+                    return value_null!("index is out of range 1..2⁶⁴: {}", index.to_string());
                   }
+                };
+                if n > 0 && n <= list_size {
+                  // unwrap below is safe, index `n` is checked above, `values` variable is immutable
+                  values.get(n - 1).unwrap().to_owned()
                 } else {
-                  let n = {
-                    if let Ok(u_index) = usize::try_from(index.abs()) {
-                      u_index
-                    } else {
-                      return value_null!("index is out of range 1..2⁶⁴: {}", index.to_string());
-                    }
-                  };
-                  if n > 0 && n <= list_size {
-                    // unwrap below is safe, index `n` is checked above, `values` variable is immutable
-                    values.get(list_size - n).unwrap().to_owned()
+                  value_null!("index in filter is out of range [1..{}], actual index is {}", list_size, n)
+                }
+              } else {
+                let n = {
+                  if let Ok(u_index) = usize::try_from(index.abs()) {
+                    u_index
                   } else {
-                    value_null!("index in filter is out of range [-{}..-1], actual index is -{}", list_size, n)
+                    return value_null!("index is out of range 1..2⁶⁴: {}", index.to_string());
                   }
+                };
+                if n > 0 && n <= list_size {
+                  // unwrap below is safe, index `n` is checked above, `values` variable is immutable
+                  values.get(list_size - n).unwrap().to_owned()
+                } else {
+                  value_null!("index in filter is out of range [-{}..-1], actual index is -{}", list_size, n)
                 }
-              } else {
-                // return null when the list is empty, no matter what value the index has
-                value_null!()
               }
             } else {
-              value_null!("index in filter must be an integer value, actual value is {}", index)
+              // return null when the list is empty, no matter what value the index has
+              value_null!()
             }
-          }
-          _ => {
-            // coerse the result list
-            if filtered_values.len() == 1 {
-              filtered_values[0].to_owned()
-            } else {
-              Value::List(filtered_values)
-            }
-          }
-        }
-      }
-      v @ Value::Number(_)
-      | v @ Value::Boolean(_)
-      | v @ Value::String(_)
-      | v @ Value::Date(_)
-      | v @ Value::DateTime(_)
-      | v @ Value::Time(_)
-      | v @ Value::DaysAndTimeDuration(_)
-      | v @ Value::YearsAndMonthsDuration(_)
-      | v @ Value::Context(_) => match rhe(scope) {
-        Value::Boolean(flag) => {
-          if flag {
-            Value::List(vec![v])
           } else {
-            Value::List(Values::default())
+            value_null!("index in filter must be an integer value, actual value is {}", index)
           }
         }
-        Value::Number(num) => {
-          if num.is_one() || (-num).is_one() {
-            v
+        _ => {
+          // coerse the result list
+          if filtered_values.len() == 1 {
+            filtered_values[0].to_owned()
           } else {
-            value_null!("for singletons, only filter index with value 1 or -1 is accepted")
+            Value::List(filtered_values)
           }
         }
-        _ => value_null!("only number or boolean indexes are allowed in filters"),
-      },
-      other => value_null!("unexpected value type in filter: {}", other.type_of()),
+      }
     }
-  }))
+    v @ Value::Number(_)
+    | v @ Value::Boolean(_)
+    | v @ Value::String(_)
+    | v @ Value::Date(_)
+    | v @ Value::DateTime(_)
+    | v @ Value::Time(_)
+    | v @ Value::DaysAndTimeDuration(_)
+    | v @ Value::YearsAndMonthsDuration(_)
+    | v @ Value::Context(_) => match condition(None) {
+      Value::Boolean(flag) => {
+        if flag {
+          Value::List(vec![v])
+        } else {
+          Value::List(Values::default())
+        }
+      }
+      Value::Number(num) => {
+        if num.is_one() || (-num).is_one() {
+          v
+        } else {
+          value_null!("for singletons, only filter index with value 1 or -1 is accepted")
+        }
+      }
+      _ => value_null!("only number or boolean indexes are allowed in filters"),
+    },
+    other => value_null!("unexpected value type in filter: {}", other.type_of()),
+  }
 }
 
 ///
@@ -901,13 +920,7 @@ fn build_function_definition(lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator>
     match lhv {
       Value::FormalParameters(parameters) => {
         if let Value::FunctionBody(body, external) = rhv {
-          // evaluate closure context
-          let mut closure_ctx = FeelContext::default();
-          for closure_name in closure.iter() {
-            if let Some(closure_value) = scope.search_entry(closure_name) {
-              closure_ctx.create_entry(closure_name, closure_value);
-            }
-          }
+          let closure_ctx = eval_closure_context(scope, &closure);
           //TODO is `FeelType::Any` always ok for function result type in function definition?
           Value::FunctionDefinition(parameters, body, external, closure.clone(), closure_ctx, FeelType::Any)
         } else {
@@ -920,19 +933,32 @@ fn build_function_definition(lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator>
   }))
 }
 
+/// Evaluates the closure context of a function definition, containing values of names
+/// used in the body of the function, that are available in scope.
+pub(crate) fn eval_closure_context(scope: &FeelScope, closure: &Closure) -> FeelContext {
+  let mut closure_ctx = FeelContext::default();
+  for closure_name in closure.iter() {
+    if let Some(closure_value) = scope.search_entry(closure_name) {
+      closure_ctx.create_entry(closure_name, closure_value);
+    }
+  }
+  closure_ctx
+}
+
 ///
 fn build_eq(lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
   let lhe = build_evaluator(lhs)?;
   let rhe = build_evaluator(rhs)?;
-  Ok(Box::new(move |scope: &FeelScope| {
-    let lhv = lhe(scope) as Value;
-    let rhv = rhe(scope) as Value;
-    if let Some(result) = eval_ternary_equality(&lhv, &rhv) {
-      Value::Boolean(result)
-    } else {
-      value_null!("equal err '{}' =?= '{}'", lhv, rhv)
-    }
-  }))
+  Ok(Box::new(move |scope: &FeelScope| eval_eq(&lhe(scope), &rhe(scope))))
+}
+
+/// Evaluates `equal` comparison.
+pub(crate) fn eval_eq(lhv: &Value, rhv: &Value) -> Value {
+  if let Some(result) = eval_ternary_equality(lhv, rhv) {
+    Value::Boolean(result)
+  } else {
+    value_null!("equal err '{}' =?= '{}'", lhv, rhv)
+  }
 }
 
 ///
@@ -989,18 +1015,23 @@ fn build_function_invocation_with_positional_parameters(lhs: &AstNode, rhs: &[As
   Ok(Box::new(move |scope: &FeelScope| {
     let function = function_evaluator(scope) as Value;
     let args = argument_evaluators.iter().map(|evaluator| evaluator(scope)).collect::<Vec<Value>>();
-    match function {
-      Value::BuiltInFunction(bif) => bifs::positional::evaluate_bif(bif, &args),
-      Value::FunctionDefinition(params, body, external, _, closure_ctx, result_type) => {
-        if external {
-          eval_external_function_with_positional_parameters(scope, &args, &params, &body, result_type)
-        } else {
-          eval_function_with_positional_parameters(scope, &args, &params, &body, closure_ctx, result_type)
-        }
+    eval_function_invocation_with_positional_parameters(scope, function, &args)
+  }))
+}
+
+/// Evaluates an invocation of a function with positional parameters.
+pub(crate) fn eval_function_invocation_with_positional_parameters(scope: &FeelScope, function: Value, args: &[Value]) -> Value {
+  match function {
+    Value::BuiltInFunction(bif) => bifs::positional::evaluate_bif(bif, args),
+    Value::FunctionDefinition(params, body, external, _, closure_ctx, result_type) => {
+      if external {
+        eval_external_function_with_positional_parameters(scope, args, &params, &body, result_type)
+      } else {
+        eval_function_with_positional_parameters(scope, args, &params, &body, closure_ctx, result_type)
       }
-      _ => value_null!("expected built-in function name or function definition, actual is {}", function),
     }
-  }))
+    _ => value_null!("expected built-in function name or function definition, actual is {}", function),
+  }
 }
 
 ///
@@ -1010,18 +1041,23 @@ fn build_function_invocation_with_named_parameters(lhs: &AstNode, rhs: &AstNode)
   Ok(Box::new(move |scope: &FeelScope| {
     let function = function_evaluator(scope) as Value;
     let args = arguments_evaluator(scope) as Value;
-    match function {
-      Value::BuiltInFunction(bif) => bifs::named::evaluate_bif(bif, &args),
-      Value::FunctionDefinition(params, body, external, _, closure_ctx, result_type) => {
-        if external {
-          eval_external_function_with_named_parameters(scope, &args, &params, &body, result_type)
-        } else {
-          eval_function_with_named_parameters(scope, &args, &params, &body, closure_ctx, result_type)
-        }
+    eval_function_invocation_with_named_parameters(scope, function, &args)
+  }))
+}
+
+/// Evaluates an invocation of a function with named parameters.
+pub(crate) fn eval_function_invocation_with_named_parameters(scope: &FeelScope, function: Value, args: &Value) -> Value {
+  match function {
+    Value::BuiltInFunction(bif) => bifs::named::evaluate_bif(bif, args),
+    Value::FunctionDefinition(params, body, external, _, closure_ctx, result_type) => {
+      if external {
+        eval_external_function_with_named_parameters(scope, args, &params, &body, result_type)
+      } else {
+        eval_function_with_named_parameters(scope, args, &params, &body, closure_ctx, result_type)
       }
-      _ => value_null!("expected built-in function name or function definition, actual is {}", function),
     }
-  }))
+    _ => value_null!("expected built-in function name or function definition, actual is {}", function),
+  }
 }
 
 ///
@@ -1051,82 +1087,84 @@ fn build_function_type(lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
 fn build_ge(lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
   let lhe = build_evaluator(lhs)?;
   let rhe = build_evaluator(rhs)?;
-  Ok(Box::new(move |scope: &FeelScope| {
-    let lhv = lhe(scope);
-    let rhv = rhe(scope);
-    match lhv {
-      Value::Number(lh) => match rhv {
-        Value::Number(rh) => Value::Boolean(lh >= rh),
-        _ => value_null!("eval_greater_or_equal_number"),
-      },
-      Value::String(lh) => match rhv {
-        Value::String(rh) => Value::Boolean(lh >= rh),
-        _ => value_null!("eval_greater_or_equal_string"),
-      },
-      Value::Date(lh) => match rhv {
-        Value::Date(rh) => Value::Boolean(lh >= rh),
-        _ => value_null!("eval_greater_or_equal_date"),
-      },
-      Value::DateTime(lh) => match rhv {
-        Value::DateTime(rh) => Value::Boolean(lh >= rh),
-        _ => value_null!("eval_greater_or_equal_date_time"),
-      },
-      Value::Time(lh) => match rhv {
-        Value::Time(rh) => Value::Boolean(lh >= rh),
-        _ => value_null!("eval_greater_or_equal_time"),
-      },
-      Value::DaysAndTimeDuration(lh) => match rhv {
-        Value::DaysAndTimeDuration(rh) => Value::Boolean(lh >= rh),
-        _ => value_null!("eval_greater_or_equal_days_and_time_duration"),
-      },
-      Value::YearsAndMonthsDuration(lh) => match rhv {
-        Value::YearsAndMonthsDuration(rh) => Value::Boolean(lh >= rh),
-        _ => value_null!("eval_greater_or_equal_years_and_months_duration"),
-      },
-      _ => value_null!("eval_greater_or_equal"),
-    }
-  }))
+  Ok(Box::new(move |scope: &FeelScope| eval_ge(&lhe(scope), &rhe(scope))))
+}
+
+/// Evaluates `greater or equal` comparison.
+pub(crate) fn eval_ge(lhv: &Value, rhv: &Value) -> Value {
+  match lhv {
+    Value::Number(lh) => match rhv {
+      Value::Number(rh) => Value::Boolean(lh >= rh),
+      _ => value_null!("eval_greater_or_equal_number"),
+    },
+    Value::String(lh) => match rhv {
+      Value::String(rh) => Value::Boolean(lh >= rh),
+      _ => value_null!("eval_greater_or_equal_string"),
+    },
+    Value::Date(lh) => match rhv {
+      Value::Date(rh) => Value::Boolean(lh >= rh),
+      _ => value_null!("eval_greater_or_equal_date"),
+    },
+    Value::DateTime(lh) => match rhv {
+      Value::DateTime(rh) => Value::Boolean(lh >= rh),
+      _ => value_null!("eval_greater_or_equal_date_time"),
+    },
+    Value::Time(lh) => match rhv {
+      Value::Time(rh) => Value::Boolean(lh >= rh),
+      _ => value_null!("eval_greater_or_equal_time"),
+    },
+    Value::DaysAndTimeDuration(lh) => match rhv {
+      Value::DaysAndTimeDuration(rh) => Value::Boolean(lh >= rh),
+      _ => value_null!("eval_greater_or_equal_days_and_time_duration"),
+    },
+    Value::YearsAndMonthsDuration(lh) => match rhv {
+      Value::YearsAndMonthsDuration(rh) => Value::Boolean(lh >= rh),
+      _ => value_null!("eval_greater_or_equal_years_and_months_duration"),
+    },
+    _ => value_null!("eval_greater_or_equal"),
+  }
 }
 
 ///
 fn build_gt(lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
   let lhe = build_evaluator(lhs)?;
   let rhe = build_evaluator(rhs)?;
-  Ok(Box::new(move |scope: &FeelScope| {
-    let lhv = lhe(scope) as Value;
-    let rhv = rhe(scope) as Value;
-    match lhv {
-      Value::Number(lh) => match rhv {
-        Value::Number(rh) => Value::Boolean(lh > rh),
-        _ => value_null!("eval_greater_then_number"),
-      },
-      Value::String(lh) => match rhv {
-        Value::String(rh) => Value::Boolean(lh > rh),
-        _ => value_null!("eval_greater_then_string"),
-      },
-      Value::Date(lh) => match rhv {
-        Value::Date(rh) => Value::Boolean(lh > rh),
-        _ => value_null!("eval_greater_then_date"),
-      },
-      Value::DateTime(lh) => match rhv {
-        Value::DateTime(rh) => Value::Boolean(lh > rh),
-        _ => value_null!("eval_greater_then_date_time"),
-      },
-      Value::Time(lh) => match rhv {
-        Value::Time(rh) => Value::Boolean(lh > rh),
-        _ => value_null!("eval_greater_then_time"),
-      },
-      Value::DaysAndTimeDuration(lh) => match rhv {
-        Value::DaysAndTimeDuration(rh) => Value::Boolean(lh > rh),
-        _ => value_null!("eval_greater_days_and_time_duration"),
-      },
-      Value::YearsAndMonthsDuration(lh) => match rhv {
-        Value::YearsAndMonthsDuration(rh) => Value::Boolean(lh > rh),
-        _ => value_null!("eval_greater_years_and_months_duration"),
-      },
-      _ => value_null!("eval_greater_then"),
-    }
-  }))
+  Ok(Box::new(move |scope: &FeelScope| eval_gt(&lhe(scope), &rhe(scope))))
+}
+
+/// Evaluates `greater than` comparison.
+pub(crate) fn eval_gt(lhv: &Value, rhv: &Value) -> Value {
+  match lhv {
+    Value::Number(lh) => match rhv {
+      Value::Number(rh) => Value::Boolean(lh > rh),
+      _ => value_null!("eval_greater_then_number"),
+    },
+    Value::String(lh) => match rhv {
+      Value::String(rh) => Value::Boolean(lh > rh),
+      _ => value_null!("eval_greater_then_string"),
+    },
+    Value::Date(lh) => match rhv {
+      Value::Date(rh) => Value::Boolean(lh > rh),
+      _ => value_null!("eval_greater_then_date"),
+    },
+    Value::DateTime(lh) => match rhv {
+      Value::DateTime(rh) => Value::Boolean(lh > rh),
+      _ => value_null!("eval_greater_then_date_time"),
+    },
+    Value::Time(lh) => match rhv {
+      Value::Time(rh) => Value::Boolean(lh > rh),
+      _ => value_null!("eval_greater_then_time"),
+    },
+    Value::DaysAndTimeDuration(lh) => match rhv {
+      Value::DaysAndTimeDuration(rh) => Value::Boolean(lh > rh),
+      _ => value_null!("eval_greater_days_and_time_duration"),
+    },
+    Value::YearsAndMonthsDuration(lh) => match rhv {
+      Value::YearsAndMonthsDuration(rh) => Value::Boolean(lh > rh),
+      _ => value_null!("eval_greater_years_and_months_duration"),
+    },
+    _ => value_null!("eval_greater_then"),
+  }
 }
 
 ///
@@ -1145,38 +1183,39 @@ fn build_if(lhs: &AstNode, mhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
 fn build_in(lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
   let lhe = build_evaluator(lhs)?;
   let rhe = build_evaluator(rhs)?;
-  Ok(Box::new(move |scope: &FeelScope| {
-    let lhv = lhe(scope) as Value;
-    let rhv = rhe(scope) as Value;
-    match rhv {
-      inner @ Value::Null(_)
-      | inner @ Value::Number(_)
-      | inner @ Value::String(_)
-      | inner @ Value::Boolean(_)
-      | inner @ Value::Date(_)
-      | inner @ Value::Time(_)
-      | inner @ Value::DateTime(_)
-      | inner @ Value::YearsAndMonthsDuration(_)
-      | inner @ Value::DaysAndTimeDuration(_)
-      | inner @ Value::Context(_) => eval_in_equal(&lhv, &inner),
-      Value::Range(l, l_closed, r, r_closed) => eval_in_range(&lhv, &l, l_closed, &r, r_closed),
-      Value::List(r_inner) => {
-        if let Value::List(l_inner) = lhv {
-          eval_in_list_in_list(&l_inner, &r_inner)
-        } else {
-          eval_in_list(&lhv, &r_inner)
-        }
+  Ok(Box::new(move |scope: &FeelScope| eval_in(&lhe(scope), &rhe(scope))))
+}
+
+/// Evaluates `in` operator.
+pub(crate) fn eval_in(lhv: &Value, rhv: &Value) -> Value {
+  match rhv {
+    inner @ Value::Null(_)
+    | inner @ Value::Number(_)
+    | inner @ Value::String(_)
+    | inner @ Value::Boolean(_)
+    | inner @ Value::Date(_)
+    | inner @ Value::Time(_)
+    | inner @ Value::DateTime(_)
+    | inner @ Value::YearsAndMonthsDuration(_)
+    | inner @ Value::DaysAndTimeDuration(_)
+    | inner @ Value::Context(_) => eval_in_equal(lhv, inner),
+    Value::Range(l, l_closed, r, r_closed) => eval_in_range(lhv, l, *l_closed, r, *r_closed),
+    Value::List(r_inner) => {
+      if let Value::List(l_inner) = lhv {
+        eval_in_list_in_list(l_inner, r_inner)
+      } else {
+        eval_in_list(lhv, r_inner)
       }
-      Value::ExpressionList(inner) => eval_in_list(&lhv, &inner),
-      Value::NegatedCommaList(inner) => eval_in_negated_list(&lhv, &inner),
-      Value::UnaryLess(inner) => eval_in_unary_less(&lhv, inner.borrow()),
-      Value::UnaryLessOrEqual(inner) => eval_in_unary_less_or_equal(&lhv, inner.borrow()),
-      Value::UnaryGreater(inner) => eval_in_unary_greater(&lhv, inner.borrow()),
-      Value::UnaryGreaterOrEqual(inner) => eval_in_unary_greater_or_equal(&lhv, inner.borrow()),
-      Value::Irrelevant => VALUE_TRUE,
-      _ => value_null!("unexpected argument type in 'in' operator: {}", rhv.type_of()),
     }
-  }))
+    Value::ExpressionList(inner) => eval_in_list(lhv, inner),
+    Value::NegatedCommaList(inner) => eval_in_negated_list(lhv, inner),
+    Value::UnaryLess(inner) => eval_in_unary_less(lhv, inner),
+    Value::UnaryLessOrEqual(inner) => eval_in_unary_less_or_equal(lhv, inner),
+    Value::UnaryGreater(inner) => eval_in_unary_greater(lhv, inner),
+    Value::UnaryGreaterOrEqual(inner) => eval_in_unary_greater_or_equal(lhv, inner),
+    Value::Irrelevant => VALUE_TRUE,
+    _ => value_null!("unexpected argument type in 'in' operator: {}", rhv.type_of()),
+  }
 }
 
 ///
@@ -1285,82 +1324,84 @@ fn build_instance_of(lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
 fn build_le(lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
   let lhe = build_evaluator(lhs)?;
   let rhe = build_evaluator(rhs)?;
-  Ok(Box::new(move |scope: &FeelScope| {
-    let lhv = lhe(scope);
-    let rhv = rhe(scope);
-    match lhv {
-      Value::Number(lh) => match rhv {
-        Value::Number(rh) => Value::Boolean(lh <= rh),
-        _ => value_null!("eval_less_or_equal_number"),
-      },
-      Value::String(lh) => match rhv {
-        Value::String(rh) => Value::Boolean(lh <= rh),
-        _ => value_null!("eval_less_or_equal_string"),
-      },
-      Value::Date(lh) => match rhv {
-        Value::Date(rh) => Value::Boolean(lh <= rh),
-        _ => value_null!("eval_less_or_equal_date"),
-      },
-      Value::DateTime(lh) => match rhv {
-        Value::DateTime(rh) => Value::Boolean(lh <= rh),
-        _ => value_null!("eval_less_or_equal_date_time"),
-      },
-      Value::Time(lh) => match rhv {
-        Value::Time(rh) => Value::Boolean(lh <= rh),
-        _ => value_null!("eval_less_or_equal_time"),
-      },
-      Value::DaysAndTimeDuration(lh) => match rhv {
-        Value::DaysAndTimeDuration(rh) => Value::Boolean(lh <= rh),
-        _ => value_null!("eval_less_or_equal_days_and_time_duration"),
-      },
-      Value::YearsAndMonthsDuration(lh) => match rhv {
-        Value::YearsAndMonthsDuration(rh) => Value::Boolean(lh <= rh),
-        _ => value_null!("eval_less_or_equal_years_and_months_duration"),
-      },
-      _ => value_null!("eval_less_or_equal"),
-    }
-  }))
+  Ok(Box::new(move |scope: &FeelScope| eval_le(&lhe(scope), &rhe(scope))))
+}
+
+/// Evaluates `less or equal` comparison.
+pub(crate) fn eval_le(lhv: &Value, rhv: &Value) -> Value {
+  match lhv {
+    Value::Number(lh) => match rhv {
+      Value::Number(rh) => Value::Boolean(lh <= rh),
+      _ => value_null!("eval_less_or_equal_number"),
+    },
+    Value::String(lh) => match rhv {
+      Value::String(rh) => Value::Boolean(lh <= rh),
+      _ => value_null!("eval_less_or_equal_string"),
+    },
+    Value::Date(lh) => match rhv {
+      Value::Date(rh) => Value::Boolean(lh <= rh),
+      _ => value_null!("eval_less_or_equal_date"),
+    },
+    Value::DateTime(lh) => match rhv {
+      Value::DateTime(rh) => Value::Boolean(lh <= rh),
+      _ => value_null!("eval_less_or_equal_date_time"),
+    },
+    Value::Time(lh) => match rhv {
+      Value::Time(rh) => Value::Boolean(lh <= rh),
+      _ => value_null!("eval_less_or_equal_time"),
+    },
+    Value::DaysAndTimeDuration(lh) => match rhv {
+      Value::DaysAndTimeDuration(rh) => Value::Boolean(lh <= rh),
+      _ => value_null!("eval_less_or_equal_days_and_time_duration"),
+    },
+    Value::YearsAndMonthsDuration(lh) => match rhv {
+      Value::YearsAndMonthsDuration(rh) => Value::Boolean(lh <= rh),
+      _ => value_null!("eval_less_or_equal_years_and_months_duration"),
+    },
+    _ => value_null!("eval_less_or_equal"),
+  }
 }
 
 ///
 fn build_lt(lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
   let lhe = build_evaluator(lhs)?;
   let rhe = build_evaluator(rhs)?;
-  Ok(Box::new(move |scope: &FeelScope| {
-    let lhv = lhe(scope);
-    let rhv = rhe(scope);
-    match lhv {
-      Value::Number(lh) => match rhv {
-        Value::Number(rh) => Value::Boolean(lh < rh),
-        _ => value_null!("eval_less_then_number"),
-      },
-      Value::String(lh) => match rhv {
-        Value::String(rh) => Value::Boolean(lh < rh),
-        _ => value_null!("eval_less_then_string"),
-      },
-      Value::Date(lh) => match rhv {
-        Value::Date(rh) => Value::Boolean(lh < rh),
-        _ => value_null!("eval_less_then_date"),
-      },
-      Value::DateTime(lh) => match rhv {
-        Value::DateTime(rh) => Value::Boolean(lh < rh),
-        _ => value_null!("eval_less_then_date_time"),
-      },
-      Value::Time(lh) => match rhv {
-        Value::Time(rh) => Value::Boolean(lh < rh),
-        _ => value_null!("eval_less_then_time"),
-      },
-      Value::DaysAndTimeDuration(lh) => match rhv {
-        Value::DaysAndTimeDuration(rh) => Value::Boolean(lh < rh),
-        _ => value_null!("eval_less_then_days_and_time_duration"),
-      },
-      Value::YearsAndMonthsDuration(lh) => match rhv {
-        Value::YearsAndMonthsDuration(rh) => Value::Boolean(lh < rh),
-        _ => value_null!("eval_less_then_years_and_months_duration"),
-      },
-      _ => value_null!("eval_less_then"),
-    }
-  }))
+  Ok(Box::new(move |scope: &FeelScope| eval_lt(&lhe(scope), &rhe(scope))))
+}
+
+/// Evaluates `less than` comparison.
+pub(crate) fn eval_lt(lhv: &Value, rhv: &Value) -> Value {
+  match lhv {
+    Value::Number(lh) => match rhv {
+      Value::Number(rh) => Value::Boolean(lh < rh),
+      _ => value_null!("eval_less_then_number"),
+    },
+    Value::String(lh) => match rhv {
+      Value::String(rh) => Value::Boolean(lh < rh),
+      _ => value_null!("eval_less_then_string"),
+    },
+    Value::Date(lh) => match rhv {
+      Value::Date(rh) => Value::Boolean(lh < rh),
+      _ => value_null!("eval_less_then_date"),
+    },
+    Value::DateTime(lh) => match rhv {
+      Value::DateTime(rh) => Value::Boolean(lh < rh),
+      _ => value_null!("eval_less_then_date_time"),
+    },
+    Value::Time(lh) => match rhv {
+      Value::Time(rh) => Value::Boolean(lh < rh),
+      _ => value_null!("eval_less_then_time"),
+    },
+    Value::DaysAndTimeDuration(lh) => match rhv {
+      Value::DaysAndTimeDuration(rh) => Value::Boolean(lh < rh),
+      _ => value_null!("eval_less_then_days_and_time_duration"),
+    },
+    Value::YearsAndMonthsDuration(lh) => match rhv {
+      Value::YearsAndMonthsDuration(rh) => Value::Boolean(lh < rh),
+      _ => value_null!("eval_less_then_years_and_months_duration"),
+    },
+    _ => value_null!("eval_less_then"),
+  }
 }
 
 ///
@@ -1395,69 +1436,69 @@ fn build_list_type(lhs: &AstNode) -> Result<Evaluator> {
 fn build_mul(lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
   let lhe = build_evaluator(lhs)?;
   let rhe = build_evaluator(rhs)?;
-  Ok(Box::new(move |scope: &FeelScope| {
-    let lhv = lhe(scope) as Value;
-    let rhv = rhe(scope) as Value;
-    match lhv {
-      Value::Number(lh) => match rhv {
-        Value::Number(rh) => Value::Number(lh * rh),
-        Value::DaysAndTimeDuration(ref rh) => {
-          let val = lh * FeelNumber::from(rh.as_nanos());
-          if let Ok(v) = FeelNumber::try_into(val) {
-            Value::DaysAndTimeDuration(FeelDaysAndTimeDuration::from_n(v))
-          } else {
-            value_null!("multiplication result is out of range of days and time duration")
-          }
-        }
-        Value::YearsAndMonthsDuration(ref rh) => {
-          let val = lh * FeelNumber::from(rh.as_months());
-          if let Ok(v) = FeelNumber::try_into(val) {
-            Value::YearsAndMonthsDuration(FeelYearsAndMonthsDuration::from_m(v))
-          } else {
-            value_null!("multiplication result is out of range of years and months duration")
-          }
-        }
-        _ => value_null!("[multiplication] incompatible types: {} * {}", lhv, rhv),
-      },
-      Value::DaysAndTimeDuration(ref lh) => match rhv {
-        Value::Number(rh) => {
-          let val = FeelNumber::from(lh.as_nanos()) * rh;
-          if let Ok(v) = FeelNumber::try_into(val) {
-            Value::DaysAndTimeDuration(FeelDaysAndTimeDuration::from_n(v))
-          } else {
-            value_null!("multiplication result is out of range of days and time duration")
-          }
-        }
-        _ => value_null!("[multiplication] incompatible types: {} * {}", lhv, rhv),
-      },
-      Value::YearsAndMonthsDuration(ref lh) => match rhv {
-        Value::Number(rh) => {
-          let val = FeelNumber::from(lh.as_months()) * rh;
-          if let Ok(v) = FeelNumber::try_into(val) {
-            Value::YearsAndMonthsDuration(FeelYearsAndMonthsDuration::from_m(v))
-          } else {
-            value_null!("multiplication result is out of range of years and months duration")
-          }
-        }
-        _ => value_null!("[multiplication] incompatible types: {} * {}", lhv, rhv),
-      },
-      value @ Value::Null(_) => value,
-      other => value_null!("unexpected value type in multiplication: {}", other.type_of()),
-    }
-  }))
+  Ok(Box::new(move |scope: &FeelScope| eval_mul(lhe(scope), rhe(scope))))
+}
+
+/// Evaluates multiplication.
+pub(crate) fn eval_mul(lhv: Value, rhv: Value) -> Value {
+  match lhv {
+    Value::Number(lh) => match rhv {
+      Value::Number(rh) => number_or_null!("multiplication", lh.mul_with_status(&rh)),
+      Value::DaysAndTimeDuration(ref rh) => eval_mul_days_and_time_duration(rh, &lh),
+      Value::YearsAndMonthsDuration(ref rh) => eval_mul_years_and_months_duration(rh, &lh),
+      _ => value_null!("[multiplication] incompatible types: {} * {}", lhv, rhv),
+    },
+    Value::DaysAndTimeDuration(ref lh) => match rhv {
+      Value::Number(rh) => eval_mul_days_and_time_duration(lh, &rh),
+      _ => value_null!("[multiplication] incompatible types: {} * {}", lhv, rhv),
+    },
+    Value::YearsAndMonthsDuration(ref lh) => match rhv {
+      Value::Number(rh) => eval_mul_years_and_months_duration(lh, &rh),
+      _ => value_null!("[multiplication] incompatible types: {} * {}", lhv, rhv),
+    },
+    value @ Value::Null(_) => value,
+    other => value_null!("unexpected value type in multiplication: {}", other.type_of()),
+  }
+}
+
+/// Evaluates multiplication of days and time duration by a number.
+fn eval_mul_days_and_time_duration(duration: &FeelDaysAndTimeDuration, factor: &FeelNumber) -> Value {
+  let (nanos, status) = FeelNumber::from(duration.as_nanos()).mul_with_status(factor);
+  match status.check() {
+    Ok(()) => match FeelNumber::try_into(nanos) {
+      Ok(nanos) => Value::DaysAndTimeDuration(FeelDaysAndTimeDuration::from_n(nanos)),
+      Err(_) => value_null!("multiplication result is out of range of days and time duration"),
+    },
+    Err(reason) => value_null!("[multiplication] {}", reason),
+  }
+}
+
+/// Evaluates multiplication of years and months duration by a number.
+fn eval_mul_years_and_months_duration(duration: &FeelYearsAndMonthsDuration, factor: &FeelNumber) -> Value {
+  let (months, status) = FeelNumber::from(duration.as_months()).mul_with_status(factor);
+  match status.check() {
+    Ok(()) => match FeelNumber::try_into(months) {
+      Ok(months) => Value::YearsAndMonthsDuration(FeelYearsAndMonthsDuration::from_m(months)),
+      Err(_) => value_null!("multiplication result is out of range of years and months duration"),
+    },
+    Err(reason) => value_null!("[multiplication] {}", reason),
+  }
 }
 
 ///
 fn build_name(name: Name) -> Result<Evaluator> {
-  Ok(Box::new(move |scope: &FeelScope| {
-    if let Some(value) = scope.get_value(&name) {
-      value
-    } else if let Ok(bif) = Bif::from_str(&name.to_string()) {
-      Value::BuiltInFunction(bif)
-    } else {
-      value_null!("context has no value for key '{}'", name)
-    }
-  }))
+  Ok(Box::new(move |scope: &FeelScope| eval_name(scope, &name)))
+}
+
+/// Evaluates the value of a name, built-in functions are resolved when the name is not present in scope.
+pub(crate) fn eval_name(scope: &FeelScope, name: &Name) -> Value {
+  if let Some(value) = scope.get_value(name) {
+    value
+  } else if let Ok(bif) = Bif::from_str(&name.to_string()) {
+    Value::BuiltInFunction(bif)
+  } else {
+    value_null!("context has no value for key '{}'", name)
+  }
 }
 
 ///
@@ -1496,15 +1537,17 @@ fn build_named_parameters(lhs: &[AstNode]) -> Result<Evaluator> {
 ///
 fn build_neg(lhs: &AstNode) -> Result<Evaluator> {
   let lhe = build_evaluator(lhs)?;
-  Ok(Box::new(move |scope: &FeelScope| {
-    let lhv = lhe(scope) as Value;
-    match lhv {
-      Value::Number(lh) => Value::Number(-lh),
-      Value::DaysAndTimeDuration(lh) => Value::DaysAndTimeDuration(-lh),
-      Value::YearsAndMonthsDuration(lh) => Value::YearsAndMonthsDuration(-lh),
-      other => value_null!("unexpected type in arithmetic negation: {}", other.type_of()),
-    }
-  }))
+  Ok(Box::new(move |scope: &FeelScope| eval_neg(lhe(scope))))
+}
+
+/// Evaluates arithmetic negation.
+pub(crate) fn eval_neg(lhv: Value) -> Value {
+  match lhv {
+    Value::Number(lh) => Value::Number(-lh),
+    Value::DaysAndTimeDuration(lh) => Value::DaysAndTimeDuration(-lh),
+    Value::YearsAndMonthsDuration(lh) => Value::YearsAndMonthsDuration(-lh),
+    other => value_null!("unexpected type in arithmetic negation: {}", other.type_of()),
+  }
 }
 
 ///
@@ -1541,15 +1584,16 @@ fn build_numeric(lhs: &str, rhs: &str) -> Result<Evaluator> {
 fn build_nq(lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
   let lhe = build_evaluator(lhs)?;
   let rhe = build_evaluator(rhs)?;
-  Ok(Box::new(move |scope: &FeelScope| {
-    let lhv = lhe(scope);
-    let rhv = rhe(scope);
-    if let Some(result) = eval_ternary_equality(&lhv, &rhv) {
-      Value::Boolean(!result)
-    } else {
-      value_null!()
-    }
-  }))
+  Ok(Box::new(move |scope: &FeelScope| eval_nq(&lhe(scope), &rhe(scope))))
+}
+
+/// Evaluates `not equal` comparison.
+pub(crate) fn eval_nq(lhv: &Value, rhv: &Value) -> Value {
+  if let Some(result) = eval_ternary_equality(lhv, rhv) {
+    Value::Boolean(!result)
+  } else {
+    value_null!()
+  }
 }
 
 /// Semantics of disjunction.
@@ -1569,32 +1613,33 @@ fn build_nq(lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
 fn build_or(lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
   let lhe = build_evaluator(lhs)?;
   let rhe = build_evaluator(rhs)?;
-  Ok(Box::new(move |scope: &FeelScope| {
-    let lhv = lhe(scope);
-    let rhv = rhe(scope);
-    match lhv {
-      Value::Boolean(lh) => match rhv {
-        Value::Boolean(rh) => Value::Boolean(lh || rh),
-        _ => {
-          if lh {
-            Value::Boolean(true)
-          } else {
-            value_null!()
-          }
+  Ok(Box::new(move |scope: &FeelScope| eval_or(lhe(scope), rhe(scope))))
+}
+
+/// Evaluates disjunction.
+pub(crate) fn eval_or(lhv: Value, rhv: Value) -> Value {
+  match lhv {
+    Value::Boolean(lh) => match rhv {
+      Value::Boolean(rh) => Value::Boolean(lh || rh),
+      _ => {
+        if lh {
+          Value::Boolean(true)
+        } else {
+          value_null!()
         }
-      },
-      _ => match rhv {
-        Value::Boolean(rh) => {
-          if rh {
-            Value::Boolean(true)
-          } else {
-            value_null!()
-          }
+      }
+    },
+    _ => match rhv {
+      Value::Boolean(rh) => {
+        if rh {
+          Value::Boolean(true)
+        } else {
+          value_null!()
         }
-        _ => value_null!(),
-      },
-    }
-  }))
+      }
+      _ => value_null!(),
+    },
+  }
 }
 
 ///
@@ -1771,7 +1816,7 @@ fn get_property_from_value(value: Value, name: &Name) -> Value {
 }
 
 ///
-fn build_qualified_name_from_path(node: &AstNode) -> Result<QualifiedName> {
+pub(crate) fn build_qualified_name_from_path(node: &AstNode) -> Result<QualifiedName> {
   match node {
     AstNode::Path(lhs, rhs) => {
       return if let AstNode::Name(name) = lhs.borrow() {
@@ -1794,55 +1839,58 @@ fn build_path(lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
   let mut property_path = qualified_name.clone();
   let property_name = property_path.pop().unwrap();
   let lhe = build_evaluator(lhs)?;
-  Ok(Box::new(move |scope: &FeelScope| {
-    let lhv = lhe(scope) as Value;
-    match lhv {
-      Value::Context(context) => {
-        if let Some(value) = context.search_entry(&qualified_name) {
-          return value.clone();
-        }
-        if let Some(value) = context.search_entry(&property_path) {
-          return get_property_from_value(value.clone(), &property_name);
-        }
-        value_null!("build_path: no entry {} in context: {}", qualified_name, context)
-      }
-      Value::List(items) => {
-        let mut result = vec![];
-        for item in items {
-          if let Value::Context(context) = item {
-            if let Some(value) = context.search_entry(&qualified_name) {
-              result.push(value.clone());
-            } else if let Some(value) = context.search_entry(&property_path) {
-              result.push(get_property_from_value(value.clone(), &property_name));
-            }
-          } else {
-            return value_null!("build_path: no context in list");
+  Ok(Box::new(move |scope: &FeelScope| eval_path(lhe(scope), &qualified_name, &property_path, &property_name)))
+}
+
+/// Evaluates path expression.
+pub(crate) fn eval_path(lhv: Value, qualified_name: &QualifiedName, property_path: &QualifiedName, property_name: &Name) -> Value {
+  match lhv {
+    Value::Context(context) => {
+      if let Some(value) = context.search_entry(qualified_name) {
+        return value.clone();
+      }
+      if let Some(value) = context.search_entry(property_path) {
+        return get_property_from_value(value.clone(), property_name);
+      }
+      value_null!("build_path: no entry {} in context: {}", qualified_name, context)
+    }
+    Value::List(items) => {
+      let mut result = vec![];
+      for item in items {
+        if let Value::Context(context) = item {
+          if let Some(value) = context.search_entry(qualified_name) {
+            result.push(value.clone());
+          } else if let Some(value) = context.search_entry(property_path) {
+            result.push(get_property_from_value(value.clone(), property_name));
           }
+        } else {
+          return value_null!("build_path: no context in list");
         }
-        Value::List(result)
       }
-      other => get_property_from_value(other, &property_name),
+      Value::List(result)
     }
-  }))
+    other => get_property_from_value(other, property_name),
+  }
 }
 
 ///
 fn build_range(lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
   let lhe = build_evaluator(lhs)?;
   let rhe = build_evaluator(rhs)?;
-  Ok(Box::new(move |scope: &FeelScope| {
-    let lhv = lhe(scope);
-    let rhv = rhe(scope);
-    if let Value::IntervalStart(lhv, l_closed) = lhv {
-      if let Value::IntervalEnd(rhv, r_closed) = rhv {
-        Value::Range(lhv, l_closed, rhv, r_closed)
-      } else {
-        value_null!("expected interval end")
-      }
+  Ok(Box::new(move |scope: &FeelScope| eval_range(lhe(scope), rhe(scope))))
+}
+
+/// Evaluates range of values.
+pub(crate) fn eval_range(lhv: Value, rhv: Value) -> Value {
+  if let Value::IntervalStart(lhv, l_closed) = lhv {
+    if let Value::IntervalEnd(rhv, r_closed) = rhv {
+      Value::Range(lhv, l_closed, rhv, r_closed)
     } else {
-      value_null!("expected interval start")
+      value_null!("expected interval end")
     }
-  }))
+  } else {
+    value_null!("expected interval start")
+  }
 }
 
 ///
@@ -1897,90 +1945,91 @@ fn build_string(lhs: &str) -> Result<Evaluator> {
 fn build_sub(lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
   let lhe = build_evaluator(lhs)?;
   let rhe = build_evaluator(rhs)?;
-  Ok(Box::new(move |scope: &FeelScope| {
-    let lhv = lhe(scope) as Value;
-    let rhv = rhe(scope) as Value;
-    match lhv {
-      Value::Number(lh) => {
-        if let Value::Number(rh) = rhv {
-          return Value::Number(lh - rh);
-        }
+  Ok(Box::new(move |scope: &FeelScope| eval_sub(lhe(scope), rhe(scope))))
+}
+
+/// Evaluates subtraction.
+pub(crate) fn eval_sub(lhv: Value, rhv: Value) -> Value {
+  match lhv.clone() {
+    Value::Number(lh) => {
+      if let Value::Number(rh) = rhv.clone() {
+        return number_or_null!("subtraction", lh.sub_with_status(&rh));
       }
-      Value::Date(lh) => match rhv {
-        Value::Date(rh) => {
-          let l = FeelDateTime::new(lh, FeelTime::utc(0, 0, 0, 0));
-          let r = FeelDateTime::new(rh, FeelTime::utc(0, 0, 0, 0));
-          if let Some(result) = l - r {
-            return Value::DaysAndTimeDuration(result);
-          }
-        }
-        Value::DateTime(rh) => {
-          let l = FeelDateTime::new(lh, FeelTime::utc(0, 0, 0, 0));
-          if let Some(result) = l - rh {
-            return Value::DaysAndTimeDuration(result);
-          }
-        }
-        Value::DaysAndTimeDuration(rh) => {
-          if let Some(date) = lh - rh {
-            return Value::Date(date);
-          }
-        }
-        Value::YearsAndMonthsDuration(rh) => {
-          if let Some(date) = lh - rh {
-            return Value::Date(date);
-          }
+    }
+    Value::Date(lh) => match rhv.clone() {
+      Value::Date(rh) => {
+        let l = FeelDateTime::new(lh, FeelTime::utc(0, 0, 0, 0));
+        let r = FeelDateTime::new(rh, FeelTime::utc(0, 0, 0, 0));
+        if let Some(result) = l - r {
+          return Value::DaysAndTimeDuration(result);
         }
-        _ => {}
-      },
-      Value::Time(lh) => match rhv {
-        Value::Time(rh) => {
-          if let Some(result) = lh - rh {
-            return Value::DaysAndTimeDuration(result);
-          }
+      }
+      Value::DateTime(rh) => {
+        let l = FeelDateTime::new(lh, FeelTime::utc(0, 0, 0, 0));
+        if let Some(result) = l - rh {
+          return Value::DaysAndTimeDuration(result);
         }
-        Value::DaysAndTimeDuration(rh) => {
-          return Value::Time(lh - rh);
+      }
+      Value::DaysAndTimeDuration(rh) => {
+        if let Some(date) = lh - rh {
+          return Value::Date(date);
         }
-        _ => {}
-      },
-      Value::DateTime(lh) => match rhv {
-        Value::Date(rh) => {
-          let r = FeelDateTime::new(rh, FeelTime::utc(0, 0, 0, 0));
-          if let Some(result) = lh - r {
-            return Value::DaysAndTimeDuration(result);
-          }
+      }
+      Value::YearsAndMonthsDuration(rh) => {
+        if let Some(date) = lh - rh {
+          return Value::Date(date);
         }
-        Value::DateTime(rh) => {
-          if let Some(result) = lh - rh {
-            return Value::DaysAndTimeDuration(result);
-          }
+      }
+      _ => {}
+    },
+    Value::Time(lh) => match rhv.clone() {
+      Value::Time(rh) => {
+        if let Some(result) = lh - rh {
+          return Value::DaysAndTimeDuration(result);
         }
-        Value::DaysAndTimeDuration(rh) => {
-          if let Some(result) = lh - rh {
-            return Value::DateTime(result);
-          }
+      }
+      Value::DaysAndTimeDuration(rh) => {
+        return Value::Time(lh - rh);
+      }
+      _ => {}
+    },
+    Value::DateTime(lh) => match rhv.clone() {
+      Value::Date(rh) => {
+        let r = FeelDateTime::new(rh, FeelTime::utc(0, 0, 0, 0));
+        if let Some(result) = lh - r {
+          return Value::DaysAndTimeDuration(result);
         }
-        Value::YearsAndMonthsDuration(rh) => {
-          if let Some(result) = lh - rh {
-            return Value::DateTime(result);
-          }
+      }
+      Value::DateTime(rh) => {
+        if let Some(result) = lh - rh {
+          return Value::DaysAndTimeDuration(result);
         }
-        _ => {}
-      },
-      Value::DaysAndTimeDuration(lh) => {
-        if let Value::DaysAndTimeDuration(rh) = rhv {
-          return Value::DaysAndTimeDuration(lh - rh);
+      }
+      Value::DaysAndTimeDuration(rh) => {
+        if let Some(result) = lh - rh {
+          return Value::DateTime(result);
         }
       }
-      Value::YearsAndMonthsDuration(lh) => {
-        if let Value::YearsAndMonthsDuration(rh) = rhv {
-          return Value::YearsAndMonthsDuration(lh - rh);
+      Value::YearsAndMonthsDuration(rh) => {
+        if let Some(result) = lh - rh {
+          return Value::DateTime(result);
         }
       }
       _ => {}
+    },
+    Value::DaysAndTimeDuration(lh) => {
+      if let Value::DaysAndTimeDuration(rh) = rhv.clone() {
+        return Value::DaysAndTimeDuration(lh - rh);
+      }
     }
-    value_null!("[subtraction] incompatible types: {} - {}", lhe(scope) as Value, rhe(scope) as Value)
-  }))
+    Value::YearsAndMonthsDuration(lh) => {
+      if let Value::YearsAndMonthsDuration(rh) = rhv.clone() {
+        return Value::YearsAndMonthsDuration(lh - rh);
+      }
+    }
+    _ => {}
+  }
+  value_null!("[subtraction] incompatible types: {} - {}", lhv, rhv)
 }
 
 ///
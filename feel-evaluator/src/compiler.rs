@@ -0,0 +1,627 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Compiler of `FEEL` expressions into intermediate representation.
+//!
+//! Expressions that are compiled and the meaning of slots are described in the `ir` module.
+
+use crate::builders::{build_evaluator, build_qualified_name_from_path};
+use crate::ir::{Arithmetic, Binding, Comparison, Filter, Frame, Function, Instruction, Loop, PathSegments, Program, Quantifier, Resolution};
+use dmntk_common::{Result, Span};
+use dmntk_feel::bif::Bif;
+use dmntk_feel::values::Value;
+use dmntk_feel::{FeelScope, FunctionBody, Name};
+use dmntk_feel_parser::{AstNode, ClosureBuilder, Spans};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Spans of nodes in source text, keyed by addresses of nodes.
+type NodeSpans = HashMap<*const AstNode, Span>;
+
+/// Compiles [AstNode] into [Program].
+pub fn compile(node: &AstNode) -> Result<Program> {
+  compile_program(node, &NodeSpans::new())
+}
+
+/// Compiles [AstNode] into [Program], instructions are annotated with spans
+/// of the nodes they were compiled from, spans are the same as returned by the parser.
+pub fn compile_with_spans(node: &AstNode, spans: &Spans) -> Result<Program> {
+  let mut node_spans = NodeSpans::new();
+  collect_node_spans(node, spans, &mut vec![], &mut node_spans);
+  compile_program(node, &node_spans)
+}
+
+/// Compiles [AstNode] into [Program], using specified spans of nodes.
+fn compile_program(node: &AstNode, spans: &NodeSpans) -> Result<Program> {
+  let mut compiler = Compiler {
+    program: Program::default(),
+    slots: HashMap::new(),
+    frames: vec![],
+    spans,
+    span: None,
+  };
+  compiler.compile(node)?;
+  compiler.uncache_single_names();
+  Ok(compiler.program)
+}
+
+/// Collects spans of specified node and all its descendants, keyed by addresses of nodes.
+fn collect_node_spans(node: &AstNode, spans: &Spans, path: &mut Vec<usize>, node_spans: &mut NodeSpans) {
+  if let Some(span) = spans.get(path) {
+    node_spans.insert(node, *span);
+  }
+  for (index, child) in node.children().into_iter().enumerate() {
+    path.push(index);
+    collect_node_spans(child, spans, path, node_spans);
+    path.pop();
+  }
+}
+
+/// Compiler state.
+struct Compiler<'a> {
+  /// Compiled program.
+  program: Program,
+  /// Slots assigned to free names.
+  slots: HashMap<Name, usize>,
+  /// Local names visible in currently compiled node, from the outermost to the innermost.
+  frames: Vec<Frame>,
+  /// Spans of nodes in source text.
+  spans: &'a NodeSpans,
+  /// Span of the innermost currently compiled node, that has a span.
+  span: Option<Span>,
+}
+
+impl Compiler<'_> {
+  /// Compiles a node, the value of the node is left on top of the stack.
+  /// Instructions emitted for the node are annotated with the span of the node.
+  fn compile(&mut self, node: &AstNode) -> Result<()> {
+    let outer_span = self.span;
+    if let Some(span) = self.spans.get(&(node as *const AstNode)) {
+      self.span = Some(*span);
+    }
+    let result = self.compile_node(node);
+    self.span = outer_span;
+    result
+  }
+
+  /// Compiles instructions of a node.
+  fn compile_node(&mut self, node: &AstNode) -> Result<()> {
+    if is_constant(node) {
+      return self.compile_constant(node);
+    }
+    match node {
+      AstNode::Add(lhs, rhs) => self.compile_arithmetic(lhs, rhs, Arithmetic::Add),
+      AstNode::Sub(lhs, rhs) => self.compile_arithmetic(lhs, rhs, Arithmetic::Sub),
+      AstNode::Mul(lhs, rhs) => self.compile_arithmetic(lhs, rhs, Arithmetic::Mul),
+      AstNode::Div(lhs, rhs) => self.compile_arithmetic(lhs, rhs, Arithmetic::Div),
+      AstNode::Exp(lhs, rhs) => self.compile_arithmetic(lhs, rhs, Arithmetic::Exp),
+      AstNode::Eq(lhs, rhs) => self.compile_comparison(lhs, rhs, Comparison::Eq),
+      AstNode::Nq(lhs, rhs) => self.compile_comparison(lhs, rhs, Comparison::Nq),
+      AstNode::Lt(lhs, rhs) => self.compile_comparison(lhs, rhs, Comparison::Lt),
+      AstNode::Le(lhs, rhs) => self.compile_comparison(lhs, rhs, Comparison::Le),
+      AstNode::Gt(lhs, rhs) => self.compile_comparison(lhs, rhs, Comparison::Gt),
+      AstNode::Ge(lhs, rhs) => self.compile_comparison(lhs, rhs, Comparison::Ge),
+      AstNode::In(lhs, rhs) => self.compile_comparison(lhs, rhs, Comparison::In),
+      AstNode::Range(lhs, rhs) => self.compile_binary(lhs, rhs, Instruction::Range),
+      AstNode::Between(lhs, mhs, rhs) => {
+        self.compile(lhs)?;
+        self.compile(mhs)?;
+        self.compile(rhs)?;
+        self.emit(Instruction::Between);
+        Ok(())
+      }
+      AstNode::And(lhs, rhs) => self.compile_short_circuit(lhs, rhs, Instruction::JumpIfFalse(0), Instruction::And),
+      AstNode::Or(lhs, rhs) => self.compile_short_circuit(lhs, rhs, Instruction::JumpIfTrue(0), Instruction::Or),
+      AstNode::If(lhs, mhs, rhs) => self.compile_if(lhs, mhs, rhs),
+      AstNode::Neg(lhs) => self.compile_unary(lhs, Instruction::Neg),
+      AstNode::UnaryLt(lhs) => self.compile_unary(lhs, Instruction::UnaryLt),
+      AstNode::UnaryLe(lhs) => self.compile_unary(lhs, Instruction::UnaryLe),
+      AstNode::UnaryGt(lhs) => self.compile_unary(lhs, Instruction::UnaryGt),
+      AstNode::UnaryGe(lhs) => self.compile_unary(lhs, Instruction::UnaryGe),
+      AstNode::IntervalStart(lhs, closed) => self.compile_unary(lhs, Instruction::IntervalStart(*closed)),
+      AstNode::IntervalEnd(lhs, closed) => self.compile_unary(lhs, Instruction::IntervalEnd(*closed)),
+      AstNode::List(items) => self.compile_items(items, Instruction::List(items.len())),
+      AstNode::ExpressionList(items) => self.compile_items(items, Instruction::ExpressionList(items.len())),
+      AstNode::NegatedList(items) => self.compile_items(items, Instruction::NegatedList(items.len())),
+      AstNode::Name(name) => {
+        let instruction = self.resolve(name);
+        self.emit(instruction);
+        Ok(())
+      }
+      AstNode::EvaluatedExpression(lhs) => self.compile(lhs),
+      AstNode::Path(lhs, rhs) => self.compile_path(lhs, rhs),
+      AstNode::Context(entries) => self.compile_context(node, entries),
+      AstNode::For(lhs, rhs) => self.compile_for(node, lhs, rhs),
+      AstNode::Some(lhs, rhs) => self.compile_quantified(node, lhs, rhs, Instruction::Some),
+      AstNode::Every(lhs, rhs) => self.compile_quantified(node, lhs, rhs, Instruction::Every),
+      AstNode::Filter(lhs, rhs) => self.compile_filter(lhs, rhs),
+      AstNode::FunctionDefinition(lhs, rhs) => self.compile_function_definition(node, lhs, rhs),
+      AstNode::FunctionInvocation(lhs, rhs) => self.compile_function_invocation(node, lhs, rhs),
+      _ => self.compile_closure(node),
+    }
+  }
+
+  /// Replaces loading names used only once with lookups, that do not cache values.
+  fn uncache_single_names(&mut self) {
+    let mut counts = vec![0_usize; self.program.names.len()];
+    for instruction in &self.program.instructions {
+      match instruction {
+        Instruction::Load(slot) | Instruction::Folded(slot, _, _) => counts[*slot] += 1,
+        Instruction::Resolve(index) => {
+          for binding in &self.program.resolutions[*index].bindings {
+            if let Binding::Free(slot) = binding {
+              counts[*slot] += 1;
+            }
+          }
+        }
+        _ => {}
+      }
+    }
+    for instruction in &mut self.program.instructions {
+      if let Instruction::Load(slot) = instruction {
+        if counts[*slot] == 1 {
+          *instruction = Instruction::Lookup(*slot);
+        }
+      }
+    }
+  }
+
+  /// Evaluates a constant node in compile time.
+  fn compile_constant(&mut self, node: &AstNode) -> Result<()> {
+    let index = self.fold(node)?;
+    self.emit(Instruction::Const(index));
+    Ok(())
+  }
+
+  /// Evaluates a constant node in compile time and returns the index of the constant value.
+  /// The reason of constant `null` value is annotated with the span of the node.
+  fn fold(&mut self, node: &AstNode) -> Result<usize> {
+    let mut value = build_evaluator(node)?(&FeelScope::default());
+    if let Some(span) = self.spans.get(&(node as *const AstNode)).or(self.span.as_ref()) {
+      value = value.with_span(span);
+    }
+    Ok(self.constant(value))
+  }
+
+  /// Compiles a node into a closure built by evaluator builders,
+  /// the node and all its subexpressions are not compiled.
+  fn compile_closure(&mut self, node: &AstNode) -> Result<()> {
+    let index = self.closure(node)?;
+    let environment = self.environment();
+    self.emit(Instruction::Closure(index, environment));
+    Ok(())
+  }
+
+  fn compile_unary(&mut self, lhs: &AstNode, instruction: Instruction) -> Result<()> {
+    self.compile(lhs)?;
+    self.emit(instruction);
+    Ok(())
+  }
+
+  fn compile_binary(&mut self, lhs: &AstNode, rhs: &AstNode, instruction: Instruction) -> Result<()> {
+    self.compile(lhs)?;
+    self.compile(rhs)?;
+    self.emit(instruction);
+    Ok(())
+  }
+
+  /// Compiles arithmetic operation, constant value on the right side is not pushed on the stack.
+  fn compile_arithmetic(&mut self, lhs: &AstNode, rhs: &AstNode, arithmetic: Arithmetic) -> Result<()> {
+    self.compile(lhs)?;
+    if is_constant(rhs) {
+      let index = self.fold(rhs)?;
+      self.emit(Instruction::ArithmeticConst(arithmetic, index));
+    } else {
+      self.compile(rhs)?;
+      self.emit(Instruction::Arithmetic(arithmetic));
+    }
+    Ok(())
+  }
+
+  /// Compiles comparison, constant value on the right side is compared without copying it to the stack.
+  fn compile_comparison(&mut self, lhs: &AstNode, rhs: &AstNode, comparison: Comparison) -> Result<()> {
+    self.compile(lhs)?;
+    if is_constant(rhs) {
+      let index = self.fold(rhs)?;
+      self.emit(Instruction::CompareConst(comparison, index));
+    } else {
+      self.compile(rhs)?;
+      self.emit(Instruction::Compare(comparison));
+    }
+    Ok(())
+  }
+
+  /// Compiles conjunction or disjunction, the right side is not evaluated
+  /// when the left side alone determines the result.
+  fn compile_short_circuit(&mut self, lhs: &AstNode, rhs: &AstNode, jump: Instruction, instruction: Instruction) -> Result<()> {
+    self.compile(lhs)?;
+    let jump_index = self.emit(jump);
+    self.compile(rhs)?;
+    self.emit(instruction);
+    let target = self.program.instructions.len();
+    self.program.instructions[jump_index] = match self.program.instructions[jump_index] {
+      Instruction::JumpIfFalse(_) => Instruction::JumpIfFalse(target),
+      _ => Instruction::JumpIfTrue(target),
+    };
+    Ok(())
+  }
+
+  fn compile_if(&mut self, lhs: &AstNode, mhs: &AstNode, rhs: &AstNode) -> Result<()> {
+    self.compile(lhs)?;
+    let if_index = self.emit(Instruction::If(0, 0));
+    self.compile(mhs)?;
+    let jump_index = self.emit(Instruction::Jump(0));
+    let else_target = self.program.instructions.len();
+    self.compile(rhs)?;
+    let end_target = self.program.instructions.len();
+    self.program.instructions[if_index] = Instruction::If(else_target, end_target);
+    self.program.instructions[jump_index] = Instruction::Jump(end_target);
+    Ok(())
+  }
+
+  fn compile_items(&mut self, items: &[AstNode], instruction: Instruction) -> Result<()> {
+    for item in items {
+      self.compile(item)?;
+    }
+    self.emit(instruction);
+    Ok(())
+  }
+
+  fn compile_path(&mut self, lhs: &AstNode, rhs: &AstNode) -> Result<()> {
+    let qualified_name = build_qualified_name_from_path(rhs)?;
+    let mut property_path = qualified_name.clone();
+    let property_name = property_path.pop().unwrap(); // unwrap is ok, qualified name has at least one segment
+    self.compile(lhs)?;
+    self.program.paths.push(PathSegments {
+      qualified_name,
+      property_path,
+      property_name,
+    });
+    self.emit(Instruction::Path(self.program.paths.len() - 1));
+    Ok(())
+  }
+
+  /// Compiles a context, evaluated entries are stored in local slots, visible in the following entries.
+  /// Contexts with duplicated entry keys are evaluated by a closure.
+  fn compile_context(&mut self, node: &AstNode, entries: &[AstNode]) -> Result<()> {
+    let mut keys: Vec<(&Name, &AstNode)> = vec![];
+    for entry in entries {
+      let AstNode::ContextEntry(key, value) = entry else {
+        return self.compile_closure(node);
+      };
+      let AstNode::ContextEntryKey(name) = key.as_ref() else {
+        return self.compile_closure(node);
+      };
+      if keys.iter().any(|(key, _)| *key == name) {
+        return self.compile_closure(node);
+      }
+      keys.push((name, value));
+    }
+    let mut slots = vec![];
+    self.frames.push(Frame::Context(vec![]));
+    for (name, value) in keys {
+      self.compile(value)?;
+      let slot = self.local(name);
+      self.emit(Instruction::Store(slot));
+      slots.push(slot);
+      if let Some(Frame::Context(visible)) = self.frames.last_mut() {
+        visible.push(slot);
+      }
+    }
+    self.frames.pop();
+    self.program.contexts.push(slots);
+    self.emit(Instruction::Context(self.program.contexts.len() - 1));
+    Ok(())
+  }
+
+  /// Compiles `for` expression, iteration contexts are evaluated before the loop,
+  /// variables iterating over lists precede variables iterating over ranges.
+  fn compile_for(&mut self, node: &AstNode, lhs: &AstNode, rhs: &AstNode) -> Result<()> {
+    let AstNode::IterationContexts(items) = lhs else {
+      return self.compile_closure(node);
+    };
+    let mut singles = vec![];
+    let mut ranges = vec![];
+    for item in items {
+      match item {
+        AstNode::IterationContextSingle(variable, source) => match variable.as_ref() {
+          AstNode::Name(name) => singles.push((name, source)),
+          _ => return self.compile_closure(node),
+        },
+        AstNode::IterationContextRange(variable, range_start, range_end) => match variable.as_ref() {
+          AstNode::Name(name) => ranges.push((name, range_start, range_end)),
+          _ => return self.compile_closure(node),
+        },
+        _ => return self.compile_closure(node),
+      }
+    }
+    for (_, source) in &singles {
+      self.compile(source)?;
+    }
+    for (_, range_start, range_end) in &ranges {
+      self.compile(range_start)?;
+      self.compile(range_end)?;
+    }
+    let singles = singles.into_iter().map(|(name, _)| self.local(name)).collect::<Vec<usize>>();
+    let ranges = ranges.into_iter().map(|(name, _, _)| self.local(name)).collect::<Vec<usize>>();
+    let partial = self.local(&"partial".into());
+    let mut variables = singles.clone();
+    variables.extend(&ranges);
+    variables.push(partial);
+    self.program.loops.push(Loop { singles, ranges, partial, end: 0 });
+    let index = self.program.loops.len() - 1;
+    self.emit(Instruction::For(index));
+    self.program.loops[index].end = self.compile_block(rhs, Frame::Iteration(variables))?;
+    Ok(())
+  }
+
+  /// Compiles `some` or `every` expression, quantified contexts are evaluated before the iterations.
+  fn compile_quantified(&mut self, node: &AstNode, lhs: &AstNode, rhs: &AstNode, instruction: fn(usize) -> Instruction) -> Result<()> {
+    let (AstNode::QuantifiedContexts(items), AstNode::Satisfies(satisfies)) = (lhs, rhs) else {
+      return self.compile_closure(node);
+    };
+    let mut variables = vec![];
+    for item in items {
+      let AstNode::QuantifiedContext(variable, source) = item else {
+        return self.compile_closure(node);
+      };
+      let AstNode::Name(name) = variable.as_ref() else {
+        return self.compile_closure(node);
+      };
+      variables.push((name, source));
+    }
+    for (_, source) in &variables {
+      self.compile(source)?;
+    }
+    let variables = variables.into_iter().map(|(name, _)| self.local(name)).collect::<Vec<usize>>();
+    self.program.quantifiers.push(Quantifier {
+      variables: variables.clone(),
+      end: 0,
+    });
+    let index = self.program.quantifiers.len() - 1;
+    self.emit(instruction(index));
+    self.program.quantifiers[index].end = self.compile_block(satisfies, Frame::Iteration(variables))?;
+    Ok(())
+  }
+
+  /// Compiles a filter, the condition is a block evaluated for every list item.
+  fn compile_filter(&mut self, lhs: &AstNode, rhs: &AstNode) -> Result<()> {
+    self.compile(lhs)?;
+    let item = self.local(&"item".into());
+    self.program.filters.push(Filter { item, end: 0 });
+    let index = self.program.filters.len() - 1;
+    self.emit(Instruction::Filter(index));
+    self.program.filters[index].end = self.compile_block(rhs, Frame::Item(item))?;
+    Ok(())
+  }
+
+  /// Compiles a block ended with [Instruction::Return], local names of the specified frame
+  /// are visible in the block, returns the index of the instruction following the block.
+  fn compile_block(&mut self, node: &AstNode, frame: Frame) -> Result<usize> {
+    self.frames.push(frame);
+    self.compile(node)?;
+    self.frames.pop();
+    self.emit(Instruction::Return);
+    Ok(self.program.instructions.len())
+  }
+
+  /// Compiles a function definition, the body of the function is compiled into a separate program.
+  /// Definitions of external functions are evaluated by a closure.
+  fn compile_function_definition(&mut self, node: &AstNode, lhs: &AstNode, rhs: &AstNode) -> Result<()> {
+    let AstNode::FunctionBody(body, false) = rhs else {
+      return self.compile_closure(node);
+    };
+    let Value::FormalParameters(parameters) = build_evaluator(lhs)?(&FeelScope::default()) else {
+      return self.compile_closure(node);
+    };
+    let closure = ClosureBuilder::from_function_definition(lhs, rhs);
+    let body = FunctionBody::LiteralExpression(Arc::new(compile_program(body, self.spans)?.into_evaluator()));
+    self.program.functions.push(Function { parameters, body, closure });
+    let environment = self.environment();
+    self.emit(Instruction::Function(self.program.functions.len() - 1, environment));
+    Ok(())
+  }
+
+  /// Compiles an invocation of a function.
+  /// Invocations of functions building temporal values from constant arguments are evaluated in compile time,
+  /// the constant is used in run time only when the function's name was not redefined in the scope.
+  fn compile_function_invocation(&mut self, node: &AstNode, lhs: &AstNode, rhs: &AstNode) -> Result<()> {
+    match rhs {
+      AstNode::PositionalParameters(parameters) => {
+        if let AstNode::Name(name) = lhs {
+          if self.frames.is_empty() && is_foldable_bif(name) && parameters.iter().all(is_constant) {
+            let value = build_evaluator(node)?(&FeelScope::default());
+            let slot = self.slot(name);
+            let index = self.constant(value);
+            let closure = self.closure(node)?;
+            self.emit(Instruction::Folded(slot, index, closure));
+            return Ok(());
+          }
+        }
+        self.compile(lhs)?;
+        for parameter in parameters {
+          self.compile(parameter)?;
+        }
+        let environment = self.environment();
+        self.emit(Instruction::Invoke(parameters.len(), environment));
+        Ok(())
+      }
+      AstNode::NamedParameters(parameters) => {
+        let mut arguments = vec![];
+        for parameter in parameters {
+          let AstNode::NamedParameter(name, value) = parameter else {
+            return self.compile_closure(node);
+          };
+          let AstNode::ParameterName(name) = name.as_ref() else {
+            return self.compile_closure(node);
+          };
+          arguments.push((name.clone(), value));
+        }
+        self.compile(lhs)?;
+        for (_, value) in &arguments {
+          self.compile(value)?;
+        }
+        self.program.arguments.push(arguments.into_iter().map(|(name, _)| name).collect());
+        let environment = self.environment();
+        self.emit(Instruction::InvokeNamed(self.program.arguments.len() - 1, environment));
+        Ok(())
+      }
+      _ => self.compile_closure(node),
+    }
+  }
+
+  /// Resolves a name in visible local names, from the innermost to the outermost,
+  /// names not bound locally are free names resolved in scope.
+  fn resolve(&mut self, name: &Name) -> Instruction {
+    let mut bindings = vec![];
+    for frame in self.frames.iter().rev() {
+      match frame {
+        Frame::Context(slots) => {
+          if let Some(slot) = slots.iter().rev().find(|slot| self.program.locals[**slot] == *name) {
+            if bindings.is_empty() {
+              return Instruction::Local(*slot);
+            }
+            bindings.push(Binding::Local(*slot));
+            break;
+          }
+        }
+        Frame::Iteration(slots) => {
+          if let Some(slot) = slots.iter().rev().find(|slot| self.program.locals[**slot] == *name) {
+            bindings.push(Binding::Local(*slot));
+          }
+        }
+        Frame::Item(slot) => bindings.push(Binding::Item(*slot, *name == "item".into())),
+      }
+    }
+    if bindings.is_empty() {
+      return Instruction::Load(self.slot(name));
+    }
+    if !matches!(bindings.last(), Some(Binding::Local(slot)) if self.is_context_entry(*slot)) {
+      bindings.push(Binding::Free(self.slot(name)));
+    }
+    self.program.resolutions.push(Resolution { name: name.clone(), bindings });
+    Instruction::Resolve(self.program.resolutions.len() - 1)
+  }
+
+  /// Returns `true` when the local slot is bound to a context entry, that always has a value when visible.
+  fn is_context_entry(&self, slot: usize) -> bool {
+    self.frames.iter().any(|frame| matches!(frame, Frame::Context(slots) if slots.contains(&slot)))
+  }
+
+  /// Returns the environment of local names visible in currently compiled node,
+  /// `None` when no local names are visible.
+  fn environment(&mut self) -> Option<usize> {
+    if self.frames.is_empty() {
+      return None;
+    }
+    self.program.environments.push(self.frames.clone());
+    Some(self.program.environments.len() - 1)
+  }
+
+  /// Assigns a new local slot to specified name.
+  fn local(&mut self, name: &Name) -> usize {
+    self.program.locals.push(name.clone());
+    self.program.locals.len() - 1
+  }
+
+  /// Appends an instruction and returns its index.
+  fn emit(&mut self, instruction: Instruction) -> usize {
+    self.program.instructions.push(instruction);
+    self.program.spans.push(self.span);
+    self.program.instructions.len() - 1
+  }
+
+  /// Returns the slot assigned to specified free name.
+  fn slot(&mut self, name: &Name) -> usize {
+    if let Some(slot) = self.slots.get(name) {
+      return *slot;
+    }
+    self.program.names.push(name.clone());
+    self.slots.insert(name.clone(), self.program.names.len() - 1);
+    self.program.names.len() - 1
+  }
+
+  /// Appends a constant and returns its index.
+  fn constant(&mut self, value: Value) -> usize {
+    self.program.constants.push(value);
+    self.program.constants.len() - 1
+  }
+
+  /// Builds a closure for specified node and returns its index.
+  fn closure(&mut self, node: &AstNode) -> Result<usize> {
+    self.program.closures.push(build_evaluator(node)?);
+    Ok(self.program.closures.len() - 1)
+  }
+}
+
+/// Returns `true` when the value of the node does not depend on the scope.
+fn is_constant(node: &AstNode) -> bool {
+  match node {
+    AstNode::In(_, rhs) if matches!(rhs.as_ref(), AstNode::Irrelevant) => true,
+    AstNode::Numeric(_, _) | AstNode::String(_) | AstNode::Boolean(_) | AstNode::Null | AstNode::At(_) | AstNode::Irrelevant => true,
+    AstNode::Add(lhs, rhs)
+    | AstNode::Sub(lhs, rhs)
+    | AstNode::Mul(lhs, rhs)
+    | AstNode::Div(lhs, rhs)
+    | AstNode::Exp(lhs, rhs)
+    | AstNode::Eq(lhs, rhs)
+    | AstNode::Nq(lhs, rhs)
+    | AstNode::Lt(lhs, rhs)
+    | AstNode::Le(lhs, rhs)
+    | AstNode::Gt(lhs, rhs)
+    | AstNode::Ge(lhs, rhs)
+    | AstNode::And(lhs, rhs)
+    | AstNode::Or(lhs, rhs)
+    | AstNode::In(lhs, rhs)
+    | AstNode::Range(lhs, rhs) => is_constant(lhs) && is_constant(rhs),
+    AstNode::Between(lhs, mhs, rhs) | AstNode::If(lhs, mhs, rhs) => is_constant(lhs) && is_constant(mhs) && is_constant(rhs),
+    AstNode::Neg(lhs)
+    | AstNode::UnaryLt(lhs)
+    | AstNode::UnaryLe(lhs)
+    | AstNode::UnaryGt(lhs)
+    | AstNode::UnaryGe(lhs)
+    | AstNode::IntervalStart(lhs, _)
+    | AstNode::IntervalEnd(lhs, _) => is_constant(lhs),
+    AstNode::List(items) | AstNode::ExpressionList(items) | AstNode::NegatedList(items) => items.iter().all(is_constant),
+    _ => false,
+  }
+}
+
+/// Returns `true` when the name refers to a built-in function
+/// that may be evaluated in compile time.
+fn is_foldable_bif(name: &Name) -> bool {
+  matches!(
+    Bif::from_str(&name.to_string()),
+    Ok(Bif::Date | Bif::DateAndTime | Bif::Time | Bif::Duration | Bif::YearsAndMonthsDuration)
+  )
+}
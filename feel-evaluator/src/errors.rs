@@ -59,3 +59,56 @@ pub fn err_unexpected_ast_node(s: &str) -> DmntkError {
 pub fn err_invalid_at_literal(s: &str) -> DmntkError {
   FeelEvaluatorError(format!("invalid at (@) literal '{s}'")).into()
 }
+
+/// Regular expression errors.
+#[derive(ToErrorMessage)]
+struct RegexError(String);
+
+/// Creates an error indicating invalid regular expression flag.
+pub fn err_invalid_regex_flag(flag: char) -> DmntkError {
+  RegexError(format!("invalid flag '{flag}', allowed flags are: s, m, i, x, q")).into()
+}
+
+/// Creates an error indicating invalid regular expression pattern.
+pub fn err_invalid_regex_pattern(pattern: &str, reason: &str) -> DmntkError {
+  RegexError(format!("invalid pattern '{pattern}', {reason}")).into()
+}
+
+/// Creates an error indicating that the pattern matches a zero-length string.
+pub fn err_regex_matches_zero_length_string(pattern: &str) -> DmntkError {
+  RegexError(format!("pattern '{pattern}' matches a zero-length string")).into()
+}
+
+/// Creates an error indicating invalid replacement string.
+pub fn err_invalid_regex_replacement(replacement: &str, reason: &str) -> DmntkError {
+  RegexError(format!("invalid replacement string '{replacement}', {reason}")).into()
+}
+
+/// Holiday calendar errors.
+#[cfg(feature = "calendar")]
+#[derive(ToErrorMessage)]
+struct CalendarError(String);
+
+/// Creates an error indicating invalid line in holiday calendar.
+#[cfg(feature = "calendar")]
+pub fn err_invalid_calendar_line(line: usize, text: &str) -> DmntkError {
+  CalendarError(format!("invalid holiday date in line {line}: '{text}'")).into()
+}
+
+/// Creates an error indicating invalid date in holiday calendar.
+#[cfg(feature = "calendar")]
+pub fn err_invalid_calendar_date(date: &str) -> DmntkError {
+  CalendarError(format!("invalid date '{date}'")).into()
+}
+
+/// Creates an error indicating missing start date of the calendar event.
+#[cfg(feature = "calendar")]
+pub fn err_missing_calendar_event_start() -> DmntkError {
+  CalendarError("event has no start date".to_string()).into()
+}
+
+/// Creates an error indicating unsupported recurrence rule of the calendar event.
+#[cfg(feature = "calendar")]
+pub fn err_unsupported_recurrence_rule(rule: &str) -> DmntkError {
+  CalendarError(format!("unsupported recurrence rule '{rule}', only yearly recurrence is supported")).into()
+}
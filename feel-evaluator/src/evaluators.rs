@@ -30,24 +30,36 @@
  * limitations under the License.
  */
 
+use crate::compiler::{compile, compile_with_spans};
 use crate::errors::err_not_a_context;
 use dmntk_common::Result;
 use dmntk_feel::context::FeelContext;
 use dmntk_feel::values::Value;
 use dmntk_feel::{Evaluator, FeelScope};
-use dmntk_feel_parser::AstNode;
+use dmntk_feel_parser::{AstNode, Spans};
 
 /// Evaluates a [Value] from given [AstNode].
 pub fn evaluate(scope: &FeelScope, node: &AstNode) -> Result<Value> {
-  let evaluator = crate::builders::build_evaluator(node)?;
-  Ok(evaluator(scope))
+  let program = compile(node)?;
+  Ok(program.evaluate(scope))
 }
 
-/// Prepares an evaluator for given [AstNode].
+/// Prepares an evaluator for given [AstNode], the node is compiled into [Program](crate::Program).
 pub fn prepare(node: &AstNode) -> Result<Evaluator> {
+  Ok(compile(node)?.into_evaluator())
+}
+
+/// Prepares an evaluator for given [AstNode] built as a tree of closures, without compilation.
+pub fn prepare_uncompiled(node: &AstNode) -> Result<Evaluator> {
   crate::builders::build_evaluator(node)
 }
 
+/// Prepares an evaluator for given [AstNode] parsed together with its [Spans], reasons of `null` values
+/// are annotated with the span of the innermost expression in source text, that produced the `null` value.
+pub fn prepare_with_spans(node: &AstNode, spans: &Spans) -> Result<Evaluator> {
+  Ok(compile_with_spans(node, spans)?.into_evaluator())
+}
+
 /// Evaluates the sum of specified values.
 pub fn evaluate_sum(values: Vec<Value>) -> Value {
   crate::bifs::core::sum(&values)
@@ -76,8 +88,7 @@ pub fn evaluate_context(scope: &FeelScope, input: &str) -> Result<FeelContext> {
 
 /// Evaluates a context from AST node.
 pub fn evaluate_context_node(scope: &FeelScope, node: &AstNode) -> Result<FeelContext> {
-  let evaluator = crate::builders::build_evaluator(node)?;
-  if let Value::Context(context) = evaluator(scope) {
+  if let Value::Context(context) = compile(node)?.evaluate(scope) {
     Ok(context)
   } else {
     Err(err_not_a_context())
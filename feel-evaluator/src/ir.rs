@@ -0,0 +1,742 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Intermediate representation of compiled `FEEL` expressions.
+//!
+//! Compiled expression is a flat sequence of [Instruction]s executed by a simple stack machine.
+//!
+//! Names bound inside the expression, like context entries, iteration variables, quantified variables
+//! and list items in filters, are resolved at compile time and stored in local slots. Free names are
+//! resolved in scope, which is known only during evaluation; the compiler assigns a slot to every distinct
+//! free name, and the slot caches the value of the name for a single evaluation, so the scope is searched
+//! at most once per name and evaluation. Names that may be unbound at run time, like iteration variables
+//! over empty lists or entries of filtered items, are resolved by a chain of bindings, searched in the order
+//! of nesting.
+//!
+//! Bodies of `for`, `some` and `every` expressions and conditions of filters are blocks of instructions
+//! following the instruction that evaluates them, ended with [Instruction::Return].
+//! Bodies of function definitions are compiled into separate programs, invoked functions and closures
+//! see local names in scope, like in expressions evaluated without compilation.
+//! Only types, `instance of` tests, qualified names and external functions are evaluated by closures
+//! prepared by evaluator builders.
+
+use crate::builders::*;
+use crate::iterations::{EveryExpressionEvaluator, ForExpressionEvaluator, SomeExpressionEvaluator};
+use dmntk_common::Span;
+use dmntk_feel::closure::Closure;
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::values::Value;
+use dmntk_feel::{value_null, Evaluator, FeelScope, FeelType, FunctionBody, Name, QualifiedName};
+use smallvec::SmallVec;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fmt::Display;
+
+/// Stack of values used during evaluation, small stacks are not allocated on the heap.
+type Stack = SmallVec<[Value; 8]>;
+
+/// Values of names cached during evaluation.
+type Slots = SmallVec<[Option<Value>; 4]>;
+
+/// Values of local names during evaluation.
+type Locals = SmallVec<[Option<Value>; 8]>;
+
+/// Single instruction of the compiled expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Instruction {
+  /// Pushes a constant value.
+  Const(usize),
+  /// Pushes the value of the free name stored in a slot, the value is cached during evaluation.
+  Load(usize),
+  /// Pushes the value of the free name stored in a slot, the value is not cached
+  /// because the name is used only once.
+  Lookup(usize),
+  /// Pushes the value of the local name stored in a slot.
+  Local(usize),
+  /// Pushes the value of the name resolved by the specified chain of bindings.
+  Resolve(usize),
+  /// Pops the value from the top of the stack and stores it in a local slot.
+  Store(usize),
+  /// Builds a context from the values of local names, stored by previous instructions.
+  Context(usize),
+  /// Pushes the value evaluated by a closure, the whole subexpression
+  /// of the closure is evaluated without compilation, local names
+  /// of specified environment are pushed to scope during evaluation.
+  Closure(usize, Option<usize>),
+  /// Pushes a constant value calculated by a built-in function in compile time,
+  /// when the name in a slot still refers to a built-in function, otherwise
+  /// pushes the value evaluated by a closure.
+  Folded(usize, usize, usize),
+  /// Arithmetic operation on two values on top of the stack.
+  Arithmetic(Arithmetic),
+  /// Arithmetic operation on the value on top of the stack and a constant value.
+  ArithmeticConst(Arithmetic, usize),
+  /// Arithmetic negation of the value on top of the stack.
+  Neg,
+  /// Comparison of two values on top of the stack.
+  Compare(Comparison),
+  /// Comparison of the value on top of the stack with a constant value,
+  /// the constant value is not copied to the stack.
+  CompareConst(Comparison, usize),
+  /// Comparison `between` of three values on top of the stack.
+  Between,
+  /// Conjunction of two values on top of the stack.
+  And,
+  /// Disjunction of two values on top of the stack.
+  Or,
+  /// Jumps to specified instruction when the value on top of the stack is `false`,
+  /// the value is left on the stack as the result of conjunction.
+  JumpIfFalse(usize),
+  /// Jumps to specified instruction when the value on top of the stack is `true`,
+  /// the value is left on the stack as the result of disjunction.
+  JumpIfTrue(usize),
+  /// Pops the condition of `if` expression, continues when the condition is `true`,
+  /// jumps to the first instruction when the condition is `false` or `null`,
+  /// otherwise pushes `null` and jumps to the second instruction.
+  If(usize, usize),
+  /// Jumps to specified instruction.
+  Jump(usize),
+  /// Builds a list from the specified number of values on top of the stack.
+  List(usize),
+  /// Builds an expression list from the specified number of values on top of the stack.
+  ExpressionList(usize),
+  /// Builds a negated list from the specified number of values on top of the stack.
+  NegatedList(usize),
+  /// Builds unary `<` test from the value on top of the stack.
+  UnaryLt,
+  /// Builds unary `<=` test from the value on top of the stack.
+  UnaryLe,
+  /// Builds unary `>` test from the value on top of the stack.
+  UnaryGt,
+  /// Builds unary `>=` test from the value on top of the stack.
+  UnaryGe,
+  /// Builds the start of an interval from the value on top of the stack.
+  IntervalStart(bool),
+  /// Builds the end of an interval from the value on top of the stack.
+  IntervalEnd(bool),
+  /// Builds a range from two values on top of the stack.
+  Range,
+  /// Evaluates the path expression with specified path on the value on top of the stack.
+  Path(usize),
+  /// Evaluates the following block for every iteration of `for` expression,
+  /// iteration contexts are built from the values on top of the stack.
+  For(usize),
+  /// Evaluates the following block for every iteration of `some` expression,
+  /// quantified contexts are built from the values on top of the stack.
+  Some(usize),
+  /// Evaluates the following block for every iteration of `every` expression,
+  /// quantified contexts are built from the values on top of the stack.
+  Every(usize),
+  /// Evaluates the following block as the condition of the filter
+  /// applied to the value on top of the stack.
+  Filter(usize),
+  /// Ends the block, the value on top of the stack is the result of the block.
+  Return,
+  /// Pushes the definition of a function, the closure of the function
+  /// is evaluated with local names of specified environment pushed to scope.
+  Function(usize, Option<usize>),
+  /// Invokes the function with specified number of positional arguments on top of the stack,
+  /// local names of specified environment are pushed to scope during the invocation.
+  Invoke(usize, Option<usize>),
+  /// Invokes the function with named arguments on top of the stack, with names
+  /// at specified index, local names of specified environment are pushed to scope
+  /// during the invocation.
+  InvokeNamed(usize, Option<usize>),
+}
+
+/// Arithmetic operators.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Arithmetic {
+  /// Addition.
+  Add,
+  /// Subtraction.
+  Sub,
+  /// Multiplication.
+  Mul,
+  /// Division.
+  Div,
+  /// Exponentiation.
+  Exp,
+}
+
+impl Arithmetic {
+  /// Evaluates arithmetic operation on two values.
+  fn evaluate(&self, lhv: Value, rhv: Value) -> Value {
+    match self {
+      Arithmetic::Add => eval_add(lhv, rhv),
+      Arithmetic::Sub => eval_sub(lhv, rhv),
+      Arithmetic::Mul => eval_mul(lhv, rhv),
+      Arithmetic::Div => eval_div(lhv, rhv),
+      Arithmetic::Exp => eval_exp(lhv, rhv),
+    }
+  }
+}
+
+/// Comparison operators, including `in` operator.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Comparison {
+  /// Comparison `=`.
+  Eq,
+  /// Comparison `!=`.
+  Nq,
+  /// Comparison `<`.
+  Lt,
+  /// Comparison `<=`.
+  Le,
+  /// Comparison `>`.
+  Gt,
+  /// Comparison `>=`.
+  Ge,
+  /// Operator `in`.
+  In,
+}
+
+impl Comparison {
+  /// Compares two values.
+  fn evaluate(&self, lhv: &Value, rhv: &Value) -> Value {
+    match self {
+      Comparison::Eq => eval_eq(lhv, rhv),
+      Comparison::Nq => eval_nq(lhv, rhv),
+      Comparison::Lt => eval_lt(lhv, rhv),
+      Comparison::Le => eval_le(lhv, rhv),
+      Comparison::Gt => eval_gt(lhv, rhv),
+      Comparison::Ge => eval_ge(lhv, rhv),
+      Comparison::In => eval_in(lhv, rhv),
+    }
+  }
+}
+
+/// Path used in path expression.
+pub(crate) struct PathSegments {
+  /// Full qualified name of the path.
+  pub qualified_name: QualifiedName,
+  /// Qualified name without the last segment.
+  pub property_path: QualifiedName,
+  /// Last segment of the path.
+  pub property_name: Name,
+}
+
+/// Binding of a name, searched when the name is resolved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Binding {
+  /// Name bound to a local slot, skipped when the slot has no value.
+  Local(usize),
+  /// Name available in filter condition, bound to an entry of the list item stored in a local slot,
+  /// or to the item itself when the flag is `true` (the name is `item`).
+  Item(usize, bool),
+  /// Free name stored in a slot, resolved in scope.
+  Free(usize),
+}
+
+/// Name resolved by a chain of bindings.
+pub(crate) struct Resolution {
+  /// Resolved name.
+  pub name: Name,
+  /// Bindings searched in order, the last binding is always a free name.
+  pub bindings: Vec<Binding>,
+}
+
+/// Local names visible in some part of the compiled expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Frame {
+  /// Local slots of already evaluated context entries.
+  Context(Vec<usize>),
+  /// Local slots of iteration variables.
+  Iteration(Vec<usize>),
+  /// Local slot of the list item in filter condition.
+  Item(usize),
+}
+
+/// Iterations of `for` expression.
+pub(crate) struct Loop {
+  /// Local slots of variables iterating over lists.
+  pub singles: Vec<usize>,
+  /// Local slots of variables iterating over ranges.
+  pub ranges: Vec<usize>,
+  /// Local slot of partial results.
+  pub partial: usize,
+  /// Index of the instruction following the body of the loop.
+  pub end: usize,
+}
+
+/// Iterations of `some` or `every` expression.
+pub(crate) struct Quantifier {
+  /// Local slots of quantified variables.
+  pub variables: Vec<usize>,
+  /// Index of the instruction following the `satisfies` block.
+  pub end: usize,
+}
+
+/// Filter applied to a list.
+pub(crate) struct Filter {
+  /// Local slot of the list item.
+  pub item: usize,
+  /// Index of the instruction following the condition block.
+  pub end: usize,
+}
+
+/// Function definition with compiled body.
+pub(crate) struct Function {
+  /// Formal parameters of the function.
+  pub parameters: Vec<(Name, FeelType)>,
+  /// Body of the function.
+  pub body: FunctionBody,
+  /// Names used in the body of the function, captured from scope.
+  pub closure: Closure,
+}
+
+/// Compiled `FEEL` expression.
+#[derive(Default)]
+pub struct Program {
+  /// Instructions of the program.
+  pub(crate) instructions: Vec<Instruction>,
+  /// Spans of expressions in source text, that instructions were compiled from.
+  pub(crate) spans: Vec<Option<Span>>,
+  /// Constant values.
+  pub(crate) constants: Vec<Value>,
+  /// Free names resolved to slots.
+  pub(crate) names: Vec<Name>,
+  /// Local names resolved to slots.
+  pub(crate) locals: Vec<Name>,
+  /// Names resolved by chains of bindings.
+  pub(crate) resolutions: Vec<Resolution>,
+  /// Local names visible in parts of the expression, pushed to scope when needed.
+  pub(crate) environments: Vec<Vec<Frame>>,
+  /// Local slots of context entries.
+  pub(crate) contexts: Vec<Vec<usize>>,
+  /// Closures evaluating not compiled subexpressions.
+  pub(crate) closures: Vec<Evaluator>,
+  /// Paths used in path expressions.
+  pub(crate) paths: Vec<PathSegments>,
+  /// Iterations of `for` expressions.
+  pub(crate) loops: Vec<Loop>,
+  /// Iterations of `some` and `every` expressions.
+  pub(crate) quantifiers: Vec<Quantifier>,
+  /// Filters.
+  pub(crate) filters: Vec<Filter>,
+  /// Function definitions.
+  pub(crate) functions: Vec<Function>,
+  /// Names of arguments in invocations with named parameters.
+  pub(crate) arguments: Vec<Vec<Name>>,
+}
+
+/// Values of names during a single evaluation.
+struct State {
+  /// Cached values of free names.
+  slots: Slots,
+  /// Values of local names.
+  locals: Locals,
+}
+
+impl Program {
+  /// Returns instructions of this program.
+  pub fn instructions(&self) -> &[Instruction] {
+    &self.instructions
+  }
+
+  /// Converts this program into an evaluator, programs consisting of a single
+  /// instruction are converted into evaluators that do not run the stack machine,
+  /// unless the result of the instruction has to be annotated with a span.
+  pub fn into_evaluator(mut self) -> Evaluator {
+    let span = self.spans.first().copied().flatten();
+    match (self.instructions.as_slice(), span) {
+      ([Instruction::Const(index)], _) => {
+        let value = self.constants.swap_remove(*index);
+        Box::new(move |_: &FeelScope| value.clone())
+      }
+      ([Instruction::Lookup(slot)], None) => {
+        let name = self.names.swap_remove(*slot);
+        Box::new(move |scope: &FeelScope| eval_name(scope, &name))
+      }
+      ([Instruction::Closure(index, None)], None) => self.closures.swap_remove(*index),
+      _ => Box::new(move |scope: &FeelScope| self.evaluate(scope)),
+    }
+  }
+
+  /// Evaluates this program in specified scope.
+  pub fn evaluate(&self, scope: &FeelScope) -> Value {
+    let mut state = State {
+      slots: SmallVec::new(),
+      locals: SmallVec::from_elem(None, self.locals.len()),
+    };
+    self.execute(scope, &mut state, 0)
+  }
+
+  /// Executes instructions starting from specified index, until the end of the program or the end of the block.
+  fn execute(&self, scope: &FeelScope, state: &mut State, mut pc: usize) -> Value {
+    let mut stack: Stack = SmallVec::new();
+    while let Some(instruction) = self.instructions.get(pc) {
+      let span = self.spans.get(pc).copied().flatten();
+      pc += 1;
+      match instruction {
+        Instruction::Const(index) => stack.push(self.constants[*index].clone()),
+        Instruction::Load(slot) => stack.push(self.load(scope, &mut state.slots, *slot).clone()),
+        Instruction::Lookup(slot) => stack.push(eval_name(scope, &self.names[*slot])),
+        Instruction::Local(slot) => stack.push(
+          state.locals[*slot]
+            .clone()
+            .unwrap_or_else(|| value_null!("no value for local name '{}'", self.locals[*slot])),
+        ),
+        Instruction::Resolve(index) => stack.push(self.resolve(scope, state, *index)),
+        Instruction::Store(slot) => state.locals[*slot] = Some(pop(&mut stack)),
+        Instruction::Context(index) => {
+          let mut context = FeelContext::default();
+          for slot in &self.contexts[*index] {
+            if let Some(value) = state.locals[*slot].take() {
+              context.set_entry(&self.locals[*slot], value);
+            }
+          }
+          stack.push(Value::Context(context));
+        }
+        Instruction::Closure(index, environment) => {
+          let count = self.enter(scope, state, *environment);
+          stack.push(self.closures[*index](scope));
+          leave(scope, count);
+        }
+        Instruction::Folded(slot, index, closure) => {
+          if let Value::BuiltInFunction(_) = self.load(scope, &mut state.slots, *slot) {
+            stack.push(self.constants[*index].clone());
+          } else {
+            stack.push(self.closures[*closure](scope));
+          }
+        }
+        Instruction::Arithmetic(arithmetic) => binary(&mut stack, |lhv, rhv| arithmetic.evaluate(lhv, rhv)),
+        Instruction::ArithmeticConst(arithmetic, index) => unary(&mut stack, |lhv| arithmetic.evaluate(lhv, self.constants[*index].clone())),
+        Instruction::Neg => unary(&mut stack, eval_neg),
+        Instruction::Compare(comparison) => {
+          let rhv = pop(&mut stack);
+          let lhv = pop(&mut stack);
+          stack.push(comparison.evaluate(&lhv, &rhv));
+        }
+        Instruction::CompareConst(comparison, index) => {
+          let lhv = pop(&mut stack);
+          stack.push(comparison.evaluate(&lhv, &self.constants[*index]));
+        }
+        Instruction::Between => {
+          let rhv = pop(&mut stack);
+          let mhv = pop(&mut stack);
+          let lhv = pop(&mut stack);
+          stack.push(eval_between(lhv, mhv, rhv));
+        }
+        Instruction::And => binary(&mut stack, eval_and),
+        Instruction::Or => binary(&mut stack, eval_or),
+        Instruction::JumpIfFalse(target) => {
+          if let Some(Value::Boolean(false)) = stack.last() {
+            pc = *target;
+          }
+        }
+        Instruction::JumpIfTrue(target) => {
+          if let Some(Value::Boolean(true)) = stack.last() {
+            pc = *target;
+          }
+        }
+        Instruction::If(else_target, end_target) => match pop(&mut stack) {
+          Value::Boolean(true) => {}
+          Value::Boolean(false) | Value::Null(_) => pc = *else_target,
+          _ => {
+            stack.push(value_null!("condition in 'if' expression is not a boolean value"));
+            pc = *end_target;
+          }
+        },
+        Instruction::Jump(target) => pc = *target,
+        Instruction::List(count) => {
+          let values = stack.drain(stack.len() - count..).collect();
+          stack.push(Value::List(values));
+        }
+        Instruction::ExpressionList(count) => {
+          let values = stack.drain(stack.len() - count..).collect();
+          stack.push(Value::ExpressionList(values));
+        }
+        Instruction::NegatedList(count) => {
+          let values = stack.drain(stack.len() - count..).collect();
+          stack.push(Value::NegatedCommaList(values));
+        }
+        Instruction::UnaryLt => unary(&mut stack, |value| Value::UnaryLess(Box::new(value))),
+        Instruction::UnaryLe => unary(&mut stack, |value| Value::UnaryLessOrEqual(Box::new(value))),
+        Instruction::UnaryGt => unary(&mut stack, |value| Value::UnaryGreater(Box::new(value))),
+        Instruction::UnaryGe => unary(&mut stack, |value| Value::UnaryGreaterOrEqual(Box::new(value))),
+        Instruction::IntervalStart(closed) => unary(&mut stack, |value| Value::IntervalStart(Box::new(value), *closed)),
+        Instruction::IntervalEnd(closed) => unary(&mut stack, |value| Value::IntervalEnd(Box::new(value), *closed)),
+        Instruction::Range => binary(&mut stack, eval_range),
+        Instruction::Path(index) => {
+          let path = &self.paths[*index];
+          unary(&mut stack, |value| eval_path(value, &path.qualified_name, &path.property_path, &path.property_name));
+        }
+        Instruction::For(index) => {
+          let lp = &self.loops[*index];
+          let mut values = stack.drain(stack.len() - lp.singles.len() - 2 * lp.ranges.len()..).collect::<Vec<Value>>().into_iter();
+          let mut expression_evaluator = ForExpressionEvaluator::new();
+          for slot in &lp.singles {
+            expression_evaluator.add_single(self.locals[*slot].clone(), values.next().unwrap_or_else(|| value_null!()));
+          }
+          for slot in &lp.ranges {
+            let range_start = values.next().unwrap_or_else(|| value_null!());
+            let range_end = values.next().unwrap_or_else(|| value_null!());
+            expression_evaluator.add_range(self.locals[*slot].clone(), range_start, range_end);
+          }
+          let results = expression_evaluator.evaluate_with(|iteration_context| {
+            for slot in lp.singles.iter().chain(&lp.ranges).chain([&lp.partial]) {
+              state.locals[*slot] = iteration_context.get_entry(&self.locals[*slot]).cloned();
+            }
+            self.execute(scope, state, pc)
+          });
+          stack.push(Value::List(results));
+          pc = lp.end;
+        }
+        Instruction::Some(index) => {
+          let quantifier = &self.quantifiers[*index];
+          let mut expression_evaluator = SomeExpressionEvaluator::new();
+          let values = stack.drain(stack.len() - quantifier.variables.len()..).collect::<Vec<Value>>();
+          for (slot, value) in quantifier.variables.iter().zip(values) {
+            expression_evaluator.add(self.locals[*slot].clone(), value);
+          }
+          let result = expression_evaluator.evaluate_with(|quantified_context| self.iterate(scope, state, quantified_context, &quantifier.variables, pc));
+          stack.push(result);
+          pc = quantifier.end;
+        }
+        Instruction::Every(index) => {
+          let quantifier = &self.quantifiers[*index];
+          let mut expression_evaluator = EveryExpressionEvaluator::new();
+          let values = stack.drain(stack.len() - quantifier.variables.len()..).collect::<Vec<Value>>();
+          for (slot, value) in quantifier.variables.iter().zip(values) {
+            expression_evaluator.add(self.locals[*slot].clone(), value);
+          }
+          let result = expression_evaluator.evaluate_with(|quantified_context| self.iterate(scope, state, quantified_context, &quantifier.variables, pc));
+          stack.push(result);
+          pc = quantifier.end;
+        }
+        Instruction::Filter(index) => {
+          let filter = &self.filters[*index];
+          let lhv = pop(&mut stack);
+          stack.push(eval_filter(lhv, |item| {
+            state.locals[filter.item] = item.cloned();
+            self.execute(scope, state, pc)
+          }));
+          pc = filter.end;
+        }
+        Instruction::Return => break,
+        Instruction::Function(index, environment) => {
+          let function = &self.functions[*index];
+          let count = self.enter(scope, state, *environment);
+          let closure_ctx = eval_closure_context(scope, &function.closure);
+          leave(scope, count);
+          //TODO is `FeelType::Any` always ok for function result type in function definition?
+          stack.push(Value::FunctionDefinition(
+            function.parameters.clone(),
+            function.body.clone(),
+            false,
+            function.closure.clone(),
+            closure_ctx,
+            FeelType::Any,
+          ));
+        }
+        Instruction::Invoke(count, environment) => {
+          let args: Vec<Value> = stack.drain(stack.len() - count..).collect();
+          let function = pop(&mut stack);
+          let count = self.enter_invocation(scope, state, &function, *environment);
+          stack.push(eval_function_invocation_with_positional_parameters(scope, function, &args));
+          leave(scope, count);
+        }
+        Instruction::InvokeNamed(index, environment) => {
+          let names = &self.arguments[*index];
+          let mut parameters = BTreeMap::new();
+          for (position, (name, value)) in names.iter().zip(stack.drain(stack.len() - names.len()..)).enumerate() {
+            parameters.insert(name.clone(), (value, position + 1));
+          }
+          let function = pop(&mut stack);
+          let count = self.enter_invocation(scope, state, &function, *environment);
+          stack.push(eval_function_invocation_with_named_parameters(scope, function, &Value::NamedParameters(parameters)));
+          leave(scope, count);
+        }
+      }
+      if let Some(span) = span {
+        annotate(&mut stack, instruction, &span);
+      }
+    }
+    pop(&mut stack)
+  }
+
+  /// Returns the value of the free name stored in a slot, the name is searched in scope
+  /// on the first access during evaluation, and the value is cached for subsequent accesses.
+  fn load<'a>(&self, scope: &FeelScope, slots: &'a mut Slots, slot: usize) -> &'a Value {
+    if slots.is_empty() {
+      slots.resize(self.names.len(), None);
+    }
+    slots[slot].get_or_insert_with(|| eval_name(scope, &self.names[slot]))
+  }
+
+  /// Returns the value of the name resolved by the first binding that has a value.
+  fn resolve(&self, scope: &FeelScope, state: &mut State, index: usize) -> Value {
+    let resolution = &self.resolutions[index];
+    for binding in &resolution.bindings {
+      match binding {
+        Binding::Local(slot) => {
+          if let Some(value) = &state.locals[*slot] {
+            return value.clone();
+          }
+        }
+        Binding::Item(slot, is_item) => match &state.locals[*slot] {
+          Some(Value::Context(context)) if context.contains_entry(&resolution.name) => return context.get_entry(&resolution.name).cloned().unwrap_or_else(|| value_null!()),
+          Some(value) if *is_item => return value.clone(),
+          _ => {}
+        },
+        Binding::Free(slot) => return self.load(scope, &mut state.slots, *slot).clone(),
+      }
+    }
+    value_null!("context has no value for key '{}'", resolution.name)
+  }
+
+  /// Evaluates the block starting at specified instruction for a single iteration of quantified expression.
+  fn iterate(&self, scope: &FeelScope, state: &mut State, context: &FeelContext, variables: &[usize], pc: usize) -> Value {
+    for slot in variables {
+      state.locals[*slot] = context.get_entry(&self.locals[*slot]).cloned();
+    }
+    self.execute(scope, state, pc)
+  }
+
+  /// Pushes local names of specified environment to scope, returns the number of contexts pushed.
+  fn enter(&self, scope: &FeelScope, state: &State, environment: Option<usize>) -> usize {
+    let Some(index) = environment else {
+      return 0;
+    };
+    let mut count = 0;
+    for frame in &self.environments[index] {
+      match frame {
+        Frame::Context(slots) | Frame::Iteration(slots) => {
+          let mut context = FeelContext::default();
+          for slot in slots {
+            if let Some(value) = &state.locals[*slot] {
+              context.set_entry(&self.locals[*slot], value.clone());
+            }
+          }
+          scope.push(context);
+          count += 1;
+        }
+        Frame::Item(slot) => {
+          if let Some(item) = &state.locals[*slot] {
+            count += push_item(scope, item);
+          }
+        }
+      }
+    }
+    count
+  }
+
+  /// Pushes local names of specified environment to scope before invoking a function definition,
+  /// built-in functions do not use the scope.
+  fn enter_invocation(&self, scope: &FeelScope, state: &State, function: &Value, environment: Option<usize>) -> usize {
+    if let Value::FunctionDefinition(..) = function {
+      self.enter(scope, state, environment)
+    } else {
+      0
+    }
+  }
+}
+
+impl Display for Program {
+  /// Converts [Program] into human readable listing of instructions.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    for (index, instruction) in self.instructions.iter().enumerate() {
+      write!(f, "{index:>4}: ")?;
+      match instruction {
+        Instruction::Const(index) => writeln!(f, "const {}", self.constants[*index])?,
+        Instruction::Load(slot) => writeln!(f, "load {}", self.names[*slot])?,
+        Instruction::Lookup(slot) => writeln!(f, "lookup {}", self.names[*slot])?,
+        Instruction::Local(slot) => writeln!(f, "local {}", self.locals[*slot])?,
+        Instruction::Resolve(index) => writeln!(f, "resolve {}", self.resolutions[*index].name)?,
+        Instruction::Store(slot) => writeln!(f, "store {}", self.locals[*slot])?,
+        Instruction::Closure(index, _) => writeln!(f, "closure {index}")?,
+        Instruction::Folded(slot, index, closure) => writeln!(f, "folded {} {} closure {closure}", self.names[*slot], self.constants[*index])?,
+        Instruction::ArithmeticConst(arithmetic, index) => writeln!(f, "arithmetic {} const {}", format!("{arithmetic:?}").to_lowercase(), self.constants[*index])?,
+        Instruction::CompareConst(comparison, index) => writeln!(f, "compare {} const {}", format!("{comparison:?}").to_lowercase(), self.constants[*index])?,
+        Instruction::Path(index) => writeln!(f, "path {}", self.paths[*index].qualified_name)?,
+        Instruction::For(index) => writeln!(f, "for end {}", self.loops[*index].end)?,
+        Instruction::Some(index) => writeln!(f, "some end {}", self.quantifiers[*index].end)?,
+        Instruction::Every(index) => writeln!(f, "every end {}", self.quantifiers[*index].end)?,
+        Instruction::Filter(index) => writeln!(f, "filter end {}", self.filters[*index].end)?,
+        Instruction::Function(index, _) => writeln!(f, "function {index}")?,
+        Instruction::Invoke(count, _) => writeln!(f, "invoke {count}")?,
+        Instruction::InvokeNamed(index, _) => writeln!(
+          f,
+          "invoke named {}",
+          self.arguments[*index].iter().map(|name| name.to_string()).collect::<Vec<String>>().join(", ")
+        )?,
+        other => writeln!(f, "{}", format!("{other:?}").to_lowercase())?,
+      }
+    }
+    Ok(())
+  }
+}
+
+/// Pops contexts pushed to scope.
+fn leave(scope: &FeelScope, count: usize) {
+  for _ in 0..count {
+    scope.pop();
+  }
+}
+
+/// Annotates the reason of `null` value produced by an instruction with the span
+/// of the expression the instruction was compiled from. Instructions that do not
+/// leave their result on top of the stack are skipped.
+fn annotate(stack: &mut Stack, instruction: &Instruction, span: &Span) {
+  if matches!(
+    instruction,
+    Instruction::Store(_) | Instruction::JumpIfFalse(_) | Instruction::JumpIfTrue(_) | Instruction::If(..) | Instruction::Jump(_) | Instruction::Return
+  ) {
+    return;
+  }
+  if let Some(value @ Value::Null(Some(_))) = stack.last_mut() {
+    *value = std::mem::replace(value, Value::Null(None)).with_span(span);
+  }
+}
+
+/// Pops a value from the stack, missing values are reported as `null`.
+fn pop(stack: &mut Stack) -> Value {
+  stack.pop().unwrap_or_else(|| value_null!("stack underflow in compiled expression"))
+}
+
+/// Replaces the value on top of the stack with the result of unary operation.
+fn unary(stack: &mut Stack, operation: impl FnOnce(Value) -> Value) {
+  if let Some(top) = stack.last_mut() {
+    let value = std::mem::replace(top, Value::Irrelevant);
+    *top = operation(value);
+  } else {
+    let value = pop(stack);
+    stack.push(operation(value));
+  }
+}
+
+/// Replaces two values on top of the stack with the result of binary operation.
+fn binary(stack: &mut Stack, operation: impl FnOnce(Value, Value) -> Value) {
+  let rhv = pop(stack);
+  unary(stack, |lhv| operation(lhv, rhv));
+}
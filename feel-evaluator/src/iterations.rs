@@ -200,14 +200,21 @@ impl ForExpressionEvaluator {
   }
   ///
   pub fn evaluate(&mut self, scope: &FeelScope, evaluator: &Evaluator) -> Values {
+    self.evaluate_with(|iteration_context| {
+      scope.push(iteration_context.clone());
+      let iteration_value = evaluator(scope);
+      scope.pop();
+      iteration_value
+    })
+  }
+  /// Evaluates the result of every iteration using specified handler,
+  /// the handler receives the iteration context including partial results.
+  pub fn evaluate_with(&mut self, mut handler: impl FnMut(&FeelContext) -> Value) -> Values {
     let mut results = vec![];
     self.feel_iterator.run(|ctx| {
       let mut iteration_context = ctx.clone();
       iteration_context.set_entry(&self.name_partial, Value::List(results.clone()));
-      scope.push(iteration_context.clone());
-      let iteration_value = evaluator(scope);
-      scope.pop();
-      results.push(iteration_value);
+      results.push(handler(&iteration_context));
     });
     results
   }
@@ -235,13 +242,21 @@ impl SomeExpressionEvaluator {
   }
   ///
   pub fn evaluate(&mut self, scope: &FeelScope, evaluator: &Evaluator) -> Value {
+    self.evaluate_with(|ctx| {
+      scope.push(ctx.clone());
+      let value = evaluator(scope);
+      scope.pop();
+      value
+    })
+  }
+  /// Evaluates the result of every iteration using specified handler,
+  /// the handler receives the iteration context.
+  pub fn evaluate_with(&mut self, mut handler: impl FnMut(&FeelContext) -> Value) -> Value {
     let mut result = false;
     self.feel_iterator.run(|ctx| {
-      scope.push(ctx.clone());
-      if let Value::Boolean(value) = evaluator(scope) {
+      if let Value::Boolean(value) = handler(ctx) {
         result = result || value;
       }
-      scope.pop();
     });
     Value::Boolean(result)
   }
@@ -269,13 +284,21 @@ impl EveryExpressionEvaluator {
   }
   ///
   pub fn evaluate(&mut self, scope: &FeelScope, evaluator: &Evaluator) -> Value {
+    self.evaluate_with(|ctx| {
+      scope.push(ctx.clone());
+      let value = evaluator(scope);
+      scope.pop();
+      value
+    })
+  }
+  /// Evaluates the result of every iteration using specified handler,
+  /// the handler receives the iteration context.
+  pub fn evaluate_with(&mut self, mut handler: impl FnMut(&FeelContext) -> Value) -> Value {
     let mut result = true;
     self.feel_iterator.run(|ctx| {
-      scope.push(ctx.clone());
-      if let Value::Boolean(value) = evaluator(scope) {
+      if let Value::Boolean(value) = handler(ctx) {
         result = result && value;
       }
-      scope.pop();
     });
     Value::Boolean(result)
   }
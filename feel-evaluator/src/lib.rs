@@ -37,12 +37,20 @@ extern crate dmntk_macros;
 
 mod bifs;
 mod builders;
+mod compiler;
 mod errors;
 mod evaluators;
+mod ir;
 mod iterations;
 mod macros;
 
 #[cfg(test)]
 mod tests;
 
-pub use crate::evaluators::{evaluate, evaluate_context, evaluate_context_node, evaluate_equals, evaluate_max, evaluate_min, evaluate_sum, prepare};
+#[cfg(feature = "calendar")]
+pub use crate::bifs::calendar::{with_calendars, HolidayCalendar, HolidayCalendars};
+pub use crate::compiler::{compile, compile_with_spans};
+pub use crate::evaluators::{
+  evaluate, evaluate_context, evaluate_context_node, evaluate_equals, evaluate_max, evaluate_min, evaluate_sum, prepare, prepare_uncompiled, prepare_with_spans,
+};
+pub use crate::ir::{Arithmetic, Comparison, Instruction, Program};
@@ -56,3 +56,33 @@ macro_rules! invalid_number_of_parameters {
 }
 
 pub(crate) use invalid_number_of_parameters;
+
+/// Builds number value from the result of decimal arithmetic operation,
+/// or `null` value with a message when the result is not a valid number.
+/// Operations on infinite numbers do not raise any flags, so the result is also checked to be finite.
+macro_rules! number_or_null {
+  ($operation:literal, $result:expr) => {{
+    use dmntk_feel::value_null;
+    use dmntk_feel::values::Value;
+    let (number, status) = $result;
+    match status.check() {
+      Ok(()) if number.is_finite() => Value::Number(number),
+      Ok(()) => value_null!("[{}] result is not a finite number", $operation),
+      Err(reason) => value_null!("[{}] {}", $operation, reason),
+    }
+  }};
+}
+
+pub(crate) use number_or_null;
+
+/// Builds `null` value with a message informing that the extension function is not enabled.
+#[cfg(not(feature = "calendar"))]
+macro_rules! extension_not_enabled {
+  ($bif:expr) => {{
+    use dmntk_feel::value_null;
+    value_null!("extension function {:?} is not enabled, build with `calendar` feature", $bif)
+  }};
+}
+
+#[cfg(not(feature = "calendar"))]
+pub(crate) use extension_not_enabled;
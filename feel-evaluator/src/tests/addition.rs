@@ -347,3 +347,8 @@ fn _0045() {
     "[builders::add] invalid argument type, expected days and time duration, actual type is number",
   );
 }
+
+#[test]
+fn _0046() {
+  te_null(false, &scope!(), r#" 9 * 10 ** 6144 + 9 * 10 ** 6144 "#, "[addition] <FeelNumberError> overflow");
+}
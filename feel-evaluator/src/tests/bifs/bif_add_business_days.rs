@@ -0,0 +1,187 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::super::*;
+use crate::{with_calendars, HolidayCalendar, HolidayCalendars};
+use dmntk_feel::scope;
+use std::sync::Arc;
+
+fn calendars() -> Arc<HolidayCalendars> {
+  let calendar = HolidayCalendar::from_csv("date,name\n2023-12-25,Christmas Day\n2023-12-26,Second Day of Christmas\n2024-01-01,New Year's Day\n").unwrap();
+  let mut calendars = HolidayCalendars::default();
+  calendars.insert("ABD", calendar);
+  Arc::new(calendars)
+}
+
+fn yearly_calendars() -> Arc<HolidayCalendars> {
+  let calendar = HolidayCalendar::from_icalendar(
+    "BEGIN:VCALENDAR\nBEGIN:VEVENT\nDTSTART;VALUE=DATE:20000101\nRRULE:FREQ=YEARLY\nEND:VEVENT\nBEGIN:VEVENT\nDTSTART;VALUE=DATE:20001225\nRRULE:FREQ=YEARLY\nEND:VEVENT\nEND:VCALENDAR\n",
+  )
+  .unwrap();
+  let mut calendars = HolidayCalendars::default();
+  calendars.insert("YRL", calendar);
+  Arc::new(calendars)
+}
+
+#[test]
+fn _0001() {
+  te_date(false, &scope!(), r#"dmntk.add business days(date(2023,12,22),1)"#, 2023, 12, 25);
+}
+
+#[test]
+fn _0002() {
+  with_calendars(&calendars(), || {
+    te_date(false, &scope!(), r#"dmntk.add business days(date(2023,12,22),1,"ABD")"#, 2023, 12, 27)
+  });
+}
+
+#[test]
+fn _0003() {
+  with_calendars(&calendars(), || {
+    te_date(false, &scope!(), r#"dmntk.add business days(date(2023,12,27),-1,"ABD")"#, 2023, 12, 22)
+  });
+}
+
+#[test]
+fn _0004() {
+  with_calendars(&calendars(), || {
+    te_date(false, &scope!(), r#"dmntk.add business days(date(2023,12,22),5,"ABD")"#, 2024, 1, 3)
+  });
+}
+
+#[test]
+fn _0005() {
+  te_date(false, &scope!(), r#"dmntk.add business days(date(2023,12,23),0)"#, 2023, 12, 23);
+}
+
+#[test]
+fn _0006() {
+  with_calendars(&calendars(), || {
+    te_date_time_local(
+      false,
+      &scope!(),
+      r#"dmntk.add business days(date and time("2023-12-22T10:30:00"),2,"ABD")"#,
+      (2023, 12, 28),
+      (10, 30, 0, 0),
+    );
+  });
+}
+
+#[test]
+fn _0007() {
+  with_calendars(&calendars(), || {
+    te_date(
+      false,
+      &scope!(),
+      r#"dmntk.add business days(date: date(2023,12,22), days: 1, calendar: "ABD")"#,
+      2023,
+      12,
+      27,
+    );
+  });
+}
+
+#[test]
+fn _0008() {
+  te_date(false, &scope!(), r#"dmntk.add business days(days: 1, date: date(2023,12,22))"#, 2023, 12, 25);
+}
+
+#[test]
+fn _0009() {
+  te_null(
+    false,
+    &scope!(),
+    r#"dmntk.add business days(date(2023,12,22),1,"UNKNOWN")"#,
+    r#"[dmntk.add business days] calendar 'UNKNOWN' not found"#,
+  );
+}
+
+#[test]
+fn _0010() {
+  te_null(
+    false,
+    &scope!(),
+    r#"dmntk.add business days(date(2023,12,22),1.5)"#,
+    r#"[dmntk.add business days] expected integer number of days, actual value is 1.5"#,
+  );
+}
+
+#[test]
+fn _0011() {
+  te_null(
+    false,
+    &scope!(),
+    r#"dmntk.add business days(date(2023,12,22))"#,
+    r#"expected 2,3 parameters, actual number of parameters is 1"#,
+  );
+}
+
+#[test]
+fn _0012() {
+  with_calendars(&yearly_calendars(), || {
+    te_date(false, &scope!(), r#"dmntk.add business days(date(2023,1,2),1000,"YRL")"#, 2026, 11, 10)
+  });
+}
+
+#[test]
+fn _0013() {
+  with_calendars(&yearly_calendars(), || {
+    te_date(false, &scope!(), r#"dmntk.add business days(date(2023,1,2),-1000,"YRL")"#, 2019, 2, 26)
+  });
+}
+
+#[test]
+fn _0014() {
+  with_calendars(&yearly_calendars(), || {
+    te_date(false, &scope!(), r#"dmntk.add business days(date(1999,12,31),100000,"YRL")"#, 2385, 5, 31)
+  });
+}
+
+#[test]
+fn _0015() {
+  te_null(
+    false,
+    &scope!(),
+    r#"dmntk.add business days(date(2023,12,22),1000000000)"#,
+    r#"[dmntk.add business days] date is out of range"#,
+  );
+}
+
+#[test]
+fn _0016() {
+  te_null(
+    false,
+    &scope!(),
+    r#"dmntk.add business days(date(2023,12,22),-9223372036854775807)"#,
+    r#"[dmntk.add business days] date is out of range"#,
+  );
+}
@@ -0,0 +1,119 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::super::*;
+use crate::{with_calendars, HolidayCalendar, HolidayCalendars};
+use dmntk_feel::scope;
+use std::sync::Arc;
+
+fn calendars() -> Arc<HolidayCalendars> {
+  let calendar = HolidayCalendar::from_csv("# holidays\n2023-12-25\n2023-12-26\n2024-01-01\n").unwrap();
+  let mut calendars = HolidayCalendars::default();
+  calendars.insert("BDB", calendar);
+  Arc::new(calendars)
+}
+
+fn yearly_calendars() -> Arc<HolidayCalendars> {
+  let calendar = HolidayCalendar::from_icalendar(
+    "BEGIN:VCALENDAR\nBEGIN:VEVENT\nDTSTART;VALUE=DATE:20000101\nRRULE:FREQ=YEARLY\nEND:VEVENT\nBEGIN:VEVENT\nDTSTART;VALUE=DATE:20001225\nRRULE:FREQ=YEARLY\nEND:VEVENT\nEND:VCALENDAR\n",
+  )
+  .unwrap();
+  let mut calendars = HolidayCalendars::default();
+  calendars.insert("YRL", calendar);
+  Arc::new(calendars)
+}
+
+#[test]
+fn _0001() {
+  te_number(false, &scope!(), r#"dmntk.business days between(date(2023,12,22),date(2024,1,2))"#, 7, 0);
+}
+
+#[test]
+fn _0002() {
+  with_calendars(&calendars(), || {
+    te_number(false, &scope!(), r#"dmntk.business days between(date(2023,12,22),date(2024,1,2),"BDB")"#, 4, 0)
+  });
+}
+
+#[test]
+fn _0003() {
+  with_calendars(&calendars(), || {
+    te_number(false, &scope!(), r#"dmntk.business days between(date(2024,1,2),date(2023,12,22),"BDB")"#, -4, 0)
+  });
+}
+
+#[test]
+fn _0004() {
+  te_number(false, &scope!(), r#"dmntk.business days between(date(2023,12,22),date(2023,12,22))"#, 0, 0);
+}
+
+#[test]
+fn _0005() {
+  with_calendars(&calendars(), || {
+    te_number(
+      false,
+      &scope!(),
+      r#"dmntk.business days between(from: date and time("2023-12-22T23:00:00"), to: date(2023,12,29), calendar: "BDB")"#,
+      3,
+      0,
+    );
+  });
+}
+
+#[test]
+fn _0006() {
+  te_null(
+    false,
+    &scope!(),
+    r#"dmntk.business days between(date(2023,12,22),"2024-01-02")"#,
+    r#"[calendar::dmntk.business days between] invalid argument type, expected date, date and time, actual type is string"#,
+  );
+}
+
+#[test]
+fn _0007() {
+  with_calendars(&yearly_calendars(), || {
+    te_number(false, &scope!(), r#"dmntk.business days between(date(2000,1,1),date(2100,1,1),"YRL")"#, 25946, 0)
+  });
+}
+
+#[test]
+fn _0008() {
+  with_calendars(&yearly_calendars(), || {
+    te_number(false, &scope!(), r#"dmntk.business days between(date(2040,3,17),date(1990,6,15),"YRL")"#, -12925, 0)
+  });
+}
+
+#[test]
+fn _0009() {
+  te_number(false, &scope!(), r#"dmntk.business days between(date(1,1,1),date(9999,12,31))"#, 2608614, 0);
+}
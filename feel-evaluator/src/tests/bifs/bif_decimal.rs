@@ -112,3 +112,8 @@ fn _0015() {
 fn _0016() {
   te_null(false, &scope!(), "decimal(number: 1, scale: 2)", r#"parameter 'n' not found"#);
 }
+
+#[test]
+fn _0017() {
+  te_null(false, &scope!(), "decimal(9 * 10 ** 6100, 0)", r#"[core::decimal] <FeelNumberError> invalid operation"#);
+}
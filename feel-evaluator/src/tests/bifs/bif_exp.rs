@@ -115,5 +115,5 @@ fn _0016() {
 
 #[test]
 fn _0017() {
-  te_null(false, &scope!(), r#"exp(10000000000000000000000)"#, "exp");
+  te_null(false, &scope!(), r#"exp(10000000000000000000000)"#, "[core::exp] <FeelNumberError> overflow");
 }
@@ -0,0 +1,171 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::super::*;
+use crate::{with_calendars, HolidayCalendar, HolidayCalendars};
+use dmntk_feel::scope;
+use std::sync::Arc;
+
+const ICALENDAR: &str = r#"BEGIN:VCALENDAR
+VERSION:2.0
+PRODID:-//Holidays//EN
+BEGIN:VEVENT
+DTSTART;VALUE=DATE:20230501
+DTEND;VALUE=DATE:20230502
+RRULE:FREQ=YEARLY
+SUMMARY:Labour Day
+END:VEVENT
+BEGIN:VEVENT
+DTSTART;VALUE=DATE:20231224
+DTEND;VALUE=DATE:20231227
+SUMMARY:Christmas
+ Holidays
+END:VEVENT
+BEGIN:VEVENT
+DTSTART;VALUE=DATE:20200101
+RRULE:FREQ=YEARLY;COUNT=3
+SUMMARY:New Year's Day
+END:VEVENT
+END:VCALENDAR
+"#;
+
+fn calendars() -> Arc<HolidayCalendars> {
+  let mut calendars = HolidayCalendars::default();
+  calendars.insert("IH", HolidayCalendar::from_icalendar(ICALENDAR).unwrap());
+  Arc::new(calendars)
+}
+
+#[test]
+fn _0001() {
+  with_calendars(&calendars(), || te_bool(false, &scope!(), r#"dmntk.is holiday(date(2023,5,1),"IH")"#, true));
+}
+
+#[test]
+fn _0002() {
+  with_calendars(&calendars(), || te_bool(false, &scope!(), r#"dmntk.is holiday(date(2030,5,1),"IH")"#, true));
+}
+
+#[test]
+fn _0003() {
+  with_calendars(&calendars(), || te_bool(false, &scope!(), r#"dmntk.is holiday(date(2022,5,1),"IH")"#, false));
+}
+
+#[test]
+fn _0004() {
+  with_calendars(&calendars(), || te_bool(false, &scope!(), r#"dmntk.is holiday(date(2023,12,26),"IH")"#, true));
+}
+
+#[test]
+fn _0005() {
+  with_calendars(&calendars(), || te_bool(false, &scope!(), r#"dmntk.is holiday(date(2023,12,27),"IH")"#, false));
+}
+
+#[test]
+fn _0006() {
+  with_calendars(&calendars(), || {
+    te_bool(false, &scope!(), r#"dmntk.is holiday(date and time("2022-01-01T12:00:00"),"IH")"#, true)
+  });
+}
+
+#[test]
+fn _0007() {
+  with_calendars(&calendars(), || {
+    te_bool(false, &scope!(), r#"dmntk.is holiday(date: date(2023,1,1), calendar: "IH")"#, false)
+  });
+}
+
+#[test]
+fn _0008() {
+  with_calendars(&calendars(), || {
+    // weekend days are not holidays
+    te_bool(false, &scope!(), r#"dmntk.is holiday(date(2023,12,30),"IH")"#, false);
+  });
+}
+
+#[test]
+fn _0009() {
+  te_null(
+    false,
+    &scope!(),
+    r#"dmntk.is holiday(date(2023,12,26),null)"#,
+    r#"[dmntk.is holiday] calendar name must be specified"#,
+  );
+}
+
+#[test]
+fn _0010() {
+  assert_eq!(
+    "<CalendarError> unsupported recurrence rule 'FREQ=MONTHLY', only yearly recurrence is supported",
+    HolidayCalendar::from_icalendar("BEGIN:VEVENT\nDTSTART:20230101\nRRULE:FREQ=MONTHLY\nEND:VEVENT\n")
+      .err()
+      .unwrap()
+      .to_string()
+  );
+}
+
+#[test]
+fn _0011() {
+  assert_eq!(
+    "<CalendarError> invalid holiday date in line 3: '2023-13-01,Unknown'",
+    HolidayCalendar::from_csv("date,name\n2023-12-25,Christmas Day\n2023-13-01,Unknown\n")
+      .err()
+      .unwrap()
+      .to_string()
+  );
+}
+
+#[test]
+fn _0012() {
+  // calendars are available only during evaluation started with these calendars
+  with_calendars(&calendars(), || te_bool(false, &scope!(), r#"dmntk.is holiday(date(2023,5,1),"IH")"#, true));
+  te_null(
+    false,
+    &scope!(),
+    r#"dmntk.is holiday(date(2023,5,1),"IH")"#,
+    r#"[dmntk.is holiday] calendar 'IH' not found"#,
+  );
+}
+
+#[test]
+fn _0013() {
+  // calendars of nested evaluation are replaced with outer calendars when the nested evaluation ends
+  let outer = Arc::new(HolidayCalendars::default());
+  with_calendars(&outer, || {
+    with_calendars(&calendars(), || te_bool(false, &scope!(), r#"dmntk.is holiday(date(2023,5,1),"IH")"#, true));
+    te_null(
+      false,
+      &scope!(),
+      r#"dmntk.is holiday(date(2023,5,1),"IH")"#,
+      r#"[dmntk.is holiday] calendar 'IH' not found"#,
+    );
+  });
+}
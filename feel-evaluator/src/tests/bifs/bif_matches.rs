@@ -120,3 +120,214 @@ fn _0015() {
 fn _0016() {
   te_null(false, &scope!(), r#"matches(input: "foobar", pattern: true)"#, r#"matches"#);
 }
+
+#[test]
+fn _0017() {
+  te_null(
+    false,
+    &scope!(),
+    r#"matches("abc","a","z")"#,
+    r#"[core::matches] <RegexError> invalid flag 'z', allowed flags are: s, m, i, x, q"#,
+  );
+}
+
+#[test]
+fn _0018() {
+  te_bool(false, &scope!(), r#"matches("b","^[a-z-[aeiou]]$")"#, true);
+}
+
+#[test]
+fn _0019() {
+  te_bool(false, &scope!(), r#"matches("e","^[a-z-[aeiou]]$")"#, false);
+}
+
+#[test]
+fn _0020() {
+  te_bool(false, &scope!(), r#"matches("x","^[a-z-[aeiou-[e]]]$")"#, true);
+}
+
+#[test]
+fn _0021() {
+  te_bool(false, &scope!(), r#"matches("_abc-1.x","^\i\c*$")"#, true);
+}
+
+#[test]
+fn _0022() {
+  te_bool(false, &scope!(), r#"matches("1abc","^\i\c*$")"#, false);
+}
+
+#[test]
+fn _0023() {
+  te_bool(false, &scope!(), r#"matches("a b","^\c\C\c$")"#, true);
+}
+
+#[test]
+fn _0024() {
+  te_bool(false, &scope!(), r#"matches("a\rb","a.b")"#, false);
+}
+
+#[test]
+fn _0025() {
+  te_bool(false, &scope!(), r#"matches("a\rb","a.b","s")"#, true);
+}
+
+#[test]
+fn _0026() {
+  te_bool(false, &scope!(), r#"matches("a.b","^a.b$","q")"#, false);
+}
+
+#[test]
+fn _0027() {
+  te_bool(false, &scope!(), r#"matches("x^a.b$y","^a.b$","q")"#, true);
+}
+
+#[test]
+fn _0028() {
+  te_bool(false, &scope!(), r#"matches("A.B","a.b","qi")"#, true);
+}
+
+#[test]
+fn _0029() {
+  te_bool(false, &scope!(), r#"matches("abc","a b  c","x")"#, true);
+}
+
+#[test]
+fn _0030() {
+  te_bool(false, &scope!(), r#"matches("a c","a[ ]c","x")"#, true);
+}
+
+#[test]
+fn _0031() {
+  te_bool(false, &scope!(), r#"matches("ABC","abc","i")"#, true);
+}
+
+#[test]
+fn _0032() {
+  te_bool(false, &scope!(), r#"matches("a\nb","^b$")"#, false);
+}
+
+#[test]
+fn _0033() {
+  te_bool(false, &scope!(), r#"matches("a\nb","^b$","m")"#, true);
+}
+
+#[test]
+fn _0034() {
+  te_bool(false, &scope!(), r#"matches("a-b","^\w\W\w$")"#, true);
+}
+
+#[test]
+fn _0035() {
+  te_bool(false, &scope!(), r#"matches("Ab","^\p{Lu}\P{Lu}$")"#, true);
+}
+
+#[test]
+fn _0036() {
+  te_bool(false, &scope!(), r#"matches("a&b","^[a&b]+$")"#, true);
+}
+
+#[test]
+fn _0037() {
+  te_bool(false, &scope!(), r#"matches("abab","^(?:ab){2}$")"#, true);
+}
+
+#[test]
+fn _0038() {
+  te_null(
+    false,
+    &scope!(),
+    r#"matches("aa","(a)\1")"#,
+    r#"[core::matches] <RegexError> invalid pattern '(a)\1', back-references are not supported"#,
+  );
+}
+
+#[test]
+fn _0039() {
+  te_null(
+    false,
+    &scope!(),
+    r#"matches("abc","(?i)ABC")"#,
+    r#"[core::matches] <RegexError> invalid pattern '(?i)ABC', only non-capturing groups '(?:' are allowed after '(?'"#,
+  );
+}
+
+#[test]
+fn _0040() {
+  te_null(
+    false,
+    &scope!(),
+    r#"matches("a b","a\bb")"#,
+    r#"[core::matches] <RegexError> invalid pattern 'a\bb', invalid escape '\b'"#,
+  );
+}
+
+#[test]
+fn _0041() {
+  te_null(
+    false,
+    &scope!(),
+    r#"matches("abc","\p{IsBasicLatin}")"#,
+    r#"[core::matches] <RegexError> invalid pattern '\p{IsBasicLatin}', unicode block escape 'IsBasicLatin' is not supported"#,
+  );
+}
+
+#[test]
+fn _0042() {
+  te_null(
+    false,
+    &scope!(),
+    r#"matches("abc","a{,2}")"#,
+    r#"[core::matches] <RegexError> invalid pattern 'a{,2}', quantifier must start with a number"#,
+  );
+}
+
+#[test]
+fn _0043() {
+  te_null(
+    false,
+    &scope!(),
+    r#"matches("abc","a}")"#,
+    r#"[core::matches] <RegexError> invalid pattern 'a}', unescaped '}'"#,
+  );
+}
+
+#[test]
+fn _0044() {
+  te_null(
+    false,
+    &scope!(),
+    r#"matches("abc","[a-c-x]")"#,
+    r#"[core::matches] <RegexError> invalid pattern '[a-c-x]', unescaped '-' in character class"#,
+  );
+}
+
+#[test]
+fn _0045() {
+  te_null(
+    false,
+    &scope!(),
+    r#"matches("abc","[z-a]")"#,
+    r#"[core::matches] <RegexError> invalid pattern '[z-a]', invalid range in character class"#,
+  );
+}
+
+#[test]
+fn _0046() {
+  te_null(false, &scope!(), r#"matches("abc","a",10)"#, r#"[core::matches] flags must be a string"#);
+}
+
+#[test]
+fn _0047() {
+  // U+0378 is not assigned to any character
+  te_bool(false, &scope!(), r#"matches("\u0378","^\p{Cn}$")"#, true);
+}
+
+#[test]
+fn _0048() {
+  te_bool(false, &scope!(), r#"matches("a","\p{Cn}")"#, false);
+}
+
+#[test]
+fn _0049() {
+  te_bool(false, &scope!(), r#"matches("a\u0378","^\P{Cn}\p{Cn}$")"#, true);
+}
@@ -72,3 +72,8 @@ fn _0006() {
 fn _0007() {
   te_null(false, &scope!(), r#"mean()"#, r#"expected 1+ parameters, actual number of parameters is 0"#);
 }
+
+#[test]
+fn _0008() {
+  te_null(false, &scope!(), r#"mean([9 * 10 ** 6144, 9 * 10 ** 6144])"#, r#"[core::mean] <FeelNumberError> overflow"#);
+}
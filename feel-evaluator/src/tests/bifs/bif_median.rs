@@ -142,3 +142,8 @@ fn _0021() {
 fn _0022() {
   te_number(false, &scope!(), "median(1999,2999)", 2499, 0);
 }
+
+#[test]
+fn _0023() {
+  te_null(false, &scope!(), "median(9 * 10 ** 6144, 9 * 10 ** 6144)", r#"[core::median] <FeelNumberError> overflow"#);
+}
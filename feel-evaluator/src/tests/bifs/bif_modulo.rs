@@ -157,3 +157,8 @@ fn _0021() {
 fn _0022() {
   te_null(false, &scope!(), r#"modulo(d: 12, divisor: 6)"#, r#"parameter 'dividend' not found"#);
 }
+
+#[test]
+fn _0023() {
+  te_null(false, &scope!(), r#"modulo(9 * 10 ** 6144, 10 ** -6000)"#, r#"[core::modulo] <FeelNumberError> overflow"#);
+}
@@ -72,3 +72,8 @@ fn _0006() {
 fn _0007() {
   te_null(false, &scope!(), r#"product()"#, r#"expected 1+ parameters, actual number of parameters is 0"#);
 }
+
+#[test]
+fn _0008() {
+  te_null(false, &scope!(), r#"product([10 ** 6000, 1, 10 ** 6000])"#, r#"[core::product] <FeelNumberError> overflow"#);
+}
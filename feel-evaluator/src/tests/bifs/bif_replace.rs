@@ -185,7 +185,12 @@ fn _0030() {
 
 #[test]
 fn _0031() {
-  te_string(false, &scope!(), r##"replace("abc","[A-Z]","#","all unknown but i")"##, r##"###"##);
+  te_null(
+    false,
+    &scope!(),
+    r##"replace("abc","[A-Z]","#","all unknown but i")"##,
+    r#"[core::replace] <RegexError> invalid flag 'a', allowed flags are: s, m, i, x, q"#,
+  );
 }
 
 #[test]
@@ -270,5 +275,75 @@ fn _0043() {
 
 #[test]
 fn _0044() {
-  te_null(false, &scope!(), r#"replace("abc1","[a-z","A")"#, r#"replace: invalid pattern"#);
+  te_null(
+    false,
+    &scope!(),
+    r#"replace("abc1","[a-z","A")"#,
+    r#"[core::replace] <RegexError> invalid pattern '[a-z', unterminated character class"#,
+  );
+}
+
+#[test]
+fn _0045() {
+  te_string(false, &scope!(), r##"replace("a.b.c",".","-","sq")"##, r##"a-b-c"##);
+}
+
+#[test]
+fn _0046() {
+  te_string(false, &scope!(), r##"replace("abc","b","\$")"##, r##"a$c"##);
+}
+
+#[test]
+fn _0047() {
+  te_string(false, &scope!(), r##"replace("abc","(b)","[$10]")"##, r##"a[b0]c"##);
+}
+
+#[test]
+fn _0048() {
+  te_string(false, &scope!(), r##"replace("abcdefghijk","(a)(b)(c)(d)(e)(f)(g)(h)(i)(j)(k)","$11$10")"##, r##"kj"##);
+}
+
+#[test]
+fn _0049() {
+  te_string(false, &scope!(), r##"replace("a e i o u x","[a-z-[aeiou]]","#")"##, r##"a e i o u #"##);
+}
+
+#[test]
+fn _0050() {
+  te_string(false, &scope!(), r##"replace("a\rb\nc",".","#")"##, "#\r#\n#");
+}
+
+#[test]
+fn _0051() {
+  te_null(
+    false,
+    &scope!(),
+    r##"replace("abc","b","$")"##,
+    r#"[core::replace] <RegexError> invalid replacement string '$', '$' must be followed by a digit"#,
+  );
+}
+
+#[test]
+fn _0052() {
+  te_null(
+    false,
+    &scope!(),
+    r##"replace("abc","b","\\n")"##,
+    r#"[core::replace] <RegexError> invalid replacement string '\n', '\' must be followed by '\' or '$'"#,
+  );
+}
+
+#[test]
+fn _0053() {
+  te_null(
+    false,
+    &scope!(),
+    r##"replace("abc","x*","-")"##,
+    r#"[core::replace] <RegexError> pattern 'x*' matches a zero-length string"#,
+  );
+}
+
+#[test]
+fn _0054() {
+  te_null(false, &scope!(), r##"replace("abc","b","-",10)"##, r#"replace: flags must be a string"#);
 }
@@ -90,5 +90,30 @@ fn _0011() {
 
 #[test]
 fn _0012() {
-  te_null(false, &scope!(), r#"split("John Doe","[a-z")"#, r#"split: invalid delimiter"#);
+  te_null(
+    false,
+    &scope!(),
+    r#"split("John Doe","[a-z")"#,
+    r#"[core::split] <RegexError> invalid pattern '[a-z', unterminated character class"#,
+  );
+}
+
+#[test]
+fn _0013() {
+  te_be_value(false, &scope!(), r#"split("a1b2c","\d")"#, r#"["a","b","c"]"#);
+}
+
+#[test]
+fn _0014() {
+  te_be_value(false, &scope!(), r#"split("John Doe Smith","\s")"#, r#"["John Doe","Smith"]"#);
+}
+
+#[test]
+fn _0015() {
+  te_null(
+    false,
+    &scope!(),
+    r#"split("abc","")"#,
+    r#"[core::split] <RegexError> pattern '' matches a zero-length string"#,
+  );
 }
@@ -126,5 +126,5 @@ fn _0017() {
 #[test]
 fn _0018() {
   let result = sqrt(&Value::Number(FeelNumber::infinite()));
-  assert_eq!("null(sqrt: result is not a finite number)", result.to_string());
+  assert_eq!("null([core::sqrt] result is not a finite number)", result.to_string());
 }
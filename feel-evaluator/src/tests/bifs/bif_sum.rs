@@ -87,3 +87,8 @@ fn _0008() {
 fn _0009() {
   te_null(false, &scope!(), r#"sum(l: [1,2,3])"#, r#"parameter 'list' not found"#);
 }
+
+#[test]
+fn _0010() {
+  te_null(false, &scope!(), r#"sum([9 * 10 ** 6144, 1, 9 * 10 ** 6144])"#, r#"[core::sum] <FeelNumberError> overflow"#);
+}
@@ -33,11 +33,15 @@
 //! Test cases for built-in functions.  
 
 mod bif_abs;
+#[cfg(feature = "calendar")]
+mod bif_add_business_days;
 mod bif_after;
 mod bif_all;
 mod bif_any;
 mod bif_append;
 mod bif_before;
+#[cfg(feature = "calendar")]
+mod bif_business_days_between;
 mod bif_ceiling;
 mod bif_coincides;
 mod bif_concatenate;
@@ -64,6 +68,8 @@ mod bif_includes;
 mod bif_index_of;
 mod bif_insert_before;
 mod bif_is;
+#[cfg(feature = "calendar")]
+mod bif_is_holiday;
 mod bif_list_contains;
 mod bif_log;
 mod bif_lower_case;
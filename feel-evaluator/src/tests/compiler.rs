@@ -0,0 +1,238 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::*;
+use crate::{Arithmetic, Comparison, Instruction, Program};
+
+/// Utility function that compiles textual expression into a program.
+fn compile(scope: &FeelScope, text: &str) -> Program {
+  let node = dmntk_feel_parser::parse_expression(scope, text, false).unwrap();
+  crate::compile(&node).unwrap()
+}
+
+#[test]
+fn _0001() {
+  let scope = &te_scope("{}");
+  let program = compile(scope, "1 + 2 * 3");
+  assert_eq!(&[Instruction::Const(0)], program.instructions());
+  assert_eq!(Value::Number(7.into()), program.evaluate(scope));
+}
+
+#[test]
+fn _0002() {
+  let scope = &te_scope("{a: 2}");
+  let program = compile(scope, "a + a * a");
+  assert_eq!(
+    &[
+      Instruction::Load(0),
+      Instruction::Load(0),
+      Instruction::Load(0),
+      Instruction::Arithmetic(Arithmetic::Mul),
+      Instruction::Arithmetic(Arithmetic::Add)
+    ],
+    program.instructions()
+  );
+  assert_eq!(Value::Number(6.into()), program.evaluate(scope));
+}
+
+#[test]
+fn _0003() {
+  let scope = &te_scope("{a: 2}");
+  let program = compile(scope, "false and a");
+  assert!(program.instructions().iter().any(|instruction| matches!(instruction, Instruction::JumpIfFalse(_))));
+  assert_eq!(Value::Boolean(false), program.evaluate(scope));
+}
+
+#[test]
+fn _0004() {
+  let scope = &te_scope("{a: 2}");
+  let program = compile(scope, "true or a");
+  assert!(program.instructions().iter().any(|instruction| matches!(instruction, Instruction::JumpIfTrue(_))));
+  assert_eq!(Value::Boolean(true), program.evaluate(scope));
+}
+
+#[test]
+fn _0005() {
+  let scope = &te_scope("{a: 2}");
+  let program = compile(scope, r#"if a > 1 then "big" else "small""#);
+  assert_eq!(Value::String("big".to_string()), program.evaluate(scope));
+  let scope = &te_scope("{a: 1}");
+  assert_eq!(Value::String("small".to_string()), program.evaluate(scope));
+}
+
+#[test]
+fn _0006() {
+  let scope = &te_scope("{}");
+  let program = compile(scope, r#"date("2023-01-31")"#);
+  assert!(matches!(program.instructions(), [Instruction::Folded(0, 0, 0)]));
+  assert_eq!(Value::Date(FeelDate::new(2023, 1, 31)), program.evaluate(scope));
+}
+
+#[test]
+fn _0007() {
+  let scope = &te_scope("{}");
+  let program = compile(scope, r#"date("2023-01-31")"#);
+  let scope = &te_scope("{date: function(s) s}");
+  assert_eq!(Value::String("2023-01-31".to_string()), program.evaluate(scope));
+}
+
+#[test]
+fn _0008() {
+  let scope = &te_scope("{a: 2}");
+  let program = compile(scope, "a in [1..3]");
+  assert_eq!(&[Instruction::Lookup(0), Instruction::CompareConst(Comparison::In, 0)], program.instructions());
+  assert_eq!(Value::Boolean(true), program.evaluate(scope));
+  assert_eq!("   0: lookup a\n   1: compare in const [1..3]\n", program.to_string());
+}
+
+#[test]
+fn _0009() {
+  let scope = &te_scope("{a: 2}");
+  let program = compile(scope, "a * 10 - 1");
+  assert_eq!(
+    &[
+      Instruction::Lookup(0),
+      Instruction::ArithmeticConst(Arithmetic::Mul, 0),
+      Instruction::ArithmeticConst(Arithmetic::Sub, 1)
+    ],
+    program.instructions()
+  );
+  assert_eq!(Value::Number(19.into()), program.evaluate(scope));
+}
+
+#[test]
+fn _0010() {
+  let scope = &te_scope("{a: 2}");
+  let node = AstNode::In(Box::new(AstNode::Name("a".into())), Box::new(AstNode::Irrelevant));
+  let program = crate::compile(&node).unwrap();
+  assert_eq!(&[Instruction::Const(0)], program.instructions());
+  assert_eq!(Value::Boolean(true), program.into_evaluator()(scope));
+}
+
+#[test]
+fn _0011() {
+  let scope = &te_scope("{a: 2}");
+  let program = compile(scope, "{b: a + 1}.b");
+  assert_eq!(
+    &[
+      Instruction::Lookup(0),
+      Instruction::ArithmeticConst(Arithmetic::Add, 0),
+      Instruction::Store(0),
+      Instruction::Context(0),
+      Instruction::Path(0)
+    ],
+    program.instructions()
+  );
+  assert_eq!(Value::Number(3.into()), program.into_evaluator()(scope));
+}
+
+#[test]
+fn _0012() {
+  let scope = &te_scope("{a: 10}");
+  let program = compile(scope, "for x in [1, 2, 3] return x * a");
+  assert!(program.instructions().iter().any(|instruction| matches!(instruction, Instruction::For(_))));
+  assert!(program.instructions().iter().any(|instruction| matches!(instruction, Instruction::Resolve(_))));
+  assert_eq!("[10, 20, 30]", program.evaluate(scope).to_string());
+}
+
+#[test]
+fn _0013() {
+  let scope = &te_scope("{}");
+  let program = compile(scope, "for i in 1..5 return if i = 1 then 1 else i * partial[-1]");
+  assert_eq!("[1, 2, 6, 24, 120]", program.evaluate(scope).to_string());
+}
+
+#[test]
+fn _0014() {
+  let scope = &te_scope("{limit: 2}");
+  let program = compile(scope, "[(some x in [1, 2, 3] satisfies x > limit), (every x in [1, 2, 3] satisfies x > limit)]");
+  assert_eq!("[true, false]", program.evaluate(scope).to_string());
+}
+
+#[test]
+fn _0015() {
+  let scope = &te_scope("{a: 1}");
+  let program = compile(scope, "[[1, 2, 3][item > a], [{a: 1}, {a: 2}, {a: 3}][a > 2], [{item: 5}][item = 5], [1, 2, 3][-1]]");
+  assert!(program.instructions().iter().any(|instruction| matches!(instruction, Instruction::Filter(_))));
+  assert_eq!("[[2, 3], {a: 3}, {item: 5}, 3]", program.evaluate(scope).to_string());
+}
+
+#[test]
+fn _0016() {
+  let scope = &te_scope("{}");
+  let program = compile(scope, "{fact: function(n) if n <= 1 then 1 else n * fact(n - 1), result: fact(5)}.result");
+  assert!(program.instructions().iter().any(|instruction| matches!(instruction, Instruction::Function(_, Some(_)))));
+  assert!(program.instructions().iter().any(|instruction| matches!(instruction, Instruction::Invoke(1, Some(_)))));
+  assert_eq!(Value::Number(120.into()), program.evaluate(scope));
+}
+
+#[test]
+fn _0017() {
+  let scope = &te_scope("{}");
+  let program = compile(scope, r#"{s: "abcdef", result: substring(start position: 2, string: s, length: 3)}.result"#);
+  assert!(program.instructions().iter().any(|instruction| matches!(instruction, Instruction::InvokeNamed(_, Some(_)))));
+  assert_eq!(Value::String("bcd".to_string()), program.evaluate(scope));
+}
+
+#[test]
+fn _0018() {
+  let scope = &te_scope("{a: 1, b: [{c: 2}, {c: 3}]}");
+  let program = compile(scope, "{a: a + 1, b: a * 10, c: (for a in [] return a)}");
+  assert_eq!(&Instruction::Lookup(0), &program.instructions()[0]);
+  assert_eq!(&Instruction::Local(0), &program.instructions()[3]);
+  assert_eq!("{a: 2, b: 20, c: []}", program.evaluate(scope).to_string());
+}
+
+#[test]
+fn _0019() {
+  let scope = &te_scope("{a: 1, b: [{c: 2}, {c: 3}], d: function(x) x + a}");
+  for text in [
+    "for x in b return x.c + a",
+    "for x in [1, 2], y in [10, 20] return x + y + a",
+    "for x in [], y in [1, 2] return x",
+    "b[c > a + 1].c",
+    "b[item.c = 2]",
+    "{f: function(x) x * a, g: f(3), h: (for i in [1, 2] return f(i))}",
+    "{x: 5, y: d(x), z: [1, 2, 3][item > x - 4]}",
+    "{l: [1, 2, 3], s: sum(l[item > 1]), m: (some i in l satisfies i > s)}",
+    "for f in [function(x) x + 1, function(x) x * a] return f(10)",
+    "{a: 100, f: function() a}.f()",
+    "{k: 3, v: (for i in 1..k return i instance of number)}",
+    "every x in b satisfies x.c > a",
+    "{x: 1, x: 2}",
+  ] {
+    let node = dmntk_feel_parser::parse_expression(scope, text, false).unwrap();
+    let expected = crate::prepare_uncompiled(&node).unwrap()(scope);
+    let actual = crate::prepare(&node).unwrap()(scope);
+    assert_eq!(expected, actual, "{text}");
+  }
+}
@@ -157,3 +157,8 @@ fn _0022() {
 fn _0023() {
   te_null(false, &scope!(), r#" "a" / 2.21 "#, r#"[division] incompatible types: "a" / 2.21"#);
 }
+
+#[test]
+fn _0024() {
+  te_null(false, &scope!(), r#" 10 ** 6000 / 10 ** -6000 "#, r#"[division] <FeelNumberError> overflow"#);
+}
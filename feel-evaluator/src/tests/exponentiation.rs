@@ -149,6 +149,11 @@ fn test_0023() {
     false,
     &scope!(),
     r#"4 ** 10000000000000000000000000000000000000000"#,
-    r#"exponentiation result is not a finite number"#,
+    r#"[exponentiation] <FeelNumberError> overflow"#,
   );
 }
+
+#[test]
+fn test_0024() {
+  te_null(false, &scope!(), r#"(-8) ** 0.5"#, r#"[exponentiation] <FeelNumberError> invalid operation"#);
+}
@@ -48,6 +48,7 @@ mod comparison_in;
 mod comparison_le;
 mod comparison_lt;
 mod comparison_ne;
+mod compiler;
 mod conjunction;
 mod context;
 mod disjunction;
@@ -279,6 +280,9 @@ fn textual_expression(trace: bool, scope: &FeelScope, text: &str, expected: Valu
       Ok(evaluator) => {
         let actual = evaluator(scope) as Value;
         assert_eq!(actual, expected, "ERROR\nexpected: {expected}\n  actual: {actual}\n");
+        let program = crate::compile(&node).unwrap();
+        let compiled = program.evaluate(scope);
+        assert_eq!(compiled, expected, "ERROR in compiled program\nexpected: {expected}\n  actual: {compiled}\n{program}");
       }
       Err(reason) => {
         panic!("building evaluator for textual expression failed with reason: {reason}");
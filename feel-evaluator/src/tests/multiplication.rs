@@ -178,3 +178,23 @@ fn _0024() {
 fn _0025() {
   te_null(false, &scope!(), r#" "a" * 2 "#, r#"unexpected value type in multiplication: string"#);
 }
+
+#[test]
+fn _0026() {
+  te_null(false, &scope!(), r#" 10 ** 6000 * 10 ** 6000 "#, r#"[multiplication] <FeelNumberError> overflow"#);
+}
+
+#[test]
+fn _0027() {
+  te_number(false, &scope!(), r#" 10 ** -6000 * 10 ** -6000 "#, 0, 0);
+}
+
+#[test]
+fn _0028() {
+  te_null(false, &scope!(), r#" 10 ** 6144 * duration("P1D") "#, r#"[multiplication] <FeelNumberError> overflow"#);
+}
+
+#[test]
+fn _0029() {
+  te_null(false, &scope!(), r#" duration("P1Y") * 10 ** 6144 * 10 "#, r#"[multiplication] <FeelNumberError> overflow"#);
+}
@@ -293,3 +293,8 @@ fn _0040() {
     "[subtraction] incompatible types: 999999999-01-01T00:00:00 - P20Y",
   );
 }
+
+#[test]
+fn _0041() {
+  te_null(false, &scope!(), r#" -9 * 10 ** 6144 - 9 * 10 ** 6144 "#, "[subtraction] <FeelNumberError> overflow");
+}
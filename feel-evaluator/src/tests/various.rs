@@ -88,3 +88,30 @@ fn test_0002() {
   let expression = r#" ((Bounds.Max.Rate - Bounds.Min.Rate) * (Days - Bounds.Min.Tenor) / (Bounds.Max.Tenor - Bounds.Min.Tenor)) + Bounds.Min.Rate "#;
   te_be_value(false, scope, expression, r#"0.03856096626819070799478443831677885"#);
 }
+
+#[test]
+fn test_0003() {
+  let scope = &te_scope("{}");
+  let (node, spans) = dmntk_feel_parser::parse_textual_expression_with_spans(scope, r#" 1 + "a" "#, false).unwrap();
+  let evaluator = crate::prepare_with_spans(&node, &spans).unwrap();
+  assert_eq!(
+    r#"null(incompatible types in addition: 1(number) + "a"(string) (at 1:2..1:9))"#,
+    evaluator(scope).to_string()
+  );
+}
+
+#[test]
+fn test_0004() {
+  let scope = &te_scope("{}");
+  let (node, spans) = dmntk_feel_parser::parse_textual_expression_with_spans(scope, r#"{a: 2, b: [1, a * "x"], c: b[2]}.c"#, false).unwrap();
+  let evaluator = crate::prepare_with_spans(&node, &spans).unwrap();
+  assert_eq!(r#"null([multiplication] incompatible types: 2 * "x" (at 1:15..1:22))"#, evaluator(scope).to_string());
+}
+
+#[test]
+fn test_0005() {
+  let scope = &te_scope("{}");
+  let (node, spans) = dmntk_feel_parser::parse_textual_expression_with_spans(scope, r#"{f: function(x) x - "b", r: f(3)}.r"#, false).unwrap();
+  let evaluator = crate::prepare_with_spans(&node, &spans).unwrap();
+  assert_eq!(r#"null([subtraction] incompatible types: 3 - "b" (at 1:17..1:24))"#, evaluator(scope).to_string());
+}
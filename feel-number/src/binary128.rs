@@ -0,0 +1,405 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Binary floating-point numbers in `binary128` format defined in IEEE 754-2008.
+//!
+//! Intel's library evaluates the exponential function and the natural logarithm
+//! of decimal numbers in this format and converts the results back to decimal,
+//! so the pure-Rust implementation does the same to return identical results.
+//! Only finite numbers are supported. Arithmetic operations round to nearest, ties to even,
+//! the exponential function and the natural logarithm are correctly rounded.
+
+use crate::natural::Natural;
+use std::cmp::Ordering;
+
+/// Number of bits in the significand, including the implicit leading bit.
+const PRECISION: u64 = 113;
+
+/// Minimum exponent of the significand (subnormal numbers included).
+const MIN_EXPONENT: i64 = -16494;
+
+/// Square root of two, scaled by 2^127.
+const SQRT_2: u128 = 0xb504f333f9de6484597d89b3754abe9f;
+
+/// Number of fractional bits used in the first attempt of evaluating transcendental functions.
+const INITIAL_WORKING_PRECISION: u64 = 256;
+
+/// Maximum number of fractional bits used while evaluating transcendental functions.
+const MAX_WORKING_PRECISION: u64 = 2048;
+
+/// Binary floating-point number equal to `significand * 2^exponent`.
+///
+/// Non-zero numbers are normalized, the significand has [PRECISION] bits
+/// unless the number is subnormal.
+#[derive(Debug, Clone)]
+pub struct Binary128 {
+  /// Flag indicating if the number is negative.
+  negative: bool,
+  /// Significand.
+  significand: u128,
+  /// Exponent of the significand.
+  exponent: i64,
+}
+
+impl Binary128 {
+  /// Returns the number equal to one.
+  pub fn one() -> Self {
+    Self::round(false, &Natural::one(), 0)
+  }
+
+  /// Creates a number from its encoding in `binary128` interchange format.
+  pub fn from_bits(bits: u128) -> Self {
+    let biased_exponent = ((bits >> 112) & 0x7fff) as i64;
+    let fraction = bits & ((1 << 112) - 1);
+    let (significand, exponent) = if biased_exponent == 0 {
+      (fraction, MIN_EXPONENT)
+    } else {
+      (fraction | (1 << 112), biased_exponent - 16383 - 112)
+    };
+    Self {
+      negative: bits >> 127 == 1,
+      significand,
+      exponent,
+    }
+  }
+
+  /// Rounds the exact value `m * 2^e` to the nearest number, ties to even.
+  pub fn round(negative: bool, m: &Natural, e: i64) -> Self {
+    Self::pack(negative, m, e, true)
+  }
+
+  /// Truncates the exact value `m * 2^e` to the number not greater in magnitude.
+  pub fn truncate(negative: bool, m: &Natural, e: i64) -> Self {
+    Self::pack(negative, m, e, false)
+  }
+
+  /// Returns the sign, the significand and the exponent of this number.
+  pub fn parts(&self) -> (bool, u128, i64) {
+    (self.negative, self.significand, self.exponent)
+  }
+
+  pub fn is_zero(&self) -> bool {
+    self.significand == 0
+  }
+
+  pub fn abs(&self) -> Self {
+    Self { negative: false, ..*self }
+  }
+
+  pub fn neg(&self) -> Self {
+    Self {
+      negative: !self.negative,
+      ..*self
+    }
+  }
+
+  pub fn add(&self, rhs: &Self) -> Self {
+    if rhs.is_zero() {
+      return self.clone();
+    }
+    if self.is_zero() {
+      return rhs.clone();
+    }
+    let (large, small) = if self.top() >= rhs.top() { (self, rhs) } else { (rhs, self) };
+    // when the smaller operand is far below the rounding position of the result,
+    // it only matters that it is non-zero, so it is replaced with a single bit
+    let small = if small.top() < large.exponent - 4 {
+      Self {
+        negative: small.negative,
+        significand: 1,
+        exponent: large.exponent - 5,
+      }
+    } else {
+      small.clone()
+    };
+    let exponent = large.exponent.min(small.exponent);
+    let a = Natural::from_u128(large.significand).shl((large.exponent - exponent) as u64);
+    let b = Natural::from_u128(small.significand).shl((small.exponent - exponent) as u64);
+    if large.negative == small.negative {
+      return Self::round(large.negative, &a.add(&b), exponent);
+    }
+    match a.cmp(&b) {
+      Ordering::Greater => Self::round(large.negative, &a.sub(&b), exponent),
+      Ordering::Less => Self::round(small.negative, &b.sub(&a), exponent),
+      Ordering::Equal => Self::round(false, &Natural::zero(), 0),
+    }
+  }
+
+  pub fn sub(&self, rhs: &Self) -> Self {
+    self.add(&rhs.neg())
+  }
+
+  pub fn mul(&self, rhs: &Self) -> Self {
+    let m = Natural::from_u128(self.significand).mul(&Natural::from_u128(rhs.significand));
+    Self::round(self.negative != rhs.negative, &m, self.exponent + rhs.exponent)
+  }
+
+  /// Divides this number by non-zero number.
+  pub fn div(&self, rhs: &Self) -> Self {
+    let (a, b) = (Natural::from_u128(self.significand), Natural::from_u128(rhs.significand));
+    // the quotient has at least two bits more than the significand,
+    // the additional lowest bit is set when the remainder is not zero
+    let scale = (PRECISION + 2 + b.bits()).saturating_sub(a.bits());
+    let (quotient, remainder) = a.shl(scale).div_rem(&b);
+    let sticky = if remainder.is_zero() { Natural::zero() } else { Natural::one() };
+    Self::round(
+      self.negative != rhs.negative,
+      &quotient.shl(1).add(&sticky),
+      self.exponent - rhs.exponent - scale as i64 - 1,
+    )
+  }
+
+  /// Compares the values of two numbers.
+  pub fn compare(&self, rhs: &Self) -> Ordering {
+    let difference = self.sub(rhs);
+    match (difference.is_zero(), difference.negative) {
+      (true, _) => Ordering::Equal,
+      (false, true) => Ordering::Less,
+      (false, false) => Ordering::Greater,
+    }
+  }
+
+  /// Returns the correctly rounded exponential function of this number.
+  pub fn exp(&self) -> Self {
+    // for arguments this small, the result rounds to one
+    if self.is_zero() || self.top() < -(PRECISION as i64) {
+      return Self::one();
+    }
+    // reduce the argument to range [-ln(2)/2, ln(2)/2] and multiply the result by power of two
+    let k = (self.to_f64() / std::f64::consts::LN_2).round() as i64;
+    let k_ln_2 = |precision| Fixed::new(k < 0, ln_2(precision).mul_small(k.unsigned_abs() as u32));
+    approximate(|precision| {
+      let r = self.to_fixed(precision).sub(&k_ln_2(precision));
+      (Fixed::new(false, exp_fixed(&r, precision)), k - precision as i64)
+    })
+  }
+
+  /// Returns the correctly rounded natural logarithm of this positive number.
+  pub fn ln(&self) -> Self {
+    let bits = 128 - self.significand.leading_zeros() as i64;
+    if self.significand.is_power_of_two() && self.top() == 1 {
+      return Self::round(false, &Natural::zero(), 0);
+    }
+    // split the number into m * 2^j, where m is in range [sqrt(1/2), sqrt(2))
+    let mut j = self.exponent + bits - 1;
+    if self.significand << (128 - bits) > SQRT_2 {
+      j += 1;
+    }
+    approximate(|precision| {
+      let m = Fixed::new(false, shift(&Natural::from_u128(self.significand), self.exponent - j + precision as i64));
+      let one = Fixed::new(false, Natural::one().shl(precision));
+      let j_ln_2 = Fixed::new(j < 0, ln_2(precision).mul_small(j.unsigned_abs() as u32));
+      (ln_fixed(&m, &one, precision).add(&j_ln_2), -(precision as i64))
+    })
+  }
+
+  /// Returns the floating-point approximation of this number.
+  fn to_f64(&self) -> f64 {
+    let value = self.significand as f64 * 2_f64.powi(self.exponent.clamp(-1100, 1100) as i32);
+    if self.negative {
+      -value
+    } else {
+      value
+    }
+  }
+
+  /// Converts this number into fixed-point number with specified number of fractional bits, truncating the value.
+  fn to_fixed(&self, precision: u64) -> Fixed {
+    Fixed::new(self.negative, shift(&Natural::from_u128(self.significand), self.exponent + precision as i64))
+  }
+
+  /// Returns the exponent of the power of two just above the magnitude of this non-zero number.
+  fn top(&self) -> i64 {
+    self.exponent + 128 - self.significand.leading_zeros() as i64
+  }
+
+  fn pack(negative: bool, m: &Natural, e: i64, nearest: bool) -> Self {
+    let bits = m.bits() as i64;
+    if bits == 0 {
+      return Self {
+        negative,
+        significand: 0,
+        exponent: 0,
+      };
+    }
+    let count = (bits - PRECISION as i64).max(MIN_EXPONENT - e);
+    if count <= 0 {
+      return Self {
+        negative,
+        significand: m.shl(count.unsigned_abs()).to_u128().unwrap(),
+        exponent: e + count,
+      };
+    }
+    let count = count as u64;
+    let quotient = m.shr(count);
+    let mut significand = quotient.to_u128().unwrap();
+    let mut exponent = e + count as i64;
+    if nearest {
+      let remainder = m.sub(&quotient.shl(count));
+      match remainder.cmp(&Natural::one().shl(count - 1)) {
+        Ordering::Greater => significand += 1,
+        Ordering::Equal => significand += significand & 1,
+        Ordering::Less => {}
+      }
+      if significand >> PRECISION != 0 {
+        significand >>= 1;
+        exponent += 1;
+      }
+    }
+    Self { negative, significand, exponent }
+  }
+}
+
+/// Signed fixed-point number, the number of fractional bits is defined by the context.
+#[derive(Clone)]
+struct Fixed {
+  /// Flag indicating if the number is negative.
+  negative: bool,
+  /// Magnitude scaled by the power of two defined by the context.
+  magnitude: Natural,
+}
+
+impl Fixed {
+  fn new(negative: bool, magnitude: Natural) -> Self {
+    Self { negative, magnitude }
+  }
+
+  fn add(&self, rhs: &Self) -> Self {
+    if self.negative == rhs.negative {
+      return Self::new(self.negative, self.magnitude.add(&rhs.magnitude));
+    }
+    if self.magnitude >= rhs.magnitude {
+      Self::new(self.negative, self.magnitude.sub(&rhs.magnitude))
+    } else {
+      Self::new(rhs.negative, rhs.magnitude.sub(&self.magnitude))
+    }
+  }
+
+  fn sub(&self, rhs: &Self) -> Self {
+    self.add(&Self::new(!rhs.negative, rhs.magnitude.clone()))
+  }
+
+  fn mul(&self, rhs: &Self, precision: u64) -> Self {
+    Self::new(self.negative != rhs.negative, self.magnitude.mul(&rhs.magnitude).shr(precision))
+  }
+
+  fn div_small(&self, rhs: u32) -> Self {
+    Self::new(self.negative, self.magnitude.div_rem_small(rhs).0)
+  }
+}
+
+/// Shifts the number left by specified number of bits, or right when the number is negative.
+fn shift(n: &Natural, bits: i64) -> Natural {
+  if bits >= 0 {
+    n.shl(bits as u64)
+  } else {
+    n.shr(bits.unsigned_abs())
+  }
+}
+
+/// Evaluates approximations `x * 2^e` with increasing precision (the error is far below `2^(e + 24)`),
+/// until the rounded result is certainly the same as the rounded exact value.
+fn approximate(f: impl Fn(u64) -> (Fixed, i64)) -> Binary128 {
+  let mut precision = INITIAL_WORKING_PRECISION;
+  loop {
+    let (x, e) = f(precision);
+    if precision >= MAX_WORKING_PRECISION || !is_ambiguous(&x.magnitude, e, 24) {
+      return Binary128::round(x.negative, &x.magnitude, e);
+    }
+    precision += 64;
+  }
+}
+
+/// Returns `true` when the value `m * 2^e`, having the error less than `2^(e + tolerance)`,
+/// is too close to the midpoint between two adjacent numbers to be rounded.
+fn is_ambiguous(m: &Natural, e: i64, tolerance: u64) -> bool {
+  let count = (m.bits() as i64 - PRECISION as i64).max(MIN_EXPONENT - e);
+  if count <= tolerance as i64 + 1 {
+    return true;
+  }
+  let count = count as u64;
+  let remainder = m.sub(&m.shr(count).shl(count));
+  let half = Natural::one().shl(count - 1);
+  let distance = if remainder >= half { remainder.sub(&half) } else { half.sub(&remainder) };
+  distance <= Natural::one().shl(tolerance)
+}
+
+/// Returns the exponential function of the fixed-point number close to zero.
+fn exp_fixed(x: &Fixed, precision: u64) -> Natural {
+  let mut sum = Fixed::new(false, Natural::one().shl(precision));
+  let mut term = sum.clone();
+  let mut n = 1;
+  loop {
+    term = term.mul(x, precision).div_small(n);
+    if term.magnitude.is_zero() {
+      return sum.magnitude;
+    }
+    sum = sum.add(&term);
+    n += 1;
+  }
+}
+
+/// Returns the natural logarithm of the fixed-point number close to one, calculated as `2 * atanh((x - 1) / (x + 1))`.
+fn ln_fixed(x: &Fixed, one: &Fixed, precision: u64) -> Fixed {
+  let numerator = x.sub(one);
+  let denominator = x.add(one);
+  let z = Fixed::new(numerator.negative, numerator.magnitude.shl(precision).div_rem(&denominator.magnitude).0);
+  let z2 = z.mul(&z, precision);
+  let mut sum = z.clone();
+  let mut power = z;
+  let mut n = 3;
+  loop {
+    power = power.mul(&z2, precision);
+    let term = power.div_small(n);
+    if term.magnitude.is_zero() {
+      return Fixed::new(sum.negative, sum.magnitude.shl(1));
+    }
+    sum = sum.add(&term);
+    n += 2;
+  }
+}
+
+/// Returns the natural logarithm of 2 as fixed-point number, calculated as `2 * atanh(1/3)`.
+fn ln_2(precision: u64) -> Natural {
+  let scale = precision + 10;
+  let mut power = Natural::one().shl(scale).div_rem_small(3).0;
+  let mut sum = power.clone();
+  let mut k = 3;
+  loop {
+    power = power.div_rem_small(9).0;
+    if power.is_zero() {
+      return sum.shl(1).shr(10);
+    }
+    sum = sum.add(&power.div_rem_small(k).0);
+    k += 2;
+  }
+}
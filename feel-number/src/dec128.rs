@@ -0,0 +1,1343 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Pure-Rust implementation of 128-bit decimal floating-point arithmetic.
+//!
+//! Provides the subset of functions from **Intel(R) Decimal Floating-Point Math Library**
+//! used by [FeelNumber](crate::FeelNumber), with the same names and semantics.
+//! Numbers have 34-digit coefficients and exponents in range defined for `decimal128`
+//! format in IEEE 754-2008. Arithmetic operations and square root are correctly rounded.
+//! The exponential function, the natural logarithm and powers follow the algorithms
+//! of the library being replaced, including the intermediate calculations
+//! in `binary128` format, so their results are the same.
+
+use crate::binary128::Binary128;
+use crate::natural::Natural;
+use std::cmp::Ordering;
+
+/// Rounding to nearest, ties to even.
+pub const RM_NEAREST_EVEN: u32 = 0;
+/// Rounding toward negative infinity.
+pub const RM_DOWNWARD: u32 = 1;
+/// Rounding toward positive infinity.
+pub const RM_UPWARD: u32 = 2;
+/// Rounding toward zero.
+pub const RM_TOWARD_ZERO: u32 = 3;
+/// Rounding to nearest, ties away from zero.
+pub const RM_NEAREST_AWAY: u32 = 4;
+
+/// No status flags set.
+pub const FB_CLEAR: u32 = 0x00;
+/// Invalid operation.
+pub const FB_INVALID: u32 = 0x01;
+/// Division by zero.
+pub const FB_ZERO_DIVIDE: u32 = 0x04;
+/// Overflow.
+pub const FB_OVERFLOW: u32 = 0x08;
+/// Underflow.
+pub const FB_UNDERFLOW: u32 = 0x10;
+/// Inexact result.
+pub const FB_INEXACT: u32 = 0x20;
+
+/// Number of digits in coefficient.
+const PRECISION: u32 = 34;
+
+/// Maximum value of the coefficient.
+const MAX_COEFFICIENT: u128 = 9_999_999_999_999_999_999_999_999_999_999_999;
+
+/// Maximum exponent of the coefficient.
+const MAX_EXPONENT: i64 = 6111;
+
+/// Minimum exponent of the coefficient (subnormal numbers included).
+const MIN_EXPONENT: i64 = -6176;
+
+/// Minimum adjusted exponent of normal numbers.
+const MIN_NORMAL_EXPONENT: i64 = -6143;
+
+/// Number of bits in the significand of `binary128` format.
+const BINARY_PRECISION: u64 = 113;
+
+/// Number of digits of the natural logarithm calculated while evaluating powers.
+const LN_PARTS_PRECISION: u32 = 60;
+
+/// Exponential function of 11000, rounded.
+const EXP_11000: BID128 = BID128::constant(1_735_005_819_277_738_509_638_425_077_349_642, 4744);
+
+/// Exponential function of -11000, rounded.
+const EXP_MINUS_11000: BID128 = BID128::constant(5_763_669_429_168_183_696_272_822_960_961_269, -4811);
+
+/// Natural logarithm of 10^4464, encoded in `binary128` format.
+const LN_10_4464: u128 = 0x400c_4135_eb39_29fb_a719_f2c9_46d2_d728;
+
+/// Coefficient of the second term of Taylor series of the natural logarithm, -1/2.
+const TAYLOR_LN_2: BID128 = BID128 {
+  negative: true,
+  ..BID128::constant(5, -1)
+};
+
+/// Coefficient of the third term of Taylor series of the natural logarithm, 1/3 rounded.
+const TAYLOR_LN_3: BID128 = BID128::constant(3_333_333_333_333_333_333_333_333_333_333_333, -34);
+
+/// Coefficient of the fourth term of Taylor series of the natural logarithm, -1/4.
+const TAYLOR_LN_4: BID128 = BID128 {
+  negative: true,
+  ..BID128::constant(25, -2)
+};
+
+/// Class of the decimal number.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Class {
+  Finite,
+  Infinite,
+  NaN,
+}
+
+/// 128-bit decimal floating-point number.
+///
+/// Unlike in the library being replaced, the number is kept in unpacked form,
+/// only the name of the type is retained.
+#[derive(Debug, Copy, Clone)]
+pub struct BID128 {
+  /// Class of the number.
+  class: Class,
+  /// Flag indicating if the number is negative.
+  negative: bool,
+  /// Coefficient, not greater than [MAX_COEFFICIENT].
+  coefficient: u128,
+  /// Exponent of the coefficient.
+  exponent: i32,
+}
+
+impl BID128 {
+  /// Creates a positive finite number in constant context.
+  const fn constant(coefficient: u128, exponent: i32) -> Self {
+    Self {
+      class: Class::Finite,
+      negative: false,
+      coefficient,
+      exponent,
+    }
+  }
+
+  /// Creates a finite number, exponent must be in valid range.
+  fn finite(negative: bool, coefficient: u128, exponent: i64) -> Self {
+    debug_assert!(coefficient <= MAX_COEFFICIENT && (MIN_EXPONENT..=MAX_EXPONENT).contains(&exponent));
+    Self {
+      class: Class::Finite,
+      negative,
+      coefficient,
+      exponent: exponent as i32,
+    }
+  }
+
+  /// Creates a signed zero, exponent is clamped to valid range.
+  fn zero(negative: bool, exponent: i64) -> Self {
+    Self::finite(negative, 0, exponent.clamp(MIN_EXPONENT, MAX_EXPONENT))
+  }
+
+  /// Creates a number equal to one.
+  fn one() -> Self {
+    Self::finite(false, 1, 0)
+  }
+
+  /// Creates a signed infinity.
+  fn infinite(negative: bool) -> Self {
+    Self {
+      class: Class::Infinite,
+      negative,
+      coefficient: 0,
+      exponent: 0,
+    }
+  }
+
+  /// Creates a quiet NaN.
+  fn nan() -> Self {
+    Self {
+      class: Class::NaN,
+      negative: false,
+      coefficient: 0,
+      exponent: 0,
+    }
+  }
+
+  fn is_finite(&self) -> bool {
+    self.class == Class::Finite
+  }
+
+  fn is_infinite(&self) -> bool {
+    self.class == Class::Infinite
+  }
+
+  fn is_nan(&self) -> bool {
+    self.class == Class::NaN
+  }
+
+  fn is_zero(&self) -> bool {
+    self.is_finite() && self.coefficient == 0
+  }
+
+  /// Returns the absolute value.
+  fn abs(&self) -> Self {
+    Self { negative: false, ..*self }
+  }
+
+  /// Returns `true` when this number is finite and equal to one.
+  fn is_one(&self) -> bool {
+    self.is_finite() && !self.negative && compare_magnitudes(self, &Self::one()) == Ordering::Equal
+  }
+
+  /// Returns the integral value of this finite number when it has no fractional part.
+  fn integral_value(&self) -> Option<Natural> {
+    if self.exponent >= 0 {
+      return Some(Natural::from_u128(self.coefficient).mul(&Natural::pow10(self.exponent as u32)));
+    }
+    let scale = self.exponent.unsigned_abs();
+    if scale > PRECISION + 4 {
+      return if self.coefficient == 0 { Some(Natural::zero()) } else { None };
+    }
+    let divisor = 10_u128.pow(scale);
+    if self.coefficient % divisor == 0 {
+      Some(Natural::from_u128(self.coefficient / divisor))
+    } else {
+      None
+    }
+  }
+}
+
+/// Returns `true` when the coefficient should be incremented after dropping digits.
+fn round_up(rnd: u32, negative: bool, odd: bool, half: Ordering, inexact: bool) -> bool {
+  match rnd {
+    RM_NEAREST_EVEN => half == Ordering::Greater || (half == Ordering::Equal && odd),
+    RM_NEAREST_AWAY => half != Ordering::Less,
+    RM_DOWNWARD => negative && inexact,
+    RM_UPWARD => !negative && inexact,
+    _ => false,
+  }
+}
+
+/// Drops the specified number of digits from the coefficient, using specified rounding mode.
+/// Returns the rounded coefficient and a flag indicating if any non-zero digit was dropped.
+fn drop_digits(coefficient: u128, count: u32, negative: bool, rnd: u32) -> (u128, bool) {
+  if count == 0 {
+    return (coefficient, false);
+  }
+  if count > 38 {
+    // the coefficient is always less than half of the dropped unit
+    let inexact = coefficient > 0;
+    return (round_up(rnd, negative, false, Ordering::Less, inexact) as u128, inexact);
+  }
+  let unit = 10_u128.pow(count);
+  let (quotient, remainder) = (coefficient / unit, coefficient % unit);
+  let half = remainder.cmp(&(unit / 2));
+  let inexact = remainder > 0;
+  (quotient + round_up(rnd, negative, quotient % 2 == 1, half, inexact) as u128, inexact)
+}
+
+/// Creates a number from the coefficient and exponent of any size,
+/// applying rounding, underflow and overflow rules.
+fn finish(negative: bool, coefficient: Natural, exponent: i64, rnd: u32, flags: &mut u32) -> BID128 {
+  if coefficient.is_zero() {
+    return BID128::zero(negative, exponent);
+  }
+  let digits = coefficient.digits() as i64;
+  let count = (digits - PRECISION as i64).max(MIN_EXPONENT - exponent).max(0);
+  let (mut coefficient, mut exponent, inexact) = if count == 0 {
+    (coefficient.to_u128().unwrap(), exponent, false)
+  } else if count > digits {
+    let result = round_up(rnd, negative, false, Ordering::Less, true) as u128;
+    (result, exponent + count, true)
+  } else {
+    let unit = Natural::pow10(count as u32);
+    let (quotient, remainder) = coefficient.div_rem(&unit);
+    let half = remainder.mul_small(2).cmp(&unit);
+    let inexact = !remainder.is_zero();
+    let increment = round_up(rnd, negative, quotient.is_odd(), half, inexact) as u128;
+    (quotient.to_u128().unwrap() + increment, exponent + count, inexact)
+  };
+  if coefficient > MAX_COEFFICIENT {
+    coefficient /= 10;
+    exponent += 1;
+  }
+  if inexact {
+    *flags |= FB_INEXACT;
+    if exponent + (digits - count) - 1 < MIN_NORMAL_EXPONENT {
+      *flags |= FB_UNDERFLOW;
+    }
+  }
+  if coefficient == 0 {
+    return BID128::zero(negative, exponent);
+  }
+  if exponent > MAX_EXPONENT {
+    let padding = (exponent - MAX_EXPONENT) as u32;
+    if padding <= PRECISION && coefficient.checked_mul(10_u128.pow(padding)).is_some_and(|padded| padded <= MAX_COEFFICIENT) {
+      return BID128::finite(negative, coefficient * 10_u128.pow(padding), MAX_EXPONENT);
+    }
+    *flags |= FB_OVERFLOW | FB_INEXACT;
+    return overflow(negative, rnd);
+  }
+  BID128::finite(negative, coefficient, exponent)
+}
+
+/// Same as [finish] but for coefficients fitting in [u128].
+fn finish_u128(negative: bool, coefficient: u128, exponent: i64, rnd: u32, flags: &mut u32) -> BID128 {
+  if coefficient <= MAX_COEFFICIENT && (MIN_EXPONENT..=MAX_EXPONENT).contains(&exponent) {
+    BID128::finite(negative, coefficient, exponent)
+  } else {
+    finish(negative, Natural::from_u128(coefficient), exponent, rnd, flags)
+  }
+}
+
+/// Returns the result of overflow for specified rounding mode.
+fn overflow(negative: bool, rnd: u32) -> BID128 {
+  let infinite = match rnd {
+    RM_DOWNWARD => negative,
+    RM_UPWARD => !negative,
+    RM_TOWARD_ZERO => false,
+    _ => true,
+  };
+  if infinite {
+    BID128::infinite(negative)
+  } else {
+    BID128::finite(negative, MAX_COEFFICIENT, MAX_EXPONENT)
+  }
+}
+
+/// Returns the number of decimal digits in coefficient.
+fn digits(coefficient: u128) -> u32 {
+  if coefficient == 0 {
+    0
+  } else {
+    coefficient.ilog10() + 1
+  }
+}
+
+/// Compares magnitudes of two finite numbers.
+fn compare_magnitudes(x: &BID128, y: &BID128) -> Ordering {
+  match (x.coefficient == 0, y.coefficient == 0) {
+    (true, true) => return Ordering::Equal,
+    (true, false) => return Ordering::Less,
+    (false, true) => return Ordering::Greater,
+    _ => {}
+  }
+  let (dx, dy) = (digits(x.coefficient), digits(y.coefficient));
+  let (ax, ay) = (x.exponent as i64 + dx as i64, y.exponent as i64 + dy as i64);
+  ax.cmp(&ay).then_with(|| {
+    if dx >= dy {
+      x.coefficient.cmp(&(y.coefficient * 10_u128.pow(dx - dy)))
+    } else {
+      (x.coefficient * 10_u128.pow(dy - dx)).cmp(&y.coefficient)
+    }
+  })
+}
+
+/// Compares two numbers, returns `None` when any of them is NaN.
+fn compare(x: &BID128, y: &BID128) -> Option<Ordering> {
+  if x.is_nan() || y.is_nan() {
+    return None;
+  }
+  let sign = |n: &BID128| -> i8 {
+    if n.is_zero() {
+      0
+    } else if n.negative {
+      -1
+    } else {
+      1
+    }
+  };
+  let (sx, sy) = (sign(x), sign(y));
+  if sx != sy {
+    return Some(sx.cmp(&sy));
+  }
+  let magnitudes = match (x.is_infinite(), y.is_infinite()) {
+    (true, true) => Ordering::Equal,
+    (true, false) => Ordering::Greater,
+    (false, true) => Ordering::Less,
+    _ => compare_magnitudes(x, y),
+  };
+  Some(if sx < 0 { magnitudes.reverse() } else { magnitudes })
+}
+
+pub fn bid128_abs(x: BID128) -> BID128 {
+  x.abs()
+}
+
+pub fn bid128_negate(x: BID128) -> BID128 {
+  BID128 { negative: !x.negative, ..x }
+}
+
+pub fn bid128_inf() -> BID128 {
+  BID128::infinite(false)
+}
+
+pub fn bid128_is_finite(x: BID128) -> bool {
+  x.is_finite()
+}
+
+pub fn bid128_is_zero(x: BID128) -> bool {
+  x.is_zero()
+}
+
+pub fn bid128_from_int32(x: i32) -> BID128 {
+  BID128::finite(x < 0, x.unsigned_abs() as u128, 0)
+}
+
+pub fn bid128_from_int64(x: i64) -> BID128 {
+  BID128::finite(x < 0, x.unsigned_abs() as u128, 0)
+}
+
+pub fn bid128_from_uint32(x: u32) -> BID128 {
+  BID128::finite(false, x as u128, 0)
+}
+
+pub fn bid128_from_uint64(x: u64) -> BID128 {
+  BID128::finite(false, x as u128, 0)
+}
+
+pub fn bid128_add(x: BID128, y: BID128, rnd: u32, flags: &mut u32) -> BID128 {
+  if x.is_nan() || y.is_nan() {
+    return BID128::nan();
+  }
+  match (x.is_infinite(), y.is_infinite()) {
+    (true, true) if x.negative != y.negative => {
+      *flags |= FB_INVALID;
+      return BID128::nan();
+    }
+    (true, _) => return x,
+    (_, true) => return y,
+    _ => {}
+  }
+  let exponent = x.exponent.min(y.exponent) as i64;
+  match (x.coefficient == 0, y.coefficient == 0) {
+    (true, true) => {
+      let negative = if x.negative == y.negative { x.negative } else { rnd == RM_DOWNWARD };
+      return BID128::zero(negative, exponent);
+    }
+    (true, false) => return rescale(&y, exponent),
+    (false, true) => return rescale(&x, exponent),
+    _ => {}
+  }
+  let (large, small) = if x.exponent >= y.exponent { (x, y) } else { (y, x) };
+  let shift = (large.exponent - small.exponent) as u32;
+  let large_digits = digits(large.coefficient);
+  // when the smaller operand is below the rounding position of the result,
+  // it only matters that it is non-zero, so it is replaced with a single digit
+  let scale = (PRECISION + 3).saturating_sub(large_digits);
+  let (small_coefficient, shift) = if shift > scale && small.exponent as i64 + (digits(small.coefficient) as i64) < large.exponent as i64 - scale as i64 {
+    (1, scale + 1)
+  } else {
+    (small.coefficient, shift)
+  };
+  let exponent = large.exponent as i64 - shift as i64;
+  if shift <= 4 {
+    let large_coefficient = large.coefficient * 10_u128.pow(shift);
+    let (negative, coefficient) = if large.negative == small.negative {
+      (large.negative, large_coefficient + small_coefficient)
+    } else if large_coefficient >= small_coefficient {
+      (large.negative, large_coefficient - small_coefficient)
+    } else {
+      (small.negative, small_coefficient - large_coefficient)
+    };
+    if coefficient == 0 {
+      return BID128::zero(rnd == RM_DOWNWARD, exponent);
+    }
+    return finish_u128(negative, coefficient, exponent, rnd, flags);
+  }
+  let large_coefficient = Natural::from_u128(large.coefficient).mul(&Natural::pow10(shift));
+  let small_coefficient = Natural::from_u128(small_coefficient);
+  let (negative, coefficient) = if large.negative == small.negative {
+    (large.negative, large_coefficient.add(&small_coefficient))
+  } else if large_coefficient >= small_coefficient {
+    (large.negative, large_coefficient.sub(&small_coefficient))
+  } else {
+    (small.negative, small_coefficient.sub(&large_coefficient))
+  };
+  if coefficient.is_zero() {
+    return BID128::zero(rnd == RM_DOWNWARD, exponent);
+  }
+  finish(negative, coefficient, exponent, rnd, flags)
+}
+
+/// Rescales a finite non-zero number to the exponent as close as possible to specified one,
+/// not changing its value.
+fn rescale(x: &BID128, exponent: i64) -> BID128 {
+  let padding = (x.exponent as i64 - exponent).clamp(0, (PRECISION - digits(x.coefficient)) as i64) as u32;
+  BID128::finite(x.negative, x.coefficient * 10_u128.pow(padding), x.exponent as i64 - padding as i64)
+}
+
+pub fn bid128_sub(x: BID128, y: BID128, rnd: u32, flags: &mut u32) -> BID128 {
+  bid128_add(x, bid128_negate(y), rnd, flags)
+}
+
+pub fn bid128_mul(x: BID128, y: BID128, rnd: u32, flags: &mut u32) -> BID128 {
+  if x.is_nan() || y.is_nan() {
+    return BID128::nan();
+  }
+  let negative = x.negative != y.negative;
+  if x.is_infinite() || y.is_infinite() {
+    if x.is_zero() || y.is_zero() {
+      *flags |= FB_INVALID;
+      return BID128::nan();
+    }
+    return BID128::infinite(negative);
+  }
+  let exponent = x.exponent as i64 + y.exponent as i64;
+  match x.coefficient.checked_mul(y.coefficient) {
+    Some(coefficient) => finish_u128(negative, coefficient, exponent, rnd, flags),
+    None => finish(negative, Natural::from_u128(x.coefficient).mul(&Natural::from_u128(y.coefficient)), exponent, rnd, flags),
+  }
+}
+
+pub fn bid128_div(x: BID128, y: BID128, rnd: u32, flags: &mut u32) -> BID128 {
+  if x.is_nan() || y.is_nan() {
+    return BID128::nan();
+  }
+  let negative = x.negative != y.negative;
+  match (x.is_infinite(), y.is_infinite()) {
+    (true, true) => {
+      *flags |= FB_INVALID;
+      return BID128::nan();
+    }
+    (true, false) => return BID128::infinite(negative),
+    (false, true) => return BID128::zero(negative, MIN_EXPONENT),
+    _ => {}
+  }
+  if y.is_zero() {
+    if x.is_zero() {
+      *flags |= FB_INVALID;
+      return BID128::nan();
+    }
+    *flags |= FB_ZERO_DIVIDE;
+    return BID128::infinite(negative);
+  }
+  let exponent = x.exponent as i64 - y.exponent as i64;
+  if x.is_zero() {
+    return BID128::zero(negative, exponent);
+  }
+  divide(negative, Natural::from_u128(x.coefficient), Natural::from_u128(y.coefficient), exponent, rnd, flags)
+}
+
+/// Divides coefficients, the result has the exponent as close as possible to the preferred one.
+fn divide(negative: bool, dividend: Natural, divisor: Natural, preferred_exponent: i64, rnd: u32, flags: &mut u32) -> BID128 {
+  // scale the dividend, so the quotient has at least one digit more than the precision
+  let scale = (PRECISION as i64 + 1 + divisor.digits() as i64 - dividend.digits() as i64).max(0) as u32;
+  let (quotient, remainder) = dividend.mul(&Natural::pow10(scale)).div_rem(&divisor);
+  let mut exponent = preferred_exponent - scale as i64;
+  if remainder.is_zero() {
+    let mut quotient = quotient;
+    while exponent < preferred_exponent {
+      let (reduced, digit) = quotient.div_rem_small(10);
+      if digit != 0 {
+        break;
+      }
+      quotient = reduced;
+      exponent += 1;
+    }
+    return finish(negative, quotient, exponent, rnd, flags);
+  }
+  // append a non-zero digit, so the rounding knows the quotient is inexact
+  exponent -= 1;
+  finish(negative, quotient.mul_small(10).add(&Natural::one()), exponent, rnd, flags)
+}
+
+pub fn bid128_rem(x: BID128, y: BID128, flags: &mut u32) -> BID128 {
+  if x.is_nan() || y.is_nan() {
+    return BID128::nan();
+  }
+  if x.is_infinite() || y.is_zero() {
+    *flags |= FB_INVALID;
+    return BID128::nan();
+  }
+  if y.is_infinite() {
+    return x;
+  }
+  let exponent = x.exponent.min(y.exponent) as i64;
+  if x.is_zero() {
+    return BID128::zero(x.negative, exponent);
+  }
+  let dividend = Natural::from_u128(x.coefficient).mul(&Natural::pow10((x.exponent as i64 - exponent) as u32));
+  let divisor = Natural::from_u128(y.coefficient).mul(&Natural::pow10((y.exponent as i64 - exponent) as u32));
+  let (quotient, remainder) = dividend.div_rem(&divisor);
+  // the quotient is rounded to the nearest integer, ties to even
+  let (negative, remainder) = match remainder.mul_small(2).cmp(&divisor) {
+    Ordering::Greater => (!x.negative, divisor.sub(&remainder)),
+    Ordering::Equal if quotient.is_odd() => (!x.negative, divisor.sub(&remainder)),
+    _ => (x.negative, remainder),
+  };
+  if remainder.is_zero() {
+    return BID128::zero(x.negative, exponent);
+  }
+  finish(negative, remainder, exponent, RM_NEAREST_EVEN, flags)
+}
+
+pub fn bid128_sqrt(x: BID128, rnd: u32, flags: &mut u32) -> BID128 {
+  if x.is_nan() {
+    return BID128::nan();
+  }
+  let preferred_exponent = (x.exponent as i64).div_euclid(2);
+  if x.is_zero() {
+    return BID128::zero(x.negative, preferred_exponent);
+  }
+  if x.negative {
+    *flags |= FB_INVALID;
+    return BID128::nan();
+  }
+  if x.is_infinite() {
+    return x;
+  }
+  let mut coefficient = Natural::from_u128(x.coefficient);
+  let mut exponent = x.exponent as i64;
+  if exponent.rem_euclid(2) == 1 {
+    coefficient = coefficient.mul_small(10);
+    exponent -= 1;
+  }
+  // scale the radicand, so the root has at least one digit more than the precision
+  let scale = (2 * (PRECISION + 1)).saturating_sub(coefficient.digits()).div_ceil(2);
+  coefficient = coefficient.mul(&Natural::pow10(2 * scale));
+  exponent -= 2 * scale as i64;
+  let root = coefficient.sqrt();
+  let mut exponent = exponent / 2;
+  if root.mul(&root) == coefficient {
+    let mut root = root;
+    while exponent < preferred_exponent {
+      let (reduced, digit) = root.div_rem_small(10);
+      if digit != 0 {
+        break;
+      }
+      root = reduced;
+      exponent += 1;
+    }
+    return finish(false, root, exponent, rnd, flags);
+  }
+  exponent -= 1;
+  finish(false, root.mul_small(10).add(&Natural::one()), exponent, rnd, flags)
+}
+
+pub fn bid128_exp(x: BID128, rnd: u32, flags: &mut u32) -> BID128 {
+  if x.is_nan() {
+    return BID128::nan();
+  }
+  if x.is_infinite() {
+    return if x.negative { BID128::zero(false, 0) } else { x };
+  }
+  if x.is_zero() {
+    return BID128::one();
+  }
+  // results out of range of finite numbers are calculated by overflowing or underflowing multiplication
+  if compare(&x, &bid128_from_int32(15000)) == Some(Ordering::Greater) {
+    return bid128_mul(EXP_11000, EXP_11000, rnd, flags);
+  }
+  if compare(&x, &bid128_from_int32(-15000)) == Some(Ordering::Less) {
+    let tiny = BID128::finite(false, 1, -6000);
+    return bid128_mul(tiny, tiny, rnd, flags);
+  }
+  // like in Intel's library, the result is calculated in binary128 format
+  // from the argument converted into the sum of two binary numbers
+  let (high, low) = to_binary128_parts(&x);
+  let limit = to_binary128(&bid128_from_int32(11000));
+  let (high, scale) = if high.compare(&limit) == Ordering::Greater {
+    (high.sub(&limit), Some(EXP_11000))
+  } else if high.compare(&limit.neg()) == Ordering::Less {
+    (high.add(&limit), Some(EXP_MINUS_11000))
+  } else {
+    (high, None)
+  };
+  let r = high.exp();
+  let result = from_binary128(&r.add(&r.mul(&low)), rnd, flags);
+  match scale {
+    Some(scale) => bid128_mul(result, scale, rnd, flags),
+    None => result,
+  }
+}
+
+pub fn bid128_log(x: BID128, rnd: u32, flags: &mut u32) -> BID128 {
+  if x.is_nan() {
+    return BID128::nan();
+  }
+  if x.is_zero() {
+    *flags |= FB_ZERO_DIVIDE;
+    return BID128::infinite(true);
+  }
+  if x.negative {
+    *flags |= FB_INVALID;
+    return BID128::nan();
+  }
+  if x.is_infinite() {
+    return x;
+  }
+  // like in Intel's library, the result is calculated in binary128 format,
+  // arguments out of range of this format are scaled by the power of ten
+  let ln_10_4464 = Binary128::from_bits(LN_10_4464);
+  let (x, offset) = if compare(&x, &BID128::finite(false, 1, 4464)) == Some(Ordering::Greater) {
+    (bid128_mul(x, BID128::finite(false, 1, -4464), rnd, flags), Some(ln_10_4464))
+  } else if compare(&x, &BID128::finite(false, 1, -4464)) == Some(Ordering::Less) {
+    (bid128_mul(x, BID128::finite(false, 1, 4464), rnd, flags), Some(ln_10_4464.neg()))
+  } else {
+    (x, None)
+  };
+  let xq = to_binary128(&x);
+  let mut r = xq.ln();
+  match offset {
+    Some(offset) => r = r.add(&offset),
+    None => {
+      // for arguments close to one, the error of converting the argument into binary is compensated
+      let e = xq.sub(&Binary128::one());
+      if e.abs().compare(&Binary128::round(false, &Natural::one(), -1)) == Ordering::Less {
+        let exact_e = to_binary128(&bid128_sub(x, BID128::one(), rnd, flags));
+        r = r.sub(&e.sub(&exact_e).div(&xq));
+      }
+    }
+  }
+  from_binary128(&r, rnd, flags)
+}
+
+pub fn bid128_pow(x: BID128, y: BID128, rnd: u32, flags: &mut u32) -> BID128 {
+  if y.is_zero() || x.is_one() {
+    return BID128::one();
+  }
+  if x.is_nan() || y.is_nan() {
+    return BID128::nan();
+  }
+  if y.is_infinite() {
+    return match compare(&x.abs(), &BID128::one()) {
+      Some(Ordering::Equal) => BID128::one(),
+      Some(Ordering::Less) if y.negative => BID128::infinite(false),
+      Some(Ordering::Less) => BID128::zero(false, 0),
+      _ if y.negative => BID128::zero(false, 0),
+      _ => BID128::infinite(false),
+    };
+  }
+  let integral = y.integral_value();
+  let negative = x.negative && integral.as_ref().is_some_and(|n| n.is_odd());
+  if x.is_infinite() {
+    let result = if y.negative { BID128::zero(false, 0) } else { BID128::infinite(false) };
+    return BID128 { negative, ..result };
+  }
+  if x.is_zero() {
+    let result = if y.negative {
+      *flags |= FB_ZERO_DIVIDE;
+      BID128::infinite(false)
+    } else {
+      BID128::zero(false, 0)
+    };
+    return BID128 { negative, ..result };
+  }
+  if x.negative && integral.is_none() {
+    *flags |= FB_INVALID;
+    return BID128::nan();
+  }
+  if let Some(n) = integral.and_then(|n| n.to_u128()).filter(|n| *n <= i32::MAX as u128) {
+    let result = power_by_squaring(x, n as u64, rnd, flags);
+    return if y.negative { bid128_div(BID128::one(), result, rnd, flags) } else { result };
+  }
+  // like in Intel's library, the result is calculated as exp(y * ln(|x|)),
+  // using the logarithm split into two parts and corrected with fused multiply-add
+  let (ln_high, ln_low) = ln_parts(x.abs(), rnd, flags);
+  let l = bid128_mul(y, ln_high, rnd, flags);
+  let l_high = fma(y, ln_high, bid128_negate(l), rnd, flags);
+  let l_low = fma(y, ln_low, l_high, rnd, flags);
+  let result = bid128_exp(l, rnd, flags);
+  // zero and infinite results are returned without the sign, like in Intel's library
+  if result.is_zero() {
+    *flags |= FB_UNDERFLOW;
+    return BID128::zero(false, MIN_EXPONENT);
+  }
+  if result.is_infinite() {
+    *flags |= FB_OVERFLOW;
+    return result;
+  }
+  let result = fma(result, l_low, result, rnd, flags);
+  if negative {
+    bid128_negate(result)
+  } else {
+    result
+  }
+}
+
+/// Raises the number to the integer power by repeated squaring.
+fn power_by_squaring(mut base: BID128, mut n: u64, rnd: u32, flags: &mut u32) -> BID128 {
+  let mut result = BID128::one();
+  while n > 0 {
+    if n & 1 == 1 {
+      result = bid128_mul(result, base, rnd, flags);
+    }
+    n >>= 1;
+    if n > 0 {
+      base = bid128_mul(base, base, rnd, flags);
+    }
+  }
+  result
+}
+
+/// Returns the natural logarithm of the positive number split into two parts,
+/// the high part has 28 and the low part 47 fractional digits, both are truncated.
+/// For arguments close to one, the parts are calculated from few terms of Taylor series,
+/// exactly the same way as in Intel's library.
+fn ln_parts(x: BID128, rnd: u32, flags: &mut u32) -> (BID128, BID128) {
+  let ln = Approx::from(&x).ln(LN_PARTS_PRECISION);
+  let exponent = ln.exponent + 47;
+  let scaled = if exponent >= 0 {
+    ln.coefficient.mul(&Natural::pow10(exponent as u32))
+  } else {
+    ln.coefficient.div_rem(&Natural::pow10(exponent.unsigned_abs() as u32)).0
+  };
+  let (high, low) = scaled.div_rem(&Natural::pow10(19));
+  if high.bits() > 65 {
+    let high = BID128::finite(ln.negative, high.to_u128().unwrap(), -28);
+    let low = BID128::finite(ln.negative, low.to_u128().unwrap(), -47);
+    return (high, low);
+  }
+  let t = bid128_sub(x, BID128::one(), rnd, flags);
+  let mut power = bid128_mul(t, t, rnd, flags);
+  let mut sum = bid128_mul(TAYLOR_LN_2, power, rnd, flags);
+  power = bid128_mul(t, power, rnd, flags);
+  sum = bid128_add(sum, bid128_mul(TAYLOR_LN_3, power, rnd, flags), rnd, flags);
+  power = bid128_mul(t, power, rnd, flags);
+  sum = bid128_add(sum, bid128_mul(TAYLOR_LN_4, power, rnd, flags), rnd, flags);
+  let high = bid128_add(t, sum, rnd, flags);
+  let low = bid128_sub(bid128_sub(high, t, rnd, flags), sum, rnd, flags);
+  (high, low)
+}
+
+/// Calculates `x * y + z` with a single rounding.
+fn fma(x: BID128, y: BID128, z: BID128, rnd: u32, flags: &mut u32) -> BID128 {
+  if x.is_nan() || y.is_nan() || z.is_nan() {
+    return BID128::nan();
+  }
+  if x.is_infinite() || y.is_infinite() {
+    return bid128_add(bid128_mul(x, y, rnd, flags), z, rnd, flags);
+  }
+  if z.is_infinite() {
+    return z;
+  }
+  let negative = x.negative != y.negative;
+  let coefficient = Natural::from_u128(x.coefficient).mul(&Natural::from_u128(y.coefficient));
+  let exponent = x.exponent as i64 + y.exponent as i64;
+  let preferred_exponent = exponent.min(z.exponent as i64);
+  match (coefficient.is_zero(), z.coefficient == 0) {
+    (true, true) => {
+      let negative = if negative == z.negative { negative } else { rnd == RM_DOWNWARD };
+      return BID128::zero(negative, preferred_exponent);
+    }
+    (true, false) => return rescale(&z, preferred_exponent),
+    (false, true) => {
+      let result = finish(negative, coefficient, exponent, rnd, flags);
+      return if result.is_finite() && result.coefficient != 0 {
+        rescale(&result, preferred_exponent)
+      } else {
+        result
+      };
+    }
+    _ => {}
+  }
+  let product = (negative, coefficient, exponent);
+  let addend = (z.negative, Natural::from_u128(z.coefficient), z.exponent as i64);
+  let (large, small) = if product.2 >= addend.2 { (product, addend) } else { (addend, product) };
+  // when the smaller operand is below the rounding position of the result,
+  // it only matters that it is non-zero, so it is replaced with a single digit
+  let scale = (PRECISION as i64 + 3 - large.1.digits() as i64).max(0);
+  let small = if large.2 - small.2 > scale && small.2 + (small.1.digits() as i64) < large.2 - scale {
+    (small.0, Natural::one(), large.2 - scale - 1)
+  } else {
+    small
+  };
+  let large_coefficient = large.1.mul(&Natural::pow10((large.2 - small.2) as u32));
+  let (negative, coefficient) = if large.0 == small.0 {
+    (large.0, large_coefficient.add(&small.1))
+  } else if large_coefficient >= small.1 {
+    (large.0, large_coefficient.sub(&small.1))
+  } else {
+    (small.0, small.1.sub(&large_coefficient))
+  };
+  if coefficient.is_zero() {
+    return BID128::zero(rnd == RM_DOWNWARD, small.2);
+  }
+  finish(negative, coefficient, small.2, rnd, flags)
+}
+
+/// Converts finite number into the nearest binary floating-point number.
+fn to_binary128(x: &BID128) -> Binary128 {
+  let (m, e) = to_binary(x, BINARY_PRECISION + 2);
+  Binary128::round(x.negative, &m, e)
+}
+
+/// Converts finite number into the sum of two binary floating-point numbers,
+/// the first one is the value truncated to binary precision, the second one is the truncated remainder.
+fn to_binary128_parts(x: &BID128) -> (Binary128, Binary128) {
+  let (m, e) = to_binary(x, 2 * BINARY_PRECISION + 16);
+  let high = Binary128::truncate(x.negative, &m, e);
+  let (_, significand, exponent) = high.parts();
+  let remainder = m.sub(&Natural::from_u128(significand).shl((exponent - e) as u64));
+  (high, Binary128::truncate(x.negative, &remainder, e))
+}
+
+/// Returns the magnitude of finite number as `m * 2^e`, where `m` has at least specified number of bits.
+/// The value is truncated, and the lowest bit of `m` is set when any non-zero bit was dropped.
+fn to_binary(x: &BID128, bits: u64) -> (Natural, i64) {
+  let coefficient = Natural::from_u128(x.coefficient);
+  if x.exponent >= 0 {
+    let n = coefficient.mul(&Natural::pow10(x.exponent as u32));
+    let scale = bits.saturating_sub(n.bits());
+    return (n.shl(scale), -(scale as i64));
+  }
+  let divisor = Natural::pow10(x.exponent.unsigned_abs());
+  let scale = (bits + divisor.bits()).saturating_sub(coefficient.bits()) + 1;
+  let (quotient, remainder) = coefficient.shl(scale).div_rem(&divisor);
+  let sticky = if remainder.is_zero() { Natural::zero() } else { Natural::one() };
+  (quotient.shl(1).add(&sticky), -(scale as i64) - 1)
+}
+
+/// Converts binary floating-point number into decimal number.
+///
+/// Like in Intel's library, exact values with coefficients fitting the precision
+/// are returned with the exponent as close to zero as possible, other values
+/// are rounded to full precision.
+fn from_binary128(x: &Binary128, rnd: u32, flags: &mut u32) -> BID128 {
+  let (negative, significand, exponent) = x.parts();
+  if significand == 0 {
+    return BID128::zero(negative, 0);
+  }
+  let trailing_zeros = significand.trailing_zeros();
+  let (c, e) = (significand >> trailing_zeros, exponent + trailing_zeros as i64);
+  if e >= 0 {
+    if e < c.leading_zeros() as i64 && c << e <= MAX_COEFFICIENT {
+      return BID128::finite(negative, c << e, 0);
+    }
+  } else if let Some(coefficient) = 5_u128.checked_pow(e.unsigned_abs() as u32).and_then(|power| c.checked_mul(power)) {
+    if coefficient <= MAX_COEFFICIENT {
+      return BID128::finite(negative, coefficient, e);
+    }
+  }
+  if exponent >= 0 {
+    finish(negative, Natural::from_u128(significand).shl(exponent as u64), 0, rnd, flags)
+  } else {
+    let power_of_five = Natural::pow10(exponent.unsigned_abs() as u32).shr(exponent.unsigned_abs());
+    finish(negative, Natural::from_u128(significand).mul(&power_of_five), exponent, rnd, flags)
+  }
+}
+
+pub fn bid128_quantize(x: BID128, y: BID128, rnd: u32, flags: &mut u32) -> BID128 {
+  if x.is_nan() || y.is_nan() {
+    return BID128::nan();
+  }
+  match (x.is_infinite(), y.is_infinite()) {
+    (true, true) => return x,
+    (false, false) => {}
+    _ => {
+      *flags |= FB_INVALID;
+      return BID128::nan();
+    }
+  }
+  let exponent = y.exponent as i64;
+  if x.exponent as i64 >= exponent {
+    let shift = (x.exponent as i64 - exponent) as u32;
+    if x.coefficient == 0 {
+      return BID128::zero(x.negative, exponent);
+    }
+    if digits(x.coefficient) + shift > PRECISION {
+      *flags |= FB_INVALID;
+      return BID128::nan();
+    }
+    return BID128::finite(x.negative, x.coefficient * 10_u128.pow(shift), exponent);
+  }
+  let count = (exponent - x.exponent as i64) as u32;
+  let (coefficient, inexact) = drop_digits(x.coefficient, count, x.negative, rnd);
+  if inexact {
+    *flags |= FB_INEXACT;
+  }
+  if coefficient > MAX_COEFFICIENT {
+    *flags |= FB_INVALID;
+    return BID128::nan();
+  }
+  BID128::finite(x.negative, coefficient, exponent)
+}
+
+/// Rounds the number to integral value using specified rounding mode.
+fn round_integral(x: BID128, rnd: u32) -> BID128 {
+  if !x.is_finite() || x.exponent >= 0 {
+    return x;
+  }
+  let (coefficient, _) = drop_digits(x.coefficient, x.exponent.unsigned_abs(), x.negative, rnd);
+  BID128::finite(x.negative, coefficient, 0)
+}
+
+pub fn bid128_round_integral_negative(x: BID128, _flags: &mut u32) -> BID128 {
+  round_integral(x, RM_DOWNWARD)
+}
+
+pub fn bid128_round_integral_positive(x: BID128, _flags: &mut u32) -> BID128 {
+  round_integral(x, RM_UPWARD)
+}
+
+pub fn bid128_round_integral_zero(x: BID128, _flags: &mut u32) -> BID128 {
+  round_integral(x, RM_TOWARD_ZERO)
+}
+
+pub fn bid128_scalbn(x: BID128, n: i32) -> BID128 {
+  if !x.is_finite() {
+    return x;
+  }
+  let mut flags = FB_CLEAR;
+  finish_u128(x.negative, x.coefficient, x.exponent as i64 + n as i64, RM_NEAREST_EVEN, &mut flags)
+}
+
+pub fn bid128_quiet_equal(x: BID128, y: BID128, _flags: &mut u32) -> bool {
+  compare(&x, &y) == Some(Ordering::Equal)
+}
+
+pub fn bid128_quiet_greater(x: BID128, y: BID128, _flags: &mut u32) -> bool {
+  compare(&x, &y) == Some(Ordering::Greater)
+}
+
+pub fn bid128_quiet_greater_equal(x: BID128, y: BID128, _flags: &mut u32) -> bool {
+  matches!(compare(&x, &y), Some(Ordering::Greater | Ordering::Equal))
+}
+
+pub fn bid128_quiet_less(x: BID128, y: BID128, _flags: &mut u32) -> bool {
+  compare(&x, &y) == Some(Ordering::Less)
+}
+
+pub fn bid128_quiet_less_equal(x: BID128, y: BID128, _flags: &mut u32) -> bool {
+  matches!(compare(&x, &y), Some(Ordering::Less | Ordering::Equal))
+}
+
+/// Truncates the number to integer, returns `None` when the number is not finite or too big.
+fn truncate(x: BID128) -> Option<i128> {
+  if !x.is_finite() {
+    return None;
+  }
+  let magnitude = if x.exponent < 0 {
+    drop_digits(x.coefficient, x.exponent.unsigned_abs(), x.negative, RM_TOWARD_ZERO).0
+  } else if x.coefficient == 0 {
+    0
+  } else {
+    x.coefficient.checked_mul(10_u128.checked_pow(x.exponent as u32)?)?
+  };
+  let magnitude = i128::try_from(magnitude).ok()?;
+  Some(if x.negative { -magnitude } else { magnitude })
+}
+
+pub fn bid128_to_int32_int(x: BID128, flags: &mut u32) -> i32 {
+  truncate(x).and_then(|n| i32::try_from(n).ok()).unwrap_or_else(|| {
+    *flags |= FB_INVALID;
+    i32::MIN
+  })
+}
+
+pub fn bid128_to_int64_int(x: BID128, flags: &mut u32) -> i64 {
+  truncate(x).and_then(|n| i64::try_from(n).ok()).unwrap_or_else(|| {
+    *flags |= FB_INVALID;
+    i64::MIN
+  })
+}
+
+pub fn bid128_to_uint32_int(x: BID128, flags: &mut u32) -> u32 {
+  truncate(x).and_then(|n| u32::try_from(n).ok()).unwrap_or_else(|| {
+    *flags |= FB_INVALID;
+    0x8000_0000
+  })
+}
+
+pub fn bid128_to_uint64_int(x: BID128, flags: &mut u32) -> u64 {
+  truncate(x).and_then(|n| u64::try_from(n).ok()).unwrap_or_else(|| {
+    *flags |= FB_INVALID;
+    0x8000_0000_0000_0000
+  })
+}
+
+pub fn bid128_from_string(s: &str, rnd: u32, flags: &mut u32) -> BID128 {
+  parse(s, rnd, flags).unwrap_or_else(BID128::nan)
+}
+
+/// Parses the text in format `[+|-]digits[.digits][(E|e)[+|-]digits]`,
+/// infinities and NaNs are recognized too.
+fn parse(s: &str, rnd: u32, flags: &mut u32) -> Option<BID128> {
+  let (negative, s) = match s.as_bytes().first() {
+    Some(b'-') => (true, &s[1..]),
+    Some(b'+') => (false, &s[1..]),
+    _ => (false, s),
+  };
+  match s.to_ascii_lowercase().as_str() {
+    "inf" | "infinity" => return Some(BID128::infinite(negative)),
+    "nan" | "qnan" | "snan" => return Some(BID128::nan()),
+    _ => {}
+  }
+  let (mantissa, exponent) = match s.find(['E', 'e']) {
+    Some(index) => (&s[..index], parse_exponent(&s[index + 1..])?),
+    None => (s, 0),
+  };
+  let (integral, fractional) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+  if integral.is_empty() && fractional.is_empty() {
+    return None;
+  }
+  if !integral.bytes().chain(fractional.bytes()).all(|b| b.is_ascii_digit()) {
+    return None;
+  }
+  let digits = format!("{integral}{fractional}");
+  let digits = digits.trim_start_matches('0');
+  let exponent = exponent - fractional.len() as i64;
+  if digits.len() <= 38 {
+    let coefficient = digits.parse::<u128>().unwrap_or(0);
+    return Some(finish_u128(negative, coefficient, exponent, rnd, flags));
+  }
+  Some(finish(negative, Natural::from_digits(digits), exponent, rnd, flags))
+}
+
+/// Parses the exponent, values out of any reasonable range are saturated.
+fn parse_exponent(s: &str) -> Option<i64> {
+  let (negative, digits) = match s.as_bytes().first() {
+    Some(b'-') => (true, &s[1..]),
+    Some(b'+') => (false, &s[1..]),
+    _ => (false, s),
+  };
+  if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+    return None;
+  }
+  let value = digits.parse::<i64>().unwrap_or(i64::MAX).min(1_000_000_000);
+  Some(if negative { -value } else { value })
+}
+
+pub fn bid128_to_string(x: BID128, _flags: &mut u32) -> String {
+  let sign = if x.negative { '-' } else { '+' };
+  match x.class {
+    Class::Finite => format!("{}{}E{}{}", sign, x.coefficient, if x.exponent < 0 { '-' } else { '+' }, x.exponent.unsigned_abs()),
+    Class::Infinite => format!("{sign}Inf"),
+    Class::NaN => format!("{sign}NaN"),
+  }
+}
+
+/// Approximate decimal number used while evaluating transcendental functions.
+#[derive(Debug, Clone)]
+struct Approx {
+  /// Flag indicating if the number is negative.
+  negative: bool,
+  /// Coefficient.
+  coefficient: Natural,
+  /// Exponent of the coefficient.
+  exponent: i64,
+}
+
+impl From<&BID128> for Approx {
+  /// Converts finite number into exact approximation.
+  fn from(x: &BID128) -> Self {
+    Self {
+      negative: x.negative,
+      coefficient: Natural::from_u128(x.coefficient),
+      exponent: x.exponent as i64,
+    }
+  }
+}
+
+impl Approx {
+  fn new(negative: bool, coefficient: Natural, exponent: i64) -> Self {
+    Self { negative, coefficient, exponent }
+  }
+
+  fn one() -> Self {
+    Self::new(false, Natural::one(), 0)
+  }
+
+  fn is_zero(&self) -> bool {
+    self.coefficient.is_zero()
+  }
+
+  /// Returns the exponent of the power of ten just above the magnitude of this number.
+  fn magnitude(&self) -> i64 {
+    self.exponent + self.coefficient.digits() as i64
+  }
+
+  /// Truncates the coefficient to specified number of digits.
+  fn trim(&self, precision: u32) -> Self {
+    let digits = self.coefficient.digits();
+    if digits <= precision {
+      return self.clone();
+    }
+    let count = digits - precision;
+    let (coefficient, _) = self.coefficient.div_rem(&Natural::pow10(count));
+    Self::new(self.negative, coefficient, self.exponent + count as i64)
+  }
+
+  fn neg(&self) -> Self {
+    Self::new(!self.negative, self.coefficient.clone(), self.exponent)
+  }
+
+  fn add(&self, rhs: &Self, precision: u32) -> Self {
+    if rhs.is_zero() {
+      return self.trim(precision);
+    }
+    if self.is_zero() {
+      return rhs.trim(precision);
+    }
+    let (x, y) = (self.trim(precision), rhs.trim(precision));
+    if x.magnitude() > y.magnitude() + precision as i64 + 2 {
+      return x;
+    }
+    if y.magnitude() > x.magnitude() + precision as i64 + 2 {
+      return y;
+    }
+    let exponent = x.exponent.min(y.exponent);
+    let cx = x.coefficient.mul(&Natural::pow10((x.exponent - exponent) as u32));
+    let cy = y.coefficient.mul(&Natural::pow10((y.exponent - exponent) as u32));
+    let result = if x.negative == y.negative {
+      Self::new(x.negative, cx.add(&cy), exponent)
+    } else if cx >= cy {
+      Self::new(x.negative, cx.sub(&cy), exponent)
+    } else {
+      Self::new(y.negative, cy.sub(&cx), exponent)
+    };
+    result.trim(precision)
+  }
+
+  fn sub(&self, rhs: &Self, precision: u32) -> Self {
+    self.add(&rhs.neg(), precision)
+  }
+
+  fn mul(&self, rhs: &Self, precision: u32) -> Self {
+    Self::new(self.negative != rhs.negative, self.coefficient.mul(&rhs.coefficient), self.exponent + rhs.exponent).trim(precision)
+  }
+
+  fn mul_small(&self, rhs: u32, precision: u32) -> Self {
+    Self::new(self.negative, self.coefficient.mul_small(rhs), self.exponent).trim(precision)
+  }
+
+  fn div(&self, rhs: &Self, precision: u32) -> Self {
+    let scale = (precision as i64 + 1 + rhs.coefficient.digits() as i64 - self.coefficient.digits() as i64).max(0) as u32;
+    let (coefficient, _) = self.coefficient.mul(&Natural::pow10(scale)).div_rem(&rhs.coefficient);
+    Self::new(self.negative != rhs.negative, coefficient, self.exponent - rhs.exponent - scale as i64).trim(precision)
+  }
+
+  fn div_small(&self, rhs: u32, precision: u32) -> Self {
+    let scale = (precision as i64 + 10 - self.coefficient.digits() as i64).max(0) as u32;
+    let (coefficient, _) = self.coefficient.mul(&Natural::pow10(scale)).div_rem_small(rhs);
+    Self::new(self.negative, coefficient, self.exponent - scale as i64).trim(precision)
+  }
+
+  /// Returns `true` when the term is too small to change the sum.
+  fn is_negligible(&self, sum: &Self, precision: u32) -> bool {
+    self.is_zero() || self.magnitude() < sum.magnitude() - precision as i64 - 2
+  }
+
+  /// Converts this number into floating-point approximation.
+  fn to_f64(&self) -> f64 {
+    let trimmed = self.trim(30);
+    let value = trimmed.coefficient.to_u128().unwrap_or(0) as f64 * 10_f64.powi(trimmed.exponent.clamp(-400, 400) as i32);
+    if self.negative {
+      -value
+    } else {
+      value
+    }
+  }
+
+  /// Returns the natural logarithm of this positive number, with specified precision.
+  fn ln(&self, precision: u32) -> Self {
+    let guard = precision + 15;
+    let value = self.to_f64();
+    if (0.5..2.0).contains(&value) {
+      return ln_near_one(self, guard);
+    }
+    // split the number into m * 2^j * 10^a, where m is in range [0.75, 1.5)
+    let a = self.magnitude() - 1;
+    let m = Approx::new(false, self.coefficient.clone(), self.exponent - a);
+    let m_value = m.to_f64();
+    let j = if m_value < 1.5 {
+      0
+    } else if m_value < 3.0 {
+      1
+    } else if m_value < 6.0 {
+      2
+    } else {
+      3
+    };
+    // division by power of two is exact in decimal arithmetic
+    let m = Approx::new(false, m.coefficient.mul(&Natural::from_u128(5_u128.pow(j))), m.exponent - j as i64);
+    let mut result = ln_near_one(&m, guard);
+    if j > 0 {
+      result = result.add(&ln_2(guard).mul_small(j, guard), guard);
+    }
+    if a != 0 {
+      let a_ln10 = ln_10(guard).mul(&Approx::new(a < 0, Natural::from_u128(a.unsigned_abs() as u128), 0), guard);
+      result = result.add(&a_ln10, guard);
+    }
+    result
+  }
+}
+
+/// Returns the natural logarithm of the number close to one, calculated as `2 * atanh((x - 1) / (x + 1))`.
+fn ln_near_one(x: &Approx, precision: u32) -> Approx {
+  let numerator = x.sub(&Approx::one(), u32::MAX);
+  if numerator.is_zero() {
+    return numerator;
+  }
+  let z = numerator.div(&x.add(&Approx::one(), precision), precision);
+  let z2 = z.mul(&z, precision);
+  let mut sum = z.clone();
+  let mut power = z;
+  let mut n = 3;
+  loop {
+    power = power.mul(&z2, precision);
+    let term = power.div_small(n, precision);
+    if term.is_negligible(&sum, precision) {
+      break;
+    }
+    sum = sum.add(&term, precision);
+    n += 2;
+  }
+  sum.mul_small(2, precision)
+}
+
+/// Returns `atanh(1/n)` with specified precision.
+fn atanh_inverse(n: u32, precision: u32) -> Approx {
+  let scale = precision + 10;
+  let n2 = n * n;
+  let mut power = Natural::pow10(scale).div_rem_small(n).0;
+  let mut sum = power.clone();
+  let mut k = 3;
+  loop {
+    power = power.div_rem_small(n2).0;
+    if power.is_zero() {
+      break;
+    }
+    sum = sum.add(&power.div_rem_small(k).0);
+    k += 2;
+  }
+  Approx::new(false, sum, -(scale as i64)).trim(precision)
+}
+
+/// Returns the natural logarithm of 2, calculated as `2 * atanh(1/3)`.
+fn ln_2(precision: u32) -> Approx {
+  atanh_inverse(3, precision + 2).mul_small(2, precision)
+}
+
+/// Returns the natural logarithm of 10, calculated as `3 * ln(2) + 2 * atanh(1/9)`.
+fn ln_10(precision: u32) -> Approx {
+  let ln_2 = ln_2(precision + 2).mul_small(3, precision + 2);
+  ln_2.add(&atanh_inverse(9, precision + 2).mul_small(2, precision + 2), precision)
+}
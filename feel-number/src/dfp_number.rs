@@ -33,9 +33,14 @@
 //! `FEEL` number type.
 //!
 //! Implementation of the `FEEL` number based on
-//! **Intel(R) Decimal Floating-Point Math Library**.
+//! **Intel(R) Decimal Floating-Point Math Library**,
+//! or on its pure-Rust replacement when `pure-rust` feature is enabled.
 
+#[cfg(feature = "pure-rust")]
+use crate::dec128::*;
 use crate::errors::*;
+use crate::status::FeelNumberStatus;
+#[cfg(not(feature = "pure-rust"))]
 use dfp_number_sys::*;
 use dmntk_common::{DmntkError, Jsonify};
 use std::cmp::Ordering;
@@ -44,7 +49,7 @@ use std::fmt::{Debug, Display};
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign};
 use std::str::FromStr;
 
-/// Flags for operation status.
+/// Flags for operation status, used when the status is not reported.
 macro_rules! flags {
   () => {
     &mut 0_u32
@@ -225,12 +230,107 @@ impl FeelNumber {
     Self(bid128_round_integral_zero(self.0, flags!()), false)
   }
 
+  /// Adds two [FeelNumbers](FeelNumber) and returns the status of the operation.
+  pub fn add_with_status(&self, rhs: &FeelNumber) -> (Self, FeelNumberStatus) {
+    let mut flags = FB_CLEAR;
+    let n = bid128_add(self.0, rhs.0, round!(), &mut flags);
+    (Self(n, true), flags.into())
+  }
+
+  /// Subtracts two [FeelNumbers](FeelNumber) and returns the status of the operation.
+  pub fn sub_with_status(&self, rhs: &FeelNumber) -> (Self, FeelNumberStatus) {
+    let mut flags = FB_CLEAR;
+    let n = bid128_sub(self.0, rhs.0, round!(), &mut flags);
+    (Self(n, true), flags.into())
+  }
+
+  /// Multiplies two [FeelNumbers](FeelNumber) and returns the status of the operation.
+  pub fn mul_with_status(&self, rhs: &FeelNumber) -> (Self, FeelNumberStatus) {
+    let mut flags = FB_CLEAR;
+    let n = bid128_mul(self.0, rhs.0, round!(), &mut flags);
+    (Self(n, true), flags.into())
+  }
+
+  /// Divides two [FeelNumbers](FeelNumber) and returns the status of the operation.
+  pub fn div_with_status(&self, rhs: &FeelNumber) -> (Self, FeelNumberStatus) {
+    let mut flags = FB_CLEAR;
+    let n = bid128_div(self.0, rhs.0, round!(), &mut flags);
+    (Self(n, true), flags.into())
+  }
+
+  /// Calculates the remainder of the division and returns the status of the operation.
+  pub fn rem_with_status(&self, rhs: &FeelNumber) -> (Self, FeelNumberStatus) {
+    let mut flags = FB_CLEAR;
+    let n = self.remainder(rhs.0, &mut flags);
+    (Self(n, true), flags.into())
+  }
+
+  /// Raises this [FeelNumber] to the power and returns the status of the operation.
+  pub fn pow_with_status(&self, rhs: &FeelNumber) -> (Self, FeelNumberStatus) {
+    let mut flags = FB_CLEAR;
+    let n = bid128_pow(self.0, rhs.0, round!(), &mut flags);
+    (Self(n, true), flags.into())
+  }
+
+  /// Calculates the exponential function and returns the status of the operation.
+  pub fn exp_with_status(&self) -> (Self, FeelNumberStatus) {
+    let mut flags = FB_CLEAR;
+    let n = bid128_exp(self.0, round!(), &mut flags);
+    (Self(n, true), flags.into())
+  }
+
+  /// Calculates the natural logarithm and returns the status of the operation.
+  pub fn ln_with_status(&self) -> (Self, FeelNumberStatus) {
+    let mut flags = FB_CLEAR;
+    let n = bid128_log(self.0, round!(), &mut flags);
+    (Self(n, true), flags.into())
+  }
+
+  /// Calculates the square root and returns the status of the operation.
+  pub fn sqrt_with_status(&self) -> (Self, FeelNumberStatus) {
+    let mut flags = FB_CLEAR;
+    let n = bid128_sqrt(self.0, round!(), &mut flags);
+    (Self(n, true), flags.into())
+  }
+
+  /// Returns the nearest integer less than or equal to this [FeelNumber] and the status of the operation.
+  pub fn floor_with_status(&self) -> (Self, FeelNumberStatus) {
+    let mut flags = FB_CLEAR;
+    let n = bid128_round_integral_negative(self.0, &mut flags);
+    (Self(n, false), flags.into())
+  }
+
+  /// Returns the nearest integer greater than or equal to this [FeelNumber] and the status of the operation.
+  pub fn ceiling_with_status(&self) -> (Self, FeelNumberStatus) {
+    let mut flags = FB_CLEAR;
+    let n = bid128_round_integral_positive(self.0, &mut flags);
+    if bid128_is_zero(n) {
+      (Self::zero(), flags.into())
+    } else {
+      (Self(n, false), flags.into())
+    }
+  }
+
+  /// Rounds this [FeelNumber] to specified scale and returns the status of the operation.
+  pub fn round_with_status(&self, rhs: &FeelNumber) -> (Self, FeelNumberStatus) {
+    let mut flags = FB_CLEAR;
+    let n = bid128_to_int32_int(bid128_negate(rhs.0), &mut flags);
+    let q = bid128_scalbn(Self::one().0, n);
+    let n = bid128_quantize(self.0, q, round!(), &mut flags);
+    (Self(n, false), flags.into())
+  }
+
+  /// Returns `true` when this [FeelNumber] is neither infinite nor NaN.
+  pub fn is_finite(&self) -> bool {
+    bid128_is_finite(self.0)
+  }
+
   /// Calculates the remainder of the division.
-  fn remainder(&self, rhs: BID128) -> BID128 {
-    let mut n = bid128_div(self.0, rhs, round!(), flags!());
-    n = bid128_round_integral_negative(n, flags!());
-    n = bid128_mul(rhs, n, round!(), flags!());
-    bid128_sub(self.0, n, round!(), flags!())
+  fn remainder(&self, rhs: BID128, flags: &mut u32) -> BID128 {
+    let mut n = bid128_div(self.0, rhs, round!(), flags);
+    n = bid128_round_integral_negative(n, flags);
+    n = bid128_mul(rhs, n, round!(), flags);
+    bid128_sub(self.0, n, round!(), flags)
   }
 }
 
@@ -314,14 +414,16 @@ impl PartialOrd<isize> for FeelNumber {
 
 impl Add<FeelNumber> for FeelNumber {
   type Output = Self;
-  ///
+  /// Adds two [FeelNumbers](FeelNumber), status flags of the operation are discarded.
+  /// Use [FeelNumber::add_with_status] when overflow or invalid operation must be detected.
   fn add(self, rhs: Self) -> Self::Output {
     Self(bid128_add(self.0, rhs.0, round!(), flags!()), true)
   }
 }
 
 impl AddAssign<FeelNumber> for FeelNumber {
-  ///
+  /// Adds two [FeelNumbers](FeelNumber), status flags of the operation are discarded.
+  /// Use [FeelNumber::add_with_status] when overflow or invalid operation must be detected.
   fn add_assign(&mut self, rhs: Self) {
     self.0 = bid128_add(self.0, rhs.0, round!(), flags!());
     self.1 = true;
@@ -330,14 +432,16 @@ impl AddAssign<FeelNumber> for FeelNumber {
 
 impl Sub<FeelNumber> for FeelNumber {
   type Output = Self;
-  ///
+  /// Subtracts two [FeelNumbers](FeelNumber), status flags of the operation are discarded.
+  /// Use [FeelNumber::sub_with_status] when overflow or invalid operation must be detected.
   fn sub(self, rhs: Self) -> Self::Output {
     Self(bid128_sub(self.0, rhs.0, round!(), flags!()), true)
   }
 }
 
 impl SubAssign<FeelNumber> for FeelNumber {
-  ///
+  /// Subtracts two [FeelNumbers](FeelNumber), status flags of the operation are discarded.
+  /// Use [FeelNumber::sub_with_status] when overflow or invalid operation must be detected.
   fn sub_assign(&mut self, rhs: Self) {
     self.0 = bid128_sub(self.0, rhs.0, round!(), flags!());
     self.1 = true;
@@ -346,14 +450,16 @@ impl SubAssign<FeelNumber> for FeelNumber {
 
 impl Mul<FeelNumber> for FeelNumber {
   type Output = Self;
-  ///
+  /// Multiplies two [FeelNumbers](FeelNumber), status flags of the operation are discarded.
+  /// Use [FeelNumber::mul_with_status] when overflow or invalid operation must be detected.
   fn mul(self, rhs: Self) -> Self::Output {
     Self(bid128_mul(self.0, rhs.0, round!(), flags!()), true)
   }
 }
 
 impl MulAssign<FeelNumber> for FeelNumber {
-  ///
+  /// Multiplies two [FeelNumbers](FeelNumber), status flags of the operation are discarded.
+  /// Use [FeelNumber::mul_with_status] when overflow or invalid operation must be detected.
   fn mul_assign(&mut self, rhs: Self) {
     self.0 = bid128_mul(self.0, rhs.0, round!(), flags!());
     self.1 = true;
@@ -362,14 +468,16 @@ impl MulAssign<FeelNumber> for FeelNumber {
 
 impl Div<FeelNumber> for FeelNumber {
   type Output = Self;
-  ///
+  /// Divides two [FeelNumbers](FeelNumber), status flags of the operation are discarded.
+  /// Use [FeelNumber::div_with_status] when overflow or invalid operation must be detected.
   fn div(self, rhs: Self) -> Self::Output {
     Self(bid128_div(self.0, rhs.0, round!(), flags!()), true)
   }
 }
 
 impl DivAssign<FeelNumber> for FeelNumber {
-  ///
+  /// Divides two [FeelNumbers](FeelNumber), status flags of the operation are discarded.
+  /// Use [FeelNumber::div_with_status] when overflow or invalid operation must be detected.
   fn div_assign(&mut self, rhs: Self) {
     self.0 = bid128_div(self.0, rhs.0, round!(), flags!());
     self.1 = true;
@@ -378,16 +486,18 @@ impl DivAssign<FeelNumber> for FeelNumber {
 
 impl Rem<FeelNumber> for FeelNumber {
   type Output = Self;
-  ///
+  /// Calculates the remainder of the division of two [FeelNumbers](FeelNumber), status flags of the operation are discarded.
+  /// Use [FeelNumber::rem_with_status] when overflow or invalid operation must be detected.
   fn rem(self, rhs: Self) -> Self::Output {
-    Self(self.remainder(rhs.0), true)
+    Self(self.remainder(rhs.0, flags!()), true)
   }
 }
 
 impl RemAssign<FeelNumber> for FeelNumber {
-  ///
+  /// Calculates the remainder of the division of two [FeelNumbers](FeelNumber), status flags of the operation are discarded.
+  /// Use [FeelNumber::rem_with_status] when overflow or invalid operation must be detected.
   fn rem_assign(&mut self, rhs: Self) {
-    self.0 = self.remainder(rhs.0);
+    self.0 = self.remainder(rhs.0, flags!());
     self.1 = true;
   }
 }
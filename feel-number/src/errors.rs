@@ -45,3 +45,18 @@ pub fn err_invalid_number_literal(s: &str) -> DmntkError {
 pub fn err_number_conversion_failed() -> DmntkError {
   FeelNumberError("number conversion failed".to_string()).into()
 }
+
+/// Creates invalid operation error.
+pub fn err_invalid_operation() -> DmntkError {
+  FeelNumberError("invalid operation".to_string()).into()
+}
+
+/// Creates division by zero error.
+pub fn err_division_by_zero() -> DmntkError {
+  FeelNumberError("division by zero".to_string()).into()
+}
+
+/// Creates arithmetic overflow error.
+pub fn err_overflow() -> DmntkError {
+  FeelNumberError("overflow".to_string()).into()
+}
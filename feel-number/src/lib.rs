@@ -35,7 +35,23 @@
 #[macro_use]
 extern crate dmntk_macros;
 
+#[cfg(not(any(feature = "bid128", feature = "pure-rust")))]
+compile_error!("either `bid128` or `pure-rust` feature must be enabled");
+
+#[cfg(any(feature = "pure-rust", test))]
+mod binary128;
+#[cfg(any(feature = "pure-rust", test))]
+mod dec128;
 mod dfp_number;
 mod errors;
+#[cfg(any(feature = "pure-rust", test))]
+mod natural;
+#[cfg(feature = "serde")]
+mod serialization;
+mod status;
+
+#[cfg(test)]
+mod tests;
 
 pub use dfp_number::FeelNumber;
+pub use status::FeelNumberStatus;
@@ -0,0 +1,357 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Unsigned integers of arbitrary size used by the pure-Rust decimal implementation.
+
+use std::cmp::Ordering;
+
+/// Number of powers of ten kept in cache.
+const CACHED_POWERS: usize = 256;
+
+/// Unsigned integer of arbitrary size.
+///
+/// Stored as little-endian 32-bit limbs without trailing zero limbs,
+/// so the zero value has no limbs at all.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Natural(Vec<u32>);
+
+impl Natural {
+  /// Returns zero.
+  pub fn zero() -> Self {
+    Self(vec![])
+  }
+
+  /// Returns one.
+  pub fn one() -> Self {
+    Self(vec![1])
+  }
+
+  /// Creates a [Natural] from [u128].
+  pub fn from_u128(mut n: u128) -> Self {
+    let mut limbs = vec![];
+    while n > 0 {
+      limbs.push(n as u32);
+      n >>= 32;
+    }
+    Self(limbs)
+  }
+
+  /// Converts this [Natural] to [u128], returns `None` when the value is too big.
+  pub fn to_u128(&self) -> Option<u128> {
+    if self.0.len() > 4 {
+      return None;
+    }
+    Some(self.0.iter().rev().fold(0_u128, |acc, limb| (acc << 32) | *limb as u128))
+  }
+
+  /// Parses a string of decimal digits.
+  pub fn from_digits(digits: &str) -> Self {
+    let mut n = Self::zero();
+    for chunk in digits.as_bytes().chunks(9) {
+      let value = chunk.iter().fold(0_u32, |acc, digit| acc * 10 + (digit - b'0') as u32);
+      n = n.mul_small(10_u32.pow(chunk.len() as u32)).add(&Self::from_u128(value as u128));
+    }
+    n
+  }
+
+  /// Returns `true` when this [Natural] is zero.
+  pub fn is_zero(&self) -> bool {
+    self.0.is_empty()
+  }
+
+  /// Returns `true` when this [Natural] is odd.
+  pub fn is_odd(&self) -> bool {
+    self.0.first().is_some_and(|limb| limb & 1 == 1)
+  }
+
+  /// Returns the number of significant bits.
+  pub fn bits(&self) -> u64 {
+    match self.0.last() {
+      Some(last) => self.0.len() as u64 * 32 - last.leading_zeros() as u64,
+      None => 0,
+    }
+  }
+
+  /// Returns the number of decimal digits, zero has no digits.
+  pub fn digits(&self) -> u32 {
+    if self.is_zero() {
+      return 0;
+    }
+    // the estimate based on the decimal logarithm of 2 is exact or one too small
+    let estimate = ((self.bits() - 1) as f64 * std::f64::consts::LOG10_2) as u32 + 1;
+    if *self >= Self::pow10(estimate) {
+      estimate + 1
+    } else {
+      estimate
+    }
+  }
+
+  /// Returns ten raised to the specified power.
+  pub fn pow10(n: u32) -> Self {
+    static POWERS: once_cell::sync::Lazy<Vec<Natural>> = once_cell::sync::Lazy::new(|| {
+      let mut powers = vec![Natural::one()];
+      for i in 1..CACHED_POWERS {
+        powers.push(powers[i - 1].mul_small(10));
+      }
+      powers
+    });
+    let n = n as usize;
+    if n < CACHED_POWERS {
+      return POWERS[n].clone();
+    }
+    let mut result = POWERS[n % (CACHED_POWERS - 1)].clone();
+    for _ in 0..n / (CACHED_POWERS - 1) {
+      result = result.mul(&POWERS[CACHED_POWERS - 1]);
+    }
+    result
+  }
+
+  /// Returns the sum of two [Natural] numbers.
+  pub fn add(&self, rhs: &Self) -> Self {
+    let (long, short) = if self.0.len() >= rhs.0.len() { (self, rhs) } else { (rhs, self) };
+    let mut limbs = Vec::with_capacity(long.0.len() + 1);
+    let mut carry = 0_u64;
+    for (i, limb) in long.0.iter().enumerate() {
+      let sum = *limb as u64 + short.0.get(i).copied().unwrap_or(0) as u64 + carry;
+      limbs.push(sum as u32);
+      carry = sum >> 32;
+    }
+    if carry > 0 {
+      limbs.push(carry as u32);
+    }
+    Self(limbs)
+  }
+
+  /// Returns the difference of two [Natural] numbers, `rhs` must not be greater than `self`.
+  pub fn sub(&self, rhs: &Self) -> Self {
+    debug_assert!(*self >= *rhs);
+    let mut limbs = Vec::with_capacity(self.0.len());
+    let mut borrow = 0_i64;
+    for (i, limb) in self.0.iter().enumerate() {
+      let mut diff = *limb as i64 - rhs.0.get(i).copied().unwrap_or(0) as i64 - borrow;
+      borrow = 0;
+      if diff < 0 {
+        diff += 1 << 32;
+        borrow = 1;
+      }
+      limbs.push(diff as u32);
+    }
+    Self(limbs).normalized()
+  }
+
+  /// Returns the product of two [Natural] numbers.
+  pub fn mul(&self, rhs: &Self) -> Self {
+    if self.is_zero() || rhs.is_zero() {
+      return Self::zero();
+    }
+    let mut limbs = vec![0_u32; self.0.len() + rhs.0.len()];
+    for (i, a) in self.0.iter().enumerate() {
+      let mut carry = 0_u64;
+      for (j, b) in rhs.0.iter().enumerate() {
+        let product = *a as u64 * *b as u64 + limbs[i + j] as u64 + carry;
+        limbs[i + j] = product as u32;
+        carry = product >> 32;
+      }
+      limbs[i + rhs.0.len()] = carry as u32;
+    }
+    Self(limbs).normalized()
+  }
+
+  /// Returns the product of this [Natural] and a small number.
+  pub fn mul_small(&self, rhs: u32) -> Self {
+    let mut limbs = Vec::with_capacity(self.0.len() + 1);
+    let mut carry = 0_u64;
+    for limb in &self.0 {
+      let product = *limb as u64 * rhs as u64 + carry;
+      limbs.push(product as u32);
+      carry = product >> 32;
+    }
+    if carry > 0 {
+      limbs.push(carry as u32);
+    }
+    Self(limbs).normalized()
+  }
+
+  /// Returns the quotient and the remainder of the division by a small number.
+  pub fn div_rem_small(&self, rhs: u32) -> (Self, u32) {
+    debug_assert!(rhs > 0);
+    let mut limbs = vec![0_u32; self.0.len()];
+    let mut remainder = 0_u64;
+    for (i, limb) in self.0.iter().enumerate().rev() {
+      let dividend = (remainder << 32) | *limb as u64;
+      limbs[i] = (dividend / rhs as u64) as u32;
+      remainder = dividend % rhs as u64;
+    }
+    (Self(limbs).normalized(), remainder as u32)
+  }
+
+  /// Returns the quotient and the remainder of the division (Knuth, algorithm D).
+  pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+    debug_assert!(!rhs.is_zero());
+    if self < rhs {
+      return (Self::zero(), self.clone());
+    }
+    if rhs.0.len() == 1 {
+      let (quotient, remainder) = self.div_rem_small(rhs.0[0]);
+      return (quotient, Self::from_u128(remainder as u128));
+    }
+    let shift = rhs.0.last().unwrap().leading_zeros();
+    // the divisor is normalized, so its most significant bit is set, the carry limb is always zero
+    let mut v = shift_left(&rhs.0, shift);
+    v.pop();
+    let mut u = shift_left(&self.0, shift);
+    let n = v.len();
+    let m = u.len() - n - 1;
+    let mut quotient = vec![0_u32; m + 1];
+    for j in (0..=m).rev() {
+      let numerator = ((u[j + n] as u64) << 32) | u[j + n - 1] as u64;
+      let mut q = numerator / v[n - 1] as u64;
+      let mut r = numerator % v[n - 1] as u64;
+      while q >= 1 << 32 || q * v[n - 2] as u64 > ((r << 32) | u[j + n - 2] as u64) {
+        q -= 1;
+        r += v[n - 1] as u64;
+        if r >= 1 << 32 {
+          break;
+        }
+      }
+      let mut borrow = 0_i64;
+      let mut carry = 0_u64;
+      for i in 0..n {
+        let product = q * v[i] as u64 + carry;
+        carry = product >> 32;
+        let diff = u[i + j] as i64 - borrow - (product & 0xffff_ffff) as i64;
+        u[i + j] = diff as u32;
+        borrow = if diff < 0 { 1 } else { 0 };
+      }
+      let diff = u[j + n] as i64 - borrow - carry as i64;
+      u[j + n] = diff as u32;
+      if diff < 0 {
+        q -= 1;
+        let mut carry = 0_u64;
+        for i in 0..n {
+          let sum = u[i + j] as u64 + v[i] as u64 + carry;
+          u[i + j] = sum as u32;
+          carry = sum >> 32;
+        }
+        u[j + n] = u[j + n].wrapping_add(carry as u32);
+      }
+      quotient[j] = q as u32;
+    }
+    u.truncate(n);
+    (Self(quotient).normalized(), Self(shift_right(&u, shift)).normalized())
+  }
+
+  /// Returns the integer square root.
+  pub fn sqrt(&self) -> Self {
+    if self.is_zero() {
+      return Self::zero();
+    }
+    let mut x = Self::one().shl((self.bits() + 1) / 2);
+    loop {
+      let y = x.add(&self.div_rem(&x).0).shr1();
+      if y >= x {
+        return x;
+      }
+      x = y;
+    }
+  }
+
+  /// Shifts this [Natural] left by the specified number of bits.
+  pub fn shl(&self, bits: u64) -> Self {
+    let mut limbs = vec![0_u32; (bits / 32) as usize];
+    limbs.extend(shift_left(&self.0, (bits % 32) as u32));
+    Self(limbs).normalized()
+  }
+
+  /// Shifts this [Natural] right by the specified number of bits, dropping the shifted out bits.
+  pub fn shr(&self, bits: u64) -> Self {
+    let skipped = (bits / 32) as usize;
+    if skipped >= self.0.len() {
+      return Self::zero();
+    }
+    Self(shift_right(&self.0[skipped..], (bits % 32) as u32)).normalized()
+  }
+
+  /// Shifts this [Natural] right by one bit.
+  fn shr1(&self) -> Self {
+    Self(shift_right(&self.0, 1)).normalized()
+  }
+
+  /// Removes trailing zero limbs.
+  fn normalized(mut self) -> Self {
+    while self.0.last() == Some(&0) {
+      self.0.pop();
+    }
+    self
+  }
+}
+
+impl PartialOrd for Natural {
+  fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+    Some(self.cmp(rhs))
+  }
+}
+
+impl Ord for Natural {
+  fn cmp(&self, rhs: &Self) -> Ordering {
+    self.0.len().cmp(&rhs.0.len()).then_with(|| self.0.iter().rev().cmp(rhs.0.iter().rev()))
+  }
+}
+
+/// Shifts limbs left by less than 32 bits, the result has one limb more.
+fn shift_left(limbs: &[u32], shift: u32) -> Vec<u32> {
+  let mut result = Vec::with_capacity(limbs.len() + 1);
+  let mut carry = 0_u32;
+  for limb in limbs {
+    if shift == 0 {
+      result.push(*limb);
+    } else {
+      result.push((limb << shift) | carry);
+      carry = limb >> (32 - shift);
+    }
+  }
+  result.push(carry);
+  result
+}
+
+/// Shifts limbs right by less than 32 bits.
+fn shift_right(limbs: &[u32], shift: u32) -> Vec<u32> {
+  if shift == 0 {
+    return limbs.to_vec();
+  }
+  let mut result = vec![0_u32; limbs.len()];
+  for i in 0..limbs.len() {
+    let high = limbs.get(i + 1).map_or(0, |limb| limb << (32 - shift));
+    result[i] = (limbs[i] >> shift) | high;
+  }
+  result
+}
@@ -0,0 +1,83 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Implementation of `serde` serialization for [FeelNumber].
+//!
+//! [FeelNumber] is serialized as a string containing its decimal representation,
+//! so no precision is lost when numbers travel through formats like JSON.
+//! While deserializing, both strings and native JSON numbers are accepted.
+
+use crate::FeelNumber;
+use serde::de::{Error, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+impl Serialize for FeelNumber {
+  /// Serializes [FeelNumber] as a decimal string.
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(self)
+  }
+}
+
+impl<'de> Deserialize<'de> for FeelNumber {
+  /// Deserializes [FeelNumber] from a decimal string or a native number.
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    deserializer.deserialize_any(FeelNumberVisitor)
+  }
+}
+
+struct FeelNumberVisitor;
+
+impl<'de> Visitor<'de> for FeelNumberVisitor {
+  type Value = FeelNumber;
+
+  fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    write!(formatter, "a decimal number or a string containing a decimal number")
+  }
+
+  fn visit_i64<E: Error>(self, value: i64) -> Result<Self::Value, E> {
+    Ok(FeelNumber::from(value))
+  }
+
+  fn visit_u64<E: Error>(self, value: u64) -> Result<Self::Value, E> {
+    Ok(FeelNumber::from(value))
+  }
+
+  fn visit_f64<E: Error>(self, value: f64) -> Result<Self::Value, E> {
+    FeelNumber::from_str(&value.to_string()).map_err(E::custom)
+  }
+
+  fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+    FeelNumber::from_str(value).map_err(E::custom)
+  }
+}
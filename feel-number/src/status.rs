@@ -0,0 +1,130 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Status of decimal arithmetic operations.
+
+#[cfg(feature = "pure-rust")]
+use crate::dec128::{FB_CLEAR, FB_INEXACT, FB_INVALID, FB_OVERFLOW, FB_UNDERFLOW, FB_ZERO_DIVIDE};
+use crate::errors::*;
+#[cfg(not(feature = "pure-rust"))]
+use dfp_number_sys::{FB_CLEAR, FB_INEXACT, FB_INVALID, FB_OVERFLOW, FB_UNDERFLOW, FB_ZERO_DIVIDE};
+use dmntk_common::Result;
+use std::fmt;
+use std::fmt::Display;
+use std::ops::{BitOr, BitOrAssign};
+
+/// Status flags raised by an operation on [FeelNumber](crate::FeelNumber).
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct FeelNumberStatus(u32);
+
+impl FeelNumberStatus {
+  /// Returns `true` when no flags were raised.
+  pub fn is_clear(&self) -> bool {
+    self.0 == FB_CLEAR
+  }
+
+  /// Returns `true` when the operation was invalid, like square root of a negative number.
+  pub fn is_invalid(&self) -> bool {
+    self.0 & FB_INVALID != 0
+  }
+
+  /// Returns `true` when a finite number was divided by zero.
+  pub fn is_division_by_zero(&self) -> bool {
+    self.0 & FB_ZERO_DIVIDE != 0
+  }
+
+  /// Returns `true` when the result was too large to be represented.
+  pub fn is_overflow(&self) -> bool {
+    self.0 & FB_OVERFLOW != 0
+  }
+
+  /// Returns `true` when the result was too small to be represented without loss of precision.
+  pub fn is_underflow(&self) -> bool {
+    self.0 & FB_UNDERFLOW != 0
+  }
+
+  /// Returns `true` when the result was rounded.
+  pub fn is_inexact(&self) -> bool {
+    self.0 & FB_INEXACT != 0
+  }
+
+  /// Returns an error when the result of the operation is not a valid number.
+  /// Underflow and inexact results are not reported as errors, the rounded result is valid.
+  pub fn check(&self) -> Result<()> {
+    if self.is_invalid() {
+      Err(err_invalid_operation())
+    } else if self.is_division_by_zero() {
+      Err(err_division_by_zero())
+    } else if self.is_overflow() {
+      Err(err_overflow())
+    } else {
+      Ok(())
+    }
+  }
+}
+
+impl From<u32> for FeelNumberStatus {
+  /// Converts flags set by decimal arithmetic functions into [FeelNumberStatus].
+  fn from(flags: u32) -> Self {
+    Self(flags)
+  }
+}
+
+impl BitOr for FeelNumberStatus {
+  type Output = Self;
+  /// Combines flags raised by two operations.
+  fn bitor(self, rhs: Self) -> Self::Output {
+    Self(self.0 | rhs.0)
+  }
+}
+
+impl BitOrAssign for FeelNumberStatus {
+  /// Combines flags raised by two operations.
+  fn bitor_assign(&mut self, rhs: Self) {
+    self.0 |= rhs.0
+  }
+}
+
+impl Display for FeelNumberStatus {
+  /// Converts [FeelNumberStatus] into comma separated list of raised flags.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let names = [
+      (self.is_invalid(), "invalid"),
+      (self.is_division_by_zero(), "division by zero"),
+      (self.is_overflow(), "overflow"),
+      (self.is_underflow(), "underflow"),
+      (self.is_inexact(), "inexact"),
+    ];
+    let raised = names.iter().filter_map(|(raised, name)| raised.then_some(*name)).collect::<Vec<&str>>();
+    write!(f, "{}", raised.join(", "))
+  }
+}
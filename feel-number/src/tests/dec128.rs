@@ -0,0 +1,284 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Expected values were calculated using decimal128 arithmetic with 34 digits of precision,
+//! rounding half to even, and exponent clamping.
+
+use crate::dec128::*;
+
+macro_rules! flags {
+  () => {
+    &mut 0_u32
+  };
+}
+
+fn num(s: &str) -> BID128 {
+  bid128_from_string(s, RM_NEAREST_EVEN, flags!())
+}
+
+fn eq(expected: &str, actual: BID128) {
+  assert_eq!(expected, bid128_to_string(actual, flags!()));
+}
+
+#[test]
+fn _0001() {
+  eq("+0E+0", num("0"));
+  eq("-0E-2", num("-0.00"));
+  eq("+150E-2", num("1.50"));
+  eq("+1234567890123456789012345678901234E+1", num("12345678901234567890123456789012345"));
+  eq("+1234567890123456789012345678901236E+1", num("12345678901234567890123456789012355"));
+  eq("+Inf", num("1E+6200"));
+  eq("+0E-6176", num("1E-6200"));
+  eq("+12E-6176", num("123E-6177"));
+  eq("+10E+6111", num("1E+6112"));
+}
+
+#[test]
+fn _0002() {
+  eq("+3E+0", bid128_add(num("1"), num("2"), RM_NEAREST_EVEN, flags!()));
+  eq("+375E-2", bid128_add(num("1.5"), num("2.25"), RM_NEAREST_EVEN, flags!()));
+  eq(
+    "+1000000000000000000000000000000000E+1",
+    bid128_add(num("9999999999999999999999999999999999"), num("1"), RM_NEAREST_EVEN, flags!()),
+  );
+  eq("+1000000000000000000000000000000000E+7", bid128_add(num("1E+40"), num("1"), RM_NEAREST_EVEN, flags!()));
+  eq("+1000000000000000000000000000000000E+7", bid128_add(num("1E+40"), num("-1"), RM_NEAREST_EVEN, flags!()));
+  eq("+0E+0", bid128_add(num("-1"), num("1"), RM_NEAREST_EVEN, flags!()));
+  eq("+100000E-5", bid128_add(num("0E-5"), num("1"), RM_NEAREST_EVEN, flags!()));
+  eq(
+    "+1000000000000000000000000000000000E-23",
+    bid128_add(num("1E+10"), num("0E-6000"), RM_NEAREST_EVEN, flags!()),
+  );
+  eq("+0E-3", bid128_add(num("123.456"), num("-123.456"), RM_NEAREST_EVEN, flags!()));
+  eq("+1000000000000000000000000000000000E-33", bid128_add(num("5E-34"), num("1"), RM_NEAREST_EVEN, flags!()));
+  eq("+1000000000000000000000000000000000E-33", bid128_add(num("0.5E-33"), num("1"), RM_NEAREST_EVEN, flags!()));
+}
+
+#[test]
+fn _0003() {
+  eq(
+    "+1000000000000000000000000000000000E-33",
+    bid128_sub(num("1"), num("0.000000000000000000000000000000000001"), RM_NEAREST_EVEN, flags!()),
+  );
+  eq("+1E-2", bid128_sub(num("10"), num("9.99"), RM_NEAREST_EVEN, flags!()));
+}
+
+#[test]
+fn _0004() {
+  eq("+100E-1", bid128_mul(num("2.5"), num("4"), RM_NEAREST_EVEN, flags!()));
+  eq(
+    "+1219326311370217952237463801111264E+4",
+    bid128_mul(num("1234567890123456789"), num("9876543210987654321"), RM_NEAREST_EVEN, flags!()),
+  );
+  eq("+Inf", bid128_mul(num("1E+6000"), num("1E+6000"), RM_NEAREST_EVEN, flags!()));
+  eq("+0E-6176", bid128_mul(num("1E-6000"), num("1E-6000"), RM_NEAREST_EVEN, flags!()));
+  eq("-15E-1", bid128_mul(num("-3"), num("0.5"), RM_NEAREST_EVEN, flags!()));
+}
+
+#[test]
+fn _0005() {
+  eq("+3333333333333333333333333333333333E-34", bid128_div(num("1"), num("3"), RM_NEAREST_EVEN, flags!()));
+  eq("+6666666666666666666666666666666667E-34", bid128_div(num("2"), num("3"), RM_NEAREST_EVEN, flags!()));
+  eq("+25E-1", bid128_div(num("10"), num("4"), RM_NEAREST_EVEN, flags!()));
+  eq("+125E-3", bid128_div(num("1"), num("8"), RM_NEAREST_EVEN, flags!()));
+  eq("+10E+0", bid128_div(num("100"), num("10"), RM_NEAREST_EVEN, flags!()));
+  eq("+30E-1", bid128_div(num("6.0"), num("2"), RM_NEAREST_EVEN, flags!()));
+  eq("+3333333333333333333333333333333333E-19", bid128_div(num("1E+10"), num("3E-5"), RM_NEAREST_EVEN, flags!()));
+  eq(
+    "+1763668414462081127160493827001764E+3",
+    bid128_div(num("12345678901234567890123456789012345678"), num("7"), RM_NEAREST_EVEN, flags!()),
+  );
+  eq("-1428571428571428571428571428571429E-34", bid128_div(num("-1"), num("7"), RM_NEAREST_EVEN, flags!()));
+  eq("+0E+0", bid128_div(num("0"), num("5"), RM_NEAREST_EVEN, flags!()));
+}
+
+#[test]
+fn _0006() {
+  eq("-1E+0", bid128_rem(num("7"), num("2"), flags!()));
+  eq("+0E+0", bid128_rem(num("8"), num("2"), flags!()));
+  eq("-5E-1", bid128_rem(num("5.5"), num("2"), flags!()));
+  eq("+0E+0", bid128_rem(num("1E+10"), num("2"), flags!()));
+  eq("+1E+0", bid128_rem(num("7"), num("-3"), flags!()));
+}
+
+#[test]
+fn _0007() {
+  eq("+2E+0", bid128_sqrt(num("4"), RM_NEAREST_EVEN, flags!()));
+  eq("+1414213562373095048801688724209698E-33", bid128_sqrt(num("2"), RM_NEAREST_EVEN, flags!()));
+  eq("+5E-1", bid128_sqrt(num("0.25"), RM_NEAREST_EVEN, flags!()));
+  eq("+10E+0", bid128_sqrt(num("100"), RM_NEAREST_EVEN, flags!()));
+  eq("+3162277660168379331998893544432719E-37", bid128_sqrt(num("1E-7"), RM_NEAREST_EVEN, flags!()));
+  eq(
+    "+3513641828820144253093654172555782E-24",
+    bid128_sqrt(num("12345678901234567890"), RM_NEAREST_EVEN, flags!()),
+  );
+  eq("+0E+0", bid128_sqrt(num("0"), RM_NEAREST_EVEN, flags!()));
+}
+
+#[test]
+fn _0008() {
+  eq("+123E-2", bid128_quantize(num("1.23456"), num("0.01"), RM_NEAREST_EVEN, flags!()));
+  eq("+2E+0", bid128_quantize(num("2.5"), num("1"), RM_NEAREST_EVEN, flags!()));
+  eq("+4E+0", bid128_quantize(num("3.5"), num("1"), RM_NEAREST_EVEN, flags!()));
+  eq("+123000E-3", bid128_quantize(num("123"), num("0.001"), RM_NEAREST_EVEN, flags!()));
+}
+
+#[test]
+fn _0009() {
+  eq("+2718281828459045235360287471352662E-33", bid128_exp(num("1"), RM_NEAREST_EVEN, flags!()));
+  eq("+1484131591025766034211155800405523E-31", bid128_exp(num("5"), RM_NEAREST_EVEN, flags!()));
+  eq("+3678794411714423215955237701614609E-34", bid128_exp(num("-1"), RM_NEAREST_EVEN, flags!()));
+  eq("+1648721270700128146848650787814164E-33", bid128_exp(num("0.5"), RM_NEAREST_EVEN, flags!()));
+  eq("+2688117141816135448412625551580013E+10", bid128_exp(num("100"), RM_NEAREST_EVEN, flags!()));
+  eq("+3720075976020835962959695803863118E-77", bid128_exp(num("-100"), RM_NEAREST_EVEN, flags!()));
+  eq("+1000000000000000000010000000000000E-33", bid128_exp(num("1E-20"), RM_NEAREST_EVEN, flags!()));
+  eq("+1326620321137711275937776890335631E+6047", bid128_exp(num("14000"), RM_NEAREST_EVEN, flags!()));
+  eq("+4299910989167493863094252222392987E+3039", bid128_exp(num("7075"), RM_NEAREST_EVEN, flags!()));
+}
+
+#[test]
+fn _0010() {
+  eq("+6931471805599453094172321214581766E-34", bid128_log(num("2"), RM_NEAREST_EVEN, flags!()));
+  eq("+1386294361119890618834464242916353E-33", bid128_log(num("4"), RM_NEAREST_EVEN, flags!()));
+  eq("+2302585092994045684017991454684364E-33", bid128_log(num("10"), RM_NEAREST_EVEN, flags!()));
+  eq("-6931471805599453094172321214581766E-34", bid128_log(num("0.5"), RM_NEAREST_EVEN, flags!()));
+  eq("+9999999999500000000033333333330834E-44", bid128_log(num("1.0000000001"), RM_NEAREST_EVEN, flags!()));
+  eq("+1381551055796427410410794872810618E-29", bid128_log(num("1E+6000"), RM_NEAREST_EVEN, flags!()));
+  eq("-1381551055796427410410794872810618E-29", bid128_log(num("1E-6000"), RM_NEAREST_EVEN, flags!()));
+  eq("-1053605156578263012275009808393128E-34", bid128_log(num("0.9"), RM_NEAREST_EVEN, flags!()));
+  eq("+1172364648718588098113995898391011E-32", bid128_log(num("123456.789"), RM_NEAREST_EVEN, flags!()));
+}
+
+#[test]
+fn _0011() {
+  eq("+1024E+0", bid128_pow(num("2"), num("10"), RM_NEAREST_EVEN, flags!()));
+  eq("+1414213562373095048801688724209698E-33", bid128_pow(num("2"), num("0.5"), RM_NEAREST_EVEN, flags!()));
+  eq("+1E-2", bid128_pow(num("10"), num("-2"), RM_NEAREST_EVEN, flags!()));
+  eq("+1048808848170151546991453513679938E-33", bid128_pow(num("1.1"), num("0.5"), RM_NEAREST_EVEN, flags!()));
+  eq("+64E-3", bid128_pow(num("2.5"), num("-3"), RM_NEAREST_EVEN, flags!()));
+  eq("+2000000000000000000000000000000000E-33", bid128_pow(num("4"), num("0.5"), RM_NEAREST_EVEN, flags!()));
+  eq("+1852025917745213413351131027547482E-32", bid128_pow(num("7"), num("1.5"), RM_NEAREST_EVEN, flags!()));
+  eq("+1792728671193156477399422023278662E-3", bid128_pow(num("2"), num("100.5"), RM_NEAREST_EVEN, flags!()));
+}
+
+#[test]
+fn _0012() {
+  // integer powers are calculated by repeated squaring, like in Intel's library
+  eq("+3733918487410200435329597541848665E+455", bid128_pow(num("3"), num("1024"), RM_NEAREST_EVEN, flags!()));
+  eq("+81E+0", bid128_pow(num("-3"), num("4"), RM_NEAREST_EVEN, flags!()));
+  eq("-27E+0", bid128_pow(num("-3"), num("3"), RM_NEAREST_EVEN, flags!()));
+  eq("+NaN", bid128_pow(num("-3"), num("0.5"), RM_NEAREST_EVEN, flags!()));
+  eq("+1E+0", bid128_pow(num("NaN"), num("0"), RM_NEAREST_EVEN, flags!()));
+  eq("+1E+0", bid128_pow(num("1"), num("NaN"), RM_NEAREST_EVEN, flags!()));
+  eq("+Inf", bid128_pow(num("0"), num("-1"), RM_NEAREST_EVEN, flags!()));
+  eq("+Inf", bid128_pow(num("4"), num("1E+40"), RM_NEAREST_EVEN, flags!()));
+  eq("+0E+0", bid128_pow(num("0.5"), num("Inf"), RM_NEAREST_EVEN, flags!()));
+  eq("+1E+0", bid128_pow(num("-1"), num("-Inf"), RM_NEAREST_EVEN, flags!()));
+  eq("+Inf", bid128_pow(num("-2"), num("Inf"), RM_NEAREST_EVEN, flags!()));
+  eq("+Inf", bid128_pow(num("-Inf"), num("0.5"), RM_NEAREST_EVEN, flags!()));
+  eq("-0E+0", bid128_pow(num("-Inf"), num("-3"), RM_NEAREST_EVEN, flags!()));
+  eq("+Inf", bid128_pow(num("1.000000000000000000000000000000001"), num("1E+6000"), RM_NEAREST_EVEN, flags!()));
+}
+
+#[test]
+fn _0013() {
+  assert!(bid128_quiet_equal(num("1.0"), num("1"), flags!()));
+  assert!(bid128_quiet_equal(num("0"), num("-0E+10"), flags!()));
+  assert!(!bid128_quiet_equal(num("NaN"), num("NaN"), flags!()));
+  assert!(bid128_quiet_less(num("-Inf"), num("-1E+6000"), flags!()));
+  assert!(bid128_quiet_less(num("-2"), num("-1.5"), flags!()));
+  assert!(bid128_quiet_less(num("0.999999"), num("1"), flags!()));
+  assert!(bid128_quiet_greater(num("1E+1"), num("9.99"), flags!()));
+  assert!(bid128_quiet_greater_equal(num("10.00"), num("1E+1"), flags!()));
+  assert!(bid128_quiet_less_equal(num("-0"), num("0"), flags!()));
+  assert!(!bid128_quiet_less(num("NaN"), num("1"), flags!()));
+}
+
+#[test]
+fn _0014() {
+  eq("+1E+0", bid128_round_integral_negative(num("1.5"), flags!()));
+  eq("-2E+0", bid128_round_integral_negative(num("-1.5"), flags!()));
+  eq("+2E+0", bid128_round_integral_positive(num("1.01"), flags!()));
+  eq("-0E+0", bid128_round_integral_positive(num("-0.5"), flags!()));
+  eq("-1E+0", bid128_round_integral_zero(num("-1.99"), flags!()));
+  eq("+120E+1", bid128_round_integral_zero(num("120E+1"), flags!()));
+  eq("+125E-2", bid128_scalbn(num("125"), -2));
+  eq("-7E+3", bid128_scalbn(num("-7"), 3));
+}
+
+#[test]
+fn _0015() {
+  let mut flags = FB_CLEAR;
+  assert_eq!(-12, bid128_to_int32_int(num("-12.99"), &mut flags));
+  assert_eq!(4_000_000_000, bid128_to_uint32_int(num("4E+9"), &mut flags));
+  assert_eq!(i64::MAX, bid128_to_int64_int(num("9223372036854775807"), &mut flags));
+  assert_eq!(FB_CLEAR, flags);
+  bid128_to_int32_int(num("3E+9"), &mut flags);
+  assert_eq!(FB_INVALID, flags);
+  let mut flags = FB_CLEAR;
+  bid128_to_uint64_int(num("-1"), &mut flags);
+  assert_eq!(FB_INVALID, flags);
+}
+
+#[test]
+fn _0016() {
+  let mut flags = FB_CLEAR;
+  bid128_add(num("1"), num("2"), RM_NEAREST_EVEN, &mut flags);
+  assert_eq!(FB_CLEAR, flags);
+  bid128_div(num("1"), num("3"), RM_NEAREST_EVEN, &mut flags);
+  assert_eq!(FB_INEXACT, flags);
+  let mut flags = FB_CLEAR;
+  bid128_mul(num("1E+6000"), num("1E+6000"), RM_NEAREST_EVEN, &mut flags);
+  assert_eq!(FB_OVERFLOW | FB_INEXACT, flags);
+  let mut flags = FB_CLEAR;
+  bid128_mul(num("1E-6000"), num("1E-6000"), RM_NEAREST_EVEN, &mut flags);
+  assert_eq!(FB_UNDERFLOW | FB_INEXACT, flags);
+  let mut flags = FB_CLEAR;
+  eq("-Inf", bid128_div(num("-1"), num("0"), RM_NEAREST_EVEN, &mut flags));
+  assert_eq!(FB_ZERO_DIVIDE, flags);
+  let mut flags = FB_CLEAR;
+  eq("+NaN", bid128_sqrt(num("-1"), RM_NEAREST_EVEN, &mut flags));
+  assert_eq!(FB_INVALID, flags);
+}
+
+#[test]
+fn _0017() {
+  eq(
+    "+9999999999999999999999999999999999E+6111",
+    bid128_mul(num("1E+6000"), num("1E+6000"), RM_TOWARD_ZERO, flags!()),
+  );
+  eq("+1E-6176", bid128_mul(num("1E-6000"), num("1E-6000"), RM_UPWARD, flags!()));
+  eq("+3333333333333333333333333333333334E-34", bid128_div(num("1"), num("3"), RM_UPWARD, flags!()));
+  eq("-3333333333333333333333333333333334E-34", bid128_div(num("-1"), num("3"), RM_DOWNWARD, flags!()));
+  eq("+3E+0", bid128_quantize(num("2.5"), num("1"), RM_NEAREST_AWAY, flags!()));
+  eq("-0E+0", bid128_sub(num("1"), num("1"), RM_DOWNWARD, flags!()));
+}
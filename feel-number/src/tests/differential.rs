@@ -0,0 +1,224 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Differential tests comparing results of the pure-Rust backend with results of Intel's library.
+
+use crate::dec128 as pure;
+use dfp_number_sys as intel;
+
+macro_rules! flags {
+  () => {
+    &mut 0_u32
+  };
+}
+
+const OPERANDS: &[&str] = &[
+  "0",
+  "-0",
+  "1",
+  "-1",
+  "2",
+  "0.5",
+  "-0.25",
+  "3.14159265358979323846264338327950288",
+  "2.718281828459045235360287471352662",
+  "10",
+  "123456789012345678901234567890",
+  "-98765.4321",
+  "0.000000000000000000000000000001",
+  "1E+100",
+  "-7E-100",
+  "9999999999999999999999999999999999",
+  "1.000000000000000000000000000000001",
+  "0.999999999999999999999999999999999",
+  "1E+6111",
+  "1E-6176",
+  "Inf",
+  "-Inf",
+  "NaN",
+];
+
+/// Compares string representations of numbers calculated by both backends.
+fn same(operation: &str, s: &[&str], expected: intel::BID128, actual: pure::BID128) {
+  let expected = intel::bid128_to_string(expected, flags!());
+  let actual = pure::bid128_to_string(actual, flags!());
+  assert_eq!(expected, actual, "{operation} {s:?}");
+}
+
+/// Compares values of numbers calculated by both backends, ignoring the exponent of the representation.
+fn equal(operation: &str, s: &[&str], expected: intel::BID128, actual: pure::BID128) {
+  let text = pure::bid128_to_string(actual, flags!());
+  let actual = intel::bid128_from_string(&text, intel::RM_NEAREST_EVEN, flags!());
+  let is_nan = |x| intel::bid128_to_string(x, flags!()).ends_with("NaN");
+  if is_nan(expected) && is_nan(actual) {
+    return;
+  }
+  assert!(
+    intel::bid128_quiet_equal(expected, actual, flags!()),
+    "{operation} {s:?}: expected {}, actual {text}",
+    intel::bid128_to_string(expected, flags!())
+  );
+}
+
+fn intel_num(s: &str) -> intel::BID128 {
+  intel::bid128_from_string(s, intel::RM_NEAREST_EVEN, flags!())
+}
+
+fn pure_num(s: &str) -> pure::BID128 {
+  pure::bid128_from_string(s, pure::RM_NEAREST_EVEN, flags!())
+}
+
+#[test]
+fn _0001() {
+  for s in OPERANDS {
+    let (a, b) = (intel_num(s), pure_num(s));
+    same("from_string", &[s], a, b);
+    same("abs", &[s], intel::bid128_abs(a), pure::bid128_abs(b));
+    same("negate", &[s], intel::bid128_negate(a), pure::bid128_negate(b));
+    same(
+      "floor",
+      &[s],
+      intel::bid128_round_integral_negative(a, flags!()),
+      pure::bid128_round_integral_negative(b, flags!()),
+    );
+    same(
+      "ceiling",
+      &[s],
+      intel::bid128_round_integral_positive(a, flags!()),
+      pure::bid128_round_integral_positive(b, flags!()),
+    );
+    same("trunc", &[s], intel::bid128_round_integral_zero(a, flags!()), pure::bid128_round_integral_zero(b, flags!()));
+    assert_eq!(intel::bid128_is_finite(a), pure::bid128_is_finite(b), "is_finite {s}");
+    assert_eq!(intel::bid128_is_zero(a), pure::bid128_is_zero(b), "is_zero {s}");
+    assert_eq!(intel::bid128_to_int32_int(a, flags!()), pure::bid128_to_int32_int(b, flags!()), "to_int32 {s}");
+    assert_eq!(intel::bid128_to_int64_int(a, flags!()), pure::bid128_to_int64_int(b, flags!()), "to_int64 {s}");
+  }
+}
+
+#[test]
+fn _0002() {
+  for s in OPERANDS {
+    let (a, b) = (intel_num(s), pure_num(s));
+    equal(
+      "sqrt",
+      &[s],
+      intel::bid128_sqrt(a, intel::RM_NEAREST_EVEN, flags!()),
+      pure::bid128_sqrt(b, pure::RM_NEAREST_EVEN, flags!()),
+    );
+    same(
+      "exp",
+      &[s],
+      intel::bid128_exp(a, intel::RM_NEAREST_EVEN, flags!()),
+      pure::bid128_exp(b, pure::RM_NEAREST_EVEN, flags!()),
+    );
+    same(
+      "ln",
+      &[s],
+      intel::bid128_log(a, intel::RM_NEAREST_EVEN, flags!()),
+      pure::bid128_log(b, pure::RM_NEAREST_EVEN, flags!()),
+    );
+  }
+}
+
+#[test]
+fn _0003() {
+  for x in OPERANDS {
+    for y in OPERANDS {
+      let s = &[*x, *y];
+      let (a1, b1) = (intel_num(x), pure_num(x));
+      let (a2, b2) = (intel_num(y), pure_num(y));
+      let rnd = intel::RM_NEAREST_EVEN;
+      same("add", s, intel::bid128_add(a1, a2, rnd, flags!()), pure::bid128_add(b1, b2, rnd, flags!()));
+      same("sub", s, intel::bid128_sub(a1, a2, rnd, flags!()), pure::bid128_sub(b1, b2, rnd, flags!()));
+      same("mul", s, intel::bid128_mul(a1, a2, rnd, flags!()), pure::bid128_mul(b1, b2, rnd, flags!()));
+      equal("div", s, intel::bid128_div(a1, a2, rnd, flags!()), pure::bid128_div(b1, b2, rnd, flags!()));
+      equal("rem", s, intel::bid128_rem(a1, a2, flags!()), pure::bid128_rem(b1, b2, flags!()));
+      assert_eq!(intel::bid128_quiet_equal(a1, a2, flags!()), pure::bid128_quiet_equal(b1, b2, flags!()), "eq {s:?}");
+      assert_eq!(intel::bid128_quiet_less(a1, a2, flags!()), pure::bid128_quiet_less(b1, b2, flags!()), "lt {s:?}");
+      assert_eq!(intel::bid128_quiet_greater(a1, a2, flags!()), pure::bid128_quiet_greater(b1, b2, flags!()), "gt {s:?}");
+    }
+  }
+}
+
+#[test]
+fn _0004() {
+  let exponents = [
+    "0",
+    "1",
+    "-1",
+    "2",
+    "3",
+    "-3",
+    "0.5",
+    "-0.5",
+    "1.5",
+    "10",
+    "100.5",
+    "2147483647",
+    "-2147483648",
+    "3000000001",
+    "1E+100",
+    "-1E+100",
+    "Inf",
+    "-Inf",
+    "NaN",
+  ];
+  for x in OPERANDS {
+    for y in exponents {
+      let s = &[*x, y];
+      let rnd = intel::RM_NEAREST_EVEN;
+      same(
+        "pow",
+        s,
+        intel::bid128_pow(intel_num(x), intel_num(y), rnd, flags!()),
+        pure::bid128_pow(pure_num(x), pure_num(y), rnd, flags!()),
+      );
+    }
+  }
+}
+
+#[test]
+fn _0005() {
+  let scales = ["0", "1", "1E+2", "0.01", "1E-10", "1E+10"];
+  for x in OPERANDS {
+    for y in scales {
+      let s = &[*x, y];
+      let rnd = intel::RM_NEAREST_EVEN;
+      same(
+        "quantize",
+        s,
+        intel::bid128_quantize(intel_num(x), intel_num(y), rnd, flags!()),
+        pure::bid128_quantize(pure_num(x), pure_num(y), rnd, flags!()),
+      );
+    }
+  }
+}
@@ -0,0 +1,70 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::natural::Natural;
+
+fn num(s: &str) -> Natural {
+  Natural::from_digits(s)
+}
+
+#[test]
+fn _0001() {
+  assert!(Natural::zero().is_zero());
+  assert_eq!(0, Natural::zero().digits());
+  assert_eq!(1, Natural::one().digits());
+  assert_eq!(34, num("9999999999999999999999999999999999").digits());
+  assert_eq!(35, num("10000000000000000000000000000000000").digits());
+  assert_eq!(Some(u128::MAX), Natural::from_u128(u128::MAX).to_u128());
+  assert_eq!(None, Natural::from_u128(u128::MAX).add(&Natural::one()).to_u128());
+}
+
+#[test]
+fn _0002() {
+  let a = num("123456789012345678901234567890123456789012345678901234567890");
+  let b = num("987654321098765432109876543210");
+  let product = a.mul(&b);
+  assert_eq!(num("121932631137021795226185032733744855963374485596337448559633622923332237463801111263526900"), product);
+  let (quotient, remainder) = product.add(&Natural::from_u128(12345)).div_rem(&b);
+  assert_eq!(a, quotient);
+  assert_eq!(Natural::from_u128(12345), remainder);
+  assert_eq!(a, product.add(&a).sub(&a).div_rem(&b).0);
+}
+
+#[test]
+fn _0003() {
+  let n = Natural::pow10(300);
+  assert_eq!(301, n.digits());
+  assert_eq!(Natural::pow10(150), n.sqrt());
+  assert_eq!(num("31622776601683793319"), Natural::pow10(39).sqrt());
+  let (quotient, remainder) = n.div_rem_small(7);
+  assert_eq!(n, quotient.mul_small(7).add(&Natural::from_u128(remainder as u128)));
+}
@@ -0,0 +1,39 @@
+use crate::FeelNumber;
+use std::str::FromStr;
+
+fn num(s: &str) -> FeelNumber {
+  FeelNumber::from_str(s).unwrap()
+}
+
+#[test]
+fn _0001() {
+  assert_eq!(r#""1.25""#, serde_json::to_string(&num("1.25")).unwrap());
+}
+
+#[test]
+fn _0002() {
+  assert_eq!(
+    r#""-12345678901234567890.123456789""#,
+    serde_json::to_string(&num("-12345678901234567890.123456789")).unwrap()
+  );
+}
+
+#[test]
+fn _0003() {
+  let n = num("0.1000000000000000000000000000000001");
+  let json = serde_json::to_string(&n).unwrap();
+  assert_eq!(n, serde_json::from_str::<FeelNumber>(&json).unwrap());
+}
+
+#[test]
+fn _0004() {
+  assert_eq!(num("42"), serde_json::from_str::<FeelNumber>("42").unwrap());
+  assert_eq!(num("-42"), serde_json::from_str::<FeelNumber>("-42").unwrap());
+  assert_eq!(num("2.5"), serde_json::from_str::<FeelNumber>("2.5").unwrap());
+}
+
+#[test]
+fn _0005() {
+  assert!(serde_json::from_str::<FeelNumber>(r#""abc""#).is_err());
+  assert!(serde_json::from_str::<FeelNumber>("true").is_err());
+}
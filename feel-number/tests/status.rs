@@ -0,0 +1,135 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+mod common;
+
+use dmntk_feel_number::{FeelNumber, FeelNumberStatus};
+
+#[test]
+fn test_status_001() {
+  let (n, status) = num!(1).add_with_status(&num!(2));
+  eqs!("3", n);
+  assert!(status.is_clear());
+  assert!(status.check().is_ok());
+  eqs!("", status);
+}
+
+#[test]
+fn test_status_002() {
+  let (n, status) = num!(1).div_with_status(&num!(3));
+  eqs!("0.3333333333333333333333333333333333", n);
+  assert!(status.is_inexact());
+  assert!(!status.is_overflow());
+  assert!(status.check().is_ok());
+  eqs!("inexact", status);
+}
+
+#[test]
+fn test_status_003() {
+  let (n, status) = num!(9E6000).mul_with_status(&num!(9E6000));
+  assert!(!n.is_finite());
+  assert!(status.is_overflow());
+  assert!(status.is_inexact());
+  eqs!("overflow, inexact", status);
+  assert_eq!("<FeelNumberError> overflow", status.check().unwrap_err().to_string());
+}
+
+#[test]
+fn test_status_004() {
+  let (n, status) = num!(1E-6000).mul_with_status(&num!(1E-6000));
+  assert!(n.is_zero());
+  assert!(status.is_underflow());
+  assert!(status.check().is_ok());
+}
+
+#[test]
+fn test_status_005() {
+  let (n, status) = num!(1).div_with_status(&num!(0));
+  assert!(!n.is_finite());
+  assert!(status.is_division_by_zero());
+  assert_eq!("<FeelNumberError> division by zero", status.check().unwrap_err().to_string());
+}
+
+#[test]
+fn test_status_006() {
+  let (n, status) = num!(-1).sqrt_with_status();
+  assert!(!n.is_finite());
+  assert!(status.is_invalid());
+  assert_eq!("<FeelNumberError> invalid operation", status.check().unwrap_err().to_string());
+}
+
+#[test]
+fn test_status_007() {
+  let (_, status) = num!(9999).pow_with_status(&num!(9999));
+  assert!(status.is_overflow());
+  let (_, status) = num!(100000).exp_with_status();
+  assert!(status.is_overflow());
+  let (_, status) = num!(0).ln_with_status();
+  assert!(status.is_division_by_zero());
+  let (n, status) = num!(2).pow_with_status(&num!(10));
+  eqs!("1024", n);
+  assert!(status.is_clear());
+}
+
+#[test]
+fn test_status_008() {
+  let (n, status) = num!(10).rem_with_status(&num!(4));
+  eqs!("2", n);
+  assert!(status.check().is_ok());
+  let (n, status) = num!(9999999999999999999999999999999999).sub_with_status(&num!(-0.5));
+  eqs!("10000000000000000000000000000000000", n);
+  assert!(status.is_inexact());
+}
+
+#[test]
+fn test_status_009() {
+  let mut status = FeelNumberStatus::default();
+  assert!(status.is_clear());
+  status |= num!(1).div_with_status(&num!(3)).1;
+  let status = status | num!(1E-6000).mul_with_status(&num!(1E-6000)).1;
+  eqs!("underflow, inexact", status);
+}
+
+#[test]
+fn test_status_010() {
+  let (n, status) = num!(-2.5).floor_with_status();
+  eqs!("-3", n);
+  assert!(status.check().is_ok());
+  let (n, status) = num!(-0.5).ceiling_with_status();
+  eqs!("0", n);
+  assert!(status.check().is_ok());
+  let (n, status) = num!(2.345).round_with_status(&num!(2));
+  eqs!("2.34", n);
+  assert!(status.check().is_ok());
+  let (n, status) = num!(9E6100).round_with_status(&num!(0));
+  assert!(!n.is_finite());
+  assert!(status.is_invalid());
+}
@@ -339,6 +339,90 @@ impl AstNode {
     println!("{output}");
     output
   }
+
+  /// Returns direct child nodes of this node, in the order of their definition.
+  /// Indexes of child nodes in the returned vector are used as segments of node paths.
+  pub fn children(&self) -> Vec<&AstNode> {
+    match self {
+      AstNode::Add(lhs, rhs)
+      | AstNode::And(lhs, rhs)
+      | AstNode::ContextEntry(lhs, rhs)
+      | AstNode::ContextTypeEntry(lhs, rhs)
+      | AstNode::Div(lhs, rhs)
+      | AstNode::Eq(lhs, rhs)
+      | AstNode::Every(lhs, rhs)
+      | AstNode::Exp(lhs, rhs)
+      | AstNode::Filter(lhs, rhs)
+      | AstNode::For(lhs, rhs)
+      | AstNode::FormalParameter(lhs, rhs)
+      | AstNode::FunctionDefinition(lhs, rhs)
+      | AstNode::FunctionInvocation(lhs, rhs)
+      | AstNode::FunctionType(lhs, rhs)
+      | AstNode::Ge(lhs, rhs)
+      | AstNode::Gt(lhs, rhs)
+      | AstNode::In(lhs, rhs)
+      | AstNode::InstanceOf(lhs, rhs)
+      | AstNode::IterationContextSingle(lhs, rhs)
+      | AstNode::Le(lhs, rhs)
+      | AstNode::Lt(lhs, rhs)
+      | AstNode::Mul(lhs, rhs)
+      | AstNode::NamedParameter(lhs, rhs)
+      | AstNode::Nq(lhs, rhs)
+      | AstNode::Or(lhs, rhs)
+      | AstNode::Out(lhs, rhs)
+      | AstNode::Path(lhs, rhs)
+      | AstNode::QuantifiedContext(lhs, rhs)
+      | AstNode::Range(lhs, rhs)
+      | AstNode::Some(lhs, rhs)
+      | AstNode::Sub(lhs, rhs) => vec![lhs, rhs],
+      AstNode::Between(lhs, mid, rhs) | AstNode::If(lhs, mid, rhs) | AstNode::IterationContextRange(lhs, mid, rhs) => vec![lhs, mid, rhs],
+      AstNode::EvaluatedExpression(mid)
+      | AstNode::FunctionBody(mid, _)
+      | AstNode::IntervalEnd(mid, _)
+      | AstNode::IntervalStart(mid, _)
+      | AstNode::ListType(mid)
+      | AstNode::Neg(mid)
+      | AstNode::RangeType(mid)
+      | AstNode::Satisfies(mid)
+      | AstNode::UnaryGe(mid)
+      | AstNode::UnaryGt(mid)
+      | AstNode::UnaryLe(mid)
+      | AstNode::UnaryLt(mid) => vec![mid],
+      AstNode::CommaList(items)
+      | AstNode::Context(items)
+      | AstNode::ContextType(items)
+      | AstNode::ExpressionList(items)
+      | AstNode::FormalParameters(items)
+      | AstNode::IterationContexts(items)
+      | AstNode::List(items)
+      | AstNode::NamedParameters(items)
+      | AstNode::NegatedList(items)
+      | AstNode::ParameterTypes(items)
+      | AstNode::PositionalParameters(items)
+      | AstNode::QualifiedName(items)
+      | AstNode::QuantifiedContexts(items) => items.iter().collect(),
+      AstNode::At(_)
+      | AstNode::Boolean(_)
+      | AstNode::ContextEntryKey(_)
+      | AstNode::ContextTypeEntryKey(_)
+      | AstNode::FeelType(_)
+      | AstNode::Irrelevant
+      | AstNode::Name(_)
+      | AstNode::Null
+      | AstNode::Numeric(_, _)
+      | AstNode::ParameterName(_)
+      | AstNode::QualifiedNameSegment(_)
+      | AstNode::String(_) => vec![],
+    }
+  }
+
+  /// Returns the node at specified path of child indexes, starting from this node.
+  pub fn node_at(&self, path: &[usize]) -> Option<&AstNode> {
+    match path.split_first() {
+      Some((index, tail)) => self.children().get(*index).and_then(|child| child.node_at(tail)),
+      None => Some(self),
+    }
+  }
 }
 
 /// Returns ASCII tree representation of the specified node.
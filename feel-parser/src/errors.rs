@@ -32,7 +32,30 @@
 
 //! Implementation of errors for FEEL parser.
 
-use dmntk_common::{DmntkError, ToErrorMessage};
+use dmntk_common::{DmntkError, Span, ToErrorMessage};
+
+/// Maximum number of expected tokens reported in the message of the syntax error.
+const MAX_REPORTED_EXPECTED_TOKENS: usize = 5;
+
+/// Syntax error with the position of the offending token.
+#[derive(Debug, PartialEq)]
+pub struct SyntaxError {
+  /// Error reported to the caller.
+  pub error: DmntkError,
+  /// Text of the offending token, `None` when the end of input was reached.
+  pub token: Option<String>,
+  /// Span of the offending token.
+  pub span: Span,
+  /// Names of the tokens that were expected instead of the offending token.
+  pub expected: Vec<String>,
+}
+
+impl From<SyntaxError> for DmntkError {
+  /// Converts [SyntaxError] into [DmntkError].
+  fn from(syntax_error: SyntaxError) -> Self {
+    syntax_error.error
+  }
+}
 
 /// Lexer error.
 #[derive(ToErrorMessage)]
@@ -63,7 +86,24 @@ pub fn err_not_a_feel_name(s: &str) -> DmntkError {
   ParserError(format!("expected `FEEL` name on input but found `{s}`")).into()
 }
 
-/// Creates syntax error on specified input.
-pub fn err_syntax_error(input: &str) -> DmntkError {
-  ParserError(format!("syntax error: {input}")).into()
+/// Creates syntax error on specified input, with the offending token, its position and expected tokens.
+pub fn err_syntax_error(input: &str, token: Option<&str>, span: &Span, expected: &[String]) -> DmntkError {
+  let unexpected = match token {
+    Some(token) => format!("unexpected '{token}' at {}", span.start),
+    None => format!("unexpected end of input at {}", span.start),
+  };
+  let expected = match expected.len() {
+    0 => "".to_string(),
+    1..=MAX_REPORTED_EXPECTED_TOKENS => {
+      let names = expected.iter().map(|name| format!("'{name}'")).collect::<Vec<String>>();
+      let (last, first) = names.split_last().unwrap();
+      if first.is_empty() {
+        format!(", expected {last}")
+      } else {
+        format!(", expected {} or {last}", first.join(", "))
+      }
+    }
+    _ => "".to_string(),
+  };
+  ParserError(format!("syntax error: {input}, {unexpected}{expected}")).into()
 }
@@ -0,0 +1,408 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Formatter converting `FEEL` AST back into canonical textual form.
+//!
+//! Formatting works on the AST, so the output does not depend on the layout of the original
+//! source, comments are not preserved. Parsing the formatted text in the same scope as the
+//! original expression yields the same AST, so formatting the output again returns it unchanged.
+
+use crate::lexer::{is_name_part_char, is_name_start_char};
+use crate::AstNode;
+use dmntk_feel::{FeelType, Name};
+
+/// Default number of spaces used for one level of indentation.
+const DEFAULT_INDENT: usize = 2;
+
+/// Default maximum width of the line.
+const DEFAULT_MAX_WIDTH: usize = 80;
+
+/// Reserved words that can not be used as unquoted context entry keys.
+const RESERVED_WORDS: [&str; 19] = [
+  "and",
+  "between",
+  "else",
+  "every",
+  "external",
+  "false",
+  "for",
+  "function",
+  "if",
+  "in",
+  "instance",
+  "not",
+  "null",
+  "of",
+  "or",
+  "return",
+  "satisfies",
+  "some",
+  "then",
+];
+
+/// Binding strength of `FEEL` expressions, from the weakest to the strongest.
+///
+/// Expressions starting with a keyword (`if`, `for`, `some`, `every`, `function`)
+/// extend as far to the right as possible, so they bind the weakest.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Precedence {
+  Keyword,
+  Disjunction,
+  Conjunction,
+  Comparison,
+  Between,
+  In,
+  Additive,
+  Multiplicative,
+  Exponentiation,
+  Negation,
+  InstanceOf,
+  Postfix,
+  Primary,
+}
+
+/// Formatter of `FEEL` expressions.
+#[derive(Debug, Clone)]
+pub struct FeelFormatter {
+  /// Number of spaces used for one level of indentation.
+  indent: usize,
+  /// Maximum width of the line; contexts, lists and `if`, `for`, `some`, `every` expressions
+  /// that do not fit in this width are split into multiple indented lines.
+  max_width: usize,
+}
+
+impl Default for FeelFormatter {
+  /// Creates a formatter with default indentation and line width.
+  fn default() -> Self {
+    Self::new(DEFAULT_INDENT, DEFAULT_MAX_WIDTH)
+  }
+}
+
+impl FeelFormatter {
+  /// Creates a formatter with specified indentation and maximum line width.
+  pub fn new(indent: usize, max_width: usize) -> Self {
+    Self { indent, max_width }
+  }
+
+  /// Formats the specified node into canonical `FEEL` text.
+  pub fn format(&self, node: &AstNode) -> String {
+    self.node(node, 0, false)
+  }
+
+  /// Formats a node at specified indentation level.
+  /// When `flat` is `true`, the whole node is formatted in a single line.
+  fn node(&self, node: &AstNode, level: usize, flat: bool) -> String {
+    if !flat && is_breakable(node) {
+      let text = self.node(node, level, true);
+      if level * self.indent + text.chars().count() <= self.max_width {
+        return text;
+      }
+      return self.broken(node, level);
+    }
+    match node {
+      AstNode::Add(lhs, rhs) => self.binary(node, lhs, "+", rhs, level, flat),
+      AstNode::And(lhs, rhs) => self.binary(node, lhs, "and", rhs, level, flat),
+      AstNode::At(text) => format!("@{}", quote(text)),
+      AstNode::Between(value, low, high) => {
+        let value = self.operand(value, Precedence::Additive, level, flat);
+        let low = self.operand(low, Precedence::Additive, level, flat);
+        let high = self.operand(high, Precedence::Additive, level, flat);
+        format!("{value} between {low} and {high}")
+      }
+      AstNode::Boolean(value) => value.to_string(),
+      AstNode::CommaList(items) | AstNode::List(items) => format!("[{}]", self.items(items, level, flat)),
+      AstNode::Context(entries) => {
+        if entries.is_empty() {
+          "{}".to_string()
+        } else {
+          format!("{{{}}}", self.items(entries, level, flat))
+        }
+      }
+      AstNode::ContextEntry(key, value) => format!("{}: {}", self.node(key, level, flat), self.node(value, level, flat)),
+      AstNode::ContextEntryKey(name) => key(name),
+      AstNode::ContextType(entries) => format!("context<{}>", self.items(entries, level, flat)),
+      AstNode::ContextTypeEntry(key, feel_type) => format!("{}: {}", self.node(key, level, flat), self.node(feel_type, level, flat)),
+      AstNode::ContextTypeEntryKey(name) => name.to_string(),
+      AstNode::Div(lhs, rhs) => self.binary(node, lhs, "/", rhs, level, flat),
+      AstNode::Eq(lhs, rhs) => self.binary(node, lhs, "=", rhs, level, flat),
+      AstNode::EvaluatedExpression(expression) => self.node(expression, level, flat),
+      AstNode::Every(contexts, satisfies) => format!("every {} satisfies {}", self.node(contexts, level, flat), self.node(satisfies, level, flat)),
+      AstNode::Exp(lhs, rhs) => self.binary(node, lhs, "**", rhs, level, flat),
+      AstNode::ExpressionList(items) => self.items(items, level, flat),
+      AstNode::FeelType(feel_type) => feel_type.to_string(),
+      AstNode::Filter(lhs, rhs) => format!("{}[{}]", self.operand(lhs, Precedence::Postfix, level, flat), self.node(rhs, level, flat)),
+      AstNode::For(contexts, expression) => format!("for {} return {}", self.node(contexts, level, flat), self.node(expression, level, flat)),
+      AstNode::FormalParameter(name, feel_type) => {
+        if let AstNode::FeelType(FeelType::Any) = feel_type.as_ref() {
+          self.node(name, level, flat)
+        } else {
+          format!("{}: {}", self.node(name, level, flat), self.node(feel_type, level, flat))
+        }
+      }
+      AstNode::FormalParameters(parameters) => format!("({})", self.items(parameters, level, flat)),
+      AstNode::FunctionBody(body, external) => {
+        if *external {
+          format!("external {}", self.node(body, level, flat))
+        } else {
+          self.node(body, level, flat)
+        }
+      }
+      AstNode::FunctionDefinition(parameters, body) => format!("function{} {}", self.node(parameters, level, flat), self.node(body, level, flat)),
+      AstNode::FunctionInvocation(function, parameters) => format!("{}({})", self.operand(function, Precedence::Postfix, level, flat), self.node(parameters, level, flat)),
+      AstNode::FunctionType(parameter_types, result_type) => format!("function<{}> -> {}", self.node(parameter_types, level, flat), self.node(result_type, level, flat)),
+      AstNode::Ge(lhs, rhs) => self.binary(node, lhs, ">=", rhs, level, flat),
+      AstNode::Gt(lhs, rhs) => self.binary(node, lhs, ">", rhs, level, flat),
+      AstNode::If(condition, then_branch, else_branch) => format!(
+        "if {} then {} else {}",
+        self.node(condition, level, flat),
+        self.node(then_branch, level, flat),
+        self.node(else_branch, level, flat)
+      ),
+      AstNode::In(lhs, rhs) => {
+        let lhs = self.operand(lhs, Precedence::Additive, level, flat);
+        let rhs = if let AstNode::ExpressionList(items) = rhs.as_ref() {
+          format!("({})", self.items(items, level, flat))
+        } else {
+          self.operand(rhs, Precedence::In, level, flat)
+        };
+        format!("{lhs} in {rhs}")
+      }
+      AstNode::InstanceOf(value, feel_type) => format!(
+        "{} instance of {}",
+        self.operand(value, Precedence::InstanceOf, level, flat),
+        self.node(feel_type, level, flat)
+      ),
+      AstNode::IntervalEnd(endpoint, closed) => format!("{}{}", self.node(endpoint, level, flat), if *closed { "]" } else { ")" }),
+      AstNode::IntervalStart(endpoint, closed) => format!("{}{}", if *closed { "[" } else { "(" }, self.node(endpoint, level, flat)),
+      AstNode::Irrelevant => "-".to_string(),
+      AstNode::IterationContexts(items) | AstNode::QuantifiedContexts(items) => self.items(items, level, flat),
+      AstNode::IterationContextSingle(name, values) | AstNode::QuantifiedContext(name, values) => {
+        format!("{} in {}", self.node(name, level, flat), self.operand(values, Precedence::Disjunction, level, flat))
+      }
+      AstNode::IterationContextRange(name, start, end) => format!(
+        "{} in {}..{}",
+        self.node(name, level, flat),
+        self.operand(start, Precedence::Disjunction, level, flat),
+        self.operand(end, Precedence::Disjunction, level, flat)
+      ),
+      AstNode::Le(lhs, rhs) => self.binary(node, lhs, "<=", rhs, level, flat),
+      AstNode::ListType(feel_type) => format!("list<{}>", self.node(feel_type, level, flat)),
+      AstNode::Lt(lhs, rhs) => self.binary(node, lhs, "<", rhs, level, flat),
+      AstNode::Mul(lhs, rhs) => self.binary(node, lhs, "*", rhs, level, flat),
+      AstNode::Name(name) => name.to_string(),
+      AstNode::NamedParameter(name, value) => format!("{}: {}", self.node(name, level, flat), self.node(value, level, flat)),
+      AstNode::NamedParameters(items) | AstNode::ParameterTypes(items) | AstNode::PositionalParameters(items) => self.items(items, level, flat),
+      AstNode::NegatedList(items) => format!("not({})", self.items(items, level, flat)),
+      AstNode::Neg(value) => format!("-{}", self.operand(value, Precedence::InstanceOf, level, flat)),
+      AstNode::Nq(lhs, rhs) => self.binary(node, lhs, "!=", rhs, level, flat),
+      AstNode::Null => "null".to_string(),
+      AstNode::Numeric(before, after) => {
+        if after.is_empty() {
+          before.to_string()
+        } else {
+          format!("{before}.{after}")
+        }
+      }
+      AstNode::Or(lhs, rhs) => self.binary(node, lhs, "or", rhs, level, flat),
+      // output value selection is built from decision table cells, only the output entry has a textual form
+      AstNode::Out(entry, _) => self.node(entry, level, flat),
+      AstNode::ParameterName(name) => name.to_string(),
+      AstNode::Path(lhs, rhs) => format!("{}.{}", self.operand(lhs, Precedence::Postfix, level, flat), self.node(rhs, level, flat)),
+      AstNode::QualifiedName(segments) => segments.iter().map(|segment| self.node(segment, level, flat)).collect::<Vec<String>>().join("."),
+      AstNode::QualifiedNameSegment(name) => name.to_string(),
+      AstNode::Range(start, end) => format!("{}..{}", self.node(start, level, flat), self.node(end, level, flat)),
+      AstNode::RangeType(feel_type) => format!("range<{}>", self.node(feel_type, level, flat)),
+      AstNode::Satisfies(expression) => self.node(expression, level, flat),
+      AstNode::Some(contexts, satisfies) => format!("some {} satisfies {}", self.node(contexts, level, flat), self.node(satisfies, level, flat)),
+      AstNode::String(text) => quote(text),
+      AstNode::Sub(lhs, rhs) => self.binary(node, lhs, "-", rhs, level, flat),
+      AstNode::UnaryGe(endpoint) => format!(">= {}", self.node(endpoint, level, flat)),
+      AstNode::UnaryGt(endpoint) => format!("> {}", self.node(endpoint, level, flat)),
+      AstNode::UnaryLe(endpoint) => format!("<= {}", self.node(endpoint, level, flat)),
+      AstNode::UnaryLt(endpoint) => format!("< {}", self.node(endpoint, level, flat)),
+    }
+  }
+
+  /// Formats a node that does not fit in a single line, splitting it into indented lines.
+  fn broken(&self, node: &AstNode, level: usize) -> String {
+    let outer = self.indentation(level);
+    let inner = self.indentation(level + 1);
+    match node {
+      AstNode::Context(items) => {
+        let entries = items
+          .iter()
+          .map(|item| format!("{inner}{}", self.node(item, level + 1, false)))
+          .collect::<Vec<String>>()
+          .join(",\n");
+        format!("{{\n{entries}\n{outer}}}")
+      }
+      AstNode::CommaList(items) | AstNode::List(items) => {
+        let items = items
+          .iter()
+          .map(|item| format!("{inner}{}", self.node(item, level + 1, false)))
+          .collect::<Vec<String>>()
+          .join(",\n");
+        format!("[\n{items}\n{outer}]")
+      }
+      AstNode::If(condition, then_branch, else_branch) => {
+        let condition = self.node(condition, level, false);
+        let then_branch = self.node(then_branch, level + 1, false);
+        let else_branch = if let AstNode::If(..) = else_branch.as_ref() {
+          // chained conditions are kept at the same level of indentation
+          format!(" {}", self.node(else_branch, level, false))
+        } else {
+          format!("\n{inner}{}", self.node(else_branch, level + 1, false))
+        };
+        format!("if {condition} then\n{inner}{then_branch}\n{outer}else{else_branch}")
+      }
+      AstNode::For(contexts, expression) => {
+        format!("for {} return\n{inner}{}", self.node(contexts, level, false), self.node(expression, level + 1, false))
+      }
+      AstNode::Some(contexts, satisfies) => {
+        format!("some {} satisfies\n{inner}{}", self.node(contexts, level, false), self.node(satisfies, level + 1, false))
+      }
+      AstNode::Every(contexts, satisfies) => {
+        format!("every {} satisfies\n{inner}{}", self.node(contexts, level, false), self.node(satisfies, level + 1, false))
+      }
+      _ => self.node(node, level, true),
+    }
+  }
+
+  /// Formats binary operator, adding parentheses to operands that bind weaker than the operator.
+  /// All binary operators are left-associative, except `in`, comparisons are not associative.
+  fn binary(&self, node: &AstNode, lhs: &AstNode, operator: &str, rhs: &AstNode, level: usize, flat: bool) -> String {
+    let precedence = precedence(node);
+    let (lhs_min, rhs_min) = match precedence {
+      Precedence::Comparison => (next(precedence), next(precedence)),
+      _ => (precedence, next(precedence)),
+    };
+    format!("{} {operator} {}", self.operand(lhs, lhs_min, level, flat), self.operand(rhs, rhs_min, level, flat))
+  }
+
+  /// Formats an operand, enclosing it in parentheses when it binds weaker than required.
+  fn operand(&self, node: &AstNode, min: Precedence, level: usize, flat: bool) -> String {
+    let text = self.node(node, level, flat);
+    if precedence(node) < min {
+      format!("({text})")
+    } else {
+      text
+    }
+  }
+
+  /// Formats a comma separated list of nodes.
+  fn items(&self, items: &[AstNode], level: usize, flat: bool) -> String {
+    items.iter().map(|item| self.node(item, level, flat)).collect::<Vec<String>>().join(", ")
+  }
+
+  /// Returns indentation for specified level.
+  fn indentation(&self, level: usize) -> String {
+    " ".repeat(level * self.indent)
+  }
+}
+
+/// Returns `true` when the node may be split into multiple lines.
+fn is_breakable(node: &AstNode) -> bool {
+  matches!(
+    node,
+    AstNode::Context(_) | AstNode::CommaList(_) | AstNode::List(_) | AstNode::If(..) | AstNode::For(..) | AstNode::Some(..) | AstNode::Every(..)
+  ) && !matches!(node, AstNode::Context(items) | AstNode::CommaList(items) | AstNode::List(items) if items.is_empty())
+}
+
+/// Returns the binding strength of the node.
+fn precedence(node: &AstNode) -> Precedence {
+  match node {
+    AstNode::Every(..) | AstNode::For(..) | AstNode::FunctionDefinition(..) | AstNode::If(..) | AstNode::Some(..) => Precedence::Keyword,
+    AstNode::Or(..) => Precedence::Disjunction,
+    AstNode::And(..) => Precedence::Conjunction,
+    AstNode::Eq(..) | AstNode::Nq(..) | AstNode::Lt(..) | AstNode::Le(..) | AstNode::Gt(..) | AstNode::Ge(..) => Precedence::Comparison,
+    AstNode::Between(..) => Precedence::Between,
+    AstNode::In(..) => Precedence::In,
+    AstNode::Add(..) | AstNode::Sub(..) => Precedence::Additive,
+    AstNode::Mul(..) | AstNode::Div(..) => Precedence::Multiplicative,
+    AstNode::Exp(..) => Precedence::Exponentiation,
+    AstNode::Neg(..) => Precedence::Negation,
+    AstNode::InstanceOf(..) => Precedence::InstanceOf,
+    AstNode::Filter(..) | AstNode::FunctionInvocation(..) | AstNode::Path(..) => Precedence::Postfix,
+    _ => Precedence::Primary,
+  }
+}
+
+/// Returns the precedence binding one level stronger than specified one.
+fn next(precedence: Precedence) -> Precedence {
+  match precedence {
+    Precedence::Keyword => Precedence::Disjunction,
+    Precedence::Disjunction => Precedence::Conjunction,
+    Precedence::Conjunction => Precedence::Comparison,
+    Precedence::Comparison => Precedence::Between,
+    Precedence::Between => Precedence::In,
+    Precedence::In => Precedence::Additive,
+    Precedence::Additive => Precedence::Multiplicative,
+    Precedence::Multiplicative => Precedence::Exponentiation,
+    Precedence::Exponentiation => Precedence::Negation,
+    Precedence::Negation => Precedence::InstanceOf,
+    Precedence::InstanceOf => Precedence::Postfix,
+    Precedence::Postfix | Precedence::Primary => Precedence::Primary,
+  }
+}
+
+/// Formats the key of the context entry, keys that are not plain names are quoted.
+fn key(name: &Name) -> String {
+  let text = name.to_string();
+  let is_plain = text.split(' ').all(|word| {
+    let mut chars = word.chars();
+    chars.next().is_some_and(is_name_start_char) && chars.all(is_name_part_char) && !RESERVED_WORDS.contains(&word)
+  });
+  if is_plain {
+    text
+  } else {
+    quote(&text)
+  }
+}
+
+/// Encloses the text in double quotes, escaping characters that can not appear literally in string literal.
+fn quote(text: &str) -> String {
+  let mut quoted = String::with_capacity(text.len() + 2);
+  quoted.push('"');
+  for ch in text.chars() {
+    match ch {
+      '"' => quoted.push_str("\\\""),
+      '\\' => quoted.push_str("\\\\"),
+      '\n' => quoted.push_str("\\n"),
+      '\r' => quoted.push_str("\\r"),
+      '\t' => quoted.push_str("\\t"),
+      '\u{000B}' | '\u{000C}' => quoted.push_str(&format!("\\u{:04X}", ch as u32)),
+      _ => quoted.push(ch),
+    }
+  }
+  quoted.push('"');
+  quoted
+}
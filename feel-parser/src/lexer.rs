@@ -35,7 +35,7 @@
 use crate::errors::*;
 use crate::lalr::TokenType;
 use crate::scope::ParsingScope;
-use dmntk_common::Result;
+use dmntk_common::{Position, Result, Span};
 use dmntk_feel::Name;
 
 /// Definition of a single space character.
@@ -123,6 +123,10 @@ pub struct Lexer<'lexer> {
   input: Vec<char>,
   /// Current cursor position in input vector.
   position: usize,
+  /// Position in input vector where the most recently read token starts.
+  token_start: usize,
+  /// Positions in input vector where the lines of input text start.
+  line_starts: Vec<usize>,
   /// Flag indicating if the unary tests rule is the starting point.
   /// This flag is used to identify `not` keyword,
   /// which otherwise would be recognized as a name.
@@ -145,11 +149,17 @@ pub struct Lexer<'lexer> {
 impl<'lexer> Lexer<'lexer> {
   /// Creates a new lexer for specified input text.
   pub fn new(scope: &'lexer ParsingScope, start_token_type: TokenType, input: &str) -> Self {
+    let input = input.chars().collect::<Vec<char>>();
+    let line_starts = std::iter::once(0)
+      .chain(input.iter().enumerate().filter_map(|(offset, ch)| if *ch == '\n' { Some(offset + 1) } else { None }))
+      .collect();
     Self {
       scope,
       start_token_type: Some(start_token_type),
-      input: input.chars().collect(),
+      input,
       position: 0,
+      token_start: 0,
+      line_starts,
       unary_tests: false,
       between: false,
       type_name: false,
@@ -192,6 +202,24 @@ impl<'lexer> Lexer<'lexer> {
     self.scope.set_name(name.to_owned());
   }
 
+  /// Returns the span of the most recently read token.
+  pub fn token_span(&self) -> Span {
+    Span::new(self.position_at(self.token_start), self.position_at(self.position.max(self.token_start)))
+  }
+
+  /// Returns the text of the most recently read token.
+  /// When the token could not be recognized, the character at the token start is returned.
+  pub fn token_text(&self) -> String {
+    let end = self.position.max(self.token_start + 1).min(self.input.len());
+    self.input[self.token_start.min(end)..end].iter().collect()
+  }
+
+  /// Returns the line and column of the character at specified offset in input vector.
+  pub fn position_at(&self, offset: usize) -> Position {
+    let line = self.line_starts.partition_point(|start| *start <= offset);
+    Position::new(offset, line, offset - self.line_starts[line - 1] + 1)
+  }
+
   /// Returns the next token from input.
   pub fn next_token(&mut self) -> Result<(TokenType, TokenValue)> {
     if let Some(start_token_type) = self.start_token_type.clone() {
@@ -435,6 +463,7 @@ impl<'lexer> Lexer<'lexer> {
         break;
       }
     }
+    self.token_start = self.position;
     let mut buffer: [char; BUF_SIZE] = [WS; BUF_SIZE];
     for (offset, value) in buffer.iter_mut().enumerate() {
       if let Some(ch) = self.char_at(offset) {
@@ -943,7 +972,7 @@ fn is_additional_name_symbol(ch: char) -> bool {
 
 /// Returns `true` when the specified character is name start character.
 /// Specification: 10.3.1.2 Grammar rules, p.120, grammar rule 28.
-fn is_name_start_char(ch: char) -> bool {
+pub(crate) fn is_name_start_char(ch: char) -> bool {
   matches!(ch, '?' | 'A'..='Z' | '_' | 'a'..='z' |
                '\u{00C0}'..='\u{00D6}' | '\u{00D8}'..='\u{00F6}' | '\u{00F8}'..='\u{02FF}' |
                '\u{0370}'..='\u{037D}' | '\u{037F}'..='\u{1FFF}' | '\u{200C}'..='\u{200D}' |
@@ -953,7 +982,7 @@ fn is_name_start_char(ch: char) -> bool {
 
 /// Returns `true` when the specified character is name start character.
 /// Specification: 10.3.1.2 Grammar rules, p.120, grammar rule 29.
-fn is_name_part_char(ch: char) -> bool {
+pub(crate) fn is_name_part_char(ch: char) -> bool {
   is_name_start_char(ch) || is_digit(ch) || matches!(ch, '\u{00B7}' | '\u{0300}'..='\u{036F}' | '\u{203F}'..='\u{2040}')
 }
 
@@ -39,17 +39,24 @@ mod ast;
 mod closure;
 mod context;
 mod errors;
+mod formatter;
 mod lalr;
 mod lexer;
 mod parser;
 mod scope;
+mod spans;
+mod type_checker;
 
 #[cfg(test)]
 mod tests;
 
 pub use ast::{ast_tree, AstNode};
 pub use closure::ClosureBuilder;
+pub use errors::SyntaxError;
+pub use formatter::FeelFormatter;
 pub use scope::ParsingScope;
+pub use spans::Spans;
+pub use type_checker::{TypeCheck, TypeChecker, TypeError};
 
 use crate::errors::*;
 use crate::lalr::TokenType;
@@ -100,3 +107,28 @@ pub fn parse_boxed_expression(scope: &FeelScope, input: &str, trace: bool) -> Re
 pub fn parse_context(scope: &FeelScope, input: &str, trace: bool) -> Result<AstNode> {
   Parser::new(&scope.into(), TokenType::StartContext, input, trace).parse()
 }
+
+/// Parses an `expression` as defined in grammar rule `1`, returns the spans of all AST nodes.
+pub fn parse_expression_with_spans(scope: &FeelScope, input: &str, trace: bool) -> std::result::Result<(AstNode, Spans), SyntaxError> {
+  Parser::new(&scope.into(), TokenType::StartExpression, input, trace).parse_with_spans()
+}
+
+/// Parses a `textual expression` as defined in grammar rule `2`, returns the spans of all AST nodes.
+pub fn parse_textual_expression_with_spans(scope: &FeelScope, input: &str, trace: bool) -> std::result::Result<(AstNode, Spans), SyntaxError> {
+  Parser::new(&scope.into(), TokenType::StartTextualExpression, input, trace).parse_with_spans()
+}
+
+/// Parses `unary tests` as defined in grammar rule `17`, returns the spans of all AST nodes.
+pub fn parse_unary_tests_with_spans(scope: &FeelScope, input: &str, trace: bool) -> std::result::Result<(AstNode, Spans), SyntaxError> {
+  Parser::new(&scope.into(), TokenType::StartUnaryTests, input, trace).parse_with_spans()
+}
+
+/// Parses a `boxed expression` as defined in grammar rule `53`, returns the spans of all AST nodes.
+pub fn parse_boxed_expression_with_spans(scope: &FeelScope, input: &str, trace: bool) -> std::result::Result<(AstNode, Spans), SyntaxError> {
+  Parser::new(&scope.into(), TokenType::StartBoxedExpression, input, trace).parse_with_spans()
+}
+
+/// Parses a `context` as defined in grammar rule `59`, returns the spans of all AST nodes.
+pub fn parse_context_with_spans(scope: &FeelScope, input: &str, trace: bool) -> std::result::Result<(AstNode, Spans), SyntaxError> {
+  Parser::new(&scope.into(), TokenType::StartContext, input, trace).parse_with_spans()
+}
@@ -36,8 +36,9 @@ use crate::errors::*;
 use crate::lalr::*;
 use crate::lexer::*;
 use crate::scope::ParsingScope;
+use crate::spans::{SpanNode, Spans};
 use crate::AstNode;
-use dmntk_common::Result;
+use dmntk_common::{DmntkError, Result, Span};
 use dmntk_feel::{FeelType, Name};
 
 enum Action {
@@ -98,6 +99,14 @@ pub struct Parser<'parser> {
   yy_value_stack: Vec<TokenValue>,
   /// AST node stack.
   yy_node_stack: Vec<AstNode>,
+  /// The location of the lookahead token.
+  yy_location: Span,
+  /// Location stack, parallel to state stack.
+  yy_location_stack: Vec<Span>,
+  /// The location of the rule being reduced.
+  yy_rule_location: Span,
+  /// Span stack, parallel to AST node stack.
+  yy_span_stack: Vec<SpanNode>,
 }
 
 impl<'parser> Parser<'parser> {
@@ -118,11 +127,20 @@ impl<'parser> Parser<'parser> {
       yy_state_stack: vec![0],
       yy_value_stack: vec![TokenValue::YyEmpty],
       yy_node_stack: vec![],
+      yy_location: Span::default(),
+      yy_location_stack: vec![Span::default()],
+      yy_rule_location: Span::default(),
+      yy_span_stack: vec![],
     }
   }
 
   /// Parses the input.
   pub fn parse(&mut self) -> Result<AstNode> {
+    self.parse_with_spans().map(|(node, _)| node).map_err(Into::into)
+  }
+
+  /// Parses the input and returns the spans of all AST nodes.
+  pub fn parse_with_spans(&mut self) -> std::result::Result<(AstNode, Spans), SyntaxError> {
     let mut action = Action::NewState;
     loop {
       match action {
@@ -142,7 +160,8 @@ impl<'parser> Parser<'parser> {
           }
           // not known, so get a lookahead token if don't already have one
           if self.yy_char == TokenType::YyEmpty as i16 {
-            let (token_type, opt_token_value) = self.yy_lexer.next_token()?;
+            let (token_type, opt_token_value) = self.yy_lexer.next_token().map_err(|error| self.lexer_error(error))?;
+            self.yy_location = self.yy_lexer.token_span();
             self.yy_char = token_type as i16;
             self.yy_token = SymbolKind::YyEmpty as i16;
             self.yy_value = opt_token_value;
@@ -200,6 +219,7 @@ impl<'parser> Parser<'parser> {
           self.yy_state = self.yy_n as usize;
           self.yy_state_stack.push(self.yy_state);
           self.yy_value_stack.push(self.yy_value.clone());
+          self.yy_location_stack.push(self.yy_location);
           trace!(self, "  state_stack={:?}", self.yy_state_stack);
           trace!(self, "  value_stack={:?}", self.yy_value_stack);
           trace!(self, "  node_stack={:?}", self.yy_node_stack);
@@ -215,12 +235,14 @@ impl<'parser> Parser<'parser> {
           // yy_n is the number of a rule to reduce with
           trace!(self, "  --------------------------------------------");
           trace!(self, "  reducing_using_rule = {}", self.yy_n);
-          reduce(self, self.yy_n)?;
+          self.yy_rule_location = self.rule_location();
+          reduce(self, self.yy_n).map_err(|error| self.lexer_error(error))?;
           trace!(self, "  --------------------------------------------");
-          // pop the state stack and semantic value stack
+          // pop the state stack, semantic value stack and location stack
           for _ in 0..self.yy_len {
             self.yy_state_stack.pop();
             self.yy_value_stack.pop();
+            self.yy_location_stack.pop();
           }
           // keep yy_len = 0
           self.yy_len = 0;
@@ -237,6 +259,7 @@ impl<'parser> Parser<'parser> {
           // push the new state on the stack
           self.yy_state_stack.push(self.yy_state);
           self.yy_value_stack.push(TokenValue::YyState(self.yy_state));
+          self.yy_location_stack.push(self.yy_rule_location);
           trace!(self, "  state_stack={:?}", self.yy_state_stack);
           trace!(self, "  value_stack={:?}", self.yy_value_stack);
           trace!(self, "  node_stack={:?}", self.yy_node_stack);
@@ -245,11 +268,11 @@ impl<'parser> Parser<'parser> {
         Action::Error => {
           trace!(self, "\nERROR");
           self.yy_token = SymbolKind::YyError as i16;
-          return Err(err_syntax_error(self.input));
+          return Err(self.syntax_error(self.expected_tokens()));
         }
         Action::Error1 => {
           trace!(self, "\nERROR 1");
-          return Err(err_syntax_error(self.input));
+          return Err(self.syntax_error(vec![]));
         }
         Action::Accept => {
           trace!(self, "\n**********");
@@ -258,16 +281,238 @@ impl<'parser> Parser<'parser> {
           self.yy_token = SymbolKind::YyAccept as i16;
           let node = self.yy_node_stack.pop().unwrap();
           debug_assert!(self.yy_node_stack.is_empty());
+          let span_node = self.yy_span_stack.pop().unwrap();
+          debug_assert!(self.yy_span_stack.is_empty());
+          debug_assert!(span_node.conforms(&node));
           if self.yy_trace {
             node.trace();
           }
-          return Ok(node);
+          return Ok((node, Spans::from(&span_node)));
         }
       }
     }
   }
+
+  /// Returns the location of the symbol at specified offset from the top of the location stack,
+  /// the offset is the same as used for accessing the semantic value stack.
+  fn location(&self, offset: usize) -> Span {
+    self.yy_location_stack[self.yy_location_stack.len() - offset]
+  }
+
+  /// Returns the location of the rule being reduced, covering all symbols of the rule's right-hand side.
+  /// The location of the empty rule is an empty span at the end of the preceding symbol.
+  fn rule_location(&self) -> Span {
+    let len = self.yy_len as usize;
+    if len == 0 {
+      return Span::empty(self.location(1).end);
+    }
+    self.location(len).union(&self.location(1))
+  }
+
+  /// Returns the span of the node built by reduced rule, covering the location of the rule and all child nodes.
+  /// Nodes built by mid-rule actions (having an empty location) take the location of the preceding symbol.
+  fn node_span(&self, children: &[SpanNode]) -> Span {
+    let rule_location = if self.yy_rule_location.is_empty() && children.is_empty() {
+      self.location(1)
+    } else {
+      self.yy_rule_location
+    };
+    children
+      .iter()
+      .fold(if rule_location.is_empty() { None } else { Some(rule_location) }, |span, child| match span {
+        Some(span) => Some(span.union(&child.span)),
+        None => Some(child.span),
+      })
+      .unwrap_or(rule_location)
+  }
+
+  /// Pushes the span of the node built by reduced rule,
+  /// the spans of `count` child nodes are taken from the top of the span stack.
+  fn reduce_spans(&mut self, count: usize) {
+    let children = self.yy_span_stack.split_off(self.yy_span_stack.len() - count);
+    self.push_spans(children);
+  }
+
+  /// Pushes the span of the node built by reduced rule, with explicitly specified child spans.
+  fn push_spans(&mut self, children: Vec<SpanNode>) {
+    self.yy_span_stack.push(SpanNode::new(self.node_span(&children), children));
+  }
+
+  /// Pushes the span of the node built by reduced rule, where the first child node
+  /// was built from a token, and the remaining `count` child spans are taken from the span stack.
+  fn push_leading_span(&mut self, location: Span, count: usize) {
+    let mut children = vec![SpanNode::leaf(location)];
+    children.append(&mut self.yy_span_stack.split_off(self.yy_span_stack.len() - count));
+    self.push_spans(children);
+  }
+
+  /// Pushes the span of the node built by reduced rule, where the first child span
+  /// is taken from the span stack and the second child node was built from a token.
+  fn push_trailing_span(&mut self, location: Span) {
+    let lhs = self.yy_span_stack.pop().unwrap();
+    self.push_spans(vec![lhs, SpanNode::leaf(location)]);
+  }
+
+  /// Pushes the span of a list node, having a single item taken from the top of the span stack.
+  fn list_spans(&mut self) {
+    let item = self.yy_span_stack.pop().unwrap();
+    self.yy_span_stack.push(SpanNode::new(item.span, vec![item]));
+  }
+
+  /// Inserts the span of an item, placed just below the list span on the span stack,
+  /// as the first child of the list span.
+  fn prepend_span(&mut self) {
+    let mut list = self.yy_span_stack.pop().unwrap();
+    let item = self.yy_span_stack.pop().unwrap();
+    list.children.insert(0, item);
+    list.span = list_span(&list.children);
+    self.yy_span_stack.push(list);
+  }
+
+  /// Inserts the span of an item built from a token as the first child of the list span.
+  fn prepend_leaf_span(&mut self, location: Span) {
+    let mut list = self.yy_span_stack.pop().unwrap();
+    list.children.insert(0, SpanNode::leaf(location));
+    list.span = list_span(&list.children);
+    self.yy_span_stack.push(list);
+  }
+
+  /// Appends the span from the top of the span stack as the last child of the list span placed below.
+  fn append_span(&mut self) {
+    let item = self.yy_span_stack.pop().unwrap();
+    let mut list = self.yy_span_stack.pop().unwrap();
+    list.children.push(item);
+    list.span = list_span(&list.children);
+    self.yy_span_stack.push(list);
+  }
+
+  /// Updates the span on the top of the span stack to the location of reduced rule.
+  fn relocate_span(&mut self) {
+    if let Some(span_node) = self.yy_span_stack.last_mut() {
+      span_node.span = self.yy_rule_location;
+    }
+  }
+
+  /// Wraps the span on the top of the span stack in a node with the same span,
+  /// then pushes the span of the node built by reduced rule with two children.
+  fn wrap_top_span_and_reduce(&mut self) {
+    let rhs = self.yy_span_stack.pop().unwrap();
+    self.yy_span_stack.push(SpanNode::new(rhs.span, vec![rhs]));
+    self.reduce_spans(2);
+  }
+
+  /// Returns the names of tokens acceptable in current state.
+  fn expected_tokens(&self) -> Vec<String> {
+    let yy_n = YY_PACT[self.yy_state];
+    if yy_n == YY_PACT_N_INF {
+      return vec![];
+    }
+    let mut expected = vec![];
+    for (token_type, token_name) in TOKEN_NAMES {
+      let yy_token = if *token_type == TokenType::YyEof as i16 {
+        0
+      } else {
+        YY_TRANSLATE[*token_type as usize] as i16
+      };
+      let yy_i = yy_n + yy_token;
+      if (0..=YY_LAST).contains(&yy_i) && YY_CHECK[yy_i as usize] == yy_token && YY_TABLE[yy_i as usize] != YY_TABLE_N_INF {
+        let token_name = token_name.to_string();
+        if !expected.contains(&token_name) {
+          expected.push(token_name);
+        }
+      }
+    }
+    expected
+  }
+
+  /// Creates a syntax error for the lookahead token.
+  fn syntax_error(&self, expected: Vec<String>) -> SyntaxError {
+    let token = if self.yy_char == TokenType::YyEof as i16 {
+      None
+    } else {
+      Some(self.yy_lexer.token_text())
+    };
+    SyntaxError {
+      error: err_syntax_error(self.input, token.as_deref(), &self.yy_location, &expected),
+      token,
+      span: self.yy_location,
+      expected,
+    }
+  }
+
+  /// Creates a syntax error from the error reported by lexer.
+  fn lexer_error(&self, error: DmntkError) -> SyntaxError {
+    let span = self.yy_lexer.token_span();
+    SyntaxError {
+      error,
+      token: Some(self.yy_lexer.token_text()),
+      span,
+      expected: vec![],
+    }
+  }
 }
 
+/// Returns the span of a list node, covering all list items.
+fn list_span(items: &[SpanNode]) -> Span {
+  items.iter().skip(1).fold(items[0].span, |span, item| span.union(&item.span))
+}
+
+/// Names of tokens reported in syntax errors as expected tokens.
+const TOKEN_NAMES: &[(i16, &str)] = &[
+  (TokenType::YyEof as i16, "end of input"),
+  (TokenType::At as i16, "@"),
+  (TokenType::Not as i16, "not"),
+  (TokenType::Colon as i16, ":"),
+  (TokenType::Comma as i16, ","),
+  (TokenType::Every as i16, "every"),
+  (TokenType::For as i16, "for"),
+  (TokenType::LeftBrace as i16, "{"),
+  (TokenType::Null as i16, "null"),
+  (TokenType::RightArrow as i16, "->"),
+  (TokenType::Of as i16, "of"),
+  (TokenType::List as i16, "list"),
+  (TokenType::Range as i16, "range"),
+  (TokenType::Context as i16, "context"),
+  (TokenType::Then as i16, "then"),
+  (TokenType::Function as i16, "function"),
+  (TokenType::External as i16, "external"),
+  (TokenType::If as i16, "if"),
+  (TokenType::RightBrace as i16, "}"),
+  (TokenType::RightBracket as i16, "]"),
+  (TokenType::RightParen as i16, ")"),
+  (TokenType::Return as i16, "return"),
+  (TokenType::Ellipsis as i16, ".."),
+  (TokenType::Some as i16, "some"),
+  (TokenType::Numeric as i16, "number"),
+  (TokenType::String as i16, "string"),
+  (TokenType::Boolean as i16, "boolean"),
+  (TokenType::Satisfies as i16, "satisfies"),
+  (TokenType::Else as i16, "else"),
+  (TokenType::Or as i16, "or"),
+  (TokenType::And as i16, "and"),
+  (TokenType::Eq as i16, "="),
+  (TokenType::Nq as i16, "!="),
+  (TokenType::Lt as i16, "<"),
+  (TokenType::Le as i16, "<="),
+  (TokenType::Gt as i16, ">"),
+  (TokenType::Ge as i16, ">="),
+  (TokenType::Between as i16, "between"),
+  (TokenType::BetweenAnd as i16, "and"),
+  (TokenType::In as i16, "in"),
+  (TokenType::Minus as i16, "-"),
+  (TokenType::Plus as i16, "+"),
+  (TokenType::Mul as i16, "*"),
+  (TokenType::Div as i16, "/"),
+  (TokenType::Exp as i16, "**"),
+  (TokenType::Instance as i16, "instance"),
+  (TokenType::Name as i16, "name"),
+  (TokenType::NameDateTime as i16, "name"),
+  (TokenType::BuiltInTypeName as i16, "type name"),
+  (TokenType::LeftParen as i16, "("),
+  (TokenType::LeftBracket as i16, "["),
+  (TokenType::Dot as i16, "."),
+];
+
 impl<'parser> ReduceActions for Parser<'parser> {
   ///
   fn action_addition(&mut self) -> Result<()> {
@@ -275,6 +520,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     let rhs = self.yy_node_stack.pop().unwrap();
     let lhs = self.yy_node_stack.pop().unwrap();
     self.yy_node_stack.push(AstNode::Add(Box::new(lhs), Box::new(rhs)));
+    self.reduce_spans(2);
     Ok(())
   }
 
@@ -285,6 +531,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     let mhs = self.yy_node_stack.pop().unwrap();
     let lhs = self.yy_node_stack.pop().unwrap();
     self.yy_node_stack.push(AstNode::Between(Box::new(lhs), Box::new(mhs), Box::new(rhs)));
+    self.reduce_spans(3);
     Ok(())
   }
 
@@ -300,6 +547,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     trace_action!(self, "built_in_type_name");
     if let TokenValue::BuiltInTypeName(name) = &self.yy_value_stack[self.yy_value_stack.len() - 1] {
       self.yy_node_stack.push(AstNode::FeelType(name.into()));
+      self.reduce_spans(0);
     }
     Ok(())
   }
@@ -310,6 +558,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     let rhs = self.yy_node_stack.pop().unwrap();
     let lhs = self.yy_node_stack.pop().unwrap();
     self.yy_node_stack.push(AstNode::Eq(Box::new(lhs), Box::new(rhs)));
+    self.reduce_spans(2);
     Ok(())
   }
 
@@ -319,6 +568,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     let rhs = self.yy_node_stack.pop().unwrap();
     let lhs = self.yy_node_stack.pop().unwrap();
     self.yy_node_stack.push(AstNode::Ge(Box::new(lhs), Box::new(rhs)));
+    self.reduce_spans(2);
     Ok(())
   }
 
@@ -328,6 +578,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     let rhs = self.yy_node_stack.pop().unwrap();
     let lhs = self.yy_node_stack.pop().unwrap();
     self.yy_node_stack.push(AstNode::Gt(Box::new(lhs), Box::new(rhs)));
+    self.reduce_spans(2);
     Ok(())
   }
 
@@ -337,6 +588,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     let rhs = self.yy_node_stack.pop().unwrap();
     let lhs = self.yy_node_stack.pop().unwrap();
     self.yy_node_stack.push(AstNode::In(Box::new(lhs), Box::new(rhs)));
+    self.reduce_spans(2);
     Ok(())
   }
 
@@ -346,6 +598,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     let rhs = self.yy_node_stack.pop().unwrap();
     let lhs = self.yy_node_stack.pop().unwrap();
     self.yy_node_stack.push(AstNode::Le(Box::new(lhs), Box::new(rhs)));
+    self.reduce_spans(2);
     Ok(())
   }
 
@@ -355,6 +608,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     let rhs = self.yy_node_stack.pop().unwrap();
     let lhs = self.yy_node_stack.pop().unwrap();
     self.yy_node_stack.push(AstNode::Lt(Box::new(lhs), Box::new(rhs)));
+    self.reduce_spans(2);
     Ok(())
   }
 
@@ -364,6 +618,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     let rhs = self.yy_node_stack.pop().unwrap();
     let lhs = self.yy_node_stack.pop().unwrap();
     self.yy_node_stack.push(AstNode::Nq(Box::new(lhs), Box::new(rhs)));
+    self.reduce_spans(2);
     Ok(())
   }
 
@@ -372,6 +627,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     trace_action!(self, "comparison_unary_ge");
     let lhs = self.yy_node_stack.pop().unwrap();
     self.yy_node_stack.push(AstNode::UnaryGe(Box::new(lhs)));
+    self.reduce_spans(1);
     Ok(())
   }
 
@@ -380,6 +636,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     trace_action!(self, "comparison_unary_gt");
     let lhs = self.yy_node_stack.pop().unwrap();
     self.yy_node_stack.push(AstNode::UnaryGt(Box::new(lhs)));
+    self.reduce_spans(1);
     Ok(())
   }
 
@@ -388,6 +645,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     trace_action!(self, "comparison_unary_le");
     let lhs = self.yy_node_stack.pop().unwrap();
     self.yy_node_stack.push(AstNode::UnaryLe(Box::new(lhs)));
+    self.reduce_spans(1);
     Ok(())
   }
 
@@ -396,6 +654,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     trace_action!(self, "comparison_unary_lt");
     let lhs = self.yy_node_stack.pop().unwrap();
     self.yy_node_stack.push(AstNode::UnaryLt(Box::new(lhs)));
+    self.reduce_spans(1);
     Ok(())
   }
 
@@ -405,6 +664,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     let rhs = self.yy_node_stack.pop().unwrap();
     let lhs = self.yy_node_stack.pop().unwrap();
     self.yy_node_stack.push(AstNode::And(Box::new(lhs), Box::new(rhs)));
+    self.reduce_spans(2);
     Ok(())
   }
 
@@ -431,6 +691,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
       self.yy_lexer.add_name_to_scope(name);
     }
     self.yy_node_stack.push(AstNode::ContextEntry(Box::new(key_node), Box::new(value_node)));
+    self.reduce_spans(2);
     Ok(())
   }
 
@@ -442,9 +703,11 @@ impl<'parser> ReduceActions for Parser<'parser> {
       let item = self.yy_node_stack.pop().unwrap();
       items.insert(0, item);
       self.yy_node_stack.push(AstNode::Context(items));
+      self.prepend_span();
       return Ok(());
     }
     self.yy_node_stack.push(AstNode::Context(vec![node]));
+    self.list_spans();
     Ok(())
   }
 
@@ -456,6 +719,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
       let lhs = Box::new(AstNode::ContextTypeEntryKey(name.clone()));
       let rhs = Box::new(type_node);
       self.yy_node_stack.push(AstNode::ContextTypeEntry(lhs, rhs));
+      self.push_leading_span(self.location(self.yy_len as usize), 1);
     }
     Ok(())
   }
@@ -468,9 +732,11 @@ impl<'parser> ReduceActions for Parser<'parser> {
       let item = self.yy_node_stack.pop().unwrap();
       items.insert(0, item);
       self.yy_node_stack.push(AstNode::ContextType(items));
+      self.prepend_span();
       return Ok(());
     }
     self.yy_node_stack.push(AstNode::ContextType(vec![node]));
+    self.list_spans();
     Ok(())
   }
 
@@ -480,6 +746,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     let rhs = self.yy_node_stack.pop().unwrap();
     let lhs = self.yy_node_stack.pop().unwrap();
     self.yy_node_stack.push(AstNode::Or(Box::new(lhs), Box::new(rhs)));
+    self.reduce_spans(2);
     Ok(())
   }
 
@@ -489,6 +756,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     let rhs = self.yy_node_stack.pop().unwrap();
     let lhs = self.yy_node_stack.pop().unwrap();
     self.yy_node_stack.push(AstNode::Div(Box::new(lhs), Box::new(rhs)));
+    self.reduce_spans(2);
     Ok(())
   }
 
@@ -496,6 +764,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
   fn action_empty_context(&mut self) -> Result<()> {
     trace_action!(self, "empty context");
     self.yy_node_stack.push(AstNode::Context(vec![]));
+    self.reduce_spans(0);
     Ok(())
   }
 
@@ -508,6 +777,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     let lhs = self.yy_node_stack.pop().unwrap();
     let satisfies = Box::new(AstNode::Satisfies(Box::new(rhs)));
     self.yy_node_stack.push(AstNode::Every(Box::new(lhs), satisfies));
+    self.wrap_top_span_and_reduce();
     Ok(())
   }
 
@@ -527,6 +797,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     let rhs = self.yy_node_stack.pop().unwrap();
     let lhs = self.yy_node_stack.pop().unwrap();
     self.yy_node_stack.push(AstNode::Exp(Box::new(lhs), Box::new(rhs)));
+    self.reduce_spans(2);
     Ok(())
   }
 
@@ -538,9 +809,11 @@ impl<'parser> ReduceActions for Parser<'parser> {
       let item = self.yy_node_stack.pop().unwrap();
       items.insert(0, item);
       self.yy_node_stack.push(AstNode::ExpressionList(items));
+      self.prepend_span();
       return Ok(());
     }
     self.yy_node_stack.push(AstNode::ExpressionList(vec![node]));
+    self.list_spans();
     Ok(())
   }
 
@@ -550,6 +823,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     let rhs = self.yy_node_stack.pop().unwrap();
     let lhs = self.yy_node_stack.pop().unwrap();
     self.yy_node_stack.push(AstNode::Filter(Box::new(lhs), Box::new(rhs)));
+    self.reduce_spans(2);
     Ok(())
   }
 
@@ -564,6 +838,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     let lhs = self.yy_node_stack.pop().unwrap();
     let evaluated_expression = AstNode::EvaluatedExpression(Box::new(rhs));
     self.yy_node_stack.push(AstNode::For(Box::new(lhs), Box::new(evaluated_expression)));
+    self.wrap_top_span_and_reduce();
     Ok(())
   }
 
@@ -594,6 +869,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
       // set the name of the parameter to local context on the top of the scope stack
       // this name will be properly interpreted as a name while parsing the function body
       self.scope.set_name(name.to_owned());
+      self.push_leading_span(self.location(self.yy_len as usize), 1);
     }
     Ok(())
   }
@@ -610,6 +886,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
       // set the name of the parameter to local context on the top of the scope stack
       // this name will be properly interpreted as a name while parsing the function body
       self.scope.set_name(name.to_owned());
+      self.push_spans(vec![SpanNode::leaf(self.location(self.yy_len as usize)); 2]);
     }
     Ok(())
   }
@@ -627,6 +904,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     trace_action!(self, "function_formal_parameters_empty");
     // push the empty list of formal parameters onto the node stack
     self.yy_node_stack.push(AstNode::FormalParameters(vec![]));
+    self.reduce_spans(0);
     Ok(())
   }
 
@@ -636,6 +914,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     // the first parameter is on the top of the node stack
     let lhs = self.yy_node_stack.pop().unwrap();
     self.yy_node_stack.push(AstNode::FormalParameters(vec![lhs]));
+    self.list_spans();
     Ok(())
   }
 
@@ -648,6 +927,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     if let Some(AstNode::FormalParameters(mut items)) = self.yy_node_stack.pop() {
       items.push(rhs);
       self.yy_node_stack.push(AstNode::FormalParameters(items));
+      self.append_span();
     }
     Ok(())
   }
@@ -661,6 +941,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     trace_action!(self, "function_body");
     if let Some(function_body_node) = self.yy_node_stack.pop() {
       self.yy_node_stack.push(AstNode::FunctionBody(Box::new(function_body_node), false));
+      self.reduce_spans(1);
     }
     // pop temporary context from the top of scope stack
     self.scope.pop();
@@ -676,6 +957,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     trace_action!(self, "function_body_external");
     if let Some(function_body_node) = self.yy_node_stack.pop() {
       self.yy_node_stack.push(AstNode::FunctionBody(Box::new(function_body_node), true));
+      self.reduce_spans(1);
     }
     // pop temporary context from the top of scope stack
     self.scope.pop();
@@ -690,6 +972,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     let rhs = self.yy_node_stack.pop().unwrap();
     let lhs = self.yy_node_stack.pop().unwrap();
     self.yy_node_stack.push(AstNode::FunctionDefinition(Box::new(lhs), Box::new(rhs)));
+    self.reduce_spans(2);
     Ok(())
   }
 
@@ -699,6 +982,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     let rhs = self.yy_node_stack.pop().unwrap();
     let lhs = self.yy_node_stack.pop().unwrap();
     self.yy_node_stack.push(AstNode::FunctionInvocation(Box::new(lhs), Box::new(rhs)));
+    self.reduce_spans(2);
     Ok(())
   }
 
@@ -708,6 +992,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     if let Some(lhs) = self.yy_node_stack.pop() {
       let rhs = AstNode::PositionalParameters(vec![]);
       self.yy_node_stack.push(AstNode::FunctionInvocation(Box::new(lhs), Box::new(rhs)));
+      self.push_trailing_span(self.location(1));
     }
     Ok(())
   }
@@ -718,6 +1003,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     let rhs = self.yy_node_stack.pop().unwrap();
     let lhs = self.yy_node_stack.pop().unwrap();
     self.yy_node_stack.push(AstNode::FunctionType(Box::new(lhs), Box::new(rhs)));
+    self.reduce_spans(2);
     Ok(())
   }
 
@@ -725,6 +1011,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
   fn action_function_type_parameters_empty(&mut self) -> Result<()> {
     trace_action!(self, "function_type_parameters_empty");
     self.yy_node_stack.push(AstNode::ParameterTypes(vec![]));
+    self.reduce_spans(0);
     Ok(())
   }
 
@@ -736,9 +1023,11 @@ impl<'parser> ReduceActions for Parser<'parser> {
       let item = self.yy_node_stack.pop().unwrap();
       items.insert(0, item);
       self.yy_node_stack.push(AstNode::ParameterTypes(items));
+      self.prepend_span();
       return Ok(());
     }
     self.yy_node_stack.push(AstNode::ParameterTypes(vec![node]));
+    self.list_spans();
     Ok(())
   }
 
@@ -749,6 +1038,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     let mid = self.yy_node_stack.pop().unwrap();
     let lhs = self.yy_node_stack.pop().unwrap();
     self.yy_node_stack.push(AstNode::If(Box::new(lhs), Box::new(mid), Box::new(rhs)));
+    self.reduce_spans(3);
     Ok(())
   }
 
@@ -760,6 +1050,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     let checked_value = Box::new(lhs);
     let expected_type = Box::new(rhs);
     self.yy_node_stack.push(AstNode::InstanceOf(checked_value, expected_type));
+    self.reduce_spans(2);
     Ok(())
   }
 
@@ -769,6 +1060,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     let rhs = self.yy_node_stack.pop().unwrap();
     let lhs = self.yy_node_stack.pop().unwrap();
     self.yy_node_stack.push(AstNode::Range(Box::new(lhs), Box::new(rhs)));
+    self.reduce_spans(2);
     Ok(())
   }
 
@@ -778,6 +1070,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     let closed = matches!(&self.yy_value_stack[self.yy_value_stack.len() - 1], TokenValue::RightBracket);
     let lhs = self.yy_node_stack.pop().unwrap();
     self.yy_node_stack.push(AstNode::IntervalEnd(Box::new(lhs), closed));
+    self.reduce_spans(1);
     Ok(())
   }
 
@@ -787,6 +1080,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     let closed = matches!(&self.yy_value_stack[self.yy_value_stack.len() - self.yy_len as usize], TokenValue::LeftBracket);
     let lhs = self.yy_node_stack.pop().unwrap();
     self.yy_node_stack.push(AstNode::IntervalStart(Box::new(lhs), closed));
+    self.reduce_spans(1);
     Ok(())
   }
 
@@ -802,6 +1096,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     let lhs = self.yy_node_stack.pop().unwrap();
     let node = AstNode::IterationContextRange(Box::new(lhs), Box::new(mid), Box::new(rhs));
     self.yy_node_stack.push(node);
+    self.reduce_spans(3);
     Ok(())
   }
 
@@ -815,6 +1110,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     let lhs = self.yy_node_stack.pop().unwrap();
     let node = AstNode::IterationContextSingle(Box::new(lhs), Box::new(rhs));
     self.yy_node_stack.push(node);
+    self.reduce_spans(2);
     Ok(())
   }
 
@@ -827,6 +1123,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
       self.yy_node_stack.push(AstNode::Name(name.clone()));
       // add this variable name to the temporary context present on top of the scope
       self.yy_lexer.add_name_to_scope(name);
+      self.reduce_spans(0);
     }
     Ok(())
   }
@@ -846,9 +1143,11 @@ impl<'parser> ReduceActions for Parser<'parser> {
       let item = self.yy_node_stack.pop().unwrap();
       items.insert(0, item);
       self.yy_node_stack.push(AstNode::IterationContexts(items));
+      self.prepend_span();
       return Ok(());
     }
     self.yy_node_stack.push(AstNode::IterationContexts(vec![node]));
+    self.list_spans();
     Ok(())
   }
 
@@ -857,6 +1156,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     trace_action!(self, "key_name");
     if let Some(TokenValue::Name(name)) = self.yy_value_stack.last() {
       self.yy_node_stack.push(AstNode::ContextEntryKey(name.clone()));
+      self.reduce_spans(0);
     }
     Ok(())
   }
@@ -866,6 +1166,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     trace_action!(self, "key_string");
     if let Some(TokenValue::String(value)) = self.yy_value_stack.last() {
       self.yy_node_stack.push(AstNode::ContextEntryKey(Name::from(value.clone())));
+      self.reduce_spans(0);
     }
     Ok(())
   }
@@ -875,6 +1176,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     trace_action!(self, "list");
     if let Some(AstNode::CommaList(items)) = self.yy_node_stack.pop() {
       self.yy_node_stack.push(AstNode::List(items));
+      self.relocate_span();
     }
     Ok(())
   }
@@ -883,6 +1185,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
   fn action_list_empty(&mut self) -> Result<()> {
     trace_action!(self, "list_empty");
     self.yy_node_stack.push(AstNode::CommaList(vec![]));
+    self.reduce_spans(0);
     Ok(())
   }
 
@@ -894,9 +1197,11 @@ impl<'parser> ReduceActions for Parser<'parser> {
       let item = self.yy_node_stack.pop().unwrap();
       items.insert(0, item);
       self.yy_node_stack.push(AstNode::CommaList(items));
+      self.prepend_span();
       return Ok(());
     }
     self.yy_node_stack.push(AstNode::CommaList(vec![node]));
+    self.list_spans();
     Ok(())
   }
 
@@ -905,6 +1210,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     trace_action!(self, "list_type");
     let lhs = self.yy_node_stack.pop().unwrap();
     self.yy_node_stack.push(AstNode::ListType(Box::new(lhs)));
+    self.reduce_spans(1);
     Ok(())
   }
 
@@ -913,6 +1219,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     trace_action!(self, "literal_at");
     if let Some(TokenValue::String(value)) = self.yy_value_stack.last() {
       self.yy_node_stack.push(AstNode::At(value.clone()));
+      self.reduce_spans(0);
     }
     Ok(())
   }
@@ -922,6 +1229,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     trace_action!(self, "literal_boolean");
     if let Some(TokenValue::Boolean(value)) = self.yy_value_stack.last() {
       self.yy_node_stack.push(AstNode::Boolean(*value));
+      self.reduce_spans(0);
     }
     Ok(())
   }
@@ -931,6 +1239,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     trace_action!(self, "literal_date_time");
     if let TokenValue::NameDateTime(name) = &self.yy_value_stack[self.yy_value_stack.len() - 2] {
       self.yy_node_stack.push(AstNode::Name(name.clone()));
+      self.reduce_spans(0);
     }
     Ok(())
   }
@@ -940,6 +1249,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     trace_action!(self, "literal_null");
     if let Some(TokenValue::Null) = self.yy_value_stack.last() {
       self.yy_node_stack.push(AstNode::Null);
+      self.reduce_spans(0);
     }
     Ok(())
   }
@@ -949,6 +1259,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     trace_action!(self, "numeric_literal");
     if let Some(TokenValue::Numeric(before, after)) = self.yy_value_stack.last() {
       self.yy_node_stack.push(AstNode::Numeric(before.clone(), after.clone()));
+      self.reduce_spans(0);
     }
     Ok(())
   }
@@ -958,6 +1269,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     trace_action!(self, "string_literal");
     if let Some(TokenValue::String(value)) = self.yy_value_stack.last() {
       self.yy_node_stack.push(AstNode::String(value.clone()));
+      self.reduce_spans(0);
     }
     Ok(())
   }
@@ -968,6 +1280,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     let rhs = self.yy_node_stack.pop().unwrap();
     let lhs = self.yy_node_stack.pop().unwrap();
     self.yy_node_stack.push(AstNode::Mul(Box::new(lhs), Box::new(rhs)));
+    self.reduce_spans(2);
     Ok(())
   }
 
@@ -976,6 +1289,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     trace_action!(self, "name");
     if let Some(TokenValue::Name(value)) = self.yy_value_stack.last() {
       self.yy_node_stack.push(AstNode::Name(value.clone()));
+      self.reduce_spans(0);
     }
     Ok(())
   }
@@ -989,6 +1303,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
       let parameter_name = Box::new(AstNode::ParameterName(name.clone()));
       let parameter_value = Box::new(rhs);
       self.yy_node_stack.push(AstNode::NamedParameter(parameter_name, parameter_value));
+      self.push_leading_span(self.location(3), 1);
     }
     Ok(())
   }
@@ -1001,9 +1316,11 @@ impl<'parser> ReduceActions for Parser<'parser> {
       let item = self.yy_node_stack.pop().unwrap();
       items.insert(0, item);
       self.yy_node_stack.push(AstNode::NamedParameters(items));
+      self.prepend_span();
       return Ok(());
     }
     self.yy_node_stack.push(AstNode::NamedParameters(vec![node]));
+    self.list_spans();
     Ok(())
   }
 
@@ -1012,6 +1329,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     trace_action!(self, "negation");
     if let Some(node) = self.yy_node_stack.pop() {
       self.yy_node_stack.push(AstNode::Neg(Box::new(node)));
+      self.reduce_spans(1);
     }
     Ok(())
   }
@@ -1023,6 +1341,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     if let Some(TokenValue::Name(name)) = &self.yy_value_stack.last() {
       let rhs = AstNode::Name(name.clone());
       self.yy_node_stack.push(AstNode::Path(Box::new(lhs), Box::new(rhs)));
+      self.push_trailing_span(self.location(1));
     }
     Ok(())
   }
@@ -1034,6 +1353,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     if let TokenValue::Name(name) = &self.yy_value_stack[self.yy_value_stack.len() - 3] {
       let lhs = AstNode::Name(name.clone());
       self.yy_node_stack.push(AstNode::Path(Box::new(lhs), Box::new(rhs)));
+      self.push_leading_span(self.location(3), 1);
     }
     Ok(())
   }
@@ -1043,6 +1363,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     trace_action!(self, "path_segment_tail");
     if let Some(TokenValue::Name(name)) = &self.yy_value_stack.last() {
       self.yy_node_stack.push(AstNode::Name(name.clone()));
+      self.reduce_spans(0);
     }
     Ok(())
   }
@@ -1055,9 +1376,11 @@ impl<'parser> ReduceActions for Parser<'parser> {
       let item = self.yy_node_stack.pop().unwrap();
       items.insert(0, item);
       self.yy_node_stack.push(AstNode::PositionalParameters(items));
+      self.prepend_span();
       return Ok(());
     }
     self.yy_node_stack.push(AstNode::PositionalParameters(vec![node]));
+    self.list_spans();
     Ok(())
   }
 
@@ -1066,6 +1389,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     trace_action!(self, "action_qualified_name");
     if let Some(TokenValue::Name(name)) = &self.yy_value_stack.last() {
       self.yy_node_stack.push(AstNode::QualifiedName(vec![AstNode::QualifiedNameSegment(name.clone())]));
+      self.push_spans(vec![SpanNode::leaf(self.location(1))]);
     }
     Ok(())
   }
@@ -1077,6 +1401,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
       if let Some(AstNode::QualifiedName(mut parts)) = self.yy_node_stack.pop() {
         parts.insert(0, AstNode::QualifiedNameSegment(name.clone()));
         self.yy_node_stack.push(AstNode::QualifiedName(parts));
+        self.prepend_leaf_span(self.location(3));
       }
     }
     Ok(())
@@ -1089,6 +1414,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     let lhs = self.yy_node_stack.pop().unwrap();
     let node = AstNode::QuantifiedContext(Box::new(lhs), Box::new(rhs));
     self.yy_node_stack.push(node);
+    self.reduce_spans(2);
     Ok(())
   }
 
@@ -1101,6 +1427,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
       self.yy_node_stack.push(AstNode::Name(name.clone()));
       // add this variable name to the temporary context present on top of the scope
       self.yy_lexer.add_name_to_scope(name);
+      self.reduce_spans(0);
     }
     Ok(())
   }
@@ -1120,9 +1447,11 @@ impl<'parser> ReduceActions for Parser<'parser> {
       let item = self.yy_node_stack.pop().unwrap();
       items.insert(0, item);
       self.yy_node_stack.push(AstNode::QuantifiedContexts(items));
+      self.prepend_span();
       return Ok(());
     }
     self.yy_node_stack.push(AstNode::QuantifiedContexts(vec![node]));
+    self.list_spans();
     Ok(())
   }
 
@@ -1131,6 +1460,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     trace_action!(self, "range_type");
     let lhs = self.yy_node_stack.pop().unwrap();
     self.yy_node_stack.push(AstNode::RangeType(Box::new(lhs)));
+    self.reduce_spans(1);
     Ok(())
   }
 
@@ -1143,6 +1473,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     let lhs = self.yy_node_stack.pop().unwrap();
     let satisfies = Box::new(AstNode::Satisfies(Box::new(rhs)));
     self.yy_node_stack.push(AstNode::Some(Box::new(lhs), satisfies));
+    self.wrap_top_span_and_reduce();
     Ok(())
   }
 
@@ -1162,6 +1493,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     let rhs = self.yy_node_stack.pop().unwrap();
     let lhs = self.yy_node_stack.pop().unwrap();
     self.yy_node_stack.push(AstNode::Sub(Box::new(lhs), Box::new(rhs)));
+    self.reduce_spans(2);
     Ok(())
   }
 
@@ -1183,6 +1515,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
   fn action_unary_tests_irrelevant(&mut self) -> Result<()> {
     trace_action!(self, "unary_tests_irrelevant");
     self.yy_node_stack.push(AstNode::Irrelevant);
+    self.reduce_spans(0);
     Ok(())
   }
 
@@ -1191,6 +1524,7 @@ impl<'parser> ReduceActions for Parser<'parser> {
     trace_action!(self, "unary_tests_negated");
     if let Some(AstNode::ExpressionList(items)) = self.yy_node_stack.pop() {
       self.yy_node_stack.push(AstNode::NegatedList(items));
+      self.relocate_span();
     }
     Ok(())
   }
@@ -0,0 +1,117 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Source spans of AST nodes.
+
+use crate::AstNode;
+use dmntk_common::Span;
+use std::collections::BTreeMap;
+
+/// Spans of AST nodes, keyed by paths of child indexes leading from the root node.
+///
+/// The root node has an empty path, the indexes of child nodes
+/// are the same as returned by [AstNode::children].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Spans(BTreeMap<Vec<usize>, Span>);
+
+impl Spans {
+  /// Returns the span of the node at specified path.
+  pub fn get(&self, path: &[usize]) -> Option<&Span> {
+    self.0.get(path)
+  }
+
+  /// Returns the span of the root node.
+  pub fn root(&self) -> Option<&Span> {
+    self.0.get(&vec![])
+  }
+
+  /// Returns an iterator over paths and spans of all nodes.
+  pub fn iter(&self) -> impl Iterator<Item = (&Vec<usize>, &Span)> {
+    self.0.iter()
+  }
+
+  /// Returns the path of the innermost node whose span contains the character at specified offset.
+  pub fn find(&self, offset: usize) -> Option<Vec<usize>> {
+    self
+      .0
+      .iter()
+      .filter(|(_, span)| span.contains(offset))
+      .max_by_key(|(path, _)| path.len())
+      .map(|(path, _)| path.clone())
+  }
+}
+
+/// Tree of spans built while parsing, mirroring the structure of the AST.
+#[derive(Debug, Clone)]
+pub(crate) struct SpanNode {
+  /// Span of the node.
+  pub span: Span,
+  /// Spans of child nodes.
+  pub children: Vec<SpanNode>,
+}
+
+impl SpanNode {
+  /// Creates a span node with specified children.
+  pub fn new(span: Span, children: Vec<SpanNode>) -> Self {
+    Self { span, children }
+  }
+
+  /// Creates a span node without children.
+  pub fn leaf(span: Span) -> Self {
+    Self { span, children: vec![] }
+  }
+
+  /// Returns `true` when this tree of spans has the same shape as the tree of specified AST node.
+  pub fn conforms(&self, node: &AstNode) -> bool {
+    let children = node.children();
+    self.children.len() == children.len() && self.children.iter().zip(children).all(|(span_node, child)| span_node.conforms(child))
+  }
+
+  /// Adds spans of this node and all its descendants to the map.
+  fn collect(&self, path: &mut Vec<usize>, spans: &mut BTreeMap<Vec<usize>, Span>) {
+    spans.insert(path.clone(), self.span);
+    for (index, child) in self.children.iter().enumerate() {
+      path.push(index);
+      child.collect(path, spans);
+      path.pop();
+    }
+  }
+}
+
+impl From<&SpanNode> for Spans {
+  /// Converts the tree of spans into [Spans].
+  fn from(span_node: &SpanNode) -> Self {
+    let mut spans = BTreeMap::new();
+    span_node.collect(&mut vec![], &mut spans);
+    Self(spans)
+  }
+}
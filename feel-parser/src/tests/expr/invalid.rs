@@ -46,20 +46,20 @@ fn te(input: &str, source: &str, message: &str) {
 #[test]
 fn _0001() {
   // Caret character is not valid at the beginning of any FEEL statement.
-  te(r#"^123"#, "ParserError", r#"syntax error: ^123"#);
+  te(r#"^123"#, "ParserError", r#"syntax error: ^123, unexpected '^' at 1:1"#);
 }
 
 #[test]
 fn _0002() {
   // Vertical space is not allowed inside FEEL string.
   let input = from_utf8(&[34, 49, 50, 10, 51, 34]).unwrap(); // "12\n3"
-  te(input, "ParserError", "syntax error: \"12\n3\"");
+  te(input, "ParserError", "syntax error: \"12\n3\", unexpected '\"12' at 1:1");
 }
 
 #[test]
 fn _0003() {
   // Unexpected end of input before the FEEL string is closed with quotation mark `"`.
-  te(r#""123"#, "ParserError", "syntax error: \"123");
+  te(r#""123"#, "ParserError", "syntax error: \"123, unexpected end of input at 1:1");
 }
 
 #[test]
@@ -71,19 +71,19 @@ fn _0004() {
 #[test]
 fn _0005() {
   // After decimal point must always be a digit.
-  te(r#"1. + 2"#, "ParserError", "syntax error: 1. + 2");
+  te(r#"1. + 2"#, "ParserError", "syntax error: 1. + 2, unexpected '+' at 1:4, expected 'name'");
 }
 
 #[test]
 fn _0006() {
   // After decimal point must be a digit.
-  te(r#"1.^ + 2"#, "ParserError", "syntax error: 1.^ + 2");
+  te(r#"1.^ + 2"#, "ParserError", "syntax error: 1.^ + 2, unexpected '^' at 1:3, expected 'name'");
 }
 
 #[test]
 fn _0007() {
   // End of input after decimal point.
-  te(r#"1."#, "ParserError", "syntax error: 1.");
+  te(r#"1."#, "ParserError", "syntax error: 1., unexpected end of input at 1:3, expected 'name'");
 }
 
 #[test]
@@ -118,7 +118,7 @@ fn _0010() {
 fn _0011() {
   let scope = scope!();
   assert_eq!(
-    "<ParserError> syntax error: 1 += 2",
+    "<ParserError> syntax error: 1 += 2, unexpected '=' at 1:4",
     Parser::new(&scope, StartExpression, "1 += 2", false).parse().err().unwrap().to_string().as_str()
   );
 }
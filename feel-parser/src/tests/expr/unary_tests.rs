@@ -209,7 +209,7 @@ fn _0009() {
 fn _00010() {
   let scope = scope!();
   assert_eq!(
-    Err(DmntkError::new(r#"ParserError"#, r#"syntax error: (1,2,3,4)"#)),
+    Err(DmntkError::new(r#"ParserError"#, r#"syntax error: (1,2,3,4), unexpected ',' at 1:3"#)),
     Parser::new(&scope, StartUnaryTests, "(1,2,3,4)", false).parse()
   );
 }
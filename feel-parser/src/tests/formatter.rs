@@ -0,0 +1,176 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{parse_expression, parse_unary_tests, FeelFormatter};
+use dmntk_feel::values::Value;
+use dmntk_feel::{scope, FeelScope, Name};
+
+/// Formats the expression and checks the result, then checks if formatting
+/// is idempotent and if the formatted expression is parsed into the same AST.
+fn check(formatter: &FeelFormatter, scope: &FeelScope, input: &str, expected: &str) {
+  let node = parse_expression(scope, input, false).unwrap();
+  let actual = formatter.format(&node);
+  assert_eq!(expected, actual);
+  let reparsed = parse_expression(scope, &actual, false).unwrap();
+  assert_eq!(node, reparsed);
+  assert_eq!(actual, formatter.format(&reparsed));
+}
+
+/// Formats the expression using default formatter.
+fn check_default(input: &str, expected: &str) {
+  let scope = scope!();
+  scope.set_value(&Name::from("a"), Value::Null(None));
+  scope.set_value(&Name::from("b"), Value::Null(None));
+  scope.set_value(&Name::from("c"), Value::Null(None));
+  check(&FeelFormatter::default(), &scope, input, expected);
+}
+
+#[test]
+fn _0001() {
+  check_default("1+2*3", "1 + 2 * 3");
+  check_default("(1+2)*3", "(1 + 2) * 3");
+  check_default("1-(2-3)", "1 - (2 - 3)");
+  check_default("(1-2)-3", "1 - 2 - 3");
+  check_default("2**3**4", "2 ** 3 ** 4");
+  check_default("2**(3**4)", "2 ** (3 ** 4)");
+  check_default("-(1+2)", "-(1 + 2)");
+  check_default("-1.25", "-1.25");
+  check_default("- -a", "-(-a)");
+}
+
+#[test]
+fn _0002() {
+  check_default("a and b or c", "a and b or c");
+  check_default("a and (b or c)", "a and (b or c)");
+  check_default("(a = b) = c", "(a = b) = c");
+  check_default("a+1 >= b*2", "a + 1 >= b * 2");
+  check_default("a != null", "a != null");
+  check_default("a between 1 and 10", "a between 1 and 10");
+  check_default("a in [1,2,3]", "a in [1, 2, 3]");
+  check_default("a in (1,2,3)", "a in (1, 2, 3)");
+  check_default("a in [1..10)", "a in [1..10)");
+  check_default("a in ]1..10]", "a in (1..10]");
+  check_default("a in <= 5", "a in <= 5");
+}
+
+#[test]
+fn _0003() {
+  check_default(r#""Hello ""#, r#""Hello ""#);
+  check_default(r#""a\"b\\c\nd\te""#, r#""a\"b\\c\nd\te""#);
+  check_default(r#"@"2023-01-10T10:11:12""#, r#"@"2023-01-10T10:11:12""#);
+  check_default(r#"date( "2023-01-10" )"#, r#"date("2023-01-10")"#);
+  check_default(r#"true"#, r#"true"#);
+  check_default(r#"null"#, r#"null"#);
+}
+
+#[test]
+fn _0004() {
+  check_default("{a:1,b:{c:2}}", "{a: 1, b: {c: 2}}");
+  check_default(r#"{"first name":"John", "x+y": 1, "if": 2}"#, r#"{first name: "John", "x+y": 1, "if": 2}"#);
+  check_default("{}", "{}");
+  check_default("[]", "[]");
+  check_default("{a:1}.a", "{a: 1}.a");
+  check_default("[1,2,3][item > 1]", "[1, 2, 3][item > 1]");
+  check_default("[1,2,3][1]", "[1, 2, 3][1]");
+}
+
+#[test]
+fn _0005() {
+  check_default("if a>1 then \"big\" else \"small\"", r#"if a > 1 then "big" else "small""#);
+  check_default("(if a then 1 else 2) + 3", "(if a then 1 else 2) + 3");
+  check_default("for x in [1,2], y in 1..3 return x*y", "for x in [1, 2], y in 1..3 return x * y");
+  check_default("some x in [1,2] satisfies x > 1", "some x in [1, 2] satisfies x > 1");
+  check_default("every x in [1,2], y in [3] satisfies x < y", "every x in [1, 2], y in [3] satisfies x < y");
+}
+
+#[test]
+fn _0006() {
+  check_default("function(x,y:number) x+y", "function(x, y: number) x + y");
+  check_default(
+    r#"function(s: string) external {java: {class: "java.lang.Math", method signature: "max(double, double)"}}"#,
+    r#"function(s: string) external {java: {class: "java.lang.Math", method signature: "max(double, double)"}}"#,
+  );
+  check_default("{f: function(a) a * 2, r: f(a: 3)}", "{f: function(a) a * 2, r: f(a: 3)}");
+  check_default("substring(\"abc\", 2)", "substring(\"abc\", 2)");
+  check_default("count([1,2]) instance of number", "count([1, 2]) instance of number");
+  check_default(
+    "a instance of list<context<x: number, y: date and time>>",
+    "a instance of list<context<x: number, y: date and time>>",
+  );
+  check_default(
+    "a instance of function<string, number> -> range<date>",
+    "a instance of function<string, number> -> range<date>",
+  );
+}
+
+#[test]
+fn _0007() {
+  let formatter = FeelFormatter::new(2, 20);
+  let scope = scope!();
+  check(
+    &formatter,
+    &scope,
+    "{a: 1, b: [1, 2, 3, 4, 5, 6, 7, 8], c: {d: 2}}",
+    "{\n  a: 1,\n  b: [\n    1,\n    2,\n    3,\n    4,\n    5,\n    6,\n    7,\n    8\n  ],\n  c: {d: 2}\n}",
+  );
+}
+
+#[test]
+fn _0008() {
+  let formatter = FeelFormatter::new(4, 30);
+  let scope = scope!();
+  scope.set_value(&Name::from("score"), Value::Null(None));
+  check(
+    &formatter,
+    &scope,
+    r#"if score > 700 then "excellent" else if score > 500 then "good" else "poor""#,
+    "if score > 700 then\n    \"excellent\"\nelse if score > 500 then\n    \"good\"\nelse\n    \"poor\"",
+  );
+  check(
+    &formatter,
+    &scope,
+    "for i in 1..10 return if i > 5 then i * score else i",
+    "for i in 1..10 return\n    if i > 5 then\n        i * score\n    else\n        i",
+  );
+}
+
+#[test]
+fn _0009() {
+  let formatter = FeelFormatter::default();
+  let scope = scope!();
+  let node = parse_unary_tests(&scope, "< 10,[20..30],  \"a\"", false).unwrap();
+  assert_eq!(r#"< 10, [20..30], "a""#, formatter.format(&node));
+  let node = parse_unary_tests(&scope, "not( 1,2 )", false).unwrap();
+  assert_eq!("not(1, 2)", formatter.format(&node));
+  let node = parse_unary_tests(&scope, " - ", false).unwrap();
+  assert_eq!("-", formatter.format(&node));
+}
@@ -35,6 +35,9 @@ mod bifs;
 mod closure;
 mod context;
 mod expr;
+mod formatter;
+mod spans;
+mod type_checker;
 
 /// Creates a parsing scope.
 macro_rules! scope {
@@ -0,0 +1,128 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{parse_textual_expression_with_spans, parse_unary_tests_with_spans, AstNode, Spans, TypeChecker};
+use dmntk_feel::{scope, FeelScope, FeelType, Name};
+use std::collections::BTreeMap;
+
+/// Returns the text covered by the span of the node at specified path.
+fn text(input: &str, spans: &Spans, path: &[usize]) -> String {
+  let span = spans.get(path).unwrap();
+  input.chars().skip(span.start.offset).take(span.end.offset - span.start.offset).collect()
+}
+
+#[test]
+fn _0001() {
+  let input = r#"1 + foo(2, "b")"#;
+  let scope = scope!();
+  scope.set_value(&Name::from("foo"), dmntk_feel::values::Value::Null(None));
+  let (node, spans) = parse_textual_expression_with_spans(&scope, input, false).unwrap();
+  assert!(matches!(node, AstNode::Add(_, _)));
+  assert_eq!(input, text(input, &spans, &[]));
+  assert_eq!("1", text(input, &spans, &[0]));
+  assert_eq!(r#"foo(2, "b")"#, text(input, &spans, &[1]));
+  assert_eq!("foo", text(input, &spans, &[1, 0]));
+  assert_eq!(r#"2, "b""#, text(input, &spans, &[1, 1]));
+  assert_eq!(r#""b""#, text(input, &spans, &[1, 1, 1]));
+  assert_eq!("1:1..1:16", spans.root().unwrap().to_string());
+}
+
+#[test]
+fn _0002() {
+  let input = "if a > 1\nthen [1, 2]\nelse x.y";
+  let scope = scope!();
+  scope.set_value(&Name::from("a"), dmntk_feel::values::Value::Null(None));
+  scope.set_value(&Name::from("x"), dmntk_feel::values::Value::Null(None));
+  let (node, spans) = parse_textual_expression_with_spans(&scope, input, false).unwrap();
+  assert_eq!(3, node.children().len());
+  assert_eq!("a > 1", text(input, &spans, &[0]));
+  assert_eq!("[1, 2]", text(input, &spans, &[1]));
+  assert_eq!("2:6..2:12", spans.get(&[1]).unwrap().to_string());
+  assert_eq!("x.y", text(input, &spans, &[2]));
+  assert_eq!("y", text(input, &spans, &[2, 1]));
+  assert_eq!("3:8..3:9", spans.get(&[2, 1]).unwrap().to_string());
+}
+
+#[test]
+fn _0003() {
+  let input = "for i in 1..3 return i * 2";
+  let (node, spans) = parse_textual_expression_with_spans(&scope!(), input, false).unwrap();
+  assert_eq!(spans.iter().count(), count_nodes(&node));
+  assert_eq!("i", text(input, &spans, &[0, 0, 0]));
+  assert_eq!("i * 2", text(input, &spans, &[1, 0]));
+  assert_eq!(Some(vec![1, 0, 1]), spans.find(25));
+}
+
+#[test]
+fn _0004() {
+  let input = "< 10, [1..5], not(3)";
+  let (node, spans) = parse_unary_tests_with_spans(&scope!(), input, false).unwrap();
+  assert_eq!(spans.iter().count(), count_nodes(&node));
+  assert_eq!("< 10", text(input, &spans, &[0]));
+  assert_eq!("[1..5]", text(input, &spans, &[1]));
+}
+
+#[test]
+fn _0005() {
+  let syntax_error = parse_textual_expression_with_spans(&scope!(), "(1 + 2", false).unwrap_err();
+  assert_eq!(None, syntax_error.token);
+  assert_eq!("1:7..1:7", syntax_error.span.to_string());
+  assert_eq!(
+    vec![")".to_string()],
+    syntax_error.expected.iter().filter(|name| *name == ")").cloned().collect::<Vec<String>>()
+  );
+}
+
+#[test]
+fn _0006() {
+  let syntax_error = parse_textual_expression_with_spans(&scope!(), "1 +\n* 2", false).unwrap_err();
+  assert_eq!(Some("*".to_string()), syntax_error.token);
+  assert_eq!("2:1..2:2", syntax_error.span.to_string());
+  assert_eq!("<ParserError> syntax error: 1 +\n* 2, unexpected '*' at 2:1", syntax_error.error.to_string());
+}
+
+#[test]
+fn _0007() {
+  let input = "Birthday > 10";
+  let scope = scope!();
+  scope.set_value(&Name::from("Birthday"), dmntk_feel::values::Value::Null(None));
+  let (node, spans) = parse_textual_expression_with_spans(&scope, input, false).unwrap();
+  let mut inputs = BTreeMap::new();
+  inputs.insert(Name::from("Birthday"), FeelType::Date);
+  let type_check = TypeChecker::check(&node, &inputs);
+  assert_eq!("1:1..1:14", type_check.errors[0].span(&spans).unwrap().to_string());
+}
+
+/// Returns the number of nodes in the tree starting from specified node.
+fn count_nodes(node: &AstNode) -> usize {
+  1 + node.children().into_iter().map(count_nodes).sum::<usize>()
+}
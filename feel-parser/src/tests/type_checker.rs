@@ -0,0 +1,199 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{parse_expression, TypeCheck, TypeChecker};
+use dmntk_feel::{scope, FeelScope, FeelType, Name};
+use std::collections::BTreeMap;
+
+/// Parses the input expression and checks its types, using specified types of input names.
+fn check(inputs: &[(&str, FeelType)], input: &str) -> TypeCheck {
+  let scope = scope!();
+  let mut types = BTreeMap::new();
+  for (name, feel_type) in inputs {
+    scope.set_value(&Name::from(*name), dmntk_feel::values::Value::Null(None));
+    types.insert(Name::from(*name), feel_type.clone());
+  }
+  let node = parse_expression(&scope, input, false).unwrap();
+  TypeChecker::check(&node, &types)
+}
+
+/// Checks that the inferred type is as expected and there are no type errors.
+fn accept(inputs: &[(&str, FeelType)], input: &str, expected: &str) {
+  let type_check = check(inputs, input);
+  assert_eq!(Vec::<String>::new(), type_check.errors.iter().map(|e| e.to_string()).collect::<Vec<String>>());
+  assert_eq!(expected, type_check.feel_type.to_string());
+}
+
+/// Checks that the reported type errors are as expected.
+fn reject(inputs: &[(&str, FeelType)], input: &str, expected: &[&str]) {
+  let type_check = check(inputs, input);
+  assert_eq!(expected, type_check.errors.iter().map(|e| e.to_string()).collect::<Vec<String>>());
+}
+
+#[test]
+fn _0001() {
+  accept(&[], "1 + 2 * 3", "number");
+}
+
+#[test]
+fn _0002() {
+  accept(&[], r#""a" + "b""#, "string");
+}
+
+#[test]
+fn _0003() {
+  accept(&[("Birthday", FeelType::Date)], r#"Birthday + duration("P1Y")"#, "Any");
+}
+
+#[test]
+fn _0004() {
+  accept(&[("Birthday", FeelType::Date)], r#"Birthday - @"2000-01-01""#, "days and time duration");
+}
+
+#[test]
+fn _0005() {
+  reject(&[("Birthday", FeelType::Date)], "Birthday > 10", &["[] cannot compare date with number"]);
+}
+
+#[test]
+fn _0006() {
+  reject(&[("Age", FeelType::Number)], r#"Age + "years""#, &["[] operator '+' is not defined for number and string"]);
+}
+
+#[test]
+fn _0007() {
+  reject(
+    &[],
+    r#"substring("abc", 1, 2, 3)"#,
+    &["[] invalid number of parameters for function 'substring', expected 2,3, actual number of parameters is 4"],
+  );
+}
+
+#[test]
+fn _0008() {
+  reject(
+    &[],
+    "substring(1, 2)",
+    &["[1.0] invalid argument type for function 'substring', expected string, actual type is number"],
+  );
+}
+
+#[test]
+fn _0009() {
+  reject(&[("Age", FeelType::Number)], "Age.value", &["[1] path expression is not allowed on value of type number"]);
+}
+
+#[test]
+fn _0010() {
+  let person = FeelType::context(&[(&Name::from("name"), &FeelType::String), (&Name::from("age"), &FeelType::Number)]);
+  accept(&[("Person", person)], "Person.age", "number");
+}
+
+#[test]
+fn _0011() {
+  let person = FeelType::context(&[(&Name::from("name"), &FeelType::String)]);
+  reject(&[("Person", person)], "Person.age", &["[1] context has no entry named 'age'"]);
+}
+
+#[test]
+fn _0012() {
+  accept(&[], r#"{ a: 1, b: a + 1, c: "x" }"#, "context<a: number, b: number, c: string>");
+}
+
+#[test]
+fn _0013() {
+  accept(&[], "[1, 2, 3][item > 1]", "list<number>");
+}
+
+#[test]
+fn _0014() {
+  accept(&[], "[1, 2, 3][1]", "number");
+}
+
+#[test]
+fn _0015() {
+  accept(&[], "for i in 1..3 return i * 2", "list<number>");
+}
+
+#[test]
+fn _0016() {
+  accept(&[], r#"if 1 > 2 then "a" else "b""#, "string");
+}
+
+#[test]
+fn _0017() {
+  reject(&[], r#"if 1 then "a" else "b""#, &["[0] expected boolean condition, actual type is number"]);
+}
+
+#[test]
+fn _0018() {
+  accept(&[], "some x in [1, 2, 3] satisfies x > 2", "boolean");
+}
+
+#[test]
+fn _0019() {
+  accept(&[], "function(a: number, b: number) a + b", "function<number, number>->number");
+}
+
+#[test]
+fn _0020() {
+  reject(
+    &[],
+    r#"{ f: function(a, b) a + b, r: f(1) }"#,
+    &["[1.1] invalid number of parameters, expected 2, actual number of parameters is 1"],
+  );
+}
+
+#[test]
+fn _0021() {
+  reject(&[], "Unknown", &["[] unknown name 'Unknown'"]);
+}
+
+#[test]
+fn _0022() {
+  accept(&[("Birthday", FeelType::Date)], "Birthday.year", "number");
+}
+
+#[test]
+fn _0023() {
+  reject(&[("Birthday", FeelType::Date)], r#"Birthday in [1..10]"#, &["[1] cannot compare date with number"]);
+}
+
+#[test]
+fn _0024() {
+  accept(&[("Items", FeelType::list(&FeelType::Number))], "max(Items)", "number");
+}
+
+#[test]
+fn _0025() {
+  accept(&[], "[1, 2] instance of list<number>", "boolean");
+}
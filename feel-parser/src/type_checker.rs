@@ -0,0 +1,884 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Static type inference and type checking of `FEEL` expressions.
+
+use crate::{AstNode, Spans};
+use dmntk_common::Span;
+use dmntk_feel::bif::Bif;
+use dmntk_feel::{FeelType, Name};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// Type error detected while checking types of `FEEL` expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeError {
+  /// Position of the node that caused the error, expressed as the sequence of child indexes
+  /// leading from the root node of the AST to this node.
+  pub path: Vec<usize>,
+  /// Error message.
+  pub message: String,
+}
+
+impl TypeError {
+  /// Returns the span of the node that caused this error.
+  pub fn span<'a>(&self, spans: &'a Spans) -> Option<&'a Span> {
+    spans.get(&self.path)
+  }
+}
+
+impl fmt::Display for TypeError {
+  /// Converts [TypeError] into text representation.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let path = self.path.iter().map(|index| index.to_string()).collect::<Vec<String>>().join(".");
+    write!(f, "[{path}] {}", self.message)
+  }
+}
+
+/// Result of checking types of `FEEL` expression.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeCheck {
+  /// Inferred type of the expression.
+  pub feel_type: FeelType,
+  /// Type errors found in the expression, in order of appearance.
+  pub errors: Vec<TypeError>,
+}
+
+impl TypeCheck {
+  /// Returns `true` when no type errors were found.
+  pub fn is_ok(&self) -> bool {
+    self.errors.is_empty()
+  }
+}
+
+/// Type checker for `FEEL` expressions.
+pub struct TypeChecker {
+  /// Stack of scopes with types of visible names.
+  scopes: Vec<Scope>,
+  /// Path from the root node to currently visited node.
+  path: Vec<usize>,
+  /// Collected type errors.
+  errors: Vec<TypeError>,
+}
+
+/// Scope with types of visible names.
+#[derive(Default)]
+struct Scope {
+  /// Types of names defined in this scope.
+  names: BTreeMap<Name, FeelType>,
+  /// Flag indicating if this scope may contain names with unknown types,
+  /// like entries of list items with unknown type in filter expressions.
+  open: bool,
+}
+
+impl TypeChecker {
+  /// Infers the type of the expression represented by the specified node
+  /// and checks the types of all its subexpressions.
+  /// `inputs` contain the types of the names visible in the expression.
+  pub fn check(node: &AstNode, inputs: &BTreeMap<Name, FeelType>) -> TypeCheck {
+    let mut type_checker = Self {
+      scopes: vec![Scope {
+        names: inputs.clone(),
+        open: false,
+      }],
+      path: vec![],
+      errors: vec![],
+    };
+    let feel_type = type_checker.infer(node);
+    TypeCheck {
+      feel_type,
+      errors: type_checker.errors,
+    }
+  }
+
+  /// Infers the type of the child node with specified index.
+  fn visit(&mut self, index: usize, node: &AstNode) -> FeelType {
+    self.path.push(index);
+    let feel_type = self.infer(node);
+    self.path.pop();
+    feel_type
+  }
+
+  /// Reports type error at currently visited node.
+  fn error(&mut self, message: String) {
+    self.errors.push(TypeError { path: self.path.clone(), message });
+  }
+
+  /// Reports type error at the child of currently visited node.
+  fn error_at(&mut self, index: usize, message: String) {
+    self.path.push(index);
+    self.error(message);
+    self.path.pop();
+  }
+
+  /// Pushes a new scope with specified names.
+  fn push_scope(&mut self, names: BTreeMap<Name, FeelType>, open: bool) {
+    self.scopes.push(Scope { names, open });
+  }
+
+  /// Removes the scope from the top of the stack.
+  fn pop_scope(&mut self) {
+    self.scopes.pop();
+  }
+
+  /// Sets the type of a name in the scope on the top of the stack.
+  fn set_name(&mut self, name: &Name, feel_type: FeelType) {
+    if let Some(scope) = self.scopes.last_mut() {
+      scope.names.insert(name.clone(), feel_type);
+    }
+  }
+
+  /// Returns the type of the name, searching from the top of the scope stack.
+  fn resolve(&self, name: &Name) -> Option<FeelType> {
+    for scope in self.scopes.iter().rev() {
+      if let Some(feel_type) = scope.names.get(name) {
+        return Some(feel_type.clone());
+      }
+      if scope.open {
+        return Some(FeelType::Any);
+      }
+    }
+    None
+  }
+
+  /// Infers the type of the specified node.
+  fn infer(&mut self, node: &AstNode) -> FeelType {
+    match node {
+      AstNode::Add(lhs, rhs) => self.infer_arithmetic("+", lhs, rhs),
+      AstNode::And(lhs, rhs) | AstNode::Or(lhs, rhs) => self.infer_logical(lhs, rhs),
+      AstNode::At(text) => infer_at_literal(text),
+      AstNode::Between(lhs, mid, rhs) => self.infer_between(lhs, mid, rhs),
+      AstNode::Boolean(_) => FeelType::Boolean,
+      AstNode::CommaList(items) | AstNode::ExpressionList(items) => self.infer_list(items),
+      AstNode::Context(items) => self.infer_context(items),
+      AstNode::ContextEntry(_, rhs) => self.visit(1, rhs),
+      AstNode::ContextEntryKey(_) | AstNode::ContextTypeEntryKey(_) | AstNode::ParameterName(_) | AstNode::QualifiedNameSegment(_) => FeelType::Any,
+      AstNode::ContextType(_) | AstNode::FeelType(_) | AstNode::FunctionType(_, _) | AstNode::ListType(_) | AstNode::RangeType(_) => FeelType::Any,
+      AstNode::ContextTypeEntry(_, _) | AstNode::FormalParameter(_, _) | AstNode::FormalParameters(_) | AstNode::ParameterTypes(_) => FeelType::Any,
+      AstNode::Div(lhs, rhs) => self.infer_arithmetic("/", lhs, rhs),
+      AstNode::Eq(lhs, rhs) | AstNode::Nq(lhs, rhs) => self.infer_comparison(lhs, rhs, false),
+      AstNode::EvaluatedExpression(lhs) | AstNode::Satisfies(lhs) => self.visit(0, lhs),
+      AstNode::Every(lhs, rhs) | AstNode::Some(lhs, rhs) => self.infer_quantified(lhs, rhs),
+      AstNode::Exp(lhs, rhs) => self.infer_arithmetic("**", lhs, rhs),
+      AstNode::Filter(lhs, rhs) => self.infer_filter(lhs, rhs),
+      AstNode::For(lhs, rhs) => self.infer_for(lhs, rhs),
+      AstNode::FunctionBody(lhs, external) => {
+        if *external {
+          FeelType::Any
+        } else {
+          self.visit(0, lhs)
+        }
+      }
+      AstNode::FunctionDefinition(lhs, rhs) => self.infer_function_definition(lhs, rhs),
+      AstNode::FunctionInvocation(lhs, rhs) => self.infer_function_invocation(lhs, rhs),
+      AstNode::Ge(lhs, rhs) | AstNode::Gt(lhs, rhs) | AstNode::Le(lhs, rhs) | AstNode::Lt(lhs, rhs) => self.infer_comparison(lhs, rhs, true),
+      AstNode::If(lhs, mid, rhs) => self.infer_if(lhs, mid, rhs),
+      AstNode::In(lhs, rhs) => self.infer_in(lhs, rhs),
+      AstNode::InstanceOf(lhs, _) => {
+        let _ = self.visit(0, lhs);
+        FeelType::Boolean
+      }
+      AstNode::IntervalEnd(lhs, _) | AstNode::IntervalStart(lhs, _) => self.visit(0, lhs),
+      AstNode::Irrelevant => FeelType::Any,
+      AstNode::IterationContexts(_) | AstNode::IterationContextSingle(_, _) | AstNode::IterationContextRange(_, _, _) => FeelType::Any,
+      AstNode::List(items) => self.infer_list(items),
+      AstNode::Mul(lhs, rhs) => self.infer_arithmetic("*", lhs, rhs),
+      AstNode::Name(name) => self.infer_name(name),
+      AstNode::NamedParameter(_, rhs) => self.visit(1, rhs),
+      AstNode::NamedParameters(items) | AstNode::PositionalParameters(items) => self.infer_list(items),
+      AstNode::NegatedList(items) => {
+        let _ = self.infer_list(items);
+        FeelType::Boolean
+      }
+      AstNode::Neg(lhs) => self.infer_negation(lhs),
+      AstNode::Null => FeelType::Null,
+      AstNode::Numeric(_, _) => FeelType::Number,
+      AstNode::Out(lhs, rhs) => {
+        let _ = self.visit(1, rhs);
+        self.visit(0, lhs)
+      }
+      AstNode::Path(lhs, rhs) => self.infer_path(lhs, rhs),
+      AstNode::QualifiedName(_) => FeelType::Any,
+      AstNode::QuantifiedContexts(_) | AstNode::QuantifiedContext(_, _) => FeelType::Any,
+      AstNode::Range(lhs, rhs) => self.infer_range(lhs, rhs),
+      AstNode::String(_) => FeelType::String,
+      AstNode::Sub(lhs, rhs) => self.infer_arithmetic("-", lhs, rhs),
+      AstNode::UnaryGe(lhs) | AstNode::UnaryGt(lhs) | AstNode::UnaryLe(lhs) | AstNode::UnaryLt(lhs) => {
+        let _ = self.visit(0, lhs);
+        FeelType::Boolean
+      }
+    }
+  }
+
+  /// Infers the type of a name.
+  fn infer_name(&mut self, name: &Name) -> FeelType {
+    if let Some(feel_type) = self.resolve(name) {
+      return feel_type;
+    }
+    if let Ok(bif) = Bif::from_str(&name.to_string()) {
+      let signature = bif_signature(&bif);
+      return FeelType::Function(signature.parameters, Box::new(signature.result));
+    }
+    self.error(format!("unknown name '{name}'"));
+    FeelType::Any
+  }
+
+  /// Infers the type of arithmetic operation.
+  fn infer_arithmetic(&mut self, operator: &str, lhs: &AstNode, rhs: &AstNode) -> FeelType {
+    let lht = self.visit(0, lhs);
+    let rht = self.visit(1, rhs);
+    if matches!(lht, FeelType::Null) || matches!(rht, FeelType::Null) {
+      return FeelType::Null;
+    }
+    if matches!(lht, FeelType::Any) || matches!(rht, FeelType::Any) {
+      return arithmetic_type_with_any(operator, &lht, &rht);
+    }
+    match arithmetic_type(operator, &lht, &rht) {
+      Some(feel_type) => feel_type,
+      None => {
+        self.error(format!("operator '{operator}' is not defined for {lht} and {rht}"));
+        FeelType::Null
+      }
+    }
+  }
+
+  /// Infers the type of arithmetic negation.
+  fn infer_negation(&mut self, lhs: &AstNode) -> FeelType {
+    let lht = self.visit(0, lhs);
+    match lht {
+      FeelType::Any | FeelType::Null | FeelType::Number | FeelType::DaysAndTimeDuration | FeelType::YearsAndMonthsDuration => lht,
+      other => {
+        self.error(format!("arithmetic negation is not defined for {other}"));
+        FeelType::Null
+      }
+    }
+  }
+
+  /// Infers the type of logical conjunction or disjunction.
+  fn infer_logical(&mut self, lhs: &AstNode, rhs: &AstNode) -> FeelType {
+    for (index, node) in [lhs, rhs].into_iter().enumerate() {
+      let feel_type = self.visit(index, node);
+      if !matches!(feel_type, FeelType::Boolean | FeelType::Any | FeelType::Null) {
+        self.error_at(index, format!("expected boolean operand, actual type is {feel_type}"));
+      }
+    }
+    FeelType::Boolean
+  }
+
+  /// Infers the type of comparison.
+  fn infer_comparison(&mut self, lhs: &AstNode, rhs: &AstNode, ordered: bool) -> FeelType {
+    let lht = self.visit(0, lhs);
+    let rht = self.visit(1, rhs);
+    if !is_comparable(&lht, &rht, ordered) {
+      self.error(format!("cannot compare {lht} with {rht}"));
+      return FeelType::Null;
+    }
+    FeelType::Boolean
+  }
+
+  /// Infers the type of `between` expression.
+  fn infer_between(&mut self, lhs: &AstNode, mid: &AstNode, rhs: &AstNode) -> FeelType {
+    let value_type = self.visit(0, lhs);
+    for (index, node) in [mid, rhs].into_iter().enumerate() {
+      let bound_type = self.visit(index + 1, node);
+      if !is_comparable(&value_type, &bound_type, true) {
+        self.error_at(index + 1, format!("cannot compare {value_type} with {bound_type}"));
+      }
+    }
+    FeelType::Boolean
+  }
+
+  /// Infers the type of `in` expression.
+  fn infer_in(&mut self, lhs: &AstNode, rhs: &AstNode) -> FeelType {
+    let value_type = self.visit(0, lhs);
+    self.path.push(1);
+    let mut names = BTreeMap::new();
+    names.insert(Name::from("?"), value_type.clone());
+    self.push_scope(names, false);
+    self.check_unary_tests(&value_type, rhs);
+    self.pop_scope();
+    self.path.pop();
+    FeelType::Boolean
+  }
+
+  /// Checks if the tested value type fits to unary tests.
+  fn check_unary_tests(&mut self, value_type: &FeelType, node: &AstNode) {
+    match node {
+      AstNode::ExpressionList(items) | AstNode::NegatedList(items) => {
+        for (index, item) in items.iter().enumerate() {
+          self.path.push(index);
+          self.check_unary_tests(value_type, item);
+          self.path.pop();
+        }
+      }
+      AstNode::UnaryGe(lhs) | AstNode::UnaryGt(lhs) | AstNode::UnaryLe(lhs) | AstNode::UnaryLt(lhs) => {
+        let feel_type = self.visit(0, lhs);
+        if !is_comparable(value_type, &feel_type, true) {
+          self.error(format!("cannot compare {value_type} with {feel_type}"));
+        }
+      }
+      AstNode::Irrelevant => {}
+      other => match self.infer(other) {
+        FeelType::Range(element_type) => {
+          if !is_comparable(value_type, &element_type, true) {
+            self.error(format!("cannot compare {value_type} with {element_type}"));
+          }
+        }
+        FeelType::List(item_type) => {
+          if !matches!(value_type, FeelType::List(_)) && !is_comparable(value_type, &item_type, false) && !matches!(*item_type, FeelType::Boolean) {
+            self.error(format!("cannot compare {value_type} with {item_type}"));
+          }
+        }
+        FeelType::Boolean => {}
+        feel_type => {
+          if !is_comparable(value_type, &feel_type, false) {
+            self.error(format!("cannot compare {value_type} with {feel_type}"));
+          }
+        }
+      },
+    }
+  }
+
+  /// Infers the type of `if` expression.
+  fn infer_if(&mut self, lhs: &AstNode, mid: &AstNode, rhs: &AstNode) -> FeelType {
+    let condition_type = self.visit(0, lhs);
+    if !matches!(condition_type, FeelType::Boolean | FeelType::Any | FeelType::Null) {
+      self.error_at(0, format!("expected boolean condition, actual type is {condition_type}"));
+    }
+    let then_type = self.visit(1, mid);
+    let else_type = self.visit(2, rhs);
+    unify(&then_type, &else_type)
+  }
+
+  /// Infers the type of a list.
+  fn infer_list(&mut self, items: &[AstNode]) -> FeelType {
+    let mut item_type = FeelType::Null;
+    for (index, item) in items.iter().enumerate() {
+      let feel_type = self.visit(index, item);
+      item_type = if index == 0 { feel_type } else { unify(&item_type, &feel_type) };
+    }
+    FeelType::List(Box::new(if items.is_empty() { FeelType::Any } else { item_type }))
+  }
+
+  /// Infers the type of a context, entries may refer to previously defined entries.
+  fn infer_context(&mut self, items: &[AstNode]) -> FeelType {
+    self.push_scope(BTreeMap::new(), false);
+    let mut entries = BTreeMap::new();
+    for (index, item) in items.iter().enumerate() {
+      let feel_type = self.visit(index, item);
+      if let AstNode::ContextEntry(key, _) = item {
+        if let AstNode::ContextEntryKey(name) = key.as_ref() {
+          self.set_name(name, feel_type.clone());
+          entries.insert(name.clone(), feel_type);
+        }
+      }
+    }
+    self.pop_scope();
+    FeelType::Context(entries)
+  }
+
+  /// Infers the type of a range.
+  fn infer_range(&mut self, lhs: &AstNode, rhs: &AstNode) -> FeelType {
+    let start_type = self.visit(0, lhs);
+    let end_type = self.visit(1, rhs);
+    if !is_comparable(&start_type, &end_type, true) {
+      self.error(format!("range bounds have incompatible types {start_type} and {end_type}"));
+      return FeelType::Null;
+    }
+    FeelType::Range(Box::new(unify(&start_type, &end_type)))
+  }
+
+  /// Infers the type of a path expression.
+  fn infer_path(&mut self, lhs: &AstNode, rhs: &AstNode) -> FeelType {
+    let lht = self.visit(0, lhs);
+    let AstNode::Name(name) = rhs else {
+      return FeelType::Any;
+    };
+    match path_type(&lht, name) {
+      Ok(feel_type) => feel_type,
+      Err(message) => {
+        self.error_at(1, message);
+        FeelType::Null
+      }
+    }
+  }
+
+  /// Infers the type of a filter expression.
+  fn infer_filter(&mut self, lhs: &AstNode, rhs: &AstNode) -> FeelType {
+    let lht = self.visit(0, lhs);
+    let item_type = match &lht {
+      FeelType::List(item_type) => item_type.as_ref().clone(),
+      other => other.clone(),
+    };
+    let mut names = BTreeMap::new();
+    let open = match &item_type {
+      FeelType::Context(entries) => {
+        names.extend(entries.clone());
+        false
+      }
+      FeelType::Any => true,
+      _ => false,
+    };
+    names.insert(Name::from("item"), item_type.clone());
+    self.push_scope(names, open);
+    let rht = self.visit(1, rhs);
+    self.pop_scope();
+    match rht {
+      FeelType::Number => item_type,
+      FeelType::Boolean | FeelType::Any | FeelType::Null => FeelType::List(Box::new(item_type)),
+      other => {
+        self.error_at(1, format!("expected boolean or number filter, actual type is {other}"));
+        FeelType::Null
+      }
+    }
+  }
+
+  /// Pushes a scope with variables defined in iteration contexts or quantified contexts.
+  fn push_iteration_scope(&mut self, contexts: &AstNode) {
+    self.push_scope(BTreeMap::new(), false);
+    let items = match contexts {
+      AstNode::IterationContexts(items) | AstNode::QuantifiedContexts(items) => items.as_slice(),
+      _ => &[],
+    };
+    self.path.push(0);
+    for (index, item) in items.iter().enumerate() {
+      self.path.push(index);
+      let variable = match item {
+        AstNode::IterationContextSingle(lhs, rhs) | AstNode::QuantifiedContext(lhs, rhs) => {
+          let item_type = match self.visit(1, rhs) {
+            FeelType::List(item_type) => *item_type,
+            FeelType::Range(element_type) => *element_type,
+            other => other,
+          };
+          Some((lhs, item_type))
+        }
+        AstNode::IterationContextRange(lhs, mid, rhs) => {
+          for (index, node) in [mid, rhs].into_iter().enumerate() {
+            let bound_type = self.visit(index + 1, node);
+            if !matches!(bound_type, FeelType::Number | FeelType::Any | FeelType::Null) {
+              self.error_at(index + 1, format!("expected number as iteration range bound, actual type is {bound_type}"));
+            }
+          }
+          Some((lhs, FeelType::Number))
+        }
+        _ => None,
+      };
+      if let Some((lhs, item_type)) = variable {
+        if let AstNode::Name(name) = lhs.as_ref() {
+          self.set_name(name, item_type);
+        }
+      }
+      self.path.pop();
+    }
+    self.path.pop();
+  }
+
+  /// Infers the type of `for` expression.
+  fn infer_for(&mut self, lhs: &AstNode, rhs: &AstNode) -> FeelType {
+    self.push_iteration_scope(lhs);
+    self.set_name(&Name::from("partial"), FeelType::List(Box::new(FeelType::Any)));
+    let result_type = self.visit(1, rhs);
+    self.pop_scope();
+    FeelType::List(Box::new(result_type))
+  }
+
+  /// Infers the type of quantified expression `some` or `every`.
+  fn infer_quantified(&mut self, lhs: &AstNode, rhs: &AstNode) -> FeelType {
+    self.push_iteration_scope(lhs);
+    let satisfies_type = self.visit(1, rhs);
+    self.pop_scope();
+    if !matches!(satisfies_type, FeelType::Boolean | FeelType::Any | FeelType::Null) {
+      self.error_at(1, format!("expected boolean in 'satisfies' clause, actual type is {satisfies_type}"));
+    }
+    FeelType::Boolean
+  }
+
+  /// Infers the type of function definition.
+  fn infer_function_definition(&mut self, lhs: &AstNode, rhs: &AstNode) -> FeelType {
+    let mut parameter_types = vec![];
+    let mut names = BTreeMap::new();
+    if let AstNode::FormalParameters(parameters) = lhs {
+      for parameter in parameters {
+        if let AstNode::FormalParameter(name, feel_type) = parameter {
+          let feel_type = type_of_type_node(feel_type);
+          if let AstNode::ParameterName(name) = name.as_ref() {
+            names.insert(name.clone(), feel_type.clone());
+          }
+          parameter_types.push(feel_type);
+        }
+      }
+    }
+    self.push_scope(names, false);
+    let result_type = self.visit(1, rhs);
+    self.pop_scope();
+    FeelType::Function(parameter_types, Box::new(result_type))
+  }
+
+  /// Infers the type of function invocation.
+  fn infer_function_invocation(&mut self, lhs: &AstNode, rhs: &AstNode) -> FeelType {
+    let (argument_types, named) = match rhs {
+      AstNode::PositionalParameters(items) | AstNode::NamedParameters(items) => {
+        self.path.push(1);
+        let argument_types = items.iter().enumerate().map(|(index, item)| self.visit(index, item)).collect::<Vec<FeelType>>();
+        self.path.pop();
+        (argument_types, matches!(rhs, AstNode::NamedParameters(_)))
+      }
+      _ => (vec![], false),
+    };
+    // built-in functions are checked using their signatures, unless the name is shadowed in scope
+    if let AstNode::Name(name) = lhs {
+      if self.resolve(name).is_none() {
+        if let Ok(bif) = Bif::from_str(&name.to_string()) {
+          return self.check_bif_invocation(&bif, name, &argument_types, named);
+        }
+      }
+    }
+    match self.visit(0, lhs) {
+      FeelType::Function(parameter_types, result_type) => {
+        if parameter_types.len() != argument_types.len() {
+          self.error(format!(
+            "invalid number of parameters, expected {}, actual number of parameters is {}",
+            parameter_types.len(),
+            argument_types.len()
+          ));
+        } else if !named {
+          for (index, (parameter_type, argument_type)) in parameter_types.iter().zip(argument_types.iter()).enumerate() {
+            if !argument_type.is_conformant(parameter_type) && !matches!(argument_type, FeelType::Any) {
+              self.error_at(
+                1,
+                format!("invalid type of parameter {}, expected {parameter_type}, actual type is {argument_type}", index + 1),
+              );
+            }
+          }
+        }
+        *result_type
+      }
+      FeelType::Any => FeelType::Any,
+      other => {
+        self.error_at(0, format!("expected function, actual type is {other}"));
+        FeelType::Null
+      }
+    }
+  }
+
+  /// Checks the invocation of a built-in function.
+  fn check_bif_invocation(&mut self, bif: &Bif, name: &Name, argument_types: &[FeelType], named: bool) -> FeelType {
+    let signature = bif_signature(bif);
+    let count = argument_types.len();
+    if !signature.arity.accepts(count) {
+      self.error(format!(
+        "invalid number of parameters for function '{name}', expected {}, actual number of parameters is {count}",
+        signature.arity
+      ));
+      return FeelType::Null;
+    }
+    if !named {
+      for (index, (parameter_type, argument_type)) in signature.parameters.iter().zip(argument_types.iter()).enumerate() {
+        if !argument_type.is_conformant(parameter_type) && !matches!(argument_type, FeelType::Any) {
+          self.path.push(1);
+          self.error_at(
+            index,
+            format!("invalid argument type for function '{name}', expected {parameter_type}, actual type is {argument_type}"),
+          );
+          self.path.pop();
+        }
+      }
+    }
+    bif_result_type(bif, signature.result, argument_types)
+  }
+}
+
+/// Returns the type of the temporal literal `@"..."`.
+fn infer_at_literal(text: &str) -> FeelType {
+  if text.starts_with('P') || text.starts_with("-P") {
+    let date_part = text.split('T').next().unwrap_or_default();
+    if text.contains('T') || date_part.contains('D') {
+      FeelType::DaysAndTimeDuration
+    } else {
+      FeelType::YearsAndMonthsDuration
+    }
+  } else if text.contains('T') {
+    FeelType::DateTime
+  } else if text.len() > 2 && text.as_bytes()[2] == b':' {
+    FeelType::Time
+  } else {
+    FeelType::Date
+  }
+}
+
+/// Returns the type described by the node representing `FEEL` type.
+fn type_of_type_node(node: &AstNode) -> FeelType {
+  match node {
+    AstNode::FeelType(feel_type) => feel_type.clone(),
+    AstNode::ListType(lhs) => FeelType::List(Box::new(type_of_type_node(lhs))),
+    AstNode::RangeType(lhs) => FeelType::Range(Box::new(type_of_type_node(lhs))),
+    AstNode::ContextType(items) => FeelType::Context(
+      items
+        .iter()
+        .filter_map(|item| match item {
+          AstNode::ContextTypeEntry(key, feel_type) => match key.as_ref() {
+            AstNode::ContextTypeEntryKey(name) | AstNode::Name(name) => Some((name.clone(), type_of_type_node(feel_type))),
+            _ => None,
+          },
+          _ => None,
+        })
+        .collect(),
+    ),
+    AstNode::FunctionType(lhs, rhs) => {
+      let parameter_types = match lhs.as_ref() {
+        AstNode::ParameterTypes(items) => items.iter().map(type_of_type_node).collect(),
+        _ => vec![],
+      };
+      FeelType::Function(parameter_types, Box::new(type_of_type_node(rhs)))
+    }
+    _ => FeelType::Any,
+  }
+}
+
+/// Returns the common type of two types.
+fn unify(lht: &FeelType, rht: &FeelType) -> FeelType {
+  match (lht, rht) {
+    _ if lht == rht => lht.clone(),
+    (FeelType::Null, other) | (other, FeelType::Null) => other.clone(),
+    (FeelType::List(lhi), FeelType::List(rhi)) => FeelType::List(Box::new(unify(lhi, rhi))),
+    (FeelType::Range(lhe), FeelType::Range(rhe)) => FeelType::Range(Box::new(unify(lhe, rhe))),
+    _ => FeelType::Any,
+  }
+}
+
+/// Returns `true` when values of specified types can be compared.
+fn is_comparable(lht: &FeelType, rht: &FeelType, ordered: bool) -> bool {
+  match (lht, rht) {
+    (FeelType::Any | FeelType::Null, _) | (_, FeelType::Any | FeelType::Null) => true,
+    (FeelType::List(_), FeelType::List(_)) | (FeelType::Context(_), FeelType::Context(_)) | (FeelType::Range(_), FeelType::Range(_)) => !ordered,
+    (FeelType::Function(_, _), FeelType::Function(_, _)) => !ordered,
+    (FeelType::Boolean, FeelType::Boolean) => !ordered,
+    _ => lht == rht && lht.is_simple_built_in_type(),
+  }
+}
+
+/// Returns the result type of arithmetic operation on known types.
+fn arithmetic_type(operator: &str, lht: &FeelType, rht: &FeelType) -> Option<FeelType> {
+  use FeelType::*;
+  match (operator, lht, rht) {
+    (_, Number, Number) => Some(Number),
+    ("+", String, String) => Some(String),
+    ("+" | "-", YearsAndMonthsDuration, YearsAndMonthsDuration) => Some(YearsAndMonthsDuration),
+    ("+" | "-", DaysAndTimeDuration, DaysAndTimeDuration) => Some(DaysAndTimeDuration),
+    ("+" | "-", Date, YearsAndMonthsDuration | DaysAndTimeDuration) => Some(Date),
+    ("+" | "-", DateTime, YearsAndMonthsDuration | DaysAndTimeDuration) => Some(DateTime),
+    ("+" | "-", Time, DaysAndTimeDuration) => Some(Time),
+    ("+", YearsAndMonthsDuration | DaysAndTimeDuration, Date) => Some(Date),
+    ("+", YearsAndMonthsDuration | DaysAndTimeDuration, DateTime) => Some(DateTime),
+    ("+", DaysAndTimeDuration, Time) => Some(Time),
+    ("-", Date | DateTime, Date | DateTime) => Some(DaysAndTimeDuration),
+    ("-", Time, Time) => Some(DaysAndTimeDuration),
+    ("*" | "/", YearsAndMonthsDuration | DaysAndTimeDuration, Number) => Some(lht.clone()),
+    ("*", Number, YearsAndMonthsDuration | DaysAndTimeDuration) => Some(rht.clone()),
+    ("/", YearsAndMonthsDuration, YearsAndMonthsDuration) => Some(Number),
+    ("/", DaysAndTimeDuration, DaysAndTimeDuration) => Some(Number),
+    _ => None,
+  }
+}
+
+/// Returns the result type of arithmetic operation when at least one operand type is unknown.
+fn arithmetic_type_with_any(operator: &str, lht: &FeelType, rht: &FeelType) -> FeelType {
+  match (operator, lht, rht) {
+    ("**", _, _) => FeelType::Number,
+    ("-", FeelType::Any, FeelType::Number) | ("-", FeelType::Number, FeelType::Any) => FeelType::Number,
+    _ => FeelType::Any,
+  }
+}
+
+/// Returns the type of the path expression, or an error message when the path can not be resolved.
+fn path_type(lht: &FeelType, name: &Name) -> Result<FeelType, String> {
+  let property = name.to_string();
+  let number_properties: &[&str] = match lht {
+    FeelType::Any | FeelType::Null => return Ok(lht.clone()),
+    FeelType::Context(entries) => {
+      return entries.get(name).cloned().ok_or_else(|| format!("context has no entry named '{name}'"));
+    }
+    FeelType::List(item_type) => return path_type(item_type, name).map(|feel_type| FeelType::List(Box::new(feel_type))),
+    FeelType::Range(element_type) => {
+      return match property.as_str() {
+        "start" | "end" => Ok(element_type.as_ref().clone()),
+        "start included" | "end included" => Ok(FeelType::Boolean),
+        _ => Err(format!("no property named '{name}' in {lht}")),
+      }
+    }
+    FeelType::Date => &["year", "month", "day", "weekday"],
+    FeelType::Time => &["hour", "minute", "second"],
+    FeelType::DateTime => &["year", "month", "day", "weekday", "hour", "minute", "second"],
+    FeelType::YearsAndMonthsDuration => &["years", "months"],
+    FeelType::DaysAndTimeDuration => &["days", "hours", "minutes", "seconds"],
+    other => return Err(format!("path expression is not allowed on value of type {other}")),
+  };
+  if number_properties.contains(&property.as_str()) {
+    return Ok(FeelType::Number);
+  }
+  if matches!(lht, FeelType::Time | FeelType::DateTime) {
+    match property.as_str() {
+      "time offset" => return Ok(FeelType::DaysAndTimeDuration),
+      "timezone" => return Ok(FeelType::String),
+      _ => {}
+    }
+  }
+  Err(format!("no property named '{name}' in {lht}"))
+}
+
+/// Number of parameters accepted by a function.
+enum Arity {
+  /// Any of the listed numbers of parameters.
+  Exact(&'static [usize]),
+  /// At least specified number of parameters.
+  AtLeast(usize),
+}
+
+impl Arity {
+  /// Returns `true` when the specified number of parameters is accepted.
+  fn accepts(&self, count: usize) -> bool {
+    match self {
+      Arity::Exact(counts) => counts.contains(&count),
+      Arity::AtLeast(min) => count >= *min,
+    }
+  }
+}
+
+impl fmt::Display for Arity {
+  /// Converts [Arity] into text, in the same format as used in runtime errors.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Arity::Exact(counts) => write!(f, "{}", counts.iter().map(|count| count.to_string()).collect::<Vec<String>>().join(",")),
+      Arity::AtLeast(min) => write!(f, "{min}+"),
+    }
+  }
+}
+
+/// Signature of a built-in function.
+struct BifSignature {
+  /// Accepted numbers of parameters.
+  arity: Arity,
+  /// Types of parameters, checked only for positional parameters.
+  parameters: Vec<FeelType>,
+  /// Type of the result.
+  result: FeelType,
+}
+
+/// Returns the signature of the built-in function.
+fn bif_signature(bif: &Bif) -> BifSignature {
+  use FeelType::*;
+  let (arity, parameters, result) = match bif {
+    Bif::Abs => (Arity::Exact(&[1]), vec![Any], Any),
+    Bif::After
+    | Bif::Before
+    | Bif::Coincides
+    | Bif::During
+    | Bif::FinishedBy
+    | Bif::Finishes
+    | Bif::Includes
+    | Bif::Meets
+    | Bif::MetBy
+    | Bif::Overlaps
+    | Bif::OverlapsAfter
+    | Bif::OverlapsBefore
+    | Bif::StartedBy
+    | Bif::Starts => (Arity::Exact(&[2]), vec![Any, Any], Boolean),
+    Bif::All | Bif::Any => (Arity::AtLeast(1), vec![], Boolean),
+    Bif::Append => (Arity::AtLeast(2), vec![], List(Box::new(Any))),
+    Bif::Ceiling | Bif::Floor => (Arity::Exact(&[1]), vec![Number], Number),
+    Bif::Concatenate | Bif::Union => (Arity::AtLeast(1), vec![], List(Box::new(Any))),
+    Bif::Contains | Bif::EndsWith | Bif::StartsWith => (Arity::Exact(&[2]), vec![String, String], Boolean),
+    Bif::Count => (Arity::Exact(&[1]), vec![], Number),
+    Bif::Date => (Arity::Exact(&[1, 3]), vec![], Date),
+    Bif::DateAndTime => (Arity::Exact(&[1, 2]), vec![], DateTime),
+    Bif::DayOfWeek | Bif::MonthOfYear => (Arity::Exact(&[1]), vec![], String),
+    Bif::DayOfYear | Bif::WeekOfYear => (Arity::Exact(&[1]), vec![], Number),
+    Bif::Decimal | Bif::Modulo => (Arity::Exact(&[2]), vec![Number, Number], Number),
+    Bif::DistinctValues | Bif::Flatten | Bif::Reverse => (Arity::Exact(&[1]), vec![], List(Box::new(Any))),
+    Bif::Duration => (Arity::Exact(&[1]), vec![String], Any),
+    Bif::Even | Bif::Odd => (Arity::Exact(&[1]), vec![Number], Boolean),
+    Bif::Exp | Bif::Log | Bif::Sqrt => (Arity::Exact(&[1]), vec![Number], Number),
+    Bif::GetEntries => (Arity::Exact(&[1]), vec![], List(Box::new(Any))),
+    Bif::GetValue => (Arity::Exact(&[2]), vec![], Any),
+    Bif::IndexOf => (Arity::Exact(&[2]), vec![], List(Box::new(Number))),
+    Bif::InsertBefore => (Arity::Exact(&[3]), vec![Any, Number, Any], List(Box::new(Any))),
+    Bif::Is | Bif::ListContains => (Arity::Exact(&[2]), vec![], Boolean),
+    Bif::LoweCase | Bif::UpperCase => (Arity::Exact(&[1]), vec![String], String),
+    Bif::Matches => (Arity::Exact(&[2, 3]), vec![String, String, String], Boolean),
+    Bif::Max | Bif::Min => (Arity::AtLeast(1), vec![], Any),
+    Bif::Mean | Bif::Median | Bif::Product | Bif::Stddev | Bif::Sum => (Arity::AtLeast(1), vec![], Number),
+    Bif::Mode => (Arity::AtLeast(1), vec![], List(Box::new(Number))),
+    Bif::Not => (Arity::Exact(&[1]), vec![Boolean], Boolean),
+    Bif::Number => (Arity::Exact(&[3]), vec![String, String, String], Number),
+    Bif::Remove => (Arity::Exact(&[2]), vec![Any, Number], List(Box::new(Any))),
+    Bif::Replace => (Arity::Exact(&[3, 4]), vec![String, String, String, String], String),
+    Bif::Sort => (Arity::Exact(&[2]), vec![], List(Box::new(Any))),
+    Bif::Split => (Arity::Exact(&[2]), vec![String, String], List(Box::new(String))),
+    Bif::String => (Arity::Exact(&[1]), vec![], String),
+    Bif::StringLength => (Arity::Exact(&[1]), vec![String], Number),
+    Bif::Sublist => (Arity::Exact(&[2, 3]), vec![Any, Number, Number], List(Box::new(Any))),
+    Bif::Substring => (Arity::Exact(&[2, 3]), vec![String, Number, Number], String),
+    Bif::SubstringAfter | Bif::SubstringBefore => (Arity::Exact(&[2]), vec![String, String], String),
+    Bif::Time => (Arity::Exact(&[1, 3, 4]), vec![], Time),
+    Bif::YearsAndMonthsDuration => (Arity::Exact(&[2]), vec![], YearsAndMonthsDuration),
+    Bif::AddBusinessDays => (Arity::Exact(&[2, 3]), vec![Any, Number, String], Any),
+    Bif::BusinessDaysBetween => (Arity::Exact(&[2, 3]), vec![Any, Any, String], Number),
+    Bif::IsHoliday => (Arity::Exact(&[2]), vec![Any, String], Boolean),
+  };
+  BifSignature { arity, parameters, result }
+}
+
+/// Returns the result type of built-in function, refined using the types of arguments when possible.
+fn bif_result_type(bif: &Bif, result: FeelType, argument_types: &[FeelType]) -> FeelType {
+  let first = argument_types.first().cloned().unwrap_or(FeelType::Any);
+  match bif {
+    Bif::Abs => match first {
+      FeelType::Number | FeelType::DaysAndTimeDuration | FeelType::YearsAndMonthsDuration => first,
+      _ => result,
+    },
+    Bif::Append | Bif::DistinctValues | Bif::InsertBefore | Bif::Remove | Bif::Reverse | Bif::Sort | Bif::Sublist => match first {
+      FeelType::List(_) => first,
+      _ => result,
+    },
+    Bif::AddBusinessDays => match first {
+      FeelType::Date | FeelType::DateTime => first,
+      _ => result,
+    },
+    Bif::Max | Bif::Min => match (argument_types.len(), first) {
+      (1, FeelType::List(item_type)) => *item_type,
+      (1, _) => result,
+      _ => argument_types.iter().skip(1).fold(argument_types[0].clone(), |acc, feel_type| unify(&acc, feel_type)),
+    },
+    _ => result,
+  }
+}
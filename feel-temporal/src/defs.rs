@@ -34,7 +34,7 @@
 
 use crate::feel_date_time::FeelDateTime;
 use crate::feel_zone::FeelZone;
-use chrono::{DateTime, Datelike, FixedOffset, Local, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc};
+use chrono::{DateTime, Datelike, Duration, FixedOffset, Local, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone, Timelike, Utc};
 use once_cell::sync::Lazy;
 use regex::Regex;
 
@@ -87,7 +87,7 @@ pub fn feel_time_offset(me: &FeelDateTime) -> Option<i32> {
     FeelZone::Utc => Some(0),
     FeelZone::Local => None, // in FEEL semantic domain the local offset is treated as none
     FeelZone::Offset(offset) => Some(*offset),
-    FeelZone::Zone(zone_name) => get_zone_offset_dt(zone_name, me_date_tuple, me_time_tuple),
+    FeelZone::Zone(zone_name) => me.resolved_offset().or_else(|| get_zone_offset_dt(zone_name, me_date_tuple, me_time_tuple)),
   };
   if let Some(me_offset) = me_offset_opt {
     return Some(me_offset);
@@ -122,14 +122,10 @@ pub fn date_time_offset_t(time: (u32, u32, u32, u32), offset: i32) -> Option<Dat
 
 /// Returns the time offset (in seconds) between local time zone
 /// and UTC time zone at specified **date and time**.
+///
+/// Ambiguous and skipped local date and time values are resolved as described in [resolve_offset].
 pub fn get_local_offset_dt(date: (i32, u32, u32), time: (u32, u32, u32, u32)) -> Option<i32> {
-  if let Some(naive_date) = NaiveDate::from_ymd_opt(date.0, date.1, date.2) {
-    if let Some(naive_time) = NaiveTime::from_hms_nano_opt(time.0, time.1, time.2, time.3) {
-      let naive_date_time = NaiveDateTime::new(naive_date, naive_time);
-      return Some(Local.offset_from_utc_datetime(&naive_date_time).local_minus_utc());
-    }
-  }
-  None
+  resolve_offset(&Local, &naive_date_time(date, time)?)
 }
 
 /// Returns the time offset (in seconds) between local time zone
@@ -141,25 +137,39 @@ pub fn get_local_offset_t(time: (u32, u32, u32, u32)) -> Option<i32> {
 
 /// Returns time offset (in seconds) between named time zone
 /// and UTC time zone at specified **date and time**.
+///
+/// Ambiguous and skipped local date and time values are resolved as described in [resolve_offset].
 pub fn get_zone_offset_dt(zone_name: &str, date: (i32, u32, u32), time: (u32, u32, u32, u32)) -> Option<i32> {
-  // try to build UTC date and time from specified values
-  if let LocalResult::Single(utc_date_time) = Utc.with_ymd_and_hms(date.0, date.1, date.2, time.0, time.1, time.2) {
-    if let Some(utc) = utc_date_time.with_nanosecond(time.3) {
-      // try parse the time zone specified as text
-      if let Ok(tz) = zone_name.parse::<chrono_tz::Tz>() {
-        // build date and time in parsed time zone
-        if let LocalResult::Single(z_date_time) = tz.with_ymd_and_hms(date.0, date.1, date.2, time.0, time.1, time.2) {
-          if let Some(zdt) = z_date_time.with_nanosecond(time.3) {
-            // calculate the time offset, the result is a chrono::Duration
-            let offset: chrono::Duration = utc.with_timezone(&tz) - zdt;
-            // return seconds
-            return Some(offset.num_seconds() as i32);
-          }
-        }
-      }
+  let tz = zone_name.parse::<chrono_tz::Tz>().ok()?;
+  resolve_offset(&tz, &naive_date_time(date, time)?)
+}
+
+/// Returns time offset (in seconds) of the specified time zone at specified local date and time.
+///
+/// Local date and time values that do not map to exactly one instant are resolved like this:
+/// - when the local date and time occurs twice (clocks moved back, overlap),
+///   the offset of the earlier instant is used,
+/// - when the local date and time does not exist (clocks moved forward, gap),
+///   the offset in force before the transition is used, which effectively
+///   moves the local date and time forward by the length of the gap.
+pub fn resolve_offset<Tz: TimeZone>(tz: &Tz, naive_date_time: &NaiveDateTime) -> Option<i32> {
+  match tz.offset_from_local_datetime(naive_date_time) {
+    LocalResult::Single(offset) => Some(offset.fix().local_minus_utc()),
+    LocalResult::Ambiguous(earliest, _) => Some(earliest.fix().local_minus_utc()),
+    LocalResult::None => {
+      // time zone transitions are never closer than one day, so the offset
+      // one day before the skipped date and time is the offset before the gap
+      let before = naive_date_time.checked_sub_signed(Duration::days(1))?;
+      Some(tz.offset_from_utc_datetime(&before).fix().local_minus_utc())
     }
   }
-  None
+}
+
+/// Creates naive date and time from specified date and time tuples.
+pub fn naive_date_time(date: (i32, u32, u32), time: (u32, u32, u32, u32)) -> Option<NaiveDateTime> {
+  let naive_date = NaiveDate::from_ymd_opt(date.0, date.1, date.2)?;
+  let naive_time = NaiveTime::from_hms_nano_opt(time.0, time.1, time.2, time.3)?;
+  Some(NaiveDateTime::new(naive_date, naive_time))
 }
 
 /// Returns time offset (in seconds) between named time zone
@@ -309,6 +319,14 @@ mod tests {
     assert_eq!(Some(-10 * SECONDS_IN_HOUR), get_zone_offset_dt("Pacific/Honolulu", (2020, 11, 12), (18, 4, 33, 0)));
     // no time change in Honolulu in summer, offset = -10:00
     assert_eq!(Some(-10 * SECONDS_IN_HOUR), get_zone_offset_dt("Pacific/Honolulu", (2020, 6, 8), (8, 0, 0, 0)));
+    // time skipped when changing to summer time in Warsaw, offset before the change = +01:00
+    assert_eq!(Some(SECONDS_IN_HOUR), get_zone_offset_dt("Europe/Warsaw", (2023, 3, 26), (2, 30, 0, 0)));
+    // time repeated when changing to winter time in Warsaw, offset of the earlier instant = +02:00
+    assert_eq!(Some(2 * SECONDS_IN_HOUR), get_zone_offset_dt("Europe/Warsaw", (2023, 10, 29), (2, 30, 0, 0)));
+    // time skipped when changing to summer time in New York, offset before the change = -05:00
+    assert_eq!(Some(-5 * SECONDS_IN_HOUR), get_zone_offset_dt("America/New_York", (2023, 3, 12), (2, 30, 0, 0)));
+    // time repeated when changing to winter time in New York, offset of the earlier instant = -04:00
+    assert_eq!(Some(-4 * SECONDS_IN_HOUR), get_zone_offset_dt("America/New_York", (2023, 11, 5), (1, 30, 0, 0)));
   }
 
   #[test]
@@ -339,6 +339,16 @@ impl FeelDate {
     None
   }
 
+  ///
+  pub fn sub_days(&self, days: u64) -> Option<Self> {
+    if let Some(naive_date) = NaiveDate::from_ymd_opt(self.0, self.1, self.2) {
+      if let Some(updated_date) = naive_date.checked_sub_days(Days::new(days)) {
+        return Some(Self(updated_date.year(), updated_date.month(), updated_date.day()));
+      }
+    }
+    None
+  }
+
   ///
   pub fn add_months(&self, months: u32) -> Option<Self> {
     if let Some(naive_date) = NaiveDate::from_ymd_opt(self.0, self.1, self.2) {
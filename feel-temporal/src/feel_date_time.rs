@@ -40,15 +40,20 @@ use crate::defs::*;
 use crate::errors::err_date_time_conversion_failed;
 use crate::feel_ym_duration::FeelYearsAndMonthsDuration;
 use crate::FeelDaysAndTimeDuration;
-use chrono::{DateTime, Datelike, Duration, FixedOffset, Timelike};
+use chrono::{DateTime, Datelike, Duration, FixedOffset, Local, NaiveDateTime, Offset, Timelike};
 use dmntk_common::{DmntkError, Result};
 use std::cmp::Ordering;
 use std::fmt;
 use std::ops::{Add, Sub};
 
 /// FEEL date and time.
+///
+/// The last field holds the offset resolved for date and time with named time zone,
+/// when it was calculated from an instant. It distinguishes the second occurrence of the local
+/// date and time, when the clocks are moved back (overlap); when the offset is not present,
+/// the earlier occurrence is assumed.
 #[derive(Debug, Clone)]
-pub struct FeelDateTime(FeelDate, FeelTime);
+pub struct FeelDateTime(FeelDate, FeelTime, Option<i32>);
 
 /// Implements `Display` trait for date and time.
 impl fmt::Display for FeelDateTime {
@@ -97,7 +102,7 @@ impl TryFrom<&str> for FeelDateTime {
                                     }
                                   }
                                   if let Some(time) = FeelTime::zone_opt(hour, min, sec, nanos, zone) {
-                                    return Ok(FeelDateTime(date, time));
+                                    return Ok(FeelDateTime(date, time, None).resolved());
                                   }
                                 }
                               }
@@ -119,64 +124,37 @@ impl TryFrom<&str> for FeelDateTime {
 }
 
 impl PartialEq for FeelDateTime {
+  /// Returns `true` when two date and times are equal.
   ///
+  /// Date and times with time zones are equal when they represent the same instant,
+  /// no matter in which time zones they were specified. Local date and times are
+  /// compared field by field (nanoseconds are omitted). Local date and time
+  /// is never equal to date and time with time zone.
   fn eq(&self, rhs: &Self) -> bool {
-    let lhs_zone = self.1.zone();
-    let rhs_zone = rhs.1.zone();
-    let equal_zones = match (lhs_zone, rhs_zone) {
-      (FeelZone::Utc, FeelZone::Utc) => true,
-      (FeelZone::Utc, FeelZone::Zone(zone_name)) | (FeelZone::Zone(zone_name), FeelZone::Utc) => zone_name == ETC_UTC,
-      (FeelZone::Utc, FeelZone::Offset(offset)) | (FeelZone::Offset(offset), FeelZone::Utc) => *offset == 0,
-      (FeelZone::Local, FeelZone::Local) => true,
-      (FeelZone::Offset(offset), FeelZone::Zone(zone_name)) | (FeelZone::Zone(zone_name), FeelZone::Offset(offset)) => *offset == 0 && zone_name == ETC_UTC,
-      (FeelZone::Offset(_), FeelZone::Offset(_)) => true,
-      (FeelZone::Zone(zone_name1), FeelZone::Zone(zone_name2)) => (zone_name1 == zone_name2) || (get_zone_offset(zone_name1) == get_zone_offset(zone_name2)),
-      _ => return false,
-    };
-    if !equal_zones {
-      return false;
-    }
-    let lhs_date_tuple = self.0.as_tuple();
-    let lhs_time_tuple = ((self.1).hour() as u32, (self.1).minute() as u32, (self.1).second() as u32, 0);
-    let lhs_offset_opt = match lhs_zone {
-      FeelZone::Utc => Some(0),
-      FeelZone::Local => get_local_offset_t(lhs_time_tuple),
-      FeelZone::Offset(offset) => Some(*offset),
-      FeelZone::Zone(zone_name) => get_zone_offset_dt(zone_name, lhs_date_tuple, lhs_time_tuple),
-    };
-    let rhs_date_tuple = rhs.0.as_tuple();
-    let rhs_time_tuple = ((rhs.1).hour() as u32, (rhs.1).minute() as u32, (rhs.1).second() as u32, 0);
-    let rhs_offset_opt = match rhs_zone {
-      FeelZone::Utc => Some(0),
-      FeelZone::Local => get_local_offset_t(rhs_time_tuple),
-      FeelZone::Offset(offset) => Some(*offset),
-      FeelZone::Zone(zone_name) => get_zone_offset_dt(zone_name, rhs_date_tuple, rhs_time_tuple),
-    };
-    if let Some((lhs_offset, rhs_offset)) = lhs_offset_opt.zip(rhs_offset_opt) {
-      let lhs_date_opt = date_time_offset_dt(lhs_date_tuple, lhs_time_tuple, lhs_offset);
-      let rhs_date_opt = date_time_offset_dt(rhs_date_tuple, rhs_time_tuple, rhs_offset);
-      if let Some((lhs_date, rhs_date)) = lhs_date_opt.zip(rhs_date_opt) {
-        return lhs_date == rhs_date;
-      }
+    match (self.1.zone().has_offset(), rhs.1.zone().has_offset()) {
+      (true, true) => matches!(self.instant().zip(rhs.instant()), Some((lhs_instant, rhs_instant)) if lhs_instant == rhs_instant),
+      (false, false) => self.0 == rhs.0 && self.1 == rhs.1,
+      _ => false,
     }
-    false
   }
 }
 
 impl PartialOrd for FeelDateTime {
   /// Returns the ordering of two date and times.
+  ///
+  /// Date and times with time zones are ordered by the instants they represent.
+  /// Local date and times are ordered field by field. Local date and time
+  /// can not be compared with date and time with time zone.
   fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-    let d = self.0.cmp(&other.0);
-    if let Some(t) = self.1.partial_cmp(&other.1) {
-      return match (d, t) {
-        (Ordering::Equal, Ordering::Equal) => Some(Ordering::Equal),
-        (Ordering::Equal, Ordering::Less) => Some(Ordering::Less),
-        (Ordering::Equal, Ordering::Greater) => Some(Ordering::Greater),
-        (Ordering::Less, _) => Some(Ordering::Less),
-        (Ordering::Greater, _) => Some(Ordering::Greater),
-      };
+    match (self.1.zone().has_offset(), other.1.zone().has_offset()) {
+      (true, true) => self.instant().zip(other.instant()).map(|(lhs_instant, rhs_instant)| lhs_instant.cmp(&rhs_instant)),
+      (false, false) => {
+        let d = self.0.cmp(&other.0);
+        let t = self.1.partial_cmp(&other.1)?;
+        Some(d.then(t))
+      }
+      _ => None,
     }
-    None
   }
 }
 
@@ -188,7 +166,7 @@ impl Add<FeelYearsAndMonthsDuration> for FeelDateTime {
       return self.sub(rhs.abs());
     }
     if let Some(date) = self.0.add(rhs) {
-      Some(FeelDateTime(date, self.1))
+      Some(FeelDateTime(date, self.1, None))
     } else {
       None
     }
@@ -203,7 +181,7 @@ impl Sub<FeelYearsAndMonthsDuration> for FeelDateTime {
       return self.add(rhs.abs());
     }
     if let Some(date) = self.0.sub(rhs) {
-      Some(FeelDateTime(date, self.1))
+      Some(FeelDateTime(date, self.1, None))
     } else {
       None
     }
@@ -217,23 +195,7 @@ impl Add<FeelDaysAndTimeDuration> for FeelDateTime {
     if rhs.is_negative() {
       return self.sub(rhs.abs());
     }
-    let zone = self.1.zone().clone();
-    if let Ok(fixed_date_time) = <FeelDateTime as TryInto<DateTime<FixedOffset>>>::try_into(self) {
-      if let Some(date_time) = fixed_date_time.checked_add_signed(Duration::nanoseconds(rhs.as_nanos())) {
-        return Some(FeelDateTime(
-          FeelDate::new(date_time.year(), date_time.month(), date_time.day()),
-          FeelTime::zone_opt(
-            date_time.hour() as u8,
-            date_time.minute() as u8,
-            date_time.second() as u8,
-            date_time.nanosecond() as u64,
-            zone,
-          )
-          .unwrap(),
-        ));
-      }
-    }
-    None
+    self.shift(Duration::nanoseconds(rhs.as_nanos()))
   }
 }
 
@@ -244,60 +206,20 @@ impl Sub<FeelDaysAndTimeDuration> for FeelDateTime {
     if rhs.is_negative() {
       return self.add(rhs.abs());
     }
-    let zone = self.1.zone().clone();
-    if let Ok(mut date_time) = <FeelDateTime as TryInto<DateTime<FixedOffset>>>::try_into(self) {
-      date_time -= Duration::nanoseconds(rhs.as_nanos());
-      return Some(FeelDateTime(
-        FeelDate::new(date_time.year(), date_time.month(), date_time.day()),
-        FeelTime::zone_opt(
-          date_time.hour() as u8,
-          date_time.minute() as u8,
-          date_time.second() as u8,
-          date_time.nanosecond() as u64,
-          zone,
-        )
-        .unwrap(),
-      ));
-    }
-    None
+    self.shift(-Duration::nanoseconds(rhs.as_nanos()))
   }
 }
 
 impl Sub<FeelDateTime> for FeelDateTime {
   type Output = Option<FeelDaysAndTimeDuration>;
-  ///
+  /// Subtracts two date and times, the result is the duration between them.
   fn sub(self, other: FeelDateTime) -> Self::Output {
-    let me_zone = self.1.zone();
-    let other_zone = other.1.zone();
-    if me_zone.has_offset() != other_zone.has_offset() {
-      return None;
-    }
-    let me_date_tuple = self.0.as_tuple();
-    let me_time_tuple = ((self.1).hour() as u32, (self.1).minute() as u32, (self.1).second() as u32, (self.1).nanos() as u32);
-    let me_offset_opt = match me_zone {
-      FeelZone::Utc => Some(0),
-      FeelZone::Local => get_local_offset_dt(me_date_tuple, me_time_tuple),
-      FeelZone::Offset(offset) => Some(*offset),
-      FeelZone::Zone(zone_name) => get_zone_offset_dt(zone_name, me_date_tuple, me_time_tuple),
+    let difference = match (self.1.zone().has_offset(), other.1.zone().has_offset()) {
+      (true, true) => self.instant()? - other.instant()?,
+      (false, false) => self.naive()? - other.naive()?,
+      _ => return None,
     };
-    let other_date_tuple = other.0.as_tuple();
-    let other_time_tuple = ((other.1).hour() as u32, (other.1).minute() as u32, (other.1).second() as u32, (other.1).nanos() as u32);
-    let other_offset_opt = match other_zone {
-      FeelZone::Utc => Some(0),
-      FeelZone::Local => get_local_offset_dt(other_date_tuple, other_time_tuple),
-      FeelZone::Offset(offset) => Some(*offset),
-      FeelZone::Zone(zone_name) => get_zone_offset_dt(zone_name, other_date_tuple, other_time_tuple),
-    };
-    if let Some((me_offset, other_offset)) = me_offset_opt.zip(other_offset_opt) {
-      let me_date_opt = date_time_offset_dt(me_date_tuple, me_time_tuple, me_offset);
-      let other_date_opt = date_time_offset_dt(other_date_tuple, other_time_tuple, other_offset);
-      if let Some((me_date, other_date)) = me_date_opt.zip(other_date_opt) {
-        if let Some(nanos) = me_date.sub(other_date).num_nanoseconds() {
-          return Some(FeelDaysAndTimeDuration::from_n(nanos));
-        }
-      }
-    }
-    None
+    difference.num_nanoseconds().map(FeelDaysAndTimeDuration::from_n)
   }
 }
 
@@ -311,7 +233,7 @@ impl TryFrom<FeelDateTime> for DateTime<FixedOffset> {
       FeelZone::Utc => Some(0),
       FeelZone::Local => get_local_offset_dt(me_date_tuple, me_time_tuple),
       FeelZone::Offset(offset) => Some(*offset),
-      FeelZone::Zone(zone_name) => get_zone_offset_dt(zone_name, me_date_tuple, me_time_tuple),
+      FeelZone::Zone(zone_name) => value.2.or_else(|| get_zone_offset_dt(zone_name, me_date_tuple, me_time_tuple)),
     };
     if let Some(me_offset) = me_offset_opt {
       if let Some(me_date) = date_time_offset_dt(me_date_tuple, me_time_tuple, me_offset) {
@@ -325,22 +247,22 @@ impl TryFrom<FeelDateTime> for DateTime<FixedOffset> {
 impl FeelDateTime {
   /// Creates date and time from provided [FeelDate] and [FeelTime] values.  
   pub fn new(date: FeelDate, time: FeelTime) -> Self {
-    Self(date, time)
+    Self(date, time, None)
   }
 
   /// Creates UTC date and time from specified date and time values.
   pub fn utc(year: Year, month: Month, day: Day, hour: u8, minute: u8, second: u8, nanosecond: u64) -> Self {
-    Self(FeelDate::new(year, month, day), FeelTime::utc(hour, minute, second, nanosecond))
+    Self(FeelDate::new(year, month, day), FeelTime::utc(hour, minute, second, nanosecond), None)
   }
 
   /// Creates local date and time from specified date and time values.
   pub fn local(year: Year, month: Month, day: Day, hour: u8, min: u8, sec: u8, nanos: u64) -> Self {
-    Self(FeelDate::new(year, month, day), FeelTime::local(hour, min, sec, nanos))
+    Self(FeelDate::new(year, month, day), FeelTime::local(hour, min, sec, nanos), None)
   }
 
   /// Creates  date and time from specified date, time and offset values.
   pub fn offset(date: (Year, Month, Day), time: (u8, u8, u8, u64), offset: i32) -> Self {
-    Self(FeelDate::new(date.0, date.1, date.2), FeelTime::offset(time.0, time.1, time.2, time.3, offset))
+    Self(FeelDate::new(date.0, date.1, date.2), FeelTime::offset(time.0, time.1, time.2, time.3, offset), None)
   }
 
   /// Returns the `Date` part from date and time value.
@@ -413,6 +335,77 @@ impl FeelDateTime {
     feel_time_zone(self)
   }
 
+  /// Returns the instant represented by this date and time.
+  /// Local date and time is converted using the offset of the local time zone.
+  fn instant(&self) -> Option<DateTime<FixedOffset>> {
+    DateTime::<FixedOffset>::try_from(self.clone()).ok()
+  }
+
+  /// Returns this date and time without time zone.
+  fn naive(&self) -> Option<NaiveDateTime> {
+    naive_date_time(
+      self.0.as_tuple(),
+      (self.1.hour() as u32, self.1.minute() as u32, self.1.second() as u32, self.1.nanos() as u32),
+    )
+  }
+
+  /// Returns the offset resolved for date and time with named time zone, if present.
+  pub(crate) fn resolved_offset(&self) -> Option<i32> {
+    self.2
+  }
+
+  /// Creates date and time from the instant, expressed in specified time zone.
+  /// For named time zones, the offset in force at the instant is retained.
+  fn from_instant(instant: DateTime<FixedOffset>, zone: FeelZone) -> Option<Self> {
+    let (naive, resolved_offset) = match &zone {
+      FeelZone::Utc => (instant.naive_utc(), None),
+      FeelZone::Local => (instant.with_timezone(&Local).naive_local(), None),
+      FeelZone::Offset(offset) => (instant.with_timezone(&FixedOffset::east_opt(*offset)?).naive_local(), None),
+      FeelZone::Zone(zone_name) => {
+        let zoned = instant.with_timezone(&zone_name.parse::<chrono_tz::Tz>().ok()?);
+        (zoned.naive_local(), Some(zoned.offset().fix().local_minus_utc()))
+      }
+    };
+    let mut date_time = Self::from_naive(naive, zone)?;
+    date_time.2 = resolved_offset;
+    Some(date_time)
+  }
+
+  /// Creates date and time from naive date and time, with specified time zone.
+  fn from_naive(naive: NaiveDateTime, zone: FeelZone) -> Option<Self> {
+    Some(FeelDateTime(
+      FeelDate::new_opt(naive.year(), naive.month(), naive.day())?,
+      FeelTime::zone_opt(naive.hour() as u8, naive.minute() as u8, naive.second() as u8, naive.nanosecond() as u64, zone)?,
+      None,
+    ))
+  }
+
+  /// Shifts this date and time by specified duration.
+  ///
+  /// Date and time with time zone is shifted on the time line and then expressed
+  /// back in its own time zone, so the daylight saving time transitions
+  /// are properly taken into account. Local date and time is shifted field by field.
+  fn shift(self, duration: Duration) -> Option<Self> {
+    let zone = self.1.zone().clone();
+    if zone.has_offset() {
+      Self::from_instant(self.instant()?.checked_add_signed(duration)?, zone)
+    } else {
+      Self::from_naive(self.naive()?.checked_add_signed(duration)?, zone)
+    }
+  }
+
+  /// Resolves local date and time that does not exist in the named time zone
+  /// (it falls into the gap when clocks are moved forward), by moving it forward
+  /// by the length of the gap. Other date and times are returned unchanged.
+  fn resolved(self) -> Self {
+    if let FeelZone::Zone(_) = self.1.zone() {
+      if let Some(resolved) = self.instant().and_then(|instant| Self::from_instant(instant, self.1.zone().clone())) {
+        return resolved;
+      }
+    }
+    self
+  }
+
   ///
   pub fn is(&self, rhs: &FeelDateTime) -> bool {
     let lhs_zone = self.1.zone();
@@ -436,7 +429,7 @@ impl FeelDateTime {
       FeelZone::Utc => Some(0),
       FeelZone::Local => get_local_offset_t(lhs_time_tuple),
       FeelZone::Offset(offset) => Some(*offset),
-      FeelZone::Zone(zone_name) => get_zone_offset_dt(zone_name, lhs_date_tuple, lhs_time_tuple),
+      FeelZone::Zone(zone_name) => self.2.or_else(|| get_zone_offset_dt(zone_name, lhs_date_tuple, lhs_time_tuple)),
     };
     let rhs_date_tuple = rhs.0.as_tuple();
     let rhs_time_tuple = ((rhs.1).hour() as u32, (rhs.1).minute() as u32, (rhs.1).second() as u32, 0);
@@ -444,7 +437,7 @@ impl FeelDateTime {
       FeelZone::Utc => Some(0),
       FeelZone::Local => get_local_offset_t(rhs_time_tuple),
       FeelZone::Offset(offset) => Some(*offset),
-      FeelZone::Zone(zone_name) => get_zone_offset_dt(zone_name, rhs_date_tuple, rhs_time_tuple),
+      FeelZone::Zone(zone_name) => rhs.2.or_else(|| get_zone_offset_dt(zone_name, rhs_date_tuple, rhs_time_tuple)),
     };
     if let Some((lhs_offset, rhs_offset)) = lhs_offset_opt.zip(rhs_offset_opt) {
       let lhs_date_opt = date_time_offset_dt(lhs_date_tuple, lhs_time_tuple, lhs_offset);
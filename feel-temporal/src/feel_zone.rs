@@ -93,7 +93,7 @@ impl PartialEq for FeelZone {
             false
           }
       }
-      (FeelZone::Offset(_), FeelZone::Offset(_)) => true,
+      (FeelZone::Offset(offset1), FeelZone::Offset(offset2)) => offset1 == offset2,
       (FeelZone::Zone(zone_name1), FeelZone::Zone(zone_name2)) => zone_name1 == zone_name2,
       _ => false,
     }
@@ -235,7 +235,7 @@ mod tests {
     assert!((FeelZone::Offset(1) == FeelZone::Offset(1)));
     assert!((FeelZone::Offset(1) != FeelZone::Zone("Europe/Warsaw".to_string())));
     assert!((FeelZone::Offset(2) != FeelZone::Zone("Europe/Sofa".to_string())));
-    assert!((FeelZone::Offset(1) == FeelZone::Offset(2)));
+    assert!((FeelZone::Offset(1) != FeelZone::Offset(2)));
     assert!((FeelZone::Zone("Europe/Warsaw".to_string()) == FeelZone::Zone("Europe/Warsaw".to_string())));
     assert!((FeelZone::Zone("Europe/Warsaw".to_string()) != FeelZone::Zone("Australia/Sydney".to_string())));
   }
@@ -43,6 +43,8 @@ mod feel_dt_duration;
 mod feel_time;
 mod feel_ym_duration;
 mod feel_zone;
+#[cfg(feature = "serde")]
+mod serialization;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,66 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Implementation of `serde` serialization for `FEEL` temporal types.
+//!
+//! All temporal values are serialized as strings in the same format
+//! as used in `FEEL` temporal literals, e.g. `"2023-05-17"`, `"10:23:45.123@Europe/Warsaw"`,
+//! `"2023-05-17T10:23:45+02:00"`, `"P2DT3H"` or `"P1Y2M"`.
+//! Deserialization parses these strings back, so the conversion is lossless.
+
+use crate::{FeelDate, FeelDateTime, FeelDaysAndTimeDuration, FeelTime, FeelYearsAndMonthsDuration};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::str::FromStr;
+
+macro_rules! impl_serde {
+  ($type:ty, $parse:expr) => {
+    impl Serialize for $type {
+      fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+      }
+    }
+
+    impl<'de> Deserialize<'de> for $type {
+      fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        $parse(s.as_str()).map_err(D::Error::custom)
+      }
+    }
+  };
+}
+
+impl_serde!(FeelDate, FeelDate::from_str);
+impl_serde!(FeelTime, FeelTime::from_str);
+impl_serde!(FeelDateTime, FeelDateTime::try_from);
+impl_serde!(FeelDaysAndTimeDuration, FeelDaysAndTimeDuration::try_from);
+impl_serde!(FeelYearsAndMonthsDuration, FeelYearsAndMonthsDuration::try_from);
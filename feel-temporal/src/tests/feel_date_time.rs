@@ -32,7 +32,7 @@
 
 //!
 
-use crate::FeelDateTime;
+use crate::{FeelDateTime, FeelDaysAndTimeDuration};
 use chrono::{DateTime, FixedOffset};
 use dmntk_common::Result;
 
@@ -82,3 +82,109 @@ fn _0006() {
     date_time.err().unwrap().to_string()
   );
 }
+
+#[test]
+fn _0007() {
+  // one day later across the transition to summer time is only 23 hours later
+  let date_time: FeelDateTime = "2023-03-25T12:00:00@Europe/Warsaw".try_into().unwrap();
+  let shifted = (date_time.clone() + FeelDaysAndTimeDuration::from_s(86_400)).unwrap();
+  assert_eq!("2023-03-26T13:00:00@Europe/Warsaw", shifted.to_string());
+  assert_eq!(86_400, (shifted.clone() - date_time.clone()).unwrap().as_seconds());
+  let shifted = (shifted - FeelDaysAndTimeDuration::from_s(86_400)).unwrap();
+  assert_eq!("2023-03-25T12:00:00@Europe/Warsaw", shifted.to_string());
+}
+
+#[test]
+fn _0008() {
+  let date_time: FeelDateTime = "2023-03-26T01:30:00@Europe/Warsaw".try_into().unwrap();
+  let shifted = (date_time + FeelDaysAndTimeDuration::from_s(3_600)).unwrap();
+  assert_eq!("2023-03-26T03:30:00@Europe/Warsaw", shifted.to_string());
+  let date_time: FeelDateTime = "2023-10-29T01:30:00@Europe/Warsaw".try_into().unwrap();
+  let shifted = (date_time + FeelDaysAndTimeDuration::from_s(3_600)).unwrap();
+  assert_eq!("2023-10-29T02:30:00@Europe/Warsaw", shifted.to_string());
+}
+
+#[test]
+fn _0009() {
+  // local time skipped by the transition to summer time is moved forward by the length of the gap
+  let date_time: FeelDateTime = "2023-03-26T02:30:00@Europe/Warsaw".try_into().unwrap();
+  assert_eq!("2023-03-26T03:30:00@Europe/Warsaw", date_time.to_string());
+  assert_eq!(Some(7_200), date_time.feel_time_offset());
+}
+
+#[test]
+fn _0010() {
+  // ambiguous local time after the transition to winter time resolves to the earlier instant
+  let date_time: FeelDateTime = "2023-10-29T02:30:00@Europe/Warsaw".try_into().unwrap();
+  assert_eq!("2023-10-29T02:30:00@Europe/Warsaw", date_time.to_string());
+  assert_eq!(Some(7_200), date_time.feel_time_offset());
+  let utc: FeelDateTime = "2023-10-29T00:30:00Z".try_into().unwrap();
+  assert!(date_time == utc);
+}
+
+#[test]
+fn _0011() {
+  let date_time_1: FeelDateTime = "2023-06-01T12:00:00@Europe/Warsaw".try_into().unwrap();
+  let date_time_2: FeelDateTime = "2023-06-01T10:00:00Z".try_into().unwrap();
+  let date_time_3: FeelDateTime = "2023-06-01T06:00:00-04:00".try_into().unwrap();
+  let date_time_4: FeelDateTime = "2023-06-01T12:00:00+01:00".try_into().unwrap();
+  assert!(date_time_1 == date_time_2);
+  assert!(date_time_1 == date_time_3);
+  assert!(date_time_2 == date_time_3);
+  assert!(date_time_1 != date_time_4);
+  assert!(date_time_1 < date_time_4);
+  assert!(date_time_4 > date_time_3);
+  assert!(date_time_1 <= date_time_3);
+}
+
+#[test]
+fn _0012() {
+  let date_time_1: FeelDateTime = "2023-06-01T12:00:00".try_into().unwrap();
+  let date_time_2: FeelDateTime = "2023-06-01T12:00:00Z".try_into().unwrap();
+  let date_time_3: FeelDateTime = "2023-06-01T13:00:00".try_into().unwrap();
+  assert_eq!(None, date_time_1.partial_cmp(&date_time_2));
+  assert!(date_time_1 < date_time_3);
+  assert_eq!(3_600, (date_time_3 - date_time_1).unwrap().as_seconds());
+}
+
+#[test]
+fn _0013() {
+  // shifting forward into the second occurrence of the local time after the transition to winter time
+  let date_time: FeelDateTime = "2023-10-29T01:30:00@Europe/Warsaw".try_into().unwrap();
+  let first = (date_time.clone() + FeelDaysAndTimeDuration::from_s(3_600)).unwrap();
+  let second = (date_time.clone() + FeelDaysAndTimeDuration::from_s(7_200)).unwrap();
+  assert_eq!("2023-10-29T02:30:00@Europe/Warsaw", first.to_string());
+  assert_eq!("2023-10-29T02:30:00@Europe/Warsaw", second.to_string());
+  assert_eq!(Some(7_200), first.feel_time_offset());
+  assert_eq!(Some(3_600), second.feel_time_offset());
+  assert!(first != second);
+  assert!(first < second);
+  assert_eq!(7_200, (second.clone() - date_time).unwrap().as_seconds());
+  assert_eq!(3_600, (second.clone() - first.clone()).unwrap().as_seconds());
+  let utc: FeelDateTime = "2023-10-29T01:30:00Z".try_into().unwrap();
+  assert!(second == utc);
+  let parsed: FeelDateTime = "2023-10-29T02:30:00@Europe/Warsaw".try_into().unwrap();
+  assert!(parsed == first);
+  assert!(parsed != second);
+  let shifted = (parsed + FeelDaysAndTimeDuration::from_s(3_600)).unwrap();
+  assert!(shifted == second);
+}
+
+#[test]
+fn _0014() {
+  // shifting backward into both occurrences of the local time after the transition to winter time
+  let date_time: FeelDateTime = "2023-10-29T03:30:00@Europe/Warsaw".try_into().unwrap();
+  let second = (date_time.clone() - FeelDaysAndTimeDuration::from_s(3_600)).unwrap();
+  let first = (date_time.clone() - FeelDaysAndTimeDuration::from_s(7_200)).unwrap();
+  assert_eq!("2023-10-29T02:30:00@Europe/Warsaw", second.to_string());
+  assert_eq!("2023-10-29T02:30:00@Europe/Warsaw", first.to_string());
+  assert_eq!(Some(3_600), second.feel_time_offset());
+  assert_eq!(Some(7_200), first.feel_time_offset());
+  assert!(second > first);
+  assert_eq!(7_200, (date_time.clone() - first.clone()).unwrap().as_seconds());
+  assert_eq!(-3_600, (first.clone() - second.clone()).unwrap().as_seconds());
+  let back = (second - FeelDaysAndTimeDuration::from_s(3_600)).unwrap();
+  assert!(back == first);
+  let forward = (first + FeelDaysAndTimeDuration::from_s(7_200)).unwrap();
+  assert!(forward == date_time);
+}
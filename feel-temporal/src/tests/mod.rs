@@ -35,3 +35,5 @@
 mod feel_date;
 mod feel_date_time;
 mod feel_time;
+#[cfg(feature = "serde")]
+mod serialization;
@@ -0,0 +1,47 @@
+use crate::{FeelDate, FeelDateTime, FeelDaysAndTimeDuration, FeelTime, FeelYearsAndMonthsDuration};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt::Debug;
+
+fn round_trip<T: Serialize + DeserializeOwned + PartialEq + Debug>(expected_json: &str, value: T) {
+  let json = serde_json::to_string(&value).unwrap();
+  assert_eq!(expected_json, json);
+  assert_eq!(value, serde_json::from_str::<T>(&json).unwrap());
+}
+
+#[test]
+fn _0001() {
+  round_trip(r#""2023-05-17""#, "2023-05-17".parse::<FeelDate>().unwrap());
+  round_trip(r#""-9999-01-01""#, "-9999-01-01".parse::<FeelDate>().unwrap());
+}
+
+#[test]
+fn _0002() {
+  round_trip(r#""10:23:45""#, "10:23:45".parse::<FeelTime>().unwrap());
+  round_trip(r#""10:23:45.123456789Z""#, "10:23:45.123456789Z".parse::<FeelTime>().unwrap());
+  round_trip(r#""10:23:45@Europe/Warsaw""#, "10:23:45@Europe/Warsaw".parse::<FeelTime>().unwrap());
+}
+
+#[test]
+fn _0003() {
+  round_trip(r#""2023-05-17T10:23:45""#, FeelDateTime::try_from("2023-05-17T10:23:45").unwrap());
+  round_trip(r#""2023-05-17T10:23:45+02:00""#, FeelDateTime::try_from("2023-05-17T10:23:45+02:00").unwrap());
+}
+
+#[test]
+fn _0004() {
+  round_trip(r#""P2DT3H""#, FeelDaysAndTimeDuration::try_from("P2DT3H").unwrap());
+  round_trip(r#""-PT0.001S""#, FeelDaysAndTimeDuration::try_from("-PT0.001S").unwrap());
+}
+
+#[test]
+fn _0005() {
+  round_trip(r#""P1Y2M""#, FeelYearsAndMonthsDuration::try_from("P1Y2M").unwrap());
+}
+
+#[test]
+fn _0006() {
+  assert!(serde_json::from_str::<FeelDate>(r#""2023-02-30""#).is_err());
+  assert!(serde_json::from_str::<FeelTime>("1023").is_err());
+  assert!(serde_json::from_str::<FeelYearsAndMonthsDuration>(r#""P1D""#).is_err());
+}
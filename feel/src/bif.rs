@@ -112,97 +112,138 @@ pub enum Bif {
   UpperCase,
   WeekOfYear,
   YearsAndMonthsDuration,
+  // extension functions, not defined in FEEL specification,
+  // their names are recognized only when `calendar` feature is enabled
+  AddBusinessDays,
+  BusinessDaysBetween,
+  IsHoliday,
 }
 
+/// Prefix of the names of extension functions, that are not defined in FEEL specification.
+pub const EXTENSION_PREFIX: &str = "dmntk.";
+
+/// Built-in functions indexed by their names, in alphabetical order.
+const BUILT_IN_FUNCTIONS: [(&str, Bif); 74] = [
+  ("abs", Bif::Abs),
+  ("after", Bif::After),
+  ("all", Bif::All),
+  ("any", Bif::Any),
+  ("append", Bif::Append),
+  ("before", Bif::Before),
+  ("ceiling", Bif::Ceiling),
+  ("coincides", Bif::Coincides),
+  ("concatenate", Bif::Concatenate),
+  ("contains", Bif::Contains),
+  ("count", Bif::Count),
+  ("date", Bif::Date),
+  ("date and time", Bif::DateAndTime),
+  ("day of week", Bif::DayOfWeek),
+  ("day of year", Bif::DayOfYear),
+  ("decimal", Bif::Decimal),
+  ("distinct values", Bif::DistinctValues),
+  ("duration", Bif::Duration),
+  ("during", Bif::During),
+  ("ends with", Bif::EndsWith),
+  ("even", Bif::Even),
+  ("exp", Bif::Exp),
+  ("finished by", Bif::FinishedBy),
+  ("finishes", Bif::Finishes),
+  ("flatten", Bif::Flatten),
+  ("floor", Bif::Floor),
+  ("get entries", Bif::GetEntries),
+  ("get value", Bif::GetValue),
+  ("includes", Bif::Includes),
+  ("index of", Bif::IndexOf),
+  ("insert before", Bif::InsertBefore),
+  ("is", Bif::Is),
+  ("list contains", Bif::ListContains),
+  ("log", Bif::Log),
+  ("lower case", Bif::LoweCase),
+  ("matches", Bif::Matches),
+  ("max", Bif::Max),
+  ("mean", Bif::Mean),
+  ("median", Bif::Median),
+  ("meets", Bif::Meets),
+  ("met by", Bif::MetBy),
+  ("min", Bif::Min),
+  ("mode", Bif::Mode),
+  ("modulo", Bif::Modulo),
+  ("month of year", Bif::MonthOfYear),
+  ("not", Bif::Not),
+  ("number", Bif::Number),
+  ("odd", Bif::Odd),
+  ("overlaps", Bif::Overlaps),
+  ("overlaps after", Bif::OverlapsAfter),
+  ("overlaps before", Bif::OverlapsBefore),
+  ("product", Bif::Product),
+  ("remove", Bif::Remove),
+  ("replace", Bif::Replace),
+  ("reverse", Bif::Reverse),
+  ("sort", Bif::Sort),
+  ("split", Bif::Split),
+  ("sqrt", Bif::Sqrt),
+  ("started by", Bif::StartedBy),
+  ("starts", Bif::Starts),
+  ("starts with", Bif::StartsWith),
+  ("stddev", Bif::Stddev),
+  ("string", Bif::String),
+  ("string length", Bif::StringLength),
+  ("sublist", Bif::Sublist),
+  ("substring", Bif::Substring),
+  ("substring after", Bif::SubstringAfter),
+  ("substring before", Bif::SubstringBefore),
+  ("sum", Bif::Sum),
+  ("time", Bif::Time),
+  ("union", Bif::Union),
+  ("upper case", Bif::UpperCase),
+  ("week of year", Bif::WeekOfYear),
+  ("years and months duration", Bif::YearsAndMonthsDuration),
+];
+
+/// Extension functions indexed by their names, in alphabetical order.
+#[cfg(feature = "calendar")]
+const EXTENSION_FUNCTIONS: [(&str, Bif); 3] = [
+  ("dmntk.add business days", Bif::AddBusinessDays),
+  ("dmntk.business days between", Bif::BusinessDaysBetween),
+  ("dmntk.is holiday", Bif::IsHoliday),
+];
+
+/// Extension functions, no extension functions are available without `calendar` feature.
+#[cfg(not(feature = "calendar"))]
+const EXTENSION_FUNCTIONS: [(&str, Bif); 0] = [];
+
 impl FromStr for Bif {
   type Err = DmntkError;
   /// Converts a string into corresponding enumeration variant of [Bif].
   fn from_str(s: &str) -> Result<Self, Self::Err> {
-    match s {
-      "abs" => Ok(Self::Abs),
-      "after" => Ok(Self::After),
-      "all" => Ok(Self::All),
-      "any" => Ok(Self::Any),
-      "append" => Ok(Self::Append),
-      "before" => Ok(Self::Before),
-      "ceiling" => Ok(Self::Ceiling),
-      "coincides" => Ok(Self::Coincides),
-      "concatenate" => Ok(Self::Concatenate),
-      "contains" => Ok(Self::Contains),
-      "count" => Ok(Self::Count),
-      "date" => Ok(Self::Date),
-      "date and time" => Ok(Self::DateAndTime),
-      "day of week" => Ok(Self::DayOfWeek),
-      "day of year" => Ok(Self::DayOfYear),
-      "decimal" => Ok(Self::Decimal),
-      "distinct values" => Ok(Self::DistinctValues),
-      "duration" => Ok(Self::Duration),
-      "during" => Ok(Self::During),
-      "ends with" => Ok(Self::EndsWith),
-      "even" => Ok(Self::Even),
-      "exp" => Ok(Self::Exp),
-      "finished by" => Ok(Self::FinishedBy),
-      "finishes" => Ok(Self::Finishes),
-      "flatten" => Ok(Self::Flatten),
-      "floor" => Ok(Self::Floor),
-      "get entries" => Ok(Self::GetEntries),
-      "get value" => Ok(Self::GetValue),
-      "includes" => Ok(Self::Includes),
-      "index of" => Ok(Self::IndexOf),
-      "insert before" => Ok(Self::InsertBefore),
-      "is" => Ok(Self::Is),
-      "list contains" => Ok(Self::ListContains),
-      "log" => Ok(Self::Log),
-      "lower case" => Ok(Self::LoweCase),
-      "matches" => Ok(Self::Matches),
-      "max" => Ok(Self::Max),
-      "mean" => Ok(Self::Mean),
-      "median" => Ok(Self::Median),
-      "meets" => Ok(Self::Meets),
-      "met by" => Ok(Self::MetBy),
-      "min" => Ok(Self::Min),
-      "mode" => Ok(Self::Mode),
-      "modulo" => Ok(Self::Modulo),
-      "month of year" => Ok(Self::MonthOfYear),
-      "not" => Ok(Self::Not),
-      "number" => Ok(Self::Number),
-      "odd" => Ok(Self::Odd),
-      "overlaps" => Ok(Self::Overlaps),
-      "overlaps after" => Ok(Self::OverlapsAfter),
-      "overlaps before" => Ok(Self::OverlapsBefore),
-      "product" => Ok(Self::Product),
-      "remove" => Ok(Self::Remove),
-      "replace" => Ok(Self::Replace),
-      "reverse" => Ok(Self::Reverse),
-      "sort" => Ok(Self::Sort),
-      "split" => Ok(Self::Split),
-      "sqrt" => Ok(Self::Sqrt),
-      "started by" => Ok(Self::StartedBy),
-      "starts" => Ok(Self::Starts),
-      "starts with" => Ok(Self::StartsWith),
-      "stddev" => Ok(Self::Stddev),
-      "string" => Ok(Self::String),
-      "string length" => Ok(Self::StringLength),
-      "sublist" => Ok(Self::Sublist),
-      "substring" => Ok(Self::Substring),
-      "substring after" => Ok(Self::SubstringAfter),
-      "substring before" => Ok(Self::SubstringBefore),
-      "sum" => Ok(Self::Sum),
-      "time" => Ok(Self::Time),
-      "union" => Ok(Self::Union),
-      "upper case" => Ok(Self::UpperCase),
-      "week of year" => Ok(Self::WeekOfYear),
-      "years and months duration" => Ok(Self::YearsAndMonthsDuration),
-      _ => Err(err_unknown_function_name(s)),
-    }
+    let functions: &[(&str, Bif)] = if s.starts_with(EXTENSION_PREFIX) { &EXTENSION_FUNCTIONS } else { &BUILT_IN_FUNCTIONS };
+    functions
+      .binary_search_by(|(name, _)| (*name).cmp(s))
+      .map(|index| functions[index].1.clone())
+      .map_err(|_| err_unknown_function_name(s))
   }
 }
 
+/// Returns the names of all built-in functions, in alphabetical order.
+pub fn built_in_function_names() -> impl Iterator<Item = &'static str> {
+  BUILT_IN_FUNCTIONS.iter().map(|(name, _)| *name)
+}
+
+/// Returns the names of extension functions, in alphabetical order.
+pub fn extension_function_names() -> impl Iterator<Item = &'static str> {
+  EXTENSION_FUNCTIONS.iter().map(|(name, _)| *name)
+}
+
 /// Returns `true` when the specified name is a built-in function name.
 pub fn is_built_in_function_name(name: &str) -> bool {
   Bif::from_str(name).is_ok()
 }
 
+/// Returns `true` when the specified name is an extension function name.
+pub fn is_extension_function_name(name: &str) -> bool {
+  name.starts_with(EXTENSION_PREFIX) && Bif::from_str(name).is_ok()
+}
+
 /// Returns `true` when the specified name is a one of the following
 /// built-in function name (date and time literals):
 /// - `date`,
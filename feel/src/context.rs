@@ -50,6 +50,7 @@ type FeelContextEntries = BTreeMap<Name, Value>;
 
 /// The FEEL context.
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
 pub struct FeelContext(FeelContextEntries);
 
 impl Deref for FeelContext {
@@ -0,0 +1,249 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Conversions between Rust types and `FEEL` values.
+//!
+//! Types implementing [IntoFeel] and [FromFeel] may be used to build input data
+//! for evaluating decision models and to read evaluation results without
+//! manipulating contexts entry by entry. Both traits can be derived for structs
+//! with named fields (converted into contexts) and enums with unit variants
+//! (converted into strings):
+//!
+//! ```
+//! use dmntk_feel::{FromFeel, IntoFeel};
+//!
+//! #[derive(IntoFeel, FromFeel, Debug, PartialEq)]
+//! struct Applicant {
+//!   name: String,
+//!   #[feel(name = "monthly income")]
+//!   monthly_income: u32,
+//!   children: Vec<String>,
+//!   spouse: Option<String>,
+//! }
+//!
+//! let applicant = Applicant {
+//!   name: "Alice".to_string(),
+//!   monthly_income: 6000,
+//!   children: vec![],
+//!   spouse: None,
+//! };
+//! let value = applicant.into_feel();
+//! assert_eq!(r#"{children: [], monthly income: 6000, name: "Alice", spouse: null}"#, value.to_string());
+//! assert_eq!("Alice", Applicant::from_feel(&value).unwrap().name);
+//! ```
+
+use crate::context::FeelContext;
+use crate::errors::*;
+use crate::names::Name;
+use crate::value_null;
+use crate::values::Value;
+use dmntk_feel_number::FeelNumber;
+use dmntk_feel_temporal::{FeelDate, FeelDateTime, FeelDaysAndTimeDuration, FeelTime, FeelYearsAndMonthsDuration};
+
+pub use dmntk_common::Result;
+
+/// Conversion of Rust values into `FEEL` values.
+pub trait IntoFeel {
+  /// Converts `self` into `FEEL` value.
+  fn into_feel(self) -> Value;
+}
+
+/// Conversion of `FEEL` values into Rust values.
+pub trait FromFeel: Sized {
+  /// Converts `FEEL` value into Rust value.
+  fn from_feel(value: &Value) -> Result<Self>;
+}
+
+impl IntoFeel for Value {
+  fn into_feel(self) -> Value {
+    self
+  }
+}
+
+impl FromFeel for Value {
+  fn from_feel(value: &Value) -> Result<Self> {
+    Ok(value.clone())
+  }
+}
+
+impl IntoFeel for bool {
+  fn into_feel(self) -> Value {
+    Value::Boolean(self)
+  }
+}
+
+impl FromFeel for bool {
+  fn from_feel(value: &Value) -> Result<Self> {
+    match value {
+      Value::Boolean(value) => Ok(*value),
+      other => Err(err_invalid_feel_conversion(other, "bool")),
+    }
+  }
+}
+
+impl IntoFeel for String {
+  fn into_feel(self) -> Value {
+    Value::String(self)
+  }
+}
+
+impl IntoFeel for &str {
+  fn into_feel(self) -> Value {
+    Value::String(self.to_string())
+  }
+}
+
+impl FromFeel for String {
+  fn from_feel(value: &Value) -> Result<Self> {
+    match value {
+      Value::String(value) => Ok(value.clone()),
+      other => Err(err_invalid_feel_conversion(other, "String")),
+    }
+  }
+}
+
+/// Implements conversions for types having their own variant in [Value].
+macro_rules! impl_feel_variant {
+  ($type:ty, $variant:ident) => {
+    impl IntoFeel for $type {
+      fn into_feel(self) -> Value {
+        Value::$variant(self)
+      }
+    }
+
+    impl FromFeel for $type {
+      fn from_feel(value: &Value) -> Result<Self> {
+        match value {
+          Value::$variant(value) => Ok(value.clone()),
+          other => Err(err_invalid_feel_conversion(other, stringify!($type))),
+        }
+      }
+    }
+  };
+}
+
+impl_feel_variant!(FeelNumber, Number);
+impl_feel_variant!(FeelDate, Date);
+impl_feel_variant!(FeelTime, Time);
+impl_feel_variant!(FeelDateTime, DateTime);
+impl_feel_variant!(FeelDaysAndTimeDuration, DaysAndTimeDuration);
+impl_feel_variant!(FeelYearsAndMonthsDuration, YearsAndMonthsDuration);
+impl_feel_variant!(FeelContext, Context);
+
+/// Implements conversions for integer types.
+macro_rules! impl_feel_integer {
+  ($($type:ty),+) => {
+    $(
+      impl IntoFeel for $type {
+        fn into_feel(self) -> Value {
+          Value::Number(self.into())
+        }
+      }
+
+      impl FromFeel for $type {
+        fn from_feel(value: &Value) -> Result<Self> {
+          match value {
+            Value::Number(number) => <$type>::try_from(number).map_err(|_| err_invalid_feel_conversion(value, stringify!($type))),
+            other => Err(err_invalid_feel_conversion(other, stringify!($type))),
+          }
+        }
+      }
+    )+
+  };
+}
+
+impl_feel_integer!(u8, i8, u16, i16, u32, i32, u64, i64, usize, isize);
+
+impl<T: IntoFeel> IntoFeel for Option<T> {
+  /// Converts [None] into `null`.
+  fn into_feel(self) -> Value {
+    match self {
+      Some(value) => value.into_feel(),
+      None => value_null!(),
+    }
+  }
+}
+
+impl<T: FromFeel> FromFeel for Option<T> {
+  /// Converts `null` into [None].
+  fn from_feel(value: &Value) -> Result<Self> {
+    match value {
+      Value::Null(_) => Ok(None),
+      other => T::from_feel(other).map(Some),
+    }
+  }
+}
+
+impl<T: IntoFeel> IntoFeel for Vec<T> {
+  fn into_feel(self) -> Value {
+    Value::List(self.into_iter().map(IntoFeel::into_feel).collect())
+  }
+}
+
+impl<T: FromFeel> FromFeel for Vec<T> {
+  fn from_feel(value: &Value) -> Result<Self> {
+    match value {
+      Value::List(values) => values.iter().map(T::from_feel).collect(),
+      other => Err(err_invalid_feel_conversion(other, "Vec")),
+    }
+  }
+}
+
+/// Returns the context held by the value, used by derived [FromFeel] implementations.
+pub fn as_context<'a>(value: &'a Value, type_name: &str) -> Result<&'a FeelContext> {
+  match value {
+    Value::Context(context) => Ok(context),
+    other => Err(err_invalid_feel_conversion(other, type_name)),
+  }
+}
+
+/// Converts the value of the named context entry, used by derived [FromFeel] implementations.
+///
+/// Missing entry is treated like an entry having `null` value,
+/// so it is accepted only when the target type is an [Option].
+pub fn from_feel_entry<T: FromFeel>(context: &FeelContext, name: &str) -> Result<T> {
+  match context.get_entry(&Name::from(name)) {
+    Some(value) => T::from_feel(value),
+    None => T::from_feel(&value_null!()).map_err(|_| err_missing_context_entry(name)),
+  }
+}
+
+/// Returns the index of the variant with the name equal to the string value,
+/// used by derived [FromFeel] implementations.
+pub fn variant_index(value: &Value, type_name: &str, variant_names: &[&str]) -> Result<usize> {
+  if let Value::String(name) = value {
+    if let Some(index) = variant_names.iter().position(|variant_name| variant_name == name) {
+      return Ok(index);
+    }
+  }
+  Err(err_invalid_feel_conversion(value, type_name))
+}
@@ -103,3 +103,17 @@ struct BifError(String);
 pub fn err_unknown_function_name(name: &str) -> DmntkError {
   BifError(format!("unknown built-in function name: {name}")).into()
 }
+
+/// Conversion errors.
+#[derive(ToErrorMessage)]
+struct ConversionError(String);
+
+/// Creates an error indicating that a value can not be converted into Rust type.
+pub fn err_invalid_feel_conversion(value: &Value, type_name: &str) -> DmntkError {
+  ConversionError(format!("value '{value}' of type '{}' can not be converted into '{type_name}'", value.type_of())).into()
+}
+
+/// Creates an error indicating that a required context entry is missing.
+pub fn err_missing_context_entry(name: &str) -> DmntkError {
+  ConversionError(format!("missing required context entry '{name}'")).into()
+}
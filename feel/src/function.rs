@@ -57,6 +57,14 @@ pub enum FunctionBody {
   Invocation(FunctionBodyEvaluator),
   /// Function body created from relation defined in `DMN` model.
   Relation(FunctionBodyEvaluator),
+  /// Function body created from conditional defined in `DMN` model.
+  Conditional(FunctionBodyEvaluator),
+  /// Function body created from filter defined in `DMN` model.
+  Filter(FunctionBodyEvaluator),
+  /// Function body created from iterator (`for`, `every` or `some`) defined in `DMN` model.
+  Iterator(FunctionBodyEvaluator),
+  /// Function body created from list defined in `DMN` model.
+  List(FunctionBodyEvaluator),
   /// Function body created from decision service defined in `DMN` model.
   DecisionService(FunctionBodyEvaluator),
   /// Function body created from externally defined function in `Java` or `PMML`.
@@ -73,6 +81,10 @@ impl FunctionBody {
       FunctionBody::FunctionDefinition(evaluator) => evaluator(scope),
       FunctionBody::Invocation(evaluator) => evaluator(scope),
       FunctionBody::Relation(evaluator) => evaluator(scope),
+      FunctionBody::Conditional(evaluator) => evaluator(scope),
+      FunctionBody::Filter(evaluator) => evaluator(scope),
+      FunctionBody::Iterator(evaluator) => evaluator(scope),
+      FunctionBody::List(evaluator) => evaluator(scope),
       FunctionBody::DecisionService(evaluator) => evaluator(scope),
       FunctionBody::External(evaluator) => evaluator(scope),
     }
@@ -89,6 +101,10 @@ impl Debug for FunctionBody {
       FunctionBody::FunctionDefinition(_) => write!(f, "FunctionBodyFunctionDefinition"),
       FunctionBody::Invocation(_) => write!(f, "FunctionBodyInvocation"),
       FunctionBody::Relation(_) => write!(f, "FunctionBodyRelation"),
+      FunctionBody::Conditional(_) => write!(f, "FunctionBodyConditional"),
+      FunctionBody::Filter(_) => write!(f, "FunctionBodyFilter"),
+      FunctionBody::Iterator(_) => write!(f, "FunctionBodyIterator"),
+      FunctionBody::List(_) => write!(f, "FunctionBodyList"),
       FunctionBody::DecisionService(_) => write!(f, "FunctionBodyDecisionService"),
       FunctionBody::External(_) => write!(f, "FunctionBodyExternal"),
     }
@@ -105,6 +121,10 @@ impl PartialEq for FunctionBody {
       FunctionBody::FunctionDefinition(_) => matches!(other, FunctionBody::FunctionDefinition(_)),
       FunctionBody::Invocation(_) => matches!(other, FunctionBody::Invocation(_)),
       FunctionBody::Relation(_) => matches!(other, FunctionBody::Relation(_)),
+      FunctionBody::Conditional(_) => matches!(other, FunctionBody::Conditional(_)),
+      FunctionBody::Filter(_) => matches!(other, FunctionBody::Filter(_)),
+      FunctionBody::Iterator(_) => matches!(other, FunctionBody::Iterator(_)),
+      FunctionBody::List(_) => matches!(other, FunctionBody::List(_)),
       FunctionBody::DecisionService(_) => matches!(other, FunctionBody::DecisionService(_)),
       FunctionBody::External(_) => matches!(other, FunctionBody::External(_)),
     }
@@ -34,16 +34,20 @@
 
 #[macro_use]
 extern crate dmntk_macros;
+extern crate self as dmntk_feel;
 
 pub mod bif;
 pub mod closure;
 pub mod context;
+pub mod convert;
 mod errors;
 mod evaluator;
 mod function;
 mod names;
 mod qualified_names;
 mod scope;
+#[cfg(feature = "serde")]
+mod serialization;
 mod strings;
 mod types;
 pub mod values;
@@ -51,7 +55,9 @@ pub mod values;
 #[cfg(test)]
 mod tests;
 
-pub use dmntk_feel_number::FeelNumber;
+pub use convert::{FromFeel, IntoFeel};
+pub use dmntk_feel_number::{FeelNumber, FeelNumberStatus};
+pub use dmntk_macros::{FromFeel, IntoFeel};
 pub use evaluator::Evaluator;
 pub use function::FunctionBody;
 pub use names::Name;
@@ -37,6 +37,7 @@ use std::fmt;
 
 /// `FEEL` name.
 #[derive(Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
 pub struct Name(String);
 
 impl From<Vec<String>> for Name {
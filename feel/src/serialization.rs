@@ -0,0 +1,187 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Implementation of `serde` serialization for `FEEL` values.
+//!
+//! Only values that may appear as inputs or results of evaluation are serializable,
+//! all intermediate values produced by the parser and evaluator are rejected.
+//!
+//! [Value] is represented as an externally tagged enumeration,
+//! tagged with the name of the `FEEL` type of the value:
+//!
+//! | `FEEL` value                  | representation (JSON)                                                             |
+//! |-------------------------------|-----------------------------------------------------------------------------------|
+//! | `null`                        | `{"null":null}` or `{"null":"tracing message"}`                                   |
+//! | boolean                       | `{"boolean":true}`                                                                |
+//! | number                        | `{"number":"1.25"}`                                                               |
+//! | string                        | `{"string":"Alice"}`                                                              |
+//! | date                          | `{"date":"2023-05-17"}`                                                           |
+//! | time                          | `{"time":"10:23:45@Europe/Warsaw"}`                                               |
+//! | date and time                 | `{"date and time":"2023-05-17T10:23:45+02:00"}`                                   |
+//! | days and time duration        | `{"days and time duration":"P2DT3H"}`                                             |
+//! | years and months duration     | `{"years and months duration":"P1Y2M"}`                                           |
+//! | list                          | `{"list":[{"number":"1"},{"number":"2"}]}`                                        |
+//! | context                       | `{"context":{"age":{"number":"49"}}}`                                             |
+//! | range                         | `{"range":{"start":…,"start included":true,"end":…,"end included":false}}`        |
+//! | irrelevant                    | `"irrelevant"`                                                                    |
+//!
+//! Numbers and temporal values are serialized as strings in `FEEL` literal format,
+//! so the representation is lossless and deserializing it gives back an equal value.
+
+use crate::context::FeelContext;
+use crate::values::{Value, Values};
+use dmntk_feel_number::FeelNumber;
+use dmntk_feel_temporal::{FeelDate, FeelDateTime, FeelDaysAndTimeDuration, FeelTime, FeelYearsAndMonthsDuration};
+use serde::ser::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Borrowed representation of serializable [Value] variants.
+#[derive(Serialize)]
+#[serde(rename_all = "lowercase")]
+enum ValueRef<'a> {
+  Null(&'a Option<String>),
+  Boolean(&'a bool),
+  Number(&'a FeelNumber),
+  String(&'a String),
+  Date(&'a FeelDate),
+  Time(&'a FeelTime),
+  #[serde(rename = "date and time")]
+  DateTime(&'a FeelDateTime),
+  #[serde(rename = "days and time duration")]
+  DaysAndTimeDuration(&'a FeelDaysAndTimeDuration),
+  #[serde(rename = "years and months duration")]
+  YearsAndMonthsDuration(&'a FeelYearsAndMonthsDuration),
+  List(&'a Values),
+  Context(&'a FeelContext),
+  Range(RangeRef<'a>),
+  Irrelevant,
+}
+
+/// Borrowed representation of range bounds.
+#[derive(Serialize)]
+struct RangeRef<'a> {
+  start: &'a Value,
+  #[serde(rename = "start included")]
+  start_included: bool,
+  end: &'a Value,
+  #[serde(rename = "end included")]
+  end_included: bool,
+}
+
+/// Owned representation of serializable [Value] variants.
+#[derive(Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum ValueRepr {
+  Null(Option<String>),
+  Boolean(bool),
+  Number(FeelNumber),
+  String(String),
+  Date(FeelDate),
+  Time(FeelTime),
+  #[serde(rename = "date and time")]
+  DateTime(FeelDateTime),
+  #[serde(rename = "days and time duration")]
+  DaysAndTimeDuration(FeelDaysAndTimeDuration),
+  #[serde(rename = "years and months duration")]
+  YearsAndMonthsDuration(FeelYearsAndMonthsDuration),
+  List(Values),
+  Context(FeelContext),
+  Range(RangeRepr),
+  Irrelevant,
+}
+
+/// Owned representation of range bounds.
+#[derive(Deserialize)]
+struct RangeRepr {
+  start: Box<Value>,
+  #[serde(rename = "start included")]
+  start_included: bool,
+  end: Box<Value>,
+  #[serde(rename = "end included")]
+  end_included: bool,
+}
+
+impl From<ValueRepr> for Value {
+  /// Converts deserialized representation into [Value].
+  fn from(value: ValueRepr) -> Self {
+    match value {
+      ValueRepr::Null(message) => Value::Null(message),
+      ValueRepr::Boolean(value) => Value::Boolean(value),
+      ValueRepr::Number(value) => Value::Number(value),
+      ValueRepr::String(value) => Value::String(value),
+      ValueRepr::Date(value) => Value::Date(value),
+      ValueRepr::Time(value) => Value::Time(value),
+      ValueRepr::DateTime(value) => Value::DateTime(value),
+      ValueRepr::DaysAndTimeDuration(value) => Value::DaysAndTimeDuration(value),
+      ValueRepr::YearsAndMonthsDuration(value) => Value::YearsAndMonthsDuration(value),
+      ValueRepr::List(values) => Value::List(values),
+      ValueRepr::Context(context) => Value::Context(context),
+      ValueRepr::Range(range) => Value::Range(range.start, range.start_included, range.end, range.end_included),
+      ValueRepr::Irrelevant => Value::Irrelevant,
+    }
+  }
+}
+
+impl Serialize for Value {
+  /// Serializes [Value], fails for values that are not `FEEL` data values.
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    let value_ref = match self {
+      Value::Null(message) => ValueRef::Null(message),
+      Value::Boolean(value) => ValueRef::Boolean(value),
+      Value::Number(value) => ValueRef::Number(value),
+      Value::String(value) => ValueRef::String(value),
+      Value::Date(value) => ValueRef::Date(value),
+      Value::Time(value) => ValueRef::Time(value),
+      Value::DateTime(value) => ValueRef::DateTime(value),
+      Value::DaysAndTimeDuration(value) => ValueRef::DaysAndTimeDuration(value),
+      Value::YearsAndMonthsDuration(value) => ValueRef::YearsAndMonthsDuration(value),
+      Value::List(values) => ValueRef::List(values),
+      Value::Context(context) => ValueRef::Context(context),
+      Value::Range(start, start_included, end, end_included) => ValueRef::Range(RangeRef {
+        start: start.as_ref(),
+        start_included: *start_included,
+        end: end.as_ref(),
+        end_included: *end_included,
+      }),
+      Value::Irrelevant => ValueRef::Irrelevant,
+      other => return Err(S::Error::custom(format!("value of type '{}' can not be serialized", other.type_of()))),
+    };
+    value_ref.serialize(serializer)
+  }
+}
+
+impl<'de> Deserialize<'de> for Value {
+  /// Deserializes [Value] from its tagged representation.
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    ValueRepr::deserialize(deserializer).map(Value::from)
+  }
+}
@@ -30,7 +30,7 @@
  * limitations under the License.
  */
 
-use crate::bif::{is_built_in_date_time_function_name, is_built_in_function_name, Bif};
+use crate::bif::{built_in_function_names, extension_function_names, is_built_in_date_time_function_name, is_built_in_function_name, is_extension_function_name, Bif};
 use std::str::FromStr;
 
 #[test]
@@ -120,11 +120,53 @@ fn test_built_in_function_from_string() {
   assert_eq!(Bif::YearsAndMonthsDuration, Bif::from_str("years and months duration").unwrap());
 }
 
+#[test]
+#[cfg(feature = "calendar")]
+fn test_extension_function_from_string() {
+  assert_eq!(Bif::AddBusinessDays, Bif::from_str("dmntk.add business days").unwrap());
+  assert_eq!(Bif::BusinessDaysBetween, Bif::from_str("dmntk.business days between").unwrap());
+  assert_eq!(Bif::IsHoliday, Bif::from_str("dmntk.is holiday").unwrap());
+}
+
+#[test]
+#[cfg(not(feature = "calendar"))]
+fn test_extension_function_from_string() {
+  assert!(Bif::from_str("dmntk.add business days").is_err());
+  assert!(Bif::from_str("dmntk.business days between").is_err());
+  assert!(Bif::from_str("dmntk.is holiday").is_err());
+}
+
 #[test]
 fn test_built_in_function_from_invalid_string() {
   assert_eq!("<BifError> unknown built-in function name: powering", Bif::from_str("powering").err().unwrap().to_string());
 }
 
+#[test]
+fn test_built_in_function_names() {
+  let names = built_in_function_names().collect::<Vec<&str>>();
+  assert_eq!(74, names.len());
+  assert!(names.windows(2).all(|pair| pair[0] < pair[1]));
+  for name in names {
+    assert!(is_built_in_function_name(name), "{name}");
+    assert!(!is_extension_function_name(name), "{name}");
+  }
+}
+
+#[test]
+fn test_extension_function_names() {
+  let names = extension_function_names().collect::<Vec<&str>>();
+  #[cfg(feature = "calendar")]
+  assert_eq!(3, names.len());
+  #[cfg(not(feature = "calendar"))]
+  assert!(names.is_empty());
+  assert!(names.windows(2).all(|pair| pair[0] < pair[1]));
+  for name in names {
+    assert!(is_extension_function_name(name), "{name}");
+  }
+  assert!(!is_extension_function_name("add business days"));
+  assert!(!is_extension_function_name("dmntk.abs"));
+}
+
 #[test]
 fn test_is_built_in_function_name() {
   assert!(is_built_in_function_name("abs"));
@@ -0,0 +1,147 @@
+use crate::context::FeelContext;
+use crate::values::Value;
+use crate::{value_null, value_number, FeelNumber, FromFeel, IntoFeel, Name};
+use dmntk_feel_temporal::FeelDate;
+
+#[derive(IntoFeel, FromFeel, Debug, PartialEq)]
+enum Status {
+  Active,
+  #[feel(name = "on hold")]
+  OnHold,
+}
+
+#[derive(IntoFeel, FromFeel, Debug, PartialEq)]
+struct Address {
+  street: String,
+  #[feel(name = "postal code")]
+  postal_code: String,
+}
+
+#[derive(IntoFeel, FromFeel, Debug, PartialEq)]
+struct Customer {
+  name: String,
+  age: u32,
+  vip: bool,
+  #[feel(name = "birth date")]
+  birth_date: FeelDate,
+  status: Status,
+  #[feel(name = "home address")]
+  home_address: Address,
+  #[feel(name = "previous addresses")]
+  previous_addresses: Vec<Address>,
+  discount: Option<FeelNumber>,
+}
+
+#[derive(IntoFeel, FromFeel, Debug, PartialEq)]
+struct Range<T> {
+  start: T,
+  end: T,
+}
+
+fn customer() -> Customer {
+  Customer {
+    name: "Alice".to_string(),
+    age: 49,
+    vip: true,
+    birth_date: "1974-05-17".parse().unwrap(),
+    status: Status::OnHold,
+    home_address: Address {
+      street: "Main".to_string(),
+      postal_code: "00-001".to_string(),
+    },
+    previous_addresses: vec![],
+    discount: None,
+  }
+}
+
+#[test]
+fn _0001() {
+  assert_eq!(Value::String("Active".to_string()), Status::Active.into_feel());
+  assert_eq!(Value::String("on hold".to_string()), Status::OnHold.into_feel());
+  assert_eq!(Status::OnHold, Status::from_feel(&Value::String("on hold".to_string())).unwrap());
+}
+
+#[test]
+fn _0002() {
+  assert_eq!(
+    r#"<ConversionError> value '"OnHold"' of type 'string' can not be converted into 'Status'"#,
+    Status::from_feel(&Value::String("OnHold".to_string())).unwrap_err().to_string()
+  );
+}
+
+#[test]
+fn _0003() {
+  assert_eq!(
+    r#"{age: 49, birth date: 1974-05-17, discount: null, home address: {postal code: "00-001", street: "Main"}, name: "Alice", previous addresses: [], status: "on hold", vip: true}"#,
+    customer().into_feel().to_string()
+  );
+}
+
+#[test]
+fn _0004() {
+  let value = customer().into_feel();
+  assert_eq!(customer(), Customer::from_feel(&value).unwrap());
+}
+
+#[test]
+fn _0005() {
+  let Value::Context(mut ctx) = customer().into_feel() else { unreachable!() };
+  ctx.remove_entry(&Name::from("discount"));
+  let home_address = ctx.get_entry(&Name::from("home address")).unwrap().clone();
+  ctx.set_entry(&Name::from("previous addresses"), Value::List(vec![home_address]));
+  let customer = Customer::from_feel(&Value::Context(ctx)).unwrap();
+  assert_eq!(None, customer.discount);
+  assert_eq!(1, customer.previous_addresses.len());
+  assert_eq!("Main", customer.previous_addresses[0].street);
+}
+
+#[test]
+fn _0006() {
+  let Value::Context(mut ctx) = customer().into_feel() else { unreachable!() };
+  ctx.remove_entry(&Name::from("home address"));
+  assert_eq!(
+    "<ConversionError> missing required context entry 'home address'",
+    Customer::from_feel(&Value::Context(ctx)).unwrap_err().to_string()
+  );
+}
+
+#[test]
+fn _0007() {
+  assert_eq!(
+    "<ConversionError> value 'null' of type 'Null' can not be converted into 'Address'",
+    Address::from_feel(&value_null!()).unwrap_err().to_string()
+  );
+}
+
+#[test]
+fn _0008() {
+  assert_eq!(10, i32::from_feel(&value_number!(10)).unwrap());
+  assert_eq!(
+    "<ConversionError> value '-1' of type 'number' can not be converted into 'u8'",
+    u8::from_feel(&value_number!(-1)).unwrap_err().to_string()
+  );
+  assert_eq!(
+    Some(vec![1_u64, 2]),
+    Option::<Vec<u64>>::from_feel(&Value::List(vec![value_number!(1), value_number!(2)])).unwrap()
+  );
+  assert_eq!(None, Option::<Vec<u64>>::from_feel(&value_null!()).unwrap());
+  assert_eq!(Value::Context(FeelContext::default()), FeelContext::default().into_feel());
+}
+
+#[test]
+fn _0009() {
+  let range = Range { start: 1_u32, end: 10 };
+  assert_eq!("{end: 10, start: 1}", range.into_feel().to_string());
+  let value = Range {
+    start: Status::Active,
+    end: Status::OnHold,
+  }
+  .into_feel();
+  assert_eq!(
+    Range {
+      start: Status::Active,
+      end: Status::OnHold
+    },
+    Range::<Status>::from_feel(&value).unwrap()
+  );
+}
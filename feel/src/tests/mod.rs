@@ -32,10 +32,13 @@
 
 mod bif;
 mod context;
+mod convert;
 mod function;
 mod names;
 mod qualified_names;
 mod scope;
+#[cfg(feature = "serde")]
+mod serialization;
 mod types;
 mod values;
 
@@ -0,0 +1,108 @@
+use crate::context::FeelContext;
+use crate::names::Name;
+use crate::values::Value;
+use crate::{value_null, value_number, FeelNumber, FeelType};
+use dmntk_feel_temporal::{FeelDate, FeelDateTime, FeelDaysAndTimeDuration, FeelTime, FeelYearsAndMonthsDuration};
+use std::collections::BTreeMap;
+
+fn round_trip(expected_json: &str, value: Value) {
+  let json = serde_json::to_string(&value).unwrap();
+  assert_eq!(expected_json, json);
+  assert_eq!(value, serde_json::from_str::<Value>(&json).unwrap());
+}
+
+#[test]
+fn _0001() {
+  round_trip(r#"{"null":null}"#, value_null!());
+  round_trip(r#"{"null":"missing input"}"#, value_null!("missing input"));
+  round_trip(r#"{"boolean":true}"#, Value::Boolean(true));
+  round_trip(r#"{"string":"Alice"}"#, Value::String("Alice".to_string()));
+  round_trip(r#""irrelevant""#, Value::Irrelevant);
+}
+
+#[test]
+fn _0002() {
+  round_trip(r#"{"number":"1.25"}"#, value_number!(125, 2));
+  round_trip(
+    r#"{"number":"12345678901234567890.12345678901234"}"#,
+    Value::Number("12345678901234567890.12345678901234".parse::<FeelNumber>().unwrap()),
+  );
+}
+
+#[test]
+fn _0003() {
+  round_trip(r#"{"date":"2023-05-17"}"#, Value::Date("2023-05-17".parse::<FeelDate>().unwrap()));
+  round_trip(r#"{"time":"10:23:45@Europe/Warsaw"}"#, Value::Time("10:23:45@Europe/Warsaw".parse::<FeelTime>().unwrap()));
+  round_trip(
+    r#"{"date and time":"2023-05-17T10:23:45+02:00"}"#,
+    Value::DateTime(FeelDateTime::try_from("2023-05-17T10:23:45+02:00").unwrap()),
+  );
+  round_trip(
+    r#"{"days and time duration":"P2DT3H"}"#,
+    Value::DaysAndTimeDuration(FeelDaysAndTimeDuration::try_from("P2DT3H").unwrap()),
+  );
+  round_trip(
+    r#"{"years and months duration":"P1Y2M"}"#,
+    Value::YearsAndMonthsDuration(FeelYearsAndMonthsDuration::try_from("P1Y2M").unwrap()),
+  );
+}
+
+#[test]
+fn _0004() {
+  round_trip(
+    r#"{"list":[{"number":"1"},{"string":"a"},{"null":null}]}"#,
+    Value::List(vec![value_number!(1), Value::String("a".to_string()), value_null!()]),
+  );
+}
+
+#[test]
+fn _0005() {
+  let mut inner = FeelContext::default();
+  inner.set_entry(&Name::from("street"), Value::String("Main".to_string()));
+  let mut ctx = FeelContext::default();
+  ctx.set_entry(&Name::from("age"), value_number!(49));
+  ctx.set_entry(&Name::from("home address"), Value::Context(inner));
+  round_trip(
+    r#"{"context":{"age":{"number":"49"},"home address":{"context":{"street":{"string":"Main"}}}}}"#,
+    Value::Context(ctx),
+  );
+}
+
+#[test]
+fn _0006() {
+  round_trip(
+    r#"{"range":{"start":{"number":"1"},"start included":true,"end":{"number":"10"},"end included":false}}"#,
+    Value::Range(Box::new(value_number!(1)), true, Box::new(value_number!(10)), false),
+  );
+}
+
+#[test]
+fn _0007() {
+  let error = serde_json::to_string(&Value::FeelType(FeelType::Number)).unwrap_err();
+  assert_eq!("value of type 'number' can not be serialized", error.to_string());
+}
+
+#[test]
+fn _0008() {
+  assert!(serde_json::from_str::<Value>(r#"{"number":"abc"}"#).is_err());
+  assert!(serde_json::from_str::<Value>(r#"{"date":"2023-02-30"}"#).is_err());
+  assert!(serde_json::from_str::<Value>(r#"{"unknown":1}"#).is_err());
+}
+
+#[test]
+fn _0009() {
+  let feel_type = FeelType::Context(BTreeMap::from([
+    (Name::from("a"), FeelType::list(&FeelType::DateTime)),
+    (Name::from("b"), FeelType::function(&[FeelType::Any], &FeelType::Null)),
+  ]));
+  let json = serde_json::to_string(&feel_type).unwrap();
+  assert_eq!(r#"{"context":{"a":{"list":"date and time"},"b":{"function":[["Any"],"Null"]}}}"#, json);
+  assert_eq!(feel_type, serde_json::from_str::<FeelType>(&json).unwrap());
+}
+
+#[test]
+fn _0010() {
+  let name = Name::from("home address");
+  assert_eq!(r#""home address""#, serde_json::to_string(&name).unwrap());
+  assert_eq!(name, serde_json::from_str::<Name>(r#""home address""#).unwrap());
+}
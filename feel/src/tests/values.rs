@@ -37,7 +37,7 @@ use crate::context::FeelContext;
 use crate::function::FunctionBody;
 use crate::values::{Value, Values};
 use crate::{value_null, value_number, FeelNumber, FeelScope, FeelType, Name, ToFeelString};
-use dmntk_common::Jsonify;
+use dmntk_common::{Jsonify, Position, Span};
 use dmntk_feel_temporal::{FeelDate, FeelDateTime, FeelDaysAndTimeDuration, FeelTime, FeelYearsAndMonthsDuration};
 use std::collections::BTreeMap;
 use std::sync::Arc;
@@ -85,7 +85,7 @@ fn test_debug() {
   eq_dbg!(r#"ContextTypeEntryKey(Name("a"))"#, Value::ContextTypeEntryKey(name.clone()));
   eq_dbg!(r#"Date(FeelDate(2022, 9, 27))"#, Value::Date(v_date));
   eq_dbg!(
-    r#"DateTime(FeelDateTime(FeelDate(2022, 9, 27), FeelTime(12, 13, 23, 0, Local)))"#,
+    r#"DateTime(FeelDateTime(FeelDate(2022, 9, 27), FeelTime(12, 13, 23, 0, Local), None))"#,
     Value::DateTime(v_date_time)
   );
   eq_dbg!(
@@ -511,3 +511,16 @@ fn test_coerced() {
   assert_eq!(r#"null(after coercion)"#, v_irrelevant.coerced(T_NUMBER).to_string());
   assert_eq!(r#"null(after coercion)"#, v_context_d.coerced(&T_CONTEXT_A).to_string());
 }
+
+#[test]
+fn test_with_span() {
+  let span = Span::new(Position::new(4, 1, 5), Position::new(9, 1, 10));
+  assert_eq!("null(division by zero (at 1:5..1:10))", value_null!("division by zero").with_span(&span).to_string());
+  assert_eq!("null", value_null!().with_span(&span).to_string());
+  assert_eq!("1", value_number!(1).with_span(&span).to_string());
+  let outer_span = Span::new(Position::new(0, 1, 1), Position::new(12, 1, 13));
+  assert_eq!(
+    "null(division by zero (at 1:5..1:10))",
+    value_null!("division by zero").with_span(&span).with_span(&outer_span).to_string()
+  );
+}
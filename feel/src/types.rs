@@ -54,9 +54,11 @@ pub const FEEL_TYPE_NAME_TIME: &str = "time";
 pub const FEEL_TYPE_NAME_YEARS_AND_MONTHS_DURATION: &str = "years and months duration";
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "lowercase"))]
 #[must_use]
 pub enum FeelType {
   /// Type representing any valid `FEEL` type.
+  #[cfg_attr(feature = "serde", serde(rename = "Any"))]
   Any,
   /// Type representing a `boolean` value.
   Boolean,
@@ -68,8 +70,10 @@ pub enum FeelType {
   /// Type representing a `date` value.
   Date,
   /// Type representing a`date and time` value.
+  #[cfg_attr(feature = "serde", serde(rename = "date and time"))]
   DateTime,
   /// Type representing a `days and time duration` value.
+  #[cfg_attr(feature = "serde", serde(rename = "days and time duration"))]
   DaysAndTimeDuration,
   /// Type representing a `function` value.
   Function(
@@ -81,6 +85,7 @@ pub enum FeelType {
   /// Type representing a `list` of values.
   List(Box<FeelType>),
   /// Type representing a `null` value.
+  #[cfg_attr(feature = "serde", serde(rename = "Null"))]
   Null,
   /// Type representing a `number` value.
   Number,
@@ -91,6 +96,7 @@ pub enum FeelType {
   /// Type representing a `time` value.
   Time,
   /// Type representing a `years and months duration` value.
+  #[cfg_attr(feature = "serde", serde(rename = "years and months duration"))]
   YearsAndMonthsDuration,
 }
 
@@ -40,7 +40,7 @@ use crate::names::Name;
 use crate::strings::ToFeelString;
 use crate::types::FeelType;
 use crate::FunctionBody;
-use dmntk_common::{Jsonify, Result};
+use dmntk_common::{Jsonify, Result, Span};
 use dmntk_feel_number::FeelNumber;
 use dmntk_feel_temporal::{FeelDate, FeelDateTime, FeelDaysAndTimeDuration, FeelTime, FeelYearsAndMonthsDuration};
 use std::collections::BTreeMap;
@@ -357,6 +357,16 @@ impl Value {
     matches!(self, Value::Null(_))
   }
 
+  /// Returns this value with the reason of `null` value annotated with the span
+  /// of the expression that produced this value. Other values, and `null` values
+  /// already annotated by nested expressions, are returned unchanged.
+  pub fn with_span(self, span: &Span) -> Value {
+    match self {
+      Value::Null(Some(reason)) if !reason.ends_with(')') || !reason.contains(" (at ") => Value::Null(Some(format!("{reason} (at {span})"))),
+      other => other,
+    }
+  }
+
   /// Returns `true` when the value is of type [Value::Boolean] and is equal to `true`.
   pub fn is_true(&self) -> bool {
     matches!(self, Value::Boolean(true))
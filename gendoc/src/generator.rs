@@ -356,6 +356,11 @@ fn create_model_expression_instance(opt_expression_instance: &Option<ExpressionI
   if let Some(expression_instance) = opt_expression_instance {
     let mut container = HtmlElement::new_div(CLASS_EXPRESSION_INSTANCE_CONTAINER);
     match expression_instance {
+      ExpressionInstance::Conditional(_) => {
+        let mut variable_details_heading = HtmlElement::new_div(Some("variable-details-heading"));
+        variable_details_heading.set_content("Decision Logic (Conditional)");
+        container.add_child(variable_details_heading);
+      }
       ExpressionInstance::Context(_) => {
         let mut variable_details_heading = HtmlElement::new_div(Some("variable-details-heading"));
         variable_details_heading.set_content("Decision Logic (Context)");
@@ -367,6 +372,21 @@ fn create_model_expression_instance(opt_expression_instance: &Option<ExpressionI
         container.add_child(variable_details_heading);
         container.add_child(create_horizontal_decision_table_elements(decision_table));
       }
+      ExpressionInstance::Every(_) => {
+        let mut variable_details_heading = HtmlElement::new_div(Some("variable-details-heading"));
+        variable_details_heading.set_content("Decision Logic (Every)");
+        container.add_child(variable_details_heading);
+      }
+      ExpressionInstance::Filter(_) => {
+        let mut variable_details_heading = HtmlElement::new_div(Some("variable-details-heading"));
+        variable_details_heading.set_content("Decision Logic (Filter)");
+        container.add_child(variable_details_heading);
+      }
+      ExpressionInstance::For(_) => {
+        let mut variable_details_heading = HtmlElement::new_div(Some("variable-details-heading"));
+        variable_details_heading.set_content("Decision Logic (For)");
+        container.add_child(variable_details_heading);
+      }
       ExpressionInstance::FunctionDefinition(_) => {
         let mut variable_details_heading = HtmlElement::new_div(Some("variable-details-heading"));
         variable_details_heading.set_content("Decision Logic (Function Definition)");
@@ -377,6 +397,11 @@ fn create_model_expression_instance(opt_expression_instance: &Option<ExpressionI
         variable_details_heading.set_content("Decision Logic (Invocation)");
         container.add_child(variable_details_heading);
       }
+      ExpressionInstance::List(_) => {
+        let mut variable_details_heading = HtmlElement::new_div(Some("variable-details-heading"));
+        variable_details_heading.set_content("Decision Logic (List)");
+        container.add_child(variable_details_heading);
+      }
       ExpressionInstance::LiteralExpression(literal_expression) => {
         let mut variable_details_heading = HtmlElement::new_div(Some("variable-details-heading"));
         variable_details_heading.set_content("Decision Logic (Literal Expression)");
@@ -392,6 +417,11 @@ fn create_model_expression_instance(opt_expression_instance: &Option<ExpressionI
         variable_details_heading.set_content("Decision Logic (Relation)");
         container.add_child(variable_details_heading);
       }
+      ExpressionInstance::Some(_) => {
+        let mut variable_details_heading = HtmlElement::new_div(Some("variable-details-heading"));
+        variable_details_heading.set_content("Decision Logic (Some)");
+        container.add_child(variable_details_heading);
+      }
     }
     return Some(container);
   }
@@ -0,0 +1,329 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Analysis of `FEEL` documents: diagnostics, completion, hover and definitions.
+
+use crate::documents::{Document, DocumentKind};
+use crate::protocol::*;
+use dmntk_feel::bif::{built_in_function_names, extension_function_names};
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::values::Value;
+use dmntk_feel::{FeelScope, FeelType, Name};
+use dmntk_feel_parser::{AstNode, Spans, SyntaxError, TypeChecker};
+use std::collections::BTreeMap;
+
+/// Name of the diagnostics source reported to the client.
+const SOURCE: &str = "dmntk";
+
+/// Maximum number of expected tokens listed in syntax error diagnostic.
+const MAX_EXPECTED_TOKENS: usize = 5;
+
+/// Result of analysing a single document.
+#[derive(Debug, Clone, Default)]
+pub struct Analysis {
+  /// Root node of the parsed document, `None` when parsing failed.
+  pub node: Option<AstNode>,
+  /// Spans of the nodes of the parsed document.
+  pub spans: Spans,
+  /// Diagnostics reported for the document.
+  pub diagnostics: Vec<Diagnostic>,
+  /// Context visible in the document; for context documents this is the evaluated document itself,
+  /// for expression documents this is the evaluated accompanying context file.
+  pub context: FeelContext,
+}
+
+/// Analyses the document. Expression documents are parsed in the scope of specified context.
+pub fn analyze(document: &Document, context: &FeelContext) -> Analysis {
+  let text = document.text();
+  let parsed = match document.kind {
+    DocumentKind::Expression => dmntk_feel_parser::parse_expression_with_spans(&context.clone().into(), &text, false),
+    DocumentKind::Context => dmntk_feel_parser::parse_context_with_spans(&FeelScope::default(), &text, false),
+  };
+  match parsed {
+    Ok((node, spans)) => {
+      let context = match document.kind {
+        DocumentKind::Expression => context.clone(),
+        DocumentKind::Context => dmntk_feel_evaluator::evaluate_context_node(&FeelScope::default(), &node).unwrap_or_default(),
+      };
+      let inputs = match document.kind {
+        DocumentKind::Expression => types_of(&context),
+        DocumentKind::Context => BTreeMap::new(),
+      };
+      let diagnostics = TypeChecker::check(&node, &inputs)
+        .errors
+        .iter()
+        .map(|error| Diagnostic {
+          range: error.span(&spans).or(spans.root()).map(|span| document.range(span)).unwrap_or_default(),
+          severity: SEVERITY_WARNING,
+          source: SOURCE.to_string(),
+          message: error.message.clone(),
+        })
+        .collect();
+      Analysis {
+        node: Some(node),
+        spans,
+        diagnostics,
+        context,
+      }
+    }
+    Err(syntax_error) => Analysis {
+      diagnostics: vec![Diagnostic {
+        range: document.range(&syntax_error.span),
+        severity: SEVERITY_ERROR,
+        source: SOURCE.to_string(),
+        message: syntax_error_message(&syntax_error),
+      }],
+      context: context.clone(),
+      ..Default::default()
+    },
+  }
+}
+
+/// Returns completion proposals for the word preceding specified offset.
+///
+/// When the word is a qualified name ending with a dot, like `Person.`, entries of the context
+/// referenced by the qualifier are proposed. Otherwise, names defined in the context are proposed,
+/// followed by built-in and extension function names. Only proposals starting with the typed
+/// part of the word (ignoring case) are returned.
+pub fn completion(document: &Document, analysis: &Analysis, offset: usize) -> Vec<CompletionItem> {
+  let word = document.word_before(offset);
+  if let Some((qualifier, typed)) = word.rsplit_once('.') {
+    let names = qualifier.split('.').map(Name::from).collect::<Vec<Name>>();
+    if let Some(Value::Context(context)) = analysis.context.search_deep(&names) {
+      return variables(context).filter(|item| starts_with(&item.label, typed)).collect();
+    }
+  }
+  let functions = built_in_function_names().map(|name| CompletionItem {
+    label: name.to_string(),
+    kind: COMPLETION_KIND_FUNCTION,
+    detail: Some("built-in function".to_string()),
+  });
+  let extensions = extension_function_names().map(|name| CompletionItem {
+    label: name.to_string(),
+    kind: COMPLETION_KIND_FUNCTION,
+    detail: Some("extension function".to_string()),
+  });
+  variables(&analysis.context)
+    .chain(functions)
+    .chain(extensions)
+    .filter(|item| starts_with(&item.label, &word))
+    .collect()
+}
+
+/// Returns completion proposals for names of entries defined in the context.
+fn variables(context: &FeelContext) -> impl Iterator<Item = CompletionItem> + '_ {
+  context.iter().map(|(name, value)| CompletionItem {
+    label: name.to_string(),
+    kind: COMPLETION_KIND_VARIABLE,
+    detail: Some(value.type_of().to_string()),
+  })
+}
+
+/// Returns `true` when the label starts with specified prefix, ignoring case.
+fn starts_with(label: &str, prefix: &str) -> bool {
+  label.to_lowercase().starts_with(&prefix.to_lowercase())
+}
+
+/// Returns hover information for the innermost node at specified offset.
+///
+/// Names and context entries defined in the context are described by their values and types,
+/// other expressions by their inferred types and, when the evaluation succeeds, by their values.
+pub fn hover(document: &Document, analysis: &Analysis, offset: usize) -> Option<Hover> {
+  let root = analysis.node.as_ref()?;
+  let path = find_path(&analysis.spans, offset)?;
+  let node = root.node_at(&path)?;
+  let span = analysis.spans.get(&path)?;
+  let names = reference(root, &path).or_else(|| entry_names(root, &path));
+  let (feel_type, value) = match names.as_ref().and_then(|names| analysis.context.search_deep(names)) {
+    Some(value) => (value.type_of(), Some(value.clone())),
+    None => {
+      let feel_type = TypeChecker::check(node, &types_of(&analysis.context)).feel_type;
+      let value = match node {
+        AstNode::FunctionDefinition(..) | AstNode::ContextEntryKey(_) | AstNode::ParameterName(_) => None,
+        _ => dmntk_feel_evaluator::evaluate(&analysis.context.clone().into(), node).ok().filter(|value| !value.is_null()),
+      };
+      (feel_type, value)
+    }
+  };
+  let mut text = format!("`{feel_type}`");
+  if let Some(value) = value {
+    text.push_str(&format!("\n\n```feel\n{value}\n```"));
+  }
+  Some(Hover {
+    contents: MarkupContent {
+      kind: "markdown".to_string(),
+      value: text,
+    },
+    range: document.range(span),
+  })
+}
+
+/// Returns the path of the innermost node at specified offset. When the node ending just before
+/// the offset is nested deeper, like a name directly preceding the cursor, this node is preferred.
+pub fn find_path(spans: &Spans, offset: usize) -> Option<Vec<usize>> {
+  let current = spans.find(offset);
+  let preceding = offset.checked_sub(1).and_then(|offset| spans.find(offset));
+  match (current, preceding) {
+    (Some(current), Some(preceding)) if preceding.len() > current.len() => Some(preceding),
+    (None, preceding) => preceding,
+    (current, _) => current,
+  }
+}
+
+/// Returns the path of the context entry referenced by the name at specified path.
+///
+/// The name is searched in all contexts enclosing the reference, starting from the innermost one.
+/// When `outer` context node is given, the name is finally searched in this node.
+pub fn definition(root: &AstNode, path: &[usize], outer: Option<&AstNode>) -> Option<Definition> {
+  let names = reference(root, path)?;
+  for depth in (0..path.len()).rev() {
+    let ancestor_path = &path[..depth];
+    if let Some(entry_path) = root.node_at(ancestor_path).and_then(|ancestor| find_entry(ancestor, &names)) {
+      let mut key_path = ancestor_path.to_vec();
+      key_path.extend(entry_path);
+      key_path.push(0);
+      return Some(Definition::Local(key_path));
+    }
+  }
+  let mut key_path = find_entry(outer?, &names)?;
+  key_path.push(0);
+  Some(Definition::Outer(key_path))
+}
+
+/// Definition of the referenced context entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Definition {
+  /// Path of the entry key in the same document.
+  Local(Vec<usize>),
+  /// Path of the entry key in the outer context document.
+  Outer(Vec<usize>),
+}
+
+/// Returns the names of the context entries referenced by the name at specified path.
+/// For names being segments of path expressions or qualified names, all preceding segments are included.
+fn reference(root: &AstNode, path: &[usize]) -> Option<Vec<Name>> {
+  let name = match root.node_at(path)? {
+    AstNode::Name(name) | AstNode::QualifiedNameSegment(name) => name.clone(),
+    _ => return None,
+  };
+  let mut top = path.to_vec();
+  while let Some((_, parent_path)) = top.split_last() {
+    match root.node_at(parent_path) {
+      Some(AstNode::Path(..)) | Some(AstNode::QualifiedName(_)) => {
+        top.pop();
+      }
+      _ => break,
+    }
+  }
+  if top.len() == path.len() {
+    return Some(vec![name]);
+  }
+  let mut segments = vec![];
+  if !segments_of(root.node_at(&top)?, &mut top, &mut segments) {
+    return None;
+  }
+  let index = segments.iter().position(|(segment_path, _)| segment_path == path)?;
+  Some(segments.into_iter().take(index + 1).map(|(_, name)| name).collect())
+}
+
+/// Collects the segments of path expression or qualified name, returns `false`
+/// when the expression contains anything else than names.
+fn segments_of(node: &AstNode, path: &mut Vec<usize>, segments: &mut Vec<(Vec<usize>, Name)>) -> bool {
+  match node {
+    AstNode::Name(name) | AstNode::QualifiedNameSegment(name) => {
+      segments.push((path.clone(), name.clone()));
+      true
+    }
+    AstNode::Path(..) | AstNode::QualifiedName(_) => node.children().iter().enumerate().all(|(index, child)| {
+      path.push(index);
+      let collected = segments_of(child, path, segments);
+      path.pop();
+      collected
+    }),
+    _ => false,
+  }
+}
+
+/// Returns the names of the context entries leading to the context entry key at specified path.
+fn entry_names(root: &AstNode, path: &[usize]) -> Option<Vec<Name>> {
+  let AstNode::ContextEntryKey(_) = root.node_at(path)? else {
+    return None;
+  };
+  let mut names = vec![];
+  for depth in 0..path.len() {
+    if let AstNode::ContextEntry(key, _) = root.node_at(&path[..depth])? {
+      if let AstNode::ContextEntryKey(name) = key.as_ref() {
+        names.push(name.clone());
+      }
+    }
+  }
+  Some(names)
+}
+
+/// Searches the context node for the entry pointed by names, returns the path of the entry.
+fn find_entry(node: &AstNode, names: &[Name]) -> Option<Vec<usize>> {
+  let (first, tail) = names.split_first()?;
+  let AstNode::Context(entries) = node else {
+    return None;
+  };
+  entries.iter().enumerate().find_map(|(index, entry)| match entry {
+    AstNode::ContextEntry(key, value) if matches!(key.as_ref(), AstNode::ContextEntryKey(name) if name == first) => {
+      if tail.is_empty() {
+        Some(vec![index])
+      } else {
+        find_entry(value, tail).map(|mut entry_path| {
+          entry_path.splice(0..0, [index, 1]);
+          entry_path
+        })
+      }
+    }
+    _ => None,
+  })
+}
+
+/// Returns types of all entries in the context.
+fn types_of(context: &FeelContext) -> BTreeMap<Name, FeelType> {
+  context.iter().map(|(name, value)| (name.clone(), value.type_of())).collect()
+}
+
+/// Prepares the message of syntax error diagnostic.
+fn syntax_error_message(syntax_error: &SyntaxError) -> String {
+  let mut message = match &syntax_error.token {
+    Some(token) => format!("unexpected '{token}'"),
+    None => "unexpected end of input".to_string(),
+  };
+  if !syntax_error.expected.is_empty() && syntax_error.expected.len() <= MAX_EXPECTED_TOKENS {
+    let expected = syntax_error.expected.iter().map(|name| format!("'{name}'")).collect::<Vec<String>>();
+    message.push_str(&format!(", expected {}", expected.join(", ")));
+  }
+  message
+}
@@ -0,0 +1,150 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Text documents edited by the client.
+
+use crate::protocol::{Position, Range};
+use dmntk_common::Span;
+use std::fs;
+use url::Url;
+
+/// Extension of files containing contexts.
+const CONTEXT_FILE_EXTENSION: &str = ".ctx";
+
+/// Extension of files containing `FEEL` expressions.
+const FEEL_FILE_EXTENSION: &str = ".feel";
+
+/// Kind of the text document.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DocumentKind {
+  /// Document containing a single `FEEL` expression.
+  Expression,
+  /// Document containing a `FEEL` context, used as a parsing context or input data.
+  Context,
+}
+
+/// Text document.
+#[derive(Debug, Clone)]
+pub struct Document {
+  /// URI of the document.
+  pub uri: String,
+  /// Characters of the document.
+  chars: Vec<char>,
+  /// Kind of the document, derived from file extension.
+  pub kind: DocumentKind,
+}
+
+impl Document {
+  /// Creates a new document with specified URI and text.
+  pub fn new(uri: &str, text: &str) -> Self {
+    let kind = if uri.ends_with(CONTEXT_FILE_EXTENSION) {
+      DocumentKind::Context
+    } else {
+      DocumentKind::Expression
+    };
+    Self {
+      uri: uri.to_string(),
+      chars: text.chars().collect(),
+      kind,
+    }
+  }
+
+  /// Returns the text of the document.
+  pub fn text(&self) -> String {
+    self.chars.iter().collect()
+  }
+
+  /// Returns the character offset of specified position.
+  /// Positions beyond the end of the line or document are clamped.
+  pub fn offset_at(&self, position: &Position) -> usize {
+    let mut offset = 0;
+    let mut line = 0;
+    while line < position.line && offset < self.chars.len() {
+      if self.chars[offset] == '\n' {
+        line += 1;
+      }
+      offset += 1;
+    }
+    let mut character = 0;
+    while character < position.character as usize && offset < self.chars.len() && self.chars[offset] != '\n' {
+      character += self.chars[offset].len_utf16();
+      offset += 1;
+    }
+    offset
+  }
+
+  /// Returns the word preceding specified offset, the word is made of
+  /// characters allowed in names and dots separating the segments of qualified names.
+  pub fn word_before(&self, offset: usize) -> String {
+    let end = offset.min(self.chars.len());
+    let start = self.chars[..end]
+      .iter()
+      .rposition(|ch| !(ch.is_alphanumeric() || matches!(ch, '_' | '?' | '\'' | '.')))
+      .map_or(0, |position| position + 1);
+    self.chars[start..end].iter().collect()
+  }
+
+  /// Returns the position of the character at specified offset.
+  pub fn position_at(&self, offset: usize) -> Position {
+    let mut position = Position::default();
+    for ch in self.chars.iter().take(offset) {
+      if *ch == '\n' {
+        position.line += 1;
+        position.character = 0;
+      } else {
+        position.character += ch.len_utf16() as u32;
+      }
+    }
+    position
+  }
+
+  /// Returns the range corresponding to specified span.
+  pub fn range(&self, span: &Span) -> Range {
+    Range {
+      start: self.position_at(span.start.offset),
+      end: self.position_at(span.end.offset),
+    }
+  }
+}
+
+/// Returns the URI of the context file accompanying the `FEEL` file with specified URI,
+/// the context file has the same name as the `FEEL` file and `.ctx` extension.
+pub fn context_uri(uri: &str) -> Option<String> {
+  uri.strip_suffix(FEEL_FILE_EXTENSION).map(|base| format!("{base}{CONTEXT_FILE_EXTENSION}"))
+}
+
+/// Loads the document with specified URI from file system.
+pub fn load_document(uri: &str) -> Option<Document> {
+  let path = Url::parse(uri).ok()?.to_file_path().ok()?;
+  let text = fs::read_to_string(path).ok()?;
+  Some(Document::new(uri, &text))
+}
@@ -0,0 +1,59 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Language server errors.
+
+use dmntk_common::{DmntkError, ToErrorMessage};
+
+/// Language server errors.
+#[derive(ToErrorMessage)]
+struct LspError(String);
+
+/// Creates an error indicating that reading or writing a message failed.
+pub fn err_io(reason: &str) -> DmntkError {
+  LspError(format!("input/output failed with reason: {reason}")).into()
+}
+
+/// Creates an error indicating invalid header of the message.
+pub fn err_invalid_header(header: &str) -> DmntkError {
+  LspError(format!("invalid message header: {header}")).into()
+}
+
+/// Creates an error indicating missing `Content-Length` header.
+pub fn err_missing_content_length() -> DmntkError {
+  LspError("missing Content-Length header".to_string()).into()
+}
+
+/// Creates an error indicating that the content of the message is not a valid JSON-RPC message.
+pub fn err_invalid_message(reason: &str) -> DmntkError {
+  LspError(format!("invalid message: {reason}")).into()
+}
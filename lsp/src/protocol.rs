@@ -0,0 +1,208 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Messages of the Language Server Protocol.
+//!
+//! Only the subset of the protocol used by this server is defined,
+//! messages are exchanged as JSON-RPC 2.0 payloads preceded by `Content-Length` header.
+
+use crate::errors::*;
+use dmntk_common::Result;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::io::{BufRead, Write};
+
+/// Diagnostic severity: error.
+pub const SEVERITY_ERROR: u8 = 1;
+
+/// Diagnostic severity: warning.
+pub const SEVERITY_WARNING: u8 = 2;
+
+/// Completion item kind: function.
+pub const COMPLETION_KIND_FUNCTION: u8 = 3;
+
+/// Completion item kind: variable.
+pub const COMPLETION_KIND_VARIABLE: u8 = 6;
+
+/// Error code reported for unsupported requests.
+pub const METHOD_NOT_FOUND: i64 = -32601;
+
+/// Error code reported for requests with invalid parameters.
+pub const INVALID_PARAMS: i64 = -32602;
+
+/// Incoming message, request or notification.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Message {
+  /// Identifier of the request, `None` for notifications.
+  pub id: Option<Value>,
+  /// Name of the method, `None` for responses sent by the client.
+  pub method: Option<String>,
+  /// Parameters of the request or notification.
+  #[serde(default)]
+  pub params: Value,
+}
+
+/// Position in a text document, zero-based line and character offset in UTF-16 code units.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Position {
+  pub line: u32,
+  pub character: u32,
+}
+
+/// Range in a text document, the end position is exclusive.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Range {
+  pub start: Position,
+  pub end: Position,
+}
+
+/// Location inside a resource.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Location {
+  pub uri: String,
+  pub range: Range,
+}
+
+/// Diagnostic reported for a text document.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Diagnostic {
+  pub range: Range,
+  pub severity: u8,
+  pub source: String,
+  pub message: String,
+}
+
+/// Completion proposal.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CompletionItem {
+  pub label: String,
+  pub kind: u8,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub detail: Option<String>,
+}
+
+/// Content of the hover.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MarkupContent {
+  pub kind: String,
+  pub value: String,
+}
+
+/// Hover information.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Hover {
+  pub contents: MarkupContent,
+  pub range: Range,
+}
+
+/// Text document transferred from the client.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TextDocumentItem {
+  pub uri: String,
+  pub text: String,
+}
+
+/// Identifier of the text document.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TextDocumentIdentifier {
+  pub uri: String,
+}
+
+/// Change of the text document, only full content changes are requested by the server.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TextDocumentContentChangeEvent {
+  pub text: String,
+}
+
+/// Parameters of `textDocument/didOpen` notification.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidOpenTextDocumentParams {
+  pub text_document: TextDocumentItem,
+}
+
+/// Parameters of `textDocument/didChange` notification.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidChangeTextDocumentParams {
+  pub text_document: TextDocumentIdentifier,
+  pub content_changes: Vec<TextDocumentContentChangeEvent>,
+}
+
+/// Parameters of `textDocument/didClose` notification.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidCloseTextDocumentParams {
+  pub text_document: TextDocumentIdentifier,
+}
+
+/// Parameters of requests referring to a position in text document.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextDocumentPositionParams {
+  pub text_document: TextDocumentIdentifier,
+  pub position: Position,
+}
+
+/// Reads a single message, returns `None` when the input is closed.
+pub fn read_message(reader: &mut impl BufRead) -> Result<Option<Message>> {
+  let mut content_length = None;
+  loop {
+    let mut header = String::new();
+    if reader.read_line(&mut header).map_err(|reason| err_io(&reason.to_string()))? == 0 {
+      return Ok(None);
+    }
+    let header = header.trim_end();
+    if header.is_empty() {
+      break;
+    }
+    let Some((name, value)) = header.split_once(':') else {
+      return Err(err_invalid_header(header));
+    };
+    if name.trim().eq_ignore_ascii_case("Content-Length") {
+      content_length = Some(value.trim().parse::<usize>().map_err(|_| err_invalid_header(header))?);
+    }
+  }
+  let Some(content_length) = content_length else {
+    return Err(err_missing_content_length());
+  };
+  let mut content = vec![0; content_length];
+  reader.read_exact(&mut content).map_err(|reason| err_io(&reason.to_string()))?;
+  serde_json::from_slice(&content).map(Some).map_err(|reason| err_invalid_message(&reason.to_string()))
+}
+
+/// Writes a single message preceded by `Content-Length` header.
+pub fn write_message(writer: &mut impl Write, message: &Value) -> Result<()> {
+  let content = message.to_string();
+  write!(writer, "Content-Length: {}\r\n\r\n{}", content.len(), content).map_err(|reason| err_io(&reason.to_string()))?;
+  writer.flush().map_err(|reason| err_io(&reason.to_string()))
+}
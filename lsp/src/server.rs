@@ -0,0 +1,265 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Language server handling requests and notifications sent by the client.
+
+use crate::analysis::*;
+use crate::documents::*;
+use crate::protocol::*;
+use dmntk_common::Result;
+use dmntk_feel::context::FeelContext;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::io::{BufRead, Write};
+
+/// Language server state.
+#[derive(Default)]
+pub struct Server {
+  /// Documents opened by the client.
+  documents: BTreeMap<String, Document>,
+  /// Analyses of the opened documents.
+  analyses: BTreeMap<String, Analysis>,
+  /// Flag indicating if the client requested exit.
+  exited: bool,
+}
+
+impl Server {
+  /// Returns `true` when the client requested exit.
+  pub fn is_exited(&self) -> bool {
+    self.exited
+  }
+
+  /// Handles single message, returns responses and notifications to be sent to the client.
+  pub fn handle(&mut self, message: &Message) -> Vec<Value> {
+    let Some(method) = message.method.as_deref() else {
+      // responses sent by the client are ignored
+      return vec![];
+    };
+    match (method, &message.id) {
+      ("initialize", Some(id)) => vec![response(id, initialize_result())],
+      ("shutdown", Some(id)) => vec![response(id, Value::Null)],
+      ("exit", _) => {
+        self.exited = true;
+        vec![]
+      }
+      ("textDocument/didOpen", None) => match params::<DidOpenTextDocumentParams>(message) {
+        Some(params) => self.update(&params.text_document.uri, &params.text_document.text),
+        None => vec![],
+      },
+      ("textDocument/didChange", None) => match params::<DidChangeTextDocumentParams>(message) {
+        Some(params) => match params.content_changes.last() {
+          Some(change) => self.update(&params.text_document.uri, &change.text),
+          None => vec![],
+        },
+        None => vec![],
+      },
+      ("textDocument/didClose", None) => match params::<DidCloseTextDocumentParams>(message) {
+        Some(params) => self.close(&params.text_document.uri),
+        None => vec![],
+      },
+      ("textDocument/completion", Some(id)) => match params::<TextDocumentPositionParams>(message) {
+        Some(params) => vec![response(id, json!(self.completion(&params.text_document.uri, &params.position)))],
+        None => vec![error(id, INVALID_PARAMS, "invalid parameters")],
+      },
+      ("textDocument/hover", Some(id)) => match params::<TextDocumentPositionParams>(message) {
+        Some(params) => vec![response(id, json!(self.hover(&params.text_document.uri, &params.position)))],
+        None => vec![error(id, INVALID_PARAMS, "invalid parameters")],
+      },
+      ("textDocument/definition", Some(id)) => match params::<TextDocumentPositionParams>(message) {
+        Some(params) => vec![response(id, json!(self.definition(&params.text_document.uri, &params.position)))],
+        None => vec![error(id, INVALID_PARAMS, "invalid parameters")],
+      },
+      (_, Some(id)) => vec![error(id, METHOD_NOT_FOUND, &format!("unsupported method: {method}"))],
+      (_, None) => vec![],
+    }
+  }
+
+  /// Updates the content of the document and publishes diagnostics for the document
+  /// and for all opened expression documents using it as the context.
+  fn update(&mut self, uri: &str, text: &str) -> Vec<Value> {
+    let document = Document::new(uri, text);
+    let kind = document.kind;
+    self.documents.insert(uri.to_string(), document);
+    let mut notifications = vec![self.refresh(uri)];
+    if kind == DocumentKind::Context {
+      let dependents = self
+        .documents
+        .keys()
+        .filter(|other| context_uri(other).as_deref() == Some(uri))
+        .cloned()
+        .collect::<Vec<String>>();
+      for dependent in dependents {
+        notifications.push(self.refresh(&dependent));
+      }
+    }
+    notifications
+  }
+
+  /// Analyses opened document and prepares diagnostics notification.
+  fn refresh(&mut self, uri: &str) -> Value {
+    let Some(document) = self.documents.get(uri) else {
+      return diagnostics_notification(uri, &[]);
+    };
+    let context = match document.kind {
+      DocumentKind::Expression => self.outer(uri).map(|(_, analysis)| analysis.context).unwrap_or_default(),
+      DocumentKind::Context => FeelContext::default(),
+    };
+    let mut analysis = analyze(document, &context);
+    if analysis.node.is_none() && document.kind == DocumentKind::Context {
+      // keep the entries of the last valid context while the document is being edited
+      if let Some(previous) = self.analyses.get(uri) {
+        analysis.context = previous.context.clone();
+      }
+    }
+    let notification = diagnostics_notification(uri, &analysis.diagnostics);
+    self.analyses.insert(uri.to_string(), analysis);
+    notification
+  }
+
+  /// Closes the document and clears its diagnostics.
+  fn close(&mut self, uri: &str) -> Vec<Value> {
+    self.documents.remove(uri);
+    self.analyses.remove(uri);
+    vec![diagnostics_notification(uri, &[])]
+  }
+
+  /// Returns the context document accompanying the expression document with specified URI,
+  /// the context document is taken from opened documents or loaded from file system.
+  fn outer(&self, uri: &str) -> Option<(Document, Analysis)> {
+    let context_uri = context_uri(uri)?;
+    if let (Some(document), Some(analysis)) = (self.documents.get(&context_uri), self.analyses.get(&context_uri)) {
+      return Some((document.clone(), analysis.clone()));
+    }
+    let document = load_document(&context_uri)?;
+    let analysis = analyze(&document, &FeelContext::default());
+    Some((document, analysis))
+  }
+
+  /// Returns completion proposals for specified position in the document.
+  fn completion(&self, uri: &str, position: &Position) -> Vec<CompletionItem> {
+    let Some(document) = self.documents.get(uri) else {
+      return vec![];
+    };
+    self
+      .analyses
+      .get(uri)
+      .map(|analysis| completion(document, analysis, document.offset_at(position)))
+      .unwrap_or_default()
+  }
+
+  /// Returns hover information for specified position in the document.
+  fn hover(&self, uri: &str, position: &Position) -> Option<Hover> {
+    let document = self.documents.get(uri)?;
+    hover(document, self.analyses.get(uri)?, document.offset_at(position))
+  }
+
+  /// Returns the location of the context entry referenced at specified position in the document.
+  fn definition(&self, uri: &str, position: &Position) -> Option<Location> {
+    let document = self.documents.get(uri)?;
+    let analysis = self.analyses.get(uri)?;
+    let path = find_path(&analysis.spans, document.offset_at(position))?;
+    let outer = match document.kind {
+      DocumentKind::Expression => self.outer(uri),
+      DocumentKind::Context => None,
+    };
+    let outer_root = outer.as_ref().and_then(|(_, analysis)| analysis.node.as_ref());
+    match definition(analysis.node.as_ref()?, &path, outer_root)? {
+      Definition::Local(key_path) => Some(Location {
+        uri: uri.to_string(),
+        range: document.range(analysis.spans.get(&key_path)?),
+      }),
+      Definition::Outer(key_path) => {
+        let (outer_document, outer_analysis) = outer?;
+        Some(Location {
+          uri: outer_document.uri.clone(),
+          range: outer_document.range(outer_analysis.spans.get(&key_path)?),
+        })
+      }
+    }
+  }
+}
+
+/// Runs the language server, reading messages from `reader` and writing messages to `writer`
+/// until the client requests exit or closes the input.
+pub fn run(mut reader: impl BufRead, mut writer: impl Write) -> Result<()> {
+  let mut server = Server::default();
+  while let Some(message) = read_message(&mut reader)? {
+    for outgoing in server.handle(&message) {
+      write_message(&mut writer, &outgoing)?;
+    }
+    if server.is_exited() {
+      break;
+    }
+  }
+  Ok(())
+}
+
+/// Returns capabilities of the server.
+fn initialize_result() -> Value {
+  json!({
+    "capabilities": {
+      "textDocumentSync": 1,
+      "completionProvider": {},
+      "hoverProvider": true,
+      "definitionProvider": true
+    },
+    "serverInfo": {
+      "name": "dmntk-lsp",
+      "version": env!("CARGO_PKG_VERSION")
+    }
+  })
+}
+
+/// Deserializes parameters of the message.
+fn params<T: DeserializeOwned>(message: &Message) -> Option<T> {
+  serde_json::from_value(message.params.clone()).ok()
+}
+
+/// Creates a successful response.
+fn response(id: &Value, result: Value) -> Value {
+  json!({ "jsonrpc": "2.0", "id": id, "result": result })
+}
+
+/// Creates an error response.
+fn error(id: &Value, code: i64, message: &str) -> Value {
+  json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+}
+
+/// Creates a notification publishing diagnostics for the document.
+fn diagnostics_notification(uri: &str, diagnostics: &[Diagnostic]) -> Value {
+  json!({
+    "jsonrpc": "2.0",
+    "method": "textDocument/publishDiagnostics",
+    "params": { "uri": uri, "diagnostics": diagnostics }
+  })
+}
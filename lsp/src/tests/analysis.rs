@@ -0,0 +1,178 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::analysis::*;
+use crate::documents::Document;
+use crate::protocol::*;
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::values::Value;
+use dmntk_feel::{value_number, FeelScope, Name};
+
+fn context() -> FeelContext {
+  dmntk_feel_evaluator::evaluate_context(&FeelScope::default(), r#"{Monthly Salary: 10000, Person: {Age: 42, Name: "John"}}"#).unwrap()
+}
+
+fn range(start: (u32, u32), end: (u32, u32)) -> Range {
+  Range {
+    start: Position {
+      line: start.0,
+      character: start.1,
+    },
+    end: Position { line: end.0, character: end.1 },
+  }
+}
+
+#[test]
+fn test_syntax_error_diagnostic() {
+  let document = Document::new("file:///e.feel", "Monthly Salary *\n  (12 + ");
+  let analysis = analyze(&document, &context());
+  assert!(analysis.node.is_none());
+  assert_eq!(1, analysis.diagnostics.len());
+  assert_eq!(SEVERITY_ERROR, analysis.diagnostics[0].severity);
+  assert_eq!("unexpected end of input", analysis.diagnostics[0].message);
+  assert_eq!(range((1, 8), (1, 8)), analysis.diagnostics[0].range);
+}
+
+#[test]
+fn test_type_error_diagnostic() {
+  let document = Document::new("file:///e.feel", r#"Monthly Salary + Person.Name"#);
+  let analysis = analyze(&document, &context());
+  assert_eq!(1, analysis.diagnostics.len());
+  assert_eq!(SEVERITY_WARNING, analysis.diagnostics[0].severity);
+  assert_eq!("operator '+' is not defined for number and string", analysis.diagnostics[0].message);
+  assert_eq!(range((0, 0), (0, 28)), analysis.diagnostics[0].range);
+}
+
+#[test]
+fn test_context_document() {
+  let document = Document::new("file:///e.ctx", "{a: 1, b: a + 1}");
+  let analysis = analyze(&document, &FeelContext::default());
+  assert!(analysis.diagnostics.is_empty());
+  assert_eq!(Some(&value_number!(2)), analysis.context.get_entry(&Name::from("b")));
+}
+
+#[test]
+fn test_completion() {
+  let document = Document::new("file:///e.feel", "Monthly Salary");
+  let items = completion(&document, &analyze(&document, &context()), 0);
+  assert_eq!("Monthly Salary", items[0].label);
+  assert_eq!(COMPLETION_KIND_VARIABLE, items[0].kind);
+  assert_eq!(Some("number".to_string()), items[0].detail);
+  assert_eq!("Person", items[1].label);
+  assert!(items.iter().any(|item| item.label == "substring before" && item.kind == COMPLETION_KIND_FUNCTION));
+  #[cfg(feature = "calendar")]
+  assert!(items.iter().any(|item| item.label == "dmntk.is holiday" && item.kind == COMPLETION_KIND_FUNCTION));
+}
+
+#[test]
+fn test_completion_prefix() {
+  let document = Document::new("file:///e.feel", "Monthly Salary * per");
+  let analysis = analyze(&document, &context());
+  let labels = completion(&document, &analysis, 20).into_iter().map(|item| item.label).collect::<Vec<String>>();
+  assert_eq!("Person", labels[0]);
+  assert!(labels.iter().all(|label| label.to_lowercase().starts_with("per")));
+  assert!(!labels.iter().any(|label| label == "Monthly Salary"));
+}
+
+#[test]
+fn test_completion_context_entries() {
+  let document = Document::new("file:///e.feel", "Monthly Salary + Person.");
+  let analysis = analyze(&document, &context());
+  let items = completion(&document, &analysis, 24);
+  assert_eq!(vec!["Age", "Name"], items.iter().map(|item| item.label.as_str()).collect::<Vec<&str>>());
+  assert_eq!(Some("number".to_string()), items[0].detail);
+  let items = completion(&document, &analysis, 7);
+  assert_eq!("Monthly Salary", items[0].label);
+  let document = Document::new("file:///e.feel", "Person.N");
+  let items = completion(&document, &analyze(&document, &context()), 8);
+  assert_eq!(vec!["Name"], items.iter().map(|item| item.label.as_str()).collect::<Vec<&str>>());
+}
+
+#[test]
+fn test_hover_name() {
+  let document = Document::new("file:///e.feel", "Person.Age * 2");
+  let analysis = analyze(&document, &context());
+  let hover = hover(&document, &analysis, 8).unwrap();
+  assert_eq!("`number`\n\n```feel\n42\n```", hover.contents.value);
+  assert_eq!(range((0, 7), (0, 10)), hover.range);
+  let hover = hover_at(&document, &analysis, 2);
+  assert_eq!("`context<Age: number, Name: string>`\n\n```feel\n{Age: 42, Name: \"John\"}\n```", hover);
+}
+
+#[test]
+fn test_hover_expression() {
+  let document = Document::new("file:///e.feel", "Monthly Salary * 12");
+  let analysis = analyze(&document, &context());
+  assert_eq!("`number`\n\n```feel\n120000\n```", hover_at(&document, &analysis, 15));
+  let document = Document::new("file:///e.feel", "function(x) x + 1");
+  let analysis = analyze(&document, &context());
+  assert_eq!("`Any`", hover_at(&document, &analysis, 14));
+}
+
+#[test]
+fn test_hover_context_entry_key() {
+  let document = Document::new("file:///e.ctx", "{a: {b: 2}}");
+  let analysis = analyze(&document, &FeelContext::default());
+  assert_eq!("`number`\n\n```feel\n2\n```", hover_at(&document, &analysis, 5));
+}
+
+#[test]
+fn test_definition_local() {
+  let document = Document::new("file:///e.ctx", "{a: {b: 2}, c: a.b, d: [c]}");
+  let analysis = analyze(&document, &FeelContext::default());
+  let root = analysis.node.as_ref().unwrap();
+  let path = find_path(&analysis.spans, 17).unwrap();
+  assert_eq!(Some(Definition::Local(vec![0, 1, 0, 0])), definition(root, &path, None));
+  let path = find_path(&analysis.spans, 15).unwrap();
+  assert_eq!(Some(Definition::Local(vec![0, 0])), definition(root, &path, None));
+  let path = find_path(&analysis.spans, 24).unwrap();
+  assert_eq!(Some(Definition::Local(vec![1, 0])), definition(root, &path, None));
+  let path = find_path(&analysis.spans, 3).unwrap();
+  assert_eq!(None, definition(root, &path, None));
+}
+
+#[test]
+fn test_definition_outer() {
+  let context_document = Document::new("file:///e.ctx", r#"{Monthly Salary: 10000, Person: {Age: 42, Name: "John"}}"#);
+  let context_analysis = analyze(&context_document, &FeelContext::default());
+  let document = Document::new("file:///e.feel", "{x: Person.Age}.x");
+  let analysis = analyze(&document, &context_analysis.context);
+  let path = find_path(&analysis.spans, 12).unwrap();
+  let outer = context_analysis.node.as_ref();
+  assert_eq!(Some(Definition::Outer(vec![1, 1, 0, 0])), definition(analysis.node.as_ref().unwrap(), &path, outer));
+  let path = find_path(&analysis.spans, 16).unwrap();
+  assert_eq!(None, definition(analysis.node.as_ref().unwrap(), &path, outer));
+}
+
+fn hover_at(document: &Document, analysis: &Analysis, offset: usize) -> String {
+  hover(document, analysis, offset).unwrap().contents.value
+}
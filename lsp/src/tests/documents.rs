@@ -0,0 +1,67 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::documents::*;
+use crate::protocol::Position;
+
+#[test]
+fn test_document_kind() {
+  assert_eq!(DocumentKind::Context, Document::new("file:///a/e1.ctx", "{}").kind);
+  assert_eq!(DocumentKind::Expression, Document::new("file:///a/e1.feel", "1").kind);
+}
+
+#[test]
+fn test_context_uri() {
+  assert_eq!(Some("file:///a/e1.ctx".to_string()), context_uri("file:///a/e1.feel"));
+  assert_eq!(None, context_uri("file:///a/e1.ctx"));
+}
+
+#[test]
+fn test_positions() {
+  let document = Document::new("file:///a.feel", "a +\n\"😀\" + b");
+  let position = Position { line: 1, character: 5 };
+  assert_eq!(8, document.offset_at(&position));
+  assert_eq!(position, document.position_at(8));
+  assert_eq!(Position { line: 1, character: 0 }, document.position_at(4));
+  assert_eq!(11, document.offset_at(&Position { line: 5, character: 0 }));
+  assert_eq!(3, document.offset_at(&Position { line: 0, character: 10 }));
+}
+
+#[test]
+fn test_word_before() {
+  let document = Document::new("file:///a.feel", "Monthly Salary * Person.Na");
+  assert_eq!("Person.Na", document.word_before(26));
+  assert_eq!("Pers", document.word_before(21));
+  assert_eq!("", document.word_before(17));
+  assert_eq!("Mon", document.word_before(3));
+  assert_eq!("Person.Na", document.word_before(100));
+}
@@ -0,0 +1,130 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::protocol::{read_message, write_message, Message};
+use crate::{run, Server};
+use serde_json::{json, Value};
+use std::io::{BufReader, Cursor};
+
+fn message(value: Value) -> Message {
+  serde_json::from_value(value).unwrap()
+}
+
+fn open(uri: &str, text: &str) -> Message {
+  message(json!({"jsonrpc": "2.0", "method": "textDocument/didOpen", "params": {"textDocument": {"uri": uri, "languageId": "feel", "version": 1, "text": text}}}))
+}
+
+fn request(id: u64, method: &str, uri: &str, line: u32, character: u32) -> Message {
+  message(json!({"jsonrpc": "2.0", "id": id, "method": method, "params": {"textDocument": {"uri": uri}, "position": {"line": line, "character": character}}}))
+}
+
+#[test]
+fn test_initialize() {
+  let mut server = Server::default();
+  let responses = server.handle(&message(json!({"jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {}})));
+  assert_eq!(1, responses[0]["id"]);
+  assert_eq!(true, responses[0]["result"]["capabilities"]["hoverProvider"]);
+  assert_eq!("dmntk-lsp", responses[0]["result"]["serverInfo"]["name"]);
+}
+
+#[test]
+fn test_diagnostics_follow_context_changes() {
+  let mut server = Server::default();
+  let notifications = server.handle(&open("file:///w/e1.feel", "Total * 2"));
+  assert_eq!("textDocument/publishDiagnostics", notifications[0]["method"]);
+  assert_eq!("unknown name 'Total*2'", notifications[0]["params"]["diagnostics"][0]["message"]);
+  let notifications = server.handle(&open("file:///w/e1.ctx", "{Total: 10}"));
+  assert_eq!(2, notifications.len());
+  assert_eq!("file:///w/e1.ctx", notifications[0]["params"]["uri"]);
+  assert_eq!("file:///w/e1.feel", notifications[1]["params"]["uri"]);
+  assert_eq!(json!([]), notifications[1]["params"]["diagnostics"]);
+  let notifications = server.handle(&message(
+    json!({"jsonrpc": "2.0", "method": "textDocument/didChange", "params": {"textDocument": {"uri": "file:///w/e1.ctx", "version": 2}, "contentChanges": [{"text": "{Total: 10"}]}}),
+  ));
+  assert_eq!(2, notifications.len());
+  assert_eq!("unexpected end of input, expected ',', '}'", notifications[0]["params"]["diagnostics"][0]["message"]);
+}
+
+#[test]
+fn test_hover_and_definition() {
+  let mut server = Server::default();
+  server.handle(&open("file:///w/e1.ctx", "{\n  Total: 10\n}"));
+  server.handle(&open("file:///w/e1.feel", "Total * 2"));
+  let responses = server.handle(&request(2, "textDocument/hover", "file:///w/e1.feel", 0, 2));
+  assert_eq!("`number`\n\n```feel\n10\n```", responses[0]["result"]["contents"]["value"]);
+  let responses = server.handle(&request(3, "textDocument/definition", "file:///w/e1.feel", 0, 5));
+  assert_eq!(
+    json!({"uri": "file:///w/e1.ctx", "range": {"start": {"line": 1, "character": 2}, "end": {"line": 1, "character": 7}}}),
+    responses[0]["result"]
+  );
+  let responses = server.handle(&request(4, "textDocument/definition", "file:///w/e1.feel", 0, 8));
+  assert_eq!(Value::Null, responses[0]["result"]);
+  let responses = server.handle(&request(5, "textDocument/completion", "file:///w/e1.feel", 0, 0));
+  assert_eq!("Total", responses[0]["result"][0]["label"]);
+  let responses = server.handle(&request(6, "textDocument/completion", "file:///w/e1.feel", 0, 9));
+  assert!(responses[0]["result"].as_array().unwrap().is_empty());
+}
+
+#[test]
+fn test_unsupported_method() {
+  let mut server = Server::default();
+  let responses = server.handle(&request(7, "textDocument/formatting", "file:///w/e1.feel", 0, 0));
+  assert_eq!(-32601, responses[0]["error"]["code"]);
+  assert!(server
+    .handle(&message(json!({"jsonrpc": "2.0", "method": "$/cancelRequest", "params": {"id": 1}})))
+    .is_empty());
+}
+
+#[test]
+fn test_run() {
+  let mut input = vec![];
+  write_message(&mut input, &json!({"jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {}})).unwrap();
+  write_message(&mut input, &json!({"jsonrpc": "2.0", "id": 2, "method": "shutdown"})).unwrap();
+  write_message(&mut input, &json!({"jsonrpc": "2.0", "method": "exit"})).unwrap();
+  write_message(&mut input, &json!({"jsonrpc": "2.0", "id": 3, "method": "initialize", "params": {}})).unwrap();
+  let mut output = vec![];
+  run(Cursor::new(input), &mut output).unwrap();
+  let mut reader = BufReader::new(Cursor::new(output));
+  let first = read_message(&mut reader).unwrap().unwrap();
+  assert_eq!(Some(json!(1)), first.id);
+  let second = read_message(&mut reader).unwrap().unwrap();
+  assert_eq!(Some(json!(2)), second.id);
+  assert!(read_message(&mut reader).unwrap().is_none());
+}
+
+#[test]
+fn test_invalid_header() {
+  let mut reader = BufReader::new(Cursor::new(b"Content-Type\r\n\r\n{}".to_vec()));
+  assert_eq!("<LspError> invalid message header: Content-Type", read_message(&mut reader).err().unwrap().to_string());
+  let mut reader = BufReader::new(Cursor::new(b"\r\n{}".to_vec()));
+  assert_eq!("<LspError> missing Content-Length header", read_message(&mut reader).err().unwrap().to_string());
+}
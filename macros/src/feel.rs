@@ -0,0 +1,194 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! # Derive macros for converting Rust types from and into `FEEL` values
+//!
+//! Structs with named fields are converted into `FEEL` contexts,
+//! every field becomes a context entry. Enums with unit variants only
+//! are converted into `FEEL` strings holding the name of the variant.
+//! Enums with data-carrying variants, tuple structs and unions are rejected.
+//!
+//! The name of the context entry (or the string representing the enum variant)
+//! may be changed using `#[feel(name = "...")]` attribute, e.g. to use `FEEL`
+//! names containing spaces.
+//!
+//! Every type parameter of a generic type is required to implement
+//! the derived trait, like in derive macros from the standard library.
+
+use proc_macro2::TokenStream;
+use syn::spanned::Spanned;
+use syn::{parse_quote, Attribute, Data, DeriveInput, Fields, Generics, LitStr};
+
+/// Returns the `FEEL` name defined in `#[feel(name = "...")]` attribute, if present.
+fn feel_name(attributes: &[Attribute]) -> syn::Result<Option<String>> {
+  let mut name = None;
+  for attribute in attributes.iter().filter(|attribute| attribute.path().is_ident("feel")) {
+    attribute.parse_nested_meta(|meta| {
+      if meta.path.is_ident("name") {
+        let value: LitStr = meta.value()?.parse()?;
+        name = Some(value.value());
+        Ok(())
+      } else {
+        Err(meta.error("unsupported feel attribute, expected `name`"))
+      }
+    })?;
+  }
+  Ok(name)
+}
+
+/// Returns generics of the input type with specified trait bound added to every type parameter.
+fn bounded_generics(input: &DeriveInput, bound: TokenStream) -> Generics {
+  let mut generics = input.generics.clone();
+  let type_params = generics.type_params().map(|type_param| type_param.ident.clone()).collect::<Vec<_>>();
+  let where_clause = generics.make_where_clause();
+  for type_param in type_params {
+    where_clause.predicates.push(parse_quote! { #type_param: #bound });
+  }
+  generics
+}
+
+/// Rejects unions, only structs and enums are converted from and into `FEEL` values.
+fn reject_union(input: &DeriveInput) -> syn::Result<()> {
+  if let Data::Union(data) = &input.data {
+    return Err(syn::Error::new(data.union_token.span(), "unions are not supported"));
+  }
+  Ok(())
+}
+
+/// Collects identifiers and `FEEL` names of struct fields.
+fn struct_fields(input: &DeriveInput) -> syn::Result<Vec<(syn::Ident, String)>> {
+  let Data::Struct(data) = &input.data else {
+    return Err(syn::Error::new(input.span(), "expected struct"));
+  };
+  let Fields::Named(fields) = &data.fields else {
+    return Err(syn::Error::new(data.fields.span(), "only structs with named fields are supported"));
+  };
+  let mut result = vec![];
+  for field in &fields.named {
+    let ident = field.ident.clone().unwrap(); // unwrap is ok, fields are named
+    let name = feel_name(&field.attrs)?.unwrap_or_else(|| ident.to_string());
+    result.push((ident, name));
+  }
+  Ok(result)
+}
+
+/// Collects identifiers and `FEEL` names of enum variants.
+fn enum_variants(input: &DeriveInput) -> syn::Result<Vec<(syn::Ident, String)>> {
+  let Data::Enum(data) = &input.data else {
+    return Err(syn::Error::new(input.span(), "expected enum"));
+  };
+  let mut result = vec![];
+  for variant in &data.variants {
+    if !matches!(variant.fields, Fields::Unit) {
+      return Err(syn::Error::new(
+        variant.fields.span(),
+        format!("variant '{}' carries data, only unit variants are supported", variant.ident),
+      ));
+    }
+    let name = feel_name(&variant.attrs)?.unwrap_or_else(|| variant.ident.to_string());
+    result.push((variant.ident.clone(), name));
+  }
+  Ok(result)
+}
+
+/// Generates the implementation of `IntoFeel` trait.
+pub fn into_feel(input: &DeriveInput) -> syn::Result<TokenStream> {
+  reject_union(input)?;
+  let ident = &input.ident;
+  let generics = bounded_generics(input, quote! { ::dmntk_feel::IntoFeel });
+  let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+  let body = if let Data::Enum(_) = &input.data {
+    let arms = enum_variants(input)?.into_iter().map(|(variant, name)| {
+      quote! { #ident::#variant => ::dmntk_feel::values::Value::String(#name.to_string()), }
+    });
+    quote! {
+      match self {
+        #(#arms)*
+      }
+    }
+  } else {
+    let entries = struct_fields(input)?.into_iter().map(|(field, name)| {
+      quote! { context.set_entry(&::dmntk_feel::Name::from(#name), ::dmntk_feel::IntoFeel::into_feel(self.#field)); }
+    });
+    quote! {
+      let mut context = ::dmntk_feel::context::FeelContext::default();
+      #(#entries)*
+      ::dmntk_feel::values::Value::Context(context)
+    }
+  };
+  Ok(quote! {
+    impl #impl_generics ::dmntk_feel::IntoFeel for #ident #type_generics #where_clause {
+      fn into_feel(self) -> ::dmntk_feel::values::Value {
+        #body
+      }
+    }
+  })
+}
+
+/// Generates the implementation of `FromFeel` trait.
+pub fn from_feel(input: &DeriveInput) -> syn::Result<TokenStream> {
+  reject_union(input)?;
+  let ident = &input.ident;
+  let type_name = ident.to_string();
+  let generics = bounded_generics(input, quote! { ::dmntk_feel::FromFeel });
+  let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+  let body = if let Data::Enum(_) = &input.data {
+    let variants = enum_variants(input)?;
+    let names = variants.iter().map(|(_, name)| name);
+    let arms = variants.iter().enumerate().map(|(index, (variant, _))| {
+      quote! { #index => ::core::result::Result::Ok(#ident::#variant), }
+    });
+    quote! {
+      match ::dmntk_feel::convert::variant_index(value, #type_name, &[#(#names),*])? {
+        #(#arms)*
+        _ => unreachable!(),
+      }
+    }
+  } else {
+    let entries = struct_fields(input)?.into_iter().map(|(field, name)| {
+      quote! { #field: ::dmntk_feel::convert::from_feel_entry(context, #name)?, }
+    });
+    quote! {
+      let context = ::dmntk_feel::convert::as_context(value, #type_name)?;
+      ::core::result::Result::Ok(Self {
+        #(#entries)*
+      })
+    }
+  };
+  Ok(quote! {
+    impl #impl_generics ::dmntk_feel::FromFeel for #ident #type_generics #where_clause {
+      fn from_feel(value: &::dmntk_feel::values::Value) -> ::dmntk_feel::convert::Result<Self> {
+        #body
+      }
+    }
+  })
+}
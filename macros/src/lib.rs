@@ -36,6 +36,11 @@ extern crate proc_macro;
 #[macro_use]
 extern crate quote;
 
+mod feel;
+
+#[cfg(test)]
+mod tests;
+
 use proc_macro::TokenStream;
 
 #[proc_macro_derive(ToErrorMessage)]
@@ -120,3 +125,15 @@ pub fn business_context_element(input: TokenStream) -> TokenStream {
   };
   TokenStream::from(expanded)
 }
+
+#[proc_macro_derive(IntoFeel, attributes(feel))]
+pub fn into_feel(input: TokenStream) -> TokenStream {
+  let input = syn::parse_macro_input!(input as syn::DeriveInput);
+  feel::into_feel(&input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+#[proc_macro_derive(FromFeel, attributes(feel))]
+pub fn from_feel(input: TokenStream) -> TokenStream {
+  let input = syn::parse_macro_input!(input as syn::DeriveInput);
+  feel::from_feel(&input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
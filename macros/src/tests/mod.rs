@@ -0,0 +1,93 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! # Tests for derive macros converting Rust types from and into `FEEL` values
+
+use crate::feel::{from_feel, into_feel};
+use syn::parse_quote;
+
+#[test]
+fn _0001() {
+  let input = parse_quote! {
+    enum Shape {
+      Point,
+      Circle(f64),
+    }
+  };
+  assert_eq!(
+    "variant 'Circle' carries data, only unit variants are supported",
+    into_feel(&input).unwrap_err().to_string()
+  );
+  assert_eq!(
+    "variant 'Circle' carries data, only unit variants are supported",
+    from_feel(&input).unwrap_err().to_string()
+  );
+}
+
+#[test]
+fn _0002() {
+  let input = parse_quote! {
+    enum Shape {
+      Rectangle { width: f64, height: f64 },
+    }
+  };
+  assert_eq!(
+    "variant 'Rectangle' carries data, only unit variants are supported",
+    into_feel(&input).unwrap_err().to_string()
+  );
+}
+
+#[test]
+fn _0003() {
+  let input = parse_quote! {
+    union Number {
+      integer: u64,
+      float: f64,
+    }
+  };
+  assert_eq!("unions are not supported", into_feel(&input).unwrap_err().to_string());
+  assert_eq!("unions are not supported", from_feel(&input).unwrap_err().to_string());
+}
+
+#[test]
+fn _0004() {
+  let input = parse_quote! {
+    struct Pair<L, R: Clone> {
+      left: L,
+      right: R,
+    }
+  };
+  let output = into_feel(&input).unwrap().to_string();
+  assert!(output.contains("where L : :: dmntk_feel :: IntoFeel , R : :: dmntk_feel :: IntoFeel"));
+  let output = from_feel(&input).unwrap().to_string();
+  assert!(output.contains("where L : :: dmntk_feel :: FromFeel , R : :: dmntk_feel :: FromFeel"));
+}
@@ -40,7 +40,7 @@ use dmntk_common::Result;
 use dmntk_feel::closure::Closure;
 use dmntk_feel::context::FeelContext;
 use dmntk_feel::values::Value;
-use dmntk_feel::{value_null, Evaluator, FeelScope, FeelType, FunctionBody};
+use dmntk_feel::{value_null, Evaluator, FeelScope, FeelType, FunctionBody, Name};
 use dmntk_feel_parser::ClosureBuilder;
 use dmntk_model::*;
 use std::sync::Arc;
@@ -74,12 +74,18 @@ pub fn bring_knowledge_requirements_into_context(def_definitions: &DefDefinition
 ///
 pub fn build_expression_instance_evaluator(scope: &FeelScope, expression_instance: &ExpressionInstance, model_evaluator: &ModelBuilder) -> Result<(Evaluator, Closure)> {
   match expression_instance {
+    ExpressionInstance::Conditional(conditional) => build_conditional_evaluator(scope, conditional, model_evaluator),
     ExpressionInstance::Context(context) => build_context_evaluator(scope, context, model_evaluator),
     ExpressionInstance::DecisionTable(decision_table) => build_decision_table_evaluator(scope, decision_table),
+    ExpressionInstance::Every(quantified) => build_every_evaluator(scope, quantified, model_evaluator),
+    ExpressionInstance::Filter(filter) => build_filter_evaluator(scope, filter, model_evaluator),
+    ExpressionInstance::For(for_expression) => build_for_evaluator(scope, for_expression, model_evaluator),
     ExpressionInstance::FunctionDefinition(function_definition) => build_function_definition_evaluator(scope, function_definition, model_evaluator),
     ExpressionInstance::Invocation(invocation) => build_invocation_evaluator(scope, invocation, model_evaluator),
+    ExpressionInstance::List(list) => build_list_evaluator(scope, list, model_evaluator),
     ExpressionInstance::LiteralExpression(literal_expression) => build_literal_expression_evaluator(scope, literal_expression),
     ExpressionInstance::Relation(relation) => build_relation_evaluator(scope, relation, model_evaluator),
+    ExpressionInstance::Some(quantified) => build_some_evaluator(scope, quantified, model_evaluator),
   }
 }
 
@@ -271,9 +277,9 @@ pub fn build_invocation_evaluator(scope: &FeelScope, invocation: &Invocation, mo
 ///
 pub fn build_literal_expression_evaluator(scope: &FeelScope, literal_expression: &LiteralExpression) -> Result<(Evaluator, Closure)> {
   let text = literal_expression.text().as_ref().ok_or_else(err_empty_literal_expression)?;
-  let node = dmntk_feel_parser::parse_expression(scope, text, false)?;
+  let (node, spans) = dmntk_feel_parser::parse_expression_with_spans(scope, text, false)?;
   let closure = ClosureBuilder::from_node(&node);
-  let literal_expression_evaluator = dmntk_feel_evaluator::prepare(&node)?;
+  let literal_expression_evaluator = dmntk_feel_evaluator::prepare_with_spans(&node, &spans)?;
   Ok((literal_expression_evaluator, closure))
 }
 
@@ -304,3 +310,168 @@ pub fn build_relation_evaluator(scope: &FeelScope, relation: &Relation, model_ev
   });
   Ok((relation_evaluator, Closure::default()))
 }
+
+///
+pub fn build_list_evaluator(scope: &FeelScope, list: &List, model_evaluator: &ModelBuilder) -> Result<(Evaluator, Closure)> {
+  let mut evaluators = vec![];
+  for element in list.elements() {
+    let (evaluator, _) = build_expression_instance_evaluator(scope, element, model_evaluator)?;
+    evaluators.push(evaluator);
+  }
+  let list_evaluator = Box::new(move |scope: &FeelScope| Value::List(evaluators.iter().map(|evaluator| evaluator(scope)).collect()));
+  Ok((list_evaluator, Closure::default()))
+}
+
+///
+pub fn build_conditional_evaluator(scope: &FeelScope, conditional: &Conditional, model_evaluator: &ModelBuilder) -> Result<(Evaluator, Closure)> {
+  let (if_evaluator, _) = build_expression_instance_evaluator(scope, conditional.if_expression().value(), model_evaluator)?;
+  let (then_evaluator, _) = build_expression_instance_evaluator(scope, conditional.then_expression().value(), model_evaluator)?;
+  let (else_evaluator, _) = build_expression_instance_evaluator(scope, conditional.else_expression().value(), model_evaluator)?;
+  let conditional_evaluator = Box::new(move |scope: &FeelScope| {
+    // only the condition evaluated to `true` selects the `then` branch,
+    // `false`, `null` and values of any other type select the `else` branch
+    if let Value::Boolean(true) = if_evaluator(scope) {
+      then_evaluator(scope)
+    } else {
+      else_evaluator(scope)
+    }
+  });
+  Ok((conditional_evaluator, Closure::default()))
+}
+
+///
+pub fn build_filter_evaluator(scope: &FeelScope, filter: &Filter, model_evaluator: &ModelBuilder) -> Result<(Evaluator, Closure)> {
+  let name_item: Name = "item".into();
+  let (in_evaluator, _) = build_expression_instance_evaluator(scope, filter.in_expression().value(), model_evaluator)?;
+  scope.push(FeelContext::default());
+  scope.set_name(name_item.clone());
+  let match_result = build_expression_instance_evaluator(scope, filter.match_expression().value(), model_evaluator);
+  scope.pop();
+  let (match_evaluator, _) = match_result?;
+  let filter_evaluator = Box::new(move |scope: &FeelScope| {
+    let values = match in_evaluator(scope) {
+      Value::List(values) => values,
+      null @ Value::Null(_) => return null,
+      other => vec![other],
+    };
+    let mut filtered_values = vec![];
+    for value in values {
+      // entries of the filtered context are directly visible in the `match` expression,
+      // the filtered element itself is always available under the name `item`
+      let mut item_context = FeelContext::default();
+      if let Value::Context(local_context) = &value {
+        item_context = local_context.clone();
+      }
+      if !item_context.contains_entry(&name_item) {
+        item_context.set_entry(&name_item, value.clone());
+      }
+      scope.push(item_context);
+      let matched = matches!(match_evaluator(scope), Value::Boolean(true));
+      scope.pop();
+      if matched {
+        filtered_values.push(value);
+      }
+    }
+    Value::List(filtered_values)
+  });
+  Ok((filter_evaluator, Closure::default()))
+}
+
+///
+pub fn build_for_evaluator(scope: &FeelScope, for_expression: &For, model_evaluator: &ModelBuilder) -> Result<(Evaluator, Closure)> {
+  let (variable_name, in_evaluator, return_evaluator) = build_iterator_evaluators(
+    scope,
+    for_expression.iterator_variable(),
+    for_expression.in_expression(),
+    for_expression.return_expression(),
+    model_evaluator,
+  )?;
+  let for_evaluator = Box::new(move |scope: &FeelScope| {
+    let values = iterated_values(in_evaluator(scope));
+    let mut results = vec![];
+    for value in values {
+      results.push(evaluate_iteration(scope, &variable_name, value, &return_evaluator));
+    }
+    Value::List(results)
+  });
+  Ok((for_evaluator, Closure::default()))
+}
+
+///
+pub fn build_every_evaluator(scope: &FeelScope, quantified: &Quantified, model_evaluator: &ModelBuilder) -> Result<(Evaluator, Closure)> {
+  let (variable_name, in_evaluator, satisfies_evaluator) = build_iterator_evaluators(
+    scope,
+    quantified.iterator_variable(),
+    quantified.in_expression(),
+    quantified.satisfies_expression(),
+    model_evaluator,
+  )?;
+  let every_evaluator = Box::new(move |scope: &FeelScope| {
+    let mut result = true;
+    for value in iterated_values(in_evaluator(scope)) {
+      if let Value::Boolean(satisfied) = evaluate_iteration(scope, &variable_name, value, &satisfies_evaluator) {
+        result = result && satisfied;
+      }
+    }
+    Value::Boolean(result)
+  });
+  Ok((every_evaluator, Closure::default()))
+}
+
+///
+pub fn build_some_evaluator(scope: &FeelScope, quantified: &Quantified, model_evaluator: &ModelBuilder) -> Result<(Evaluator, Closure)> {
+  let (variable_name, in_evaluator, satisfies_evaluator) = build_iterator_evaluators(
+    scope,
+    quantified.iterator_variable(),
+    quantified.in_expression(),
+    quantified.satisfies_expression(),
+    model_evaluator,
+  )?;
+  let some_evaluator = Box::new(move |scope: &FeelScope| {
+    let mut result = false;
+    for value in iterated_values(in_evaluator(scope)) {
+      if let Value::Boolean(satisfied) = evaluate_iteration(scope, &variable_name, value, &satisfies_evaluator) {
+        result = result || satisfied;
+      }
+    }
+    Value::Boolean(result)
+  });
+  Ok((some_evaluator, Closure::default()))
+}
+
+/// Builds evaluators of the iterated list and of the expression evaluated in each iteration.
+/// The iterator variable is visible only in the expression evaluated in each iteration.
+fn build_iterator_evaluators(
+  scope: &FeelScope,
+  iterator_variable: &str,
+  in_expression: &ChildExpression,
+  iteration_expression: &ChildExpression,
+  model_evaluator: &ModelBuilder,
+) -> Result<(Name, Evaluator, Evaluator)> {
+  let variable_name: Name = iterator_variable.into();
+  let (in_evaluator, _) = build_expression_instance_evaluator(scope, in_expression.value(), model_evaluator)?;
+  scope.push(FeelContext::default());
+  scope.set_name(variable_name.clone());
+  let iteration_result = build_expression_instance_evaluator(scope, iteration_expression.value(), model_evaluator);
+  scope.pop();
+  let (iteration_evaluator, _) = iteration_result?;
+  Ok((variable_name, in_evaluator, iteration_evaluator))
+}
+
+/// Returns the values to iterate over, a value that is not a list is treated like a single element list.
+fn iterated_values(value: Value) -> Vec<Value> {
+  match value {
+    Value::List(values) => values,
+    other => vec![other],
+  }
+}
+
+/// Evaluates a single iteration with the iterator variable set to specified value.
+fn evaluate_iteration(scope: &FeelScope, variable_name: &Name, value: Value, evaluator: &Evaluator) -> Value {
+  let mut iteration_context = FeelContext::default();
+  iteration_context.set_entry(variable_name, value);
+  scope.push(iteration_context);
+  let result = evaluator(scope);
+  scope.pop();
+  result
+}
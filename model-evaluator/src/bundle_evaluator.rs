@@ -31,13 +31,163 @@
  */
 
 //! # Model's bundle evaluator
+//!
+//! Bundle evaluator holds a separate [ModelEvaluator] for every deployable unit.
+//! Deployable unit is a model together with all models it imports (directly or indirectly).
+//! Units are built and replaced independently, so a model that can not be built
+//! does not affect the evaluation of other models in the bundle.
 
 use crate::ModelEvaluator;
-use std::collections::HashMap;
+use dmntk_common::{DmntkError, Result};
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::values::Value;
+use dmntk_feel::{value_null, QualifiedName};
+use dmntk_model::Definitions;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 
 /// Evaluator for a bundle of models.
-#[allow(dead_code)]
+#[derive(Default)]
 pub struct BundleEvaluator {
   /// Map of model evaluators indexed by model's namespace.
-  model_evaluators: HashMap<String, ModelEvaluator>,
+  model_evaluators: HashMap<String, Arc<ModelEvaluator>>,
+}
+
+impl BundleEvaluator {
+  /// Creates a [BundleEvaluator] from parsed [Definitions], see [BundleEvaluator::deploy] for details.
+  pub fn new(definitions: &[Definitions]) -> (Self, Vec<(String, DmntkError)>) {
+    let mut bundle_evaluator = Self::default();
+    let errors = bundle_evaluator.deploy(definitions);
+    (bundle_evaluator, errors)
+  }
+
+  /// Deploys every model from specified [Definitions] as a separate unit, built from the model
+  /// and all models it imports, that are also present in specified [Definitions].
+  /// Returns the namespaces of models that could not be deployed together with failure reasons.
+  pub fn deploy(&mut self, definitions: &[Definitions]) -> Vec<(String, DmntkError)> {
+    let mut errors = vec![];
+    for model in definitions {
+      let unit = deployable_unit(model, definitions);
+      if let Err(reason) = self.replace(model.namespace(), &unit) {
+        errors.push((model.namespace().to_string(), reason));
+      }
+    }
+    errors
+  }
+
+  /// Deploys the model with specified namespace as a unit built from specified [Definitions],
+  /// and redeploys units of all models importing this model, directly or indirectly.
+  /// Units that could not be built remain deployed in their previous versions.
+  /// Returns the namespaces of models that could not be deployed together with failure reasons.
+  pub fn redeploy(&mut self, namespace: &str, definitions: &[Definitions]) -> Vec<(String, DmntkError)> {
+    let mut errors = vec![];
+    for model in definitions {
+      let unit = deployable_unit(model, definitions);
+      if unit.iter().any(|unit_model| unit_model.namespace() == namespace) {
+        if let Err(reason) = self.replace(model.namespace(), &unit) {
+          errors.push((model.namespace().to_string(), reason));
+        }
+      }
+    }
+    errors
+  }
+
+  /// Builds a unit from specified [Definitions] and deploys it under specified namespace.
+  /// The previously deployed unit is replaced only when the new unit was successfully built.
+  pub fn replace(&mut self, namespace: &str, definitions: &[Definitions]) -> Result<()> {
+    let model_evaluator = ModelEvaluator::new(definitions)?;
+    self.model_evaluators.insert(namespace.to_string(), model_evaluator);
+    Ok(())
+  }
+
+  /// Removes the unit deployed under specified namespace, returns `true` when the unit was deployed.
+  pub fn remove(&mut self, namespace: &str) -> bool {
+    self.model_evaluators.remove(namespace).is_some()
+  }
+
+  /// Returns the model evaluator of the unit deployed under specified namespace.
+  pub fn model_evaluator(&self, namespace: &str) -> Option<&Arc<ModelEvaluator>> {
+    self.model_evaluators.get(namespace)
+  }
+
+  /// Returns sorted namespaces of all deployed units.
+  pub fn namespaces(&self) -> Vec<String> {
+    let mut namespaces = self.model_evaluators.keys().cloned().collect::<Vec<String>>();
+    namespaces.sort();
+    namespaces
+  }
+
+  /// Returns sorted pairs of namespace and name of invocables deployed in the bundle.
+  /// Only invocables defined in the model the unit was deployed for are returned.
+  pub fn namespace_name(&self) -> Vec<(String, String)> {
+    self.collect_invocables(|model_evaluator| model_evaluator.invocables().namespace_name())
+  }
+
+  /// Returns sorted pairs of namespace and identifier of invocables deployed in the bundle.
+  /// Only invocables defined in the model the unit was deployed for are returned.
+  pub fn namespace_id(&self) -> Vec<(String, String)> {
+    self.collect_invocables(|model_evaluator| model_evaluator.invocables().namespace_id())
+  }
+
+  /// Evaluates an invocable identified by specified _invocable_name_.
+  pub fn evaluate_invocable_by_name(&self, namespace: &str, invocable_name: &str, input_data: &FeelContext) -> Value {
+    if let Some(model_evaluator) = self.model_evaluators.get(namespace) {
+      model_evaluator.evaluate_invocable_by_name(namespace, invocable_name, input_data)
+    } else {
+      value_null!("invocable '{}' not found in namespace '{}'", invocable_name, namespace)
+    }
+  }
+
+  /// Evaluates an invocable identified by specified _invocable_id_.
+  pub fn evaluate_invocable_by_id(&self, namespace: &str, invocable_id: &str, input_data: &FeelContext) -> Value {
+    if let Some(model_evaluator) = self.model_evaluators.get(namespace) {
+      model_evaluator.evaluate_invocable_by_id(namespace, invocable_id, input_data)
+    } else {
+      value_null!("invocable '{}' not found in namespace '{}'", invocable_id, namespace)
+    }
+  }
+
+  /// Returns input data and context paths missing in _input_data_,
+  /// that still affect the result of an invocable identified by specified _invocable_name_.
+  pub fn missing_inputs_by_name(&self, namespace: &str, invocable_name: &str, input_data: &FeelContext) -> Option<Result<Vec<QualifiedName>>> {
+    self.model_evaluators.get(namespace)?.missing_inputs_by_name(namespace, invocable_name, input_data)
+  }
+
+  /// Returns input data and context paths missing in _input_data_,
+  /// that still affect the result of an invocable identified by specified _invocable_id_.
+  pub fn missing_inputs_by_id(&self, namespace: &str, invocable_id: &str, input_data: &FeelContext) -> Option<Result<Vec<QualifiedName>>> {
+    self.model_evaluators.get(namespace)?.missing_inputs_by_id(namespace, invocable_id, input_data)
+  }
+
+  /// Collects invocables of all units, leaving only invocables from the namespace of the unit.
+  fn collect_invocables(&self, invocables: impl Fn(&ModelEvaluator) -> Vec<(String, String)>) -> Vec<(String, String)> {
+    let mut items = self
+      .model_evaluators
+      .iter()
+      .flat_map(|(namespace, model_evaluator)| invocables(model_evaluator).into_iter().filter(move |(item_namespace, _)| item_namespace == namespace))
+      .collect::<Vec<(String, String)>>();
+    items.sort();
+    items
+  }
+}
+
+/// Returns the model followed by all models it imports, directly or indirectly.
+/// Imported models are searched by namespace in specified [Definitions],
+/// imports that are not present there are skipped.
+fn deployable_unit(model: &Definitions, definitions: &[Definitions]) -> Vec<Definitions> {
+  let mut unit = vec![model.clone()];
+  let mut namespaces = HashSet::from([model.namespace().to_string()]);
+  let mut index = 0;
+  while index < unit.len() {
+    let imported_models = unit[index]
+      .imports()
+      .iter()
+      .filter_map(|import| definitions.iter().find(|definitions| definitions.namespace() == import.namespace()))
+      .filter(|imported_model| namespaces.insert(imported_model.namespace().to_string()))
+      .cloned()
+      .collect::<Vec<Definitions>>();
+    unit.extend(imported_models);
+    index += 1;
+  }
+  unit
 }
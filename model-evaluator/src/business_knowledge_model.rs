@@ -144,6 +144,18 @@ fn build_bkm_expression_instance_evaluator(
   model_builder: &ModelBuilder,
 ) -> Result<BusinessKnowledgeModelEvaluatorFn> {
   match expression_instance {
+    ExpressionInstance::Conditional(conditional) => {
+      //
+      build_bkm_conditional_evaluator(
+        scope,                  //
+        formal_parameters,      //
+        conditional,            //
+        output_variable_name,   //
+        output_variable_type,   //
+        knowledge_requirements, //
+        model_builder,          //
+      )
+    }
     ExpressionInstance::Context(context) => {
       //
       build_bkm_context_evaluator(
@@ -167,6 +179,42 @@ fn build_bkm_expression_instance_evaluator(
         knowledge_requirements, //
       )
     }
+    ExpressionInstance::Every(quantified) => {
+      //
+      build_bkm_every_evaluator(
+        scope,                  //
+        formal_parameters,      //
+        quantified,             //
+        output_variable_name,   //
+        output_variable_type,   //
+        knowledge_requirements, //
+        model_builder,          //
+      )
+    }
+    ExpressionInstance::Filter(filter) => {
+      //
+      build_bkm_filter_evaluator(
+        scope,                  //
+        formal_parameters,      //
+        filter,                 //
+        output_variable_name,   //
+        output_variable_type,   //
+        knowledge_requirements, //
+        model_builder,          //
+      )
+    }
+    ExpressionInstance::For(for_expression) => {
+      //
+      build_bkm_for_evaluator(
+        scope,                  //
+        formal_parameters,      //
+        for_expression,         //
+        output_variable_name,   //
+        output_variable_type,   //
+        knowledge_requirements, //
+        model_builder,          //
+      )
+    }
     ExpressionInstance::FunctionDefinition(function_definition) => {
       //
       build_bkm_function_definition_evaluator(
@@ -191,6 +239,18 @@ fn build_bkm_expression_instance_evaluator(
         model_builder,          //
       )
     }
+    ExpressionInstance::List(list) => {
+      //
+      build_bkm_list_evaluator(
+        scope,                  //
+        formal_parameters,      //
+        list,                   //
+        output_variable_name,   //
+        output_variable_type,   //
+        knowledge_requirements, //
+        model_builder,          //
+      )
+    }
     ExpressionInstance::LiteralExpression(literal_expression) => {
       //
       build_bkm_literal_expression_evaluator(
@@ -214,6 +274,18 @@ fn build_bkm_expression_instance_evaluator(
         model_builder,          //
       )
     }
+    ExpressionInstance::Some(quantified) => {
+      //
+      build_bkm_some_evaluator(
+        scope,                  //
+        formal_parameters,      //
+        quantified,             //
+        output_variable_name,   //
+        output_variable_type,   //
+        knowledge_requirements, //
+        model_builder,          //
+      )
+    }
   }
 }
 
@@ -359,6 +431,150 @@ fn build_bkm_relation_evaluator(
   build_bkm_evaluator_from_function_definition(output_variable_name, function_definition, knowledge_requirements)
 }
 
+///
+fn build_bkm_conditional_evaluator(
+  scope: &FeelScope,
+  formal_parameters: Vec<(Name, FeelType)>,
+  conditional: &Conditional,
+  output_variable_name: Name,
+  output_variable_type: FeelType,
+  knowledge_requirements: Vec<DefKey>,
+  model_builder: &ModelBuilder,
+) -> Result<BusinessKnowledgeModelEvaluatorFn> {
+  let (evaluator, _) = build_conditional_evaluator(scope, conditional, model_builder)?;
+  let closure = Closure::default();
+  let closure_ctx = FeelContext::default();
+  let function = Value::FunctionDefinition(
+    formal_parameters,
+    FunctionBody::Conditional(Arc::new(evaluator)),
+    false,
+    closure,
+    closure_ctx,
+    output_variable_type,
+  );
+  build_bkm_evaluator_from_function_definition(output_variable_name, function, knowledge_requirements)
+}
+
+///
+fn build_bkm_every_evaluator(
+  scope: &FeelScope,
+  formal_parameters: Vec<(Name, FeelType)>,
+  quantified: &Quantified,
+  output_variable_name: Name,
+  output_variable_type: FeelType,
+  knowledge_requirements: Vec<DefKey>,
+  model_builder: &ModelBuilder,
+) -> Result<BusinessKnowledgeModelEvaluatorFn> {
+  let (evaluator, _) = build_every_evaluator(scope, quantified, model_builder)?;
+  let closure = Closure::default();
+  let closure_ctx = FeelContext::default();
+  let function = Value::FunctionDefinition(
+    formal_parameters,
+    FunctionBody::Iterator(Arc::new(evaluator)),
+    false,
+    closure,
+    closure_ctx,
+    output_variable_type,
+  );
+  build_bkm_evaluator_from_function_definition(output_variable_name, function, knowledge_requirements)
+}
+
+///
+fn build_bkm_filter_evaluator(
+  scope: &FeelScope,
+  formal_parameters: Vec<(Name, FeelType)>,
+  filter: &Filter,
+  output_variable_name: Name,
+  output_variable_type: FeelType,
+  knowledge_requirements: Vec<DefKey>,
+  model_builder: &ModelBuilder,
+) -> Result<BusinessKnowledgeModelEvaluatorFn> {
+  let (evaluator, _) = build_filter_evaluator(scope, filter, model_builder)?;
+  let closure = Closure::default();
+  let closure_ctx = FeelContext::default();
+  let function = Value::FunctionDefinition(
+    formal_parameters,
+    FunctionBody::Filter(Arc::new(evaluator)),
+    false,
+    closure,
+    closure_ctx,
+    output_variable_type,
+  );
+  build_bkm_evaluator_from_function_definition(output_variable_name, function, knowledge_requirements)
+}
+
+///
+fn build_bkm_for_evaluator(
+  scope: &FeelScope,
+  formal_parameters: Vec<(Name, FeelType)>,
+  for_expression: &For,
+  output_variable_name: Name,
+  output_variable_type: FeelType,
+  knowledge_requirements: Vec<DefKey>,
+  model_builder: &ModelBuilder,
+) -> Result<BusinessKnowledgeModelEvaluatorFn> {
+  let (evaluator, _) = build_for_evaluator(scope, for_expression, model_builder)?;
+  let closure = Closure::default();
+  let closure_ctx = FeelContext::default();
+  let function = Value::FunctionDefinition(
+    formal_parameters,
+    FunctionBody::Iterator(Arc::new(evaluator)),
+    false,
+    closure,
+    closure_ctx,
+    output_variable_type,
+  );
+  build_bkm_evaluator_from_function_definition(output_variable_name, function, knowledge_requirements)
+}
+
+///
+fn build_bkm_list_evaluator(
+  scope: &FeelScope,
+  formal_parameters: Vec<(Name, FeelType)>,
+  list: &List,
+  output_variable_name: Name,
+  output_variable_type: FeelType,
+  knowledge_requirements: Vec<DefKey>,
+  model_builder: &ModelBuilder,
+) -> Result<BusinessKnowledgeModelEvaluatorFn> {
+  let (evaluator, _) = build_list_evaluator(scope, list, model_builder)?;
+  let closure = Closure::default();
+  let closure_ctx = FeelContext::default();
+  let function = Value::FunctionDefinition(
+    formal_parameters,
+    FunctionBody::List(Arc::new(evaluator)),
+    false,
+    closure,
+    closure_ctx,
+    output_variable_type,
+  );
+  build_bkm_evaluator_from_function_definition(output_variable_name, function, knowledge_requirements)
+}
+
+///
+fn build_bkm_some_evaluator(
+  scope: &FeelScope,
+  formal_parameters: Vec<(Name, FeelType)>,
+  quantified: &Quantified,
+  output_variable_name: Name,
+  output_variable_type: FeelType,
+  knowledge_requirements: Vec<DefKey>,
+  model_builder: &ModelBuilder,
+) -> Result<BusinessKnowledgeModelEvaluatorFn> {
+  let (evaluator, _) = build_some_evaluator(scope, quantified, model_builder)?;
+  let closure = Closure::default();
+  let closure_ctx = FeelContext::default();
+  let function = Value::FunctionDefinition(
+    formal_parameters,
+    FunctionBody::Iterator(Arc::new(evaluator)),
+    false,
+    closure,
+    closure_ctx,
+    output_variable_type,
+  );
+  build_bkm_evaluator_from_function_definition(output_variable_name, function, knowledge_requirements)
+}
+
 ///
 fn build_bkm_evaluator_from_function_definition(
   output_variable_name: Name,
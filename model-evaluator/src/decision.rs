@@ -33,6 +33,8 @@
 //! # Builder for decision evaluators
 
 use crate::boxed_expressions::*;
+use crate::input_data_context::InputDataContextEvaluator;
+use crate::item_definition_context::ItemDefinitionContextEvaluator;
 use crate::model_builder::ModelBuilder;
 use crate::model_definitions::*;
 use crate::model_evaluator::ModelEvaluator;
@@ -96,8 +98,6 @@ impl DecisionEvaluator {
 fn build_decision_evaluator(def_definitions: &DefDefinitions, def_decision: &DefDecision, model_builder: &ModelBuilder) -> Result<DecisionEvaluatorEntry> {
   // acquire all needed intermediary evaluators
   let item_definition_type_evaluator = model_builder.item_definition_type_evaluator();
-  let item_definition_context_evaluator = model_builder.item_definition_context_evaluator();
-  let input_data_context_evaluator = model_builder.input_data_context_evaluator();
 
   // get the output variable properties
   let mut output_variable: Variable = def_decision.variable().into();
@@ -109,44 +109,13 @@ fn build_decision_evaluator(def_definitions: &DefDefinitions, def_decision: &Def
   // prepare output variable type for processed decision
   let output_variable_type = output_variable.feel_type().clone();
 
-  // holds variables for required decisions and required knowledge
-  let mut build_requirements_ctx = FeelContext::default();
-
-  // hods variables for required inputs
-  let mut input_requirements_ctx = FeelContext::default();
-
-  // bring into context the variables from this decision's knowledge requirements
-  bring_knowledge_requirements_into_context(def_definitions, def_decision.knowledge_requirements(), &mut build_requirements_ctx)?;
-
-  // bring into context the variables from information requirements
-  for information_requirement in def_decision.information_requirements() {
-    // bring into context the variable from required decision
-    if let Some(def_href) = information_requirement.required_decision() {
-      if let Some(required_def_decision) = def_definitions.decision_by_key(def_href.namespace(), def_href.id()) {
-        let variable_name = required_def_decision.variable().name().clone();
-        let variable_namespace = required_def_decision.variable().namespace();
-        let variable_type_ref = required_def_decision.variable().type_ref();
-        let variable_type = item_definition_context_evaluator.eval(&DefKey::new(variable_namespace, variable_type_ref), &variable_name, &mut build_requirements_ctx);
-        if let Some(import_name) = def_href.import_name() {
-          build_requirements_ctx.create_entries(&[import_name.clone(), variable_name], Value::FeelType(variable_type));
-        } else {
-          build_requirements_ctx.set_entry(&variable_name, Value::FeelType(variable_type));
-        }
-      }
-    }
-    // bring into context the variable from required input
-    if let Some(href) = information_requirement.required_input() {
-      if let Some(required_input) = def_definitions.input_data_by_key(href.namespace(), href.id()) {
-        let variable_name = required_input.variable().name();
-        let variable_type = input_data_context_evaluator.eval(&href.into(), &mut input_requirements_ctx, item_definition_context_evaluator);
-        input_requirements_ctx.set_entry(variable_name, Value::FeelType(variable_type));
-      }
-    }
-  }
-
-  // prepare a scope and build expression instance evaluator
-  let scope: FeelScope = build_requirements_ctx.into();
-  scope.push(input_requirements_ctx.clone());
+  // prepare a scope for building expression instance evaluator
+  let scope = build_decision_scope(
+    def_definitions,
+    def_decision,
+    model_builder.item_definition_context_evaluator(),
+    model_builder.input_data_context_evaluator(),
+  )?;
 
   // prepare expression instance for this decision
   let evaluator = if let Some(expression_instance) = def_decision.decision_logic().as_ref() {
@@ -256,3 +225,51 @@ fn build_decision_evaluator(def_definitions: &DefDefinitions, def_decision: &Def
   // return the output variable and decision evaluator function
   Ok((output_variable, decision_evaluator))
 }
+
+/// Builds a scope containing variables from decision's knowledge and information requirements.
+pub fn build_decision_scope(
+  def_definitions: &DefDefinitions,
+  def_decision: &DefDecision,
+  item_definition_context_evaluator: &ItemDefinitionContextEvaluator,
+  input_data_context_evaluator: &InputDataContextEvaluator,
+) -> Result<FeelScope> {
+  // holds variables for required decisions and required knowledge
+  let mut build_requirements_ctx = FeelContext::default();
+
+  // hods variables for required inputs
+  let mut input_requirements_ctx = FeelContext::default();
+
+  // bring into context the variables from this decision's knowledge requirements
+  bring_knowledge_requirements_into_context(def_definitions, def_decision.knowledge_requirements(), &mut build_requirements_ctx)?;
+
+  // bring into context the variables from information requirements
+  for information_requirement in def_decision.information_requirements() {
+    // bring into context the variable from required decision
+    if let Some(def_href) = information_requirement.required_decision() {
+      if let Some(required_def_decision) = def_definitions.decision_by_key(def_href.namespace(), def_href.id()) {
+        let variable_name = required_def_decision.variable().name().clone();
+        let variable_namespace = required_def_decision.variable().namespace();
+        let variable_type_ref = required_def_decision.variable().type_ref();
+        let variable_type = item_definition_context_evaluator.eval(&DefKey::new(variable_namespace, variable_type_ref), &variable_name, &mut build_requirements_ctx);
+        if let Some(import_name) = def_href.import_name() {
+          build_requirements_ctx.create_entries(&[import_name.clone(), variable_name], Value::FeelType(variable_type));
+        } else {
+          build_requirements_ctx.set_entry(&variable_name, Value::FeelType(variable_type));
+        }
+      }
+    }
+    // bring into context the variable from required input
+    if let Some(href) = information_requirement.required_input() {
+      if let Some(required_input) = def_definitions.input_data_by_key(href.namespace(), href.id()) {
+        let variable_name = required_input.variable().name();
+        let variable_type = input_data_context_evaluator.eval(&href.into(), &mut input_requirements_ctx, item_definition_context_evaluator);
+        input_requirements_ctx.set_entry(variable_name, Value::FeelType(variable_type));
+      }
+    }
+  }
+
+  // prepare a scope for building expression instance evaluator
+  let scope: FeelScope = build_requirements_ctx.into();
+  scope.push(input_requirements_ctx);
+  Ok(scope)
+}
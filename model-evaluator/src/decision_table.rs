@@ -299,25 +299,18 @@ fn parse_decision_table(scope: &FeelScope, decision_table: &DecisionTable) -> Re
     let mut input_entries_evaluators = vec![];
     for (i, (input_expression, input_values)) in input_expressions_and_values.iter().enumerate() {
       let input_entry_node = dmntk_feel_parser::parse_unary_tests(scope, &rule.input_entries[i].text, false)?;
-      if let Some(input_values_node) = input_values {
-        let left = AstNode::In(Box::new(input_expression.clone()), Box::new(input_values_node.clone()));
-        let right = AstNode::In(Box::new(input_expression.clone()), Box::new(input_entry_node));
-        let node = AstNode::And(Box::new(left), Box::new(right));
-        input_entries_evaluators.push(dmntk_feel_evaluator::prepare(&node)?);
-      } else {
-        let node = AstNode::In(Box::new(input_expression.clone()), Box::new(input_entry_node));
-        input_entries_evaluators.push(dmntk_feel_evaluator::prepare(&node)?);
-      }
+      let node = build_input_entry_node(input_expression, input_values, input_entry_node);
+      input_entries_evaluators.push(dmntk_feel_evaluator::prepare(&node)?);
     }
     // parse output clause
     let mut output_entries_evaluators = vec![];
     for (i, output_values) in output_values_nodes.iter().enumerate() {
-      let output_entry_node = dmntk_feel_parser::parse_expression(scope, &rule.output_entries[i].text, false)?;
+      let (output_entry_node, output_entry_spans) = dmntk_feel_parser::parse_expression_with_spans(scope, &rule.output_entries[i].text, false)?;
       if let Some(output_value_node) = output_values {
         let node = AstNode::Out(Box::new(output_entry_node), Box::new(output_value_node.clone()));
         output_entries_evaluators.push(dmntk_feel_evaluator::prepare(&node)?);
       } else {
-        output_entries_evaluators.push(dmntk_feel_evaluator::prepare(&output_entry_node)?);
+        output_entries_evaluators.push(dmntk_feel_evaluator::prepare_with_spans(&output_entry_node, &output_entry_spans)?);
       }
     }
     parsed_rules.push(ParsedRule {
@@ -349,6 +342,18 @@ fn parse_decision_table(scope: &FeelScope, decision_table: &DecisionTable) -> Re
   })
 }
 
+/// Builds a node testing the value of the input expression against the input entry,
+/// and against allowed input values when specified.
+pub fn build_input_entry_node(input_expression: &AstNode, input_values: &Option<AstNode>, input_entry_node: AstNode) -> AstNode {
+  if let Some(input_values_node) = input_values {
+    let left = AstNode::In(Box::new(input_expression.clone()), Box::new(input_values_node.clone()));
+    let right = AstNode::In(Box::new(input_expression.clone()), Box::new(input_entry_node));
+    AstNode::And(Box::new(left), Box::new(right))
+  } else {
+    AstNode::In(Box::new(input_expression.clone()), Box::new(input_entry_node))
+  }
+}
+
 ///
 fn evaluate_parsed_decision_table(scope: &FeelScope, parsed_decision_table: &ParsedDecisionTable) -> EvaluatedDecisionTable {
   // evaluate only non-empty output values
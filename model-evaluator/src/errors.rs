@@ -64,3 +64,11 @@ pub fn err_empty_feel_type() -> DmntkError {
 pub fn err_empty_function_body() -> DmntkError {
   ModelEvaluatorError("empty function definition body".into()).into()
 }
+
+pub fn err_input_discovery_failed(reason: &str) -> DmntkError {
+  ModelEvaluatorError(format!("inputs could not be discovered: {reason}")).into()
+}
+
+pub fn err_missing_input_entry(rule_index: usize, input_index: usize) -> DmntkError {
+  ModelEvaluatorError(format!("rule {rule_index} has no input entry for input clause {input_index}")).into()
+}
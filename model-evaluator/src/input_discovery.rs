@@ -0,0 +1,707 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! # Discovery of inputs read by invocables
+//!
+//! Every decision's logic is analysed into a [Demand], describing values
+//! of input data and required decisions read while evaluating the decision.
+//! Given a partial input, conditions, logical operands and decision table input entries
+//! whose values are already known are evaluated, and branches that can not influence
+//! the result any more are pruned. The remaining paths not present in the input
+//! are reported as missing inputs.
+//!
+//! Required decisions having all their inputs known are evaluated by the model evaluator,
+//! so their values are subject to the same coercion of input data as during regular evaluation.
+
+use crate::decision::build_decision_scope;
+use crate::decision_table::build_input_entry_node;
+use crate::errors::*;
+use crate::input_data_context::InputDataContextEvaluator;
+use crate::item_definition_context::ItemDefinitionContextEvaluator;
+use crate::model_definitions::*;
+use crate::model_evaluator::ModelEvaluator;
+use dmntk_common::Result;
+use dmntk_feel::bif::is_built_in_function_name;
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::values::Value;
+use dmntk_feel::{value_null, Evaluator, FeelScope, Name, QualifiedName};
+use dmntk_feel_parser::{AstNode, ClosureBuilder};
+use dmntk_model::*;
+use once_cell::sync::OnceCell;
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+/// Demand of an expression for values of input data and required decisions.
+enum Demand {
+  /// Values pointed by qualified names are read unconditionally.
+  Names(BTreeSet<QualifiedName>),
+  /// Values demanded by all nested demands are read.
+  All(Vec<Demand>),
+  /// Values read by the guard are read, followed by values demanded by the first branch
+  /// when the guard evaluates to `true`, or by the second branch otherwise.
+  Conditional(Guard, Box<Demand>, Box<Demand>),
+  /// Operands of conjunction (`false`) or disjunction (`true`); when any operand
+  /// evaluates to the short-circuit value, the remaining operands are not read.
+  Junction(bool, Vec<(Guard, Demand)>),
+  /// Rules of a decision table.
+  DecisionTable(Box<DecisionTableDemand>),
+}
+
+/// Expression that is evaluated when all values it reads are known.
+struct Guard {
+  /// Qualified names read by the guard.
+  names: BTreeSet<QualifiedName>,
+  /// Evaluator of the guard.
+  evaluator: Evaluator,
+}
+
+/// Demand of a single decision table rule.
+struct RuleDemand {
+  /// Guards built from input entries, `None` for irrelevant input entries.
+  input_entries: Vec<Option<Guard>>,
+  /// Demand of output entries.
+  output_entries: Demand,
+}
+
+/// Demand of a decision table.
+struct DecisionTableDemand {
+  /// Flag indicating if only the first matching rule contributes to the result.
+  first: bool,
+  /// Demands of rules in rule order.
+  rules: Vec<RuleDemand>,
+  /// Demand of output values and default output values.
+  output_values: Demand,
+}
+
+/// Demand of a decision.
+struct DecisionDemand {
+  /// Names of required input data.
+  input_data_names: Vec<Name>,
+  /// Required decisions (import name, output variable name, decision key).
+  required_decisions: Vec<(Option<Name>, Name, DefKey)>,
+  /// Demand of the decision logic.
+  demand: Demand,
+}
+
+/// Demand of a decision service.
+struct DecisionServiceDemand {
+  /// Keys of input decisions, their values are taken from the input.
+  input_decisions: HashSet<DefKey>,
+  /// Keys of output decisions.
+  output_decisions: Vec<DefKey>,
+}
+
+/// Input discovery built on first use.
+///
+/// Decisions are analysed only when missing or required inputs are requested for the first time,
+/// so models whose decisions can not be analysed are still deployed and evaluated,
+/// the reason of the failed analysis is reported to every request for inputs.
+pub struct LazyInputDiscovery {
+  /// Model definitions.
+  definitions: DefDefinitions,
+  /// Item definition context evaluator used to build the scopes of decisions.
+  item_definition_context_evaluator: ItemDefinitionContextEvaluator,
+  /// Input data context evaluator used to build the scopes of decisions.
+  input_data_context_evaluator: InputDataContextEvaluator,
+  /// Input discovery or the reason why it could not be built.
+  input_discovery: OnceCell<Result<InputDiscovery, String>>,
+}
+
+impl LazyInputDiscovery {
+  /// Creates a new input discovery, that will be built on first use.
+  pub fn new(definitions: DefDefinitions, item_definition_context_evaluator: ItemDefinitionContextEvaluator, input_data_context_evaluator: InputDataContextEvaluator) -> Self {
+    Self {
+      definitions,
+      item_definition_context_evaluator,
+      input_data_context_evaluator,
+      input_discovery: OnceCell::new(),
+    }
+  }
+
+  /// Returns input data and context paths missing in `input_data`,
+  /// that still affect the result of the specified invocable.
+  pub fn missing_inputs(&self, invocable_type: &InvocableType, input_data: &FeelContext, model_evaluator: &ModelEvaluator) -> Result<Vec<QualifiedName>> {
+    let input_discovery = self
+      .input_discovery
+      .get_or_init(|| InputDiscovery::new(&self.definitions, &self.item_definition_context_evaluator, &self.input_data_context_evaluator).map_err(|reason| reason.to_string()));
+    match input_discovery {
+      Ok(input_discovery) => Ok(input_discovery.missing_inputs(invocable_type, input_data, model_evaluator)),
+      Err(reason) => Err(err_input_discovery_failed(reason)),
+    }
+  }
+}
+
+/// Input discovery.
+struct InputDiscovery {
+  /// Demands of decisions.
+  decisions: HashMap<DefKey, DecisionDemand>,
+  /// Names of formal parameters of business knowledge models.
+  business_knowledge_models: HashMap<DefKey, Vec<Name>>,
+  /// Demands of decision services.
+  decision_services: HashMap<DefKey, DecisionServiceDemand>,
+}
+
+impl InputDiscovery {
+  /// Creates a new input discovery.
+  fn new(
+    definitions: &DefDefinitions,
+    item_definition_context_evaluator: &ItemDefinitionContextEvaluator,
+    input_data_context_evaluator: &InputDataContextEvaluator,
+  ) -> Result<Self> {
+    let mut decisions = HashMap::new();
+    for decision in definitions.decisions() {
+      let def_key = DefKey::new(decision.namespace(), decision.id());
+      decisions.insert(
+        def_key,
+        build_decision_demand(definitions, decision, item_definition_context_evaluator, input_data_context_evaluator)?,
+      );
+    }
+    let mut business_knowledge_models = HashMap::new();
+    for business_knowledge_model in definitions.business_knowledge_models() {
+      let def_key = DefKey::new(business_knowledge_model.namespace(), business_knowledge_model.id());
+      let parameter_names = business_knowledge_model
+        .encapsulated_logic()
+        .as_ref()
+        .map(|function_definition| function_definition.formal_parameters().iter().map(|parameter| parameter.feel_name().clone()).collect())
+        .unwrap_or_default();
+      business_knowledge_models.insert(def_key, parameter_names);
+    }
+    let mut decision_services = HashMap::new();
+    for decision_service in definitions.decision_services() {
+      let def_key = DefKey::new(decision_service.namespace(), decision_service.id());
+      let input_decisions = decision_service.input_decisions().iter().map(|href| href.into()).collect();
+      let output_decisions = decision_service.output_decisions().iter().map(|href| href.into()).collect();
+      decision_services.insert(
+        def_key,
+        DecisionServiceDemand {
+          input_decisions,
+          output_decisions,
+        },
+      );
+    }
+    Ok(Self {
+      decisions,
+      business_knowledge_models,
+      decision_services,
+    })
+  }
+
+  /// Returns input data and context paths missing in `input_data`,
+  /// that still affect the result of the specified invocable.
+  fn missing_inputs(&self, invocable_type: &InvocableType, input_data: &FeelContext, model_evaluator: &ModelEvaluator) -> Vec<QualifiedName> {
+    let mut missing = BTreeSet::new();
+    match invocable_type {
+      InvocableType::Decision(def_key) => {
+        let mut discovery = Discovery::new(self, model_evaluator, HashSet::new());
+        missing = discovery.decision(def_key, &[], input_data);
+      }
+      InvocableType::BusinessKnowledgeModel(def_key, _) => {
+        if let Some(parameter_names) = self.business_knowledge_models.get(def_key) {
+          for parameter_name in parameter_names {
+            if !input_data.contains_entry(parameter_name) {
+              missing.insert(parameter_name.clone().into());
+            }
+          }
+        }
+      }
+      InvocableType::DecisionService(def_key) => {
+        if let Some(decision_service_demand) = self.decision_services.get(def_key) {
+          let mut discovery = Discovery::new(self, model_evaluator, decision_service_demand.input_decisions.clone());
+          for output_decision in &decision_service_demand.output_decisions {
+            missing.append(&mut discovery.decision(output_decision, &[], input_data));
+          }
+        }
+      }
+    }
+    shortest_paths(missing)
+  }
+}
+
+/// Removes paths already covered by shorter paths (prefixes).
+fn shortest_paths(paths: BTreeSet<QualifiedName>) -> Vec<QualifiedName> {
+  let mut result: Vec<QualifiedName> = vec![];
+  // paths are ordered, so a prefix always precedes paths it covers
+  for path in paths {
+    if !result.iter().any(|prefix| path.starts_with(prefix)) {
+      result.push(path);
+    }
+  }
+  result
+}
+
+/// Resolution of a qualified name read by an expression.
+enum Resolution {
+  /// The value is known.
+  Known,
+  /// The value is not known, listed paths are missing in the input.
+  Missing(BTreeSet<QualifiedName>),
+  /// The name is local to the expression (context entry, parameter, iteration variable),
+  /// it does not demand any input, but its value is not known during discovery.
+  Local,
+}
+
+/// State of a single discovery run.
+struct Discovery<'a> {
+  /// Analysed demands.
+  input_discovery: &'a InputDiscovery,
+  /// Model evaluator used to evaluate decisions with all inputs known.
+  model_evaluator: &'a ModelEvaluator,
+  /// Keys of decisions whose values are taken from the input (input decisions of decision service).
+  input_decisions: HashSet<DefKey>,
+  /// Missing inputs already discovered, indexed by decision key and import path.
+  discovered: HashMap<(DefKey, Vec<Name>), BTreeSet<QualifiedName>>,
+}
+
+impl<'a> Discovery<'a> {
+  /// Creates a new discovery run.
+  fn new(input_discovery: &'a InputDiscovery, model_evaluator: &'a ModelEvaluator, input_decisions: HashSet<DefKey>) -> Self {
+    Self {
+      input_discovery,
+      model_evaluator,
+      input_decisions,
+      discovered: HashMap::new(),
+    }
+  }
+
+  /// Returns paths missing in the input, that still affect the result of the decision.
+  /// Returned paths are relative to provided input data.
+  fn decision(&mut self, def_key: &DefKey, import_path: &[Name], input_data: &FeelContext) -> BTreeSet<QualifiedName> {
+    let discovered_key = (def_key.clone(), import_path.to_vec());
+    if let Some(missing) = self.discovered.get(&discovered_key) {
+      return missing.clone();
+    }
+    let Some(decision_demand) = self.input_discovery.decisions.get(def_key) else {
+      return BTreeSet::new();
+    };
+    // collect values of provided input data
+    let mut known_ctx = FeelContext::default();
+    for input_data_name in &decision_demand.input_data_names {
+      if let Some(value) = input_data.get_entry(input_data_name) {
+        known_ctx.set_entry(input_data_name, value.clone());
+      }
+    }
+    // resolve required decisions, evaluate those having all inputs known
+    let mut required_decisions = vec![];
+    for (import_name, variable_name, required_def_key) in &decision_demand.required_decisions {
+      let mut names = vec![];
+      names.extend(import_name.clone());
+      names.push(variable_name.clone());
+      let qname: QualifiedName = names.into();
+      if self.input_decisions.contains(required_def_key) {
+        // value of the input decision is taken directly from the input
+        let mut missing = BTreeSet::new();
+        if let Some(value) = input_data.search_entry(&qname) {
+          known_ctx.create_entry(&qname, value.clone());
+        } else {
+          missing.insert(qname.clone());
+        }
+        required_decisions.push((qname, missing));
+        continue;
+      }
+      let mut required_import_path = import_path.to_vec();
+      let mut required_input_data = input_data.clone();
+      if let Some(import_name) = import_name {
+        required_import_path.push(import_name.clone());
+        if let Some(Value::Context(ctx)) = required_input_data.remove_entry(import_name) {
+          required_input_data.zip(&ctx);
+        }
+      }
+      let missing = self
+        .decision(required_def_key, &required_import_path, &required_input_data)
+        .into_iter()
+        .map(|path| match import_name {
+          Some(import_name) => {
+            let mut prefixed = path;
+            prefixed.insert(0, import_name.clone());
+            prefixed
+          }
+          None => path,
+        })
+        .collect::<BTreeSet<QualifiedName>>();
+      if missing.is_empty() {
+        let mut evaluated_ctx = FeelContext::default();
+        let decision_evaluator = self.model_evaluator.decision_evaluator();
+        if let Some(output_variable_name) = decision_evaluator.evaluate(required_def_key, &required_input_data, self.model_evaluator, &mut evaluated_ctx) {
+          let value = evaluated_ctx.get_entry(&output_variable_name).cloned().unwrap_or_else(|| value_null!());
+          known_ctx.create_entry(&qname, value);
+        }
+      }
+      required_decisions.push((qname, missing));
+    }
+    let decision_scope = DecisionScope {
+      input_data_names: &decision_demand.input_data_names,
+      required_decisions,
+      input_data,
+      known: known_ctx.into(),
+    };
+    let mut missing = BTreeSet::new();
+    decision_scope.collect(&decision_demand.demand, &mut missing);
+    self.discovered.insert(discovered_key, missing.clone());
+    missing
+  }
+}
+
+/// Values visible while discovering inputs of a single decision.
+struct DecisionScope<'a> {
+  /// Names of required input data.
+  input_data_names: &'a [Name],
+  /// Qualified names of required decisions with paths missing to evaluate them.
+  required_decisions: Vec<(QualifiedName, BTreeSet<QualifiedName>)>,
+  /// Provided input data.
+  input_data: &'a FeelContext,
+  /// Scope with known values of input data and required decisions.
+  known: FeelScope,
+}
+
+impl DecisionScope<'_> {
+  /// Resolves a qualified name read by an expression.
+  fn resolve(&self, qname: &QualifiedName) -> Resolution {
+    if let Some(first) = qname.first() {
+      if self.input_data_names.contains(first) {
+        return if self.input_data.contains_entries(qname) {
+          Resolution::Known
+        } else {
+          Resolution::Missing(BTreeSet::from([qname.clone()]))
+        };
+      }
+    }
+    for (decision_qname, missing) in &self.required_decisions {
+      if qname.starts_with(decision_qname) {
+        return if missing.is_empty() { Resolution::Known } else { Resolution::Missing(missing.clone()) };
+      }
+    }
+    if qname.len() == 1 && is_built_in_function_name(&qname[0].to_string()) {
+      return Resolution::Known;
+    }
+    Resolution::Local
+  }
+
+  /// Adds paths missing for specified names to the collection of missing paths.
+  fn collect_names(&self, names: &BTreeSet<QualifiedName>, missing: &mut BTreeSet<QualifiedName>) {
+    for qname in names {
+      if let Resolution::Missing(mut paths) = self.resolve(qname) {
+        missing.append(&mut paths);
+      }
+    }
+  }
+
+  /// Evaluates the guard when all values it reads are known.
+  fn evaluate(&self, guard: &Guard) -> Option<Value> {
+    if guard.names.iter().all(|qname| matches!(self.resolve(qname), Resolution::Known)) {
+      Some((guard.evaluator)(&self.known))
+    } else {
+      None
+    }
+  }
+
+  /// Adds paths missing for specified demand to the collection of missing paths.
+  fn collect(&self, demand: &Demand, missing: &mut BTreeSet<QualifiedName>) {
+    match demand {
+      Demand::Names(names) => self.collect_names(names, missing),
+      Demand::All(demands) => demands.iter().for_each(|demand| self.collect(demand, missing)),
+      Demand::Conditional(guard, then_demand, else_demand) => match self.evaluate(guard) {
+        Some(value) if value.is_true() => self.collect(then_demand, missing),
+        Some(_) => self.collect(else_demand, missing),
+        None => {
+          self.collect_names(&guard.names, missing);
+          self.collect(then_demand, missing);
+          self.collect(else_demand, missing);
+        }
+      },
+      Demand::Junction(short_circuit, operands) => {
+        let short_circuited = operands
+          .iter()
+          .any(|(guard, _)| matches!(self.evaluate(guard), Some(Value::Boolean(value)) if value == *short_circuit));
+        if !short_circuited {
+          operands.iter().for_each(|(_, demand)| self.collect(demand, missing));
+        }
+      }
+      Demand::DecisionTable(decision_table_demand) => {
+        for rule_demand in &decision_table_demand.rules {
+          let mut matches = true;
+          let mut rejected = false;
+          for guard in rule_demand.input_entries.iter().flatten() {
+            match self.evaluate(guard) {
+              Some(value) if value.is_true() => {}
+              Some(_) => {
+                rejected = true;
+                break;
+              }
+              None => matches = false,
+            }
+          }
+          if rejected {
+            continue;
+          }
+          for guard in rule_demand.input_entries.iter().flatten() {
+            self.collect_names(&guard.names, missing);
+          }
+          self.collect(&rule_demand.output_entries, missing);
+          if matches && decision_table_demand.first {
+            break;
+          }
+        }
+        self.collect(&decision_table_demand.output_values, missing);
+      }
+    }
+  }
+}
+
+/// Builds the demand of a decision.
+fn build_decision_demand(
+  def_definitions: &DefDefinitions,
+  def_decision: &DefDecision,
+  item_definition_context_evaluator: &ItemDefinitionContextEvaluator,
+  input_data_context_evaluator: &InputDataContextEvaluator,
+) -> Result<DecisionDemand> {
+  let mut input_data_names = vec![];
+  let mut required_decisions = vec![];
+  for information_requirement in def_decision.information_requirements() {
+    if let Some(href) = information_requirement.required_decision() {
+      if let Some(required_def_decision) = def_definitions.decision_by_key(href.namespace(), href.id()) {
+        let variable_name = required_def_decision.variable().name().clone();
+        required_decisions.push((href.import_name().cloned(), variable_name, href.into()));
+      }
+    }
+    if let Some(href) = information_requirement.required_input() {
+      if let Some(required_input) = def_definitions.input_data_by_key(href.namespace(), href.id()) {
+        input_data_names.push(required_input.variable().name().clone());
+      }
+    }
+  }
+  let demand = if let Some(expression_instance) = def_decision.decision_logic() {
+    let scope = build_decision_scope(def_definitions, def_decision, item_definition_context_evaluator, input_data_context_evaluator)?;
+    build_expression_instance_demand(&scope, expression_instance)?
+  } else {
+    Demand::All(vec![])
+  };
+  Ok(DecisionDemand {
+    input_data_names,
+    required_decisions,
+    demand,
+  })
+}
+
+/// Builds the demand of an expression instance.
+fn build_expression_instance_demand(scope: &FeelScope, expression_instance: &ExpressionInstance) -> Result<Demand> {
+  match expression_instance {
+    ExpressionInstance::Conditional(conditional) => build_conditional_demand(scope, conditional),
+    ExpressionInstance::Context(context) => build_context_demand(scope, context),
+    ExpressionInstance::DecisionTable(decision_table) => build_decision_table_demand(scope, decision_table),
+    ExpressionInstance::Every(quantified) | ExpressionInstance::Some(quantified) => {
+      build_iterator_demand(scope, quantified.iterator_variable(), quantified.in_expression(), quantified.satisfies_expression())
+    }
+    ExpressionInstance::Filter(filter) => build_iterator_demand(scope, "item", filter.in_expression(), filter.match_expression()),
+    ExpressionInstance::For(for_expression) => build_iterator_demand(
+      scope,
+      for_expression.iterator_variable(),
+      for_expression.in_expression(),
+      for_expression.return_expression(),
+    ),
+    ExpressionInstance::FunctionDefinition(function_definition) => build_function_definition_demand(scope, function_definition),
+    ExpressionInstance::Invocation(invocation) => build_invocation_demand(scope, invocation),
+    ExpressionInstance::List(list) => build_list_demand(scope, list),
+    ExpressionInstance::LiteralExpression(literal_expression) => build_literal_expression_demand(scope, literal_expression),
+    ExpressionInstance::Relation(relation) => build_relation_demand(scope, relation),
+  }
+}
+
+/// Builds the demand of a boxed conditional.
+fn build_conditional_demand(scope: &FeelScope, conditional: &Conditional) -> Result<Demand> {
+  let then_demand = build_expression_instance_demand(scope, conditional.then_expression().value())?;
+  let else_demand = build_expression_instance_demand(scope, conditional.else_expression().value())?;
+  if let ExpressionInstance::LiteralExpression(literal_expression) = conditional.if_expression().value() {
+    let text = literal_expression.text().as_ref().ok_or_else(err_empty_literal_expression)?;
+    let node = dmntk_feel_parser::parse_expression(scope, text, false)?;
+    Ok(Demand::Conditional(build_guard(&node)?, Box::new(then_demand), Box::new(else_demand)))
+  } else {
+    let if_demand = build_expression_instance_demand(scope, conditional.if_expression().value())?;
+    Ok(Demand::All(vec![if_demand, then_demand, else_demand]))
+  }
+}
+
+/// Builds the demand of a boxed filter or iterator, the iterated list is always read,
+/// the iterator variable is visible only in the expression evaluated in each iteration.
+fn build_iterator_demand(scope: &FeelScope, iterator_variable: &str, in_expression: &ChildExpression, iteration_expression: &ChildExpression) -> Result<Demand> {
+  let in_demand = build_expression_instance_demand(scope, in_expression.value())?;
+  scope.push(FeelContext::default());
+  scope.set_name(iterator_variable.into());
+  let iteration_demand = build_expression_instance_demand(scope, iteration_expression.value());
+  scope.pop();
+  Ok(Demand::All(vec![in_demand, iteration_demand?]))
+}
+
+/// Builds the demand of a boxed list.
+fn build_list_demand(scope: &FeelScope, list: &List) -> Result<Demand> {
+  let mut demands = vec![];
+  for element in list.elements() {
+    demands.push(build_expression_instance_demand(scope, element)?);
+  }
+  Ok(Demand::All(demands))
+}
+
+/// Builds the demand of a boxed context.
+fn build_context_demand(scope: &FeelScope, context: &Context) -> Result<Demand> {
+  let mut demands = vec![];
+  scope.push(FeelContext::default());
+  for context_entry in context.context_entries() {
+    demands.push(build_expression_instance_demand(scope, &context_entry.value)?);
+    if let Some(variable) = &context_entry.variable {
+      scope.set_name(variable.feel_name().clone());
+    }
+  }
+  scope.pop();
+  Ok(Demand::All(demands))
+}
+
+/// Builds the demand of a decision table.
+fn build_decision_table_demand(scope: &FeelScope, decision_table: &DecisionTable) -> Result<Demand> {
+  let mut input_expressions_and_values = vec![];
+  for input_clause in decision_table.input_clauses() {
+    let input_expression = dmntk_feel_parser::parse_expression(scope, &input_clause.input_expression, false)?;
+    let input_values = if let Some(input) = &input_clause.allowed_input_values {
+      Some(dmntk_feel_parser::parse_unary_tests(scope, input, false)?)
+    } else {
+      None
+    };
+    input_expressions_and_values.push((input_expression, input_values));
+  }
+  let mut output_values_names = BTreeSet::new();
+  for output_clause in decision_table.output_clauses() {
+    for text in output_clause.allowed_output_values.iter().chain(output_clause.default_output_entry.iter()) {
+      let node = dmntk_feel_parser::parse_unary_tests(scope, text, false)?;
+      output_values_names.append(&mut closure_names(&node));
+    }
+  }
+  let mut rules = vec![];
+  for (rule_index, rule) in decision_table.rules().enumerate() {
+    let mut input_entries = vec![];
+    for (i, (input_expression, input_values)) in input_expressions_and_values.iter().enumerate() {
+      let input_entry = rule.input_entries.get(i).ok_or_else(|| err_missing_input_entry(rule_index + 1, i + 1))?;
+      let input_entry_node = dmntk_feel_parser::parse_unary_tests(scope, &input_entry.text, false)?;
+      if matches!(input_entry_node, AstNode::Irrelevant) {
+        input_entries.push(None);
+      } else {
+        let node = build_input_entry_node(input_expression, input_values, input_entry_node);
+        input_entries.push(Some(build_guard(&node)?));
+      }
+    }
+    let mut output_entries = vec![];
+    for output_entry in &rule.output_entries {
+      let node = dmntk_feel_parser::parse_expression(scope, &output_entry.text, false)?;
+      output_entries.push(build_node_demand(&node)?);
+    }
+    rules.push(RuleDemand {
+      input_entries,
+      output_entries: Demand::All(output_entries),
+    });
+  }
+  Ok(Demand::DecisionTable(Box::new(DecisionTableDemand {
+    first: matches!(decision_table.hit_policy(), HitPolicy::First),
+    rules,
+    output_values: Demand::Names(output_values_names),
+  })))
+}
+
+/// Builds the demand of a function definition.
+fn build_function_definition_demand(scope: &FeelScope, function_definition: &FunctionDefinition) -> Result<Demand> {
+  let mut parameters_ctx = FeelContext::default();
+  for parameter in function_definition.formal_parameters() {
+    parameters_ctx.set_entry(parameter.feel_name(), value_null!());
+  }
+  let body_expression_instance = function_definition.body().as_ref().ok_or_else(err_empty_function_body)?;
+  scope.push(parameters_ctx);
+  let demand = build_expression_instance_demand(scope, body_expression_instance);
+  scope.pop();
+  demand
+}
+
+/// Builds the demand of an invocation.
+fn build_invocation_demand(scope: &FeelScope, invocation: &Invocation) -> Result<Demand> {
+  let mut demands = vec![build_expression_instance_demand(scope, invocation.called_function())?];
+  for binding in invocation.bindings() {
+    if let Some(binding_formula) = binding.binding_formula() {
+      demands.push(build_expression_instance_demand(scope, binding_formula)?);
+    }
+  }
+  Ok(Demand::All(demands))
+}
+
+/// Builds the demand of a literal expression.
+fn build_literal_expression_demand(scope: &FeelScope, literal_expression: &LiteralExpression) -> Result<Demand> {
+  let text = literal_expression.text().as_ref().ok_or_else(err_empty_literal_expression)?;
+  let node = dmntk_feel_parser::parse_expression(scope, text, false)?;
+  build_node_demand(&node)
+}
+
+/// Builds the demand of a relation.
+fn build_relation_demand(scope: &FeelScope, relation: &Relation) -> Result<Demand> {
+  let mut demands = vec![];
+  for row in relation.rows() {
+    for element in row.elements() {
+      demands.push(build_expression_instance_demand(scope, element)?);
+    }
+  }
+  Ok(Demand::All(demands))
+}
+
+/// Builds the demand of a parsed FEEL expression.
+fn build_node_demand(node: &AstNode) -> Result<Demand> {
+  match node {
+    AstNode::If(condition, then_node, else_node) => Ok(Demand::Conditional(
+      build_guard(condition)?,
+      Box::new(build_node_demand(then_node)?),
+      Box::new(build_node_demand(else_node)?),
+    )),
+    AstNode::And(lhs, rhs) => Ok(Demand::Junction(
+      false,
+      vec![(build_guard(lhs)?, build_node_demand(lhs)?), (build_guard(rhs)?, build_node_demand(rhs)?)],
+    )),
+    AstNode::Or(lhs, rhs) => Ok(Demand::Junction(
+      true,
+      vec![(build_guard(lhs)?, build_node_demand(lhs)?), (build_guard(rhs)?, build_node_demand(rhs)?)],
+    )),
+    other => Ok(Demand::Names(closure_names(other))),
+  }
+}
+
+/// Builds a guard from parsed FEEL expression.
+fn build_guard(node: &AstNode) -> Result<Guard> {
+  Ok(Guard {
+    names: closure_names(node),
+    evaluator: dmntk_feel_evaluator::prepare(node)?,
+  })
+}
+
+/// Returns qualified names read by parsed FEEL expression.
+fn closure_names(node: &AstNode) -> BTreeSet<QualifiedName> {
+  ClosureBuilder::from_node(node).iter().cloned().collect()
+}
@@ -40,6 +40,7 @@ mod decision;
 mod decision_service;
 mod decision_table;
 mod errors;
+mod input_discovery;
 mod input_data;
 mod input_data_context;
 mod item_definition;
@@ -54,5 +55,6 @@ mod variable;
 #[cfg(test)]
 mod tests;
 
+pub use bundle_evaluator::BundleEvaluator;
 pub use decision_table::build_decision_table_evaluator;
 pub use model_evaluator::ModelEvaluator;
@@ -35,6 +35,7 @@ use crate::decision::DecisionEvaluator;
 use crate::decision_service::DecisionServiceEvaluator;
 use crate::input_data::InputDataEvaluator;
 use crate::input_data_context::InputDataContextEvaluator;
+use crate::input_discovery::LazyInputDiscovery;
 use crate::item_definition::ItemDefinitionEvaluator;
 use crate::item_definition_context::ItemDefinitionContextEvaluator;
 use crate::item_definition_type::ItemDefinitionTypeEvaluator;
@@ -50,6 +51,7 @@ pub type EvaluatorBuilders = (
   BusinessKnowledgeModelEvaluator,
   DecisionEvaluator,
   DecisionServiceEvaluator,
+  LazyInputDiscovery,
   Invocables,
 );
 
@@ -151,6 +153,7 @@ impl From<ModelBuilder> for EvaluatorBuilders {
       value.business_knowledge_model_evaluator,
       value.decision_evaluator,
       value.decision_service_evaluator,
+      LazyInputDiscovery::new(value.model_definitions, value.item_definition_context_evaluator, value.input_data_context_evaluator),
       value.invocables.into_inner(),
     )
   }
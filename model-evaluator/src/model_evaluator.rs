@@ -36,13 +36,14 @@ use crate::business_knowledge_model::BusinessKnowledgeModelEvaluator;
 use crate::decision::DecisionEvaluator;
 use crate::decision_service::DecisionServiceEvaluator;
 use crate::input_data::InputDataEvaluator;
+use crate::input_discovery::LazyInputDiscovery;
 use crate::item_definition::ItemDefinitionEvaluator;
 use crate::model_builder::ModelBuilder;
 use crate::model_definitions::{DefKey, InvocableType, Invocables};
 use dmntk_common::Result;
 use dmntk_feel::context::FeelContext;
 use dmntk_feel::values::Value;
-use dmntk_feel::{value_null, Name};
+use dmntk_feel::{value_null, Name, QualifiedName};
 use dmntk_model::Definitions;
 use std::sync::Arc;
 
@@ -58,6 +59,8 @@ pub struct ModelEvaluator {
   decision_evaluator: DecisionEvaluator,
   /// Decision service evaluator.
   decision_service_evaluator: DecisionServiceEvaluator,
+  /// Input discovery, built when inputs are requested for the first time.
+  input_discovery: LazyInputDiscovery,
   /// Map of invocables indexed by invocable name.
   invocables: Invocables,
 }
@@ -65,13 +68,15 @@ pub struct ModelEvaluator {
 impl From<ModelBuilder> for ModelEvaluator {
   /// Creates [ModelEvaluator] from provided [ModelBuilder].
   fn from(model_builder: ModelBuilder) -> Self {
-    let (input_data_evaluator, item_definition_evaluator, business_knowledge_model_evaluator, decision_evaluator, decision_service_evaluator, invocables) = model_builder.into();
+    let (input_data_evaluator, item_definition_evaluator, business_knowledge_model_evaluator, decision_evaluator, decision_service_evaluator, input_discovery, invocables) =
+      model_builder.into();
     Self {
       input_data_evaluator,
       item_definition_evaluator,
       business_knowledge_model_evaluator,
       decision_evaluator,
       decision_service_evaluator,
+      input_discovery,
       invocables,
     }
   }
@@ -148,6 +153,34 @@ impl ModelEvaluator {
     }
   }
 
+  /// Returns input data and context paths read while evaluating
+  /// an invocable identified by specified _invocable_name_.
+  pub fn required_inputs_by_name(&self, namespace: &str, invocable_name: &str) -> Option<Result<Vec<QualifiedName>>> {
+    self.missing_inputs_by_name(namespace, invocable_name, &FeelContext::default())
+  }
+
+  /// Returns input data and context paths read while evaluating
+  /// an invocable identified by specified _invocable_id_.
+  pub fn required_inputs_by_id(&self, namespace: &str, invocable_id: &str) -> Option<Result<Vec<QualifiedName>>> {
+    self.missing_inputs_by_id(namespace, invocable_id, &FeelContext::default())
+  }
+
+  /// Returns input data and context paths missing in _input_data_,
+  /// that still affect the result of an invocable identified by specified _invocable_name_.
+  /// Returns `None` when the invocable was not found, and an error when the inputs could not be discovered.
+  pub fn missing_inputs_by_name(&self, namespace: &str, invocable_name: &str, input_data: &FeelContext) -> Option<Result<Vec<QualifiedName>>> {
+    let invocable_type = self.invocables.by_name(namespace, invocable_name)?;
+    Some(self.input_discovery.missing_inputs(invocable_type, input_data, self))
+  }
+
+  /// Returns input data and context paths missing in _input_data_,
+  /// that still affect the result of an invocable identified by specified _invocable_id_.
+  /// Returns `None` when the invocable was not found, and an error when the inputs could not be discovered.
+  pub fn missing_inputs_by_id(&self, namespace: &str, invocable_id: &str, input_data: &FeelContext) -> Option<Result<Vec<QualifiedName>>> {
+    let invocable_type = self.invocables.by_id(namespace, invocable_id)?;
+    Some(self.input_discovery.missing_inputs(invocable_type, input_data, self))
+  }
+
   /// Evaluates an invocable.
   fn evaluate_invocable(&self, invocable_type: &InvocableType, input_data: &FeelContext) -> Value {
     match invocable_type {
@@ -0,0 +1,130 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::*;
+use crate::BundleEvaluator;
+use dmntk_examples::*;
+use dmntk_model::Definitions;
+
+const NAMESPACE: &str = "https://dmntk.io/3_0086";
+
+const IMPORTED_NAMESPACE: &str = "https://dmntk.io/3_0086/imported";
+
+const BROKEN_NAMESPACE: &str = "https://dmntk.io/broken";
+
+/// Model containing a decision with invalid FEEL expression.
+const BROKEN_MODEL: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<definitions namespace="https://dmntk.io/broken" name="Broken" id="_broken" xmlns="https://www.omg.org/spec/DMN/20191111/MODEL/">
+    <decision name="Broken Decision" id="_broken_decision">
+        <variable name="Broken Decision" typeRef="number"/>
+        <literalExpression>
+            <text>1 + + *</text>
+        </literalExpression>
+    </decision>
+</definitions>
+"#;
+
+/// Utility function that parses models from specified XML contents.
+fn definitions(model_content: &[&str]) -> Vec<Definitions> {
+  model_content.iter().map(|content| dmntk_model::parse(content).unwrap()).collect()
+}
+
+#[test]
+fn _0001() {
+  // every model is deployed together with imported models
+  let (bundle_evaluator, errors) = BundleEvaluator::new(&definitions(&[DMN_3_0086, DMN_3_0086_IMPORT]));
+  assert!(errors.is_empty());
+  assert_eq!(vec![NAMESPACE, IMPORTED_NAMESPACE], bundle_evaluator.namespaces());
+  let ctx = context(r#" { A Person: { age: 21, name: "John Doe"}} "#);
+  let actual = bundle_evaluator.evaluate_invocable_by_name(NAMESPACE, "A Decision Ctx with DT", &ctx);
+  assert_eq!(r#""Hello John Doe!""#, actual.to_string());
+}
+
+#[test]
+fn _0002() {
+  // invocables are listed once, in the namespace of the model they are defined in
+  let (bundle_evaluator, _) = BundleEvaluator::new(&definitions(&[DMN_3_0086, DMN_3_0086_IMPORT]));
+  let expected = vec![
+    (NAMESPACE.to_string(), "A Decision Ctx with DT".to_string()),
+    (IMPORTED_NAMESPACE.to_string(), "Say Hello".to_string()),
+  ];
+  assert_eq!(expected, bundle_evaluator.namespace_name());
+}
+
+#[test]
+fn _0003() {
+  // broken model does not affect deployment of other models
+  let (bundle_evaluator, errors) = BundleEvaluator::new(&definitions(&[BROKEN_MODEL, DMN_3_0086, DMN_3_0086_IMPORT]));
+  assert_eq!(1, errors.len());
+  assert_eq!(BROKEN_NAMESPACE, errors[0].0);
+  assert!(bundle_evaluator.model_evaluator(BROKEN_NAMESPACE).is_none());
+  let ctx = context(r#" { A Person: { age: 47, name: "John Doe"}} "#);
+  let actual = bundle_evaluator.evaluate_invocable_by_name(NAMESPACE, "A Decision Ctx with DT", &ctx);
+  assert_eq!(r#""Respectfully, Hello John Doe!""#, actual.to_string());
+}
+
+#[test]
+fn _0004() {
+  // failed replacement keeps previously deployed unit
+  let (mut bundle_evaluator, _) = BundleEvaluator::new(&definitions(&[DMN_3_0086, DMN_3_0086_IMPORT]));
+  assert!(bundle_evaluator.replace(NAMESPACE, &definitions(&[BROKEN_MODEL])).is_err());
+  let ctx = context(r#" { A Person: { age: 21, name: "John Doe"}} "#);
+  let actual = bundle_evaluator.evaluate_invocable_by_name(NAMESPACE, "A Decision Ctx with DT", &ctx);
+  assert_eq!(r#""Hello John Doe!""#, actual.to_string());
+}
+
+#[test]
+fn _0005() {
+  // removed unit can not be evaluated
+  let (mut bundle_evaluator, _) = BundleEvaluator::new(&definitions(&[DMN_3_0086, DMN_3_0086_IMPORT]));
+  assert!(bundle_evaluator.remove(NAMESPACE));
+  assert!(!bundle_evaluator.remove(NAMESPACE));
+  assert_eq!(vec![IMPORTED_NAMESPACE], bundle_evaluator.namespaces());
+  let ctx = context(r#" { A Person: { age: 21, name: "John Doe"}} "#);
+  let actual = bundle_evaluator.evaluate_invocable_by_name(NAMESPACE, "A Decision Ctx with DT", &ctx);
+  assert_eq!(
+    "null(invocable 'A Decision Ctx with DT' not found in namespace 'https://dmntk.io/3_0086')",
+    actual.to_string()
+  );
+}
+
+#[test]
+fn _0006() {
+  // redeploying imported model redeploys units of importing models
+  let (mut bundle_evaluator, _) = BundleEvaluator::new(&definitions(&[DMN_3_0086, DMN_3_0086_IMPORT]));
+  let modified_import = DMN_3_0086_IMPORT.replace(r#""Hello "#, r#""Hi "#);
+  let errors = bundle_evaluator.redeploy(IMPORTED_NAMESPACE, &definitions(&[DMN_3_0086, &modified_import]));
+  assert!(errors.is_empty());
+  let ctx = context(r#" { A Person: { age: 21, name: "John Doe"}} "#);
+  let actual = bundle_evaluator.evaluate_invocable_by_name(NAMESPACE, "A Decision Ctx with DT", &ctx);
+  assert_eq!(r#""Hi John Doe!""#, actual.to_string());
+}
@@ -48,6 +48,6 @@ fn _0002() {
     &MODEL_NAMESPACE,
     "Employment Status Statement",
     &ctx,
-    r#"null(expected string as a second argument in addition)"#,
+    r#"null(expected string as a second argument in addition (at 1:1..1:31))"#,
   );
 }
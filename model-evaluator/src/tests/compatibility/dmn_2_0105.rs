@@ -127,7 +127,13 @@ fn _0015() {
 #[test]
 fn _0016() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "Decision16", &ctx, r#"null([division] division by zero)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "Decision16",
+    &ctx,
+    r#"null([division] division by zero (at 1:1..1:10))"#,
+  );
 }
 
 #[test]
@@ -186,7 +192,7 @@ fn _0025() {
     &MODEL_NAMESPACE,
     "Decision25",
     &ctx,
-    r#"null([subtraction] incompatible types: 10 - null)"#,
+    r#"null([subtraction] incompatible types: 10 - null (at 1:1..1:10))"#,
   );
 }
 
@@ -198,7 +204,7 @@ fn _0026() {
     &MODEL_NAMESPACE,
     "Decision26",
     &ctx,
-    r#"null([subtraction] incompatible types: null - 10)"#,
+    r#"null([subtraction] incompatible types: null - 10 (at 1:1..1:10))"#,
   );
 }
 
@@ -210,7 +216,7 @@ fn _0027() {
     &MODEL_NAMESPACE,
     "Decision27",
     &ctx,
-    r#"null([multiplication] incompatible types: 10 * null)"#,
+    r#"null([multiplication] incompatible types: 10 * null (at 1:1..1:10))"#,
   );
 }
 
@@ -223,13 +229,25 @@ fn _0028() {
 #[test]
 fn _0029() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "Decision29", &ctx, r#"null([division] incompatible types: 10 / null)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "Decision29",
+    &ctx,
+    r#"null([division] incompatible types: 10 / null (at 1:1..1:10))"#,
+  );
 }
 
 #[test]
 fn _0030() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "Decision30", &ctx, r#"null([division] incompatible types: null / 10)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "Decision30",
+    &ctx,
+    r#"null([division] incompatible types: null / 10 (at 1:1..1:10))"#,
+  );
 }
 
 #[test]
@@ -54,6 +54,6 @@ fn _0003() {
     &MODEL_NAMESPACE,
     "DecisionNot",
     &ctx,
-    r#"null([core::not] invalid argument type, expected boolean, actual type is Null)"#,
+    r#"null([core::not] invalid argument type, expected boolean, actual type is Null (at 1:1..1:7))"#,
   );
 }
@@ -60,7 +60,7 @@ fn _0004() {
     &MODEL_NAMESPACE,
     "decision004",
     &ctx,
-    r#"null(expected 1 parameters, actual number of parameters is 0)"#,
+    r#"null(expected 1 parameters, actual number of parameters is 0 (at 1:1..1:6))"#,
   );
 }
 
@@ -72,7 +72,7 @@ fn _0005() {
     &MODEL_NAMESPACE,
     "decision005",
     &ctx,
-    r#"null(expected 1 parameters, actual number of parameters is 2)"#,
+    r#"null(expected 1 parameters, actual number of parameters is 2 (at 1:1..1:9))"#,
   );
 }
 
@@ -85,7 +85,7 @@ fn _0006() {
 #[test]
 fn _0007() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision007", &ctx, r#"null(parameter 'n' not found)"#);
+  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision007", &ctx, r#"null(parameter 'n' not found (at 1:1..1:15))"#);
 }
 
 #[test]
@@ -96,7 +96,7 @@ fn _0008() {
     &MODEL_NAMESPACE,
     "decision008",
     &ctx,
-    r#"null([core::abs] invalid argument type, expected number, actual type is Null)"#,
+    r#"null([core::abs] invalid argument type, expected number, actual type is Null (at 1:1..1:10))"#,
   );
 }
 
@@ -108,7 +108,7 @@ fn _0009() {
     &MODEL_NAMESPACE,
     "decision009",
     &ctx,
-    r#"null([core::abs] invalid argument type, expected number, actual type is string)"#,
+    r#"null([core::abs] invalid argument type, expected number, actual type is string (at 1:1..1:10))"#,
   );
 }
 
@@ -120,7 +120,7 @@ fn _0010() {
     &MODEL_NAMESPACE,
     "decision010",
     &ctx,
-    r#"null([core::abs] invalid argument type, expected number, actual type is boolean)"#,
+    r#"null([core::abs] invalid argument type, expected number, actual type is boolean (at 1:1..1:10))"#,
   );
 }
 
@@ -156,7 +156,7 @@ fn _0015() {
     &MODEL_NAMESPACE,
     "decision013",
     &ctx,
-    r#"null([core::abs] invalid argument type, expected number, actual type is date)"#,
+    r#"null([core::abs] invalid argument type, expected number, actual type is date (at 1:1..1:25))"#,
   );
 }
 
@@ -168,7 +168,7 @@ fn _0016() {
     &MODEL_NAMESPACE,
     "decision014",
     &ctx,
-    r#"null([core::abs] invalid argument type, expected number, actual type is time)"#,
+    r#"null([core::abs] invalid argument type, expected number, actual type is time (at 1:1..1:22))"#,
   );
 }
 
@@ -180,6 +180,6 @@ fn _0017() {
     &MODEL_NAMESPACE,
     "decision015",
     &ctx,
-    r#"null([core::abs] invalid argument type, expected number, actual type is date and time)"#,
+    r#"null([core::abs] invalid argument type, expected number, actual type is date and time (at 1:1..1:42))"#,
   );
 }
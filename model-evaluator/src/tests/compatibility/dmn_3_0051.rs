@@ -48,7 +48,7 @@ fn _0002() {
     &MODEL_NAMESPACE,
     "decision002",
     &ctx,
-    r#"null(sqrt: argument must be positive number or zero)"#,
+    r#"null(sqrt: argument must be positive number or zero (at 1:1..1:9))"#,
   );
 }
 
@@ -66,7 +66,7 @@ fn _0004() {
     &MODEL_NAMESPACE,
     "decision004",
     &ctx,
-    r#"null(expected 1 parameters, actual number of parameters is 0)"#,
+    r#"null(expected 1 parameters, actual number of parameters is 0 (at 1:1..1:7))"#,
   );
 }
 
@@ -78,7 +78,7 @@ fn _0005() {
     &MODEL_NAMESPACE,
     "decision005",
     &ctx,
-    r#"null(expected 1 parameters, actual number of parameters is 2)"#,
+    r#"null(expected 1 parameters, actual number of parameters is 2 (at 1:1..1:10))"#,
   );
 }
 
@@ -91,53 +91,107 @@ fn _0006() {
 #[test]
 fn _0007() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision007", &ctx, r#"null(parameter 'number' not found)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision007",
+    &ctx,
+    r#"null(parameter 'number' not found (at 1:1..1:10))"#,
+  );
 }
 
 #[test]
 fn _0008() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision008", &ctx, r#"null(sqrt: argument must be a number)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision008",
+    &ctx,
+    r#"null(sqrt: argument must be a number (at 1:1..1:11))"#,
+  );
 }
 
 #[test]
 fn _0009() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision009", &ctx, r#"null(sqrt: argument must be a number)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision009",
+    &ctx,
+    r#"null(sqrt: argument must be a number (at 1:1..1:10))"#,
+  );
 }
 
 #[test]
 fn _0010() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision010", &ctx, r#"null(sqrt: argument must be a number)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision010",
+    &ctx,
+    r#"null(sqrt: argument must be a number (at 1:1..1:11))"#,
+  );
 }
 
 #[test]
 fn _0011() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision011", &ctx, r#"null(sqrt: argument must be a number)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision011",
+    &ctx,
+    r#"null(sqrt: argument must be a number (at 1:1..1:22))"#,
+  );
 }
 
 #[test]
 fn _0012() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision012", &ctx, r#"null(sqrt: argument must be a number)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision012",
+    &ctx,
+    r#"null(sqrt: argument must be a number (at 1:1..1:22))"#,
+  );
 }
 
 #[test]
 fn _0013() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision013", &ctx, r#"null(sqrt: argument must be a number)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision013",
+    &ctx,
+    r#"null(sqrt: argument must be a number (at 1:1..1:25))"#,
+  );
 }
 
 #[test]
 fn _0014() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision014", &ctx, r#"null(sqrt: argument must be a number)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision014",
+    &ctx,
+    r#"null(sqrt: argument must be a number (at 1:1..1:23))"#,
+  );
 }
 
 #[test]
 fn _0015() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision015", &ctx, r#"null(sqrt: argument must be a number)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision015",
+    &ctx,
+    r#"null(sqrt: argument must be a number (at 1:1..1:43))"#,
+  );
 }
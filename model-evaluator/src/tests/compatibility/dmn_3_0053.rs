@@ -60,7 +60,7 @@ fn _0004() {
     &MODEL_NAMESPACE,
     "decision004",
     &ctx,
-    r#"null(expected 1 parameters, actual number of parameters is 0)"#,
+    r#"null(expected 1 parameters, actual number of parameters is 0 (at 1:1..1:6))"#,
   );
 }
 
@@ -72,7 +72,7 @@ fn _0005() {
     &MODEL_NAMESPACE,
     "decision005",
     &ctx,
-    r#"null(expected 1 parameters, actual number of parameters is 2)"#,
+    r#"null(expected 1 parameters, actual number of parameters is 2 (at 1:1..1:9))"#,
   );
 }
 
@@ -85,7 +85,13 @@ fn _0006() {
 #[test]
 fn _0007() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision007", &ctx, r#"null(parameter 'number' not found)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision007",
+    &ctx,
+    r#"null(parameter 'number' not found (at 1:1..1:9))"#,
+  );
 }
 
 #[test]
@@ -72,7 +72,7 @@ fn _0006() {
     &MODEL_NAMESPACE,
     "decision004",
     &ctx,
-    r#"null(expected 1 parameters, actual number of parameters is 0)"#,
+    r#"null(expected 1 parameters, actual number of parameters is 0 (at 1:1..1:6))"#,
   );
 }
 
@@ -84,7 +84,7 @@ fn _0007() {
     &MODEL_NAMESPACE,
     "decision005",
     &ctx,
-    r#"null(expected 1 parameters, actual number of parameters is 2)"#,
+    r#"null(expected 1 parameters, actual number of parameters is 2 (at 1:1..1:9))"#,
   );
 }
 
@@ -97,7 +97,13 @@ fn _0008() {
 #[test]
 fn _0009() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision007", &ctx, r#"null(parameter 'number' not found)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision007",
+    &ctx,
+    r#"null(parameter 'number' not found (at 1:1..1:9))"#,
+  );
 }
 
 #[test]
@@ -108,7 +114,7 @@ fn _0010() {
     &MODEL_NAMESPACE,
     "decision008",
     &ctx,
-    r#"null([core::odd] invalid argument type, expected number, actual type is Null)"#,
+    r#"null([core::odd] invalid argument type, expected number, actual type is Null (at 1:1..1:10))"#,
   );
 }
 
@@ -120,7 +126,7 @@ fn _0011() {
     &MODEL_NAMESPACE,
     "decision009",
     &ctx,
-    r#"null([core::odd] invalid argument type, expected number, actual type is string)"#,
+    r#"null([core::odd] invalid argument type, expected number, actual type is string (at 1:1..1:9))"#,
   );
 }
 
@@ -132,7 +138,7 @@ fn _0012() {
     &MODEL_NAMESPACE,
     "decision010",
     &ctx,
-    r#"null([core::odd] invalid argument type, expected number, actual type is boolean)"#,
+    r#"null([core::odd] invalid argument type, expected number, actual type is boolean (at 1:1..1:10))"#,
   );
 }
 
@@ -144,7 +150,7 @@ fn _0013() {
     &MODEL_NAMESPACE,
     "decision011",
     &ctx,
-    r#"null([core::odd] invalid argument type, expected number, actual type is days and time duration)"#,
+    r#"null([core::odd] invalid argument type, expected number, actual type is days and time duration (at 1:1..1:21))"#,
   );
 }
 
@@ -156,7 +162,7 @@ fn _0014() {
     &MODEL_NAMESPACE,
     "decision012",
     &ctx,
-    r#"null([core::odd] invalid argument type, expected number, actual type is years and months duration)"#,
+    r#"null([core::odd] invalid argument type, expected number, actual type is years and months duration (at 1:1..1:21))"#,
   );
 }
 
@@ -168,7 +174,7 @@ fn _0015() {
     &MODEL_NAMESPACE,
     "decision013",
     &ctx,
-    r#"null([core::odd] invalid argument type, expected number, actual type is date)"#,
+    r#"null([core::odd] invalid argument type, expected number, actual type is date (at 1:1..1:24))"#,
   );
 }
 
@@ -180,7 +186,7 @@ fn _0016() {
     &MODEL_NAMESPACE,
     "decision014",
     &ctx,
-    r#"null([core::odd] invalid argument type, expected number, actual type is time)"#,
+    r#"null([core::odd] invalid argument type, expected number, actual type is time (at 1:1..1:22))"#,
   );
 }
 
@@ -192,6 +198,6 @@ fn _0017() {
     &MODEL_NAMESPACE,
     "decision015",
     &ctx,
-    r#"null([core::odd] invalid argument type, expected number, actual type is date and time)"#,
+    r#"null([core::odd] invalid argument type, expected number, actual type is date and time (at 1:1..1:42))"#,
   );
 }
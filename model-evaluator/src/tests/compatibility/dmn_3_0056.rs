@@ -61,7 +61,13 @@ fn _0004() {
 #[test]
 fn _0005() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision003_a", &ctx, r#"null([core::modulo] division by zero)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision003_a",
+    &ctx,
+    r#"null([core::modulo] division by zero (at 1:1..1:14))"#,
+  );
 }
 
 #[test]
@@ -72,7 +78,7 @@ fn _0006() {
     &MODEL_NAMESPACE,
     "decision004",
     &ctx,
-    r#"null(expected 2 parameters, actual number of parameters is 0)"#,
+    r#"null(expected 2 parameters, actual number of parameters is 0 (at 1:1..1:9))"#,
   );
 }
 
@@ -84,7 +90,7 @@ fn _0007() {
     &MODEL_NAMESPACE,
     "decision005",
     &ctx,
-    r#"null(expected 2 parameters, actual number of parameters is 1)"#,
+    r#"null(expected 2 parameters, actual number of parameters is 1 (at 1:1..1:10))"#,
   );
 }
 
@@ -96,7 +102,7 @@ fn _0008() {
     &MODEL_NAMESPACE,
     "decision005_a",
     &ctx,
-    r#"null(expected 2 parameters, actual number of parameters is 3)"#,
+    r#"null(expected 2 parameters, actual number of parameters is 3 (at 1:1..1:14))"#,
   );
 }
 
@@ -109,7 +115,13 @@ fn _0009() {
 #[test]
 fn _0010() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision007", &ctx, r#"null(parameter 'divisor' not found)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision007",
+    &ctx,
+    r#"null(parameter 'divisor' not found (at 1:1..1:27))"#,
+  );
 }
 
 #[test]
@@ -120,7 +132,7 @@ fn _0011() {
     &MODEL_NAMESPACE,
     "decision008",
     &ctx,
-    r#"null([core::modulo] invalid argument type, expected number, actual type is Null)"#,
+    r#"null([core::modulo] invalid argument type, expected number, actual type is Null (at 1:1..1:19))"#,
   );
 }
 
@@ -132,7 +144,7 @@ fn _0012() {
     &MODEL_NAMESPACE,
     "decision008_a",
     &ctx,
-    r#"null([core::modulo] invalid argument type, expected number, actual type is Null)"#,
+    r#"null([core::modulo] invalid argument type, expected number, actual type is Null (at 1:1..1:17))"#,
   );
 }
 
@@ -144,7 +156,7 @@ fn _0013() {
     &MODEL_NAMESPACE,
     "decision008_b",
     &ctx,
-    r#"null([core::modulo] invalid argument type, expected number, actual type is Null)"#,
+    r#"null([core::modulo] invalid argument type, expected number, actual type is Null (at 1:1..1:16))"#,
   );
 }
 
@@ -156,7 +168,7 @@ fn _0014() {
     &MODEL_NAMESPACE,
     "decision009",
     &ctx,
-    r#"null([core::modulo] invalid argument type, expected number, actual type is string)"#,
+    r#"null([core::modulo] invalid argument type, expected number, actual type is string (at 1:1..1:18))"#,
   );
 }
 
@@ -168,7 +180,7 @@ fn _0015() {
     &MODEL_NAMESPACE,
     "decision010",
     &ctx,
-    r#"null([core::modulo] invalid argument type, expected number, actual type is boolean)"#,
+    r#"null([core::modulo] invalid argument type, expected number, actual type is boolean (at 1:1..1:19))"#,
   );
 }
 
@@ -180,7 +192,7 @@ fn _0016() {
     &MODEL_NAMESPACE,
     "decision011",
     &ctx,
-    r#"null([core::modulo] invalid argument type, expected number, actual type is days and time duration)"#,
+    r#"null([core::modulo] invalid argument type, expected number, actual type is days and time duration (at 1:1..1:28))"#,
   );
 }
 
@@ -192,7 +204,7 @@ fn _0017() {
     &MODEL_NAMESPACE,
     "decision012",
     &ctx,
-    r#"null([core::modulo] invalid argument type, expected number, actual type is years and months duration)"#,
+    r#"null([core::modulo] invalid argument type, expected number, actual type is years and months duration (at 1:1..1:28))"#,
   );
 }
 
@@ -204,7 +216,7 @@ fn _0018() {
     &MODEL_NAMESPACE,
     "decision013",
     &ctx,
-    r#"null([core::modulo] invalid argument type, expected number, actual type is date)"#,
+    r#"null([core::modulo] invalid argument type, expected number, actual type is date (at 1:1..1:30))"#,
   );
 }
 
@@ -216,7 +228,7 @@ fn _0019() {
     &MODEL_NAMESPACE,
     "decision014",
     &ctx,
-    r#"null([core::modulo] invalid argument type, expected number, actual type is time)"#,
+    r#"null([core::modulo] invalid argument type, expected number, actual type is time (at 1:1..1:28))"#,
   );
 }
 
@@ -228,7 +240,7 @@ fn _0020() {
     &MODEL_NAMESPACE,
     "decision015",
     &ctx,
-    r#"null([core::modulo] invalid argument type, expected number, actual type is date and time)"#,
+    r#"null([core::modulo] invalid argument type, expected number, actual type is date and time (at 1:1..1:48))"#,
   );
 }
 
@@ -85,5 +85,11 @@ fn _0008() {
 #[test]
 fn _0009() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision008", &ctx, r#"null(duplicated context entry key: foo)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision008",
+    &ctx,
+    r#"null(duplicated context entry key: foo (at 1:2..1:24))"#,
+  );
 }
@@ -60,7 +60,7 @@ fn _0004() {
     &MODEL_NAMESPACE,
     "decision003_a",
     &ctx,
-    r#"null([core::number] <FeelNumberError> invalid number literal '1,000,000.01')"#,
+    r#"null([core::number] <FeelNumberError> invalid number literal '1,000,000.01' (at 1:1..1:33))"#,
   );
 }
 
@@ -72,7 +72,7 @@ fn _0005() {
     &MODEL_NAMESPACE,
     "decision004",
     &ctx,
-    r#"null([core::number] grouping separator must be space, period, comma or null)"#,
+    r#"null([core::number] grouping separator must be space, period, comma or null (at 1:1..1:33))"#,
   );
 }
 
@@ -84,7 +84,7 @@ fn _0006() {
     &MODEL_NAMESPACE,
     "decision004_a",
     &ctx,
-    r#"null([core::number] grouping separator must be space, period, comma or null)"#,
+    r#"null([core::number] grouping separator must be space, period, comma or null (at 1:1..1:33))"#,
   );
 }
 
@@ -96,7 +96,7 @@ fn _0007() {
     &MODEL_NAMESPACE,
     "decision004_b",
     &ctx,
-    r#"null([core::number] decimal separator must be period, comma or null)"#,
+    r#"null([core::number] decimal separator must be period, comma or null (at 1:1..1:33))"#,
   );
 }
 
@@ -108,7 +108,7 @@ fn _0008() {
     &MODEL_NAMESPACE,
     "decision004_c",
     &ctx,
-    r#"null([core::number] decimal separator must be period, comma or null)"#,
+    r#"null([core::number] decimal separator must be period, comma or null (at 1:1..1:33))"#,
   );
 }
 
@@ -144,7 +144,7 @@ fn _0013() {
     &MODEL_NAMESPACE,
     "decision009",
     &ctx,
-    r#"null([core::number] decimal separator must be different from grouping separator)"#,
+    r#"null([core::number] decimal separator must be different from grouping separator (at 1:1..1:33))"#,
   );
 }
 
@@ -156,7 +156,7 @@ fn _0014() {
     &MODEL_NAMESPACE,
     "decision010",
     &ctx,
-    r#"null([core::number] decimal separator must be different from grouping separator)"#,
+    r#"null([core::number] decimal separator must be different from grouping separator (at 1:1..1:33))"#,
   );
 }
 
@@ -168,7 +168,7 @@ fn _0015() {
     &MODEL_NAMESPACE,
     "decision011",
     &ctx,
-    r#"null([core::number] invalid argument type, expected string, actual type is Null)"#,
+    r#"null([core::number] invalid argument type, expected string, actual type is Null (at 1:1..1:23))"#,
   );
 }
 
@@ -180,7 +180,7 @@ fn _0016() {
     &MODEL_NAMESPACE,
     "decision012",
     &ctx,
-    r#"null([core::number] invalid argument type, expected string, actual type is number)"#,
+    r#"null([core::number] invalid argument type, expected string, actual type is number (at 1:1..1:22))"#,
   );
 }
 
@@ -193,7 +193,13 @@ fn _0017() {
 #[test]
 fn _0018() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision014", &ctx, r#"null(parameter 'grouping separator' not found)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision014",
+    &ctx,
+    r#"null(parameter 'grouping separator' not found (at 1:1..1:64))"#,
+  );
 }
 
 #[test]
@@ -204,7 +210,7 @@ fn _0019() {
     &MODEL_NAMESPACE,
     "decision015",
     &ctx,
-    r#"null([core::number] <FeelNumberError> invalid number literal 'foo.bar001')"#,
+    r#"null([core::number] <FeelNumberError> invalid number literal 'foo.bar001' (at 1:1..1:32))"#,
   );
 }
 
@@ -216,7 +222,7 @@ fn _0020() {
     &MODEL_NAMESPACE,
     "decision016",
     &ctx,
-    r#"null(expected 3 parameters, actual number of parameters is 2)"#,
+    r#"null(expected 3 parameters, actual number of parameters is 2 (at 1:1..1:28))"#,
   );
 }
 
@@ -228,6 +234,6 @@ fn _0021() {
     &MODEL_NAMESPACE,
     "decision017",
     &ctx,
-    r#"null(expected 3 parameters, actual number of parameters is 4)"#,
+    r#"null(expected 3 parameters, actual number of parameters is 4 (at 1:1..1:38))"#,
   );
 }
@@ -108,7 +108,7 @@ fn _0012() {
     &MODEL_NAMESPACE,
     "decision014",
     &ctx,
-    r#"null(expected 1+ parameters, actual number of parameters is 0)"#,
+    r#"null(expected 1+ parameters, actual number of parameters is 0 (at 1:1..1:6))"#,
   );
 }
 
@@ -133,11 +133,23 @@ fn _0015() {
 #[test]
 fn _0016() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision018", &ctx, r#"null(parameter 'list' not found)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision018",
+    &ctx,
+    r#"null(parameter 'list' not found (at 1:1..1:15))"#,
+  );
 }
 
 #[test]
 fn _0017() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision019", &ctx, r#"null(parameter 'list' not found)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision019",
+    &ctx,
+    r#"null(parameter 'list' not found (at 1:1..1:14))"#,
+  );
 }
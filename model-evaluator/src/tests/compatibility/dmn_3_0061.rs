@@ -54,14 +54,14 @@ fn _0003() {
     &MODEL_NAMESPACE,
     "decision003",
     &ctx,
-    r#"null(expected 1+ parameters, actual number of parameters is 0)"#,
+    r#"null(expected 1+ parameters, actual number of parameters is 0 (at 1:1..1:9))"#,
   );
 }
 
 #[test]
 fn _0004() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision003_a", &ctx, r#"null(median)"#);
+  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision003_a", &ctx, r#"null(median (at 1:1..1:13))"#);
 }
 
 #[test]
@@ -72,14 +72,14 @@ fn _0005() {
     &MODEL_NAMESPACE,
     "decision004",
     &ctx,
-    r#"null(expected 1+ parameters, actual number of parameters is 0)"#,
+    r#"null(expected 1+ parameters, actual number of parameters is 0 (at 1:1..1:9))"#,
   );
 }
 
 #[test]
 fn _0006() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision005", &ctx, r#"null(median)"#);
+  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision005", &ctx, r#"null(median (at 1:1..1:22))"#);
 }
 
 #[test]
@@ -121,11 +121,23 @@ fn _0012() {
 #[test]
 fn _0013() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision012", &ctx, r#"null(parameter 'list' not found)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision012",
+    &ctx,
+    r#"null(parameter 'list' not found (at 1:1..1:18))"#,
+  );
 }
 
 #[test]
 fn _0014() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision013", &ctx, r#"null(parameter 'list' not found)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision013",
+    &ctx,
+    r#"null(parameter 'list' not found (at 1:1..1:23))"#,
+  );
 }
@@ -54,7 +54,7 @@ fn _0003() {
     &MODEL_NAMESPACE,
     "decision003",
     &ctx,
-    r#"null(expected 1+ parameters, actual number of parameters is 0)"#,
+    r#"null(expected 1+ parameters, actual number of parameters is 0 (at 1:1..1:7))"#,
   );
 }
 
@@ -66,7 +66,7 @@ fn _0004() {
     &MODEL_NAMESPACE,
     "decision003_a",
     &ctx,
-    r#"null([core::mode] invalid argument type, expected number, actual type is Null)"#,
+    r#"null([core::mode] invalid argument type, expected number, actual type is Null (at 1:1..1:11))"#,
   );
 }
 
@@ -78,7 +78,7 @@ fn _0005() {
     &MODEL_NAMESPACE,
     "decision004",
     &ctx,
-    r#"null(expected 1+ parameters, actual number of parameters is 0)"#,
+    r#"null(expected 1+ parameters, actual number of parameters is 0 (at 1:1..1:7))"#,
   );
 }
 
@@ -90,7 +90,7 @@ fn _0006() {
     &MODEL_NAMESPACE,
     "decision005",
     &ctx,
-    r#"null([core::mode] invalid argument type, expected number, actual type is string)"#,
+    r#"null([core::mode] invalid argument type, expected number, actual type is string (at 1:1..1:20))"#,
   );
 }
 
@@ -127,11 +127,23 @@ fn _0011() {
 #[test]
 fn _0012() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision012", &ctx, r#"null(parameter 'list' not found)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision012",
+    &ctx,
+    r#"null(parameter 'list' not found (at 1:1..1:16))"#,
+  );
 }
 
 #[test]
 fn _0013() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision013", &ctx, r#"null(parameter 'list' not found)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision013",
+    &ctx,
+    r#"null(parameter 'list' not found (at 1:1..1:21))"#,
+  );
 }
@@ -48,7 +48,7 @@ fn _0002() {
     &MODEL_NAMESPACE,
     "decision003",
     &ctx,
-    r#"null(expected 1+ parameters, actual number of parameters is 0)"#,
+    r#"null(expected 1+ parameters, actual number of parameters is 0 (at 1:1..1:9))"#,
   );
 }
 
@@ -60,7 +60,7 @@ fn _0003() {
     &MODEL_NAMESPACE,
     "decision003_a",
     &ctx,
-    r#"null([positional::stddev] invalid argument type, expected list, actual type is Null)"#,
+    r#"null([positional::stddev] invalid argument type, expected list, actual type is Null (at 1:1..1:13))"#,
   );
 }
 
@@ -72,7 +72,7 @@ fn _0004() {
     &MODEL_NAMESPACE,
     "decision004",
     &ctx,
-    r#"null(expected 1+ parameters, actual number of parameters is 0)"#,
+    r#"null(expected 1+ parameters, actual number of parameters is 0 (at 1:1..1:9))"#,
   );
 }
 
@@ -84,7 +84,7 @@ fn _0005() {
     &MODEL_NAMESPACE,
     "decision005",
     &ctx,
-    r#"null(stddev: expected number, actual type is string with value "foo")"#,
+    r#"null(stddev: expected number, actual type is string with value "foo" (at 1:1..1:22))"#,
   );
 }
 
@@ -96,7 +96,7 @@ fn _0006() {
     &MODEL_NAMESPACE,
     "decision007",
     &ctx,
-    r#"null(stddev: minimum two input arguments expected)"#,
+    r#"null(stddev: minimum two input arguments expected (at 1:1..1:11))"#,
   );
 }
 
@@ -108,7 +108,7 @@ fn _0007() {
     &MODEL_NAMESPACE,
     "decision008",
     &ctx,
-    r#"null([positional::stddev] invalid argument type, expected list, actual type is number)"#,
+    r#"null([positional::stddev] invalid argument type, expected list, actual type is number (at 1:1..1:10))"#,
   );
 }
 
@@ -127,11 +127,23 @@ fn _0009() {
 #[test]
 fn _0010() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision012", &ctx, r#"null(parameter 'list' not found)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision012",
+    &ctx,
+    r#"null(parameter 'list' not found (at 1:1..1:18))"#,
+  );
 }
 
 #[test]
 fn _0011() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision013", &ctx, r#"null(parameter 'list' not found)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision013",
+    &ctx,
+    r#"null(parameter 'list' not found (at 1:1..1:23))"#,
+  );
 }
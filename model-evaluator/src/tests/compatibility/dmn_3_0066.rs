@@ -54,7 +54,7 @@ fn _0003() {
     &MODEL_NAMESPACE,
     "decision003_a",
     &ctx,
-    r#"null([core::not] invalid argument type, expected boolean, actual type is Null)"#,
+    r#"null([core::not] invalid argument type, expected boolean, actual type is Null (at 1:1..1:10))"#,
   );
 }
 
@@ -66,7 +66,7 @@ fn _0004() {
     &MODEL_NAMESPACE,
     "decision003_b",
     &ctx,
-    r#"null([core::not] invalid argument type, expected boolean, actual type is number)"#,
+    r#"null([core::not] invalid argument type, expected boolean, actual type is number (at 1:1..1:7))"#,
   );
 }
 
@@ -78,7 +78,7 @@ fn _0005() {
     &MODEL_NAMESPACE,
     "decision003_c",
     &ctx,
-    r#"null([core::not] invalid argument type, expected boolean, actual type is number)"#,
+    r#"null([core::not] invalid argument type, expected boolean, actual type is number (at 1:1..1:7))"#,
   );
 }
 
@@ -90,6 +90,6 @@ fn _0006() {
     &MODEL_NAMESPACE,
     "decision003_d",
     &ctx,
-    r#"null([core::not] invalid argument type, expected boolean, actual type is string)"#,
+    r#"null([core::not] invalid argument type, expected boolean, actual type is string (at 1:1..1:12))"#,
   );
 }
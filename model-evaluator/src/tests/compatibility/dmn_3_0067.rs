@@ -54,7 +54,7 @@ fn _0003() {
     &MODEL_NAMESPACE,
     "decision004",
     &ctx,
-    r#"null(expected 2 parameters, actual number of parameters is 0)"#,
+    r#"null(expected 2 parameters, actual number of parameters is 0 (at 1:1..1:8))"#,
   );
 }
 
@@ -66,7 +66,7 @@ fn _0004() {
     &MODEL_NAMESPACE,
     "decision005",
     &ctx,
-    r#"null(expected 2 parameters, actual number of parameters is 1)"#,
+    r#"null(expected 2 parameters, actual number of parameters is 1 (at 1:1..1:13))"#,
   );
 }
 
@@ -79,23 +79,47 @@ fn _0005() {
 #[test]
 fn _0006() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision007", &ctx, r#"null(parameter 'string' not found)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision007",
+    &ctx,
+    r#"null(parameter 'string' not found (at 1:1..1:37))"#,
+  );
 }
 
 #[test]
 fn _0007() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision008", &ctx, r#"null(split: input must be a string)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision008",
+    &ctx,
+    r#"null(split: input must be a string (at 1:1..1:18))"#,
+  );
 }
 
 #[test]
 fn _0008() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision008_a", &ctx, r#"null(split: delimiter must be a string)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision008_a",
+    &ctx,
+    r#"null(split: delimiter must be a string (at 1:1..1:19))"#,
+  );
 }
 
 #[test]
 fn _0009() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision008_b", &ctx, r#"null(split: input must be a string)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision008_b",
+    &ctx,
+    r#"null(split: input must be a string (at 1:1..1:17))"#,
+  );
 }
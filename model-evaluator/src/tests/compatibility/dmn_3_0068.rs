@@ -91,13 +91,13 @@ fn _0009() {
 #[test]
 fn _0010() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "boolean_008", &ctx, r#"null(equal err 'false' =?= '0')"#);
+  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "boolean_008", &ctx, r#"null(equal err 'false' =?= '0' (at 1:1..1:10))"#);
 }
 
 #[test]
 fn _0011() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "boolean_009", &ctx, r#"null(equal err 'true' =?= '1')"#);
+  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "boolean_009", &ctx, r#"null(equal err 'true' =?= '1' (at 1:1..1:9))"#);
 }
 
 #[test]
@@ -139,7 +139,13 @@ fn _0017() {
 #[test]
 fn _0018() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "number_007", &ctx, r#"null(equal err '100' =?= '"100"')"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "number_007",
+    &ctx,
+    r#"null(equal err '100' =?= '"100"' (at 1:1..1:12))"#,
+  );
 }
 
 #[test]
@@ -169,7 +175,13 @@ fn _0022() {
 #[test]
 fn _0023() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "string_005", &ctx, r#"null(equal err '"foo"' =?= '100')"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "string_005",
+    &ctx,
+    r#"null(equal err '"foo"' =?= '100' (at 1:1..1:12))"#,
+  );
 }
 
 #[test]
@@ -265,7 +277,7 @@ fn _0038() {
 #[test]
 fn _0039() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "list_016", &ctx, r#"null(equal err '[]' =?= '0')"#);
+  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "list_016", &ctx, r#"null(equal err '[]' =?= '0' (at 1:1..1:7))"#);
 }
 
 #[test]
@@ -307,7 +319,7 @@ fn _0045() {
 #[test]
 fn _0046() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "context_007", &ctx, r#"null(equal err '{}' =?= '[]')"#);
+  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "context_007", &ctx, r#"null(equal err '{}' =?= '[]' (at 1:1..1:8))"#);
 }
 
 #[test]
@@ -331,7 +343,13 @@ fn _0049() {
 #[test]
 fn _0050() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "date_004", &ctx, r#"null(equal err '2018-12-07' =?= '100')"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "date_004",
+    &ctx,
+    r#"null(equal err '2018-12-07' =?= '100' (at 1:1..1:25))"#,
+  );
 }
 
 #[test]
@@ -468,7 +486,7 @@ fn _0072() {
     &MODEL_NAMESPACE,
     "datetime_011",
     &ctx,
-    r#"null(equal err '2018-12-08T00:00:00' =?= '100')"#,
+    r#"null(equal err '2018-12-08T00:00:00' =?= '100' (at 1:1..1:43))"#,
   );
 }
 
@@ -523,7 +541,13 @@ fn _0080() {
 #[test]
 fn _0081() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "dt_duration_007", &ctx, r#"null(equal err 'P0D' =?= '0')"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "dt_duration_007",
+    &ctx,
+    r#"null(equal err 'P0D' =?= '0' (at 1:1..1:20))"#,
+  );
 }
 
 #[test]
@@ -559,7 +583,13 @@ fn _0086() {
 #[test]
 fn _0087() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "ym_duration_006", &ctx, r#"null(equal err 'P1Y' =?= 'P365D')"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "ym_duration_006",
+    &ctx,
+    r#"null(equal err 'P1Y' =?= 'P365D' (at 1:1..1:36))"#,
+  );
 }
 
 #[test]
@@ -571,7 +601,13 @@ fn _0088() {
 #[test]
 fn _0089() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "ym_duration_008", &ctx, r#"null(equal err 'P0Y' =?= '0')"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "ym_duration_008",
+    &ctx,
+    r#"null(equal err 'P0Y' =?= '0' (at 1:1..1:20))"#,
+  );
 }
 
 #[test]
@@ -54,7 +54,7 @@ fn _0003() {
     &MODEL_NAMESPACE,
     "decision002",
     &ctx,
-    r#"null(index in filter is out of range [1..3], actual index is 0)"#,
+    r#"null(index in filter is out of range [1..3], actual index is 0 (at 1:1..1:11))"#,
   );
 }
 
@@ -66,7 +66,7 @@ fn _0004() {
     &MODEL_NAMESPACE,
     "decision003",
     &ctx,
-    r#"null(index in filter is out of range [1..3], actual index is 4)"#,
+    r#"null(index in filter is out of range [1..3], actual index is 4 (at 1:1..1:11))"#,
   );
 }
 
@@ -102,7 +102,7 @@ fn _0009() {
     &MODEL_NAMESPACE,
     "decision008",
     &ctx,
-    r#"null(index in filter is out of range [-3..-1], actual index is -4)"#,
+    r#"null(index in filter is out of range [-3..-1], actual index is -4 (at 1:1..1:12))"#,
   );
 }
 
@@ -186,7 +186,7 @@ fn _0022() {
     &MODEL_NAMESPACE,
     "decision021",
     &ctx,
-    r#"null(for singletons, only filter index with value 1 or -1 is accepted)"#,
+    r#"null(for singletons, only filter index with value 1 or -1 is accepted (at 1:1..1:8))"#,
   );
 }
 
@@ -198,7 +198,7 @@ fn _0023() {
     &MODEL_NAMESPACE,
     "decision022",
     &ctx,
-    r#"null(for singletons, only filter index with value 1 or -1 is accepted)"#,
+    r#"null(for singletons, only filter index with value 1 or -1 is accepted (at 1:1..1:7))"#,
   );
 }
 
@@ -210,7 +210,7 @@ fn _0024() {
     &MODEL_NAMESPACE,
     "decision023",
     &ctx,
-    r#"null(for singletons, only filter index with value 1 or -1 is accepted)"#,
+    r#"null(for singletons, only filter index with value 1 or -1 is accepted (at 1:1..1:9))"#,
   );
 }
 
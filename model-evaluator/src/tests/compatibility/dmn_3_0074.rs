@@ -138,7 +138,7 @@ fn _0017() {
     &MODEL_NAMESPACE,
     "dateTime_008_a",
     &ctx,
-    r#"null(could not retrieve time offset for date and time)"#,
+    r#"null(could not retrieve time offset for date and time (at 1:1..1:49))"#,
   );
 }
 
@@ -156,7 +156,7 @@ fn _0019() {
     &MODEL_NAMESPACE,
     "dateTime_009_a",
     &ctx,
-    r#"null(could not retrieve timezone for date and time)"#,
+    r#"null(could not retrieve timezone for date and time (at 1:1..1:46))"#,
   );
 }
 
@@ -187,13 +187,25 @@ fn _0023() {
 #[test]
 fn _0024() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "time_004_a", &ctx, r#"null(could not retrieve time offset for time)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "time_004_a",
+    &ctx,
+    r#"null(could not retrieve time offset for time (at 1:1..1:29))"#,
+  );
 }
 
 #[test]
 fn _0025() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "time_005_a", &ctx, r#"null(could not retrieve timezone for time)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "time_005_a",
+    &ctx,
+    r#"null(could not retrieve timezone for time (at 1:1..1:26))"#,
+  );
 }
 
 #[test]
@@ -228,7 +240,7 @@ fn _0030() {
     &MODEL_NAMESPACE,
     "ym_duration_003",
     &ctx,
-    r#"null(no such property in years and months duration: days)"#,
+    r#"null(no such property in years and months duration: days (at 1:1..1:21))"#,
   );
 }
 
@@ -240,7 +252,7 @@ fn _0031() {
     &MODEL_NAMESPACE,
     "ym_duration_004",
     &ctx,
-    r#"null(no such property in years and months duration: hours)"#,
+    r#"null(no such property in years and months duration: hours (at 1:1..1:22))"#,
   );
 }
 
@@ -252,7 +264,7 @@ fn _0032() {
     &MODEL_NAMESPACE,
     "ym_duration_005",
     &ctx,
-    r#"null(no such property in years and months duration: minutes)"#,
+    r#"null(no such property in years and months duration: minutes (at 1:1..1:24))"#,
   );
 }
 
@@ -264,7 +276,7 @@ fn _0033() {
     &MODEL_NAMESPACE,
     "ym_duration_006",
     &ctx,
-    r#"null(no such property in years and months duration: seconds)"#,
+    r#"null(no such property in years and months duration: seconds (at 1:1..1:24))"#,
   );
 }
 
@@ -276,7 +288,7 @@ fn _0034() {
     &MODEL_NAMESPACE,
     "dt_duration_001",
     &ctx,
-    r#"null(no such property in days and time duration: years)"#,
+    r#"null(no such property in days and time duration: years (at 1:1..1:22))"#,
   );
 }
 
@@ -288,7 +300,7 @@ fn _0035() {
     &MODEL_NAMESPACE,
     "dt_duration_002",
     &ctx,
-    r#"null(no such property in days and time duration: months)"#,
+    r#"null(no such property in days and time duration: months (at 1:1..1:23))"#,
   );
 }
 
@@ -391,7 +403,13 @@ fn _0051() {
 #[test]
 fn _0052() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "range_009", &ctx, r#"null(no such property in unary less: start)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "range_009",
+    &ctx,
+    r#"null(no such property in unary less: start (at 1:1..1:13))"#,
+  );
 }
 
 #[test]
@@ -402,7 +420,7 @@ fn _0053() {
     &MODEL_NAMESPACE,
     "range_010",
     &ctx,
-    r#"null(no such property in unary less or equal: start)"#,
+    r#"null(no such property in unary less or equal: start (at 1:1..1:14))"#,
   );
 }
 
@@ -433,7 +451,13 @@ fn _0057() {
 #[test]
 fn _0058() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "range_015", &ctx, r#"null(no such property in unary greater: end)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "range_015",
+    &ctx,
+    r#"null(no such property in unary greater: end (at 1:1..1:11))"#,
+  );
 }
 
 #[test]
@@ -444,7 +468,7 @@ fn _0059() {
     &MODEL_NAMESPACE,
     "range_016",
     &ctx,
-    r#"null(no such property in unary greater or equal: end)"#,
+    r#"null(no such property in unary greater or equal: end (at 1:1..1:12))"#,
   );
 }
 
@@ -49,59 +49,119 @@ fn _0002() {
 #[test]
 fn _0003() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision_002", &ctx, r#"null(exponentiation base is not a number)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision_002",
+    &ctx,
+    r#"null(exponentiation base is not a number (at 1:1..1:11))"#,
+  );
 }
 
 #[test]
 fn _0004() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision_003", &ctx, r#"null(exponentiation base is not a number)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision_003",
+    &ctx,
+    r#"null(exponentiation base is not a number (at 1:1..1:10))"#,
+  );
 }
 
 #[test]
 fn _0005() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision_004", &ctx, r#"null(exponentiation base is not a number)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision_004",
+    &ctx,
+    r#"null(exponentiation base is not a number (at 1:1..1:24))"#,
+  );
 }
 
 #[test]
 fn _0006() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision_005", &ctx, r#"null(exponentiation base is not a number)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision_005",
+    &ctx,
+    r#"null(exponentiation base is not a number (at 1:1..1:22))"#,
+  );
 }
 
 #[test]
 fn _0007() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision_006", &ctx, r#"null(exponentiation base is not a number)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision_006",
+    &ctx,
+    r#"null(exponentiation base is not a number (at 1:1..1:33))"#,
+  );
 }
 
 #[test]
 fn _0008() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision_007", &ctx, r#"null(exponentiation base is not a number)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision_007",
+    &ctx,
+    r#"null(exponentiation base is not a number (at 1:1..1:21))"#,
+  );
 }
 
 #[test]
 fn _0009() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision_008", &ctx, r#"null(exponentiation base is not a number)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision_008",
+    &ctx,
+    r#"null(exponentiation base is not a number (at 1:1..1:21))"#,
+  );
 }
 
 #[test]
 fn _0010() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision_009", &ctx, r#"null(exponentiation base is not a number)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision_009",
+    &ctx,
+    r#"null(exponentiation base is not a number (at 1:1..1:12))"#,
+  );
 }
 
 #[test]
 fn _0011() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision_010", &ctx, r#"null(exponentiation base is not a number)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision_010",
+    &ctx,
+    r#"null(exponentiation base is not a number (at 1:1..1:9))"#,
+  );
 }
 
 #[test]
 fn _0012() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision_011", &ctx, r#"null(exponentiation base is not a number)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision_011",
+    &ctx,
+    r#"null(exponentiation base is not a number (at 1:1..1:24))"#,
+  );
 }
@@ -37,5 +37,11 @@ from_examples!(DMN_3_0077);
 #[test]
 fn _0001() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision_001", &ctx, r#"null([division] division by zero)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision_001",
+    &ctx,
+    r#"null([division] division by zero (at 1:1..1:10))"#,
+  );
 }
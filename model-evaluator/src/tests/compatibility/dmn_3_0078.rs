@@ -37,11 +37,23 @@ from_examples!(DMN_3_0078);
 #[test]
 fn _0001() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision_001", &ctx, r#"null([division] division by zero)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision_001",
+    &ctx,
+    r#"null([division] division by zero (at 1:1..1:10))"#,
+  );
 }
 
 #[test]
 fn _0002() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision_002", &ctx, r#"null([division] division by zero)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision_002",
+    &ctx,
+    r#"null([division] division by zero (at 1:1..1:11))"#,
+  );
 }
@@ -42,7 +42,7 @@ fn _0001() {
     &MODEL_NAMESPACE,
     "decision_001",
     &ctx,
-    r#"null(expected 2 parameters, actual number of parameters is 0)"#,
+    r#"null(expected 2 parameters, actual number of parameters is 0 (at 1:1..1:12))"#,
   );
 }
 
@@ -54,7 +54,7 @@ fn _0002() {
     &MODEL_NAMESPACE,
     "decision_002",
     &ctx,
-    r#"null(expected 2 parameters, actual number of parameters is 1)"#,
+    r#"null(expected 2 parameters, actual number of parameters is 1 (at 1:1..1:22))"#,
   );
 }
 
@@ -66,7 +66,7 @@ fn _0003() {
     &MODEL_NAMESPACE,
     "decision_003",
     &ctx,
-    r#"null(expected 2 parameters, actual number of parameters is 3)"#,
+    r#"null(expected 2 parameters, actual number of parameters is 3 (at 1:1..1:34))"#,
   );
 }
 
@@ -84,7 +84,7 @@ fn _0005() {
     &MODEL_NAMESPACE,
     "decision_005",
     &ctx,
-    r#"null([core::get value] invalid argument type, expected context, actual type is string)"#,
+    r#"null([core::get value] invalid argument type, expected context, actual type is string (at 1:1..1:24))"#,
   );
 }
 
@@ -96,7 +96,7 @@ fn _0006() {
     &MODEL_NAMESPACE,
     "decision_006",
     &ctx,
-    r#"null([core::get value] invalid argument type, expected string, actual type is number)"#,
+    r#"null([core::get value] invalid argument type, expected string, actual type is number (at 1:1..1:27))"#,
   );
 }
 
@@ -109,7 +109,13 @@ fn _0007() {
 #[test]
 fn _0008() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision_008", &ctx, r#"null(parameter 'key' not found)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision_008",
+    &ctx,
+    r#"null(parameter 'key' not found (at 1:1..1:31))"#,
+  );
 }
 
 #[test]
@@ -120,7 +126,7 @@ fn _0009() {
     &MODEL_NAMESPACE,
     "decision_009",
     &ctx,
-    r#"null([core::get value] invalid argument type, expected context, actual type is Null)"#,
+    r#"null([core::get value] invalid argument type, expected context, actual type is Null (at 1:1..1:21))"#,
   );
 }
 
@@ -132,7 +138,7 @@ fn _0010() {
     &MODEL_NAMESPACE,
     "decision_010",
     &ctx,
-    r#"null([core::get value] invalid argument type, expected string, actual type is Null)"#,
+    r#"null([core::get value] invalid argument type, expected string, actual type is Null (at 1:1..1:28))"#,
   );
 }
 
@@ -144,7 +150,7 @@ fn _0011() {
     &MODEL_NAMESPACE,
     "decision_011",
     &ctx,
-    r#"null([core::get value] invalid argument type, expected context, actual type is Null)"#,
+    r#"null([core::get value] invalid argument type, expected context, actual type is Null (at 1:1..1:22))"#,
   );
 }
 
@@ -42,7 +42,7 @@ fn _0001() {
     &MODEL_NAMESPACE,
     "decision_001",
     &ctx,
-    r#"null(expected 1 parameters, actual number of parameters is 0)"#,
+    r#"null(expected 1 parameters, actual number of parameters is 0 (at 1:1..1:14))"#,
   );
 }
 
@@ -54,7 +54,7 @@ fn _0002() {
     &MODEL_NAMESPACE,
     "decision_002",
     &ctx,
-    r#"null(expected 1 parameters, actual number of parameters is 2)"#,
+    r#"null(expected 1 parameters, actual number of parameters is 2 (at 1:1..1:36))"#,
   );
 }
 
@@ -66,7 +66,7 @@ fn _0003() {
     &MODEL_NAMESPACE,
     "decision_003",
     &ctx,
-    r#"null([core::get entries] invalid argument type, expected context, actual type is Null)"#,
+    r#"null([core::get entries] invalid argument type, expected context, actual type is Null (at 1:1..1:18))"#,
   );
 }
 
@@ -97,7 +97,7 @@ fn _0005() {
 #[test]
 fn _0006() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision_006", &ctx, r#"null(parameter 'm' not found)"#);
+  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision_006", &ctx, r#"null(parameter 'm' not found (at 1:1..1:38))"#);
 }
 
 #[test]
@@ -108,7 +108,7 @@ fn _0007() {
     &MODEL_NAMESPACE,
     "decision_007",
     &ctx,
-    r#"null([core::get entries] invalid argument type, expected context, actual type is number)"#,
+    r#"null([core::get entries] invalid argument type, expected context, actual type is number (at 1:1..1:17))"#,
   );
 }
 
@@ -120,7 +120,7 @@ fn _0008() {
     &MODEL_NAMESPACE,
     "decision_008",
     &ctx,
-    r#"null([core::get entries] invalid argument type, expected context, actual type is list<number>)"#,
+    r#"null([core::get entries] invalid argument type, expected context, actual type is list<number> (at 1:1..1:21))"#,
   );
 }
 
@@ -93,12 +93,12 @@ fn _0011() {
 
 #[test]
 fn _0012() {
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision_bkm_003", &CTX, r#"null(after coercion)"#);
+  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision_bkm_003", &CTX, r#"null(after coercion (at 1:1..1:23))"#);
 }
 
 #[test]
 fn _0013() {
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision_bkm_004_a", &CTX, r#"null(after coercion)"#);
+  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision_bkm_004_a", &CTX, r#"null(after coercion (at 1:1..1:15))"#);
 }
 
 #[test]
@@ -113,7 +113,7 @@ fn _0015() {
 
 #[test]
 fn _0016() {
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision_bkm_005_a", &CTX, r#"null(after coercion)"#);
+  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision_bkm_005_a", &CTX, r#"null(after coercion (at 1:1..1:15))"#);
 }
 
 #[test]
@@ -148,7 +148,7 @@ fn _0022() {
 
 #[test]
 fn _0023() {
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "fd_002", &CTX, r#"null(after coercion)"#);
+  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "fd_002", &CTX, r#"null(after coercion (at 1:23..1:26))"#);
 }
 
 #[test]
@@ -178,7 +178,13 @@ fn _0028() {
 
 #[test]
 fn _0029() {
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "ds_invoke_002_with_number", &CTX, r#"null(after coercion)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "ds_invoke_002_with_number",
+    &CTX,
+    r#"null(after coercion (at 1:1..1:28))"#,
+  );
 }
 
 #[test]
@@ -194,7 +200,7 @@ fn _0031() {
 #[test]
 fn _0032() {
   let ctx = context(r#"{decisionService_002_input_1: 10}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decisionService_002", &ctx, r#"null(after coercion)"#);
+  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decisionService_002", &ctx, r#"null(after coercion (at 1:1..1:28))"#);
 }
 
 #[test]
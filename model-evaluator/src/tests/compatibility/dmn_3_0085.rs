@@ -51,7 +51,7 @@ fn _0002_a() {
     &MODEL_NAMESPACE,
     "decisionService_002",
     r#"{}"#,
-    r#"null(expected string as a second argument in addition)"#,
+    r#"null(expected string as a second argument in addition (at 1:1..1:28))"#,
   );
 }
 
@@ -62,7 +62,7 @@ fn _0002_b() {
     &MODEL_NAMESPACE,
     "decisionService_002",
     r#"{decision_002_input: null}"#,
-    r#"null(expected string as a second argument in addition)"#,
+    r#"null(expected string as a second argument in addition (at 1:1..1:28))"#,
   );
 }
 
@@ -73,7 +73,7 @@ fn _0002_c() {
     &MODEL_NAMESPACE,
     "decisionService_002",
     r#"{decision_002_input: 1234}"#,
-    r#"null(expected string as a second argument in addition)"#,
+    r#"null(expected string as a second argument in addition (at 1:1..1:28))"#,
   );
 }
 
@@ -101,7 +101,13 @@ fn _0005() {
 
 #[test]
 fn _0006() {
-  assert_decision_service(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "decision_005_1", r#"{}"#, r#"null(invalid number of arguments)"#);
+  assert_decision_service(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "decision_005_1",
+    r#"{}"#,
+    r#"null(invalid number of arguments (at 1:1..1:27))"#,
+  );
 }
 
 #[test]
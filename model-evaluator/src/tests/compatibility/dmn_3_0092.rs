@@ -125,7 +125,7 @@ fn _0017() {
     &MODEL_NAMESPACE,
     "decision_016_1",
     &CTX,
-    r#"null(expected 1 parameters, actual number of parameters is 2)"#,
+    r#"null(expected 1 parameters, actual number of parameters is 2 (at 1:1..1:10))"#,
   );
 }
 
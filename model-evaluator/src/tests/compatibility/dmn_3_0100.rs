@@ -248,7 +248,7 @@ fn _0042() {
     &MODEL_NAMESPACE,
     "divide_lhs_number_by_rhs_number_002",
     &CTX,
-    r#"null([division] division by zero)"#,
+    r#"null([division] division by zero (at 1:1..1:8))"#,
   );
 }
 
@@ -264,7 +264,7 @@ fn _0044() {
     &MODEL_NAMESPACE,
     "divide_lhs_ymDuration_by_rhs_number_002",
     &CTX,
-    r#"null([division] division by zero)"#,
+    r#"null([division] division by zero (at 1:1..1:12))"#,
   );
 }
 
@@ -290,7 +290,7 @@ fn _0048() {
     &MODEL_NAMESPACE,
     "divide_lhs_ymDuration_by_rhs_ymDuration_002",
     &CTX,
-    r#"null([division] incompatible types: P10D / P0Y)"#,
+    r#"null([division] incompatible types: P10D / P0Y (at 1:1..1:17))"#,
   );
 }
 
@@ -306,7 +306,7 @@ fn _0050() {
     &MODEL_NAMESPACE,
     "divide_lhs_dtDuration_by_rhs_number_002",
     &CTX,
-    r#"null([division] division by zero)"#,
+    r#"null([division] division by zero (at 1:1..1:12))"#,
   );
 }
 
@@ -327,7 +327,7 @@ fn _0053() {
     &MODEL_NAMESPACE,
     "divide_lhs_dtDuration_by_rhs_dtDuration_002",
     &CTX,
-    r#"null([division] division by zero)"#,
+    r#"null([division] division by zero (at 1:1..1:17))"#,
   );
 }
 
@@ -1457,7 +1457,7 @@ fn _0177() {
     &MODEL_NAMESPACE,
     "subtract_lhs_dateAndTime_minus_rhs_dateAndTime_002",
     &CTX,
-    r#"null([subtraction] incompatible types: 2021-01-02T10:10:10@Europe/Paris - 2021-01-01T10:10:10)"#,
+    r#"null([subtraction] incompatible types: 2021-01-02T10:10:10@Europe/Paris - 2021-01-01T10:10:10 (at 1:1..1:61))"#,
   );
 }
 
@@ -1468,7 +1468,7 @@ fn _0178() {
     &MODEL_NAMESPACE,
     "subtract_lhs_dateAndTime_minus_rhs_dateAndTime_003",
     &CTX,
-    r#"null([subtraction] incompatible types: 2021-01-02T10:10:10 - 2021-01-01T10:10:10@Europe/Paris)"#,
+    r#"null([subtraction] incompatible types: 2021-01-02T10:10:10 - 2021-01-01T10:10:10@Europe/Paris (at 1:1..1:61))"#,
   );
 }
 
@@ -1479,7 +1479,7 @@ fn _0179() {
     &MODEL_NAMESPACE,
     "subtract_lhs_dateAndTime_minus_rhs_dateAndTime_004",
     &CTX,
-    r#"null([subtraction] incompatible types: 2021-01-02T10:10:10+02:00 - 2021-01-01T10:10:10)"#,
+    r#"null([subtraction] incompatible types: 2021-01-02T10:10:10+02:00 - 2021-01-01T10:10:10 (at 1:1..1:54))"#,
   );
 }
 
@@ -1490,7 +1490,7 @@ fn _0180() {
     &MODEL_NAMESPACE,
     "subtract_lhs_dateAndTime_minus_rhs_dateAndTime_005",
     &CTX,
-    r#"null([subtraction] incompatible types: 2021-01-02T10:10:10 - 2021-01-01T10:10:10+02:00)"#,
+    r#"null([subtraction] incompatible types: 2021-01-02T10:10:10 - 2021-01-01T10:10:10+02:00 (at 1:1..1:54))"#,
   );
 }
 
@@ -1516,7 +1516,7 @@ fn _0184() {
     &MODEL_NAMESPACE,
     "subtract_lhs_dateAndTime_minus_rhs_dateAndTime_009",
     &CTX,
-    r#"null([subtraction] incompatible types: 2021-01-02T10:10:10+01:00 - 2021-01-01T10:10:10)"#,
+    r#"null([subtraction] incompatible types: 2021-01-02T10:10:10+01:00 - 2021-01-01T10:10:10 (at 1:1..1:54))"#,
   );
 }
 
@@ -1724,7 +1724,7 @@ fn _0204() {
     &MODEL_NAMESPACE,
     "subtract_lhs_dateAndTime_minus_rhs_date_001",
     &CTX,
-    r#"null([subtraction] incompatible types: 2021-01-01T00:00:00 - 2021-01-02)"#,
+    r#"null([subtraction] incompatible types: 2021-01-01T00:00:00 - 2021-01-02 (at 1:1..1:39))"#,
   );
 }
 
@@ -1954,7 +1954,7 @@ fn _0226() {
     &MODEL_NAMESPACE,
     "subtract_lhs_date_minus_rhs_dateAndTime_001",
     &CTX,
-    r#"null([subtraction] incompatible types: 2021-01-02 - 2021-01-01T10:10:10)"#,
+    r#"null([subtraction] incompatible types: 2021-01-02 - 2021-01-01T10:10:10 (at 1:1..1:39))"#,
   );
 }
 
@@ -2253,7 +2253,7 @@ fn _0281() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_number_by_rhs_string",
     &CTX,
-    r#"null([multiplication] incompatible types: 10 * "10")"#,
+    r#"null([multiplication] incompatible types: 10 * "10" (at 1:1..1:10))"#,
   );
 }
 
@@ -2264,7 +2264,7 @@ fn _0282() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_number_by_rhs_boolean",
     &CTX,
-    r#"null([multiplication] incompatible types: 10 * true)"#,
+    r#"null([multiplication] incompatible types: 10 * true (at 1:1..1:10))"#,
   );
 }
 
@@ -2275,7 +2275,7 @@ fn _0283() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_number_by_rhs_date",
     &CTX,
-    r#"null([multiplication] incompatible types: 10 * 2021-01-01)"#,
+    r#"null([multiplication] incompatible types: 10 * 2021-01-01 (at 1:1..1:19))"#,
   );
 }
 
@@ -2286,7 +2286,7 @@ fn _0284() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_number_by_rhs_dateAndTime",
     &CTX,
-    r#"null([multiplication] incompatible types: 10 * 2021-01-01T10:10:10)"#,
+    r#"null([multiplication] incompatible types: 10 * 2021-01-01T10:10:10 (at 1:1..1:28))"#,
   );
 }
 
@@ -2297,7 +2297,7 @@ fn _0285() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_number_by_rhs_time",
     &CTX,
-    r#"null([multiplication] incompatible types: 10 * 10:10:10)"#,
+    r#"null([multiplication] incompatible types: 10 * 10:10:10 (at 1:1..1:17))"#,
   );
 }
 
@@ -2308,7 +2308,7 @@ fn _0286() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_number_by_rhs_list",
     &CTX,
-    r#"null([multiplication] incompatible types: 10 * [10])"#,
+    r#"null([multiplication] incompatible types: 10 * [10] (at 1:1..1:10))"#,
   );
 }
 
@@ -2319,7 +2319,7 @@ fn _0287() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_number_by_rhs_context",
     &CTX,
-    r#"null([multiplication] incompatible types: 10 * {a: 10})"#,
+    r#"null([multiplication] incompatible types: 10 * {a: 10} (at 1:1..1:13))"#,
   );
 }
 
@@ -2330,7 +2330,7 @@ fn _0288() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_number_by_rhs_range",
     &CTX,
-    r#"null([multiplication] incompatible types: 10 * [1..10])"#,
+    r#"null([multiplication] incompatible types: 10 * [1..10] (at 1:1..1:13))"#,
   );
 }
 
@@ -2341,7 +2341,7 @@ fn _0289() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_number_by_rhs_function",
     &CTX,
-    r#"null([multiplication] incompatible types: 10 * FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any))"#,
+    r#"null([multiplication] incompatible types: 10 * FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) (at 1:1..1:21))"#,
   );
 }
 
@@ -2352,7 +2352,7 @@ fn _0290() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_number_by_rhs_null",
     &CTX,
-    r#"null([multiplication] incompatible types: 10 * null)"#,
+    r#"null([multiplication] incompatible types: 10 * null (at 1:1..1:10))"#,
   );
 }
 
@@ -2363,7 +2363,7 @@ fn _0291() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_string_by_rhs_number",
     &CTX,
-    r#"null(unexpected value type in multiplication: string)"#,
+    r#"null(unexpected value type in multiplication: string (at 1:1..1:10))"#,
   );
 }
 
@@ -2374,7 +2374,7 @@ fn _0292() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_string_by_rhs_string",
     &CTX,
-    r#"null(unexpected value type in multiplication: string)"#,
+    r#"null(unexpected value type in multiplication: string (at 1:1..1:12))"#,
   );
 }
 
@@ -2385,7 +2385,7 @@ fn _0293() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_string_by_rhs_boolean",
     &CTX,
-    r#"null(unexpected value type in multiplication: string)"#,
+    r#"null(unexpected value type in multiplication: string (at 1:1..1:12))"#,
   );
 }
 
@@ -2396,7 +2396,7 @@ fn _0294() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_string_by_rhs_date",
     &CTX,
-    r#"null(unexpected value type in multiplication: string)"#,
+    r#"null(unexpected value type in multiplication: string (at 1:1..1:21))"#,
   );
 }
 
@@ -2407,7 +2407,7 @@ fn _0295() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_string_by_rhs_dateAndTime",
     &CTX,
-    r#"null(unexpected value type in multiplication: string)"#,
+    r#"null(unexpected value type in multiplication: string (at 1:1..1:30))"#,
   );
 }
 
@@ -2418,7 +2418,7 @@ fn _0296() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_string_by_rhs_time",
     &CTX,
-    r#"null(unexpected value type in multiplication: string)"#,
+    r#"null(unexpected value type in multiplication: string (at 1:1..1:19))"#,
   );
 }
 
@@ -2429,7 +2429,7 @@ fn _0297() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_string_by_rhs_list",
     &CTX,
-    r#"null(unexpected value type in multiplication: string)"#,
+    r#"null(unexpected value type in multiplication: string (at 1:1..1:12))"#,
   );
 }
 
@@ -2440,7 +2440,7 @@ fn _0298() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_string_by_rhs_context",
     &CTX,
-    r#"null(unexpected value type in multiplication: string)"#,
+    r#"null(unexpected value type in multiplication: string (at 1:1..1:15))"#,
   );
 }
 
@@ -2451,7 +2451,7 @@ fn _0299() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_string_by_rhs_dtDuration",
     &CTX,
-    r#"null(unexpected value type in multiplication: string)"#,
+    r#"null(unexpected value type in multiplication: string (at 1:1..1:14))"#,
   );
 }
 
@@ -2462,7 +2462,7 @@ fn _0300() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_string_by_rhs_ymDuration",
     &CTX,
-    r#"null(unexpected value type in multiplication: string)"#,
+    r#"null(unexpected value type in multiplication: string (at 1:1..1:14))"#,
   );
 }
 
@@ -2473,7 +2473,7 @@ fn _0301() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_string_by_rhs_range",
     &CTX,
-    r#"null(unexpected value type in multiplication: string)"#,
+    r#"null(unexpected value type in multiplication: string (at 1:1..1:15))"#,
   );
 }
 
@@ -2484,7 +2484,7 @@ fn _0302() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_string_by_rhs_function",
     &CTX,
-    r#"null(unexpected value type in multiplication: string)"#,
+    r#"null(unexpected value type in multiplication: string (at 1:1..1:23))"#,
   );
 }
 
@@ -2495,7 +2495,7 @@ fn _0303() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_string_by_rhs_null",
     &CTX,
-    r#"null(unexpected value type in multiplication: string)"#,
+    r#"null(unexpected value type in multiplication: string (at 1:1..1:12))"#,
   );
 }
 
@@ -2506,7 +2506,7 @@ fn _0304() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_boolean_by_rhs_number",
     &CTX,
-    r#"null(unexpected value type in multiplication: boolean)"#,
+    r#"null(unexpected value type in multiplication: boolean (at 1:1..1:10))"#,
   );
 }
 
@@ -2517,7 +2517,7 @@ fn _0305() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_boolean_by_rhs_string",
     &CTX,
-    r#"null(unexpected value type in multiplication: boolean)"#,
+    r#"null(unexpected value type in multiplication: boolean (at 1:1..1:12))"#,
   );
 }
 
@@ -2528,7 +2528,7 @@ fn _0306() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_boolean_by_rhs_boolean",
     &CTX,
-    r#"null(unexpected value type in multiplication: boolean)"#,
+    r#"null(unexpected value type in multiplication: boolean (at 1:1..1:12))"#,
   );
 }
 
@@ -2539,7 +2539,7 @@ fn _0307() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_boolean_by_rhs_date",
     &CTX,
-    r#"null(unexpected value type in multiplication: boolean)"#,
+    r#"null(unexpected value type in multiplication: boolean (at 1:1..1:21))"#,
   );
 }
 
@@ -2550,7 +2550,7 @@ fn _0308() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_boolean_by_rhs_dateAndTime",
     &CTX,
-    r#"null(unexpected value type in multiplication: boolean)"#,
+    r#"null(unexpected value type in multiplication: boolean (at 1:1..1:30))"#,
   );
 }
 
@@ -2561,7 +2561,7 @@ fn _0309() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_boolean_by_rhs_time",
     &CTX,
-    r#"null(unexpected value type in multiplication: boolean)"#,
+    r#"null(unexpected value type in multiplication: boolean (at 1:1..1:19))"#,
   );
 }
 
@@ -2572,7 +2572,7 @@ fn _0310() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_boolean_by_rhs_list",
     &CTX,
-    r#"null(unexpected value type in multiplication: boolean)"#,
+    r#"null(unexpected value type in multiplication: boolean (at 1:1..1:12))"#,
   );
 }
 
@@ -2583,7 +2583,7 @@ fn _0311() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_boolean_by_rhs_context",
     &CTX,
-    r#"null(unexpected value type in multiplication: boolean)"#,
+    r#"null(unexpected value type in multiplication: boolean (at 1:1..1:15))"#,
   );
 }
 
@@ -2594,7 +2594,7 @@ fn _0312() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_boolean_by_rhs_dtDuration",
     &CTX,
-    r#"null(unexpected value type in multiplication: boolean)"#,
+    r#"null(unexpected value type in multiplication: boolean (at 1:1..1:14))"#,
   );
 }
 
@@ -2605,7 +2605,7 @@ fn _0313() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_boolean_by_rhs_ymDuration",
     &CTX,
-    r#"null(unexpected value type in multiplication: boolean)"#,
+    r#"null(unexpected value type in multiplication: boolean (at 1:1..1:14))"#,
   );
 }
 
@@ -2616,7 +2616,7 @@ fn _0314() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_boolean_by_rhs_range",
     &CTX,
-    r#"null(unexpected value type in multiplication: boolean)"#,
+    r#"null(unexpected value type in multiplication: boolean (at 1:1..1:15))"#,
   );
 }
 
@@ -2627,7 +2627,7 @@ fn _0315() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_boolean_by_rhs_function",
     &CTX,
-    r#"null(unexpected value type in multiplication: boolean)"#,
+    r#"null(unexpected value type in multiplication: boolean (at 1:1..1:23))"#,
   );
 }
 
@@ -2638,7 +2638,7 @@ fn _0316() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_boolean_by_rhs_null",
     &CTX,
-    r#"null(unexpected value type in multiplication: boolean)"#,
+    r#"null(unexpected value type in multiplication: boolean (at 1:1..1:12))"#,
   );
 }
 
@@ -2649,7 +2649,7 @@ fn _0317() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_date_by_rhs_number",
     &CTX,
-    r#"null(unexpected value type in multiplication: date)"#,
+    r#"null(unexpected value type in multiplication: date (at 1:1..1:19))"#,
   );
 }
 
@@ -2660,7 +2660,7 @@ fn _0318() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_date_by_rhs_string",
     &CTX,
-    r#"null(unexpected value type in multiplication: date)"#,
+    r#"null(unexpected value type in multiplication: date (at 1:1..1:21))"#,
   );
 }
 
@@ -2671,7 +2671,7 @@ fn _0319() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_date_by_rhs_boolean",
     &CTX,
-    r#"null(unexpected value type in multiplication: date)"#,
+    r#"null(unexpected value type in multiplication: date (at 1:1..1:21))"#,
   );
 }
 
@@ -2682,7 +2682,7 @@ fn _0320() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_date_by_rhs_date",
     &CTX,
-    r#"null(unexpected value type in multiplication: date)"#,
+    r#"null(unexpected value type in multiplication: date (at 1:1..1:30))"#,
   );
 }
 
@@ -2693,7 +2693,7 @@ fn _0321() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_date_by_rhs_dateAndTime",
     &CTX,
-    r#"null(unexpected value type in multiplication: date)"#,
+    r#"null(unexpected value type in multiplication: date (at 1:1..1:39))"#,
   );
 }
 
@@ -2704,7 +2704,7 @@ fn _0322() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_date_by_rhs_time",
     &CTX,
-    r#"null(unexpected value type in multiplication: date)"#,
+    r#"null(unexpected value type in multiplication: date (at 1:1..1:28))"#,
   );
 }
 
@@ -2715,7 +2715,7 @@ fn _0323() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_date_by_rhs_list",
     &CTX,
-    r#"null(unexpected value type in multiplication: date)"#,
+    r#"null(unexpected value type in multiplication: date (at 1:1..1:21))"#,
   );
 }
 
@@ -2726,7 +2726,7 @@ fn _0324() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_date_by_rhs_context",
     &CTX,
-    r#"null(unexpected value type in multiplication: date)"#,
+    r#"null(unexpected value type in multiplication: date (at 1:1..1:24))"#,
   );
 }
 
@@ -2737,7 +2737,7 @@ fn _0325() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_date_by_rhs_dtDuration",
     &CTX,
-    r#"null(unexpected value type in multiplication: date)"#,
+    r#"null(unexpected value type in multiplication: date (at 1:1..1:23))"#,
   );
 }
 
@@ -2748,7 +2748,7 @@ fn _0326() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_date_by_rhs_ymDuration",
     &CTX,
-    r#"null(unexpected value type in multiplication: date)"#,
+    r#"null(unexpected value type in multiplication: date (at 1:1..1:23))"#,
   );
 }
 
@@ -2759,7 +2759,7 @@ fn _0327() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_date_by_rhs_range",
     &CTX,
-    r#"null(unexpected value type in multiplication: date)"#,
+    r#"null(unexpected value type in multiplication: date (at 1:1..1:24))"#,
   );
 }
 
@@ -2770,7 +2770,7 @@ fn _0328() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_date_by_rhs_function",
     &CTX,
-    r#"null(unexpected value type in multiplication: date)"#,
+    r#"null(unexpected value type in multiplication: date (at 1:1..1:32))"#,
   );
 }
 
@@ -2781,7 +2781,7 @@ fn _0329() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_date_by_rhs_null",
     &CTX,
-    r#"null(unexpected value type in multiplication: date)"#,
+    r#"null(unexpected value type in multiplication: date (at 1:1..1:21))"#,
   );
 }
 
@@ -2792,7 +2792,7 @@ fn _0330() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_dateAndTime_by_rhs_number",
     &CTX,
-    r#"null(unexpected value type in multiplication: date and time)"#,
+    r#"null(unexpected value type in multiplication: date and time (at 1:1..1:28))"#,
   );
 }
 
@@ -2803,7 +2803,7 @@ fn _0331() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_dateAndTime_by_rhs_string",
     &CTX,
-    r#"null(unexpected value type in multiplication: date and time)"#,
+    r#"null(unexpected value type in multiplication: date and time (at 1:1..1:30))"#,
   );
 }
 
@@ -2814,7 +2814,7 @@ fn _0332() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_dateAndTime_by_rhs_boolean",
     &CTX,
-    r#"null(unexpected value type in multiplication: date and time)"#,
+    r#"null(unexpected value type in multiplication: date and time (at 1:1..1:30))"#,
   );
 }
 
@@ -2825,7 +2825,7 @@ fn _0333() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_dateAndTime_by_rhs_date",
     &CTX,
-    r#"null(unexpected value type in multiplication: date and time)"#,
+    r#"null(unexpected value type in multiplication: date and time (at 1:1..1:39))"#,
   );
 }
 
@@ -2836,7 +2836,7 @@ fn _0334() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_dateAndTime_by_rhs_dateAndTime",
     &CTX,
-    r#"null(unexpected value type in multiplication: date and time)"#,
+    r#"null(unexpected value type in multiplication: date and time (at 1:1..1:48))"#,
   );
 }
 
@@ -2847,7 +2847,7 @@ fn _0335() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_dateAndTime_by_rhs_time",
     &CTX,
-    r#"null(unexpected value type in multiplication: date and time)"#,
+    r#"null(unexpected value type in multiplication: date and time (at 1:1..1:37))"#,
   );
 }
 
@@ -2858,7 +2858,7 @@ fn _0336() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_dateAndTime_by_rhs_list",
     &CTX,
-    r#"null(unexpected value type in multiplication: date and time)"#,
+    r#"null(unexpected value type in multiplication: date and time (at 1:1..1:30))"#,
   );
 }
 
@@ -2869,7 +2869,7 @@ fn _0337() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_dateAndTime_by_rhs_context",
     &CTX,
-    r#"null(unexpected value type in multiplication: date and time)"#,
+    r#"null(unexpected value type in multiplication: date and time (at 1:1..1:33))"#,
   );
 }
 
@@ -2880,7 +2880,7 @@ fn _0338() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_dateAndTime_by_rhs_dtDuration",
     &CTX,
-    r#"null(unexpected value type in multiplication: date and time)"#,
+    r#"null(unexpected value type in multiplication: date and time (at 1:1..1:32))"#,
   );
 }
 
@@ -2891,7 +2891,7 @@ fn _0339() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_dateAndTime_by_rhs_ymDuration",
     &CTX,
-    r#"null(unexpected value type in multiplication: date and time)"#,
+    r#"null(unexpected value type in multiplication: date and time (at 1:1..1:32))"#,
   );
 }
 
@@ -2902,7 +2902,7 @@ fn _0340() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_dateAndTime_by_rhs_range",
     &CTX,
-    r#"null(unexpected value type in multiplication: date and time)"#,
+    r#"null(unexpected value type in multiplication: date and time (at 1:1..1:33))"#,
   );
 }
 
@@ -2913,7 +2913,7 @@ fn _0341() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_dateAndTime_by_rhs_function",
     &CTX,
-    r#"null(unexpected value type in multiplication: date and time)"#,
+    r#"null(unexpected value type in multiplication: date and time (at 1:1..1:41))"#,
   );
 }
 
@@ -2924,7 +2924,7 @@ fn _0342() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_dateAndTime_by_rhs_null",
     &CTX,
-    r#"null(unexpected value type in multiplication: date and time)"#,
+    r#"null(unexpected value type in multiplication: date and time (at 1:1..1:30))"#,
   );
 }
 
@@ -2935,7 +2935,7 @@ fn _0343() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_time_by_rhs_number",
     &CTX,
-    r#"null(unexpected value type in multiplication: time)"#,
+    r#"null(unexpected value type in multiplication: time (at 1:1..1:17))"#,
   );
 }
 
@@ -2946,7 +2946,7 @@ fn _0344() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_time_by_rhs_string",
     &CTX,
-    r#"null(unexpected value type in multiplication: time)"#,
+    r#"null(unexpected value type in multiplication: time (at 1:1..1:19))"#,
   );
 }
 
@@ -2957,7 +2957,7 @@ fn _0345() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_time_by_rhs_boolean",
     &CTX,
-    r#"null(unexpected value type in multiplication: time)"#,
+    r#"null(unexpected value type in multiplication: time (at 1:1..1:19))"#,
   );
 }
 
@@ -2968,7 +2968,7 @@ fn _0346() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_time_by_rhs_date",
     &CTX,
-    r#"null(unexpected value type in multiplication: time)"#,
+    r#"null(unexpected value type in multiplication: time (at 1:1..1:28))"#,
   );
 }
 
@@ -2979,7 +2979,7 @@ fn _0347() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_time_by_rhs_dateAndTime",
     &CTX,
-    r#"null(unexpected value type in multiplication: time)"#,
+    r#"null(unexpected value type in multiplication: time (at 1:1..1:37))"#,
   );
 }
 
@@ -2990,7 +2990,7 @@ fn _0348() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_time_by_rhs_time",
     &CTX,
-    r#"null(unexpected value type in multiplication: time)"#,
+    r#"null(unexpected value type in multiplication: time (at 1:1..1:26))"#,
   );
 }
 
@@ -3001,7 +3001,7 @@ fn _0349() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_time_by_rhs_list",
     &CTX,
-    r#"null(unexpected value type in multiplication: time)"#,
+    r#"null(unexpected value type in multiplication: time (at 1:1..1:19))"#,
   );
 }
 
@@ -3012,7 +3012,7 @@ fn _0350() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_time_by_rhs_context",
     &CTX,
-    r#"null(unexpected value type in multiplication: time)"#,
+    r#"null(unexpected value type in multiplication: time (at 1:1..1:22))"#,
   );
 }
 
@@ -3023,7 +3023,7 @@ fn _0351() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_time_by_rhs_dtDuration",
     &CTX,
-    r#"null(unexpected value type in multiplication: time)"#,
+    r#"null(unexpected value type in multiplication: time (at 1:1..1:21))"#,
   );
 }
 
@@ -3034,7 +3034,7 @@ fn _0352() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_time_by_rhs_ymDuration",
     &CTX,
-    r#"null(unexpected value type in multiplication: time)"#,
+    r#"null(unexpected value type in multiplication: time (at 1:1..1:21))"#,
   );
 }
 
@@ -3045,7 +3045,7 @@ fn _0353() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_time_by_rhs_range",
     &CTX,
-    r#"null(unexpected value type in multiplication: time)"#,
+    r#"null(unexpected value type in multiplication: time (at 1:1..1:22))"#,
   );
 }
 
@@ -3056,7 +3056,7 @@ fn _0354() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_time_by_rhs_function",
     &CTX,
-    r#"null(unexpected value type in multiplication: time)"#,
+    r#"null(unexpected value type in multiplication: time (at 1:1..1:30))"#,
   );
 }
 
@@ -3067,7 +3067,7 @@ fn _0355() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_time_by_rhs_null",
     &CTX,
-    r#"null(unexpected value type in multiplication: time)"#,
+    r#"null(unexpected value type in multiplication: time (at 1:1..1:19))"#,
   );
 }
 
@@ -3078,7 +3078,7 @@ fn _0356() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_list_by_rhs_number",
     &CTX,
-    r#"null(unexpected value type in multiplication: list<number>)"#,
+    r#"null(unexpected value type in multiplication: list<number> (at 1:1..1:10))"#,
   );
 }
 
@@ -3089,7 +3089,7 @@ fn _0357() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_list_by_rhs_string",
     &CTX,
-    r#"null(unexpected value type in multiplication: list<number>)"#,
+    r#"null(unexpected value type in multiplication: list<number> (at 1:1..1:12))"#,
   );
 }
 
@@ -3100,7 +3100,7 @@ fn _0358() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_list_by_rhs_boolean",
     &CTX,
-    r#"null(unexpected value type in multiplication: list<number>)"#,
+    r#"null(unexpected value type in multiplication: list<number> (at 1:1..1:12))"#,
   );
 }
 
@@ -3111,7 +3111,7 @@ fn _0359() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_list_by_rhs_date",
     &CTX,
-    r#"null(unexpected value type in multiplication: list<number>)"#,
+    r#"null(unexpected value type in multiplication: list<number> (at 1:1..1:21))"#,
   );
 }
 
@@ -3122,7 +3122,7 @@ fn _0360() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_list_by_rhs_dateAndTime",
     &CTX,
-    r#"null(unexpected value type in multiplication: list<number>)"#,
+    r#"null(unexpected value type in multiplication: list<number> (at 1:1..1:30))"#,
   );
 }
 
@@ -3133,7 +3133,7 @@ fn _0361() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_list_by_rhs_time",
     &CTX,
-    r#"null(unexpected value type in multiplication: list<number>)"#,
+    r#"null(unexpected value type in multiplication: list<number> (at 1:1..1:19))"#,
   );
 }
 
@@ -3144,7 +3144,7 @@ fn _0362() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_list_by_rhs_list",
     &CTX,
-    r#"null(unexpected value type in multiplication: list<number>)"#,
+    r#"null(unexpected value type in multiplication: list<number> (at 1:1..1:12))"#,
   );
 }
 
@@ -3155,7 +3155,7 @@ fn _0363() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_list_by_rhs_context",
     &CTX,
-    r#"null(unexpected value type in multiplication: list<number>)"#,
+    r#"null(unexpected value type in multiplication: list<number> (at 1:1..1:15))"#,
   );
 }
 
@@ -3166,7 +3166,7 @@ fn _0364() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_list_by_rhs_dtDuration",
     &CTX,
-    r#"null(unexpected value type in multiplication: list<number>)"#,
+    r#"null(unexpected value type in multiplication: list<number> (at 1:1..1:14))"#,
   );
 }
 
@@ -3177,7 +3177,7 @@ fn _0365() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_list_by_rhs_ymDuration",
     &CTX,
-    r#"null(unexpected value type in multiplication: list<number>)"#,
+    r#"null(unexpected value type in multiplication: list<number> (at 1:1..1:14))"#,
   );
 }
 
@@ -3188,7 +3188,7 @@ fn _0366() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_list_by_rhs_range",
     &CTX,
-    r#"null(unexpected value type in multiplication: list<number>)"#,
+    r#"null(unexpected value type in multiplication: list<number> (at 1:1..1:15))"#,
   );
 }
 
@@ -3199,7 +3199,7 @@ fn _0367() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_list_by_rhs_function",
     &CTX,
-    r#"null(unexpected value type in multiplication: list<number>)"#,
+    r#"null(unexpected value type in multiplication: list<number> (at 1:1..1:23))"#,
   );
 }
 
@@ -3210,7 +3210,7 @@ fn _0368() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_list_by_rhs_null",
     &CTX,
-    r#"null(unexpected value type in multiplication: list<number>)"#,
+    r#"null(unexpected value type in multiplication: list<number> (at 1:1..1:12))"#,
   );
 }
 
@@ -3221,7 +3221,7 @@ fn _0369() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_context_by_rhs_number",
     &CTX,
-    r#"null(unexpected value type in multiplication: context<a: number>)"#,
+    r#"null(unexpected value type in multiplication: context<a: number> (at 1:1..1:13))"#,
   );
 }
 
@@ -3232,7 +3232,7 @@ fn _0370() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_context_by_rhs_string",
     &CTX,
-    r#"null(unexpected value type in multiplication: context<a: number>)"#,
+    r#"null(unexpected value type in multiplication: context<a: number> (at 1:1..1:15))"#,
   );
 }
 
@@ -3243,7 +3243,7 @@ fn _0371() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_context_by_rhs_boolean",
     &CTX,
-    r#"null(unexpected value type in multiplication: context<a: number>)"#,
+    r#"null(unexpected value type in multiplication: context<a: number> (at 1:1..1:15))"#,
   );
 }
 
@@ -3254,7 +3254,7 @@ fn _0372() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_context_by_rhs_date",
     &CTX,
-    r#"null(unexpected value type in multiplication: context<a: number>)"#,
+    r#"null(unexpected value type in multiplication: context<a: number> (at 1:1..1:24))"#,
   );
 }
 
@@ -3265,7 +3265,7 @@ fn _0373() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_context_by_rhs_dateAndTime",
     &CTX,
-    r#"null(unexpected value type in multiplication: context<a: number>)"#,
+    r#"null(unexpected value type in multiplication: context<a: number> (at 1:1..1:33))"#,
   );
 }
 
@@ -3276,7 +3276,7 @@ fn _0374() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_context_by_rhs_time",
     &CTX,
-    r#"null(unexpected value type in multiplication: context<a: number>)"#,
+    r#"null(unexpected value type in multiplication: context<a: number> (at 1:1..1:22))"#,
   );
 }
 
@@ -3287,7 +3287,7 @@ fn _0375() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_context_by_rhs_list",
     &CTX,
-    r#"null(unexpected value type in multiplication: context<a: number>)"#,
+    r#"null(unexpected value type in multiplication: context<a: number> (at 1:1..1:15))"#,
   );
 }
 
@@ -3298,7 +3298,7 @@ fn _0376() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_context_by_rhs_context",
     &CTX,
-    r#"null(unexpected value type in multiplication: context<a: number>)"#,
+    r#"null(unexpected value type in multiplication: context<a: number> (at 1:1..1:18))"#,
   );
 }
 
@@ -3309,7 +3309,7 @@ fn _0377() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_context_by_rhs_dtDuration",
     &CTX,
-    r#"null(unexpected value type in multiplication: context<a: number>)"#,
+    r#"null(unexpected value type in multiplication: context<a: number> (at 1:1..1:17))"#,
   );
 }
 
@@ -3320,7 +3320,7 @@ fn _0378() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_context_by_rhs_ymDuration",
     &CTX,
-    r#"null(unexpected value type in multiplication: context<a: number>)"#,
+    r#"null(unexpected value type in multiplication: context<a: number> (at 1:1..1:17))"#,
   );
 }
 
@@ -3331,7 +3331,7 @@ fn _0379() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_context_by_rhs_range",
     &CTX,
-    r#"null(unexpected value type in multiplication: context<a: number>)"#,
+    r#"null(unexpected value type in multiplication: context<a: number> (at 1:1..1:18))"#,
   );
 }
 
@@ -3342,7 +3342,7 @@ fn _0380() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_context_by_rhs_function",
     &CTX,
-    r#"null(unexpected value type in multiplication: context<a: number>)"#,
+    r#"null(unexpected value type in multiplication: context<a: number> (at 1:1..1:26))"#,
   );
 }
 
@@ -3353,7 +3353,7 @@ fn _0381() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_context_by_rhs_null",
     &CTX,
-    r#"null(unexpected value type in multiplication: context<a: number>)"#,
+    r#"null(unexpected value type in multiplication: context<a: number> (at 1:1..1:15))"#,
   );
 }
 
@@ -3364,7 +3364,7 @@ fn _0382() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_dtDuration_by_rhs_string",
     &CTX,
-    r#"null([multiplication] incompatible types: P1D * "10")"#,
+    r#"null([multiplication] incompatible types: P1D * "10" (at 1:1..1:14))"#,
   );
 }
 
@@ -3375,7 +3375,7 @@ fn _0383() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_dtDuration_by_rhs_boolean",
     &CTX,
-    r#"null([multiplication] incompatible types: P1D * true)"#,
+    r#"null([multiplication] incompatible types: P1D * true (at 1:1..1:14))"#,
   );
 }
 
@@ -3386,7 +3386,7 @@ fn _0384() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_dtDuration_by_rhs_date",
     &CTX,
-    r#"null([multiplication] incompatible types: P1D * 2021-01-01)"#,
+    r#"null([multiplication] incompatible types: P1D * 2021-01-01 (at 1:1..1:23))"#,
   );
 }
 
@@ -3397,7 +3397,7 @@ fn _0385() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_dtDuration_by_rhs_dateAndTime",
     &CTX,
-    r#"null([multiplication] incompatible types: P1D * 2021-01-01T10:10:10)"#,
+    r#"null([multiplication] incompatible types: P1D * 2021-01-01T10:10:10 (at 1:1..1:32))"#,
   );
 }
 
@@ -3408,7 +3408,7 @@ fn _0386() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_dtDuration_by_rhs_time",
     &CTX,
-    r#"null([multiplication] incompatible types: P1D * 10:10:10)"#,
+    r#"null([multiplication] incompatible types: P1D * 10:10:10 (at 1:1..1:21))"#,
   );
 }
 
@@ -3419,7 +3419,7 @@ fn _0387() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_dtDuration_by_rhs_list",
     &CTX,
-    r#"null([multiplication] incompatible types: P1D * [10])"#,
+    r#"null([multiplication] incompatible types: P1D * [10] (at 1:1..1:14))"#,
   );
 }
 
@@ -3430,7 +3430,7 @@ fn _0388() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_dtDuration_by_rhs_context",
     &CTX,
-    r#"null([multiplication] incompatible types: P1D * {a: 10})"#,
+    r#"null([multiplication] incompatible types: P1D * {a: 10} (at 1:1..1:17))"#,
   );
 }
 
@@ -3441,7 +3441,7 @@ fn _0389() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_dtDuration_by_rhs_dtDuration",
     &CTX,
-    r#"null([multiplication] incompatible types: P1D * P1D)"#,
+    r#"null([multiplication] incompatible types: P1D * P1D (at 1:1..1:16))"#,
   );
 }
 
@@ -3452,7 +3452,7 @@ fn _0390() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_dtDuration_by_rhs_ymDuration",
     &CTX,
-    r#"null([multiplication] incompatible types: P1D * P1Y)"#,
+    r#"null([multiplication] incompatible types: P1D * P1Y (at 1:1..1:16))"#,
   );
 }
 
@@ -3463,7 +3463,7 @@ fn _0391() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_dtDuration_by_rhs_range",
     &CTX,
-    r#"null([multiplication] incompatible types: P1D * [1..10])"#,
+    r#"null([multiplication] incompatible types: P1D * [1..10] (at 1:1..1:17))"#,
   );
 }
 
@@ -3474,7 +3474,7 @@ fn _0392() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_dtDuration_by_rhs_function",
     &CTX,
-    r#"null([multiplication] incompatible types: P1D * FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any))"#,
+    r#"null([multiplication] incompatible types: P1D * FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) (at 1:1..1:25))"#,
   );
 }
 
@@ -3485,7 +3485,7 @@ fn _0393() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_dtDuration_by_rhs_null",
     &CTX,
-    r#"null([multiplication] incompatible types: P1D * null)"#,
+    r#"null([multiplication] incompatible types: P1D * null (at 1:1..1:14))"#,
   );
 }
 
@@ -3496,7 +3496,7 @@ fn _0394() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_ymDuration_by_rhs_string",
     &CTX,
-    r#"null([multiplication] incompatible types: P1Y * "10")"#,
+    r#"null([multiplication] incompatible types: P1Y * "10" (at 1:1..1:14))"#,
   );
 }
 
@@ -3507,7 +3507,7 @@ fn _0395() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_ymDuration_by_rhs_boolean",
     &CTX,
-    r#"null([multiplication] incompatible types: P1Y * true)"#,
+    r#"null([multiplication] incompatible types: P1Y * true (at 1:1..1:14))"#,
   );
 }
 
@@ -3518,7 +3518,7 @@ fn _0396() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_ymDuration_by_rhs_date",
     &CTX,
-    r#"null([multiplication] incompatible types: P1Y * 2021-01-01)"#,
+    r#"null([multiplication] incompatible types: P1Y * 2021-01-01 (at 1:1..1:23))"#,
   );
 }
 
@@ -3529,7 +3529,7 @@ fn _0397() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_ymDuration_by_rhs_dateAndTime",
     &CTX,
-    r#"null([multiplication] incompatible types: P1Y * 2021-01-01T10:10:10)"#,
+    r#"null([multiplication] incompatible types: P1Y * 2021-01-01T10:10:10 (at 1:1..1:32))"#,
   );
 }
 
@@ -3540,7 +3540,7 @@ fn _0398() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_ymDuration_by_rhs_time",
     &CTX,
-    r#"null([multiplication] incompatible types: P1Y * 10:10:10)"#,
+    r#"null([multiplication] incompatible types: P1Y * 10:10:10 (at 1:1..1:21))"#,
   );
 }
 
@@ -3551,7 +3551,7 @@ fn _0399() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_ymDuration_by_rhs_list",
     &CTX,
-    r#"null([multiplication] incompatible types: P1Y * [10])"#,
+    r#"null([multiplication] incompatible types: P1Y * [10] (at 1:1..1:14))"#,
   );
 }
 
@@ -3562,7 +3562,7 @@ fn _0400() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_ymDuration_by_rhs_context",
     &CTX,
-    r#"null([multiplication] incompatible types: P1Y * {a: 10})"#,
+    r#"null([multiplication] incompatible types: P1Y * {a: 10} (at 1:1..1:17))"#,
   );
 }
 
@@ -3573,7 +3573,7 @@ fn _0401() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_ymDuration_by_rhs_dtDuration",
     &CTX,
-    r#"null([multiplication] incompatible types: P1Y * P1D)"#,
+    r#"null([multiplication] incompatible types: P1Y * P1D (at 1:1..1:16))"#,
   );
 }
 
@@ -3584,7 +3584,7 @@ fn _0402() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_ymDuration_by_rhs_ymDuration",
     &CTX,
-    r#"null([multiplication] incompatible types: P1Y * P1Y)"#,
+    r#"null([multiplication] incompatible types: P1Y * P1Y (at 1:1..1:16))"#,
   );
 }
 
@@ -3595,7 +3595,7 @@ fn _0403() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_ymDuration_by_rhs_range",
     &CTX,
-    r#"null([multiplication] incompatible types: P1Y * [1..10])"#,
+    r#"null([multiplication] incompatible types: P1Y * [1..10] (at 1:1..1:17))"#,
   );
 }
 
@@ -3606,7 +3606,7 @@ fn _0404() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_ymDuration_by_rhs_function",
     &CTX,
-    r#"null([multiplication] incompatible types: P1Y * FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any))"#,
+    r#"null([multiplication] incompatible types: P1Y * FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) (at 1:1..1:25))"#,
   );
 }
 
@@ -3617,7 +3617,7 @@ fn _0405() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_ymDuration_by_rhs_null",
     &CTX,
-    r#"null([multiplication] incompatible types: P1Y * null)"#,
+    r#"null([multiplication] incompatible types: P1Y * null (at 1:1..1:14))"#,
   );
 }
 
@@ -3628,7 +3628,7 @@ fn _0406() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_range_by_rhs_number",
     &CTX,
-    r#"null(unexpected value type in multiplication: range<number>)"#,
+    r#"null(unexpected value type in multiplication: range<number> (at 1:1..1:13))"#,
   );
 }
 
@@ -3639,7 +3639,7 @@ fn _0407() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_range_by_rhs_string",
     &CTX,
-    r#"null(unexpected value type in multiplication: range<number>)"#,
+    r#"null(unexpected value type in multiplication: range<number> (at 1:1..1:15))"#,
   );
 }
 
@@ -3650,7 +3650,7 @@ fn _0408() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_range_by_rhs_boolean",
     &CTX,
-    r#"null(unexpected value type in multiplication: range<number>)"#,
+    r#"null(unexpected value type in multiplication: range<number> (at 1:1..1:15))"#,
   );
 }
 
@@ -3661,7 +3661,7 @@ fn _0409() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_range_by_rhs_date",
     &CTX,
-    r#"null(unexpected value type in multiplication: range<number>)"#,
+    r#"null(unexpected value type in multiplication: range<number> (at 1:1..1:24))"#,
   );
 }
 
@@ -3672,7 +3672,7 @@ fn _0410() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_range_by_rhs_dateAndTime",
     &CTX,
-    r#"null(unexpected value type in multiplication: range<number>)"#,
+    r#"null(unexpected value type in multiplication: range<number> (at 1:1..1:33))"#,
   );
 }
 
@@ -3683,7 +3683,7 @@ fn _0411() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_range_by_rhs_time",
     &CTX,
-    r#"null(unexpected value type in multiplication: range<number>)"#,
+    r#"null(unexpected value type in multiplication: range<number> (at 1:1..1:22))"#,
   );
 }
 
@@ -3694,7 +3694,7 @@ fn _0412() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_range_by_rhs_list",
     &CTX,
-    r#"null(unexpected value type in multiplication: range<number>)"#,
+    r#"null(unexpected value type in multiplication: range<number> (at 1:1..1:15))"#,
   );
 }
 
@@ -3705,7 +3705,7 @@ fn _0413() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_range_by_rhs_context",
     &CTX,
-    r#"null(unexpected value type in multiplication: range<number>)"#,
+    r#"null(unexpected value type in multiplication: range<number> (at 1:1..1:18))"#,
   );
 }
 
@@ -3716,7 +3716,7 @@ fn _0414() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_range_by_rhs_dtDuration",
     &CTX,
-    r#"null(unexpected value type in multiplication: range<number>)"#,
+    r#"null(unexpected value type in multiplication: range<number> (at 1:1..1:17))"#,
   );
 }
 
@@ -3727,7 +3727,7 @@ fn _0415() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_range_by_rhs_ymDuration",
     &CTX,
-    r#"null(unexpected value type in multiplication: range<number>)"#,
+    r#"null(unexpected value type in multiplication: range<number> (at 1:1..1:17))"#,
   );
 }
 
@@ -3738,7 +3738,7 @@ fn _0416() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_range_by_rhs_range",
     &CTX,
-    r#"null(unexpected value type in multiplication: range<number>)"#,
+    r#"null(unexpected value type in multiplication: range<number> (at 1:1..1:18))"#,
   );
 }
 
@@ -3749,7 +3749,7 @@ fn _0417() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_range_by_rhs_function",
     &CTX,
-    r#"null(unexpected value type in multiplication: range<number>)"#,
+    r#"null(unexpected value type in multiplication: range<number> (at 1:1..1:26))"#,
   );
 }
 
@@ -3760,7 +3760,7 @@ fn _0418() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_range_by_rhs_null",
     &CTX,
-    r#"null(unexpected value type in multiplication: range<number>)"#,
+    r#"null(unexpected value type in multiplication: range<number> (at 1:1..1:15))"#,
   );
 }
 
@@ -3771,7 +3771,7 @@ fn _0419() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_function_by_rhs_number",
     &CTX,
-    r#"null(unexpected value type in multiplication: function<Any>->Any)"#,
+    r#"null(unexpected value type in multiplication: function<Any>->Any (at 1:1..1:21))"#,
   );
 }
 
@@ -3782,7 +3782,7 @@ fn _0420() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_function_by_rhs_string",
     &CTX,
-    r#"null(unexpected value type in multiplication: function<Any>->Any)"#,
+    r#"null(unexpected value type in multiplication: function<Any>->Any (at 1:1..1:23))"#,
   );
 }
 
@@ -3793,7 +3793,7 @@ fn _0421() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_function_by_rhs_boolean",
     &CTX,
-    r#"null(unexpected value type in multiplication: function<Any>->Any)"#,
+    r#"null(unexpected value type in multiplication: function<Any>->Any (at 1:1..1:23))"#,
   );
 }
 
@@ -3804,7 +3804,7 @@ fn _0422() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_function_by_rhs_date",
     &CTX,
-    r#"null(unexpected value type in multiplication: function<Any>->Any)"#,
+    r#"null(unexpected value type in multiplication: function<Any>->Any (at 1:1..1:32))"#,
   );
 }
 
@@ -3815,7 +3815,7 @@ fn _0423() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_function_by_rhs_dateAndTime",
     &CTX,
-    r#"null(unexpected value type in multiplication: function<Any>->Any)"#,
+    r#"null(unexpected value type in multiplication: function<Any>->Any (at 1:1..1:41))"#,
   );
 }
 
@@ -3826,7 +3826,7 @@ fn _0424() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_function_by_rhs_time",
     &CTX,
-    r#"null(unexpected value type in multiplication: function<Any>->Any)"#,
+    r#"null(unexpected value type in multiplication: function<Any>->Any (at 1:1..1:30))"#,
   );
 }
 
@@ -3837,7 +3837,7 @@ fn _0425() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_function_by_rhs_list",
     &CTX,
-    r#"null(unexpected value type in multiplication: function<Any>->Any)"#,
+    r#"null(unexpected value type in multiplication: function<Any>->Any (at 1:1..1:23))"#,
   );
 }
 
@@ -3848,7 +3848,7 @@ fn _0426() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_function_by_rhs_context",
     &CTX,
-    r#"null(unexpected value type in multiplication: function<Any>->Any)"#,
+    r#"null(unexpected value type in multiplication: function<Any>->Any (at 1:1..1:26))"#,
   );
 }
 
@@ -3859,7 +3859,7 @@ fn _0427() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_function_by_rhs_dtDuration",
     &CTX,
-    r#"null(unexpected value type in multiplication: function<Any>->Any)"#,
+    r#"null(unexpected value type in multiplication: function<Any>->Any (at 1:1..1:25))"#,
   );
 }
 
@@ -3870,7 +3870,7 @@ fn _0428() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_function_by_rhs_ymDuration",
     &CTX,
-    r#"null(unexpected value type in multiplication: function<Any>->Any)"#,
+    r#"null(unexpected value type in multiplication: function<Any>->Any (at 1:1..1:25))"#,
   );
 }
 
@@ -3881,7 +3881,7 @@ fn _0429() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_function_by_rhs_range",
     &CTX,
-    r#"null(unexpected value type in multiplication: function<Any>->Any)"#,
+    r#"null(unexpected value type in multiplication: function<Any>->Any (at 1:1..1:26))"#,
   );
 }
 
@@ -3892,7 +3892,7 @@ fn _0430() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_function_by_rhs_function",
     &CTX,
-    r#"null(unexpected value type in multiplication: function<Any>->Any)"#,
+    r#"null(unexpected value type in multiplication: function<Any>->Any (at 1:1..1:34))"#,
   );
 }
 
@@ -3903,7 +3903,7 @@ fn _0431() {
     &MODEL_NAMESPACE,
     "error_when_multiply_lhs_function_by_rhs_null",
     &CTX,
-    r#"null(unexpected value type in multiplication: function<Any>->Any)"#,
+    r#"null(unexpected value type in multiplication: function<Any>->Any (at 1:1..1:23))"#,
   );
 }
 
@@ -3979,7 +3979,7 @@ fn _0445() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_number_by_rhs_string",
     &CTX,
-    r#"null([division] incompatible types: 10 / "10")"#,
+    r#"null([division] incompatible types: 10 / "10" (at 1:1..1:10))"#,
   );
 }
 
@@ -3990,7 +3990,7 @@ fn _0446() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_number_by_rhs_boolean",
     &CTX,
-    r#"null([division] incompatible types: 10 / true)"#,
+    r#"null([division] incompatible types: 10 / true (at 1:1..1:10))"#,
   );
 }
 
@@ -4001,7 +4001,7 @@ fn _0447() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_number_by_rhs_date",
     &CTX,
-    r#"null([division] incompatible types: 10 / 2021-01-01)"#,
+    r#"null([division] incompatible types: 10 / 2021-01-01 (at 1:1..1:19))"#,
   );
 }
 
@@ -4012,7 +4012,7 @@ fn _0448() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_number_by_rhs_dateAndTime",
     &CTX,
-    r#"null([division] incompatible types: 10 / 2021-01-01T10:10:10)"#,
+    r#"null([division] incompatible types: 10 / 2021-01-01T10:10:10 (at 1:1..1:28))"#,
   );
 }
 
@@ -4023,7 +4023,7 @@ fn _0449() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_number_by_rhs_time",
     &CTX,
-    r#"null([division] incompatible types: 10 / 10:10:10)"#,
+    r#"null([division] incompatible types: 10 / 10:10:10 (at 1:1..1:17))"#,
   );
 }
 
@@ -4034,7 +4034,7 @@ fn _0450() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_number_by_rhs_list",
     &CTX,
-    r#"null([division] incompatible types: 10 / [10])"#,
+    r#"null([division] incompatible types: 10 / [10] (at 1:1..1:10))"#,
   );
 }
 
@@ -4045,7 +4045,7 @@ fn _0451() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_number_by_rhs_context",
     &CTX,
-    r#"null([division] incompatible types: 10 / {a: 10})"#,
+    r#"null([division] incompatible types: 10 / {a: 10} (at 1:1..1:13))"#,
   );
 }
 
@@ -4056,7 +4056,7 @@ fn _0452() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_number_by_rhs_dtDuration",
     &CTX,
-    r#"null([division] incompatible types: 10 / P1D)"#,
+    r#"null([division] incompatible types: 10 / P1D (at 1:1..1:12))"#,
   );
 }
 
@@ -4067,7 +4067,7 @@ fn _0453() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_number_by_rhs_ymDuration",
     &CTX,
-    r#"null([division] incompatible types: 10 / P1Y)"#,
+    r#"null([division] incompatible types: 10 / P1Y (at 1:1..1:12))"#,
   );
 }
 
@@ -4078,7 +4078,7 @@ fn _0454() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_number_by_rhs_range",
     &CTX,
-    r#"null([division] incompatible types: 10 / [1..10])"#,
+    r#"null([division] incompatible types: 10 / [1..10] (at 1:1..1:13))"#,
   );
 }
 
@@ -4089,7 +4089,7 @@ fn _0455() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_number_by_rhs_function",
     &CTX,
-    r#"null([division] incompatible types: 10 / FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any))"#,
+    r#"null([division] incompatible types: 10 / FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) (at 1:1..1:21))"#,
   );
 }
 
@@ -4100,7 +4100,7 @@ fn _0456() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_number_by_rhs_null",
     &CTX,
-    r#"null([division] incompatible types: 10 / null)"#,
+    r#"null([division] incompatible types: 10 / null (at 1:1..1:10))"#,
   );
 }
 
@@ -4111,7 +4111,7 @@ fn _0457() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_string_by_rhs_number",
     &CTX,
-    r#"null([division] incompatible types: "10" / 10)"#,
+    r#"null([division] incompatible types: "10" / 10 (at 1:1..1:10))"#,
   );
 }
 
@@ -4122,7 +4122,7 @@ fn _0458() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_string_by_rhs_string",
     &CTX,
-    r#"null([division] incompatible types: "10" / "10")"#,
+    r#"null([division] incompatible types: "10" / "10" (at 1:1..1:12))"#,
   );
 }
 
@@ -4133,7 +4133,7 @@ fn _0459() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_string_by_rhs_boolean",
     &CTX,
-    r#"null([division] incompatible types: "10" / true)"#,
+    r#"null([division] incompatible types: "10" / true (at 1:1..1:12))"#,
   );
 }
 
@@ -4144,7 +4144,7 @@ fn _0460() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_string_by_rhs_date",
     &CTX,
-    r#"null([division] incompatible types: "10" / 2021-01-01)"#,
+    r#"null([division] incompatible types: "10" / 2021-01-01 (at 1:1..1:21))"#,
   );
 }
 
@@ -4155,7 +4155,7 @@ fn _0461() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_string_by_rhs_dateAndTime",
     &CTX,
-    r#"null([division] incompatible types: "10" / 2021-01-01T10:10:10)"#,
+    r#"null([division] incompatible types: "10" / 2021-01-01T10:10:10 (at 1:1..1:30))"#,
   );
 }
 
@@ -4166,7 +4166,7 @@ fn _0462() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_string_by_rhs_time",
     &CTX,
-    r#"null([division] incompatible types: "10" / 10:10:10)"#,
+    r#"null([division] incompatible types: "10" / 10:10:10 (at 1:1..1:19))"#,
   );
 }
 
@@ -4177,7 +4177,7 @@ fn _0463() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_string_by_rhs_list",
     &CTX,
-    r#"null([division] incompatible types: "10" / [10])"#,
+    r#"null([division] incompatible types: "10" / [10] (at 1:1..1:12))"#,
   );
 }
 
@@ -4188,7 +4188,7 @@ fn _0464() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_string_by_rhs_context",
     &CTX,
-    r#"null([division] incompatible types: "10" / {a: 10})"#,
+    r#"null([division] incompatible types: "10" / {a: 10} (at 1:1..1:15))"#,
   );
 }
 
@@ -4199,7 +4199,7 @@ fn _0465() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_string_by_rhs_dtDuration",
     &CTX,
-    r#"null([division] incompatible types: "10" / P1D)"#,
+    r#"null([division] incompatible types: "10" / P1D (at 1:1..1:14))"#,
   );
 }
 
@@ -4210,7 +4210,7 @@ fn _0466() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_string_by_rhs_ymDuration",
     &CTX,
-    r#"null([division] incompatible types: "10" / P1Y)"#,
+    r#"null([division] incompatible types: "10" / P1Y (at 1:1..1:14))"#,
   );
 }
 
@@ -4221,7 +4221,7 @@ fn _0467() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_string_by_rhs_range",
     &CTX,
-    r#"null([division] incompatible types: "10" / [1..10])"#,
+    r#"null([division] incompatible types: "10" / [1..10] (at 1:1..1:15))"#,
   );
 }
 
@@ -4232,7 +4232,7 @@ fn _0468() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_string_by_rhs_function",
     &CTX,
-    r#"null([division] incompatible types: "10" / FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any))"#,
+    r#"null([division] incompatible types: "10" / FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) (at 1:1..1:23))"#,
   );
 }
 
@@ -4243,7 +4243,7 @@ fn _0469() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_string_by_rhs_null",
     &CTX,
-    r#"null([division] incompatible types: "10" / null)"#,
+    r#"null([division] incompatible types: "10" / null (at 1:1..1:12))"#,
   );
 }
 
@@ -4254,7 +4254,7 @@ fn _0470() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_boolean_by_rhs_number",
     &CTX,
-    r#"null([division] incompatible types: true / 10)"#,
+    r#"null([division] incompatible types: true / 10 (at 1:1..1:10))"#,
   );
 }
 
@@ -4265,7 +4265,7 @@ fn _0471() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_boolean_by_rhs_string",
     &CTX,
-    r#"null([division] incompatible types: true / "10")"#,
+    r#"null([division] incompatible types: true / "10" (at 1:1..1:12))"#,
   );
 }
 
@@ -4276,7 +4276,7 @@ fn _0472() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_boolean_by_rhs_boolean",
     &CTX,
-    r#"null([division] incompatible types: true / true)"#,
+    r#"null([division] incompatible types: true / true (at 1:1..1:12))"#,
   );
 }
 
@@ -4287,7 +4287,7 @@ fn _0473() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_boolean_by_rhs_date",
     &CTX,
-    r#"null([division] incompatible types: true / 2021-01-01)"#,
+    r#"null([division] incompatible types: true / 2021-01-01 (at 1:1..1:21))"#,
   );
 }
 
@@ -4298,7 +4298,7 @@ fn _0474() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_boolean_by_rhs_dateAndTime",
     &CTX,
-    r#"null([division] incompatible types: true / 2021-01-01T10:10:10)"#,
+    r#"null([division] incompatible types: true / 2021-01-01T10:10:10 (at 1:1..1:30))"#,
   );
 }
 
@@ -4309,7 +4309,7 @@ fn _0475() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_boolean_by_rhs_time",
     &CTX,
-    r#"null([division] incompatible types: true / 10:10:10)"#,
+    r#"null([division] incompatible types: true / 10:10:10 (at 1:1..1:19))"#,
   );
 }
 
@@ -4320,7 +4320,7 @@ fn _0476() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_boolean_by_rhs_list",
     &CTX,
-    r#"null([division] incompatible types: true / [10])"#,
+    r#"null([division] incompatible types: true / [10] (at 1:1..1:12))"#,
   );
 }
 
@@ -4331,7 +4331,7 @@ fn _0477() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_boolean_by_rhs_context",
     &CTX,
-    r#"null([division] incompatible types: true / {a: 10})"#,
+    r#"null([division] incompatible types: true / {a: 10} (at 1:1..1:15))"#,
   );
 }
 
@@ -4342,7 +4342,7 @@ fn _0478() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_boolean_by_rhs_dtDuration",
     &CTX,
-    r#"null([division] incompatible types: true / P1D)"#,
+    r#"null([division] incompatible types: true / P1D (at 1:1..1:14))"#,
   );
 }
 
@@ -4353,7 +4353,7 @@ fn _0479() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_boolean_by_rhs_ymDuration",
     &CTX,
-    r#"null([division] incompatible types: true / P1Y)"#,
+    r#"null([division] incompatible types: true / P1Y (at 1:1..1:14))"#,
   );
 }
 
@@ -4364,7 +4364,7 @@ fn _0480() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_boolean_by_rhs_range",
     &CTX,
-    r#"null([division] incompatible types: true / [1..10])"#,
+    r#"null([division] incompatible types: true / [1..10] (at 1:1..1:15))"#,
   );
 }
 
@@ -4375,7 +4375,7 @@ fn _0481() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_boolean_by_rhs_function",
     &CTX,
-    r#"null([division] incompatible types: true / FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any))"#,
+    r#"null([division] incompatible types: true / FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) (at 1:1..1:23))"#,
   );
 }
 
@@ -4386,7 +4386,7 @@ fn _0482() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_boolean_by_rhs_null",
     &CTX,
-    r#"null([division] incompatible types: true / null)"#,
+    r#"null([division] incompatible types: true / null (at 1:1..1:12))"#,
   );
 }
 
@@ -4397,7 +4397,7 @@ fn _0483() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_date_by_rhs_number",
     &CTX,
-    r#"null([division] incompatible types: 2021-01-01 / 10)"#,
+    r#"null([division] incompatible types: 2021-01-01 / 10 (at 1:1..1:19))"#,
   );
 }
 
@@ -4408,7 +4408,7 @@ fn _0484() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_date_by_rhs_string",
     &CTX,
-    r#"null([division] incompatible types: 2021-01-01 / "10")"#,
+    r#"null([division] incompatible types: 2021-01-01 / "10" (at 1:1..1:21))"#,
   );
 }
 
@@ -4419,7 +4419,7 @@ fn _0485() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_date_by_rhs_boolean",
     &CTX,
-    r#"null([division] incompatible types: 2021-01-01 / true)"#,
+    r#"null([division] incompatible types: 2021-01-01 / true (at 1:1..1:21))"#,
   );
 }
 
@@ -4430,7 +4430,7 @@ fn _0486() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_date_by_rhs_date",
     &CTX,
-    r#"null([division] incompatible types: 2021-01-01 / 2021-01-01)"#,
+    r#"null([division] incompatible types: 2021-01-01 / 2021-01-01 (at 1:1..1:30))"#,
   );
 }
 
@@ -4441,7 +4441,7 @@ fn _0487() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_date_by_rhs_dateAndTime",
     &CTX,
-    r#"null([division] incompatible types: 2021-01-01 / 2021-01-01T10:10:10)"#,
+    r#"null([division] incompatible types: 2021-01-01 / 2021-01-01T10:10:10 (at 1:1..1:39))"#,
   );
 }
 
@@ -4452,7 +4452,7 @@ fn _0488() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_date_by_rhs_time",
     &CTX,
-    r#"null([division] incompatible types: 2021-01-01 / 10:10:10)"#,
+    r#"null([division] incompatible types: 2021-01-01 / 10:10:10 (at 1:1..1:28))"#,
   );
 }
 
@@ -4463,7 +4463,7 @@ fn _0489() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_date_by_rhs_list",
     &CTX,
-    r#"null([division] incompatible types: 2021-01-01 / [10])"#,
+    r#"null([division] incompatible types: 2021-01-01 / [10] (at 1:1..1:21))"#,
   );
 }
 
@@ -4474,7 +4474,7 @@ fn _0490() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_date_by_rhs_context",
     &CTX,
-    r#"null([division] incompatible types: 2021-01-01 / {a: 10})"#,
+    r#"null([division] incompatible types: 2021-01-01 / {a: 10} (at 1:1..1:24))"#,
   );
 }
 
@@ -4485,7 +4485,7 @@ fn _0491() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_date_by_rhs_dtDuration",
     &CTX,
-    r#"null([division] incompatible types: 2021-01-01 / P1D)"#,
+    r#"null([division] incompatible types: 2021-01-01 / P1D (at 1:1..1:23))"#,
   );
 }
 
@@ -4496,7 +4496,7 @@ fn _0492() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_date_by_rhs_ymDuration",
     &CTX,
-    r#"null([division] incompatible types: 2021-01-01 / P1Y)"#,
+    r#"null([division] incompatible types: 2021-01-01 / P1Y (at 1:1..1:23))"#,
   );
 }
 
@@ -4507,7 +4507,7 @@ fn _0493() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_date_by_rhs_range",
     &CTX,
-    r#"null([division] incompatible types: 2021-01-01 / [1..10])"#,
+    r#"null([division] incompatible types: 2021-01-01 / [1..10] (at 1:1..1:24))"#,
   );
 }
 
@@ -4518,7 +4518,7 @@ fn _0494() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_date_by_rhs_function",
     &CTX,
-    r#"null([division] incompatible types: 2021-01-01 / FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any))"#,
+    r#"null([division] incompatible types: 2021-01-01 / FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) (at 1:1..1:32))"#,
   );
 }
 
@@ -4529,7 +4529,7 @@ fn _0495() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_date_by_rhs_null",
     &CTX,
-    r#"null([division] incompatible types: 2021-01-01 / null)"#,
+    r#"null([division] incompatible types: 2021-01-01 / null (at 1:1..1:21))"#,
   );
 }
 
@@ -4540,7 +4540,7 @@ fn _0496() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_dateAndTime_by_rhs_number",
     &CTX,
-    r#"null([division] incompatible types: 2021-01-01T10:10:10 / 10)"#,
+    r#"null([division] incompatible types: 2021-01-01T10:10:10 / 10 (at 1:1..1:28))"#,
   );
 }
 
@@ -4551,7 +4551,7 @@ fn _0497() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_dateAndTime_by_rhs_string",
     &CTX,
-    r#"null([division] incompatible types: 2021-01-01T10:10:10 / "10")"#,
+    r#"null([division] incompatible types: 2021-01-01T10:10:10 / "10" (at 1:1..1:30))"#,
   );
 }
 
@@ -4562,7 +4562,7 @@ fn _0498() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_dateAndTime_by_rhs_boolean",
     &CTX,
-    r#"null([division] incompatible types: 2021-01-01T10:10:10 / true)"#,
+    r#"null([division] incompatible types: 2021-01-01T10:10:10 / true (at 1:1..1:30))"#,
   );
 }
 
@@ -4573,7 +4573,7 @@ fn _0499() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_dateAndTime_by_rhs_date",
     &CTX,
-    r#"null([division] incompatible types: 2021-01-01T10:10:10 / 2021-01-01)"#,
+    r#"null([division] incompatible types: 2021-01-01T10:10:10 / 2021-01-01 (at 1:1..1:39))"#,
   );
 }
 
@@ -4584,7 +4584,7 @@ fn _0500() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_dateAndTime_by_rhs_dateAndTime",
     &CTX,
-    r#"null([division] incompatible types: 2021-01-01T10:10:10 / 2021-01-01T10:10:10)"#,
+    r#"null([division] incompatible types: 2021-01-01T10:10:10 / 2021-01-01T10:10:10 (at 1:1..1:48))"#,
   );
 }
 
@@ -4595,7 +4595,7 @@ fn _0501() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_dateAndTime_by_rhs_time",
     &CTX,
-    r#"null([division] incompatible types: 2021-01-01T10:10:10 / 10:10:10)"#,
+    r#"null([division] incompatible types: 2021-01-01T10:10:10 / 10:10:10 (at 1:1..1:37))"#,
   );
 }
 
@@ -4606,7 +4606,7 @@ fn _0502() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_dateAndTime_by_rhs_list",
     &CTX,
-    r#"null([division] incompatible types: 2021-01-01T10:10:10 / [10])"#,
+    r#"null([division] incompatible types: 2021-01-01T10:10:10 / [10] (at 1:1..1:30))"#,
   );
 }
 
@@ -4617,7 +4617,7 @@ fn _0503() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_dateAndTime_by_rhs_context",
     &CTX,
-    r#"null([division] incompatible types: 2021-01-01T10:10:10 / {a: 10})"#,
+    r#"null([division] incompatible types: 2021-01-01T10:10:10 / {a: 10} (at 1:1..1:33))"#,
   );
 }
 
@@ -4628,7 +4628,7 @@ fn _0504() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_dateAndTime_by_rhs_dtDuration",
     &CTX,
-    r#"null([division] incompatible types: 2021-01-01T10:10:10 / P1D)"#,
+    r#"null([division] incompatible types: 2021-01-01T10:10:10 / P1D (at 1:1..1:32))"#,
   );
 }
 
@@ -4639,7 +4639,7 @@ fn _0505() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_dateAndTime_by_rhs_ymDuration",
     &CTX,
-    r#"null([division] incompatible types: 2021-01-01T10:10:10 / P1Y)"#,
+    r#"null([division] incompatible types: 2021-01-01T10:10:10 / P1Y (at 1:1..1:32))"#,
   );
 }
 
@@ -4650,7 +4650,7 @@ fn _0506() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_dateAndTime_by_rhs_range",
     &CTX,
-    r#"null([division] incompatible types: 2021-01-01T10:10:10 / [1..10])"#,
+    r#"null([division] incompatible types: 2021-01-01T10:10:10 / [1..10] (at 1:1..1:33))"#,
   );
 }
 
@@ -4661,7 +4661,7 @@ fn _0507() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_dateAndTime_by_rhs_function",
     &CTX,
-    r#"null([division] incompatible types: 2021-01-01T10:10:10 / FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any))"#,
+    r#"null([division] incompatible types: 2021-01-01T10:10:10 / FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) (at 1:1..1:41))"#,
   );
 }
 
@@ -4672,7 +4672,7 @@ fn _0508() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_dateAndTime_by_rhs_null",
     &CTX,
-    r#"null([division] incompatible types: 2021-01-01T10:10:10 / null)"#,
+    r#"null([division] incompatible types: 2021-01-01T10:10:10 / null (at 1:1..1:30))"#,
   );
 }
 
@@ -4683,7 +4683,7 @@ fn _0509() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_time_by_rhs_number",
     &CTX,
-    r#"null([division] incompatible types: 10:10:10 / 10)"#,
+    r#"null([division] incompatible types: 10:10:10 / 10 (at 1:1..1:17))"#,
   );
 }
 
@@ -4694,7 +4694,7 @@ fn _0510() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_time_by_rhs_string",
     &CTX,
-    r#"null([division] incompatible types: 10:10:10 / "10")"#,
+    r#"null([division] incompatible types: 10:10:10 / "10" (at 1:1..1:19))"#,
   );
 }
 
@@ -4705,7 +4705,7 @@ fn _0511() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_time_by_rhs_boolean",
     &CTX,
-    r#"null([division] incompatible types: 10:10:10 / true)"#,
+    r#"null([division] incompatible types: 10:10:10 / true (at 1:1..1:19))"#,
   );
 }
 
@@ -4716,7 +4716,7 @@ fn _0512() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_time_by_rhs_date",
     &CTX,
-    r#"null([division] incompatible types: 10:10:10 / 2021-01-01)"#,
+    r#"null([division] incompatible types: 10:10:10 / 2021-01-01 (at 1:1..1:28))"#,
   );
 }
 
@@ -4727,7 +4727,7 @@ fn _0513() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_time_by_rhs_dateAndTime",
     &CTX,
-    r#"null([division] incompatible types: 10:10:10 / 2021-01-01T10:10:10)"#,
+    r#"null([division] incompatible types: 10:10:10 / 2021-01-01T10:10:10 (at 1:1..1:37))"#,
   );
 }
 
@@ -4738,7 +4738,7 @@ fn _0514() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_time_by_rhs_time",
     &CTX,
-    r#"null([division] incompatible types: 10:10:10 / 10:10:10)"#,
+    r#"null([division] incompatible types: 10:10:10 / 10:10:10 (at 1:1..1:26))"#,
   );
 }
 
@@ -4749,7 +4749,7 @@ fn _0515() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_time_by_rhs_list",
     &CTX,
-    r#"null([division] incompatible types: 10:10:10 / [10])"#,
+    r#"null([division] incompatible types: 10:10:10 / [10] (at 1:1..1:19))"#,
   );
 }
 
@@ -4760,7 +4760,7 @@ fn _0516() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_time_by_rhs_context",
     &CTX,
-    r#"null([division] incompatible types: 10:10:10 / {a: 10})"#,
+    r#"null([division] incompatible types: 10:10:10 / {a: 10} (at 1:1..1:22))"#,
   );
 }
 
@@ -4771,7 +4771,7 @@ fn _0517() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_time_by_rhs_dtDuration",
     &CTX,
-    r#"null([division] incompatible types: 10:10:10 / P1D)"#,
+    r#"null([division] incompatible types: 10:10:10 / P1D (at 1:1..1:21))"#,
   );
 }
 
@@ -4782,7 +4782,7 @@ fn _0518() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_time_by_rhs_ymDuration",
     &CTX,
-    r#"null([division] incompatible types: 10:10:10 / P1Y)"#,
+    r#"null([division] incompatible types: 10:10:10 / P1Y (at 1:1..1:21))"#,
   );
 }
 
@@ -4793,7 +4793,7 @@ fn _0519() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_time_by_rhs_range",
     &CTX,
-    r#"null([division] incompatible types: 10:10:10 / [1..10])"#,
+    r#"null([division] incompatible types: 10:10:10 / [1..10] (at 1:1..1:22))"#,
   );
 }
 
@@ -4804,7 +4804,7 @@ fn _0520() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_time_by_rhs_function",
     &CTX,
-    r#"null([division] incompatible types: 10:10:10 / FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any))"#,
+    r#"null([division] incompatible types: 10:10:10 / FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) (at 1:1..1:30))"#,
   );
 }
 
@@ -4815,7 +4815,7 @@ fn _0521() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_time_by_rhs_null",
     &CTX,
-    r#"null([division] incompatible types: 10:10:10 / null)"#,
+    r#"null([division] incompatible types: 10:10:10 / null (at 1:1..1:19))"#,
   );
 }
 
@@ -4826,7 +4826,7 @@ fn _0522() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_list_by_rhs_number",
     &CTX,
-    r#"null([division] incompatible types: [10] / 10)"#,
+    r#"null([division] incompatible types: [10] / 10 (at 1:1..1:10))"#,
   );
 }
 
@@ -4837,7 +4837,7 @@ fn _0523() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_list_by_rhs_string",
     &CTX,
-    r#"null([division] incompatible types: [10] / "10")"#,
+    r#"null([division] incompatible types: [10] / "10" (at 1:1..1:12))"#,
   );
 }
 
@@ -4848,7 +4848,7 @@ fn _0524() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_list_by_rhs_boolean",
     &CTX,
-    r#"null([division] incompatible types: [10] / true)"#,
+    r#"null([division] incompatible types: [10] / true (at 1:1..1:12))"#,
   );
 }
 
@@ -4859,7 +4859,7 @@ fn _0525() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_list_by_rhs_date",
     &CTX,
-    r#"null([division] incompatible types: [10] / 2021-01-01)"#,
+    r#"null([division] incompatible types: [10] / 2021-01-01 (at 1:1..1:21))"#,
   );
 }
 
@@ -4870,7 +4870,7 @@ fn _0526() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_list_by_rhs_dateAndTime",
     &CTX,
-    r#"null([division] incompatible types: [10] / 2021-01-01T10:10:10)"#,
+    r#"null([division] incompatible types: [10] / 2021-01-01T10:10:10 (at 1:1..1:30))"#,
   );
 }
 
@@ -4881,7 +4881,7 @@ fn _0527() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_list_by_rhs_time",
     &CTX,
-    r#"null([division] incompatible types: [10] / 10:10:10)"#,
+    r#"null([division] incompatible types: [10] / 10:10:10 (at 1:1..1:19))"#,
   );
 }
 
@@ -4892,7 +4892,7 @@ fn _0528() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_list_by_rhs_list",
     &CTX,
-    r#"null([division] incompatible types: [10] / [10])"#,
+    r#"null([division] incompatible types: [10] / [10] (at 1:1..1:12))"#,
   );
 }
 
@@ -4903,7 +4903,7 @@ fn _0529() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_list_by_rhs_context",
     &CTX,
-    r#"null([division] incompatible types: [10] / {a: 10})"#,
+    r#"null([division] incompatible types: [10] / {a: 10} (at 1:1..1:15))"#,
   );
 }
 
@@ -4914,7 +4914,7 @@ fn _0530() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_list_by_rhs_dtDuration",
     &CTX,
-    r#"null([division] incompatible types: [10] / P1D)"#,
+    r#"null([division] incompatible types: [10] / P1D (at 1:1..1:14))"#,
   );
 }
 
@@ -4925,7 +4925,7 @@ fn _0531() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_list_by_rhs_ymDuration",
     &CTX,
-    r#"null([division] incompatible types: [10] / P1Y)"#,
+    r#"null([division] incompatible types: [10] / P1Y (at 1:1..1:14))"#,
   );
 }
 
@@ -4936,7 +4936,7 @@ fn _0532() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_list_by_rhs_range",
     &CTX,
-    r#"null([division] incompatible types: [10] / [1..10])"#,
+    r#"null([division] incompatible types: [10] / [1..10] (at 1:1..1:15))"#,
   );
 }
 
@@ -4947,7 +4947,7 @@ fn _0533() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_list_by_rhs_function",
     &CTX,
-    r#"null([division] incompatible types: [10] / FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any))"#,
+    r#"null([division] incompatible types: [10] / FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) (at 1:1..1:23))"#,
   );
 }
 
@@ -4958,7 +4958,7 @@ fn _0534() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_list_by_rhs_null",
     &CTX,
-    r#"null([division] incompatible types: [10] / null)"#,
+    r#"null([division] incompatible types: [10] / null (at 1:1..1:12))"#,
   );
 }
 
@@ -4969,7 +4969,7 @@ fn _0535() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_context_by_rhs_number",
     &CTX,
-    r#"null([division] incompatible types: {a: 10} / 10)"#,
+    r#"null([division] incompatible types: {a: 10} / 10 (at 1:1..1:13))"#,
   );
 }
 
@@ -4980,7 +4980,7 @@ fn _0536() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_context_by_rhs_string",
     &CTX,
-    r#"null([division] incompatible types: {a: 10} / "10")"#,
+    r#"null([division] incompatible types: {a: 10} / "10" (at 1:1..1:15))"#,
   );
 }
 
@@ -4991,7 +4991,7 @@ fn _0537() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_context_by_rhs_boolean",
     &CTX,
-    r#"null([division] incompatible types: {a: 10} / true)"#,
+    r#"null([division] incompatible types: {a: 10} / true (at 1:1..1:15))"#,
   );
 }
 
@@ -5002,7 +5002,7 @@ fn _0538() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_context_by_rhs_date",
     &CTX,
-    r#"null([division] incompatible types: {a: 10} / 2021-01-01)"#,
+    r#"null([division] incompatible types: {a: 10} / 2021-01-01 (at 1:1..1:24))"#,
   );
 }
 
@@ -5013,7 +5013,7 @@ fn _0539() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_context_by_rhs_dateAndTime",
     &CTX,
-    r#"null([division] incompatible types: {a: 10} / 2021-01-01T10:10:10)"#,
+    r#"null([division] incompatible types: {a: 10} / 2021-01-01T10:10:10 (at 1:1..1:33))"#,
   );
 }
 
@@ -5024,7 +5024,7 @@ fn _0540() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_context_by_rhs_time",
     &CTX,
-    r#"null([division] incompatible types: {a: 10} / 10:10:10)"#,
+    r#"null([division] incompatible types: {a: 10} / 10:10:10 (at 1:1..1:22))"#,
   );
 }
 
@@ -5035,7 +5035,7 @@ fn _0541() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_context_by_rhs_list",
     &CTX,
-    r#"null([division] incompatible types: {a: 10} / [10])"#,
+    r#"null([division] incompatible types: {a: 10} / [10] (at 1:1..1:15))"#,
   );
 }
 
@@ -5046,7 +5046,7 @@ fn _0542() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_context_by_rhs_context",
     &CTX,
-    r#"null([division] incompatible types: {a: 10} / {a: 10})"#,
+    r#"null([division] incompatible types: {a: 10} / {a: 10} (at 1:1..1:18))"#,
   );
 }
 
@@ -5057,7 +5057,7 @@ fn _0543() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_context_by_rhs_dtDuration",
     &CTX,
-    r#"null([division] incompatible types: {a: 10} / P1D)"#,
+    r#"null([division] incompatible types: {a: 10} / P1D (at 1:1..1:17))"#,
   );
 }
 
@@ -5068,7 +5068,7 @@ fn _0544() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_context_by_rhs_ymDuration",
     &CTX,
-    r#"null([division] incompatible types: {a: 10} / P1Y)"#,
+    r#"null([division] incompatible types: {a: 10} / P1Y (at 1:1..1:17))"#,
   );
 }
 
@@ -5079,7 +5079,7 @@ fn _0545() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_context_by_rhs_range",
     &CTX,
-    r#"null([division] incompatible types: {a: 10} / [1..10])"#,
+    r#"null([division] incompatible types: {a: 10} / [1..10] (at 1:1..1:18))"#,
   );
 }
 
@@ -5090,7 +5090,7 @@ fn _0546() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_context_by_rhs_function",
     &CTX,
-    r#"null([division] incompatible types: {a: 10} / FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any))"#,
+    r#"null([division] incompatible types: {a: 10} / FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) (at 1:1..1:26))"#,
   );
 }
 
@@ -5101,7 +5101,7 @@ fn _0547() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_context_by_rhs_null",
     &CTX,
-    r#"null([division] incompatible types: {a: 10} / null)"#,
+    r#"null([division] incompatible types: {a: 10} / null (at 1:1..1:15))"#,
   );
 }
 
@@ -5112,7 +5112,7 @@ fn _0548() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_dtDuration_by_rhs_string",
     &CTX,
-    r#"null([division] incompatible types: P1D / "10")"#,
+    r#"null([division] incompatible types: P1D / "10" (at 1:1..1:14))"#,
   );
 }
 
@@ -5123,7 +5123,7 @@ fn _0549() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_dtDuration_by_rhs_boolean",
     &CTX,
-    r#"null([division] incompatible types: P1D / true)"#,
+    r#"null([division] incompatible types: P1D / true (at 1:1..1:14))"#,
   );
 }
 
@@ -5134,7 +5134,7 @@ fn _0550() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_dtDuration_by_rhs_date",
     &CTX,
-    r#"null([division] incompatible types: P1D / 2021-01-01)"#,
+    r#"null([division] incompatible types: P1D / 2021-01-01 (at 1:1..1:23))"#,
   );
 }
 
@@ -5145,7 +5145,7 @@ fn _0551() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_dtDuration_by_rhs_dateAndTime",
     &CTX,
-    r#"null([division] incompatible types: P1D / 2021-01-01T10:10:10)"#,
+    r#"null([division] incompatible types: P1D / 2021-01-01T10:10:10 (at 1:1..1:32))"#,
   );
 }
 
@@ -5156,7 +5156,7 @@ fn _0552() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_dtDuration_by_rhs_time",
     &CTX,
-    r#"null([division] incompatible types: P1D / 10:10:10)"#,
+    r#"null([division] incompatible types: P1D / 10:10:10 (at 1:1..1:21))"#,
   );
 }
 
@@ -5167,7 +5167,7 @@ fn _0553() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_dtDuration_by_rhs_list",
     &CTX,
-    r#"null([division] incompatible types: P1D / [10])"#,
+    r#"null([division] incompatible types: P1D / [10] (at 1:1..1:14))"#,
   );
 }
 
@@ -5178,7 +5178,7 @@ fn _0554() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_dtDuration_by_rhs_context",
     &CTX,
-    r#"null([division] incompatible types: P1D / {a: 10})"#,
+    r#"null([division] incompatible types: P1D / {a: 10} (at 1:1..1:17))"#,
   );
 }
 
@@ -5189,7 +5189,7 @@ fn _0555() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_dtDuration_by_rhs_ymDuration",
     &CTX,
-    r#"null([division] incompatible types: P1D / P1Y)"#,
+    r#"null([division] incompatible types: P1D / P1Y (at 1:1..1:16))"#,
   );
 }
 
@@ -5200,7 +5200,7 @@ fn _0556() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_dtDuration_by_rhs_range",
     &CTX,
-    r#"null([division] incompatible types: P1D / [1..10])"#,
+    r#"null([division] incompatible types: P1D / [1..10] (at 1:1..1:17))"#,
   );
 }
 
@@ -5211,7 +5211,7 @@ fn _0557() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_dtDuration_by_rhs_function",
     &CTX,
-    r#"null([division] incompatible types: P1D / FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any))"#,
+    r#"null([division] incompatible types: P1D / FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) (at 1:1..1:25))"#,
   );
 }
 
@@ -5222,7 +5222,7 @@ fn _0558() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_dtDuration_by_rhs_null",
     &CTX,
-    r#"null([division] incompatible types: P1D / null)"#,
+    r#"null([division] incompatible types: P1D / null (at 1:1..1:14))"#,
   );
 }
 
@@ -5233,7 +5233,7 @@ fn _0559() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_ymDuration_by_rhs_string",
     &CTX,
-    r#"null([division] incompatible types: P1Y / "10")"#,
+    r#"null([division] incompatible types: P1Y / "10" (at 1:1..1:14))"#,
   );
 }
 
@@ -5244,7 +5244,7 @@ fn _0560() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_ymDuration_by_rhs_boolean",
     &CTX,
-    r#"null([division] incompatible types: P1Y / true)"#,
+    r#"null([division] incompatible types: P1Y / true (at 1:1..1:14))"#,
   );
 }
 
@@ -5255,7 +5255,7 @@ fn _0561() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_ymDuration_by_rhs_date",
     &CTX,
-    r#"null([division] incompatible types: P1Y / 2021-01-01)"#,
+    r#"null([division] incompatible types: P1Y / 2021-01-01 (at 1:1..1:23))"#,
   );
 }
 
@@ -5266,7 +5266,7 @@ fn _0562() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_ymDuration_by_rhs_dateAndTime",
     &CTX,
-    r#"null([division] incompatible types: P1Y / 2021-01-01T10:10:10)"#,
+    r#"null([division] incompatible types: P1Y / 2021-01-01T10:10:10 (at 1:1..1:32))"#,
   );
 }
 
@@ -5277,7 +5277,7 @@ fn _0563() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_ymDuration_by_rhs_time",
     &CTX,
-    r#"null([division] incompatible types: P1Y / 10:10:10)"#,
+    r#"null([division] incompatible types: P1Y / 10:10:10 (at 1:1..1:21))"#,
   );
 }
 
@@ -5288,7 +5288,7 @@ fn _0564() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_ymDuration_by_rhs_list",
     &CTX,
-    r#"null([division] incompatible types: P1Y / [10])"#,
+    r#"null([division] incompatible types: P1Y / [10] (at 1:1..1:14))"#,
   );
 }
 
@@ -5299,7 +5299,7 @@ fn _0565() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_ymDuration_by_rhs_context",
     &CTX,
-    r#"null([division] incompatible types: P1Y / {a: 10})"#,
+    r#"null([division] incompatible types: P1Y / {a: 10} (at 1:1..1:17))"#,
   );
 }
 
@@ -5310,7 +5310,7 @@ fn _0566() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_ymDuration_by_rhs_dtDuration",
     &CTX,
-    r#"null([division] incompatible types: P1Y / P1D)"#,
+    r#"null([division] incompatible types: P1Y / P1D (at 1:1..1:16))"#,
   );
 }
 
@@ -5321,7 +5321,7 @@ fn _0567() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_ymDuration_by_rhs_range",
     &CTX,
-    r#"null([division] incompatible types: P1Y / [1..10])"#,
+    r#"null([division] incompatible types: P1Y / [1..10] (at 1:1..1:17))"#,
   );
 }
 
@@ -5332,7 +5332,7 @@ fn _0568() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_ymDuration_by_rhs_function",
     &CTX,
-    r#"null([division] incompatible types: P1Y / FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any))"#,
+    r#"null([division] incompatible types: P1Y / FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) (at 1:1..1:25))"#,
   );
 }
 
@@ -5343,7 +5343,7 @@ fn _0569() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_ymDuration_by_rhs_null",
     &CTX,
-    r#"null([division] incompatible types: P1Y / null)"#,
+    r#"null([division] incompatible types: P1Y / null (at 1:1..1:14))"#,
   );
 }
 
@@ -5354,7 +5354,7 @@ fn _0570() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_range_by_rhs_number",
     &CTX,
-    r#"null([division] incompatible types: [1..10] / 10)"#,
+    r#"null([division] incompatible types: [1..10] / 10 (at 1:1..1:13))"#,
   );
 }
 
@@ -5365,7 +5365,7 @@ fn _0571() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_range_by_rhs_string",
     &CTX,
-    r#"null([division] incompatible types: [1..10] / "10")"#,
+    r#"null([division] incompatible types: [1..10] / "10" (at 1:1..1:15))"#,
   );
 }
 
@@ -5376,7 +5376,7 @@ fn _0572() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_range_by_rhs_boolean",
     &CTX,
-    r#"null([division] incompatible types: [1..10] / true)"#,
+    r#"null([division] incompatible types: [1..10] / true (at 1:1..1:15))"#,
   );
 }
 
@@ -5387,7 +5387,7 @@ fn _0573() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_range_by_rhs_date",
     &CTX,
-    r#"null([division] incompatible types: [1..10] / 2021-01-01)"#,
+    r#"null([division] incompatible types: [1..10] / 2021-01-01 (at 1:1..1:24))"#,
   );
 }
 
@@ -5398,7 +5398,7 @@ fn _0574() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_range_by_rhs_dateAndTime",
     &CTX,
-    r#"null([division] incompatible types: [1..10] / 2021-01-01T10:10:10)"#,
+    r#"null([division] incompatible types: [1..10] / 2021-01-01T10:10:10 (at 1:1..1:33))"#,
   );
 }
 
@@ -5409,7 +5409,7 @@ fn _0575() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_range_by_rhs_time",
     &CTX,
-    r#"null([division] incompatible types: [1..10] / 10:10:10)"#,
+    r#"null([division] incompatible types: [1..10] / 10:10:10 (at 1:1..1:22))"#,
   );
 }
 
@@ -5420,7 +5420,7 @@ fn _0576() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_range_by_rhs_list",
     &CTX,
-    r#"null([division] incompatible types: [1..10] / [10])"#,
+    r#"null([division] incompatible types: [1..10] / [10] (at 1:1..1:15))"#,
   );
 }
 
@@ -5431,7 +5431,7 @@ fn _0577() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_range_by_rhs_context",
     &CTX,
-    r#"null([division] incompatible types: [1..10] / {a: 10})"#,
+    r#"null([division] incompatible types: [1..10] / {a: 10} (at 1:1..1:18))"#,
   );
 }
 
@@ -5442,7 +5442,7 @@ fn _0578() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_range_by_rhs_dtDuration",
     &CTX,
-    r#"null([division] incompatible types: [1..10] / P1D)"#,
+    r#"null([division] incompatible types: [1..10] / P1D (at 1:1..1:17))"#,
   );
 }
 
@@ -5453,7 +5453,7 @@ fn _0579() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_range_by_rhs_ymDuration",
     &CTX,
-    r#"null([division] incompatible types: [1..10] / P1Y)"#,
+    r#"null([division] incompatible types: [1..10] / P1Y (at 1:1..1:17))"#,
   );
 }
 
@@ -5464,7 +5464,7 @@ fn _0580() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_range_by_rhs_range",
     &CTX,
-    r#"null([division] incompatible types: [1..10] / [1..10])"#,
+    r#"null([division] incompatible types: [1..10] / [1..10] (at 1:1..1:18))"#,
   );
 }
 
@@ -5475,7 +5475,7 @@ fn _0581() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_range_by_rhs_function",
     &CTX,
-    r#"null([division] incompatible types: [1..10] / FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any))"#,
+    r#"null([division] incompatible types: [1..10] / FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) (at 1:1..1:26))"#,
   );
 }
 
@@ -5486,7 +5486,7 @@ fn _0582() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_range_by_rhs_null",
     &CTX,
-    r#"null([division] incompatible types: [1..10] / null)"#,
+    r#"null([division] incompatible types: [1..10] / null (at 1:1..1:15))"#,
   );
 }
 
@@ -5497,7 +5497,7 @@ fn _0583() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_function_by_rhs_number",
     &CTX,
-    r#"null([division] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) / 10)"#,
+    r#"null([division] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) / 10 (at 1:1..1:21))"#,
   );
 }
 
@@ -5508,7 +5508,7 @@ fn _0584() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_function_by_rhs_string",
     &CTX,
-    r#"null([division] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) / "10")"#,
+    r#"null([division] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) / "10" (at 1:1..1:23))"#,
   );
 }
 
@@ -5519,7 +5519,7 @@ fn _0585() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_function_by_rhs_boolean",
     &CTX,
-    r#"null([division] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) / true)"#,
+    r#"null([division] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) / true (at 1:1..1:23))"#,
   );
 }
 
@@ -5530,7 +5530,7 @@ fn _0586() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_function_by_rhs_date",
     &CTX,
-    r#"null([division] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) / 2021-01-01)"#,
+    r#"null([division] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) / 2021-01-01 (at 1:1..1:32))"#,
   );
 }
 
@@ -5541,7 +5541,7 @@ fn _0587() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_function_by_rhs_dateAndTime",
     &CTX,
-    r#"null([division] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) / 2021-01-01T10:10:10)"#,
+    r#"null([division] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) / 2021-01-01T10:10:10 (at 1:1..1:41))"#,
   );
 }
 
@@ -5552,7 +5552,7 @@ fn _0588() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_function_by_rhs_time",
     &CTX,
-    r#"null([division] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) / 10:10:10)"#,
+    r#"null([division] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) / 10:10:10 (at 1:1..1:30))"#,
   );
 }
 
@@ -5563,7 +5563,7 @@ fn _0589() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_function_by_rhs_list",
     &CTX,
-    r#"null([division] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) / [10])"#,
+    r#"null([division] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) / [10] (at 1:1..1:23))"#,
   );
 }
 
@@ -5574,7 +5574,7 @@ fn _0590() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_function_by_rhs_context",
     &CTX,
-    r#"null([division] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) / {a: 10})"#,
+    r#"null([division] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) / {a: 10} (at 1:1..1:26))"#,
   );
 }
 
@@ -5585,7 +5585,7 @@ fn _0591() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_function_by_rhs_dtDuration",
     &CTX,
-    r#"null([division] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) / P1D)"#,
+    r#"null([division] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) / P1D (at 1:1..1:25))"#,
   );
 }
 
@@ -5596,7 +5596,7 @@ fn _0592() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_function_by_rhs_ymDuration",
     &CTX,
-    r#"null([division] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) / P1Y)"#,
+    r#"null([division] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) / P1Y (at 1:1..1:25))"#,
   );
 }
 
@@ -5607,7 +5607,7 @@ fn _0593() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_function_by_rhs_range",
     &CTX,
-    r#"null([division] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) / [1..10])"#,
+    r#"null([division] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) / [1..10] (at 1:1..1:26))"#,
   );
 }
 
@@ -5618,7 +5618,7 @@ fn _0594() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_function_by_rhs_function",
     &CTX,
-    r#"null([division] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) / FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any))"#,
+    r#"null([division] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) / FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) (at 1:1..1:34))"#,
   );
 }
 
@@ -5629,7 +5629,7 @@ fn _0595() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_function_by_rhs_null",
     &CTX,
-    r#"null([division] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) / null)"#,
+    r#"null([division] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) / null (at 1:1..1:23))"#,
   );
 }
 
@@ -5640,7 +5640,7 @@ fn _0596() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_null_by_rhs_number",
     &CTX,
-    r#"null([division] incompatible types: null / 10)"#,
+    r#"null([division] incompatible types: null / 10 (at 1:1..1:10))"#,
   );
 }
 
@@ -5651,7 +5651,7 @@ fn _0597() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_null_by_rhs_string",
     &CTX,
-    r#"null([division] incompatible types: null / "10")"#,
+    r#"null([division] incompatible types: null / "10" (at 1:1..1:12))"#,
   );
 }
 
@@ -5662,7 +5662,7 @@ fn _0598() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_null_by_rhs_boolean",
     &CTX,
-    r#"null([division] incompatible types: null / true)"#,
+    r#"null([division] incompatible types: null / true (at 1:1..1:12))"#,
   );
 }
 
@@ -5673,7 +5673,7 @@ fn _0599() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_null_by_rhs_date",
     &CTX,
-    r#"null([division] incompatible types: null / 2021-01-01)"#,
+    r#"null([division] incompatible types: null / 2021-01-01 (at 1:1..1:21))"#,
   );
 }
 
@@ -5684,7 +5684,7 @@ fn _0600() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_null_by_rhs_dateAndTime",
     &CTX,
-    r#"null([division] incompatible types: null / 2021-01-01T10:10:10)"#,
+    r#"null([division] incompatible types: null / 2021-01-01T10:10:10 (at 1:1..1:30))"#,
   );
 }
 
@@ -5695,7 +5695,7 @@ fn _0601() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_null_by_rhs_time",
     &CTX,
-    r#"null([division] incompatible types: null / 10:10:10)"#,
+    r#"null([division] incompatible types: null / 10:10:10 (at 1:1..1:19))"#,
   );
 }
 
@@ -5706,7 +5706,7 @@ fn _0602() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_null_by_rhs_list",
     &CTX,
-    r#"null([division] incompatible types: null / [10])"#,
+    r#"null([division] incompatible types: null / [10] (at 1:1..1:12))"#,
   );
 }
 
@@ -5717,7 +5717,7 @@ fn _0603() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_null_by_rhs_context",
     &CTX,
-    r#"null([division] incompatible types: null / {a: 10})"#,
+    r#"null([division] incompatible types: null / {a: 10} (at 1:1..1:15))"#,
   );
 }
 
@@ -5728,7 +5728,7 @@ fn _0604() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_null_by_rhs_dtDuration",
     &CTX,
-    r#"null([division] incompatible types: null / P1D)"#,
+    r#"null([division] incompatible types: null / P1D (at 1:1..1:14))"#,
   );
 }
 
@@ -5739,7 +5739,7 @@ fn _0605() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_null_by_rhs_ymDuration",
     &CTX,
-    r#"null([division] incompatible types: null / P1Y)"#,
+    r#"null([division] incompatible types: null / P1Y (at 1:1..1:14))"#,
   );
 }
 
@@ -5750,7 +5750,7 @@ fn _0606() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_null_by_rhs_range",
     &CTX,
-    r#"null([division] incompatible types: null / [1..10])"#,
+    r#"null([division] incompatible types: null / [1..10] (at 1:1..1:15))"#,
   );
 }
 
@@ -5761,7 +5761,7 @@ fn _0607() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_null_by_rhs_function",
     &CTX,
-    r#"null([division] incompatible types: null / FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any))"#,
+    r#"null([division] incompatible types: null / FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) (at 1:1..1:23))"#,
   );
 }
 
@@ -5772,7 +5772,7 @@ fn _0608() {
     &MODEL_NAMESPACE,
     "error_when_divide_lhs_null_by_rhs_null",
     &CTX,
-    r#"null([division] incompatible types: null / null)"#,
+    r#"null([division] incompatible types: null / null (at 1:1..1:12))"#,
   );
 }
 
@@ -5783,7 +5783,7 @@ fn _0609() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_number_to_rhs_string",
     &CTX,
-    r#"null(incompatible types in addition: 10(number) + "10"(string))"#,
+    r#"null(incompatible types in addition: 10(number) + "10"(string) (at 1:1..1:10))"#,
   );
 }
 
@@ -5794,7 +5794,7 @@ fn _0610() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_number_to_rhs_boolean",
     &CTX,
-    r#"null(incompatible types in addition: 10(number) + true(boolean))"#,
+    r#"null(incompatible types in addition: 10(number) + true(boolean) (at 1:1..1:10))"#,
   );
 }
 
@@ -5805,7 +5805,7 @@ fn _0611() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_number_to_rhs_date",
     &CTX,
-    r#"null(incompatible types in addition: 10(number) + 2021-01-01(date))"#,
+    r#"null(incompatible types in addition: 10(number) + 2021-01-01(date) (at 1:1..1:19))"#,
   );
 }
 
@@ -5816,7 +5816,7 @@ fn _0612() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_number_to_rhs_dateAndTime",
     &CTX,
-    r#"null(incompatible types in addition: 10(number) + 2021-01-01T10:10:10(date and time))"#,
+    r#"null(incompatible types in addition: 10(number) + 2021-01-01T10:10:10(date and time) (at 1:1..1:28))"#,
   );
 }
 
@@ -5827,7 +5827,7 @@ fn _0613() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_number_to_rhs_time",
     &CTX,
-    r#"null(incompatible types in addition: 10(number) + 10:10:10(time))"#,
+    r#"null(incompatible types in addition: 10(number) + 10:10:10(time) (at 1:1..1:17))"#,
   );
 }
 
@@ -5838,7 +5838,7 @@ fn _0614() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_number_to_rhs_list",
     &CTX,
-    r#"null(incompatible types in addition: 10(number) + [10](list<number>))"#,
+    r#"null(incompatible types in addition: 10(number) + [10](list<number>) (at 1:1..1:10))"#,
   );
 }
 
@@ -5849,7 +5849,7 @@ fn _0615() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_number_to_rhs_context",
     &CTX,
-    r#"null(incompatible types in addition: 10(number) + {a: 10}(context<a: number>))"#,
+    r#"null(incompatible types in addition: 10(number) + {a: 10}(context<a: number>) (at 1:1..1:13))"#,
   );
 }
 
@@ -5860,7 +5860,7 @@ fn _0616() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_number_to_rhs_dtDuration",
     &CTX,
-    r#"null(incompatible types in addition: 10(number) + P1D(days and time duration))"#,
+    r#"null(incompatible types in addition: 10(number) + P1D(days and time duration) (at 1:1..1:12))"#,
   );
 }
 
@@ -5871,7 +5871,7 @@ fn _0617() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_number_to_rhs_ymDuration",
     &CTX,
-    r#"null(incompatible types in addition: 10(number) + P1Y(years and months duration))"#,
+    r#"null(incompatible types in addition: 10(number) + P1Y(years and months duration) (at 1:1..1:12))"#,
   );
 }
 
@@ -5882,7 +5882,7 @@ fn _0618() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_number_to_rhs_range",
     &CTX,
-    r#"null(incompatible types in addition: 10(number) + [1..10](range<number>))"#,
+    r#"null(incompatible types in addition: 10(number) + [1..10](range<number>) (at 1:1..1:13))"#,
   );
 }
 
@@ -5893,7 +5893,7 @@ fn _0619() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_number_to_rhs_function",
     &CTX,
-    r#"null(incompatible types in addition: 10(number) + FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any)(function<Any>->Any))"#,
+    r#"null(incompatible types in addition: 10(number) + FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any)(function<Any>->Any) (at 1:1..1:21))"#,
   );
 }
 
@@ -5909,7 +5909,7 @@ fn _0621() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_string_to_rhs_number",
     &CTX,
-    r#"null(expected string as a second argument in addition)"#,
+    r#"null(expected string as a second argument in addition (at 1:1..1:10))"#,
   );
 }
 
@@ -5920,7 +5920,7 @@ fn _0622() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_string_to_rhs_boolean",
     &CTX,
-    r#"null(expected string as a second argument in addition)"#,
+    r#"null(expected string as a second argument in addition (at 1:1..1:12))"#,
   );
 }
 
@@ -5931,7 +5931,7 @@ fn _0623() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_string_to_rhs_date",
     &CTX,
-    r#"null(expected string as a second argument in addition)"#,
+    r#"null(expected string as a second argument in addition (at 1:1..1:21))"#,
   );
 }
 
@@ -5942,7 +5942,7 @@ fn _0624() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_string_to_rhs_dateAndTime",
     &CTX,
-    r#"null(expected string as a second argument in addition)"#,
+    r#"null(expected string as a second argument in addition (at 1:1..1:30))"#,
   );
 }
 
@@ -5953,7 +5953,7 @@ fn _0625() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_string_to_rhs_time",
     &CTX,
-    r#"null(expected string as a second argument in addition)"#,
+    r#"null(expected string as a second argument in addition (at 1:1..1:19))"#,
   );
 }
 
@@ -5964,7 +5964,7 @@ fn _0626() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_string_to_rhs_list",
     &CTX,
-    r#"null(expected string as a second argument in addition)"#,
+    r#"null(expected string as a second argument in addition (at 1:1..1:12))"#,
   );
 }
 
@@ -5975,7 +5975,7 @@ fn _0627() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_string_to_rhs_context",
     &CTX,
-    r#"null(expected string as a second argument in addition)"#,
+    r#"null(expected string as a second argument in addition (at 1:1..1:15))"#,
   );
 }
 
@@ -5986,7 +5986,7 @@ fn _0628() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_string_to_rhs_dtDuration",
     &CTX,
-    r#"null(expected string as a second argument in addition)"#,
+    r#"null(expected string as a second argument in addition (at 1:1..1:14))"#,
   );
 }
 
@@ -5997,7 +5997,7 @@ fn _0629() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_string_to_rhs_ymDuration",
     &CTX,
-    r#"null(expected string as a second argument in addition)"#,
+    r#"null(expected string as a second argument in addition (at 1:1..1:14))"#,
   );
 }
 
@@ -6008,7 +6008,7 @@ fn _0630() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_string_to_rhs_range",
     &CTX,
-    r#"null(expected string as a second argument in addition)"#,
+    r#"null(expected string as a second argument in addition (at 1:1..1:15))"#,
   );
 }
 
@@ -6019,7 +6019,7 @@ fn _0631() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_string_to_rhs_function",
     &CTX,
-    r#"null(expected string as a second argument in addition)"#,
+    r#"null(expected string as a second argument in addition (at 1:1..1:23))"#,
   );
 }
 
@@ -6030,7 +6030,7 @@ fn _0632() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_string_to_rhs_null",
     &CTX,
-    r#"null(expected string as a second argument in addition)"#,
+    r#"null(expected string as a second argument in addition (at 1:1..1:12))"#,
   );
 }
 
@@ -6041,7 +6041,7 @@ fn _0633() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_boolean_to_rhs_number",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is boolean)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is boolean (at 1:1..1:10))"#,
   );
 }
 
@@ -6052,7 +6052,7 @@ fn _0634() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_boolean_to_rhs_string",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is boolean)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is boolean (at 1:1..1:12))"#,
   );
 }
 
@@ -6063,7 +6063,7 @@ fn _0635() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_boolean_to_rhs_boolean",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is boolean)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is boolean (at 1:1..1:12))"#,
   );
 }
 
@@ -6074,7 +6074,7 @@ fn _0636() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_boolean_to_rhs_date",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is boolean)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is boolean (at 1:1..1:21))"#,
   );
 }
 
@@ -6085,7 +6085,7 @@ fn _0637() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_boolean_to_rhs_dateAndTime",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is boolean)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is boolean (at 1:1..1:30))"#,
   );
 }
 
@@ -6096,7 +6096,7 @@ fn _0638() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_boolean_to_rhs_time",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is boolean)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is boolean (at 1:1..1:19))"#,
   );
 }
 
@@ -6107,7 +6107,7 @@ fn _0639() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_boolean_to_rhs_list",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is boolean)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is boolean (at 1:1..1:12))"#,
   );
 }
 
@@ -6118,7 +6118,7 @@ fn _0640() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_boolean_to_rhs_context",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is boolean)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is boolean (at 1:1..1:15))"#,
   );
 }
 
@@ -6129,7 +6129,7 @@ fn _0641() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_boolean_to_rhs_dtDuration",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is boolean)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is boolean (at 1:1..1:14))"#,
   );
 }
 
@@ -6140,7 +6140,7 @@ fn _0642() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_boolean_to_rhs_ymDuration",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is boolean)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is boolean (at 1:1..1:14))"#,
   );
 }
 
@@ -6151,7 +6151,7 @@ fn _0643() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_boolean_to_rhs_range",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is boolean)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is boolean (at 1:1..1:15))"#,
   );
 }
 
@@ -6162,7 +6162,7 @@ fn _0644() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_boolean_to_rhs_function",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is boolean)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is boolean (at 1:1..1:23))"#,
   );
 }
 
@@ -6173,7 +6173,7 @@ fn _0645() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_boolean_to_rhs_null",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is boolean)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is boolean (at 1:1..1:12))"#,
   );
 }
 
@@ -6184,7 +6184,7 @@ fn _0646() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_date_to_rhs_number",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected years and months duration, actual type is number)"#,
+    r#"null([builders::add] invalid argument type, expected years and months duration, actual type is number (at 1:1..1:19))"#,
   );
 }
 
@@ -6195,7 +6195,7 @@ fn _0647() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_date_to_rhs_string",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected years and months duration, actual type is string)"#,
+    r#"null([builders::add] invalid argument type, expected years and months duration, actual type is string (at 1:1..1:21))"#,
   );
 }
 
@@ -6206,7 +6206,7 @@ fn _0648() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_date_to_rhs_boolean",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected years and months duration, actual type is boolean)"#,
+    r#"null([builders::add] invalid argument type, expected years and months duration, actual type is boolean (at 1:1..1:21))"#,
   );
 }
 
@@ -6217,7 +6217,7 @@ fn _0649() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_date_to_rhs_date",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected years and months duration, actual type is date)"#,
+    r#"null([builders::add] invalid argument type, expected years and months duration, actual type is date (at 1:1..1:30))"#,
   );
 }
 
@@ -6228,7 +6228,7 @@ fn _0650() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_date_to_rhs_dateAndTime",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected years and months duration, actual type is date and time)"#,
+    r#"null([builders::add] invalid argument type, expected years and months duration, actual type is date and time (at 1:1..1:39))"#,
   );
 }
 
@@ -6239,7 +6239,7 @@ fn _0651() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_date_to_rhs_time",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected years and months duration, actual type is time)"#,
+    r#"null([builders::add] invalid argument type, expected years and months duration, actual type is time (at 1:1..1:28))"#,
   );
 }
 
@@ -6250,7 +6250,7 @@ fn _0652() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_date_to_rhs_list",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected years and months duration, actual type is list<number>)"#,
+    r#"null([builders::add] invalid argument type, expected years and months duration, actual type is list<number> (at 1:1..1:21))"#,
   );
 }
 
@@ -6261,7 +6261,7 @@ fn _0653() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_date_to_rhs_context",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected years and months duration, actual type is context<a: number>)"#,
+    r#"null([builders::add] invalid argument type, expected years and months duration, actual type is context<a: number> (at 1:1..1:24))"#,
   );
 }
 
@@ -6272,7 +6272,7 @@ fn _0654() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_date_to_rhs_range",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected years and months duration, actual type is range<number>)"#,
+    r#"null([builders::add] invalid argument type, expected years and months duration, actual type is range<number> (at 1:1..1:24))"#,
   );
 }
 
@@ -6283,7 +6283,7 @@ fn _0655() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_date_to_rhs_function",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected years and months duration, actual type is function<Any>->Any)"#,
+    r#"null([builders::add] invalid argument type, expected years and months duration, actual type is function<Any>->Any (at 1:1..1:32))"#,
   );
 }
 
@@ -6294,7 +6294,7 @@ fn _0656() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_date_to_rhs_null",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected years and months duration, actual type is Null)"#,
+    r#"null([builders::add] invalid argument type, expected years and months duration, actual type is Null (at 1:1..1:21))"#,
   );
 }
 
@@ -6305,7 +6305,7 @@ fn _0657() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_dateAndTime_to_rhs_number",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected days and time duration, years and months duration, actual type is number)"#,
+    r#"null([builders::add] invalid argument type, expected days and time duration, years and months duration, actual type is number (at 1:1..1:28))"#,
   );
 }
 
@@ -6316,7 +6316,7 @@ fn _0658() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_dateAndTime_to_rhs_string",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected days and time duration, years and months duration, actual type is string)"#,
+    r#"null([builders::add] invalid argument type, expected days and time duration, years and months duration, actual type is string (at 1:1..1:30))"#,
   );
 }
 
@@ -6327,7 +6327,7 @@ fn _0659() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_dateAndTime_to_rhs_boolean",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected days and time duration, years and months duration, actual type is boolean)"#,
+    r#"null([builders::add] invalid argument type, expected days and time duration, years and months duration, actual type is boolean (at 1:1..1:30))"#,
   );
 }
 
@@ -6338,7 +6338,7 @@ fn _0660() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_dateAndTime_to_rhs_date",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected days and time duration, years and months duration, actual type is date)"#,
+    r#"null([builders::add] invalid argument type, expected days and time duration, years and months duration, actual type is date (at 1:1..1:39))"#,
   );
 }
 
@@ -6349,7 +6349,7 @@ fn _0661() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_dateAndTime_to_rhs_dateAndTime",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected days and time duration, years and months duration, actual type is date and time)"#,
+    r#"null([builders::add] invalid argument type, expected days and time duration, years and months duration, actual type is date and time (at 1:1..1:48))"#,
   );
 }
 
@@ -6360,7 +6360,7 @@ fn _0662() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_dateAndTime_to_rhs_time",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected days and time duration, years and months duration, actual type is time)"#,
+    r#"null([builders::add] invalid argument type, expected days and time duration, years and months duration, actual type is time (at 1:1..1:37))"#,
   );
 }
 
@@ -6371,7 +6371,7 @@ fn _0663() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_dateAndTime_to_rhs_list",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected days and time duration, years and months duration, actual type is list<number>)"#,
+    r#"null([builders::add] invalid argument type, expected days and time duration, years and months duration, actual type is list<number> (at 1:1..1:30))"#,
   );
 }
 
@@ -6382,7 +6382,7 @@ fn _0664() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_dateAndTime_to_rhs_context",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected days and time duration, years and months duration, actual type is context<a: number>)"#,
+    r#"null([builders::add] invalid argument type, expected days and time duration, years and months duration, actual type is context<a: number> (at 1:1..1:33))"#,
   );
 }
 
@@ -6393,7 +6393,7 @@ fn _0665() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_dateAndTime_to_rhs_range",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected days and time duration, years and months duration, actual type is range<number>)"#,
+    r#"null([builders::add] invalid argument type, expected days and time duration, years and months duration, actual type is range<number> (at 1:1..1:33))"#,
   );
 }
 
@@ -6404,7 +6404,7 @@ fn _0666() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_dateAndTime_to_rhs_function",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected days and time duration, years and months duration, actual type is function<Any>->Any)"#,
+    r#"null([builders::add] invalid argument type, expected days and time duration, years and months duration, actual type is function<Any>->Any (at 1:1..1:41))"#,
   );
 }
 
@@ -6415,7 +6415,7 @@ fn _0667() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_dateAndTime_to_rhs_null",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected days and time duration, years and months duration, actual type is Null)"#,
+    r#"null([builders::add] invalid argument type, expected days and time duration, years and months duration, actual type is Null (at 1:1..1:30))"#,
   );
 }
 
@@ -6426,7 +6426,7 @@ fn _0668() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_time_to_rhs_number",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected days and time duration, actual type is number)"#,
+    r#"null([builders::add] invalid argument type, expected days and time duration, actual type is number (at 1:1..1:17))"#,
   );
 }
 
@@ -6437,7 +6437,7 @@ fn _0669() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_time_to_rhs_string",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected days and time duration, actual type is string)"#,
+    r#"null([builders::add] invalid argument type, expected days and time duration, actual type is string (at 1:1..1:19))"#,
   );
 }
 
@@ -6448,7 +6448,7 @@ fn _0670() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_time_to_rhs_boolean",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected days and time duration, actual type is boolean)"#,
+    r#"null([builders::add] invalid argument type, expected days and time duration, actual type is boolean (at 1:1..1:19))"#,
   );
 }
 
@@ -6459,7 +6459,7 @@ fn _0671() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_time_to_rhs_date",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected days and time duration, actual type is date)"#,
+    r#"null([builders::add] invalid argument type, expected days and time duration, actual type is date (at 1:1..1:28))"#,
   );
 }
 
@@ -6470,7 +6470,7 @@ fn _0672() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_time_to_rhs_dateAndTime",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected days and time duration, actual type is date and time)"#,
+    r#"null([builders::add] invalid argument type, expected days and time duration, actual type is date and time (at 1:1..1:37))"#,
   );
 }
 
@@ -6481,7 +6481,7 @@ fn _0673() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_time_to_rhs_time",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected days and time duration, actual type is time)"#,
+    r#"null([builders::add] invalid argument type, expected days and time duration, actual type is time (at 1:1..1:26))"#,
   );
 }
 
@@ -6492,7 +6492,7 @@ fn _0674() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_time_to_rhs_list",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected days and time duration, actual type is list<number>)"#,
+    r#"null([builders::add] invalid argument type, expected days and time duration, actual type is list<number> (at 1:1..1:19))"#,
   );
 }
 
@@ -6503,7 +6503,7 @@ fn _0675() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_time_to_rhs_context",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected days and time duration, actual type is context<a: number>)"#,
+    r#"null([builders::add] invalid argument type, expected days and time duration, actual type is context<a: number> (at 1:1..1:22))"#,
   );
 }
 
@@ -6514,7 +6514,7 @@ fn _0676() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_time_to_rhs_ymDuration",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected days and time duration, actual type is years and months duration)"#,
+    r#"null([builders::add] invalid argument type, expected days and time duration, actual type is years and months duration (at 1:1..1:21))"#,
   );
 }
 
@@ -6525,7 +6525,7 @@ fn _0677() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_time_to_rhs_range",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected days and time duration, actual type is range<number>)"#,
+    r#"null([builders::add] invalid argument type, expected days and time duration, actual type is range<number> (at 1:1..1:22))"#,
   );
 }
 
@@ -6536,7 +6536,7 @@ fn _0678() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_time_to_rhs_function",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected days and time duration, actual type is function<Any>->Any)"#,
+    r#"null([builders::add] invalid argument type, expected days and time duration, actual type is function<Any>->Any (at 1:1..1:30))"#,
   );
 }
 
@@ -6547,7 +6547,7 @@ fn _0679() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_time_to_rhs_null",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected days and time duration, actual type is Null)"#,
+    r#"null([builders::add] invalid argument type, expected days and time duration, actual type is Null (at 1:1..1:19))"#,
   );
 }
 
@@ -6558,7 +6558,7 @@ fn _0680() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_list_to_rhs_number",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is list<number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is list<number> (at 1:1..1:10))"#,
   );
 }
 
@@ -6569,7 +6569,7 @@ fn _0681() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_list_to_rhs_string",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is list<number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is list<number> (at 1:1..1:12))"#,
   );
 }
 
@@ -6580,7 +6580,7 @@ fn _0682() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_list_to_rhs_boolean",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is list<number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is list<number> (at 1:1..1:12))"#,
   );
 }
 
@@ -6591,7 +6591,7 @@ fn _0683() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_list_to_rhs_date",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is list<number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is list<number> (at 1:1..1:21))"#,
   );
 }
 
@@ -6602,7 +6602,7 @@ fn _0684() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_list_to_rhs_dateAndTime",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is list<number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is list<number> (at 1:1..1:30))"#,
   );
 }
 
@@ -6613,7 +6613,7 @@ fn _0685() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_list_to_rhs_time",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is list<number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is list<number> (at 1:1..1:19))"#,
   );
 }
 
@@ -6624,7 +6624,7 @@ fn _0686() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_list_to_rhs_list",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is list<number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is list<number> (at 1:1..1:12))"#,
   );
 }
 
@@ -6635,7 +6635,7 @@ fn _0687() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_list_to_rhs_context",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is list<number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is list<number> (at 1:1..1:15))"#,
   );
 }
 
@@ -6646,7 +6646,7 @@ fn _0688() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_list_to_rhs_dtDuration",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is list<number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is list<number> (at 1:1..1:14))"#,
   );
 }
 
@@ -6657,7 +6657,7 @@ fn _0689() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_list_to_rhs_ymDuration",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is list<number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is list<number> (at 1:1..1:14))"#,
   );
 }
 
@@ -6668,7 +6668,7 @@ fn _0690() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_list_to_rhs_range",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is list<number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is list<number> (at 1:1..1:15))"#,
   );
 }
 
@@ -6679,7 +6679,7 @@ fn _0691() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_list_to_rhs_function",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is list<number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is list<number> (at 1:1..1:23))"#,
   );
 }
 
@@ -6690,7 +6690,7 @@ fn _0692() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_list_to_rhs_null",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is list<number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is list<number> (at 1:1..1:12))"#,
   );
 }
 
@@ -6701,7 +6701,7 @@ fn _0693() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_context_to_rhs_number",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is context<a: number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is context<a: number> (at 1:1..1:13))"#,
   );
 }
 
@@ -6712,7 +6712,7 @@ fn _0694() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_context_to_rhs_string",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is context<a: number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is context<a: number> (at 1:1..1:15))"#,
   );
 }
 
@@ -6723,7 +6723,7 @@ fn _0695() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_context_to_rhs_boolean",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is context<a: number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is context<a: number> (at 1:1..1:15))"#,
   );
 }
 
@@ -6734,7 +6734,7 @@ fn _0696() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_context_to_rhs_date",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is context<a: number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is context<a: number> (at 1:1..1:24))"#,
   );
 }
 
@@ -6745,7 +6745,7 @@ fn _0697() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_context_to_rhs_dateAndTime",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is context<a: number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is context<a: number> (at 1:1..1:33))"#,
   );
 }
 
@@ -6756,7 +6756,7 @@ fn _0698() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_context_to_rhs_time",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is context<a: number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is context<a: number> (at 1:1..1:22))"#,
   );
 }
 
@@ -6767,7 +6767,7 @@ fn _0699() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_context_to_rhs_list",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is context<a: number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is context<a: number> (at 1:1..1:15))"#,
   );
 }
 
@@ -6778,7 +6778,7 @@ fn _0700() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_context_to_rhs_context",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is context<a: number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is context<a: number> (at 1:1..1:18))"#,
   );
 }
 
@@ -6789,7 +6789,7 @@ fn _0701() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_context_to_rhs_dtDuration",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is context<a: number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is context<a: number> (at 1:1..1:17))"#,
   );
 }
 
@@ -6800,7 +6800,7 @@ fn _0702() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_context_to_rhs_ymDuration",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is context<a: number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is context<a: number> (at 1:1..1:17))"#,
   );
 }
 
@@ -6811,7 +6811,7 @@ fn _0703() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_context_to_rhs_range",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is context<a: number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is context<a: number> (at 1:1..1:18))"#,
   );
 }
 
@@ -6822,7 +6822,7 @@ fn _0704() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_context_to_rhs_function",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is context<a: number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is context<a: number> (at 1:1..1:26))"#,
   );
 }
 
@@ -6833,7 +6833,7 @@ fn _0705() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_context_to_rhs_null",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is context<a: number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is context<a: number> (at 1:1..1:15))"#,
   );
 }
 
@@ -6844,7 +6844,7 @@ fn _0706() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_dtDuration_to_rhs_number",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected days and time duration, date and time, actual type is number)"#,
+    r#"null([builders::add] invalid argument type, expected days and time duration, date and time, actual type is number (at 1:1..1:12))"#,
   );
 }
 
@@ -6855,7 +6855,7 @@ fn _0707() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_dtDuration_to_rhs_string",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected days and time duration, date and time, actual type is string)"#,
+    r#"null([builders::add] invalid argument type, expected days and time duration, date and time, actual type is string (at 1:1..1:14))"#,
   );
 }
 
@@ -6866,7 +6866,7 @@ fn _0708() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_dtDuration_to_rhs_boolean",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected days and time duration, date and time, actual type is boolean)"#,
+    r#"null([builders::add] invalid argument type, expected days and time duration, date and time, actual type is boolean (at 1:1..1:14))"#,
   );
 }
 
@@ -6877,7 +6877,7 @@ fn _0709() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_dtDuration_to_rhs_list",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected days and time duration, date and time, actual type is list<number>)"#,
+    r#"null([builders::add] invalid argument type, expected days and time duration, date and time, actual type is list<number> (at 1:1..1:14))"#,
   );
 }
 
@@ -6888,7 +6888,7 @@ fn _0710() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_dtDuration_to_rhs_context",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected days and time duration, date and time, actual type is context<a: number>)"#,
+    r#"null([builders::add] invalid argument type, expected days and time duration, date and time, actual type is context<a: number> (at 1:1..1:17))"#,
   );
 }
 
@@ -6899,7 +6899,7 @@ fn _0711() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_dtDuration_to_rhs_ymDuration",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected days and time duration, date and time, actual type is years and months duration)"#,
+    r#"null([builders::add] invalid argument type, expected days and time duration, date and time, actual type is years and months duration (at 1:1..1:16))"#,
   );
 }
 
@@ -6910,7 +6910,7 @@ fn _0712() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_dtDuration_to_rhs_range",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected days and time duration, date and time, actual type is range<number>)"#,
+    r#"null([builders::add] invalid argument type, expected days and time duration, date and time, actual type is range<number> (at 1:1..1:17))"#,
   );
 }
 
@@ -6921,7 +6921,7 @@ fn _0713() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_dtDuration_to_rhs_function",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected days and time duration, date and time, actual type is function<Any>->Any)"#,
+    r#"null([builders::add] invalid argument type, expected days and time duration, date and time, actual type is function<Any>->Any (at 1:1..1:25))"#,
   );
 }
 
@@ -6932,7 +6932,7 @@ fn _0714() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_dtDuration_to_rhs_null",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected days and time duration, date and time, actual type is Null)"#,
+    r#"null([builders::add] invalid argument type, expected days and time duration, date and time, actual type is Null (at 1:1..1:14))"#,
   );
 }
 
@@ -6943,7 +6943,7 @@ fn _0715() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_ymDuration_to_rhs_number",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected years and months duration, date and time, actual type is number)"#,
+    r#"null([builders::add] invalid argument type, expected years and months duration, date and time, actual type is number (at 1:1..1:12))"#,
   );
 }
 
@@ -6954,7 +6954,7 @@ fn _0716() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_ymDuration_to_rhs_string",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected years and months duration, date and time, actual type is string)"#,
+    r#"null([builders::add] invalid argument type, expected years and months duration, date and time, actual type is string (at 1:1..1:14))"#,
   );
 }
 
@@ -6965,7 +6965,7 @@ fn _0717() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_ymDuration_to_rhs_boolean",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected years and months duration, date and time, actual type is boolean)"#,
+    r#"null([builders::add] invalid argument type, expected years and months duration, date and time, actual type is boolean (at 1:1..1:14))"#,
   );
 }
 
@@ -6976,7 +6976,7 @@ fn _0718() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_ymDuration_to_rhs_time",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected years and months duration, date and time, actual type is time)"#,
+    r#"null([builders::add] invalid argument type, expected years and months duration, date and time, actual type is time (at 1:1..1:21))"#,
   );
 }
 
@@ -6987,7 +6987,7 @@ fn _0719() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_ymDuration_to_rhs_list",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected years and months duration, date and time, actual type is list<number>)"#,
+    r#"null([builders::add] invalid argument type, expected years and months duration, date and time, actual type is list<number> (at 1:1..1:14))"#,
   );
 }
 
@@ -6998,7 +6998,7 @@ fn _0720() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_ymDuration_to_rhs_context",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected years and months duration, date and time, actual type is context<a: number>)"#,
+    r#"null([builders::add] invalid argument type, expected years and months duration, date and time, actual type is context<a: number> (at 1:1..1:17))"#,
   );
 }
 
@@ -7009,7 +7009,7 @@ fn _0721() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_ymDuration_to_rhs_dtDuration",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected years and months duration, date and time, actual type is days and time duration)"#,
+    r#"null([builders::add] invalid argument type, expected years and months duration, date and time, actual type is days and time duration (at 1:1..1:16))"#,
   );
 }
 
@@ -7020,7 +7020,7 @@ fn _0722() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_ymDuration_to_rhs_range",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected years and months duration, date and time, actual type is range<number>)"#,
+    r#"null([builders::add] invalid argument type, expected years and months duration, date and time, actual type is range<number> (at 1:1..1:17))"#,
   );
 }
 
@@ -7031,7 +7031,7 @@ fn _0723() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_ymDuration_to_rhs_function",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected years and months duration, date and time, actual type is function<Any>->Any)"#,
+    r#"null([builders::add] invalid argument type, expected years and months duration, date and time, actual type is function<Any>->Any (at 1:1..1:25))"#,
   );
 }
 
@@ -7042,7 +7042,7 @@ fn _0724() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_ymDuration_to_rhs_null",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected years and months duration, date and time, actual type is Null)"#,
+    r#"null([builders::add] invalid argument type, expected years and months duration, date and time, actual type is Null (at 1:1..1:14))"#,
   );
 }
 
@@ -7053,7 +7053,7 @@ fn _0725() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_range_to_rhs_number",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is range<number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is range<number> (at 1:1..1:13))"#,
   );
 }
 
@@ -7064,7 +7064,7 @@ fn _0726() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_range_to_rhs_string",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is range<number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is range<number> (at 1:1..1:15))"#,
   );
 }
 
@@ -7075,7 +7075,7 @@ fn _0727() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_range_to_rhs_boolean",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is range<number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is range<number> (at 1:1..1:15))"#,
   );
 }
 
@@ -7086,7 +7086,7 @@ fn _0728() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_range_to_rhs_date",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is range<number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is range<number> (at 1:1..1:24))"#,
   );
 }
 
@@ -7097,7 +7097,7 @@ fn _0729() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_range_to_rhs_dateAndTime",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is range<number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is range<number> (at 1:1..1:33))"#,
   );
 }
 
@@ -7108,7 +7108,7 @@ fn _0730() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_range_to_rhs_time",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is range<number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is range<number> (at 1:1..1:22))"#,
   );
 }
 
@@ -7119,7 +7119,7 @@ fn _0731() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_range_to_rhs_list",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is range<number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is range<number> (at 1:1..1:15))"#,
   );
 }
 
@@ -7130,7 +7130,7 @@ fn _0732() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_range_to_rhs_context",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is range<number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is range<number> (at 1:1..1:18))"#,
   );
 }
 
@@ -7141,7 +7141,7 @@ fn _0733() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_range_to_rhs_dtDuration",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is range<number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is range<number> (at 1:1..1:17))"#,
   );
 }
 
@@ -7152,7 +7152,7 @@ fn _0734() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_range_to_rhs_ymDuration",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is range<number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is range<number> (at 1:1..1:17))"#,
   );
 }
 
@@ -7163,7 +7163,7 @@ fn _0735() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_range_to_rhs_range",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is range<number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is range<number> (at 1:1..1:18))"#,
   );
 }
 
@@ -7174,7 +7174,7 @@ fn _0736() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_range_to_rhs_function",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is range<number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is range<number> (at 1:1..1:26))"#,
   );
 }
 
@@ -7185,7 +7185,7 @@ fn _0737() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_range_to_rhs_null",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is range<number>)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is range<number> (at 1:1..1:15))"#,
   );
 }
 
@@ -7196,7 +7196,7 @@ fn _0738() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_function_to_rhs_number",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is function<Any>->Any)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is function<Any>->Any (at 1:1..1:21))"#,
   );
 }
 
@@ -7207,7 +7207,7 @@ fn _0739() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_function_to_rhs_string",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is function<Any>->Any)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is function<Any>->Any (at 1:1..1:23))"#,
   );
 }
 
@@ -7218,7 +7218,7 @@ fn _0740() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_function_to_rhs_boolean",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is function<Any>->Any)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is function<Any>->Any (at 1:1..1:23))"#,
   );
 }
 
@@ -7229,7 +7229,7 @@ fn _0741() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_function_to_rhs_date",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is function<Any>->Any)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is function<Any>->Any (at 1:1..1:32))"#,
   );
 }
 
@@ -7240,7 +7240,7 @@ fn _0742() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_function_to_rhs_dateAndTime",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is function<Any>->Any)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is function<Any>->Any (at 1:1..1:41))"#,
   );
 }
 
@@ -7251,7 +7251,7 @@ fn _0743() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_function_to_rhs_time",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is function<Any>->Any)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is function<Any>->Any (at 1:1..1:30))"#,
   );
 }
 
@@ -7262,7 +7262,7 @@ fn _0744() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_function_to_rhs_list",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is function<Any>->Any)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is function<Any>->Any (at 1:1..1:23))"#,
   );
 }
 
@@ -7273,7 +7273,7 @@ fn _0745() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_function_to_rhs_context",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is function<Any>->Any)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is function<Any>->Any (at 1:1..1:26))"#,
   );
 }
 
@@ -7284,7 +7284,7 @@ fn _0746() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_function_to_rhs_dtDuration",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is function<Any>->Any)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is function<Any>->Any (at 1:1..1:25))"#,
   );
 }
 
@@ -7295,7 +7295,7 @@ fn _0747() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_function_to_rhs_ymDuration",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is function<Any>->Any)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is function<Any>->Any (at 1:1..1:25))"#,
   );
 }
 
@@ -7306,7 +7306,7 @@ fn _0748() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_function_to_rhs_range",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is function<Any>->Any)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is function<Any>->Any (at 1:1..1:26))"#,
   );
 }
 
@@ -7317,7 +7317,7 @@ fn _0749() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_function_to_rhs_function",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is function<Any>->Any)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is function<Any>->Any (at 1:1..1:34))"#,
   );
 }
 
@@ -7328,7 +7328,7 @@ fn _0750() {
     &MODEL_NAMESPACE,
     "error_when_add_lhs_function_to_rhs_null",
     &CTX,
-    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is function<Any>->Any)"#,
+    r#"null([builders::add] invalid argument type, expected number, string, date and time, days and time duration, years and months duration, null, actual type is function<Any>->Any (at 1:1..1:23))"#,
   );
 }
 
@@ -7404,7 +7404,7 @@ fn _0764() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_number_minus_rhs_string",
     &CTX,
-    r#"null([subtraction] incompatible types: 10 - "10")"#,
+    r#"null([subtraction] incompatible types: 10 - "10" (at 1:1..1:10))"#,
   );
 }
 
@@ -7415,7 +7415,7 @@ fn _0765() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_number_minus_rhs_boolean",
     &CTX,
-    r#"null([subtraction] incompatible types: 10 - true)"#,
+    r#"null([subtraction] incompatible types: 10 - true (at 1:1..1:10))"#,
   );
 }
 
@@ -7426,7 +7426,7 @@ fn _0766() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_number_minus_rhs_date",
     &CTX,
-    r#"null([subtraction] incompatible types: 10 - 2021-01-01)"#,
+    r#"null([subtraction] incompatible types: 10 - 2021-01-01 (at 1:1..1:19))"#,
   );
 }
 
@@ -7437,7 +7437,7 @@ fn _0767() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_number_minus_rhs_dateAndTime",
     &CTX,
-    r#"null([subtraction] incompatible types: 10 - 2021-01-01T10:10:10)"#,
+    r#"null([subtraction] incompatible types: 10 - 2021-01-01T10:10:10 (at 1:1..1:28))"#,
   );
 }
 
@@ -7448,7 +7448,7 @@ fn _0768() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_number_minus_rhs_time",
     &CTX,
-    r#"null([subtraction] incompatible types: 10 - 10:10:10)"#,
+    r#"null([subtraction] incompatible types: 10 - 10:10:10 (at 1:1..1:17))"#,
   );
 }
 
@@ -7459,7 +7459,7 @@ fn _0769() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_number_minus_rhs_list",
     &CTX,
-    r#"null([subtraction] incompatible types: 10 - [10])"#,
+    r#"null([subtraction] incompatible types: 10 - [10] (at 1:1..1:10))"#,
   );
 }
 
@@ -7470,7 +7470,7 @@ fn _0770() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_number_minus_rhs_context",
     &CTX,
-    r#"null([subtraction] incompatible types: 10 - {a: 10})"#,
+    r#"null([subtraction] incompatible types: 10 - {a: 10} (at 1:1..1:13))"#,
   );
 }
 
@@ -7481,7 +7481,7 @@ fn _0771() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_number_minus_rhs_dtDuration",
     &CTX,
-    r#"null([subtraction] incompatible types: 10 - P1D)"#,
+    r#"null([subtraction] incompatible types: 10 - P1D (at 1:1..1:12))"#,
   );
 }
 
@@ -7492,7 +7492,7 @@ fn _0772() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_number_minus_rhs_ymDuration",
     &CTX,
-    r#"null([subtraction] incompatible types: 10 - P1Y)"#,
+    r#"null([subtraction] incompatible types: 10 - P1Y (at 1:1..1:12))"#,
   );
 }
 
@@ -7503,7 +7503,7 @@ fn _0773() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_number_minus_rhs_range",
     &CTX,
-    r#"null([subtraction] incompatible types: 10 - [1..10])"#,
+    r#"null([subtraction] incompatible types: 10 - [1..10] (at 1:1..1:13))"#,
   );
 }
 
@@ -7514,7 +7514,7 @@ fn _0774() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_number_minus_rhs_function",
     &CTX,
-    r#"null([subtraction] incompatible types: 10 - FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any))"#,
+    r#"null([subtraction] incompatible types: 10 - FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) (at 1:1..1:21))"#,
   );
 }
 
@@ -7525,7 +7525,7 @@ fn _0775() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_number_minus_rhs_null",
     &CTX,
-    r#"null([subtraction] incompatible types: 10 - null)"#,
+    r#"null([subtraction] incompatible types: 10 - null (at 1:1..1:10))"#,
   );
 }
 
@@ -7536,7 +7536,7 @@ fn _0776() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_string_minus_rhs_number",
     &CTX,
-    r#"null([subtraction] incompatible types: "10" - 10)"#,
+    r#"null([subtraction] incompatible types: "10" - 10 (at 1:1..1:10))"#,
   );
 }
 
@@ -7547,7 +7547,7 @@ fn _0777() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_string_minus_rhs_string",
     &CTX,
-    r#"null([subtraction] incompatible types: "10" - "10")"#,
+    r#"null([subtraction] incompatible types: "10" - "10" (at 1:1..1:12))"#,
   );
 }
 
@@ -7558,7 +7558,7 @@ fn _0778() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_string_minus_rhs_boolean",
     &CTX,
-    r#"null([subtraction] incompatible types: "10" - true)"#,
+    r#"null([subtraction] incompatible types: "10" - true (at 1:1..1:12))"#,
   );
 }
 
@@ -7569,7 +7569,7 @@ fn _0779() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_string_minus_rhs_date",
     &CTX,
-    r#"null([subtraction] incompatible types: "10" - 2021-01-01)"#,
+    r#"null([subtraction] incompatible types: "10" - 2021-01-01 (at 1:1..1:21))"#,
   );
 }
 
@@ -7580,7 +7580,7 @@ fn _0780() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_string_minus_rhs_dateAndTime",
     &CTX,
-    r#"null([subtraction] incompatible types: "10" - 2021-01-01T10:10:10)"#,
+    r#"null([subtraction] incompatible types: "10" - 2021-01-01T10:10:10 (at 1:1..1:30))"#,
   );
 }
 
@@ -7591,7 +7591,7 @@ fn _0781() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_string_minus_rhs_time",
     &CTX,
-    r#"null([subtraction] incompatible types: "10" - 10:10:10)"#,
+    r#"null([subtraction] incompatible types: "10" - 10:10:10 (at 1:1..1:19))"#,
   );
 }
 
@@ -7602,7 +7602,7 @@ fn _0782() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_string_minus_rhs_list",
     &CTX,
-    r#"null([subtraction] incompatible types: "10" - [10])"#,
+    r#"null([subtraction] incompatible types: "10" - [10] (at 1:1..1:12))"#,
   );
 }
 
@@ -7613,7 +7613,7 @@ fn _0783() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_string_minus_rhs_context",
     &CTX,
-    r#"null([subtraction] incompatible types: "10" - {a: 10})"#,
+    r#"null([subtraction] incompatible types: "10" - {a: 10} (at 1:1..1:15))"#,
   );
 }
 
@@ -7624,7 +7624,7 @@ fn _0784() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_string_minus_rhs_dtDuration",
     &CTX,
-    r#"null([subtraction] incompatible types: "10" - P1D)"#,
+    r#"null([subtraction] incompatible types: "10" - P1D (at 1:1..1:14))"#,
   );
 }
 
@@ -7635,7 +7635,7 @@ fn _0785() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_string_minus_rhs_ymDuration",
     &CTX,
-    r#"null([subtraction] incompatible types: "10" - P1Y)"#,
+    r#"null([subtraction] incompatible types: "10" - P1Y (at 1:1..1:14))"#,
   );
 }
 
@@ -7646,7 +7646,7 @@ fn _0786() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_string_minus_rhs_range",
     &CTX,
-    r#"null([subtraction] incompatible types: "10" - [1..10])"#,
+    r#"null([subtraction] incompatible types: "10" - [1..10] (at 1:1..1:15))"#,
   );
 }
 
@@ -7657,7 +7657,7 @@ fn _0787() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_string_minus_rhs_function",
     &CTX,
-    r#"null([subtraction] incompatible types: "10" - FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any))"#,
+    r#"null([subtraction] incompatible types: "10" - FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) (at 1:1..1:23))"#,
   );
 }
 
@@ -7668,7 +7668,7 @@ fn _0788() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_string_minus_rhs_null",
     &CTX,
-    r#"null([subtraction] incompatible types: "10" - null)"#,
+    r#"null([subtraction] incompatible types: "10" - null (at 1:1..1:12))"#,
   );
 }
 
@@ -7679,7 +7679,7 @@ fn _0789() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_boolean_minus_rhs_number",
     &CTX,
-    r#"null([subtraction] incompatible types: true - 10)"#,
+    r#"null([subtraction] incompatible types: true - 10 (at 1:1..1:10))"#,
   );
 }
 
@@ -7690,7 +7690,7 @@ fn _0790() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_boolean_minus_rhs_string",
     &CTX,
-    r#"null([subtraction] incompatible types: true - "10")"#,
+    r#"null([subtraction] incompatible types: true - "10" (at 1:1..1:12))"#,
   );
 }
 
@@ -7701,7 +7701,7 @@ fn _0791() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_boolean_minus_rhs_boolean",
     &CTX,
-    r#"null([subtraction] incompatible types: true - true)"#,
+    r#"null([subtraction] incompatible types: true - true (at 1:1..1:12))"#,
   );
 }
 
@@ -7712,7 +7712,7 @@ fn _0792() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_boolean_minus_rhs_date",
     &CTX,
-    r#"null([subtraction] incompatible types: true - 2021-01-01)"#,
+    r#"null([subtraction] incompatible types: true - 2021-01-01 (at 1:1..1:21))"#,
   );
 }
 
@@ -7723,7 +7723,7 @@ fn _0793() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_boolean_minus_rhs_dateAndTime",
     &CTX,
-    r#"null([subtraction] incompatible types: true - 2021-01-01T10:10:10)"#,
+    r#"null([subtraction] incompatible types: true - 2021-01-01T10:10:10 (at 1:1..1:30))"#,
   );
 }
 
@@ -7734,7 +7734,7 @@ fn _0794() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_boolean_minus_rhs_time",
     &CTX,
-    r#"null([subtraction] incompatible types: true - 10:10:10)"#,
+    r#"null([subtraction] incompatible types: true - 10:10:10 (at 1:1..1:19))"#,
   );
 }
 
@@ -7745,7 +7745,7 @@ fn _0795() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_boolean_minus_rhs_list",
     &CTX,
-    r#"null([subtraction] incompatible types: true - [10])"#,
+    r#"null([subtraction] incompatible types: true - [10] (at 1:1..1:12))"#,
   );
 }
 
@@ -7756,7 +7756,7 @@ fn _0796() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_boolean_minus_rhs_context",
     &CTX,
-    r#"null([subtraction] incompatible types: true - {a: 10})"#,
+    r#"null([subtraction] incompatible types: true - {a: 10} (at 1:1..1:15))"#,
   );
 }
 
@@ -7767,7 +7767,7 @@ fn _0797() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_boolean_minus_rhs_dtDuration",
     &CTX,
-    r#"null([subtraction] incompatible types: true - P1D)"#,
+    r#"null([subtraction] incompatible types: true - P1D (at 1:1..1:14))"#,
   );
 }
 
@@ -7778,7 +7778,7 @@ fn _0798() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_boolean_minus_rhs_ymDuration",
     &CTX,
-    r#"null([subtraction] incompatible types: true - P1Y)"#,
+    r#"null([subtraction] incompatible types: true - P1Y (at 1:1..1:14))"#,
   );
 }
 
@@ -7789,7 +7789,7 @@ fn _0799() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_boolean_minus_rhs_range",
     &CTX,
-    r#"null([subtraction] incompatible types: true - [1..10])"#,
+    r#"null([subtraction] incompatible types: true - [1..10] (at 1:1..1:15))"#,
   );
 }
 
@@ -7800,7 +7800,7 @@ fn _0800() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_boolean_minus_rhs_function",
     &CTX,
-    r#"null([subtraction] incompatible types: true - FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any))"#,
+    r#"null([subtraction] incompatible types: true - FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) (at 1:1..1:23))"#,
   );
 }
 
@@ -7811,7 +7811,7 @@ fn _0801() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_boolean_minus_rhs_null",
     &CTX,
-    r#"null([subtraction] incompatible types: true - null)"#,
+    r#"null([subtraction] incompatible types: true - null (at 1:1..1:12))"#,
   );
 }
 
@@ -7822,7 +7822,7 @@ fn _0802() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_date_minus_rhs_number",
     &CTX,
-    r#"null([subtraction] incompatible types: 2021-01-01 - 10)"#,
+    r#"null([subtraction] incompatible types: 2021-01-01 - 10 (at 1:1..1:19))"#,
   );
 }
 
@@ -7833,7 +7833,7 @@ fn _0803() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_date_minus_rhs_string",
     &CTX,
-    r#"null([subtraction] incompatible types: 2021-01-01 - "10")"#,
+    r#"null([subtraction] incompatible types: 2021-01-01 - "10" (at 1:1..1:21))"#,
   );
 }
 
@@ -7844,7 +7844,7 @@ fn _0804() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_date_minus_rhs_boolean",
     &CTX,
-    r#"null([subtraction] incompatible types: 2021-01-01 - true)"#,
+    r#"null([subtraction] incompatible types: 2021-01-01 - true (at 1:1..1:21))"#,
   );
 }
 
@@ -7855,7 +7855,7 @@ fn _0805() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_date_minus_rhs_time",
     &CTX,
-    r#"null([subtraction] incompatible types: 2021-01-01 - 10:10:10)"#,
+    r#"null([subtraction] incompatible types: 2021-01-01 - 10:10:10 (at 1:1..1:28))"#,
   );
 }
 
@@ -7866,7 +7866,7 @@ fn _0806() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_date_minus_rhs_list",
     &CTX,
-    r#"null([subtraction] incompatible types: 2021-01-01 - [10])"#,
+    r#"null([subtraction] incompatible types: 2021-01-01 - [10] (at 1:1..1:21))"#,
   );
 }
 
@@ -7877,7 +7877,7 @@ fn _0807() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_date_minus_rhs_context",
     &CTX,
-    r#"null([subtraction] incompatible types: 2021-01-01 - {a: 10})"#,
+    r#"null([subtraction] incompatible types: 2021-01-01 - {a: 10} (at 1:1..1:24))"#,
   );
 }
 
@@ -7888,7 +7888,7 @@ fn _0808() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_date_minus_rhs_range",
     &CTX,
-    r#"null([subtraction] incompatible types: 2021-01-01 - [1..10])"#,
+    r#"null([subtraction] incompatible types: 2021-01-01 - [1..10] (at 1:1..1:24))"#,
   );
 }
 
@@ -7899,7 +7899,7 @@ fn _0809() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_date_minus_rhs_function",
     &CTX,
-    r#"null([subtraction] incompatible types: 2021-01-01 - FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any))"#,
+    r#"null([subtraction] incompatible types: 2021-01-01 - FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) (at 1:1..1:32))"#,
   );
 }
 
@@ -7910,7 +7910,7 @@ fn _0810() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_date_minus_rhs_null",
     &CTX,
-    r#"null([subtraction] incompatible types: 2021-01-01 - null)"#,
+    r#"null([subtraction] incompatible types: 2021-01-01 - null (at 1:1..1:21))"#,
   );
 }
 
@@ -7921,7 +7921,7 @@ fn _0811() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_dateAndTime_minus_rhs_number",
     &CTX,
-    r#"null([subtraction] incompatible types: 2021-01-01T10:10:10 - 10)"#,
+    r#"null([subtraction] incompatible types: 2021-01-01T10:10:10 - 10 (at 1:1..1:28))"#,
   );
 }
 
@@ -7932,7 +7932,7 @@ fn _0812() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_dateAndTime_minus_rhs_string",
     &CTX,
-    r#"null([subtraction] incompatible types: 2021-01-01T10:10:10 - "10")"#,
+    r#"null([subtraction] incompatible types: 2021-01-01T10:10:10 - "10" (at 1:1..1:30))"#,
   );
 }
 
@@ -7943,7 +7943,7 @@ fn _0813() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_dateAndTime_minus_rhs_boolean",
     &CTX,
-    r#"null([subtraction] incompatible types: 2021-01-01T10:10:10 - true)"#,
+    r#"null([subtraction] incompatible types: 2021-01-01T10:10:10 - true (at 1:1..1:30))"#,
   );
 }
 
@@ -7954,7 +7954,7 @@ fn _0814() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_dateAndTime_minus_rhs_time",
     &CTX,
-    r#"null([subtraction] incompatible types: 2021-01-01T10:10:10 - 10:10:10)"#,
+    r#"null([subtraction] incompatible types: 2021-01-01T10:10:10 - 10:10:10 (at 1:1..1:37))"#,
   );
 }
 
@@ -7965,7 +7965,7 @@ fn _0815() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_dateAndTime_minus_rhs_list",
     &CTX,
-    r#"null([subtraction] incompatible types: 2021-01-01T10:10:10 - [10])"#,
+    r#"null([subtraction] incompatible types: 2021-01-01T10:10:10 - [10] (at 1:1..1:30))"#,
   );
 }
 
@@ -7976,7 +7976,7 @@ fn _0816() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_dateAndTime_minus_rhs_context",
     &CTX,
-    r#"null([subtraction] incompatible types: 2021-01-01T10:10:10 - {a: 10})"#,
+    r#"null([subtraction] incompatible types: 2021-01-01T10:10:10 - {a: 10} (at 1:1..1:33))"#,
   );
 }
 
@@ -7987,7 +7987,7 @@ fn _0817() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_dateAndTime_minus_rhs_range",
     &CTX,
-    r#"null([subtraction] incompatible types: 2021-01-01T10:10:10 - [1..10])"#,
+    r#"null([subtraction] incompatible types: 2021-01-01T10:10:10 - [1..10] (at 1:1..1:33))"#,
   );
 }
 
@@ -7998,7 +7998,7 @@ fn _0818() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_dateAndTime_minus_rhs_function",
     &CTX,
-    r#"null([subtraction] incompatible types: 2021-01-01T10:10:10 - FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any))"#,
+    r#"null([subtraction] incompatible types: 2021-01-01T10:10:10 - FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) (at 1:1..1:41))"#,
   );
 }
 
@@ -8009,7 +8009,7 @@ fn _0819() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_dateAndTime_minus_rhs_null",
     &CTX,
-    r#"null([subtraction] incompatible types: 2021-01-01T10:10:10 - null)"#,
+    r#"null([subtraction] incompatible types: 2021-01-01T10:10:10 - null (at 1:1..1:30))"#,
   );
 }
 
@@ -8020,7 +8020,7 @@ fn _0820() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_time_minus_rhs_number",
     &CTX,
-    r#"null([subtraction] incompatible types: 10:10:10 - 10)"#,
+    r#"null([subtraction] incompatible types: 10:10:10 - 10 (at 1:1..1:17))"#,
   );
 }
 
@@ -8031,7 +8031,7 @@ fn _0821() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_time_minus_rhs_string",
     &CTX,
-    r#"null([subtraction] incompatible types: 10:10:10 - "10")"#,
+    r#"null([subtraction] incompatible types: 10:10:10 - "10" (at 1:1..1:19))"#,
   );
 }
 
@@ -8042,7 +8042,7 @@ fn _0822() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_time_minus_rhs_boolean",
     &CTX,
-    r#"null([subtraction] incompatible types: 10:10:10 - true)"#,
+    r#"null([subtraction] incompatible types: 10:10:10 - true (at 1:1..1:19))"#,
   );
 }
 
@@ -8053,7 +8053,7 @@ fn _0823() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_time_minus_rhs_date",
     &CTX,
-    r#"null([subtraction] incompatible types: 10:10:10 - 2021-01-01)"#,
+    r#"null([subtraction] incompatible types: 10:10:10 - 2021-01-01 (at 1:1..1:28))"#,
   );
 }
 
@@ -8064,7 +8064,7 @@ fn _0824() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_time_minus_rhs_dateAndTime",
     &CTX,
-    r#"null([subtraction] incompatible types: 10:10:10 - 2021-01-01T10:10:10)"#,
+    r#"null([subtraction] incompatible types: 10:10:10 - 2021-01-01T10:10:10 (at 1:1..1:37))"#,
   );
 }
 
@@ -8075,7 +8075,7 @@ fn _0825() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_time_minus_rhs_list",
     &CTX,
-    r#"null([subtraction] incompatible types: 10:10:10 - [10])"#,
+    r#"null([subtraction] incompatible types: 10:10:10 - [10] (at 1:1..1:19))"#,
   );
 }
 
@@ -8086,7 +8086,7 @@ fn _0826() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_time_minus_rhs_context",
     &CTX,
-    r#"null([subtraction] incompatible types: 10:10:10 - {a: 10})"#,
+    r#"null([subtraction] incompatible types: 10:10:10 - {a: 10} (at 1:1..1:22))"#,
   );
 }
 
@@ -8097,7 +8097,7 @@ fn _0827() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_time_minus_rhs_ymDuration",
     &CTX,
-    r#"null([subtraction] incompatible types: 10:10:10 - P1Y)"#,
+    r#"null([subtraction] incompatible types: 10:10:10 - P1Y (at 1:1..1:21))"#,
   );
 }
 
@@ -8108,7 +8108,7 @@ fn _0828() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_time_minus_rhs_range",
     &CTX,
-    r#"null([subtraction] incompatible types: 10:10:10 - [1..10])"#,
+    r#"null([subtraction] incompatible types: 10:10:10 - [1..10] (at 1:1..1:22))"#,
   );
 }
 
@@ -8119,7 +8119,7 @@ fn _0829() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_time_minus_rhs_function",
     &CTX,
-    r#"null([subtraction] incompatible types: 10:10:10 - FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any))"#,
+    r#"null([subtraction] incompatible types: 10:10:10 - FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) (at 1:1..1:30))"#,
   );
 }
 
@@ -8130,7 +8130,7 @@ fn _0830() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_time_minus_rhs_null",
     &CTX,
-    r#"null([subtraction] incompatible types: 10:10:10 - null)"#,
+    r#"null([subtraction] incompatible types: 10:10:10 - null (at 1:1..1:19))"#,
   );
 }
 
@@ -8141,7 +8141,7 @@ fn _0831() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_list_minus_rhs_number",
     &CTX,
-    r#"null([subtraction] incompatible types: [10] - 10)"#,
+    r#"null([subtraction] incompatible types: [10] - 10 (at 1:1..1:10))"#,
   );
 }
 
@@ -8152,7 +8152,7 @@ fn _0832() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_list_minus_rhs_string",
     &CTX,
-    r#"null([subtraction] incompatible types: [10] - "10")"#,
+    r#"null([subtraction] incompatible types: [10] - "10" (at 1:1..1:12))"#,
   );
 }
 
@@ -8163,7 +8163,7 @@ fn _0833() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_list_minus_rhs_boolean",
     &CTX,
-    r#"null([subtraction] incompatible types: [10] - true)"#,
+    r#"null([subtraction] incompatible types: [10] - true (at 1:1..1:12))"#,
   );
 }
 
@@ -8174,7 +8174,7 @@ fn _0834() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_list_minus_rhs_date",
     &CTX,
-    r#"null([subtraction] incompatible types: [10] - 2021-01-01)"#,
+    r#"null([subtraction] incompatible types: [10] - 2021-01-01 (at 1:1..1:21))"#,
   );
 }
 
@@ -8185,7 +8185,7 @@ fn _0835() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_list_minus_rhs_dateAndTime",
     &CTX,
-    r#"null([subtraction] incompatible types: [10] - 2021-01-01T10:10:10)"#,
+    r#"null([subtraction] incompatible types: [10] - 2021-01-01T10:10:10 (at 1:1..1:30))"#,
   );
 }
 
@@ -8196,7 +8196,7 @@ fn _0836() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_list_minus_rhs_time",
     &CTX,
-    r#"null([subtraction] incompatible types: [10] - 10:10:10)"#,
+    r#"null([subtraction] incompatible types: [10] - 10:10:10 (at 1:1..1:19))"#,
   );
 }
 
@@ -8207,7 +8207,7 @@ fn _0837() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_list_minus_rhs_list",
     &CTX,
-    r#"null([subtraction] incompatible types: [10] - [10])"#,
+    r#"null([subtraction] incompatible types: [10] - [10] (at 1:1..1:12))"#,
   );
 }
 
@@ -8218,7 +8218,7 @@ fn _0838() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_list_minus_rhs_context",
     &CTX,
-    r#"null([subtraction] incompatible types: [10] - {a: 10})"#,
+    r#"null([subtraction] incompatible types: [10] - {a: 10} (at 1:1..1:15))"#,
   );
 }
 
@@ -8229,7 +8229,7 @@ fn _0839() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_list_minus_rhs_dtDuration",
     &CTX,
-    r#"null([subtraction] incompatible types: [10] - P1D)"#,
+    r#"null([subtraction] incompatible types: [10] - P1D (at 1:1..1:14))"#,
   );
 }
 
@@ -8240,7 +8240,7 @@ fn _0840() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_list_minus_rhs_ymDuration",
     &CTX,
-    r#"null([subtraction] incompatible types: [10] - P1Y)"#,
+    r#"null([subtraction] incompatible types: [10] - P1Y (at 1:1..1:14))"#,
   );
 }
 
@@ -8251,7 +8251,7 @@ fn _0841() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_list_minus_rhs_range",
     &CTX,
-    r#"null([subtraction] incompatible types: [10] - [1..10])"#,
+    r#"null([subtraction] incompatible types: [10] - [1..10] (at 1:1..1:15))"#,
   );
 }
 
@@ -8262,7 +8262,7 @@ fn _0842() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_list_minus_rhs_function",
     &CTX,
-    r#"null([subtraction] incompatible types: [10] - FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any))"#,
+    r#"null([subtraction] incompatible types: [10] - FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) (at 1:1..1:23))"#,
   );
 }
 
@@ -8273,7 +8273,7 @@ fn _0843() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_list_minus_rhs_null",
     &CTX,
-    r#"null([subtraction] incompatible types: [10] - null)"#,
+    r#"null([subtraction] incompatible types: [10] - null (at 1:1..1:12))"#,
   );
 }
 
@@ -8284,7 +8284,7 @@ fn _0844() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_context_minus_rhs_number",
     &CTX,
-    r#"null([subtraction] incompatible types: {a: 10} - 10)"#,
+    r#"null([subtraction] incompatible types: {a: 10} - 10 (at 1:1..1:13))"#,
   );
 }
 
@@ -8295,7 +8295,7 @@ fn _0845() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_context_minus_rhs_string",
     &CTX,
-    r#"null([subtraction] incompatible types: {a: 10} - "10")"#,
+    r#"null([subtraction] incompatible types: {a: 10} - "10" (at 1:1..1:15))"#,
   );
 }
 
@@ -8306,7 +8306,7 @@ fn _0846() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_context_minus_rhs_boolean",
     &CTX,
-    r#"null([subtraction] incompatible types: {a: 10} - true)"#,
+    r#"null([subtraction] incompatible types: {a: 10} - true (at 1:1..1:15))"#,
   );
 }
 
@@ -8317,7 +8317,7 @@ fn _0847() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_context_minus_rhs_date",
     &CTX,
-    r#"null([subtraction] incompatible types: {a: 10} - 2021-01-01)"#,
+    r#"null([subtraction] incompatible types: {a: 10} - 2021-01-01 (at 1:1..1:24))"#,
   );
 }
 
@@ -8328,7 +8328,7 @@ fn _0848() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_context_minus_rhs_dateAndTime",
     &CTX,
-    r#"null([subtraction] incompatible types: {a: 10} - 2021-01-01T10:10:10)"#,
+    r#"null([subtraction] incompatible types: {a: 10} - 2021-01-01T10:10:10 (at 1:1..1:33))"#,
   );
 }
 
@@ -8339,7 +8339,7 @@ fn _0849() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_context_minus_rhs_time",
     &CTX,
-    r#"null([subtraction] incompatible types: {a: 10} - 10:10:10)"#,
+    r#"null([subtraction] incompatible types: {a: 10} - 10:10:10 (at 1:1..1:22))"#,
   );
 }
 
@@ -8350,7 +8350,7 @@ fn _0850() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_context_minus_rhs_list",
     &CTX,
-    r#"null([subtraction] incompatible types: {a: 10} - [10])"#,
+    r#"null([subtraction] incompatible types: {a: 10} - [10] (at 1:1..1:15))"#,
   );
 }
 
@@ -8361,7 +8361,7 @@ fn _0851() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_context_minus_rhs_context",
     &CTX,
-    r#"null([subtraction] incompatible types: {a: 10} - {a: 10})"#,
+    r#"null([subtraction] incompatible types: {a: 10} - {a: 10} (at 1:1..1:18))"#,
   );
 }
 
@@ -8372,7 +8372,7 @@ fn _0852() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_context_minus_rhs_dtDuration",
     &CTX,
-    r#"null([subtraction] incompatible types: {a: 10} - P1D)"#,
+    r#"null([subtraction] incompatible types: {a: 10} - P1D (at 1:1..1:17))"#,
   );
 }
 
@@ -8383,7 +8383,7 @@ fn _0853() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_context_minus_rhs_ymDuration",
     &CTX,
-    r#"null([subtraction] incompatible types: {a: 10} - P1Y)"#,
+    r#"null([subtraction] incompatible types: {a: 10} - P1Y (at 1:1..1:17))"#,
   );
 }
 
@@ -8394,7 +8394,7 @@ fn _0854() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_context_minus_rhs_range",
     &CTX,
-    r#"null([subtraction] incompatible types: {a: 10} - [1..10])"#,
+    r#"null([subtraction] incompatible types: {a: 10} - [1..10] (at 1:1..1:18))"#,
   );
 }
 
@@ -8405,7 +8405,7 @@ fn _0855() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_context_minus_rhs_function",
     &CTX,
-    r#"null([subtraction] incompatible types: {a: 10} - FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any))"#,
+    r#"null([subtraction] incompatible types: {a: 10} - FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) (at 1:1..1:26))"#,
   );
 }
 
@@ -8416,7 +8416,7 @@ fn _0856() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_context_minus_rhs_null",
     &CTX,
-    r#"null([subtraction] incompatible types: {a: 10} - null)"#,
+    r#"null([subtraction] incompatible types: {a: 10} - null (at 1:1..1:15))"#,
   );
 }
 
@@ -8427,7 +8427,7 @@ fn _0857() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_dtDuration_minus_rhs_number",
     &CTX,
-    r#"null([subtraction] incompatible types: P1D - 10)"#,
+    r#"null([subtraction] incompatible types: P1D - 10 (at 1:1..1:12))"#,
   );
 }
 
@@ -8438,7 +8438,7 @@ fn _0858() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_dtDuration_minus_rhs_string",
     &CTX,
-    r#"null([subtraction] incompatible types: P1D - "10")"#,
+    r#"null([subtraction] incompatible types: P1D - "10" (at 1:1..1:14))"#,
   );
 }
 
@@ -8449,7 +8449,7 @@ fn _0859() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_dtDuration_minus_rhs_boolean",
     &CTX,
-    r#"null([subtraction] incompatible types: P1D - true)"#,
+    r#"null([subtraction] incompatible types: P1D - true (at 1:1..1:14))"#,
   );
 }
 
@@ -8460,7 +8460,7 @@ fn _0860() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_dtDuration_minus_rhs_date",
     &CTX,
-    r#"null([subtraction] incompatible types: P1D - 2021-01-01)"#,
+    r#"null([subtraction] incompatible types: P1D - 2021-01-01 (at 1:1..1:23))"#,
   );
 }
 
@@ -8471,7 +8471,7 @@ fn _0861() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_dtDuration_minus_rhs_dateAndTime",
     &CTX,
-    r#"null([subtraction] incompatible types: P1D - 2021-01-01T10:10:10)"#,
+    r#"null([subtraction] incompatible types: P1D - 2021-01-01T10:10:10 (at 1:1..1:32))"#,
   );
 }
 
@@ -8482,7 +8482,7 @@ fn _0862() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_dtDuration_minus_rhs_time",
     &CTX,
-    r#"null([subtraction] incompatible types: P1D - 10:10:10)"#,
+    r#"null([subtraction] incompatible types: P1D - 10:10:10 (at 1:1..1:21))"#,
   );
 }
 
@@ -8493,7 +8493,7 @@ fn _0863() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_dtDuration_minus_rhs_list",
     &CTX,
-    r#"null([subtraction] incompatible types: P1D - [10])"#,
+    r#"null([subtraction] incompatible types: P1D - [10] (at 1:1..1:14))"#,
   );
 }
 
@@ -8504,7 +8504,7 @@ fn _0864() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_dtDuration_minus_rhs_context",
     &CTX,
-    r#"null([subtraction] incompatible types: P1D - {a: 10})"#,
+    r#"null([subtraction] incompatible types: P1D - {a: 10} (at 1:1..1:17))"#,
   );
 }
 
@@ -8515,7 +8515,7 @@ fn _0865() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_dtDuration_minus_rhs_ymDuration",
     &CTX,
-    r#"null([subtraction] incompatible types: P1D - P1Y)"#,
+    r#"null([subtraction] incompatible types: P1D - P1Y (at 1:1..1:16))"#,
   );
 }
 
@@ -8526,7 +8526,7 @@ fn _0866() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_dtDuration_minus_rhs_range",
     &CTX,
-    r#"null([subtraction] incompatible types: P1D - [1..10])"#,
+    r#"null([subtraction] incompatible types: P1D - [1..10] (at 1:1..1:17))"#,
   );
 }
 
@@ -8537,7 +8537,7 @@ fn _0867() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_dtDuration_minus_rhs_function",
     &CTX,
-    r#"null([subtraction] incompatible types: P1D - FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any))"#,
+    r#"null([subtraction] incompatible types: P1D - FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) (at 1:1..1:25))"#,
   );
 }
 
@@ -8548,7 +8548,7 @@ fn _0868() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_dtDuration_minus_rhs_null",
     &CTX,
-    r#"null([subtraction] incompatible types: P1D - null)"#,
+    r#"null([subtraction] incompatible types: P1D - null (at 1:1..1:14))"#,
   );
 }
 
@@ -8559,7 +8559,7 @@ fn _0869() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_ymDuration_minus_rhs_number",
     &CTX,
-    r#"null([subtraction] incompatible types: P1Y - 10)"#,
+    r#"null([subtraction] incompatible types: P1Y - 10 (at 1:1..1:12))"#,
   );
 }
 
@@ -8570,7 +8570,7 @@ fn _0870() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_ymDuration_minus_rhs_string",
     &CTX,
-    r#"null([subtraction] incompatible types: P1Y - "10")"#,
+    r#"null([subtraction] incompatible types: P1Y - "10" (at 1:1..1:14))"#,
   );
 }
 
@@ -8581,7 +8581,7 @@ fn _0871() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_ymDuration_minus_rhs_boolean",
     &CTX,
-    r#"null([subtraction] incompatible types: P1Y - true)"#,
+    r#"null([subtraction] incompatible types: P1Y - true (at 1:1..1:14))"#,
   );
 }
 
@@ -8592,7 +8592,7 @@ fn _0872() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_ymDuration_minus_rhs_date",
     &CTX,
-    r#"null([subtraction] incompatible types: P1Y - 2021-01-01)"#,
+    r#"null([subtraction] incompatible types: P1Y - 2021-01-01 (at 1:1..1:23))"#,
   );
 }
 
@@ -8603,7 +8603,7 @@ fn _0873() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_ymDuration_minus_rhs_dateAndTime",
     &CTX,
-    r#"null([subtraction] incompatible types: P1Y - 2021-01-01T10:10:10)"#,
+    r#"null([subtraction] incompatible types: P1Y - 2021-01-01T10:10:10 (at 1:1..1:32))"#,
   );
 }
 
@@ -8614,7 +8614,7 @@ fn _0874() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_ymDuration_minus_rhs_time",
     &CTX,
-    r#"null([subtraction] incompatible types: P1Y - 10:10:10)"#,
+    r#"null([subtraction] incompatible types: P1Y - 10:10:10 (at 1:1..1:21))"#,
   );
 }
 
@@ -8625,7 +8625,7 @@ fn _0875() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_ymDuration_minus_rhs_list",
     &CTX,
-    r#"null([subtraction] incompatible types: P1Y - [10])"#,
+    r#"null([subtraction] incompatible types: P1Y - [10] (at 1:1..1:14))"#,
   );
 }
 
@@ -8636,7 +8636,7 @@ fn _0876() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_ymDuration_minus_rhs_context",
     &CTX,
-    r#"null([subtraction] incompatible types: P1Y - {a: 10})"#,
+    r#"null([subtraction] incompatible types: P1Y - {a: 10} (at 1:1..1:17))"#,
   );
 }
 
@@ -8647,7 +8647,7 @@ fn _0877() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_ymDuration_minus_rhs_dtDuration",
     &CTX,
-    r#"null([subtraction] incompatible types: P1Y - P1D)"#,
+    r#"null([subtraction] incompatible types: P1Y - P1D (at 1:1..1:16))"#,
   );
 }
 
@@ -8658,7 +8658,7 @@ fn _0878() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_ymDuration_minus_rhs_range",
     &CTX,
-    r#"null([subtraction] incompatible types: P1Y - [1..10])"#,
+    r#"null([subtraction] incompatible types: P1Y - [1..10] (at 1:1..1:17))"#,
   );
 }
 
@@ -8669,7 +8669,7 @@ fn _0879() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_ymDuration_minus_rhs_function",
     &CTX,
-    r#"null([subtraction] incompatible types: P1Y - FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any))"#,
+    r#"null([subtraction] incompatible types: P1Y - FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) (at 1:1..1:25))"#,
   );
 }
 
@@ -8680,7 +8680,7 @@ fn _0880() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_ymDuration_minus_rhs_null",
     &CTX,
-    r#"null([subtraction] incompatible types: P1Y - null)"#,
+    r#"null([subtraction] incompatible types: P1Y - null (at 1:1..1:14))"#,
   );
 }
 
@@ -8691,7 +8691,7 @@ fn _0881() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_range_minus_rhs_number",
     &CTX,
-    r#"null([subtraction] incompatible types: [1..10] - 10)"#,
+    r#"null([subtraction] incompatible types: [1..10] - 10 (at 1:1..1:13))"#,
   );
 }
 
@@ -8702,7 +8702,7 @@ fn _0882() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_range_minus_rhs_string",
     &CTX,
-    r#"null([subtraction] incompatible types: [1..10] - "10")"#,
+    r#"null([subtraction] incompatible types: [1..10] - "10" (at 1:1..1:15))"#,
   );
 }
 
@@ -8713,7 +8713,7 @@ fn _0883() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_range_minus_rhs_boolean",
     &CTX,
-    r#"null([subtraction] incompatible types: [1..10] - true)"#,
+    r#"null([subtraction] incompatible types: [1..10] - true (at 1:1..1:15))"#,
   );
 }
 
@@ -8724,7 +8724,7 @@ fn _0884() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_range_minus_rhs_date",
     &CTX,
-    r#"null([subtraction] incompatible types: [1..10] - 2021-01-01)"#,
+    r#"null([subtraction] incompatible types: [1..10] - 2021-01-01 (at 1:1..1:24))"#,
   );
 }
 
@@ -8735,7 +8735,7 @@ fn _0885() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_range_minus_rhs_dateAndTime",
     &CTX,
-    r#"null([subtraction] incompatible types: [1..10] - 2021-01-01T10:10:10)"#,
+    r#"null([subtraction] incompatible types: [1..10] - 2021-01-01T10:10:10 (at 1:1..1:33))"#,
   );
 }
 
@@ -8746,7 +8746,7 @@ fn _0886() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_range_minus_rhs_time",
     &CTX,
-    r#"null([subtraction] incompatible types: [1..10] - 10:10:10)"#,
+    r#"null([subtraction] incompatible types: [1..10] - 10:10:10 (at 1:1..1:22))"#,
   );
 }
 
@@ -8757,7 +8757,7 @@ fn _0887() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_range_minus_rhs_list",
     &CTX,
-    r#"null([subtraction] incompatible types: [1..10] - [10])"#,
+    r#"null([subtraction] incompatible types: [1..10] - [10] (at 1:1..1:15))"#,
   );
 }
 
@@ -8768,7 +8768,7 @@ fn _0888() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_range_minus_rhs_context",
     &CTX,
-    r#"null([subtraction] incompatible types: [1..10] - {a: 10})"#,
+    r#"null([subtraction] incompatible types: [1..10] - {a: 10} (at 1:1..1:18))"#,
   );
 }
 
@@ -8779,7 +8779,7 @@ fn _0889() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_range_minus_rhs_dtDuration",
     &CTX,
-    r#"null([subtraction] incompatible types: [1..10] - P1D)"#,
+    r#"null([subtraction] incompatible types: [1..10] - P1D (at 1:1..1:17))"#,
   );
 }
 
@@ -8790,7 +8790,7 @@ fn _0890() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_range_minus_rhs_ymDuration",
     &CTX,
-    r#"null([subtraction] incompatible types: [1..10] - P1Y)"#,
+    r#"null([subtraction] incompatible types: [1..10] - P1Y (at 1:1..1:17))"#,
   );
 }
 
@@ -8801,7 +8801,7 @@ fn _0891() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_range_minus_rhs_range",
     &CTX,
-    r#"null([subtraction] incompatible types: [1..10] - [1..10])"#,
+    r#"null([subtraction] incompatible types: [1..10] - [1..10] (at 1:1..1:18))"#,
   );
 }
 
@@ -8812,7 +8812,7 @@ fn _0892() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_range_minus_rhs_function",
     &CTX,
-    r#"null([subtraction] incompatible types: [1..10] - FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any))"#,
+    r#"null([subtraction] incompatible types: [1..10] - FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) (at 1:1..1:26))"#,
   );
 }
 
@@ -8823,7 +8823,7 @@ fn _0893() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_range_minus_rhs_null",
     &CTX,
-    r#"null([subtraction] incompatible types: [1..10] - null)"#,
+    r#"null([subtraction] incompatible types: [1..10] - null (at 1:1..1:15))"#,
   );
 }
 
@@ -8834,7 +8834,7 @@ fn _0894() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_function_minus_rhs_number",
     &CTX,
-    r#"null([subtraction] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) - 10)"#,
+    r#"null([subtraction] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) - 10 (at 1:1..1:21))"#,
   );
 }
 
@@ -8845,7 +8845,7 @@ fn _0895() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_function_minus_rhs_string",
     &CTX,
-    r#"null([subtraction] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) - "10")"#,
+    r#"null([subtraction] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) - "10" (at 1:1..1:23))"#,
   );
 }
 
@@ -8856,7 +8856,7 @@ fn _0896() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_function_minus_rhs_boolean",
     &CTX,
-    r#"null([subtraction] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) - true)"#,
+    r#"null([subtraction] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) - true (at 1:1..1:23))"#,
   );
 }
 
@@ -8867,7 +8867,7 @@ fn _0897() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_function_minus_rhs_date",
     &CTX,
-    r#"null([subtraction] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) - 2021-01-01)"#,
+    r#"null([subtraction] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) - 2021-01-01 (at 1:1..1:32))"#,
   );
 }
 
@@ -8878,7 +8878,7 @@ fn _0898() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_function_minus_rhs_dateAndTime",
     &CTX,
-    r#"null([subtraction] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) - 2021-01-01T10:10:10)"#,
+    r#"null([subtraction] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) - 2021-01-01T10:10:10 (at 1:1..1:41))"#,
   );
 }
 
@@ -8889,7 +8889,7 @@ fn _0899() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_function_minus_rhs_time",
     &CTX,
-    r#"null([subtraction] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) - 10:10:10)"#,
+    r#"null([subtraction] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) - 10:10:10 (at 1:1..1:30))"#,
   );
 }
 
@@ -8900,7 +8900,7 @@ fn _0900() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_function_minus_rhs_list",
     &CTX,
-    r#"null([subtraction] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) - [10])"#,
+    r#"null([subtraction] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) - [10] (at 1:1..1:23))"#,
   );
 }
 
@@ -8911,7 +8911,7 @@ fn _0901() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_function_minus_rhs_context",
     &CTX,
-    r#"null([subtraction] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) - {a: 10})"#,
+    r#"null([subtraction] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) - {a: 10} (at 1:1..1:26))"#,
   );
 }
 
@@ -8922,7 +8922,7 @@ fn _0902() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_function_minus_rhs_dtDuration",
     &CTX,
-    r#"null([subtraction] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) - P1D)"#,
+    r#"null([subtraction] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) - P1D (at 1:1..1:25))"#,
   );
 }
 
@@ -8933,7 +8933,7 @@ fn _0903() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_function_minus_rhs_ymDuration",
     &CTX,
-    r#"null([subtraction] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) - P1Y)"#,
+    r#"null([subtraction] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) - P1Y (at 1:1..1:25))"#,
   );
 }
 
@@ -8944,7 +8944,7 @@ fn _0904() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_function_minus_rhs_range",
     &CTX,
-    r#"null([subtraction] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) - [1..10])"#,
+    r#"null([subtraction] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) - [1..10] (at 1:1..1:26))"#,
   );
 }
 
@@ -8955,7 +8955,7 @@ fn _0905() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_function_minus_rhs_function",
     &CTX,
-    r#"null([subtraction] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) - FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any))"#,
+    r#"null([subtraction] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) - FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) (at 1:1..1:34))"#,
   );
 }
 
@@ -8966,7 +8966,7 @@ fn _0906() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_function_minus_rhs_null",
     &CTX,
-    r#"null([subtraction] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) - null)"#,
+    r#"null([subtraction] incompatible types: FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) - null (at 1:1..1:23))"#,
   );
 }
 
@@ -8977,7 +8977,7 @@ fn _0907() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_null_minus_rhs_number",
     &CTX,
-    r#"null([subtraction] incompatible types: null - 10)"#,
+    r#"null([subtraction] incompatible types: null - 10 (at 1:1..1:10))"#,
   );
 }
 
@@ -8988,7 +8988,7 @@ fn _0908() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_null_minus_rhs_string",
     &CTX,
-    r#"null([subtraction] incompatible types: null - "10")"#,
+    r#"null([subtraction] incompatible types: null - "10" (at 1:1..1:12))"#,
   );
 }
 
@@ -8999,7 +8999,7 @@ fn _0909() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_null_minus_rhs_boolean",
     &CTX,
-    r#"null([subtraction] incompatible types: null - true)"#,
+    r#"null([subtraction] incompatible types: null - true (at 1:1..1:12))"#,
   );
 }
 
@@ -9010,7 +9010,7 @@ fn _0910() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_null_minus_rhs_date",
     &CTX,
-    r#"null([subtraction] incompatible types: null - 2021-01-01)"#,
+    r#"null([subtraction] incompatible types: null - 2021-01-01 (at 1:1..1:21))"#,
   );
 }
 
@@ -9021,7 +9021,7 @@ fn _0911() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_null_minus_rhs_dateAndTime",
     &CTX,
-    r#"null([subtraction] incompatible types: null - 2021-01-01T10:10:10)"#,
+    r#"null([subtraction] incompatible types: null - 2021-01-01T10:10:10 (at 1:1..1:30))"#,
   );
 }
 
@@ -9032,7 +9032,7 @@ fn _0912() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_null_minus_rhs_time",
     &CTX,
-    r#"null([subtraction] incompatible types: null - 10:10:10)"#,
+    r#"null([subtraction] incompatible types: null - 10:10:10 (at 1:1..1:19))"#,
   );
 }
 
@@ -9043,7 +9043,7 @@ fn _0913() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_null_minus_rhs_list",
     &CTX,
-    r#"null([subtraction] incompatible types: null - [10])"#,
+    r#"null([subtraction] incompatible types: null - [10] (at 1:1..1:12))"#,
   );
 }
 
@@ -9054,7 +9054,7 @@ fn _0914() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_null_minus_rhs_context",
     &CTX,
-    r#"null([subtraction] incompatible types: null - {a: 10})"#,
+    r#"null([subtraction] incompatible types: null - {a: 10} (at 1:1..1:15))"#,
   );
 }
 
@@ -9065,7 +9065,7 @@ fn _0915() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_null_minus_rhs_dtDuration",
     &CTX,
-    r#"null([subtraction] incompatible types: null - P1D)"#,
+    r#"null([subtraction] incompatible types: null - P1D (at 1:1..1:14))"#,
   );
 }
 
@@ -9076,7 +9076,7 @@ fn _0916() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_null_minus_rhs_ymDuration",
     &CTX,
-    r#"null([subtraction] incompatible types: null - P1Y)"#,
+    r#"null([subtraction] incompatible types: null - P1Y (at 1:1..1:14))"#,
   );
 }
 
@@ -9087,7 +9087,7 @@ fn _0917() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_null_minus_rhs_range",
     &CTX,
-    r#"null([subtraction] incompatible types: null - [1..10])"#,
+    r#"null([subtraction] incompatible types: null - [1..10] (at 1:1..1:15))"#,
   );
 }
 
@@ -9098,7 +9098,7 @@ fn _0918() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_null_minus_rhs_function",
     &CTX,
-    r#"null([subtraction] incompatible types: null - FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any))"#,
+    r#"null([subtraction] incompatible types: null - FunctionDefinition([(Name("a"), Any)],_,false,[],{},Any) (at 1:1..1:23))"#,
   );
 }
 
@@ -9109,7 +9109,7 @@ fn _0919() {
     &MODEL_NAMESPACE,
     "error_when_subtract_lhs_null_minus_rhs_null",
     &CTX,
-    r#"null([subtraction] incompatible types: null - null)"#,
+    r#"null([subtraction] incompatible types: null - null (at 1:1..1:12))"#,
   );
 }
 
@@ -9120,7 +9120,7 @@ fn _0920() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_number_exp_rhs_string",
     &CTX,
-    r#"null(exponentiation exponent is not a number)"#,
+    r#"null(exponentiation exponent is not a number (at 1:1..1:11))"#,
   );
 }
 
@@ -9131,7 +9131,7 @@ fn _0921() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_number_exp_rhs_boolean",
     &CTX,
-    r#"null(exponentiation exponent is not a number)"#,
+    r#"null(exponentiation exponent is not a number (at 1:1..1:11))"#,
   );
 }
 
@@ -9142,7 +9142,7 @@ fn _0922() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_number_exp_rhs_date",
     &CTX,
-    r#"null(exponentiation exponent is not a number)"#,
+    r#"null(exponentiation exponent is not a number (at 1:1..1:20))"#,
   );
 }
 
@@ -9153,7 +9153,7 @@ fn _0923() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_number_exp_rhs_dateAndTime",
     &CTX,
-    r#"null(exponentiation exponent is not a number)"#,
+    r#"null(exponentiation exponent is not a number (at 1:1..1:29))"#,
   );
 }
 
@@ -9164,7 +9164,7 @@ fn _0924() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_number_exp_rhs_time",
     &CTX,
-    r#"null(exponentiation exponent is not a number)"#,
+    r#"null(exponentiation exponent is not a number (at 1:1..1:18))"#,
   );
 }
 
@@ -9175,7 +9175,7 @@ fn _0925() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_number_exp_rhs_list",
     &CTX,
-    r#"null(exponentiation exponent is not a number)"#,
+    r#"null(exponentiation exponent is not a number (at 1:1..1:11))"#,
   );
 }
 
@@ -9186,7 +9186,7 @@ fn _0926() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_number_exp_rhs_context",
     &CTX,
-    r#"null(exponentiation exponent is not a number)"#,
+    r#"null(exponentiation exponent is not a number (at 1:1..1:14))"#,
   );
 }
 
@@ -9197,7 +9197,7 @@ fn _0927() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_number_exp_rhs_dtDuration",
     &CTX,
-    r#"null(exponentiation exponent is not a number)"#,
+    r#"null(exponentiation exponent is not a number (at 1:1..1:13))"#,
   );
 }
 
@@ -9208,7 +9208,7 @@ fn _0928() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_number_exp_rhs_ymDuration",
     &CTX,
-    r#"null(exponentiation exponent is not a number)"#,
+    r#"null(exponentiation exponent is not a number (at 1:1..1:13))"#,
   );
 }
 
@@ -9219,7 +9219,7 @@ fn _0929() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_number_exp_rhs_range",
     &CTX,
-    r#"null(exponentiation exponent is not a number)"#,
+    r#"null(exponentiation exponent is not a number (at 1:1..1:14))"#,
   );
 }
 
@@ -9230,7 +9230,7 @@ fn _0930() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_number_exp_rhs_function",
     &CTX,
-    r#"null(exponentiation exponent is not a number)"#,
+    r#"null(exponentiation exponent is not a number (at 1:1..1:22))"#,
   );
 }
 
@@ -9241,7 +9241,7 @@ fn _0931() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_number_exp_rhs_null",
     &CTX,
-    r#"null(exponentiation exponent is not a number)"#,
+    r#"null(exponentiation exponent is not a number (at 1:1..1:11))"#,
   );
 }
 
@@ -9252,7 +9252,7 @@ fn _0932() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_string_exp_rhs_number",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:11))"#,
   );
 }
 
@@ -9263,7 +9263,7 @@ fn _0933() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_string_exp_rhs_string",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:13))"#,
   );
 }
 
@@ -9274,7 +9274,7 @@ fn _0934() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_string_exp_rhs_boolean",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:13))"#,
   );
 }
 
@@ -9285,7 +9285,7 @@ fn _0935() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_string_exp_rhs_date",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:22))"#,
   );
 }
 
@@ -9296,7 +9296,7 @@ fn _0936() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_string_exp_rhs_dateAndTime",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:31))"#,
   );
 }
 
@@ -9307,7 +9307,7 @@ fn _0937() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_string_exp_rhs_time",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:20))"#,
   );
 }
 
@@ -9318,7 +9318,7 @@ fn _0938() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_string_exp_rhs_list",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:13))"#,
   );
 }
 
@@ -9329,7 +9329,7 @@ fn _0939() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_string_exp_rhs_context",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:16))"#,
   );
 }
 
@@ -9340,7 +9340,7 @@ fn _0940() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_string_exp_rhs_dtDuration",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:15))"#,
   );
 }
 
@@ -9351,7 +9351,7 @@ fn _0941() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_string_exp_rhs_ymDuration",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:15))"#,
   );
 }
 
@@ -9362,7 +9362,7 @@ fn _0942() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_string_exp_rhs_range",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:16))"#,
   );
 }
 
@@ -9373,7 +9373,7 @@ fn _0943() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_string_exp_rhs_function",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:24))"#,
   );
 }
 
@@ -9384,7 +9384,7 @@ fn _0944() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_string_exp_rhs_null",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:13))"#,
   );
 }
 
@@ -9395,7 +9395,7 @@ fn _0945() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_boolean_exp_rhs_number",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:11))"#,
   );
 }
 
@@ -9406,7 +9406,7 @@ fn _0946() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_boolean_exp_rhs_string",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:13))"#,
   );
 }
 
@@ -9417,7 +9417,7 @@ fn _0947() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_boolean_exp_rhs_boolean",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:13))"#,
   );
 }
 
@@ -9428,7 +9428,7 @@ fn _0948() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_boolean_exp_rhs_date",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:22))"#,
   );
 }
 
@@ -9439,7 +9439,7 @@ fn _0949() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_boolean_exp_rhs_dateAndTime",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:31))"#,
   );
 }
 
@@ -9450,7 +9450,7 @@ fn _0950() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_boolean_exp_rhs_time",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:20))"#,
   );
 }
 
@@ -9461,7 +9461,7 @@ fn _0951() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_boolean_exp_rhs_list",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:13))"#,
   );
 }
 
@@ -9472,7 +9472,7 @@ fn _0952() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_boolean_exp_rhs_context",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:16))"#,
   );
 }
 
@@ -9483,7 +9483,7 @@ fn _0953() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_boolean_exp_rhs_dtDuration",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:15))"#,
   );
 }
 
@@ -9494,7 +9494,7 @@ fn _0954() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_boolean_exp_rhs_ymDuration",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:15))"#,
   );
 }
 
@@ -9505,7 +9505,7 @@ fn _0955() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_boolean_exp_rhs_range",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:16))"#,
   );
 }
 
@@ -9516,7 +9516,7 @@ fn _0956() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_boolean_exp_rhs_function",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:24))"#,
   );
 }
 
@@ -9527,7 +9527,7 @@ fn _0957() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_boolean_exp_rhs_null",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:13))"#,
   );
 }
 
@@ -9538,7 +9538,7 @@ fn _0958() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_date_exp_rhs_number",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:20))"#,
   );
 }
 
@@ -9549,7 +9549,7 @@ fn _0959() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_date_exp_rhs_string",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:22))"#,
   );
 }
 
@@ -9560,7 +9560,7 @@ fn _0960() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_date_exp_rhs_boolean",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:22))"#,
   );
 }
 
@@ -9571,7 +9571,7 @@ fn _0961() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_date_exp_rhs_date",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:31))"#,
   );
 }
 
@@ -9582,7 +9582,7 @@ fn _0962() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_date_exp_rhs_dateAndTime",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:40))"#,
   );
 }
 
@@ -9593,7 +9593,7 @@ fn _0963() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_date_exp_rhs_time",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:29))"#,
   );
 }
 
@@ -9604,7 +9604,7 @@ fn _0964() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_date_exp_rhs_list",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:22))"#,
   );
 }
 
@@ -9615,7 +9615,7 @@ fn _0965() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_date_exp_rhs_context",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:25))"#,
   );
 }
 
@@ -9626,7 +9626,7 @@ fn _0966() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_date_exp_rhs_dtDuration",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:24))"#,
   );
 }
 
@@ -9637,7 +9637,7 @@ fn _0967() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_date_exp_rhs_ymDuration",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:24))"#,
   );
 }
 
@@ -9648,7 +9648,7 @@ fn _0968() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_date_exp_rhs_range",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:25))"#,
   );
 }
 
@@ -9659,7 +9659,7 @@ fn _0969() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_date_exp_rhs_function",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:33))"#,
   );
 }
 
@@ -9670,7 +9670,7 @@ fn _0970() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_date_exp_rhs_null",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:22))"#,
   );
 }
 
@@ -9681,7 +9681,7 @@ fn _0971() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_dateAndTime_exp_rhs_number",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:29))"#,
   );
 }
 
@@ -9692,7 +9692,7 @@ fn _0972() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_dateAndTime_exp_rhs_string",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:31))"#,
   );
 }
 
@@ -9703,7 +9703,7 @@ fn _0973() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_dateAndTime_exp_rhs_boolean",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:31))"#,
   );
 }
 
@@ -9714,7 +9714,7 @@ fn _0974() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_dateAndTime_exp_rhs_date",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:40))"#,
   );
 }
 
@@ -9725,7 +9725,7 @@ fn _0975() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_dateAndTime_exp_rhs_dateAndTime",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:49))"#,
   );
 }
 
@@ -9736,7 +9736,7 @@ fn _0976() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_dateAndTime_exp_rhs_time",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:38))"#,
   );
 }
 
@@ -9747,7 +9747,7 @@ fn _0977() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_dateAndTime_exp_rhs_list",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:31))"#,
   );
 }
 
@@ -9758,7 +9758,7 @@ fn _0978() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_dateAndTime_exp_rhs_context",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:34))"#,
   );
 }
 
@@ -9769,7 +9769,7 @@ fn _0979() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_dateAndTime_exp_rhs_dtDuration",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:33))"#,
   );
 }
 
@@ -9780,7 +9780,7 @@ fn _0980() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_dateAndTime_exp_rhs_ymDuration",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:33))"#,
   );
 }
 
@@ -9791,7 +9791,7 @@ fn _0981() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_dateAndTime_exp_rhs_range",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:34))"#,
   );
 }
 
@@ -9802,7 +9802,7 @@ fn _0982() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_dateAndTime_exp_rhs_function",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:42))"#,
   );
 }
 
@@ -9813,7 +9813,7 @@ fn _0983() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_dateAndTime_exp_rhs_null",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:31))"#,
   );
 }
 
@@ -9824,7 +9824,7 @@ fn _0984() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_time_exp_rhs_number",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:18))"#,
   );
 }
 
@@ -9835,7 +9835,7 @@ fn _0985() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_time_exp_rhs_string",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:20))"#,
   );
 }
 
@@ -9846,7 +9846,7 @@ fn _0986() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_time_exp_rhs_boolean",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:20))"#,
   );
 }
 
@@ -9857,7 +9857,7 @@ fn _0987() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_time_exp_rhs_date",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:29))"#,
   );
 }
 
@@ -9868,7 +9868,7 @@ fn _0988() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_time_exp_rhs_dateAndTime",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:38))"#,
   );
 }
 
@@ -9879,7 +9879,7 @@ fn _0989() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_time_exp_rhs_time",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:27))"#,
   );
 }
 
@@ -9890,7 +9890,7 @@ fn _0990() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_time_exp_rhs_list",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:20))"#,
   );
 }
 
@@ -9901,7 +9901,7 @@ fn _0991() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_time_exp_rhs_context",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:23))"#,
   );
 }
 
@@ -9912,7 +9912,7 @@ fn _0992() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_time_exp_rhs_dtDuration",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:22))"#,
   );
 }
 
@@ -9923,7 +9923,7 @@ fn _0993() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_time_exp_rhs_ymDuration",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:22))"#,
   );
 }
 
@@ -9934,7 +9934,7 @@ fn _0994() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_time_exp_rhs_range",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:23))"#,
   );
 }
 
@@ -9945,7 +9945,7 @@ fn _0995() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_time_exp_rhs_function",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:31))"#,
   );
 }
 
@@ -9956,7 +9956,7 @@ fn _0996() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_time_exp_rhs_null",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:20))"#,
   );
 }
 
@@ -9967,7 +9967,7 @@ fn _0997() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_list_exp_rhs_number",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:11))"#,
   );
 }
 
@@ -9978,7 +9978,7 @@ fn _0998() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_list_exp_rhs_string",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:13))"#,
   );
 }
 
@@ -9989,7 +9989,7 @@ fn _0999() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_list_exp_rhs_boolean",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:13))"#,
   );
 }
 
@@ -10000,7 +10000,7 @@ fn _1000() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_list_exp_rhs_date",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:22))"#,
   );
 }
 
@@ -10011,7 +10011,7 @@ fn _1001() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_list_exp_rhs_dateAndTime",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:31))"#,
   );
 }
 
@@ -10022,7 +10022,7 @@ fn _1002() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_list_exp_rhs_time",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:20))"#,
   );
 }
 
@@ -10033,7 +10033,7 @@ fn _1003() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_list_exp_rhs_list",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:13))"#,
   );
 }
 
@@ -10044,7 +10044,7 @@ fn _1004() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_list_exp_rhs_context",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:16))"#,
   );
 }
 
@@ -10055,7 +10055,7 @@ fn _1005() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_list_exp_rhs_dtDuration",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:15))"#,
   );
 }
 
@@ -10066,7 +10066,7 @@ fn _1006() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_list_exp_rhs_ymDuration",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:15))"#,
   );
 }
 
@@ -10077,7 +10077,7 @@ fn _1007() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_list_exp_rhs_range",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:16))"#,
   );
 }
 
@@ -10088,7 +10088,7 @@ fn _1008() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_list_exp_rhs_function",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:24))"#,
   );
 }
 
@@ -10099,7 +10099,7 @@ fn _1009() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_list_exp_rhs_null",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:13))"#,
   );
 }
 
@@ -10110,7 +10110,7 @@ fn _1010() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_context_exp_rhs_number",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:14))"#,
   );
 }
 
@@ -10121,7 +10121,7 @@ fn _1011() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_context_exp_rhs_string",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:16))"#,
   );
 }
 
@@ -10132,7 +10132,7 @@ fn _1012() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_context_exp_rhs_boolean",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:16))"#,
   );
 }
 
@@ -10143,7 +10143,7 @@ fn _1013() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_context_exp_rhs_date",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:25))"#,
   );
 }
 
@@ -10154,7 +10154,7 @@ fn _1014() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_context_exp_rhs_dateAndTime",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:34))"#,
   );
 }
 
@@ -10165,7 +10165,7 @@ fn _1015() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_context_exp_rhs_time",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:23))"#,
   );
 }
 
@@ -10176,7 +10176,7 @@ fn _1016() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_context_exp_rhs_list",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:16))"#,
   );
 }
 
@@ -10187,7 +10187,7 @@ fn _1017() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_context_exp_rhs_context",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:19))"#,
   );
 }
 
@@ -10198,7 +10198,7 @@ fn _1018() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_context_exp_rhs_dtDuration",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:18))"#,
   );
 }
 
@@ -10209,7 +10209,7 @@ fn _1019() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_context_exp_rhs_ymDuration",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:18))"#,
   );
 }
 
@@ -10220,7 +10220,7 @@ fn _1020() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_context_exp_rhs_range",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:19))"#,
   );
 }
 
@@ -10231,7 +10231,7 @@ fn _1021() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_context_exp_rhs_function",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:27))"#,
   );
 }
 
@@ -10242,7 +10242,7 @@ fn _1022() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_context_exp_rhs_null",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:16))"#,
   );
 }
 
@@ -10253,7 +10253,7 @@ fn _1023() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_dtDuration_exp_rhs_number",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:13))"#,
   );
 }
 
@@ -10264,7 +10264,7 @@ fn _1024() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_dtDuration_exp_rhs_string",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:15))"#,
   );
 }
 
@@ -10275,7 +10275,7 @@ fn _1025() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_dtDuration_exp_rhs_boolean",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:15))"#,
   );
 }
 
@@ -10286,7 +10286,7 @@ fn _1026() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_dtDuration_exp_rhs_date",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:24))"#,
   );
 }
 
@@ -10297,7 +10297,7 @@ fn _1027() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_dtDuration_exp_rhs_dateAndTime",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:33))"#,
   );
 }
 
@@ -10308,7 +10308,7 @@ fn _1028() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_dtDuration_exp_rhs_time",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:22))"#,
   );
 }
 
@@ -10319,7 +10319,7 @@ fn _1029() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_dtDuration_exp_rhs_list",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:15))"#,
   );
 }
 
@@ -10330,7 +10330,7 @@ fn _1030() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_dtDuration_exp_rhs_context",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:18))"#,
   );
 }
 
@@ -10341,7 +10341,7 @@ fn _1031() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_dtDuration_exp_rhs_dtDuration",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:17))"#,
   );
 }
 
@@ -10352,7 +10352,7 @@ fn _1032() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_dtDuration_exp_rhs_ymDuration",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:17))"#,
   );
 }
 
@@ -10363,7 +10363,7 @@ fn _1033() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_dtDuration_exp_rhs_range",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:18))"#,
   );
 }
 
@@ -10374,7 +10374,7 @@ fn _1034() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_dtDuration_exp_rhs_function",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:26))"#,
   );
 }
 
@@ -10385,7 +10385,7 @@ fn _1035() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_dtDuration_exp_rhs_null",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:15))"#,
   );
 }
 
@@ -10396,7 +10396,7 @@ fn _1036() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_ymDuration_exp_rhs_number",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:13))"#,
   );
 }
 
@@ -10407,7 +10407,7 @@ fn _1037() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_ymDuration_exp_rhs_string",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:15))"#,
   );
 }
 
@@ -10418,7 +10418,7 @@ fn _1038() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_ymDuration_exp_rhs_boolean",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:15))"#,
   );
 }
 
@@ -10429,7 +10429,7 @@ fn _1039() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_ymDuration_exp_rhs_date",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:24))"#,
   );
 }
 
@@ -10440,7 +10440,7 @@ fn _1040() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_ymDuration_exp_rhs_dateAndTime",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:33))"#,
   );
 }
 
@@ -10451,7 +10451,7 @@ fn _1041() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_ymDuration_exp_rhs_time",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:22))"#,
   );
 }
 
@@ -10462,7 +10462,7 @@ fn _1042() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_ymDuration_exp_rhs_list",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:15))"#,
   );
 }
 
@@ -10473,7 +10473,7 @@ fn _1043() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_ymDuration_exp_rhs_context",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:18))"#,
   );
 }
 
@@ -10484,7 +10484,7 @@ fn _1044() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_ymDuration_exp_rhs_dtDuration",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:17))"#,
   );
 }
 
@@ -10495,7 +10495,7 @@ fn _1045() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_ymDuration_exp_rhs_ymDuration",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:17))"#,
   );
 }
 
@@ -10506,7 +10506,7 @@ fn _1046() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_ymDuration_exp_rhs_range",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:18))"#,
   );
 }
 
@@ -10517,7 +10517,7 @@ fn _1047() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_ymDuration_exp_rhs_function",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:26))"#,
   );
 }
 
@@ -10528,7 +10528,7 @@ fn _1048() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_ymDuration_exp_rhs_null",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:15))"#,
   );
 }
 
@@ -10539,7 +10539,7 @@ fn _1049() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_range_exp_rhs_number",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:14))"#,
   );
 }
 
@@ -10550,7 +10550,7 @@ fn _1050() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_range_exp_rhs_string",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:16))"#,
   );
 }
 
@@ -10561,7 +10561,7 @@ fn _1051() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_range_exp_rhs_boolean",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:16))"#,
   );
 }
 
@@ -10572,7 +10572,7 @@ fn _1052() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_range_exp_rhs_date",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:25))"#,
   );
 }
 
@@ -10583,7 +10583,7 @@ fn _1053() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_range_exp_rhs_dateAndTime",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:34))"#,
   );
 }
 
@@ -10594,7 +10594,7 @@ fn _1054() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_range_exp_rhs_time",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:23))"#,
   );
 }
 
@@ -10605,7 +10605,7 @@ fn _1055() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_range_exp_rhs_list",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:16))"#,
   );
 }
 
@@ -10616,7 +10616,7 @@ fn _1056() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_range_exp_rhs_context",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:19))"#,
   );
 }
 
@@ -10627,7 +10627,7 @@ fn _1057() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_range_exp_rhs_dtDuration",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:18))"#,
   );
 }
 
@@ -10638,7 +10638,7 @@ fn _1058() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_range_exp_rhs_ymDuration",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:18))"#,
   );
 }
 
@@ -10649,7 +10649,7 @@ fn _1059() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_range_exp_rhs_range",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:19))"#,
   );
 }
 
@@ -10660,7 +10660,7 @@ fn _1060() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_range_exp_rhs_function",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:27))"#,
   );
 }
 
@@ -10671,7 +10671,7 @@ fn _1061() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_range_exp_rhs_null",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:16))"#,
   );
 }
 
@@ -10682,7 +10682,7 @@ fn _1062() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_function_exp_rhs_number",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:22))"#,
   );
 }
 
@@ -10693,7 +10693,7 @@ fn _1063() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_function_exp_rhs_string",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:24))"#,
   );
 }
 
@@ -10704,7 +10704,7 @@ fn _1064() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_function_exp_rhs_boolean",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:24))"#,
   );
 }
 
@@ -10715,7 +10715,7 @@ fn _1065() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_function_exp_rhs_date",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:33))"#,
   );
 }
 
@@ -10726,7 +10726,7 @@ fn _1066() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_function_exp_rhs_dateAndTime",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:42))"#,
   );
 }
 
@@ -10737,7 +10737,7 @@ fn _1067() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_function_exp_rhs_time",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:31))"#,
   );
 }
 
@@ -10748,7 +10748,7 @@ fn _1068() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_function_exp_rhs_list",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:24))"#,
   );
 }
 
@@ -10759,7 +10759,7 @@ fn _1069() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_function_exp_rhs_context",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:27))"#,
   );
 }
 
@@ -10770,7 +10770,7 @@ fn _1070() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_function_exp_rhs_dtDuration",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:26))"#,
   );
 }
 
@@ -10781,7 +10781,7 @@ fn _1071() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_function_exp_rhs_ymDuration",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:26))"#,
   );
 }
 
@@ -10792,7 +10792,7 @@ fn _1072() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_function_exp_rhs_range",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:27))"#,
   );
 }
 
@@ -10803,7 +10803,7 @@ fn _1073() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_function_exp_rhs_function",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:35))"#,
   );
 }
 
@@ -10814,7 +10814,7 @@ fn _1074() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_function_exp_rhs_null",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:24))"#,
   );
 }
 
@@ -10825,7 +10825,7 @@ fn _1075() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_null_exp_rhs_number",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:11))"#,
   );
 }
 
@@ -10836,7 +10836,7 @@ fn _1076() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_null_exp_rhs_string",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:13))"#,
   );
 }
 
@@ -10847,7 +10847,7 @@ fn _1077() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_null_exp_rhs_boolean",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:13))"#,
   );
 }
 
@@ -10858,7 +10858,7 @@ fn _1078() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_null_exp_rhs_date",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:22))"#,
   );
 }
 
@@ -10869,7 +10869,7 @@ fn _1079() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_null_exp_rhs_dateAndTime",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:31))"#,
   );
 }
 
@@ -10880,7 +10880,7 @@ fn _1080() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_null_exp_rhs_time",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:20))"#,
   );
 }
 
@@ -10891,7 +10891,7 @@ fn _1081() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_null_exp_rhs_list",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:13))"#,
   );
 }
 
@@ -10902,7 +10902,7 @@ fn _1082() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_null_exp_rhs_context",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:16))"#,
   );
 }
 
@@ -10913,7 +10913,7 @@ fn _1083() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_null_exp_rhs_dtDuration",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:15))"#,
   );
 }
 
@@ -10924,7 +10924,7 @@ fn _1084() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_null_exp_rhs_ymDuration",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:15))"#,
   );
 }
 
@@ -10935,7 +10935,7 @@ fn _1085() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_null_exp_rhs_range",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:16))"#,
   );
 }
 
@@ -10946,7 +10946,7 @@ fn _1086() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_null_exp_rhs_function",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:24))"#,
   );
 }
 
@@ -10957,6 +10957,6 @@ fn _1087() {
     &MODEL_NAMESPACE,
     "error_when_exponent_lhs_null_exp_rhs_null",
     &CTX,
-    r#"null(exponentiation base is not a number)"#,
+    r#"null(exponentiation base is not a number (at 1:1..1:13))"#,
   );
 }
@@ -42,7 +42,7 @@ fn _0001() {
     &MODEL_NAMESPACE,
     "feel-contains-function_ErrorCase_001_2a4d7448c6",
     &ctx,
-    r#"null([core::contains] invalid argument type, expected string, actual type is Null)"#,
+    r#"null([core::contains] invalid argument type, expected string, actual type is Null (at 1:1..1:20))"#,
   );
 }
 
@@ -54,7 +54,7 @@ fn _0002() {
     &MODEL_NAMESPACE,
     "feel-contains-function_ErrorCase_002_d2a1831b5c",
     &ctx,
-    r#"null([core::contains] invalid argument type, expected string, actual type is Null)"#,
+    r#"null([core::contains] invalid argument type, expected string, actual type is Null (at 1:1..1:21))"#,
   );
 }
 
@@ -66,7 +66,7 @@ fn _0003() {
     &MODEL_NAMESPACE,
     "feel-contains-function_ErrorCase_003_df56e0a1ad",
     &ctx,
-    r#"null([core::contains] invalid argument type, expected string, actual type is Null)"#,
+    r#"null([core::contains] invalid argument type, expected string, actual type is Null (at 1:1..1:21))"#,
   );
 }
 
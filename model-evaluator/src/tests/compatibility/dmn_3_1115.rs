@@ -42,7 +42,7 @@ fn _0001() {
     &MODEL_NAMESPACE,
     "feel-date-function_ErrorCase_001_e9ae035ab9",
     &ctx,
-    r#"null([core::date] invalid argument type, expected string, date or date and time, actual type is Null)"#,
+    r#"null([core::date] invalid argument type, expected string, date or date and time, actual type is Null (at 1:5..1:11))"#,
   );
 }
 
@@ -54,7 +54,7 @@ fn _0002() {
     &MODEL_NAMESPACE,
     "feel-date-function_ErrorCase_002_9b9e6085ce",
     &ctx,
-    r#"null(expected 1,3 parameters, actual number of parameters is 2)"#,
+    r#"null(expected 1,3 parameters, actual number of parameters is 2 (at 1:5..1:16))"#,
   );
 }
 
@@ -66,7 +66,7 @@ fn _0003() {
     &MODEL_NAMESPACE,
     "feel-date-function_ErrorCase_003_e4b7918d8f",
     &ctx,
-    r#"null([core::date] invalid argument type, expected number (year), actual type is Null)"#,
+    r#"null([core::date] invalid argument type, expected number (year), actual type is Null (at 1:5..1:15))"#,
   );
 }
 
@@ -78,7 +78,7 @@ fn _0004() {
     &MODEL_NAMESPACE,
     "feel-date-function_ErrorCase_004_f24ed41117",
     &ctx,
-    r#"null([core::date] invalid argument type, expected number (month), actual type is Null)"#,
+    r#"null([core::date] invalid argument type, expected number (month), actual type is Null (at 1:5..1:18))"#,
   );
 }
 
@@ -90,7 +90,7 @@ fn _0005() {
     &MODEL_NAMESPACE,
     "feel-date-function_ErrorCase_005_3540a22062",
     &ctx,
-    r#"null([core::date] invalid argument type, expected number (day), actual type is Null)"#,
+    r#"null([core::date] invalid argument type, expected number (day), actual type is Null (at 1:5..1:18))"#,
   );
 }
 
@@ -102,7 +102,7 @@ fn _0006() {
     &MODEL_NAMESPACE,
     "feel-date-function_ErrorCase_006_616e24dbb7",
     &ctx,
-    r#"null([core::date] invalid argument type, expected number (year), actual type is Null)"#,
+    r#"null([core::date] invalid argument type, expected number (year), actual type is Null (at 1:5..1:18))"#,
   );
 }
 
@@ -114,7 +114,7 @@ fn _0007() {
     &MODEL_NAMESPACE,
     "feel-date-function_ErrorCase_007_cda82a5d01",
     &ctx,
-    r#"null([core::date] invalid argument type, expected number (year), actual type is Null)"#,
+    r#"null([core::date] invalid argument type, expected number (year), actual type is Null (at 1:5..1:19))"#,
   );
 }
 
@@ -126,7 +126,7 @@ fn _0008() {
     &MODEL_NAMESPACE,
     "feel-date-function_ErrorCase_008_492649d3d0",
     &ctx,
-    r#"null([core::date] invalid argument type, expected number (month), actual type is Null)"#,
+    r#"null([core::date] invalid argument type, expected number (month), actual type is Null (at 1:5..1:21))"#,
   );
 }
 
@@ -138,7 +138,7 @@ fn _0009() {
     &MODEL_NAMESPACE,
     "feel-date-function_ErrorCase_009_9e00bbdad3",
     &ctx,
-    r#"null([core::date] invalid argument type, expected number (year), actual type is Null)"#,
+    r#"null([core::date] invalid argument type, expected number (year), actual type is Null (at 1:5..1:21))"#,
   );
 }
 
@@ -150,7 +150,7 @@ fn _0010() {
     &MODEL_NAMESPACE,
     "feel-date-function_ErrorCase_010_6d4d58d23a",
     &ctx,
-    r#"null(expected 1,3 parameters, actual number of parameters is 0)"#,
+    r#"null(expected 1,3 parameters, actual number of parameters is 0 (at 1:5..1:7))"#,
   );
 }
 
@@ -282,7 +282,7 @@ fn _0031() {
     &MODEL_NAMESPACE,
     "feel-date-function_ErrorCase_031_4f5ec70669",
     &ctx,
-    r#"null([core::date] invalid date string '2012-12-25T')"#,
+    r#"null([core::date] invalid date string '2012-12-25T' (at 1:5..1:20))"#,
   );
 }
 
@@ -294,7 +294,7 @@ fn _0032() {
     &MODEL_NAMESPACE,
     "feel-date-function_ErrorCase_032_fc66cc2fec",
     &ctx,
-    r#"null([core::date] invalid date string '')"#,
+    r#"null([core::date] invalid date string '' (at 1:5..1:9))"#,
   );
 }
 
@@ -306,7 +306,7 @@ fn _0033() {
     &MODEL_NAMESPACE,
     "feel-date-function_ErrorCase_033_c3a5600c62",
     &ctx,
-    r#"null([core::date] invalid date string '2012/12/25')"#,
+    r#"null([core::date] invalid date string '2012/12/25' (at 1:5..1:19))"#,
   );
 }
 
@@ -318,7 +318,7 @@ fn _0034() {
     &MODEL_NAMESPACE,
     "feel-date-function_ErrorCase_034_7d2e18a10c",
     &ctx,
-    r#"null([core::date] invalid date string '0000-12-25T')"#,
+    r#"null([core::date] invalid date string '0000-12-25T' (at 1:5..1:20))"#,
   );
 }
 
@@ -330,7 +330,7 @@ fn _0035() {
     &MODEL_NAMESPACE,
     "feel-date-function_ErrorCase_035_e6c1bb43fd",
     &ctx,
-    r#"null([core::date] invalid date string '9999999999-12-25')"#,
+    r#"null([core::date] invalid date string '9999999999-12-25' (at 1:5..1:25))"#,
   );
 }
 
@@ -342,7 +342,7 @@ fn _0036() {
     &MODEL_NAMESPACE,
     "feel-date-function_ErrorCase_036_b826a6b5f9",
     &ctx,
-    r#"null([core::date] invalid date string '2017-13-10')"#,
+    r#"null([core::date] invalid date string '2017-13-10' (at 1:5..1:19))"#,
   );
 }
 
@@ -354,7 +354,7 @@ fn _0037() {
     &MODEL_NAMESPACE,
     "feel-date-function_ErrorCase_037_cfd70896b6",
     &ctx,
-    r#"null([core::date] invalid date string '2017-12-32')"#,
+    r#"null([core::date] invalid date string '2017-12-32' (at 1:5..1:19))"#,
   );
 }
 
@@ -366,7 +366,7 @@ fn _0038() {
     &MODEL_NAMESPACE,
     "feel-date-function_ErrorCase_038_c26782f559",
     &ctx,
-    r#"null([core::date] invalid date string '998-12-31')"#,
+    r#"null([core::date] invalid date string '998-12-31' (at 1:5..1:18))"#,
   );
 }
 
@@ -378,7 +378,7 @@ fn _0039() {
     &MODEL_NAMESPACE,
     "feel-date-function_ErrorCase_039_67a6eafa3f",
     &ctx,
-    r#"null([core::date] invalid date string '01211-12-31')"#,
+    r#"null([core::date] invalid date string '01211-12-31' (at 1:5..1:20))"#,
   );
 }
 
@@ -390,7 +390,7 @@ fn _0040() {
     &MODEL_NAMESPACE,
     "feel-date-function_ErrorCase_040_dd2a2ed4a2",
     &ctx,
-    r#"null([core::date] invalid date string '2012T-12-2511:00:00Z')"#,
+    r#"null([core::date] invalid date string '2012T-12-2511:00:00Z' (at 1:5..1:29))"#,
   );
 }
 
@@ -402,7 +402,7 @@ fn _0041() {
     &MODEL_NAMESPACE,
     "feel-date-function_ErrorCase_041_9e7e388146",
     &ctx,
-    r#"null([core::date] invalid date string '+2012-12-02')"#,
+    r#"null([core::date] invalid date string '+2012-12-02' (at 1:5..1:20))"#,
   );
 }
 
@@ -414,7 +414,7 @@ fn _0042() {
     &MODEL_NAMESPACE,
     "feel-date-function_ErrorCase_042_8f5dd97588",
     &ctx,
-    r#"null([core::date_3] invalid date y=2017 m=13 d=31)"#,
+    r#"null([core::date_3] invalid date y=2017 m=13 d=31 (at 1:5..1:17))"#,
   );
 }
 
@@ -426,7 +426,7 @@ fn _0043() {
     &MODEL_NAMESPACE,
     "feel-date-function_ErrorCase_043_8f82301fac",
     &ctx,
-    r#"null([core::date_3] invalid date y=2017 m=12 d=32)"#,
+    r#"null([core::date_3] invalid date y=2017 m=12 d=32 (at 1:5..1:17))"#,
   );
 }
 
@@ -438,7 +438,7 @@ fn _0044() {
     &MODEL_NAMESPACE,
     "feel-date-function_ErrorCase_044_74893220b4",
     &ctx,
-    r#"null([core::date_3] invalid date y=2017 m=-8 d=2)"#,
+    r#"null([core::date_3] invalid date y=2017 m=-8 d=2 (at 1:5..1:16))"#,
   );
 }
 
@@ -450,7 +450,7 @@ fn _0045() {
     &MODEL_NAMESPACE,
     "feel-date-function_ErrorCase_045_969723fed5",
     &ctx,
-    r#"null([core::date_3] invalid date y=2017 m=8 d=-2)"#,
+    r#"null([core::date_3] invalid date y=2017 m=8 d=-2 (at 1:5..1:16))"#,
   );
 }
 
@@ -462,7 +462,7 @@ fn _0046() {
     &MODEL_NAMESPACE,
     "feel-date-function_ErrorCase_046_36bf30268a",
     &ctx,
-    r#"null([core::date_3] invalid date y=-1000999999 m=12 d=1)"#,
+    r#"null([core::date_3] invalid date y=-1000999999 m=12 d=1 (at 1:5..1:24))"#,
   );
 }
 
@@ -474,7 +474,7 @@ fn _0047() {
     &MODEL_NAMESPACE,
     "feel-date-function_ErrorCase_047_ba717eb672",
     &ctx,
-    r#"null([core::date_3] invalid date y=1000999999 m=12 d=32)"#,
+    r#"null([core::date_3] invalid date y=1000999999 m=12 d=32 (at 1:5..1:23))"#,
   );
 }
 
@@ -486,7 +486,7 @@ fn _0048() {
     &MODEL_NAMESPACE,
     "feel-date-function_ErrorCase_048_25595a6420",
     &ctx,
-    r#"null([core::date] invalid argument type, expected string, date or date and time, actual type is number)"#,
+    r#"null([core::date] invalid argument type, expected string, date or date and time, actual type is number (at 1:5..1:8))"#,
   );
 }
 
@@ -498,7 +498,7 @@ fn _0049() {
     &MODEL_NAMESPACE,
     "feel-date-function_ErrorCase_049_a1644ce710",
     &ctx,
-    r#"null([core::date] invalid argument type, expected string, date or date and time, actual type is list<Null>)"#,
+    r#"null([core::date] invalid argument type, expected string, date or date and time, actual type is list<Null> (at 1:5..1:9))"#,
   );
 }
 
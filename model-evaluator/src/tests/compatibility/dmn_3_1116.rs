@@ -37,7 +37,13 @@ from_examples!(DMN_3_1116);
 #[test]
 fn _0001() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "feel-time-function_ErrorCase_001_bdf26fdc72", &ctx, r#"null(time_1)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "feel-time-function_ErrorCase_001_bdf26fdc72",
+    &ctx,
+    r#"null(time_1 (at 1:5..1:11))"#,
+  );
 }
 
 #[test]
@@ -48,7 +54,7 @@ fn _0002() {
     &MODEL_NAMESPACE,
     "feel-time-function_ErrorCase_002_9d2e399b96",
     &ctx,
-    r#"null([core::time_4] hour must be a number, current type is: Null)"#,
+    r#"null([core::time_4] hour must be a number, current type is: Null (at 1:5..1:33))"#,
   );
 }
 
@@ -60,7 +66,7 @@ fn _0003() {
     &MODEL_NAMESPACE,
     "feel-time-function_ErrorCase_003_d1f0ea5bb9",
     &ctx,
-    r#"null([core::time_4] minutes must be a number, current type is: Null)"#,
+    r#"null([core::time_4] minutes must be a number, current type is: Null (at 1:5..1:33))"#,
   );
 }
 
@@ -72,7 +78,7 @@ fn _0004() {
     &MODEL_NAMESPACE,
     "feel-time-function_ErrorCase_004_57aea91d1c",
     &ctx,
-    r#"null([core::time_4] seconds must be a number, current type is: Null)"#,
+    r#"null([core::time_4] seconds must be a number, current type is: Null (at 1:5..1:32))"#,
   );
 }
 
@@ -84,7 +90,7 @@ fn _0005() {
     &MODEL_NAMESPACE,
     "feel-time-function_ErrorCase_005_32ea20b34f",
     &ctx,
-    r#"null([core::time_4] hour must be a number, current type is: Null)"#,
+    r#"null([core::time_4] hour must be a number, current type is: Null (at 1:5..1:35))"#,
   );
 }
 
@@ -96,7 +102,7 @@ fn _0006() {
     &MODEL_NAMESPACE,
     "feel-time-function_ErrorCase_006_e266498180",
     &ctx,
-    r#"null([core::time_4] hour must be a number, current type is: Null)"#,
+    r#"null([core::time_4] hour must be a number, current type is: Null (at 1:5..1:35))"#,
   );
 }
 
@@ -108,7 +114,7 @@ fn _0007() {
     &MODEL_NAMESPACE,
     "feel-time-function_ErrorCase_007_ee82c7bf12",
     &ctx,
-    r#"null([core::time_4] hour must be a number, current type is: Null)"#,
+    r#"null([core::time_4] hour must be a number, current type is: Null (at 1:5..1:22))"#,
   );
 }
 
@@ -120,7 +126,7 @@ fn _0008() {
     &MODEL_NAMESPACE,
     "feel-time-function_ErrorCase_008_08078c6c29",
     &ctx,
-    r#"null([core::time_4] minutes must be a number, current type is: Null)"#,
+    r#"null([core::time_4] minutes must be a number, current type is: Null (at 1:5..1:35))"#,
   );
 }
 
@@ -132,7 +138,7 @@ fn _0009() {
     &MODEL_NAMESPACE,
     "feel-time-function_ErrorCase_009_804c21ed52",
     &ctx,
-    r#"null([core::time_4] seconds must be a number, current type is: Null)"#,
+    r#"null([core::time_4] seconds must be a number, current type is: Null (at 1:5..1:22))"#,
   );
 }
 
@@ -144,7 +150,7 @@ fn _0010() {
     &MODEL_NAMESPACE,
     "feel-time-function_ErrorCase_010_cc773bb44b",
     &ctx,
-    r#"null([core::time_4] minutes must be a number, current type is: Null)"#,
+    r#"null([core::time_4] minutes must be a number, current type is: Null (at 1:5..1:24))"#,
   );
 }
 
@@ -156,7 +162,7 @@ fn _0011() {
     &MODEL_NAMESPACE,
     "feel-time-function_ErrorCase_011_ad5b3a26b5",
     &ctx,
-    r#"null([core::time_4] hour must be a number, current type is: Null)"#,
+    r#"null([core::time_4] hour must be a number, current type is: Null (at 1:5..1:23))"#,
   );
 }
 
@@ -168,7 +174,7 @@ fn _0012() {
     &MODEL_NAMESPACE,
     "feel-time-function_ErrorCase_012_3c2f416fc9",
     &ctx,
-    r#"null([core::time_4] hour must be a number, current type is: Null)"#,
+    r#"null([core::time_4] hour must be a number, current type is: Null (at 1:5..1:24))"#,
   );
 }
 
@@ -180,7 +186,7 @@ fn _0013() {
     &MODEL_NAMESPACE,
     "feel-time-function_ErrorCase_013_7f22c0bda8",
     &ctx,
-    r#"null([core::time_4] hour must be a number, current type is: Null)"#,
+    r#"null([core::time_4] hour must be a number, current type is: Null (at 1:5..1:37))"#,
   );
 }
 
@@ -192,7 +198,7 @@ fn _0014() {
     &MODEL_NAMESPACE,
     "feel-time-function_ErrorCase_014_0dc13176e8",
     &ctx,
-    r#"null([core::time_4] hour must be a number, current type is: Null)"#,
+    r#"null([core::time_4] hour must be a number, current type is: Null (at 1:5..1:26))"#,
   );
 }
 
@@ -210,7 +216,7 @@ fn _0016() {
     &MODEL_NAMESPACE,
     "feel-time-function_ErrorCase_016_c3cccff405",
     &ctx,
-    r#"null(expected 1,3,4 parameters, actual number of parameters is 0)"#,
+    r#"null(expected 1,3,4 parameters, actual number of parameters is 0 (at 1:5..1:7))"#,
   );
 }
 
@@ -439,133 +445,265 @@ fn _0053() {
 #[test]
 fn _0054() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "feel-time-function_ErrorCase_054_fdc3094237", &ctx, r#"null(time_1)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "feel-time-function_ErrorCase_054_fdc3094237",
+    &ctx,
+    r#"null(time_1 (at 1:5..1:11))"#,
+  );
 }
 
 #[test]
 fn _0055() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "feel-time-function_ErrorCase_055_9b47db6ea4", &ctx, r#"null(time_1)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "feel-time-function_ErrorCase_055_9b47db6ea4",
+    &ctx,
+    r#"null(time_1 (at 1:5..1:9))"#,
+  );
 }
 
 #[test]
 fn _0056() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "feel-time-function_ErrorCase_056_a8e828d64d", &ctx, r#"null(time_1)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "feel-time-function_ErrorCase_056_a8e828d64d",
+    &ctx,
+    r#"null(time_1 (at 1:5..1:9))"#,
+  );
 }
 
 #[test]
 fn _0057() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "feel-time-function_ErrorCase_057_d039115cce", &ctx, r#"null(time_1)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "feel-time-function_ErrorCase_057_d039115cce",
+    &ctx,
+    r#"null(time_1 (at 1:5..1:17))"#,
+  );
 }
 
 #[test]
 fn _0058() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "feel-time-function_ErrorCase_058_81dd4b1639", &ctx, r#"null(time_1)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "feel-time-function_ErrorCase_058_81dd4b1639",
+    &ctx,
+    r#"null(time_1 (at 1:5..1:17))"#,
+  );
 }
 
 #[test]
 fn _0059() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "feel-time-function_ErrorCase_059_c7e1705fe1", &ctx, r#"null(time_1)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "feel-time-function_ErrorCase_059_c7e1705fe1",
+    &ctx,
+    r#"null(time_1 (at 1:5..1:17))"#,
+  );
 }
 
 #[test]
 fn _0060() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "feel-time-function_ErrorCase_060_0cf4734fae", &ctx, r#"null(time_1)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "feel-time-function_ErrorCase_060_0cf4734fae",
+    &ctx,
+    r#"null(time_1 (at 1:5..1:17))"#,
+  );
 }
 
 #[test]
 fn _0061() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "feel-time-function_ErrorCase_061_da2717f085", &ctx, r#"null(time_1)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "feel-time-function_ErrorCase_061_da2717f085",
+    &ctx,
+    r#"null(time_1 (at 1:5..1:17))"#,
+  );
 }
 
 #[test]
 fn _0062() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "feel-time-function_ErrorCase_062_6cd1313fa9", &ctx, r#"null(time_1)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "feel-time-function_ErrorCase_062_6cd1313fa9",
+    &ctx,
+    r#"null(time_1 (at 1:5..1:17))"#,
+  );
 }
 
 #[test]
 fn _0063() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "feel-time-function_ErrorCase_063_e85c40b474", &ctx, r#"null(time_1)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "feel-time-function_ErrorCase_063_e85c40b474",
+    &ctx,
+    r#"null(time_1 (at 1:5..1:16))"#,
+  );
 }
 
 #[test]
 fn _0064() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "feel-time-function_ErrorCase_064_df74038c67", &ctx, r#"null(time_1)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "feel-time-function_ErrorCase_064_df74038c67",
+    &ctx,
+    r#"null(time_1 (at 1:5..1:16))"#,
+  );
 }
 
 #[test]
 fn _0065() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "feel-time-function_ErrorCase_065_79eaef6fee", &ctx, r#"null(time_1)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "feel-time-function_ErrorCase_065_79eaef6fee",
+    &ctx,
+    r#"null(time_1 (at 1:5..1:16))"#,
+  );
 }
 
 #[test]
 fn _0066() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "feel-time-function_ErrorCase_066_5116e12fd3", &ctx, r#"null(time_1)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "feel-time-function_ErrorCase_066_5116e12fd3",
+    &ctx,
+    r#"null(time_1 (at 1:5..1:25))"#,
+  );
 }
 
 #[test]
 fn _0067() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "feel-time-function_ErrorCase_067_8285edad7b", &ctx, r#"null(time_1)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "feel-time-function_ErrorCase_067_8285edad7b",
+    &ctx,
+    r#"null(time_1 (at 1:5..1:23))"#,
+  );
 }
 
 #[test]
 fn _0068() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "feel-time-function_ErrorCase_068_ad528abb23", &ctx, r#"null(time_1)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "feel-time-function_ErrorCase_068_ad528abb23",
+    &ctx,
+    r#"null(time_1 (at 1:5..1:23))"#,
+  );
 }
 
 #[test]
 fn _0069() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "feel-time-function_ErrorCase_069_5096701e2e", &ctx, r#"null(time_1)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "feel-time-function_ErrorCase_069_5096701e2e",
+    &ctx,
+    r#"null(time_1 (at 1:5..1:22))"#,
+  );
 }
 
 #[test]
 fn _0070() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "feel-time-function_ErrorCase_070_8b2e39f570", &ctx, r#"null(time_1)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "feel-time-function_ErrorCase_070_8b2e39f570",
+    &ctx,
+    r#"null(time_1 (at 1:5..1:19))"#,
+  );
 }
 
 #[test]
 fn _0071() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "feel-time-function_ErrorCase_071_cf9417648b", &ctx, r#"null(time_1)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "feel-time-function_ErrorCase_071_cf9417648b",
+    &ctx,
+    r#"null(time_1 (at 1:5..1:36))"#,
+  );
 }
 
 #[test]
 fn _0072() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "feel-time-function_ErrorCase_072_4c8c3835e4", &ctx, r#"null(time_1)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "feel-time-function_ErrorCase_072_4c8c3835e4",
+    &ctx,
+    r#"null(time_1 (at 1:5..1:13))"#,
+  );
 }
 
 #[test]
 fn _0073() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "feel-time-function_ErrorCase_073_a5fc245959", &ctx, r#"null(time_1)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "feel-time-function_ErrorCase_073_a5fc245959",
+    &ctx,
+    r#"null(time_1 (at 1:5..1:13))"#,
+  );
 }
 
 #[test]
 fn _0074() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "feel-time-function_ErrorCase_074_387d4411ea", &ctx, r#"null(time_1)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "feel-time-function_ErrorCase_074_387d4411ea",
+    &ctx,
+    r#"null(time_1 (at 1:5..1:18))"#,
+  );
 }
 
 #[test]
 fn _0075() {
   let ctx = context(r#"{}"#);
-  assert_decision(&MODEL_EVALUATOR, &MODEL_NAMESPACE, "feel-time-function_ErrorCase_075_1606dda03d", &ctx, r#"null(time_1)"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    &MODEL_NAMESPACE,
+    "feel-time-function_ErrorCase_075_1606dda03d",
+    &ctx,
+    r#"null(time_1 (at 1:5..1:29))"#,
+  );
 }
 
 #[test]
@@ -576,7 +714,7 @@ fn _0076() {
     &MODEL_NAMESPACE,
     "feel-time-function_ErrorCase_076_cb117ca612",
     &ctx,
-    r#"null([core::time_4] hour must be 0..23, current value is: 24)"#,
+    r#"null([core::time_4] hour must be 0..23, current value is: 24 (at 1:5..1:23))"#,
   );
 }
 
@@ -588,7 +726,7 @@ fn _0077() {
     &MODEL_NAMESPACE,
     "feel-time-function_ErrorCase_077_a4daad060c",
     &ctx,
-    r#"null([core::time_4] hour must be 0..23, current value is: -24)"#,
+    r#"null([core::time_4] hour must be 0..23, current value is: -24 (at 1:5..1:24))"#,
   );
 }
 
@@ -600,7 +738,7 @@ fn _0078() {
     &MODEL_NAMESPACE,
     "feel-time-function_ErrorCase_078_c2fe73418b",
     &ctx,
-    r#"null([core::time_4] minute must be 0..59, current value is: 60)"#,
+    r#"null([core::time_4] minute must be 0..59, current value is: 60 (at 1:5..1:23))"#,
   );
 }
 
@@ -612,7 +750,7 @@ fn _0079() {
     &MODEL_NAMESPACE,
     "feel-time-function_ErrorCase_079_d2d226c3cd",
     &ctx,
-    r#"null([core::time_4] second must be 0..59, current value is: 60)"#,
+    r#"null([core::time_4] second must be 0..59, current value is: 60 (at 1:5..1:23))"#,
   );
 }
 
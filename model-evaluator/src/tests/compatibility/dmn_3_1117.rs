@@ -42,7 +42,7 @@ fn _0001() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_001_05fd7d6215",
     &ctx,
-    r#"null([core::date and time] invalid argument type, expected string, actual type is Null)"#,
+    r#"null([core::date and time] invalid argument type, expected string, actual type is Null (at 1:14..1:20))"#,
   );
 }
 
@@ -54,7 +54,7 @@ fn _0002() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_002_8c66ed2d1a",
     &ctx,
-    r#"null([core::date and time] invalid argument type, expected date and time or date, actual type is Null)"#,
+    r#"null([core::date and time] invalid argument type, expected date and time or date, actual type is Null (at 1:14..1:25))"#,
   );
 }
 
@@ -66,7 +66,7 @@ fn _0003() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_003_335cff371a",
     &ctx,
-    r#"null([core::date and time] invalid argument type, expected time, actual type is Null)"#,
+    r#"null([core::date and time] invalid argument type, expected time, actual type is Null (at 1:14..1:57))"#,
   );
 }
 
@@ -78,7 +78,7 @@ fn _0004() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_004_28ef3e7882",
     &ctx,
-    r#"null([core::date and time] invalid argument type, expected time, actual type is Null)"#,
+    r#"null([core::date and time] invalid argument type, expected time, actual type is Null (at 1:14..1:39))"#,
   );
 }
 
@@ -90,7 +90,7 @@ fn _0005() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_005_15df95b27a",
     &ctx,
-    r#"null([core::date and time] invalid argument type, expected date and time or date, actual type is Null)"#,
+    r#"null([core::date and time] invalid argument type, expected date and time or date, actual type is Null (at 1:14..1:37))"#,
   );
 }
 
@@ -102,7 +102,7 @@ fn _0006() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_006_8c794da0bb",
     &ctx,
-    r#"null(expected 1,2 parameters, actual number of parameters is 0)"#,
+    r#"null(expected 1,2 parameters, actual number of parameters is 0 (at 1:14..1:16))"#,
   );
 }
 
@@ -690,7 +690,7 @@ fn _0055() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_055_6ce9202e17",
     &ctx,
-    r#"null([core::date and time] invalid argument type, expected string, actual type is number)"#,
+    r#"null([core::date and time] invalid argument type, expected string, actual type is number (at 1:14..1:20))"#,
   );
 }
 
@@ -702,7 +702,7 @@ fn _0056() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_056_e66397568e",
     &ctx,
-    r#"null([core::date and time] invalid argument type, expected string, actual type is list<Null>)"#,
+    r#"null([core::date and time] invalid argument type, expected string, actual type is list<Null> (at 1:14..1:18))"#,
   );
 }
 
@@ -714,7 +714,7 @@ fn _0057() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_057_0452ca8719",
     &ctx,
-    r#"null([core::date and time] invalid date or date and time '')"#,
+    r#"null([core::date and time] invalid date or date and time '' (at 1:14..1:18))"#,
   );
 }
 
@@ -726,7 +726,7 @@ fn _0058() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_058_588040ceaa",
     &ctx,
-    r#"null([core::date and time] invalid date or date and time '11:00:00')"#,
+    r#"null([core::date and time] invalid date or date and time '11:00:00' (at 1:14..1:26))"#,
   );
 }
 
@@ -738,7 +738,7 @@ fn _0059() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_059_dfc62a3ebc",
     &ctx,
-    r#"null([core::date and time] invalid date or date and time '2011-12-0310:15:30')"#,
+    r#"null([core::date and time] invalid date or date and time '2011-12-0310:15:30' (at 1:14..1:36))"#,
   );
 }
 
@@ -750,7 +750,7 @@ fn _0060() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_060_890c302575",
     &ctx,
-    r#"null([core::date and time] invalid date or date and time '2011-12-03T10:15:30+01:00@Europe/Paris')"#,
+    r#"null([core::date and time] invalid date or date and time '2011-12-03T10:15:30+01:00@Europe/Paris' (at 1:14..1:56))"#,
   );
 }
 
@@ -762,7 +762,7 @@ fn _0061() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_061_38ea1fc94d",
     &ctx,
-    r#"null([core::date and time] invalid date or date and time '9999999999-12-27T11:22:33')"#,
+    r#"null([core::date and time] invalid date or date and time '9999999999-12-27T11:22:33' (at 1:14..1:43))"#,
   );
 }
 
@@ -774,7 +774,7 @@ fn _0062() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_062_528aa370a3",
     &ctx,
-    r#"null([core::date and time] invalid date or date and time '2017-13-10T11:22:33')"#,
+    r#"null([core::date and time] invalid date or date and time '2017-13-10T11:22:33' (at 1:14..1:37))"#,
   );
 }
 
@@ -786,7 +786,7 @@ fn _0063() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_063_2c94303011",
     &ctx,
-    r#"null([core::date and time] invalid date or date and time '2017-00-10T11:22:33')"#,
+    r#"null([core::date and time] invalid date or date and time '2017-00-10T11:22:33' (at 1:14..1:37))"#,
   );
 }
 
@@ -798,7 +798,7 @@ fn _0064() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_064_926a372666",
     &ctx,
-    r#"null([core::date and time] invalid date or date and time '2017-13-32T11:22:33')"#,
+    r#"null([core::date and time] invalid date or date and time '2017-13-32T11:22:33' (at 1:14..1:37))"#,
   );
 }
 
@@ -810,7 +810,7 @@ fn _0065() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_065_a13de18ee4",
     &ctx,
-    r#"null([core::date and time] invalid date or date and time '2017-13-0T11:22:33')"#,
+    r#"null([core::date and time] invalid date or date and time '2017-13-0T11:22:33' (at 1:14..1:36))"#,
   );
 }
 
@@ -822,7 +822,7 @@ fn _0066() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_066_e9f3d6d2c2",
     &ctx,
-    r#"null([core::date and time] invalid date or date and time '998-12-31T11:22:33')"#,
+    r#"null([core::date and time] invalid date or date and time '998-12-31T11:22:33' (at 1:14..1:36))"#,
   );
 }
 
@@ -834,7 +834,7 @@ fn _0067() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_067_35fef99b53",
     &ctx,
-    r#"null([core::date and time] invalid date or date and time '01211-12-31T11:22:33')"#,
+    r#"null([core::date and time] invalid date or date and time '01211-12-31T11:22:33' (at 1:14..1:38))"#,
   );
 }
 
@@ -846,7 +846,7 @@ fn _0068() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_068_abaa1c2774",
     &ctx,
-    r#"null([core::date and time] invalid date or date and time '+99999-12-01T11:22:33')"#,
+    r#"null([core::date and time] invalid date or date and time '+99999-12-01T11:22:33' (at 1:14..1:39))"#,
   );
 }
 
@@ -858,7 +858,7 @@ fn _0069() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_069_ca84e9c806",
     &ctx,
-    r#"null([core::date and time] invalid date or date and time '2017-12-31T24:00:01')"#,
+    r#"null([core::date and time] invalid date or date and time '2017-12-31T24:00:01' (at 1:14..1:37))"#,
   );
 }
 
@@ -870,7 +870,7 @@ fn _0070() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_070_889c75a0cf",
     &ctx,
-    r#"null([core::date and time] invalid date or date and time '2017-12-31T24:01:00')"#,
+    r#"null([core::date and time] invalid date or date and time '2017-12-31T24:01:00' (at 1:14..1:37))"#,
   );
 }
 
@@ -882,7 +882,7 @@ fn _0071() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_071_e90b813dfe",
     &ctx,
-    r#"null([core::date and time] invalid date or date and time '2017-12-31T25:00:00')"#,
+    r#"null([core::date and time] invalid date or date and time '2017-12-31T25:00:00' (at 1:14..1:37))"#,
   );
 }
 
@@ -894,7 +894,7 @@ fn _0072() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_072_9f3e9b9c21",
     &ctx,
-    r#"null([core::date and time] invalid date or date and time '2017-12-31T00:60:00')"#,
+    r#"null([core::date and time] invalid date or date and time '2017-12-31T00:60:00' (at 1:14..1:37))"#,
   );
 }
 
@@ -906,7 +906,7 @@ fn _0073() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_073_717548bec6",
     &ctx,
-    r#"null([core::date and time] invalid date or date and time '2017-12-31T00:00:61')"#,
+    r#"null([core::date and time] invalid date or date and time '2017-12-31T00:00:61' (at 1:14..1:37))"#,
   );
 }
 
@@ -918,7 +918,7 @@ fn _0074() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_074_a15e7f8d29",
     &ctx,
-    r#"null([core::date and time] invalid date or date and time '2017-12-31T7:00:00')"#,
+    r#"null([core::date and time] invalid date or date and time '2017-12-31T7:00:00' (at 1:14..1:36))"#,
   );
 }
 
@@ -930,7 +930,7 @@ fn _0075() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_075_4c3b8e7097",
     &ctx,
-    r#"null([core::date and time] invalid date or date and time '2017-12-31T07:1:00')"#,
+    r#"null([core::date and time] invalid date or date and time '2017-12-31T07:1:00' (at 1:14..1:36))"#,
   );
 }
 
@@ -942,7 +942,7 @@ fn _0076() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_076_4d31fed18e",
     &ctx,
-    r#"null([core::date and time] invalid date or date and time '2017-12-31T07:01:2')"#,
+    r#"null([core::date and time] invalid date or date and time '2017-12-31T07:01:2' (at 1:14..1:36))"#,
   );
 }
 
@@ -954,7 +954,7 @@ fn _0077() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_077_f83b3ac8bb",
     &ctx,
-    r#"null([core::date and time] invalid date or date and time '2017-12-31T13:20:00@xyz/abc')"#,
+    r#"null([core::date and time] invalid date or date and time '2017-12-31T13:20:00@xyz/abc' (at 1:14..1:45))"#,
   );
 }
 
@@ -966,7 +966,7 @@ fn _0078() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_078_e113dabcdd",
     &ctx,
-    r#"null([core::date and time] invalid date or date and time '2017-12-31T13:20:00+19:00')"#,
+    r#"null([core::date and time] invalid date or date and time '2017-12-31T13:20:00+19:00' (at 1:14..1:43))"#,
   );
 }
 
@@ -978,7 +978,7 @@ fn _0079() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_079_2e6f80eb94",
     &ctx,
-    r#"null([core::date and time] invalid date or date and time '2017-12-31T13:20:00-19:00')"#,
+    r#"null([core::date and time] invalid date or date and time '2017-12-31T13:20:00-19:00' (at 1:14..1:43))"#,
   );
 }
 
@@ -990,7 +990,7 @@ fn _0080() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_080_69de952053",
     &ctx,
-    r#"null([core::date and time] invalid date or date and time '2017-12-31T13:20:00+05:0')"#,
+    r#"null([core::date and time] invalid date or date and time '2017-12-31T13:20:00+05:0' (at 1:14..1:42))"#,
   );
 }
 
@@ -1002,7 +1002,7 @@ fn _0081() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_081_e063215a7c",
     &ctx,
-    r#"null([core::date and time] invalid date or date and time '2017-12-31T13:20:00+5:00')"#,
+    r#"null([core::date and time] invalid date or date and time '2017-12-31T13:20:00+5:00' (at 1:14..1:42))"#,
   );
 }
 
@@ -1014,7 +1014,7 @@ fn _0082() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_082_5b6ed4e801",
     &ctx,
-    r#"null([core::date and time] invalid date or date and time '2017-12-31T13:20:00+5')"#,
+    r#"null([core::date and time] invalid date or date and time '2017-12-31T13:20:00+5' (at 1:14..1:39))"#,
   );
 }
 
@@ -1026,7 +1026,7 @@ fn _0083() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_083_4f41731f2a",
     &ctx,
-    r#"null([core::date and time] invalid date or date and time '2017-12-31T13:20:00+02:00@Europe/Paris')"#,
+    r#"null([core::date and time] invalid date or date and time '2017-12-31T13:20:00+02:00@Europe/Paris' (at 1:14..1:56))"#,
   );
 }
 
@@ -1038,7 +1038,7 @@ fn _0084() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_084_c633b01603",
     &ctx,
-    r#"null([core::date and time] invalid date or date and time '2017-12-31T7:20')"#,
+    r#"null([core::date and time] invalid date or date and time '2017-12-31T7:20' (at 1:14..1:33))"#,
   );
 }
 
@@ -1050,7 +1050,7 @@ fn _0085() {
     &MODEL_NAMESPACE,
     "feel-date-and-time-function_ErrorCase_085_a604a1bc80",
     &ctx,
-    r#"null([core::date and time] invalid date or date and time '2017-12-31T07:2')"#,
+    r#"null([core::date and time] invalid date or date and time '2017-12-31T07:2' (at 1:14..1:33))"#,
   );
 }
 
@@ -42,7 +42,7 @@ fn _0001() {
     &MODEL_NAMESPACE,
     "feel-duration-function_ErrorCase_001_f2c6cd6866",
     &ctx,
-    r#"null(duration)"#,
+    r#"null(duration (at 1:9..1:15))"#,
   );
 }
 
@@ -54,7 +54,7 @@ fn _0002() {
     &MODEL_NAMESPACE,
     "feel-duration-function_ErrorCase_002_ddca5756ca",
     &ctx,
-    r#"null(expected 1 parameters, actual number of parameters is 0)"#,
+    r#"null(expected 1 parameters, actual number of parameters is 0 (at 1:9..1:11))"#,
   );
 }
 
@@ -294,7 +294,7 @@ fn _0041() {
     &MODEL_NAMESPACE,
     "feel-duration-function_ErrorCase_041_264bc9d682",
     &ctx,
-    r#"null(duration)"#,
+    r#"null(duration (at 1:9..1:13))"#,
   );
 }
 
@@ -306,7 +306,7 @@ fn _0042() {
     &MODEL_NAMESPACE,
     "feel-duration-function_ErrorCase_042_59a0000245",
     &ctx,
-    r#"null(duration)"#,
+    r#"null(duration (at 1:9..1:15))"#,
   );
 }
 
@@ -318,7 +318,7 @@ fn _0043() {
     &MODEL_NAMESPACE,
     "feel-duration-function_ErrorCase_043_253815dc6c",
     &ctx,
-    r#"null(duration)"#,
+    r#"null(duration (at 1:9..1:33))"#,
   );
 }
 
@@ -330,7 +330,7 @@ fn _0044() {
     &MODEL_NAMESPACE,
     "feel-duration-function_ErrorCase_044_f3b338d877",
     &ctx,
-    r#"null(duration)"#,
+    r#"null(duration (at 1:9..1:13))"#,
   );
 }
 
@@ -342,7 +342,7 @@ fn _0045() {
     &MODEL_NAMESPACE,
     "feel-duration-function_ErrorCase_045_2ffcc37801",
     &ctx,
-    r#"null(duration)"#,
+    r#"null(duration (at 1:9..1:14))"#,
   );
 }
 
@@ -354,7 +354,7 @@ fn _0046() {
     &MODEL_NAMESPACE,
     "feel-duration-function_ErrorCase_046_eb637de5f6",
     &ctx,
-    r#"null(duration)"#,
+    r#"null(duration (at 1:9..1:15))"#,
   );
 }
 
@@ -366,7 +366,7 @@ fn _0047() {
     &MODEL_NAMESPACE,
     "feel-duration-function_ErrorCase_047_3210c46a5a",
     &ctx,
-    r#"null(duration)"#,
+    r#"null(duration (at 1:9..1:15))"#,
   );
 }
 
@@ -378,7 +378,7 @@ fn _0048() {
     &MODEL_NAMESPACE,
     "feel-duration-function_ErrorCase_048_ab6244f767",
     &ctx,
-    r#"null(duration)"#,
+    r#"null(duration (at 1:9..1:15))"#,
   );
 }
 
@@ -390,7 +390,7 @@ fn _0049() {
     &MODEL_NAMESPACE,
     "feel-duration-function_ErrorCase_049_2225b503a0",
     &ctx,
-    r#"null(duration)"#,
+    r#"null(duration (at 1:9..1:16))"#,
   );
 }
 
@@ -402,6 +402,6 @@ fn _0050() {
     &MODEL_NAMESPACE,
     "feel-duration-function_ErrorCase_050_dd2ef33bbd",
     &ctx,
-    r#"null(duration)"#,
+    r#"null(duration (at 1:9..1:16))"#,
   );
 }
@@ -42,7 +42,7 @@ fn _0001() {
     &MODEL_NAMESPACE,
     "feel-years-and-months-duration-function_ErrorCase_001_b24a0c91f2",
     &ctx,
-    r#"null(expected 2 parameters, actual number of parameters is 1)"#,
+    r#"null(expected 2 parameters, actual number of parameters is 1 (at 1:1..1:32))"#,
   );
 }
 
@@ -54,7 +54,7 @@ fn _0002() {
     &MODEL_NAMESPACE,
     "feel-years-and-months-duration-function_ErrorCase_002_4e7651ae0e",
     &ctx,
-    r#"null([core::years and months duration] invalid argument type, expected date, date and time, actual type is Null)"#,
+    r#"null([core::years and months duration] invalid argument type, expected date, date and time, actual type is Null (at 1:1..1:37))"#,
   );
 }
 
@@ -66,7 +66,7 @@ fn _0003() {
     &MODEL_NAMESPACE,
     "feel-years-and-months-duration-function_ErrorCase_003_0886738d31",
     &ctx,
-    r#"null([core::years and months duration] invalid argument type, expected date, date and time, actual type is Null)"#,
+    r#"null([core::years and months duration] invalid argument type, expected date, date and time, actual type is Null (at 1:1..1:51))"#,
   );
 }
 
@@ -78,7 +78,7 @@ fn _0004() {
     &MODEL_NAMESPACE,
     "feel-years-and-months-duration-function_ErrorCase_004_1bdfef922b",
     &ctx,
-    r#"null([core::years and months duration] invalid argument type, expected date, date and time, actual type is Null)"#,
+    r#"null([core::years and months duration] invalid argument type, expected date, date and time, actual type is Null (at 1:1..1:69))"#,
   );
 }
 
@@ -90,7 +90,7 @@ fn _0005() {
     &MODEL_NAMESPACE,
     "feel-years-and-months-duration-function_ErrorCase_005_d0a077da4e",
     &ctx,
-    r#"null([core::years and months duration] invalid argument type, expected date, date and time, actual type is Null)"#,
+    r#"null([core::years and months duration] invalid argument type, expected date, date and time, actual type is Null (at 1:1..1:51))"#,
   );
 }
 
@@ -102,7 +102,7 @@ fn _0006() {
     &MODEL_NAMESPACE,
     "feel-years-and-months-duration-function_ErrorCase_006_f20de28d3f",
     &ctx,
-    r#"null([core::years and months duration] invalid argument type, expected date, date and time, actual type is Null)"#,
+    r#"null([core::years and months duration] invalid argument type, expected date, date and time, actual type is Null (at 1:1..1:69))"#,
   );
 }
 
@@ -114,7 +114,7 @@ fn _0007() {
     &MODEL_NAMESPACE,
     "feel-years-and-months-duration-function_ErrorCase_007_0921c3d61a",
     &ctx,
-    r#"null(expected 2 parameters, actual number of parameters is 0)"#,
+    r#"null(expected 2 parameters, actual number of parameters is 0 (at 1:1..1:28))"#,
   );
 }
 
@@ -312,7 +312,7 @@ fn _0027() {
     &MODEL_NAMESPACE,
     "feel-years-and-months-duration-function_ErrorCase_027_3374dd86c6",
     &ctx,
-    r#"null([core::years and months duration] invalid argument type, expected date, date and time, actual type is Null)"#,
+    r#"null([core::years and months duration] invalid argument type, expected date, date and time, actual type is Null (at 1:1..1:45))"#,
   );
 }
 
@@ -324,7 +324,7 @@ fn _0028() {
     &MODEL_NAMESPACE,
     "feel-years-and-months-duration-function_ErrorCase_028_77600e7b35",
     &ctx,
-    r#"null(expected 2 parameters, actual number of parameters is 1)"#,
+    r#"null(expected 2 parameters, actual number of parameters is 1 (at 1:1..1:32))"#,
   );
 }
 
@@ -336,7 +336,7 @@ fn _0029() {
     &MODEL_NAMESPACE,
     "feel-years-and-months-duration-function_ErrorCase_029_15a0d0d9c1",
     &ctx,
-    r#"null(expected 2 parameters, actual number of parameters is 1)"#,
+    r#"null(expected 2 parameters, actual number of parameters is 1 (at 1:1..1:50))"#,
   );
 }
 
@@ -348,7 +348,7 @@ fn _0030() {
     &MODEL_NAMESPACE,
     "feel-years-and-months-duration-function_ErrorCase_030_ec16878596",
     &ctx,
-    r#"null([core::years and months duration] invalid argument type, expected date, date and time, actual type is list<Null>)"#,
+    r#"null([core::years and months duration] invalid argument type, expected date, date and time, actual type is list<Null> (at 1:1..1:33))"#,
   );
 }
 
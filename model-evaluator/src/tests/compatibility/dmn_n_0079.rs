@@ -42,7 +42,7 @@ fn _0001() {
     &MODEL_NAMESPACE,
     "decision_001",
     &ctx,
-    r#"null(expected 1 parameters, actual number of parameters is 0)"#,
+    r#"null(expected 1 parameters, actual number of parameters is 0 (at 1:1..1:9))"#,
   );
 }
 
@@ -54,7 +54,7 @@ fn _0002() {
     &MODEL_NAMESPACE,
     "decision_002",
     &ctx,
-    r#"null(expected 1 parameters, actual number of parameters is 2)"#,
+    r#"null(expected 1 parameters, actual number of parameters is 2 (at 1:1..1:21))"#,
   );
 }
 
@@ -72,7 +72,7 @@ fn _0004() {
     &MODEL_NAMESPACE,
     "decision_004",
     &ctx,
-    r#"null(expected 1 parameters, actual number of parameters is 2)"#,
+    r#"null(expected 1 parameters, actual number of parameters is 2 (at 1:1..1:21))"#,
   );
 }
 
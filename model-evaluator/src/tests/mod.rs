@@ -37,6 +37,7 @@ use dmntk_feel::FeelScope;
 use once_cell::sync::Lazy;
 use std::sync::Arc;
 
+mod bundle_evaluator;
 mod compatibility;
 mod various;
 
@@ -0,0 +1,92 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::super::*;
+
+const DMN_0004: &str = include_str!("_0004.dmn");
+
+model_evaluator!(DMN_0004);
+
+const NAMESPACE: &str = "https://dmntk.io/loans";
+
+/// Utility function that discovers inputs missing in specified input data and compares the result.
+fn assert_missing_inputs(invocable_name: &str, input_data: &FeelContext, expected: &str) {
+  let actual = MODEL_EVALUATOR
+    .missing_inputs_by_name(NAMESPACE, invocable_name, input_data)
+    .unwrap()
+    .unwrap()
+    .iter()
+    .map(|qname| qname.to_string())
+    .collect::<Vec<String>>()
+    .join(", ");
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn _0001() {
+  let actual = MODEL_EVALUATOR.required_inputs_by_name(NAMESPACE, "Offer").unwrap().unwrap();
+  let actual = actual.iter().map(|qname| qname.to_string()).collect::<Vec<String>>();
+  assert_eq!(vec!["Applicant.age", "Applicant.income", "Loan Amount"], actual);
+}
+
+#[test]
+fn _0002() {
+  assert_missing_inputs("Offer", &context(r#"{}"#), "Applicant.age, Applicant.income, Loan Amount");
+}
+
+#[test]
+fn _0003() {
+  // applicant is not eligible, so the risk and loan amount do not matter
+  assert_missing_inputs("Offer", &context(r#"{Applicant: {age: 17}}"#), "");
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Offer", &context(r#"{Applicant: {age: 17}}"#), r#""NONE""#);
+}
+
+#[test]
+fn _0004() {
+  assert_missing_inputs("Offer", &context(r#"{Applicant: {age: 30}}"#), "Applicant.income, Loan Amount");
+  assert_missing_inputs("Offer", &context(r#"{Applicant: {age: 30, income: 5000}}"#), "Loan Amount");
+  assert_missing_inputs("Offer", &context(r#"{Applicant: {age: 30, income: 5000}, Loan Amount: 1000}"#), "");
+}
+
+#[test]
+fn _0005() {
+  // first rule of the decision table matches, remaining rules are not relevant
+  assert_missing_inputs("Risk", &context(r#"{Applicant: {age: 20}}"#), "");
+  assert_missing_inputs("Risk", &context(r#"{Applicant: {age: 30}}"#), "Applicant.income");
+}
+
+#[test]
+fn _0006() {
+  // eligibility is an input decision of the decision service
+  assert_missing_inputs("Offer Service", &context(r#"{}"#), "Applicant.age, Applicant.income, Eligibility");
+  assert_missing_inputs("Offer Service", &context(r#"{Eligibility: "REVIEW"}"#), "");
+}
@@ -0,0 +1,77 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::super::*;
+
+const DMN_0005: &str = include_str!("_0005.dmn");
+
+model_evaluator!(DMN_0005);
+
+const NAMESPACE: &str = "https://dmntk.io/boxed";
+
+static_context!(CTX, r#"{Amounts: [500, 20, 150], Limit: 100}"#);
+
+#[test]
+fn _0001() {
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Level", &CTX, r#""LOW""#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Level", &context(r#"{Limit: 250}"#), r#""HIGH""#);
+  // condition evaluated to null selects the else branch
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Level", &context(r#"{}"#), r#""LOW""#);
+}
+
+#[test]
+fn _0002() {
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Large Amounts", &CTX, r#"[500, 150]"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Large Amounts", &context(r#"{Amounts: [], Limit: 100}"#), r#"[]"#);
+}
+
+#[test]
+fn _0003() {
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Doubled Amounts", &CTX, r#"[1000, 40, 300]"#);
+}
+
+#[test]
+fn _0004() {
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "All Positive", &CTX, r#"true"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "All Positive", &context(r#"{Amounts: [1, -1]}"#), r#"false"#);
+}
+
+#[test]
+fn _0005() {
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Any Above Limit", &CTX, r#"true"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Any Above Limit", &context(r#"{Amounts: [1, 2], Limit: 100}"#), r#"false"#);
+}
+
+#[test]
+fn _0006() {
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Summary", &CTX, r#"[100, [50, 25], ["nested"]]"#);
+}
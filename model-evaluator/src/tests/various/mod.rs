@@ -35,3 +35,5 @@
 mod dmn_0001;
 mod dmn_0002;
 mod dmn_0003;
+mod dmn_0004;
+mod dmn_0005;
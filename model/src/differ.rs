@@ -0,0 +1,925 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! # Semantic comparison of DMN models
+//!
+//! Two versions of a model are compared element by element, instead of comparing their XML.
+//! Elements are matched by identifier (when provided in the model) or by name.
+//! Added, removed and changed item definitions, DRG elements and requirements are reported,
+//! decision tables are compared rule by rule. Changes of diagrams are reported only on request.
+
+use crate::model::*;
+use dmntk_common::HRef;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Kind of the change.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ChangeKind {
+  /// Element is present only in the new model.
+  Added,
+  /// Element is present only in the old model.
+  Removed,
+  /// Element is present in both models, but differs.
+  Changed,
+}
+
+impl fmt::Display for ChangeKind {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      ChangeKind::Added => write!(f, "added"),
+      ChangeKind::Removed => write!(f, "removed"),
+      ChangeKind::Changed => write!(f, "changed"),
+    }
+  }
+}
+
+/// Change of a single model element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModelChange {
+  /// Kind of the change.
+  kind: ChangeKind,
+  /// Kind of the changed element, like `decision` or `item definition`.
+  element_kind: &'static str,
+  /// Name of the changed element, the name from the new model when the element was renamed.
+  element_name: String,
+  /// Descriptions of changed properties of the element.
+  details: Vec<String>,
+  /// Changes of decision table rules.
+  rule_changes: Vec<RuleChange>,
+}
+
+impl fmt::Display for ModelChange {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{} {} '{}'", self.kind, self.element_kind, self.element_name)
+  }
+}
+
+impl ModelChange {
+  /// Returns the kind of the change.
+  pub fn kind(&self) -> ChangeKind {
+    self.kind
+  }
+  /// Returns the kind of the changed element.
+  pub fn element_kind(&self) -> &str {
+    self.element_kind
+  }
+  /// Returns the name of the changed element.
+  pub fn element_name(&self) -> &str {
+    &self.element_name
+  }
+  /// Returns descriptions of changed properties of the element.
+  pub fn details(&self) -> &[String] {
+    &self.details
+  }
+  /// Returns changes of decision table rules.
+  pub fn rule_changes(&self) -> &[RuleChange] {
+    &self.rule_changes
+  }
+}
+
+/// Change of a single rule in decision table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleChange {
+  /// Kind of the change.
+  kind: ChangeKind,
+  /// Number of the rule in the old decision table, counted from 1.
+  old_number: Option<usize>,
+  /// Number of the rule in the new decision table, counted from 1.
+  new_number: Option<usize>,
+  /// Cells of the rule: input entries, output entries and annotations.
+  cells: Vec<CellChange>,
+}
+
+impl fmt::Display for RuleChange {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{} rule {}", self.kind, self.number())
+  }
+}
+
+impl RuleChange {
+  /// Returns the kind of the change.
+  pub fn kind(&self) -> ChangeKind {
+    self.kind
+  }
+  /// Returns the number of the rule in the old decision table.
+  pub fn old_number(&self) -> Option<usize> {
+    self.old_number
+  }
+  /// Returns the number of the rule in the new decision table.
+  pub fn new_number(&self) -> Option<usize> {
+    self.new_number
+  }
+  /// Returns the number of the rule in the new decision table,
+  /// or in the old decision table when the rule was removed.
+  pub fn number(&self) -> usize {
+    self.new_number.or(self.old_number).unwrap_or_default()
+  }
+  /// Returns the cells of the rule.
+  pub fn cells(&self) -> &[CellChange] {
+    &self.cells
+  }
+}
+
+/// Single cell of the changed rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CellChange {
+  /// Name of the column the cell belongs to.
+  column: String,
+  /// Text of the cell in the old decision table.
+  old_text: Option<String>,
+  /// Text of the cell in the new decision table.
+  new_text: Option<String>,
+}
+
+impl CellChange {
+  /// Returns the name of the column the cell belongs to.
+  pub fn column(&self) -> &str {
+    &self.column
+  }
+  /// Returns the text of the cell in the old decision table.
+  pub fn old_text(&self) -> Option<&str> {
+    self.old_text.as_deref()
+  }
+  /// Returns the text of the cell in the new decision table.
+  pub fn new_text(&self) -> Option<&str> {
+    self.new_text.as_deref()
+  }
+  /// Returns `true` when the text of the cell differs between decision tables.
+  pub fn is_changed(&self) -> bool {
+    self.old_text != self.new_text
+  }
+}
+
+/// Compares two versions of a model and returns the list of changes,
+/// the list is empty when models are semantically equal.
+/// When `include_diagrams` flag is set, changes of diagrams are reported too.
+pub fn diff(old: &Definitions, new: &Definitions, include_diagrams: bool) -> Vec<ModelChange> {
+  let mut differ = Differ::new(old, new);
+  differ.diff_item_definitions();
+  differ.diff_drg_elements();
+  if include_diagrams {
+    differ.diff_diagrams();
+  }
+  differ.changes
+}
+
+/// Orders of DRG element kinds in which the changes are reported.
+const DRG_ELEMENT_KINDS: [&str; 5] = ["input data", "decision", "business knowledge model", "decision service", "knowledge source"];
+
+/// State of the comparison.
+struct Differ<'a> {
+  /// Old version of the model.
+  old: &'a Definitions,
+  /// New version of the model.
+  new: &'a Definitions,
+  /// Names of DRG elements of the old model indexed by identifier.
+  old_names: HashMap<&'a str, &'a str>,
+  /// Names of DRG elements of the new model indexed by identifier.
+  new_names: HashMap<&'a str, &'a str>,
+  /// Detected changes.
+  changes: Vec<ModelChange>,
+}
+
+impl<'a> Differ<'a> {
+  /// Creates a differ for specified versions of the model.
+  fn new(old: &'a Definitions, new: &'a Definitions) -> Self {
+    let names = |definitions: &'a Definitions| definitions.drg_elements().map(|drg_element| (drg_element.id(), drg_element.name())).collect();
+    Self {
+      old,
+      new,
+      old_names: names(old),
+      new_names: names(new),
+      changes: vec![],
+    }
+  }
+
+  /// Adds a new change.
+  fn report(&mut self, kind: ChangeKind, element_kind: &'static str, element_name: &str, details: Vec<String>, rule_changes: Vec<RuleChange>) {
+    self.changes.push(ModelChange {
+      kind,
+      element_kind,
+      element_name: element_name.to_string(),
+      details,
+      rule_changes,
+    });
+  }
+
+  /// Reports added, removed and changed item definitions.
+  fn diff_item_definitions(&mut self) {
+    let old_items = self.old.item_definitions().iter().collect::<Vec<&ItemDefinition>>();
+    let new_items = self.new.item_definitions().iter().collect::<Vec<&ItemDefinition>>();
+    for pair in match_elements(&old_items, &new_items, |item| (item.opt_id(), item.name())) {
+      match pair {
+        (Some(old), Some(new)) => {
+          let mut details = vec![];
+          changed(&mut details, "name", Some(old.name()), Some(new.name()));
+          diff_item_definition("", old, new, &mut details);
+          if !details.is_empty() {
+            self.report(ChangeKind::Changed, "item definition", new.name(), details, vec![]);
+          }
+        }
+        (None, Some(new)) => self.report(ChangeKind::Added, "item definition", new.name(), vec![], vec![]),
+        (Some(old), None) => self.report(ChangeKind::Removed, "item definition", old.name(), vec![], vec![]),
+        (None, None) => {}
+      }
+    }
+  }
+
+  /// Reports added, removed and changed DRG elements, followed by changes of requirements.
+  fn diff_drg_elements(&mut self) {
+    let mut requirement_changes = vec![];
+    for kind in DRG_ELEMENT_KINDS {
+      let old_elements = self.old.drg_elements().filter(|drg_element| drg_element.kind() == kind).collect::<Vec<&DrgElement>>();
+      let new_elements = self.new.drg_elements().filter(|drg_element| drg_element.kind() == kind).collect::<Vec<&DrgElement>>();
+      for pair in match_elements(&old_elements, &new_elements, |drg_element| (drg_element.opt_id(), drg_element.name())) {
+        match pair {
+          (Some(old), Some(new)) => {
+            let mut details = vec![];
+            let mut rule_changes = vec![];
+            changed(&mut details, "name", Some(old.name()), Some(new.name()));
+            self.diff_drg_element(old, new, &mut details, &mut rule_changes);
+            if !details.is_empty() || !rule_changes.is_empty() {
+              self.report(ChangeKind::Changed, kind, new.name(), details, rule_changes);
+            }
+            requirement_changes.push((old, new));
+          }
+          (None, Some(new)) => self.report(ChangeKind::Added, kind, new.name(), vec![], vec![]),
+          (Some(old), None) => self.report(ChangeKind::Removed, kind, old.name(), vec![], vec![]),
+          (None, None) => {}
+        }
+      }
+    }
+    for (old, new) in requirement_changes {
+      self.diff_requirements(old, new);
+    }
+  }
+
+  /// Compares properties of DRG elements of the same kind.
+  fn diff_drg_element(&self, old: &DrgElement, new: &DrgElement, details: &mut Vec<String>, rule_changes: &mut Vec<RuleChange>) {
+    match (old, new) {
+      (DrgElement::Decision(old), DrgElement::Decision(new)) => {
+        changed(details, "type reference", type_ref(old.variable()), type_ref(new.variable()));
+        changed(details, "question", old.question().as_deref(), new.question().as_deref());
+        changed(details, "allowed answers", old.allowed_answers().as_deref(), new.allowed_answers().as_deref());
+        diff_expression("decision logic", old.decision_logic().as_ref(), new.decision_logic().as_ref(), details, rule_changes);
+      }
+      (DrgElement::InputData(old), DrgElement::InputData(new)) => {
+        changed(details, "type reference", type_ref(old.variable()), type_ref(new.variable()));
+      }
+      (DrgElement::BusinessKnowledgeModel(old), DrgElement::BusinessKnowledgeModel(new)) => {
+        changed(details, "type reference", type_ref(old.variable()), type_ref(new.variable()));
+        let old_logic = old.encapsulated_logic().as_ref();
+        let new_logic = new.encapsulated_logic().as_ref();
+        let old_parameters = old_logic.map(|function_definition| parameters(function_definition.formal_parameters()));
+        let new_parameters = new_logic.map(|function_definition| parameters(function_definition.formal_parameters()));
+        changed(details, "parameters", old_parameters.as_deref(), new_parameters.as_deref());
+        let old_body = old_logic.and_then(|function_definition| function_definition.body().as_ref());
+        let new_body = new_logic.and_then(|function_definition| function_definition.body().as_ref());
+        diff_expression("encapsulated logic", old_body, new_body, details, rule_changes);
+      }
+      (DrgElement::DecisionService(old), DrgElement::DecisionService(new)) => {
+        changed(details, "type reference", type_ref(old.variable()), type_ref(new.variable()));
+        for (label, old_references, new_references) in [
+          ("output decisions", old.output_decisions(), new.output_decisions()),
+          ("encapsulated decisions", old.encapsulated_decisions(), new.encapsulated_decisions()),
+          ("input decisions", old.input_decisions(), new.input_decisions()),
+          ("input data", old.input_data(), new.input_data()),
+        ] {
+          let old_names = self.reference_names(old_references, true);
+          let new_names = self.reference_names(new_references, false);
+          changed(details, label, Some(&old_names), Some(&new_names));
+        }
+      }
+      _ => {}
+    }
+  }
+
+  /// Reports requirements added to and removed from DRG element present in both models.
+  fn diff_requirements(&mut self, old: &DrgElement, new: &DrgElement) {
+    let old_requirements = self.requirements(old, true);
+    let new_requirements = self.requirements(new, false);
+    for requirement in &old_requirements {
+      if !new_requirements.contains(requirement) {
+        self.report(ChangeKind::Removed, requirement.0, &format!("{} -> {}", old.name(), requirement.1), vec![], vec![]);
+      }
+    }
+    for requirement in &new_requirements {
+      if !old_requirements.contains(requirement) {
+        self.report(ChangeKind::Added, requirement.0, &format!("{} -> {}", new.name(), requirement.1), vec![], vec![]);
+      }
+    }
+  }
+
+  /// Returns the kinds of requirements of DRG element, together with the names of required elements.
+  fn requirements(&self, drg_element: &DrgElement, in_old: bool) -> Vec<(&'static str, String)> {
+    let mut requirements = vec![];
+    let mut add = |kind: &'static str, href: &Option<HRef>| {
+      if let Some(href) = href {
+        requirements.push((kind, self.reference_name(href, in_old)));
+      }
+    };
+    let (information_requirements, knowledge_requirements, authority_requirements) = match drg_element {
+      DrgElement::Decision(decision) => (
+        decision.information_requirements().as_slice(),
+        decision.knowledge_requirements().as_slice(),
+        decision.authority_requirements().as_slice(),
+      ),
+      DrgElement::BusinessKnowledgeModel(business_knowledge_model) => (
+        [].as_slice(),
+        business_knowledge_model.knowledge_requirements().as_slice(),
+        business_knowledge_model.authority_requirements().as_slice(),
+      ),
+      DrgElement::KnowledgeSource(knowledge_source) => ([].as_slice(), [].as_slice(), knowledge_source.authority_requirements().as_slice()),
+      _ => ([].as_slice(), [].as_slice(), [].as_slice()),
+    };
+    for requirement in information_requirements {
+      add("information requirement", requirement.required_decision());
+      add("information requirement", requirement.required_input());
+    }
+    for requirement in knowledge_requirements {
+      add("knowledge requirement", &Some(requirement.required_knowledge().clone()));
+    }
+    for requirement in authority_requirements {
+      add("authority requirement", requirement.required_authority());
+      add("authority requirement", requirement.required_decision());
+      add("authority requirement", requirement.required_input());
+    }
+    requirements
+  }
+
+  /// Returns the name of the element pointed by the reference, or the reference itself
+  /// when it points to an element imported from other model or to a missing element.
+  fn reference_name(&self, href: &HRef, in_old: bool) -> String {
+    let (definitions, names) = if in_old { (self.old, &self.old_names) } else { (self.new, &self.new_names) };
+    match href.namespace() {
+      Some(namespace) if namespace.trim_end_matches('/') != definitions.namespace().trim_end_matches('/') => format!("{namespace}#{}", href.id()),
+      _ => names.get(href.id()).map(|name| name.to_string()).unwrap_or_else(|| href.id().to_string()),
+    }
+  }
+
+  /// Returns the names of elements pointed by references, separated with commas.
+  fn reference_names(&self, hrefs: &[HRef], in_old: bool) -> String {
+    hrefs.iter().map(|href| self.reference_name(href, in_old)).collect::<Vec<String>>().join(", ")
+  }
+
+  /// Reports added, removed and changed diagrams.
+  fn diff_diagrams(&mut self) {
+    let old_diagrams = self.old.dmndi().iter().flat_map(|dmndi| dmndi.diagrams.iter()).collect::<Vec<&DmnDiagram>>();
+    let new_diagrams = self.new.dmndi().iter().flat_map(|dmndi| dmndi.diagrams.iter()).collect::<Vec<&DmnDiagram>>();
+    for pair in match_elements(&old_diagrams, &new_diagrams, |diagram| (diagram.id.as_ref(), diagram.name.as_str())) {
+      match pair {
+        (Some(old), Some(new)) => {
+          let mut details = vec![];
+          changed(&mut details, "name", Some(&old.name), Some(&new.name));
+          self.diff_diagram(old, new, &mut details);
+          if !details.is_empty() {
+            self.report(ChangeKind::Changed, "diagram", &new.name, details, vec![]);
+          }
+        }
+        (None, Some(new)) => self.report(ChangeKind::Added, "diagram", &new.name, vec![], vec![]),
+        (Some(old), None) => self.report(ChangeKind::Removed, "diagram", &old.name, vec![], vec![]),
+        (None, None) => {}
+      }
+    }
+  }
+
+  /// Compares shapes and edges of two versions of a diagram.
+  fn diff_diagram(&self, old: &DmnDiagram, new: &DmnDiagram, details: &mut Vec<String>) {
+    let (old_shapes, new_shapes) = (diagram_shapes(old), diagram_shapes(new));
+    for pair in match_elements(&old_shapes, &new_shapes, |shape| (None, shape.dmn_element_ref.as_deref().unwrap_or_default())) {
+      match pair {
+        (Some(old), Some(new)) => {
+          let name = self.shape_name(new, false);
+          if old.bounds.x != new.bounds.x || old.bounds.y != new.bounds.y {
+            details.push(format!("shape of '{name}' moved"));
+          }
+          if old.bounds.width != new.bounds.width || old.bounds.height != new.bounds.height {
+            details.push(format!("shape of '{name}' resized"));
+          }
+        }
+        (None, Some(new)) => details.push(format!("shape of '{}' added", self.shape_name(new, false))),
+        (Some(old), None) => details.push(format!("shape of '{}' removed", self.shape_name(old, true))),
+        (None, None) => {}
+      }
+    }
+    let (old_edges, new_edges) = (diagram_edges(old), diagram_edges(new));
+    for pair in match_elements(&old_edges, &new_edges, |edge| (None, edge.dmn_element_ref.as_deref().unwrap_or_default())) {
+      match pair {
+        (Some(old), Some(new)) => {
+          let same_route = old.way_points.len() == new.way_points.len()
+            && old
+              .way_points
+              .iter()
+              .zip(new.way_points.iter())
+              .all(|(old_point, new_point)| old_point.x == new_point.x && old_point.y == new_point.y);
+          if !same_route {
+            details.push(format!("route of edge '{}' changed", edge_name(new)));
+          }
+        }
+        (None, Some(new)) => details.push(format!("edge '{}' added", edge_name(new))),
+        (Some(old), None) => details.push(format!("edge '{}' removed", edge_name(old))),
+        (None, None) => {}
+      }
+    }
+  }
+
+  /// Returns the name of the element depicted by the shape.
+  fn shape_name(&self, shape: &DmnShape, in_old: bool) -> String {
+    let names = if in_old { &self.old_names } else { &self.new_names };
+    let reference = shape.dmn_element_ref.as_deref().unwrap_or_default();
+    names.get(reference).map(|name| name.to_string()).unwrap_or_else(|| reference.to_string())
+  }
+}
+
+/// Pairs elements of the old and new model, first by identifier provided in the model, then by name.
+/// Returns pairs in the order of elements in the new model, followed by elements removed from the old model.
+fn match_elements<'b, T>(old: &[&'b T], new: &[&'b T], key: impl Fn(&T) -> (Option<&String>, &str)) -> Vec<(Option<&'b T>, Option<&'b T>)> {
+  let mut matched = vec![false; old.len()];
+  let mut pairs = vec![];
+  for new_element in new {
+    let (new_id, new_name) = key(new_element);
+    let position = new_id
+      .and_then(|new_id| (0..old.len()).find(|index| !matched[*index] && key(old[*index]).0 == Some(new_id)))
+      .or_else(|| (0..old.len()).find(|index| !matched[*index] && key(old[*index]).1 == new_name));
+    if let Some(index) = position {
+      matched[index] = true;
+      pairs.push((Some(old[index]), Some(*new_element)));
+    } else {
+      pairs.push((None, Some(*new_element)));
+    }
+  }
+  for (index, old_element) in old.iter().enumerate() {
+    if !matched[index] {
+      pairs.push((Some(*old_element), None));
+    }
+  }
+  pairs
+}
+
+/// Adds the description of the change when the values of the property differ.
+fn changed(details: &mut Vec<String>, property: &str, old: Option<&str>, new: Option<&str>) {
+  if old != new {
+    details.push(format!("{property} changed from {} to {}", quoted(old), quoted(new)));
+  }
+}
+
+/// Returns the quoted value, or `none` when the value is not present.
+fn quoted(value: Option<&str>) -> String {
+  value.map(|value| format!("'{value}'")).unwrap_or_else(|| "none".to_string())
+}
+
+/// Returns the type reference of the information item, when specified.
+fn type_ref(information_item: &InformationItem) -> Option<&str> {
+  Some(information_item.type_ref().as_str()).filter(|type_ref| !type_ref.is_empty())
+}
+
+/// Returns formal parameters with their types, separated with commas.
+fn parameters(formal_parameters: &[InformationItem]) -> String {
+  formal_parameters
+    .iter()
+    .map(|parameter| match type_ref(parameter) {
+      Some(type_ref) => format!("{}: {type_ref}", parameter.name()),
+      None => parameter.name().to_string(),
+    })
+    .collect::<Vec<String>>()
+    .join(", ")
+}
+
+/// Returns shapes depicted in the diagram.
+fn diagram_shapes(diagram: &DmnDiagram) -> Vec<&DmnShape> {
+  diagram
+    .diagram_elements
+    .iter()
+    .filter_map(|diagram_element| match diagram_element {
+      DmnDiagramElement::DmnShape(shape) => Some(shape),
+      _ => None,
+    })
+    .collect()
+}
+
+/// Returns edges depicted in the diagram.
+fn diagram_edges(diagram: &DmnDiagram) -> Vec<&DmnEdge> {
+  diagram
+    .diagram_elements
+    .iter()
+    .filter_map(|diagram_element| match diagram_element {
+      DmnDiagramElement::DmnEdge(edge) => Some(edge),
+      _ => None,
+    })
+    .collect()
+}
+
+/// Returns the name of the edge, the identifier of the depicted requirement.
+fn edge_name(edge: &DmnEdge) -> &str {
+  edge.dmn_element_ref.as_deref().or(edge.id.as_deref()).unwrap_or_default()
+}
+
+/// Compares two versions of the item definition, `path` prefixes names of nested components.
+fn diff_item_definition(path: &str, old: &ItemDefinition, new: &ItemDefinition, details: &mut Vec<String>) {
+  changed(details, &format!("{path}type reference"), old.type_ref().as_deref(), new.type_ref().as_deref());
+  let old_allowed_values = old.allowed_values().as_ref().and_then(|unary_tests| unary_tests.text().as_deref());
+  let new_allowed_values = new.allowed_values().as_ref().and_then(|unary_tests| unary_tests.text().as_deref());
+  changed(details, &format!("{path}allowed values"), old_allowed_values, new_allowed_values);
+  if old.is_collection() != new.is_collection() {
+    details.push(format!("{path}collection flag changed from {} to {}", old.is_collection(), new.is_collection()));
+  }
+  let old_function = old.function_item().as_ref();
+  let new_function = new.function_item().as_ref();
+  let old_parameters = old_function.map(|function_item| parameters(function_item.parameters()));
+  let new_parameters = new_function.map(|function_item| parameters(function_item.parameters()));
+  changed(details, &format!("{path}function parameters"), old_parameters.as_deref(), new_parameters.as_deref());
+  let old_output = old_function.and_then(|function_item| function_item.output_type_ref().as_deref());
+  let new_output = new_function.and_then(|function_item| function_item.output_type_ref().as_deref());
+  changed(details, &format!("{path}function output type reference"), old_output, new_output);
+  let old_components = old.item_components().iter().collect::<Vec<&ItemDefinition>>();
+  let new_components = new.item_components().iter().collect::<Vec<&ItemDefinition>>();
+  for pair in match_elements(&old_components, &new_components, |component| (component.opt_id(), component.name())) {
+    match pair {
+      (Some(old), Some(new)) => {
+        if old.name() != new.name() {
+          details.push(format!("{path}component '{}' renamed to '{}'", old.name(), new.name()));
+        }
+        diff_item_definition(&format!("{path}component '{}' ", new.name()), old, new, details);
+      }
+      (None, Some(new)) => details.push(format!("{path}component '{}' added", new.name())),
+      (Some(old), None) => details.push(format!("{path}component '{}' removed", old.name())),
+      (None, None) => {}
+    }
+  }
+}
+
+/// Returns the kind of the expression, used in descriptions of changes.
+fn expression_kind(expression_instance: Option<&ExpressionInstance>) -> &'static str {
+  match expression_instance {
+    Some(ExpressionInstance::Conditional(_)) => "conditional",
+    Some(ExpressionInstance::Context(_)) => "context",
+    Some(ExpressionInstance::DecisionTable(_)) => "decision table",
+    Some(ExpressionInstance::Every(_)) => "every",
+    Some(ExpressionInstance::Filter(_)) => "filter",
+    Some(ExpressionInstance::For(_)) => "for",
+    Some(ExpressionInstance::FunctionDefinition(_)) => "function definition",
+    Some(ExpressionInstance::Invocation(_)) => "invocation",
+    Some(ExpressionInstance::List(_)) => "list",
+    Some(ExpressionInstance::LiteralExpression(_)) => "literal expression",
+    Some(ExpressionInstance::Relation(_)) => "relation",
+    Some(ExpressionInstance::Some(_)) => "some",
+    None => "none",
+  }
+}
+
+/// Compares two versions of the expression, decision tables are compared rule by rule,
+/// other boxed expressions are compared part by part, reporting which part changed.
+fn diff_expression(label: &str, old: Option<&ExpressionInstance>, new: Option<&ExpressionInstance>, details: &mut Vec<String>, rule_changes: &mut Vec<RuleChange>) {
+  match (old, new) {
+    (Some(ExpressionInstance::DecisionTable(old)), Some(ExpressionInstance::DecisionTable(new))) => diff_decision_table(old, new, details, rule_changes),
+    (Some(ExpressionInstance::LiteralExpression(old)), Some(ExpressionInstance::LiteralExpression(new))) => {
+      if old.text() != new.text() {
+        let old_text = old.text().as_deref().unwrap_or_default().trim();
+        let new_text = new.text().as_deref().unwrap_or_default().trim();
+        details.push(format!("{label} changed from `{old_text}` to `{new_text}`"));
+      }
+      changed(details, &format!("{label} type reference"), old.type_ref().as_deref(), new.type_ref().as_deref());
+    }
+    (Some(ExpressionInstance::Context(old)), Some(ExpressionInstance::Context(new))) => diff_context(label, old, new, details),
+    (Some(ExpressionInstance::Invocation(old)), Some(ExpressionInstance::Invocation(new))) => diff_invocation(label, old, new, details),
+    (Some(ExpressionInstance::FunctionDefinition(old)), Some(ExpressionInstance::FunctionDefinition(new))) => {
+      changed(details, &format!("{label} type reference"), old.type_ref().as_deref(), new.type_ref().as_deref());
+      changed(details, &format!("{label} kind"), Some(function_kind(old.kind())), Some(function_kind(new.kind())));
+      let (old_parameters, new_parameters) = (parameters(old.formal_parameters()), parameters(new.formal_parameters()));
+      changed(details, &format!("{label} parameters"), Some(&old_parameters), Some(&new_parameters));
+      diff_nested_expression(&format!("{label} body"), old.body().as_ref(), new.body().as_ref(), details);
+    }
+    (Some(ExpressionInstance::List(old)), Some(ExpressionInstance::List(new))) => {
+      changed(details, &format!("{label} type reference"), old.type_ref().as_deref(), new.type_ref().as_deref());
+      diff_elements(label, old.elements(), new.elements(), details);
+    }
+    (Some(ExpressionInstance::Relation(old)), Some(ExpressionInstance::Relation(new))) => {
+      changed(details, &format!("{label} type reference"), old.type_ref().as_deref(), new.type_ref().as_deref());
+      let (old_columns, new_columns) = (parameters(old.columns()), parameters(new.columns()));
+      changed(details, &format!("{label} columns"), Some(&old_columns), Some(&new_columns));
+      for index in 0..old.rows().len().max(new.rows().len()) {
+        let row_label = format!("{label} row {}", index + 1);
+        match (old.rows().get(index), new.rows().get(index)) {
+          (Some(old), Some(new)) => diff_elements(&row_label, old.elements(), new.elements(), details),
+          (None, Some(_)) => details.push(format!("{row_label} added")),
+          (Some(_), None) => details.push(format!("{row_label} removed")),
+          (None, None) => {}
+        }
+      }
+    }
+    (Some(ExpressionInstance::Conditional(old)), Some(ExpressionInstance::Conditional(new))) => {
+      changed(details, &format!("{label} type reference"), old.type_ref().as_deref(), new.type_ref().as_deref());
+      diff_child_expression(&format!("{label} if"), old.if_expression(), new.if_expression(), details);
+      diff_child_expression(&format!("{label} then"), old.then_expression(), new.then_expression(), details);
+      diff_child_expression(&format!("{label} else"), old.else_expression(), new.else_expression(), details);
+    }
+    (Some(ExpressionInstance::Filter(old)), Some(ExpressionInstance::Filter(new))) => {
+      changed(details, &format!("{label} type reference"), old.type_ref().as_deref(), new.type_ref().as_deref());
+      diff_child_expression(&format!("{label} in"), old.in_expression(), new.in_expression(), details);
+      diff_child_expression(&format!("{label} match"), old.match_expression(), new.match_expression(), details);
+    }
+    (Some(ExpressionInstance::For(old)), Some(ExpressionInstance::For(new))) => {
+      changed(details, &format!("{label} type reference"), old.type_ref().as_deref(), new.type_ref().as_deref());
+      changed(details, &format!("{label} iterator variable"), Some(old.iterator_variable()), Some(new.iterator_variable()));
+      diff_child_expression(&format!("{label} in"), old.in_expression(), new.in_expression(), details);
+      diff_child_expression(&format!("{label} return"), old.return_expression(), new.return_expression(), details);
+    }
+    (Some(ExpressionInstance::Every(old)), Some(ExpressionInstance::Every(new))) | (Some(ExpressionInstance::Some(old)), Some(ExpressionInstance::Some(new))) => {
+      changed(details, &format!("{label} type reference"), old.type_ref().as_deref(), new.type_ref().as_deref());
+      changed(details, &format!("{label} iterator variable"), Some(old.iterator_variable()), Some(new.iterator_variable()));
+      diff_child_expression(&format!("{label} in"), old.in_expression(), new.in_expression(), details);
+      diff_child_expression(&format!("{label} satisfies"), old.satisfies_expression(), new.satisfies_expression(), details);
+    }
+    _ => {
+      let (old_kind, new_kind) = (expression_kind(old), expression_kind(new));
+      if old_kind != new_kind {
+        details.push(format!("{label} changed from {old_kind} to {new_kind}"));
+      }
+    }
+  }
+}
+
+/// Compares two versions of the expression nested in a boxed expression.
+/// Changes of nested decision tables, including changed rules, are reported as details prefixed with the label.
+fn diff_nested_expression(label: &str, old: Option<&ExpressionInstance>, new: Option<&ExpressionInstance>, details: &mut Vec<String>) {
+  let mut nested_details = vec![];
+  let mut rule_changes = vec![];
+  diff_expression(label, old, new, &mut nested_details, &mut rule_changes);
+  if let (Some(ExpressionInstance::DecisionTable(_)), Some(ExpressionInstance::DecisionTable(_))) = (old, new) {
+    details.extend(nested_details.iter().map(|detail| format!("{label} {detail}")));
+  } else {
+    details.extend(nested_details);
+  }
+  details.extend(rule_changes.iter().map(|rule_change| format!("{label} {rule_change}")));
+}
+
+/// Compares two versions of the child expression of a boxed expression.
+fn diff_child_expression(label: &str, old: &ChildExpression, new: &ChildExpression, details: &mut Vec<String>) {
+  changed(details, &format!("{label} type reference"), old.type_ref().as_deref(), new.type_ref().as_deref());
+  diff_nested_expression(label, Some(old.value()), Some(new.value()), details);
+}
+
+/// Compares two versions of the context, entries are matched by the names of their variables,
+/// the entry without variable is the result of the context.
+fn diff_context(label: &str, old: &Context, new: &Context, details: &mut Vec<String>) {
+  let entry_label = |entry: &ContextEntry| match &entry.variable {
+    Some(variable) => format!("{label} entry '{}'", variable.name()),
+    None => format!("{label} result"),
+  };
+  let entry_name = |entry: &ContextEntry| entry.variable.as_ref().map(|variable| variable.name().to_string());
+  for old_entry in old.context_entries() {
+    match new.context_entries().iter().find(|new_entry| entry_name(new_entry) == entry_name(old_entry)) {
+      Some(new_entry) => {
+        let old_type_ref = old_entry.variable.as_ref().and_then(type_ref);
+        let new_type_ref = new_entry.variable.as_ref().and_then(type_ref);
+        changed(details, &format!("{} type reference", entry_label(new_entry)), old_type_ref, new_type_ref);
+        diff_nested_expression(&entry_label(new_entry), Some(&old_entry.value), Some(&new_entry.value), details);
+      }
+      None => details.push(format!("{} removed", entry_label(old_entry))),
+    }
+  }
+  for new_entry in new.context_entries() {
+    if !old.context_entries().iter().any(|old_entry| entry_name(old_entry) == entry_name(new_entry)) {
+      details.push(format!("{} added", entry_label(new_entry)));
+    }
+  }
+}
+
+/// Compares two versions of the invocation, bindings are matched by the names of bound parameters.
+fn diff_invocation(label: &str, old: &Invocation, new: &Invocation, details: &mut Vec<String>) {
+  diff_nested_expression(&format!("{label} called function"), Some(old.called_function()), Some(new.called_function()), details);
+  for old_binding in old.bindings() {
+    let binding_label = format!("{label} binding '{}'", old_binding.parameter().name());
+    match new.bindings().iter().find(|new_binding| new_binding.parameter().name() == old_binding.parameter().name()) {
+      Some(new_binding) => diff_nested_expression(&binding_label, old_binding.binding_formula().as_ref(), new_binding.binding_formula().as_ref(), details),
+      None => details.push(format!("{binding_label} removed")),
+    }
+  }
+  for new_binding in new.bindings() {
+    if !old.bindings().iter().any(|old_binding| old_binding.parameter().name() == new_binding.parameter().name()) {
+      details.push(format!("{label} binding '{}' added", new_binding.parameter().name()));
+    }
+  }
+}
+
+/// Compares elements of two versions of the list, elements are matched by position.
+fn diff_elements(label: &str, old: &[ExpressionInstance], new: &[ExpressionInstance], details: &mut Vec<String>) {
+  for index in 0..old.len().max(new.len()) {
+    let element_label = format!("{label} element {}", index + 1);
+    match (old.get(index), new.get(index)) {
+      (Some(old), Some(new)) => diff_nested_expression(&element_label, Some(old), Some(new), details),
+      (None, Some(_)) => details.push(format!("{element_label} added")),
+      (Some(_), None) => details.push(format!("{element_label} removed")),
+      (None, None) => {}
+    }
+  }
+}
+
+/// Returns the name of the function kind, as used in the model.
+fn function_kind(function_kind: &FunctionKind) -> &'static str {
+  match function_kind {
+    FunctionKind::Feel => "FEEL",
+    FunctionKind::Java => "Java",
+    FunctionKind::Pmml => "PMML",
+  }
+}
+
+/// Compares clauses of two versions of the decision table, followed by comparing the rules.
+fn diff_decision_table(old: &DecisionTable, new: &DecisionTable, details: &mut Vec<String>, rule_changes: &mut Vec<RuleChange>) {
+  if old.hit_policy() != new.hit_policy() {
+    details.push(format!("hit policy changed from '{}' to '{}'", old.hit_policy(), new.hit_policy()));
+  }
+  let old_inputs = old.input_clauses().collect::<Vec<&InputClause>>();
+  let new_inputs = new.input_clauses().collect::<Vec<&InputClause>>();
+  for index in 0..old_inputs.len().max(new_inputs.len()) {
+    let label = format!("input clause {}", index + 1);
+    match (old_inputs.get(index), new_inputs.get(index)) {
+      (Some(old), Some(new)) => {
+        if old.input_expression != new.input_expression {
+          details.push(format!("{label} changed from `{}` to `{}`", old.input_expression, new.input_expression));
+        }
+        changed(
+          details,
+          &format!("allowed values of {label}"),
+          old.allowed_input_values.as_deref(),
+          new.allowed_input_values.as_deref(),
+        );
+      }
+      (None, Some(new)) => details.push(format!("{label} `{}` added", new.input_expression)),
+      (Some(old), None) => details.push(format!("{label} `{}` removed", old.input_expression)),
+      (None, None) => {}
+    }
+  }
+  let old_outputs = old.output_clauses().collect::<Vec<&OutputClause>>();
+  let new_outputs = new.output_clauses().collect::<Vec<&OutputClause>>();
+  for index in 0..old_outputs.len().max(new_outputs.len()) {
+    let label = format!("output clause {}", index + 1);
+    match (old_outputs.get(index), new_outputs.get(index)) {
+      (Some(old), Some(new)) => {
+        changed(details, &format!("name of {label}"), old.name.as_deref(), new.name.as_deref());
+        changed(details, &format!("type reference of {label}"), old.type_ref.as_deref(), new.type_ref.as_deref());
+        changed(
+          details,
+          &format!("allowed values of {label}"),
+          old.allowed_output_values.as_deref(),
+          new.allowed_output_values.as_deref(),
+        );
+        changed(
+          details,
+          &format!("default output of {label}"),
+          old.default_output_entry.as_deref(),
+          new.default_output_entry.as_deref(),
+        );
+      }
+      (None, Some(_)) => details.push(format!("{label} added")),
+      (Some(_), None) => details.push(format!("{label} removed")),
+      (None, None) => {}
+    }
+  }
+  let old_annotations = old.annotations().map(|annotation| annotation.name.as_str()).collect::<Vec<&str>>();
+  let new_annotations = new.annotations().map(|annotation| annotation.name.as_str()).collect::<Vec<&str>>();
+  if old_annotations != new_annotations {
+    details.push(format!("annotations changed from '{}' to '{}'", old_annotations.join(", "), new_annotations.join(", ")));
+  }
+  let old_rules = old.rules().collect::<Vec<&DecisionRule>>();
+  let new_rules = new.rules().collect::<Vec<&DecisionRule>>();
+  rule_changes.extend(diff_rules(&old_rules, &new_rules, &columns(old), &columns(new)));
+}
+
+/// Returns the names of columns of the decision table: input expressions,
+/// names of outputs and names of annotations.
+fn columns(decision_table: &DecisionTable) -> Vec<String> {
+  let mut columns = decision_table
+    .input_clauses()
+    .map(|input_clause| input_clause.input_expression.trim().to_string())
+    .collect::<Vec<String>>();
+  for output_clause in decision_table.output_clauses() {
+    let name = output_clause
+      .name
+      .as_ref()
+      .or(decision_table.output_label().as_ref())
+      .or(decision_table.information_item_name().as_ref())
+      .map(|name| name.to_string())
+      .unwrap_or_else(|| "output".to_string());
+    columns.push(name);
+  }
+  columns.extend(decision_table.annotations().map(|annotation| annotation.name.clone()));
+  columns
+}
+
+/// Returns the texts of cells of the rule.
+fn cells(rule: &DecisionRule) -> Vec<String> {
+  rule
+    .input_entries
+    .iter()
+    .map(|entry| entry.text.trim().to_string())
+    .chain(rule.output_entries.iter().map(|entry| entry.text.trim().to_string()))
+    .chain(rule.annotation_entries.iter().map(|entry| entry.text.trim().to_string()))
+    .collect()
+}
+
+/// Compares rules of two versions of the decision table. Rules are aligned using
+/// the longest common subsequence of rules with equal cells, rules between aligned rules
+/// are compared pairwise, the remaining rules are reported as added or removed.
+fn diff_rules(old: &[&DecisionRule], new: &[&DecisionRule], old_columns: &[String], new_columns: &[String]) -> Vec<RuleChange> {
+  let (old_len, new_len) = (old.len(), new.len());
+  let old_cells = old.iter().map(|rule| cells(rule)).collect::<Vec<Vec<String>>>();
+  let new_cells = new.iter().map(|rule| cells(rule)).collect::<Vec<Vec<String>>>();
+  let mut lengths = vec![vec![0_usize; new_len + 1]; old_len + 1];
+  for i in (0..old_len).rev() {
+    for j in (0..new_len).rev() {
+      lengths[i][j] = if old_cells[i] == new_cells[j] {
+        lengths[i + 1][j + 1] + 1
+      } else {
+        lengths[i + 1][j].max(lengths[i][j + 1])
+      };
+    }
+  }
+  let mut rule_changes = vec![];
+  let (mut removed, mut added) = (vec![], vec![]);
+  let (mut i, mut j) = (0, 0);
+  while i < old_len || j < new_len {
+    if i < old_len && j < new_len && old_cells[i] == new_cells[j] {
+      rule_changes.extend(pair_rules(&removed, &added, old, new, old_columns, new_columns));
+      removed.clear();
+      added.clear();
+      i += 1;
+      j += 1;
+    } else if j < new_len && (i == old_len || lengths[i][j + 1] >= lengths[i + 1][j]) {
+      added.push(j);
+      j += 1;
+    } else {
+      removed.push(i);
+      i += 1;
+    }
+  }
+  rule_changes.extend(pair_rules(&removed, &added, old, new, old_columns, new_columns));
+  rule_changes
+}
+
+/// Pairs rules removed from and added to the same place in the decision table as changed rules,
+/// the rules that could not be paired are reported as removed or added.
+fn pair_rules(removed: &[usize], added: &[usize], old: &[&DecisionRule], new: &[&DecisionRule], old_columns: &[String], new_columns: &[String]) -> Vec<RuleChange> {
+  let mut rule_changes = vec![];
+  for index in 0..removed.len().max(added.len()) {
+    let old_index = removed.get(index).copied();
+    let new_index = added.get(index).copied();
+    let old_cells = old_index.map(|old_index| cells(old[old_index])).unwrap_or_default();
+    let new_cells = new_index.map(|new_index| cells(new[new_index])).unwrap_or_default();
+    let kind = match (old_index, new_index) {
+      (Some(_), Some(_)) => ChangeKind::Changed,
+      (None, _) => ChangeKind::Added,
+      (_, None) => ChangeKind::Removed,
+    };
+    let cell_count = if new_index.is_some() { new_cells.len().max(old_cells.len()) } else { old_cells.len() };
+    let cells = (0..cell_count)
+      .map(|cell_index| CellChange {
+        column: new_columns
+          .get(cell_index)
+          .filter(|_| new_index.is_some())
+          .or(old_columns.get(cell_index))
+          .cloned()
+          .unwrap_or_default(),
+        old_text: old_cells.get(cell_index).cloned(),
+        new_text: new_cells.get(cell_index).cloned(),
+      })
+      .collect();
+    rule_changes.push(RuleChange {
+      kind,
+      old_number: old_index.map(|old_index| old_index + 1),
+      new_number: new_index.map(|new_index| new_index + 1),
+      cells,
+    });
+  }
+  rule_changes
+}
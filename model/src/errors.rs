@@ -44,59 +44,89 @@ pub fn err_invalid_decision_table_hit_policy(hit_policy: &str) -> DmntkError {
   ModelError(format!("invalid decision table hit policy: {hit_policy}")).into()
 }
 
+pub fn err_invocable_not_found(name: &str) -> DmntkError {
+  ModelError(format!("invocable '{name}' not found")).into()
+}
+
 /// Errors related with parsing the decision model.
 #[derive(ToErrorMessage)]
 struct ModelParserError(String);
 
 /// Raised when parsed text is not a valid function kind, accepted values are:
 /// `FEEL`, `Java` or `PMML`.
-pub fn err_invalid_function_kind(s: &str) -> DmntkError {
-  ModelParserError(format!("'{s}' is not a valid function kind, accepted values are: 'FEEL', 'Java', 'PMML'")).into()
+pub fn err_invalid_function_kind(s: &str, node: &str) -> DmntkError {
+  ModelParserError(format!("'{s}' is not a valid function kind in node {node}, accepted values are: 'FEEL', 'Java', 'PMML'")).into()
 }
 
 /// Raised when parsed text is not a valid hit policy, accepted values are:
 /// `UNIQUE`, `FIRST`, `PRIORITY`, `ANY`, `COLLECT`, `RULE ORDER` or `OUTPUT ORDER`.
-pub fn err_invalid_hit_policy(s: &str) -> DmntkError {
+pub fn err_invalid_hit_policy(s: &str, node: &str) -> DmntkError {
   ModelParserError(format!(
-    "'{s}' is not a valid hit policy, allowed values are: 'UNIQUE', 'FIRST', 'PRIORITY', 'ANY', 'COLLECT', 'RULE ORDER', 'OUTPUT ORDER'"
+    "'{s}' is not a valid hit policy in node {node}, allowed values are: 'UNIQUE', 'FIRST', 'PRIORITY', 'ANY', 'COLLECT', 'RULE ORDER', 'OUTPUT ORDER'"
   ))
   .into()
 }
 
 /// Raised when parsed text is not a valid aggregation for hit policy, accepted values are:
 /// `COUNT`, `SUM`, `MIN`, or `MAX`.
-pub fn err_invalid_aggregation(s: &str) -> DmntkError {
-  ModelParserError(format!("'{s}' is not a valid aggregation, allowed values are: 'COUNT', 'SUM', 'MIN', 'MAX'")).into()
+pub fn err_invalid_aggregation(s: &str, node: &str) -> DmntkError {
+  ModelParserError(format!("'{s}' is not a valid aggregation in node {node}, allowed values are: 'COUNT', 'SUM', 'MIN', 'MAX'")).into()
 }
 
 /// Invalid value for a color.
-pub fn err_invalid_color_value(s: &str) -> DmntkError {
-  ModelParserError(format!("conversion to valid color value failed with reason: {s}")).into()
+pub fn err_invalid_color_value(s: &str, node: &str) -> DmntkError {
+  ModelParserError(format!("conversion to valid color value in node {node} failed with reason: {s}")).into()
 }
 
 /// Invalid value for a double.
-pub fn err_invalid_double_value(reason: &str) -> DmntkError {
-  ModelParserError(format!("conversion to valid double value failed with reason: {reason}")).into()
+pub fn err_invalid_double_value(reason: &str, node: &str) -> DmntkError {
+  ModelParserError(format!("conversion to valid double value in node {node} failed with reason: {reason}")).into()
+}
+
+/// Raised when the XML namespace of the model does not match any supported DMN version.
+pub fn err_unsupported_dmn_namespace(namespace: &str) -> DmntkError {
+  ModelParserError(format!(
+    "'{namespace}' is not a supported DMN namespace, supported versions are: DMN 1.1, DMN 1.2, DMN 1.3, DMN 1.4, DMN 1.5"
+  ))
+  .into()
+}
+
+/// Raised when the namespace of the `definitions` node does not match any supported DMN version.
+pub fn err_unsupported_dmn_namespace_in_node(namespace: &str, node: &str) -> DmntkError {
+  ModelParserError(format!(
+    "'{namespace}' in node {node} is not a supported DMN namespace, supported versions are: DMN 1.1, DMN 1.2, DMN 1.3, DMN 1.4, DMN 1.5"
+  ))
+  .into()
+}
+
+/// Raised when the value of the attribute could not be converted.
+pub fn err_invalid_attribute_value(node: &str, attr_name: &str, reason: &str) -> DmntkError {
+  ModelParserError(format!("invalid value of attribute '{attr_name}' in node {node}: {reason}")).into()
+}
+
+/// Raised when the parsed node is not available in the DMN version of the model.
+pub fn err_unsupported_in_dmn_version(node: &str, version: &str, required_version: &str) -> DmntkError {
+  ModelParserError(format!("{node} is not supported in {version}, it is available since {required_version}")).into()
 }
 
 /// Raised when required child node is missing.
 pub fn err_required_child_node_is_missing(s1: &str, s2: &str) -> DmntkError {
-  ModelParserError(format!("required child node '{s2}' in parent node '{s1}' is missing")).into()
+  ModelParserError(format!("required child node '{s2}' in parent node {s1} is missing")).into()
 }
 
 /// Raised when required `inputExpression` node is missing.
-pub fn err_required_input_expression_is_missing() -> DmntkError {
-  ModelParserError("required input expression in decision table's input clause is missing".to_string()).into()
+pub fn err_required_input_expression_is_missing(node: &str) -> DmntkError {
+  ModelParserError(format!("required input expression in decision table's input clause {node} is missing")).into()
 }
 
 /// Raised when required expression instance is missing.
-pub fn err_required_expression_instance_is_missing() -> DmntkError {
-  ModelParserError("required expression instance in context entry is missing".to_string()).into()
+pub fn err_required_expression_instance_is_missing(node: &str) -> DmntkError {
+  ModelParserError(format!("required expression instance in node {node} is missing")).into()
 }
 
 /// Raised when the number of elements in a row differs from the number of columns in relation.
-pub fn err_number_of_elements_in_row_differs_from_number_of_columns() -> DmntkError {
-  ModelParserError("number of elements in a row differs from the number of columns defined in a relation".to_string()).into()
+pub fn err_number_of_elements_in_row_differs_from_number_of_columns(node: &str) -> DmntkError {
+  ModelParserError(format!("number of elements in a row {node} differs from the number of columns defined in a relation")).into()
 }
 
 pub fn err_xml_parsing_model_failed(s: &str) -> DmntkError {
@@ -116,5 +146,39 @@ pub fn err_xml_expected_mandatory_child_node(s1: &str, s2: &str) -> DmntkError {
 }
 
 pub fn err_xml_expected_mandatory_text_content(s: &str) -> DmntkError {
-  ModelParserError(format!("expected mandatory text content in node '{s}'")).into()
+  ModelParserError(format!("expected mandatory text content in node {s}")).into()
+}
+
+/// Errors related to validating the decision model against XML schema.
+#[derive(ToErrorMessage)]
+struct ModelValidatorError(String);
+
+/// Raised when the node is not declared in XML schema.
+pub fn err_unexpected_node(node: &str) -> DmntkError {
+  ModelValidatorError(format!("unexpected node {node}")).into()
+}
+
+/// Raised when the child node is not allowed in parent node.
+pub fn err_unexpected_child_node(node: &str, parent: &str) -> DmntkError {
+  ModelValidatorError(format!("unexpected child node {node} in parent node {parent}")).into()
+}
+
+/// Raised when required child node is missing.
+pub fn err_expected_child_node(parent: &str, expected: &str) -> DmntkError {
+  ModelValidatorError(format!("expected child node {expected} in parent node {parent}")).into()
+}
+
+/// Raised when the attribute is not allowed in node.
+pub fn err_unexpected_attribute(node: &str, attr_name: &str) -> DmntkError {
+  ModelValidatorError(format!("unexpected attribute '{attr_name}' in node {node}")).into()
+}
+
+/// Raised when required attribute is missing.
+pub fn err_missing_attribute(node: &str, attr_name: &str) -> DmntkError {
+  ModelValidatorError(format!("missing required attribute '{attr_name}' in node {node}")).into()
+}
+
+/// Raised when the value of the attribute does not conform to its type.
+pub fn err_invalid_attribute_type(node: &str, attr_name: &str, value: &str, type_name: &str) -> DmntkError {
+  ModelValidatorError(format!("value '{value}' of attribute '{attr_name}' in node {node} is not a valid '{type_name}'")).into()
 }
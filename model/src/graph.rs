@@ -0,0 +1,332 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! # Decision requirements graph
+//!
+//! The graph of DRG elements and requirements between them is built directly
+//! from [Definitions], so it does not depend on diagram interchange information (DMNDI).
+//! The graph may be exported in [Graphviz DOT](https://graphviz.org/doc/info/lang.html)
+//! or [Mermaid](https://mermaid.js.org/syntax/flowchart.html) format.
+
+use crate::errors::err_invocable_not_found;
+use crate::model::*;
+use dmntk_common::{HRef, Result};
+use std::collections::{HashMap, HashSet};
+
+/// Kind of the graph node.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GraphNodeKind {
+  Decision,
+  InputData,
+  BusinessKnowledgeModel,
+  DecisionService,
+  KnowledgeSource,
+}
+
+/// Kind of the graph edge.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GraphEdgeKind {
+  /// Information requirement.
+  Information,
+  /// Knowledge requirement.
+  Knowledge,
+  /// Authority requirement.
+  Authority,
+  /// Decision encapsulated in or output from decision service.
+  Encapsulation,
+}
+
+/// Node of the graph representing a DRG element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphNode {
+  /// Identifier of the DRG element.
+  id: String,
+  /// Name of the DRG element.
+  name: String,
+  /// Kind of the DRG element.
+  kind: GraphNodeKind,
+}
+
+impl GraphNode {
+  /// Returns the identifier of the DRG element.
+  pub fn id(&self) -> &str {
+    &self.id
+  }
+  /// Returns the name of the DRG element.
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+  /// Returns the kind of the DRG element.
+  pub fn kind(&self) -> GraphNodeKind {
+    self.kind
+  }
+}
+
+/// Edge of the graph, directed from the required element to the requiring element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphEdge {
+  /// Identifier of the required element.
+  source: String,
+  /// Identifier of the requiring element.
+  target: String,
+  /// Kind of the requirement.
+  kind: GraphEdgeKind,
+}
+
+impl GraphEdge {
+  /// Returns the identifier of the required element.
+  pub fn source(&self) -> &str {
+    &self.source
+  }
+  /// Returns the identifier of the requiring element.
+  pub fn target(&self) -> &str {
+    &self.target
+  }
+  /// Returns the kind of the requirement.
+  pub fn kind(&self) -> GraphEdgeKind {
+    self.kind
+  }
+}
+
+/// Decision requirements graph.
+#[derive(Debug, Clone)]
+pub struct DrgGraph {
+  /// Name of the model.
+  name: String,
+  /// Nodes in the order of DRG elements in the model.
+  nodes: Vec<GraphNode>,
+  /// Edges between nodes.
+  edges: Vec<GraphEdge>,
+}
+
+impl DrgGraph {
+  /// Creates a graph of all DRG elements of the model. Requirements pointing
+  /// to elements imported from other models or to missing elements are skipped.
+  pub fn new(definitions: &Definitions) -> Self {
+    let nodes = definitions
+      .drg_elements()
+      .map(|drg_element| GraphNode {
+        id: drg_element.id().to_string(),
+        name: drg_element.name().to_string(),
+        kind: node_kind(drg_element),
+      })
+      .collect::<Vec<GraphNode>>();
+    let ids = nodes.iter().map(|node| node.id.as_str()).collect::<HashSet<&str>>();
+    let mut edges = vec![];
+    for drg_element in definitions.drg_elements() {
+      let target = drg_element.id();
+      let mut add = |kind: GraphEdgeKind, href: &HRef| {
+        let is_local = match href.namespace() {
+          Some(namespace) => namespace.trim_end_matches('/') == definitions.namespace().trim_end_matches('/'),
+          None => true,
+        };
+        if is_local && ids.contains(href.id()) {
+          edges.push(GraphEdge {
+            source: href.id().to_string(),
+            target: target.to_string(),
+            kind,
+          });
+        }
+      };
+      match drg_element {
+        DrgElement::Decision(decision) => {
+          for requirement in decision.information_requirements() {
+            requirement
+              .required_decision()
+              .iter()
+              .chain(requirement.required_input())
+              .for_each(|href| add(GraphEdgeKind::Information, href));
+          }
+          for requirement in decision.knowledge_requirements() {
+            add(GraphEdgeKind::Knowledge, requirement.required_knowledge());
+          }
+          decision
+            .authority_requirements()
+            .iter()
+            .flat_map(AuthorityRequirement::references)
+            .for_each(|href| add(GraphEdgeKind::Authority, href));
+        }
+        DrgElement::BusinessKnowledgeModel(business_knowledge_model) => {
+          for requirement in business_knowledge_model.knowledge_requirements() {
+            add(GraphEdgeKind::Knowledge, requirement.required_knowledge());
+          }
+          business_knowledge_model
+            .authority_requirements()
+            .iter()
+            .flat_map(AuthorityRequirement::references)
+            .for_each(|href| add(GraphEdgeKind::Authority, href));
+        }
+        DrgElement::DecisionService(decision_service) => {
+          decision_service
+            .output_decisions()
+            .iter()
+            .chain(decision_service.encapsulated_decisions())
+            .for_each(|href| add(GraphEdgeKind::Encapsulation, href));
+        }
+        DrgElement::KnowledgeSource(knowledge_source) => {
+          knowledge_source
+            .authority_requirements()
+            .iter()
+            .flat_map(AuthorityRequirement::references)
+            .for_each(|href| add(GraphEdgeKind::Authority, href));
+        }
+        DrgElement::InputData(_) => {}
+      }
+    }
+    Self {
+      name: definitions.name().to_string(),
+      nodes,
+      edges,
+    }
+  }
+
+  /// Creates a graph containing the invocable (decision, business knowledge model or decision service)
+  /// with specified name and all elements it requires, directly or indirectly.
+  pub fn for_invocable(definitions: &Definitions, invocable_name: &str) -> Result<Self> {
+    let graph = Self::new(definitions);
+    let Some(invocable) = graph
+      .nodes
+      .iter()
+      .find(|node| node.name == invocable_name && !matches!(node.kind, GraphNodeKind::InputData | GraphNodeKind::KnowledgeSource))
+    else {
+      return Err(err_invocable_not_found(invocable_name));
+    };
+    let mut sources: HashMap<&str, Vec<&str>> = HashMap::new();
+    for edge in &graph.edges {
+      sources.entry(edge.target.as_str()).or_default().push(edge.source.as_str());
+    }
+    let mut required = HashSet::from([invocable.id.as_str()]);
+    let mut pending = vec![invocable.id.as_str()];
+    while let Some(id) = pending.pop() {
+      for source in sources.get(id).into_iter().flatten() {
+        if required.insert(source) {
+          pending.push(source);
+        }
+      }
+    }
+    Ok(Self {
+      name: graph.name.clone(),
+      nodes: graph.nodes.iter().filter(|node| required.contains(node.id.as_str())).cloned().collect(),
+      edges: graph.edges.iter().filter(|edge| required.contains(edge.target.as_str())).cloned().collect(),
+    })
+  }
+
+  /// Returns the nodes of the graph.
+  pub fn nodes(&self) -> &[GraphNode] {
+    &self.nodes
+  }
+
+  /// Returns the edges of the graph.
+  pub fn edges(&self) -> &[GraphEdge] {
+    &self.edges
+  }
+
+  /// Returns the graph in Graphviz DOT format.
+  pub fn to_dot(&self) -> String {
+    let node_ids = self.node_ids();
+    let mut output = format!("digraph \"{}\" {{\n  rankdir=BT;\n", dot_escape(&self.name));
+    for (index, node) in self.nodes.iter().enumerate() {
+      let shape = match node.kind {
+        GraphNodeKind::Decision => "shape=box",
+        GraphNodeKind::InputData => "shape=ellipse",
+        GraphNodeKind::BusinessKnowledgeModel => "shape=octagon",
+        GraphNodeKind::DecisionService => "shape=box, style=\"rounded,bold\"",
+        GraphNodeKind::KnowledgeSource => "shape=note",
+      };
+      output.push_str(&format!("  n{} [label=\"{}\", {shape}];\n", index + 1, dot_escape(&node.name)));
+    }
+    for edge in &self.edges {
+      let style = match edge.kind {
+        GraphEdgeKind::Information => "",
+        GraphEdgeKind::Knowledge => " [style=dashed]",
+        GraphEdgeKind::Authority => " [style=dashed, arrowhead=dot]",
+        GraphEdgeKind::Encapsulation => " [style=dotted, arrowhead=none]",
+      };
+      output.push_str(&format!("  {} -> {}{style};\n", node_ids[edge.source.as_str()], node_ids[edge.target.as_str()]));
+    }
+    output.push_str("}\n");
+    output
+  }
+
+  /// Returns the graph in Mermaid flowchart format.
+  pub fn to_mermaid(&self) -> String {
+    let node_ids = self.node_ids();
+    let mut output = "flowchart BT\n".to_string();
+    for (index, node) in self.nodes.iter().enumerate() {
+      let name = mermaid_escape(&node.name);
+      let shape = match node.kind {
+        GraphNodeKind::Decision => format!("[\"{name}\"]"),
+        GraphNodeKind::InputData => format!("([\"{name}\"])"),
+        GraphNodeKind::BusinessKnowledgeModel => format!("{{{{\"{name}\"}}}}"),
+        GraphNodeKind::DecisionService => format!("[[\"{name}\"]]"),
+        GraphNodeKind::KnowledgeSource => format!(">\"{name}\"]"),
+      };
+      output.push_str(&format!("  n{}{shape}\n", index + 1));
+    }
+    for edge in &self.edges {
+      let arrow = match edge.kind {
+        GraphEdgeKind::Information => "-->",
+        GraphEdgeKind::Knowledge => "-.->",
+        GraphEdgeKind::Authority => "-.-o",
+        GraphEdgeKind::Encapsulation => "-.-",
+      };
+      output.push_str(&format!("  {} {arrow} {}\n", node_ids[edge.source.as_str()], node_ids[edge.target.as_str()]));
+    }
+    output
+  }
+
+  /// Returns identifiers of nodes used in exported graphs, indexed by identifiers of DRG elements.
+  fn node_ids(&self) -> HashMap<&str, String> {
+    self.nodes.iter().enumerate().map(|(index, node)| (node.id.as_str(), format!("n{}", index + 1))).collect()
+  }
+}
+
+/// Returns the kind of the node representing a DRG element.
+fn node_kind(drg_element: &DrgElement) -> GraphNodeKind {
+  match drg_element {
+    DrgElement::Decision(_) => GraphNodeKind::Decision,
+    DrgElement::InputData(_) => GraphNodeKind::InputData,
+    DrgElement::BusinessKnowledgeModel(_) => GraphNodeKind::BusinessKnowledgeModel,
+    DrgElement::DecisionService(_) => GraphNodeKind::DecisionService,
+    DrgElement::KnowledgeSource(_) => GraphNodeKind::KnowledgeSource,
+  }
+}
+
+/// Escapes the text used as a quoted identifier or label in DOT format.
+fn dot_escape(text: &str) -> String {
+  text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Escapes the text used as a quoted label in Mermaid format.
+fn mermaid_escape(text: &str) -> String {
+  text.replace('"', "#quot;")
+}
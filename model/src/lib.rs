@@ -33,13 +33,24 @@
 #[macro_use]
 extern crate dmntk_macros;
 
+mod differ;
 mod errors;
+mod graph;
+mod linter;
 mod model;
 mod parser;
+mod validator;
+mod writer;
 mod xml_utils;
 
 #[cfg(test)]
 mod tests;
 
+pub use differ::{diff, CellChange, ChangeKind, ModelChange, RuleChange};
+pub use graph::{DrgGraph, GraphEdge, GraphEdgeKind, GraphNode, GraphNodeKind};
+pub use linter::{lint, LintDiagnostic, LintRule, LintSeverity};
 pub use model::*;
 pub use parser::parse;
+pub use validator::validate;
+pub use writer::write;
+pub use xml_utils::element_positions;
@@ -0,0 +1,781 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! # Static checks of DMN models
+//!
+//! The linter detects common modelling mistakes in parsed [Definitions],
+//! like elements not used by any decision, requirements pointing to missing elements,
+//! unknown type references, cycles in requirement graph or literal expressions
+//! that are not valid `FEEL` expressions. Elements imported from other models are not checked.
+
+use crate::model::*;
+use dmntk_common::HRef;
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::{is_built_in_type_name, FeelScope, Name};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// Names of types that may be used as type references in addition to `FEEL` built-in types:
+/// names of simple types from XML schema and names of generic `FEEL` types without type parameters.
+const ADDITIONAL_TYPE_NAMES: [&str; 7] = ["dateTime", "dayTimeDuration", "yearMonthDuration", "context", "function", "list", "range"];
+
+/// Severity of the detected issue.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LintSeverity {
+  /// The model is evaluable, but probably contains a modelling mistake.
+  Warning,
+  /// The model is invalid or can not be evaluated as expected.
+  Error,
+}
+
+impl fmt::Display for LintSeverity {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      LintSeverity::Warning => write!(f, "warning"),
+      LintSeverity::Error => write!(f, "error"),
+    }
+  }
+}
+
+/// Rules checked by the linter.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LintRule {
+  /// Input data not required by any decision, decision service or knowledge source.
+  UnreferencedInputData,
+  /// Business knowledge model not required by any decision or business knowledge model.
+  UnreferencedBusinessKnowledgeModel,
+  /// Requirement pointing to an element that does not exist in the model.
+  UnresolvedRequirement,
+  /// Type reference naming neither a built-in type nor an item definition.
+  UnknownTypeRef,
+  /// Decision having the name of its variable different from the name of the decision.
+  VariableNameMismatch,
+  /// Cycle in the graph of requirements between decisions, business knowledge models and decision services.
+  RequirementCycle,
+  /// Literal expression that is not a valid `FEEL` expression.
+  InvalidFeelExpression,
+}
+
+impl LintRule {
+  /// All rules checked by the linter.
+  pub const ALL: [LintRule; 7] = [
+    LintRule::UnreferencedInputData,
+    LintRule::UnreferencedBusinessKnowledgeModel,
+    LintRule::UnresolvedRequirement,
+    LintRule::UnknownTypeRef,
+    LintRule::VariableNameMismatch,
+    LintRule::RequirementCycle,
+    LintRule::InvalidFeelExpression,
+  ];
+
+  /// Returns the identifier of the rule.
+  pub fn id(&self) -> &'static str {
+    match self {
+      LintRule::UnreferencedInputData => "unreferenced-input-data",
+      LintRule::UnreferencedBusinessKnowledgeModel => "unreferenced-bkm",
+      LintRule::UnresolvedRequirement => "unresolved-requirement",
+      LintRule::UnknownTypeRef => "unknown-type-ref",
+      LintRule::VariableNameMismatch => "variable-name-mismatch",
+      LintRule::RequirementCycle => "requirement-cycle",
+      LintRule::InvalidFeelExpression => "invalid-feel-expression",
+    }
+  }
+
+  /// Returns the short description of the rule.
+  pub fn description(&self) -> &'static str {
+    match self {
+      LintRule::UnreferencedInputData => "Input data is not required by any decision, decision service or knowledge source.",
+      LintRule::UnreferencedBusinessKnowledgeModel => "Business knowledge model is not required by any decision or business knowledge model.",
+      LintRule::UnresolvedRequirement => "Requirement points to an element that does not exist in the model.",
+      LintRule::UnknownTypeRef => "Type reference names neither a built-in type nor an item definition.",
+      LintRule::VariableNameMismatch => "Name of the decision variable differs from the name of the decision.",
+      LintRule::RequirementCycle => "Requirements between elements form a cycle.",
+      LintRule::InvalidFeelExpression => "Literal expression is not a valid FEEL expression.",
+    }
+  }
+
+  /// Returns the severity of issues detected by the rule.
+  pub fn severity(&self) -> LintSeverity {
+    match self {
+      LintRule::UnreferencedInputData | LintRule::UnreferencedBusinessKnowledgeModel => LintSeverity::Warning,
+      _ => LintSeverity::Error,
+    }
+  }
+}
+
+/// Issue detected by the linter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintDiagnostic {
+  /// Rule that detected the issue.
+  rule: LintRule,
+  /// Identifier of the element the issue is reported for.
+  element_id: String,
+  /// Name of the element the issue is reported for.
+  element_name: String,
+  /// Description of the issue.
+  message: String,
+}
+
+impl fmt::Display for LintDiagnostic {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}[{}]: {}", self.severity(), self.rule.id(), self.message)
+  }
+}
+
+impl LintDiagnostic {
+  /// Returns the rule that detected the issue.
+  pub fn rule(&self) -> LintRule {
+    self.rule
+  }
+  /// Returns the severity of the issue.
+  pub fn severity(&self) -> LintSeverity {
+    self.rule.severity()
+  }
+  /// Returns the identifier of the element the issue is reported for.
+  pub fn element_id(&self) -> &str {
+    &self.element_id
+  }
+  /// Returns the name of the element the issue is reported for.
+  pub fn element_name(&self) -> &str {
+    &self.element_name
+  }
+  /// Returns the description of the issue.
+  pub fn message(&self) -> &str {
+    &self.message
+  }
+}
+
+/// Checks the model against all linter rules and returns detected issues.
+/// The list of issues is empty when no issues were found.
+pub fn lint(definitions: &Definitions) -> Vec<LintDiagnostic> {
+  let mut linter = Linter::new(definitions);
+  linter.check_unreferenced_elements();
+  linter.check_requirements();
+  linter.check_diagram_edges();
+  linter.check_variable_names();
+  linter.check_requirement_cycles();
+  linter.check_type_refs();
+  linter.check_literal_expressions();
+  linter.diagnostics
+}
+
+/// Names, type references and literal expressions found in a single element.
+#[derive(Default)]
+struct Content<'a> {
+  /// Names of variables declared in the element.
+  names: Vec<Name>,
+  /// Type references used in the element.
+  type_refs: Vec<&'a str>,
+  /// Literal expressions defined in the element.
+  literal_expressions: Vec<&'a LiteralExpression>,
+}
+
+/// Element with its content.
+struct ContentOwner<'a> {
+  /// Kind of the element, used in messages.
+  kind: &'static str,
+  /// Identifier of the element.
+  id: &'a str,
+  /// Name of the element.
+  name: &'a str,
+  /// Content of the element.
+  content: Content<'a>,
+}
+
+/// Linter state.
+struct Linter<'a> {
+  /// Checked model.
+  definitions: &'a Definitions,
+  /// DRG elements indexed by identifier.
+  drg_elements: HashMap<&'a str, &'a DrgElement>,
+  /// Elements with their content.
+  owners: Vec<ContentOwner<'a>>,
+  /// Detected issues.
+  diagnostics: Vec<LintDiagnostic>,
+}
+
+impl<'a> Linter<'a> {
+  /// Creates a linter for specified model.
+  fn new(definitions: &'a Definitions) -> Self {
+    let drg_elements = definitions.drg_elements().map(|drg_element| (drg_element.id(), drg_element)).collect();
+    let mut owners = vec![];
+    for item_definition in definitions.item_definitions() {
+      let mut content = Content::default();
+      collect_item_definition(item_definition, &mut content);
+      owners.push(ContentOwner {
+        kind: "item definition",
+        id: item_definition.id(),
+        name: item_definition.name(),
+        content,
+      });
+    }
+    for drg_element in definitions.drg_elements() {
+      let mut content = Content::default();
+      match drg_element {
+        DrgElement::Decision(decision) => {
+          collect_information_item(decision.variable(), &mut content);
+          if let Some(expression_instance) = decision.decision_logic() {
+            collect_expression_instance(expression_instance, &mut content);
+          }
+        }
+        DrgElement::InputData(input_data) => collect_information_item(input_data.variable(), &mut content),
+        DrgElement::BusinessKnowledgeModel(business_knowledge_model) => {
+          collect_information_item(business_knowledge_model.variable(), &mut content);
+          if let Some(function_definition) = business_knowledge_model.encapsulated_logic() {
+            collect_function_definition(function_definition, &mut content);
+          }
+        }
+        DrgElement::DecisionService(decision_service) => collect_information_item(decision_service.variable(), &mut content),
+        DrgElement::KnowledgeSource(_) => {}
+      }
+      owners.push(ContentOwner {
+        kind: drg_element.kind(),
+        id: drg_element.id(),
+        name: drg_element.name(),
+        content,
+      });
+    }
+    Self {
+      definitions,
+      drg_elements,
+      owners,
+      diagnostics: vec![],
+    }
+  }
+
+  /// Adds a new issue.
+  fn report(&mut self, rule: LintRule, element_id: &str, element_name: &str, message: String) {
+    self.diagnostics.push(LintDiagnostic {
+      rule,
+      element_id: element_id.to_string(),
+      element_name: element_name.to_string(),
+      message,
+    });
+  }
+
+  /// Returns the identifier of the element pointed by the reference,
+  /// when the reference points to an element in the checked model.
+  fn local_id(&self, href: &'a HRef) -> Option<&'a str> {
+    match href.namespace() {
+      Some(namespace) if namespace.trim_end_matches('/') != self.definitions.namespace().trim_end_matches('/') => None,
+      _ => Some(href.id()),
+    }
+  }
+
+  /// Reports input data and business knowledge models that are not required by any other element.
+  fn check_unreferenced_elements(&mut self) {
+    let mut referenced = HashSet::new();
+    for drg_element in self.definitions.drg_elements() {
+      let references = match drg_element {
+        DrgElement::Decision(decision) => {
+          let mut references = vec![];
+          for information_requirement in decision.information_requirements() {
+            references.extend(information_requirement.required_decision());
+            references.extend(information_requirement.required_input());
+          }
+          references.extend(decision.knowledge_requirements().iter().map(|requirement| requirement.required_knowledge()));
+          references.extend(decision.authority_requirements().iter().flat_map(AuthorityRequirement::references));
+          references
+        }
+        DrgElement::BusinessKnowledgeModel(business_knowledge_model) => {
+          let mut references: Vec<&HRef> = business_knowledge_model
+            .knowledge_requirements()
+            .iter()
+            .map(|requirement| requirement.required_knowledge())
+            .collect();
+          references.extend(business_knowledge_model.authority_requirements().iter().flat_map(AuthorityRequirement::references));
+          references
+        }
+        DrgElement::DecisionService(decision_service) => decision_service.input_data().iter().collect(),
+        DrgElement::KnowledgeSource(knowledge_source) => knowledge_source.authority_requirements().iter().flat_map(AuthorityRequirement::references).collect(),
+        DrgElement::InputData(_) => vec![],
+      };
+      referenced.extend(references.into_iter().filter_map(|href| self.local_id(href)));
+    }
+    for drg_element in self.definitions.drg_elements() {
+      match drg_element {
+        DrgElement::InputData(input_data) if !referenced.contains(input_data.id().as_str()) => self.report(
+          LintRule::UnreferencedInputData,
+          input_data.id(),
+          input_data.name(),
+          format!("input data '{}' is not required by any decision, decision service or knowledge source", input_data.name()),
+        ),
+        DrgElement::BusinessKnowledgeModel(business_knowledge_model) if !referenced.contains(business_knowledge_model.id().as_str()) => self.report(
+          LintRule::UnreferencedBusinessKnowledgeModel,
+          business_knowledge_model.id(),
+          business_knowledge_model.name(),
+          format!(
+            "business knowledge model '{}' is not required by any decision or business knowledge model",
+            business_knowledge_model.name()
+          ),
+        ),
+        _ => {}
+      }
+    }
+  }
+
+  /// Reports requirements pointing to elements that do not exist in the model.
+  fn check_requirements(&mut self) {
+    let definitions = self.definitions;
+    for drg_element in definitions.drg_elements() {
+      let (id, name) = (drg_element.id(), drg_element.name());
+      let mut missing = vec![];
+      match drg_element {
+        DrgElement::Decision(decision) => {
+          for requirement in decision.information_requirements() {
+            if let Some(target_id) = requirement.required_decision().as_ref().and_then(|href| self.local_id(href)) {
+              if definitions.get_decision(target_id).is_none() {
+                missing.push(("information", "decision", target_id));
+              }
+            }
+            if let Some(target_id) = requirement.required_input().as_ref().and_then(|href| self.local_id(href)) {
+              if definitions.get_input_data(target_id).is_none() {
+                missing.push(("information", "input data", target_id));
+              }
+            }
+          }
+          missing.extend(self.missing_knowledge(decision.knowledge_requirements()));
+          missing.extend(self.missing_authority(decision.authority_requirements()));
+        }
+        DrgElement::BusinessKnowledgeModel(business_knowledge_model) => {
+          missing.extend(self.missing_knowledge(business_knowledge_model.knowledge_requirements()));
+          missing.extend(self.missing_authority(business_knowledge_model.authority_requirements()));
+        }
+        DrgElement::KnowledgeSource(knowledge_source) => missing.extend(self.missing_authority(knowledge_source.authority_requirements())),
+        DrgElement::InputData(_) | DrgElement::DecisionService(_) => {}
+      }
+      for (requirement_kind, target_kind, target_id) in missing {
+        self.report(
+          LintRule::UnresolvedRequirement,
+          id,
+          name,
+          format!(
+            "{requirement_kind} requirement of {} '{name}' refers to {target_kind} with identifier '{target_id}' that does not exist",
+            drg_element.kind()
+          ),
+        );
+      }
+    }
+  }
+
+  /// Returns knowledge requirements pointing to missing business knowledge models or decision services.
+  fn missing_knowledge(&self, requirements: &'a [KnowledgeRequirement]) -> Vec<(&'static str, &'static str, &'a str)> {
+    requirements
+      .iter()
+      .filter_map(|requirement| self.local_id(requirement.required_knowledge()))
+      .filter(|target_id| {
+        !matches!(
+          self.drg_elements.get(target_id),
+          Some(DrgElement::BusinessKnowledgeModel(_)) | Some(DrgElement::DecisionService(_))
+        )
+      })
+      .map(|target_id| ("knowledge", "invocable", target_id))
+      .collect()
+  }
+
+  /// Returns authority requirements pointing to missing elements.
+  fn missing_authority(&self, requirements: &'a [AuthorityRequirement]) -> Vec<(&'static str, &'static str, &'a str)> {
+    let mut missing = vec![];
+    for requirement in requirements {
+      if let Some(target_id) = requirement.required_authority().as_ref().and_then(|href| self.local_id(href)) {
+        if self.definitions.get_knowledge_source(target_id).is_none() {
+          missing.push(("authority", "knowledge source", target_id));
+        }
+      }
+      if let Some(target_id) = requirement.required_decision().as_ref().and_then(|href| self.local_id(href)) {
+        if self.definitions.get_decision(target_id).is_none() {
+          missing.push(("authority", "decision", target_id));
+        }
+      }
+      if let Some(target_id) = requirement.required_input().as_ref().and_then(|href| self.local_id(href)) {
+        if self.definitions.get_input_data(target_id).is_none() {
+          missing.push(("authority", "input data", target_id));
+        }
+      }
+    }
+    missing
+  }
+
+  /// Reports diagram edges connecting two DRG elements, that do not depict any requirement.
+  /// Edges connected to other elements (like text annotations) are not checked,
+  /// because they depict associations, which are not retained in parsed model.
+  fn check_diagram_edges(&mut self) {
+    let Some(dmndi) = self.definitions.dmndi() else {
+      return;
+    };
+    for diagram in &dmndi.diagrams {
+      let shapes = diagram
+        .diagram_elements
+        .iter()
+        .filter_map(|diagram_element| match diagram_element {
+          DmnDiagramElement::DmnShape(shape) => Some((shape.id.as_deref()?, shape.dmn_element_ref.as_deref()?)),
+          _ => None,
+        })
+        .collect::<HashMap<&str, &str>>();
+      let depicts_drg_element = |shape_id: &Option<String>| {
+        shape_id
+          .as_deref()
+          .and_then(|shape_id| shapes.get(shape_id))
+          .is_some_and(|element_ref| self.drg_elements.contains_key(element_ref))
+      };
+      let mut missing = vec![];
+      for diagram_element in &diagram.diagram_elements {
+        if let DmnDiagramElement::DmnEdge(edge) = diagram_element {
+          if let Some(element_ref) = &edge.dmn_element_ref {
+            if depicts_drg_element(&edge.source_element) && depicts_drg_element(&edge.target_element) && self.definitions.get_requirement(element_ref).is_none() {
+              missing.push((edge.id.clone().unwrap_or_default(), element_ref.clone()));
+            }
+          }
+        }
+      }
+      for (edge_id, element_ref) in missing {
+        self.report(
+          LintRule::UnresolvedRequirement,
+          &edge_id,
+          &diagram.name,
+          format!(
+            "edge '{edge_id}' in diagram '{}' depicts requirement with identifier '{element_ref}' that does not exist",
+            diagram.name
+          ),
+        );
+      }
+    }
+  }
+
+  /// Reports decisions having variable name different from the decision name.
+  fn check_variable_names(&mut self) {
+    for drg_element in self.definitions.drg_elements() {
+      if let DrgElement::Decision(decision) = drg_element {
+        if decision.variable().name() != decision.name() {
+          self.report(
+            LintRule::VariableNameMismatch,
+            decision.id(),
+            decision.name(),
+            format!("variable name '{}' differs from the name of decision '{}'", decision.variable().name(), decision.name()),
+          );
+        }
+      }
+    }
+  }
+
+  /// Reports cycles in the graph of information and knowledge requirements.
+  fn check_requirement_cycles(&mut self) {
+    let mut edges: HashMap<&str, Vec<&str>> = HashMap::new();
+    for drg_element in self.definitions.drg_elements() {
+      let targets = match drg_element {
+        DrgElement::Decision(decision) => {
+          let mut targets = decision
+            .information_requirements()
+            .iter()
+            .filter_map(|requirement| requirement.required_decision().as_ref())
+            .collect::<Vec<&HRef>>();
+          targets.extend(decision.knowledge_requirements().iter().map(|requirement| requirement.required_knowledge()));
+          targets
+        }
+        DrgElement::BusinessKnowledgeModel(business_knowledge_model) => business_knowledge_model
+          .knowledge_requirements()
+          .iter()
+          .map(|requirement| requirement.required_knowledge())
+          .collect(),
+        DrgElement::DecisionService(decision_service) => decision_service.output_decisions().iter().chain(decision_service.encapsulated_decisions()).collect(),
+        DrgElement::InputData(_) | DrgElement::KnowledgeSource(_) => vec![],
+      };
+      let targets = targets
+        .into_iter()
+        .filter_map(|href| self.local_id(href))
+        .filter(|target_id| self.drg_elements.contains_key(target_id))
+        .collect();
+      edges.insert(drg_element.id(), targets);
+    }
+    let mut visited = HashSet::new();
+    let mut cycles = vec![];
+    for drg_element in self.definitions.drg_elements() {
+      find_cycles(drg_element.id(), &edges, &mut vec![], &mut visited, &mut cycles);
+    }
+    for cycle in cycles {
+      let first = self.drg_elements[cycle[0]];
+      let path = cycle.iter().chain(cycle.first()).map(|id| self.drg_elements[id].name()).collect::<Vec<&str>>().join(" -> ");
+      self.report(
+        LintRule::RequirementCycle,
+        first.id(),
+        first.name(),
+        format!("requirements of {} '{}' form a cycle: {path}", first.kind(), first.name()),
+      );
+    }
+  }
+
+  /// Reports type references naming neither built-in types nor item definitions.
+  fn check_type_refs(&mut self) {
+    let item_definition_names = self
+      .definitions
+      .item_definitions()
+      .iter()
+      .map(|item_definition| item_definition.name())
+      .collect::<HashSet<&str>>();
+    let import_names = self.definitions.imports().iter().map(|import| import.name()).collect::<HashSet<&str>>();
+    let is_known = |type_ref: &str| {
+      is_built_in_type_name(type_ref)
+        || ADDITIONAL_TYPE_NAMES.contains(&type_ref)
+        || item_definition_names.contains(type_ref)
+        // generic types like `list<number>` are not checked
+        || type_ref.contains('<')
+        // types defined in imported models are not checked
+        || type_ref.split_once('.').is_some_and(|(prefix, _)| import_names.contains(prefix))
+        || type_ref.contains(':')
+    };
+    let mut unknown = vec![];
+    for owner in &self.owners {
+      let mut reported = HashSet::new();
+      for type_ref in &owner.content.type_refs {
+        if !is_known(type_ref) && reported.insert(*type_ref) {
+          unknown.push((owner.kind, owner.id, owner.name, *type_ref));
+        }
+      }
+    }
+    for (kind, id, name, type_ref) in unknown {
+      self.report(
+        LintRule::UnknownTypeRef,
+        id,
+        name,
+        format!("type reference '{type_ref}' in {kind} '{name}' names neither a built-in type nor an item definition"),
+      );
+    }
+  }
+
+  /// Reports literal expressions that are not valid `FEEL` expressions. All names declared
+  /// in the model are visible while parsing, so the names used in expressions are properly recognized.
+  /// Expressions referring to imported models are not checked, names declared there are not known.
+  fn check_literal_expressions(&mut self) {
+    let import_prefixes = self.definitions.imports().iter().map(|import| format!("{}.", import.name())).collect::<Vec<String>>();
+    let mut context = FeelContext::default();
+    for owner in &self.owners {
+      context.set_null(Name::from(owner.name));
+      for name in &owner.content.names {
+        context.set_null(name.clone());
+      }
+    }
+    let scope = FeelScope::from(context);
+    let mut invalid = vec![];
+    for owner in &self.owners {
+      for literal_expression in &owner.content.literal_expressions {
+        if !self.is_feel(literal_expression) {
+          continue;
+        }
+        if let Some(text) = literal_expression.text() {
+          if import_prefixes.iter().any(|prefix| text.contains(prefix.as_str())) {
+            continue;
+          }
+          if let Err(reason) = dmntk_feel_parser::parse_expression(&scope, text, false) {
+            invalid.push((owner.kind, owner.id, owner.name, reason));
+          }
+        }
+      }
+    }
+    for (kind, id, name, reason) in invalid {
+      self.report(
+        LintRule::InvalidFeelExpression,
+        id,
+        name,
+        format!("literal expression in {kind} '{name}' is not a valid FEEL expression: {reason}"),
+      );
+    }
+  }
+
+  /// Returns `true` when the literal expression is written in `FEEL`.
+  fn is_feel(&self, literal_expression: &LiteralExpression) -> bool {
+    let Some(language) = literal_expression.expression_language().or_else(|| self.definitions.expression_language().clone()) else {
+      return true;
+    };
+    let language = language.trim().trim_end_matches('/').to_string();
+    [DmnVersion::V1_1, DmnVersion::V1_2, DmnVersion::V1_3, DmnVersion::V1_4, DmnVersion::V1_5]
+      .iter()
+      .any(|version| version.feel_uri().trim_end_matches('/') == language)
+  }
+}
+
+/// Finds cycles reachable from specified element using depth-first search.
+/// Every cycle is reported once, starting from the element where it was entered first.
+fn find_cycles<'a>(id: &'a str, edges: &HashMap<&'a str, Vec<&'a str>>, path: &mut Vec<&'a str>, visited: &mut HashSet<&'a str>, cycles: &mut Vec<Vec<&'a str>>) {
+  if let Some(position) = path.iter().position(|path_id| *path_id == id) {
+    cycles.push(path[position..].to_vec());
+    return;
+  }
+  if !visited.insert(id) {
+    return;
+  }
+  path.push(id);
+  for target_id in edges.get(id).into_iter().flatten() {
+    find_cycles(target_id, edges, path, visited, cycles);
+  }
+  path.pop();
+}
+
+/// Collects names and type references from item definition and its components.
+fn collect_item_definition<'a>(item_definition: &'a ItemDefinition, content: &mut Content<'a>) {
+  content.names.push(item_definition.feel_name().clone());
+  if let Some(type_ref) = item_definition.type_ref() {
+    content.type_refs.push(type_ref);
+  }
+  if let Some(function_item) = item_definition.function_item() {
+    if let Some(type_ref) = function_item.output_type_ref() {
+      content.type_refs.push(type_ref);
+    }
+    for parameter in function_item.parameters() {
+      collect_information_item(parameter, content);
+    }
+  }
+  for item_component in item_definition.item_components() {
+    collect_item_definition(item_component, content);
+  }
+}
+
+/// Collects the name and type reference of information item.
+fn collect_information_item<'a>(information_item: &'a InformationItem, content: &mut Content<'a>) {
+  content.names.push(information_item.feel_name().clone());
+  content.type_refs.push(information_item.type_ref());
+}
+
+/// Collects the content of function definition.
+fn collect_function_definition<'a>(function_definition: &'a FunctionDefinition, content: &mut Content<'a>) {
+  if let Some(type_ref) = function_definition.type_ref() {
+    content.type_refs.push(type_ref);
+  }
+  for parameter in function_definition.formal_parameters() {
+    collect_information_item(parameter, content);
+  }
+  // bodies of externally defined functions are not written in FEEL
+  if *function_definition.kind() == FunctionKind::Feel {
+    if let Some(body) = function_definition.body() {
+      collect_expression_instance(body, content);
+    }
+  }
+}
+
+/// Collects the content of child expression.
+fn collect_child_expression<'a>(child_expression: &'a ChildExpression, content: &mut Content<'a>) {
+  if let Some(type_ref) = child_expression.type_ref() {
+    content.type_refs.push(type_ref);
+  }
+  collect_expression_instance(child_expression.value(), content);
+}
+
+/// Collects the content of expression instance and all nested expressions.
+fn collect_expression_instance<'a>(expression_instance: &'a ExpressionInstance, content: &mut Content<'a>) {
+  match expression_instance {
+    ExpressionInstance::Conditional(conditional) => {
+      if let Some(type_ref) = conditional.type_ref() {
+        content.type_refs.push(type_ref);
+      }
+      collect_child_expression(conditional.if_expression(), content);
+      collect_child_expression(conditional.then_expression(), content);
+      collect_child_expression(conditional.else_expression(), content);
+    }
+    ExpressionInstance::Context(context) => {
+      for context_entry in context.context_entries() {
+        if let Some(variable) = &context_entry.variable {
+          collect_information_item(variable, content);
+        }
+        collect_expression_instance(&context_entry.value, content);
+      }
+    }
+    ExpressionInstance::DecisionTable(decision_table) => {
+      for output_clause in decision_table.output_clauses() {
+        if let Some(type_ref) = &output_clause.type_ref {
+          content.type_refs.push(type_ref);
+        }
+        if let Some(name) = &output_clause.name {
+          content.names.push(Name::from(name.as_str()));
+        }
+      }
+    }
+    ExpressionInstance::Every(quantified) | ExpressionInstance::Some(quantified) => {
+      if let Some(type_ref) = quantified.type_ref() {
+        content.type_refs.push(type_ref);
+      }
+      content.names.push(Name::from(quantified.iterator_variable()));
+      collect_child_expression(quantified.in_expression(), content);
+      collect_child_expression(quantified.satisfies_expression(), content);
+    }
+    ExpressionInstance::Filter(filter) => {
+      if let Some(type_ref) = filter.type_ref() {
+        content.type_refs.push(type_ref);
+      }
+      collect_child_expression(filter.in_expression(), content);
+      collect_child_expression(filter.match_expression(), content);
+    }
+    ExpressionInstance::For(for_expression) => {
+      if let Some(type_ref) = for_expression.type_ref() {
+        content.type_refs.push(type_ref);
+      }
+      content.names.push(Name::from(for_expression.iterator_variable()));
+      collect_child_expression(for_expression.in_expression(), content);
+      collect_child_expression(for_expression.return_expression(), content);
+    }
+    ExpressionInstance::FunctionDefinition(function_definition) => collect_function_definition(function_definition, content),
+    ExpressionInstance::Invocation(invocation) => {
+      collect_expression_instance(invocation.called_function(), content);
+      for binding in invocation.bindings() {
+        collect_information_item(binding.parameter(), content);
+        if let Some(binding_formula) = binding.binding_formula() {
+          collect_expression_instance(binding_formula, content);
+        }
+      }
+    }
+    ExpressionInstance::List(list) => {
+      if let Some(type_ref) = list.type_ref() {
+        content.type_refs.push(type_ref);
+      }
+      for element in list.elements() {
+        collect_expression_instance(element, content);
+      }
+    }
+    ExpressionInstance::LiteralExpression(literal_expression) => {
+      if let Some(type_ref) = literal_expression.type_ref() {
+        content.type_refs.push(type_ref);
+      }
+      content.literal_expressions.push(literal_expression);
+    }
+    ExpressionInstance::Relation(relation) => {
+      if let Some(type_ref) = relation.type_ref() {
+        content.type_refs.push(type_ref);
+      }
+      for column in relation.columns() {
+        collect_information_item(column, content);
+      }
+      for row in relation.rows() {
+        for element in row.elements() {
+          collect_expression_instance(element, content);
+        }
+      }
+    }
+  }
+}
@@ -36,6 +36,7 @@
 //! depicted in one or more Decision Requirements Diagrams (DRD).
 
 use crate::errors::*;
+use crate::xml_utils::escape;
 use dmntk_common::{DmntkError, HRef, Result, Uri};
 use dmntk_feel::{FeelType, Name};
 use std::convert::TryFrom;
@@ -46,6 +47,76 @@ pub const URI_FEEL: &str = "https://www.omg.org/spec/DMN/20191111/FEEL/";
 pub const URI_MODEL: &str = "https://www.omg.org/spec/DMN/20191111/MODEL/";
 pub const URI_UNINTERPRETED: &str = "http://www.omg.org/spec/DMN/uninterpreted/20140801";
 pub const URI_XML_SCHEMA: &str = "http://www.w3.org/2001/XMLSchema";
+pub const URI_MODEL_1_1: &str = "http://www.omg.org/spec/DMN/20151101/dmn.xsd";
+pub const URI_MODEL_1_2: &str = "http://www.omg.org/spec/DMN/20180521/MODEL/";
+pub const URI_MODEL_1_3: &str = URI_MODEL;
+pub const URI_MODEL_1_4: &str = "https://www.omg.org/spec/DMN/20211108/MODEL/";
+pub const URI_MODEL_1_5: &str = "https://www.omg.org/spec/DMN/20230324/MODEL/";
+pub const URI_FEEL_1_1: &str = "http://www.omg.org/spec/FEEL/20140401";
+pub const URI_FEEL_1_2: &str = "http://www.omg.org/spec/DMN/20180521/FEEL/";
+pub const URI_FEEL_1_3: &str = URI_FEEL;
+pub const URI_FEEL_1_4: &str = "https://www.omg.org/spec/DMN/20211108/FEEL/";
+pub const URI_FEEL_1_5: &str = "https://www.omg.org/spec/DMN/20230324/FEEL/";
+
+/// Versions of the DMN specification, recognized by the XML namespace of the model.
+/// Models without any namespace are treated as models in the most recent version.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DmnVersion {
+  V1_1,
+  V1_2,
+  V1_3,
+  V1_4,
+  #[default]
+  V1_5,
+}
+
+impl DmnVersion {
+  /// Returns the XML namespace of the model in this version.
+  pub fn model_uri(&self) -> &'static str {
+    match self {
+      DmnVersion::V1_1 => URI_MODEL_1_1,
+      DmnVersion::V1_2 => URI_MODEL_1_2,
+      DmnVersion::V1_3 => URI_MODEL_1_3,
+      DmnVersion::V1_4 => URI_MODEL_1_4,
+      DmnVersion::V1_5 => URI_MODEL_1_5,
+    }
+  }
+
+  /// Returns the URI of `FEEL` language in this version.
+  pub fn feel_uri(&self) -> &'static str {
+    match self {
+      DmnVersion::V1_1 => URI_FEEL_1_1,
+      DmnVersion::V1_2 => URI_FEEL_1_2,
+      DmnVersion::V1_3 => URI_FEEL_1_3,
+      DmnVersion::V1_4 => URI_FEEL_1_4,
+      DmnVersion::V1_5 => URI_FEEL_1_5,
+    }
+  }
+}
+
+impl fmt::Display for DmnVersion {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      DmnVersion::V1_1 => write!(f, "DMN 1.1"),
+      DmnVersion::V1_2 => write!(f, "DMN 1.2"),
+      DmnVersion::V1_3 => write!(f, "DMN 1.3"),
+      DmnVersion::V1_4 => write!(f, "DMN 1.4"),
+      DmnVersion::V1_5 => write!(f, "DMN 1.5"),
+    }
+  }
+}
+
+impl TryFrom<&str> for DmnVersion {
+  type Error = DmntkError;
+  /// Tries to recognize the DMN version from the XML namespace of the model.
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    let uri = value.trim().trim_end_matches('/');
+    [DmnVersion::V1_1, DmnVersion::V1_2, DmnVersion::V1_3, DmnVersion::V1_4, DmnVersion::V1_5]
+      .into_iter()
+      .find(|version| version.model_uri().trim_end_matches('/') == uri)
+      .ok_or_else(|| err_unsupported_dmn_namespace(value))
+  }
+}
 
 /// [DmnId] defines possible types of unique identifiers in model.
 /// Specification defines this identifier as optional, but this implementation
@@ -132,17 +203,76 @@ pub trait BusinessContextElement: NamedElement {
 /// The [ExtensionElement] contains element from other
 /// metamodels inside any [DmnElement].
 ///
-/// Not used, prepared for further development.
+/// Each child of the `extensionElements` node is kept as a self-contained XML fragment,
+/// with all namespaces used in the fragment declared in it.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct ExtensionElement;
+pub struct ExtensionElement {
+  /// Namespace of the extension element.
+  pub(crate) namespace: Option<String>,
+  /// Local name of the extension element.
+  pub(crate) name: String,
+  /// XML fragment containing the whole extension element.
+  pub(crate) xml: String,
+}
 
-/// The [ExtensionAttribute] element contains an [Element] or a reference
-/// to an [Element] from another metamodel. An [ExtensionAttribute] also has a name
-/// to define the role or purpose of the associated element.
-///
-/// Not used, prepared for further development.
+impl ExtensionElement {
+  /// Returns the optional namespace of the extension element.
+  pub fn namespace(&self) -> &Option<String> {
+    &self.namespace
+  }
+
+  /// Returns the local name of the extension element.
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  /// Returns the XML fragment containing the whole extension element.
+  pub fn xml(&self) -> &str {
+    &self.xml
+  }
+}
+
+/// The [ExtensionAttribute] element contains an attribute from other
+/// metamodel attached to any [DmnElement]. Only attributes
+/// having a namespace other than DMN namespace are kept as extension attributes.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct ExtensionAttribute;
+pub struct ExtensionAttribute {
+  /// Namespace of the extension attribute.
+  pub(crate) namespace: String,
+  /// Prefix bound to the namespace of the extension attribute.
+  pub(crate) prefix: String,
+  /// Local name of the extension attribute.
+  pub(crate) name: String,
+  /// Value of the extension attribute.
+  pub(crate) value: String,
+}
+
+impl ExtensionAttribute {
+  /// Returns the namespace of the extension attribute.
+  pub fn namespace(&self) -> &str {
+    &self.namespace
+  }
+
+  /// Returns the prefix bound to the namespace of the extension attribute.
+  pub fn prefix(&self) -> &str {
+    &self.prefix
+  }
+
+  /// Returns the local name of the extension attribute.
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  /// Returns the value of the extension attribute.
+  pub fn value(&self) -> &str {
+    &self.value
+  }
+
+  /// Returns the extension attribute as XML fragment, like `prefix:name="value"`.
+  pub fn xml(&self) -> String {
+    format!(r#"{}:{}="{}""#, self.prefix, self.name, escape(&self.value))
+  }
+}
 
 /// Enumeration of concrete instances of [BusinessContextElement].
 #[derive(Debug, Clone)]
@@ -234,6 +364,49 @@ pub enum DrgElement {
   KnowledgeSource(KnowledgeSource),
 }
 
+impl DrgElement {
+  /// Returns the identifier of this [DrgElement].
+  pub fn id(&self) -> &str {
+    match self {
+      DrgElement::Decision(inner) => inner.id(),
+      DrgElement::InputData(inner) => inner.id(),
+      DrgElement::BusinessKnowledgeModel(inner) => inner.id(),
+      DrgElement::DecisionService(inner) => inner.id(),
+      DrgElement::KnowledgeSource(inner) => inner.id(),
+    }
+  }
+  /// Returns the identifier of this [DrgElement], when it was explicitly specified in the model.
+  pub fn opt_id(&self) -> Option<&String> {
+    match self {
+      DrgElement::Decision(inner) => inner.opt_id(),
+      DrgElement::InputData(inner) => inner.opt_id(),
+      DrgElement::BusinessKnowledgeModel(inner) => inner.opt_id(),
+      DrgElement::DecisionService(inner) => inner.opt_id(),
+      DrgElement::KnowledgeSource(inner) => inner.opt_id(),
+    }
+  }
+  /// Returns the name of this [DrgElement].
+  pub fn name(&self) -> &str {
+    match self {
+      DrgElement::Decision(inner) => inner.name(),
+      DrgElement::InputData(inner) => inner.name(),
+      DrgElement::BusinessKnowledgeModel(inner) => inner.name(),
+      DrgElement::DecisionService(inner) => inner.name(),
+      DrgElement::KnowledgeSource(inner) => inner.name(),
+    }
+  }
+  /// Returns the kind of this [DrgElement], as used in messages.
+  pub fn kind(&self) -> &'static str {
+    match self {
+      DrgElement::Decision(_) => "decision",
+      DrgElement::InputData(_) => "input data",
+      DrgElement::BusinessKnowledgeModel(_) => "business knowledge model",
+      DrgElement::DecisionService(_) => "decision service",
+      DrgElement::KnowledgeSource(_) => "knowledge source",
+    }
+  }
+}
+
 /// Enumeration of specific requirements.
 pub enum Requirement {
   Information(InformationRequirement),
@@ -291,9 +464,16 @@ pub struct Definitions {
   pub(crate) imports: Vec<Import>,
   /// Optional diagram interchange information contained within this [Definitions].
   pub(crate) dmndi: Option<Dmndi>,
+  /// Version of the DMN specification, detected from the XML namespace of the parsed model.
+  pub(crate) dmn_version: DmnVersion,
 }
 
 impl Definitions {
+  /// Returns the version of the DMN specification this [Definitions] were parsed from.
+  pub fn dmn_version(&self) -> DmnVersion {
+    self.dmn_version
+  }
+
   /// Returns the reference to the namespace associated with this [Definitions].
   pub fn namespace(&self) -> &str {
     &self.namespace
@@ -654,20 +834,32 @@ impl Import {
 }
 
 /// Enumeration of concrete instances of abstract [Expression], which are:
+/// - [Conditional],
 /// - [Context],
 /// - [DecisionTable],
+/// - every ([Quantified]),
+/// - [Filter],
+/// - [For],
 /// - [FunctionDefinition],
 /// - [Invocation],
+/// - [List],
 /// - [LiteralExpression],
-/// - [Relation].
+/// - [Relation],
+/// - some ([Quantified]).
 #[derive(Debug, Clone, PartialEq)]
 pub enum ExpressionInstance {
+  Conditional(Box<Conditional>),
   Context(Box<Context>),
   DecisionTable(Box<DecisionTable>),
+  Every(Box<Quantified>),
+  Filter(Box<Filter>),
+  For(Box<For>),
   FunctionDefinition(Box<FunctionDefinition>),
   Invocation(Box<Invocation>),
+  List(Box<List>),
   LiteralExpression(Box<LiteralExpression>),
   Relation(Box<Relation>),
+  Some(Box<Quantified>),
 }
 
 /// A [Context] is composed of any number of model context entries, which are instances of [ContextEntry].
@@ -966,6 +1158,10 @@ impl AuthorityRequirement {
   pub fn required_input(&self) -> &Option<HRef> {
     &self.required_input
   }
+  /// Returns references to all elements required by this [AuthorityRequirement].
+  pub fn references(&self) -> impl Iterator<Item = &HRef> {
+    [&self.required_authority, &self.required_decision, &self.required_input].into_iter().flatten()
+  }
 }
 
 /// The class [KnowledgeSource] is used to model authoritative knowledge sources in a decision model.
@@ -1387,6 +1583,216 @@ impl Expression for List {
   }
 }
 
+/// A [ChildExpression] wraps an [Expression] that is a part of an enclosing
+/// boxed expression, like `if`, `then` and `else` parts of a [Conditional].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChildExpression {
+  /// Optional identifier of this [ChildExpression].
+  pub(crate) id: DmnId,
+  /// Optional type of this [ChildExpression], used only by typed child expressions,
+  /// like the `in` part of [For] and [Quantified] expressions.
+  pub(crate) type_ref: Option<String>,
+  /// The instance of [Expression] wrapped by this [ChildExpression].
+  pub(crate) value: ExpressionInstance,
+}
+
+impl ChildExpression {
+  /// Returns a reference to identifier of this [ChildExpression].
+  pub fn id(&self) -> &DmnId {
+    &self.id
+  }
+  /// Returns a reference to optional type of this [ChildExpression].
+  pub fn type_ref(&self) -> &Option<String> {
+    &self.type_ref
+  }
+  /// Returns a reference to wrapped [Expression].
+  pub fn value(&self) -> &ExpressionInstance {
+    &self.value
+  }
+}
+
+/// A [Conditional] is a boxed expression, that evaluates the `then` expression
+/// when the `if` expression is true, otherwise it evaluates the `else` expression.
+#[derive(Debug, Clone, PartialEq, DmnElement)]
+pub struct Conditional {
+  /// Namespace.
+  pub(crate) namespace: String,
+  /// Optional identifier of this this [Conditional].
+  pub(crate) id: DmnId,
+  /// Optional description of this [Conditional].
+  pub(crate) description: Option<String>,
+  /// Optional alternative short description of this [Conditional].
+  pub(crate) label: Option<String>,
+  /// Container to attach additional elements to any [Conditional].
+  pub(crate) extension_elements: Vec<ExtensionElement>,
+  /// Container to attach named extended attributes and model associations to any [Conditional].
+  pub(crate) extension_attributes: Vec<ExtensionAttribute>,
+  /// Optional base type of this [Conditional] identified by namespace-prefixed name.
+  pub(crate) type_ref: Option<String>,
+  /// Condition of this [Conditional].
+  pub(crate) if_expression: ChildExpression,
+  /// Expression evaluated when the condition is true.
+  pub(crate) then_expression: ChildExpression,
+  /// Expression evaluated when the condition is not true.
+  pub(crate) else_expression: ChildExpression,
+}
+
+impl Conditional {
+  /// Returns a reference to the condition.
+  pub fn if_expression(&self) -> &ChildExpression {
+    &self.if_expression
+  }
+  /// Returns a reference to the expression evaluated when the condition is true.
+  pub fn then_expression(&self) -> &ChildExpression {
+    &self.then_expression
+  }
+  /// Returns a reference to the expression evaluated when the condition is not true.
+  pub fn else_expression(&self) -> &ChildExpression {
+    &self.else_expression
+  }
+}
+
+impl Expression for Conditional {
+  fn type_ref(&self) -> &Option<String> {
+    &self.type_ref
+  }
+}
+
+/// A [Filter] is a boxed expression, that selects the elements
+/// of the `in` list for which the `match` expression is true.
+#[derive(Debug, Clone, PartialEq, DmnElement)]
+pub struct Filter {
+  /// Namespace.
+  pub(crate) namespace: String,
+  /// Optional identifier of this this [Filter].
+  pub(crate) id: DmnId,
+  /// Optional description of this [Filter].
+  pub(crate) description: Option<String>,
+  /// Optional alternative short description of this [Filter].
+  pub(crate) label: Option<String>,
+  /// Container to attach additional elements to any [Filter].
+  pub(crate) extension_elements: Vec<ExtensionElement>,
+  /// Container to attach named extended attributes and model associations to any [Filter].
+  pub(crate) extension_attributes: Vec<ExtensionAttribute>,
+  /// Optional base type of this [Filter] identified by namespace-prefixed name.
+  pub(crate) type_ref: Option<String>,
+  /// Filtered list.
+  pub(crate) in_expression: ChildExpression,
+  /// Filter condition evaluated for every element of the filtered list.
+  pub(crate) match_expression: ChildExpression,
+}
+
+impl Filter {
+  /// Returns a reference to the filtered list.
+  pub fn in_expression(&self) -> &ChildExpression {
+    &self.in_expression
+  }
+  /// Returns a reference to the filter condition.
+  pub fn match_expression(&self) -> &ChildExpression {
+    &self.match_expression
+  }
+}
+
+impl Expression for Filter {
+  fn type_ref(&self) -> &Option<String> {
+    &self.type_ref
+  }
+}
+
+/// A [For] is a boxed iterator expression, that evaluates the `return` expression
+/// for every element of the `in` list and returns the list of results.
+#[derive(Debug, Clone, PartialEq, DmnElement)]
+pub struct For {
+  /// Namespace.
+  pub(crate) namespace: String,
+  /// Optional identifier of this this [For].
+  pub(crate) id: DmnId,
+  /// Optional description of this [For].
+  pub(crate) description: Option<String>,
+  /// Optional alternative short description of this [For].
+  pub(crate) label: Option<String>,
+  /// Container to attach additional elements to any [For].
+  pub(crate) extension_elements: Vec<ExtensionElement>,
+  /// Container to attach named extended attributes and model associations to any [For].
+  pub(crate) extension_attributes: Vec<ExtensionAttribute>,
+  /// Optional base type of this [For] identified by namespace-prefixed name.
+  pub(crate) type_ref: Option<String>,
+  /// Name of the iterator variable.
+  pub(crate) iterator_variable: String,
+  /// Iterated list.
+  pub(crate) in_expression: ChildExpression,
+  /// Expression evaluated for every element of the iterated list.
+  pub(crate) return_expression: ChildExpression,
+}
+
+impl For {
+  /// Returns the name of the iterator variable.
+  pub fn iterator_variable(&self) -> &str {
+    &self.iterator_variable
+  }
+  /// Returns a reference to the iterated list.
+  pub fn in_expression(&self) -> &ChildExpression {
+    &self.in_expression
+  }
+  /// Returns a reference to the expression evaluated for every element of the iterated list.
+  pub fn return_expression(&self) -> &ChildExpression {
+    &self.return_expression
+  }
+}
+
+impl Expression for For {
+  fn type_ref(&self) -> &Option<String> {
+    &self.type_ref
+  }
+}
+
+/// A [Quantified] is a boxed iterator expression, that checks if the `satisfies`
+/// expression is true for every (`every`) or any (`some`) element of the `in` list.
+#[derive(Debug, Clone, PartialEq, DmnElement)]
+pub struct Quantified {
+  /// Namespace.
+  pub(crate) namespace: String,
+  /// Optional identifier of this this [Quantified].
+  pub(crate) id: DmnId,
+  /// Optional description of this [Quantified].
+  pub(crate) description: Option<String>,
+  /// Optional alternative short description of this [Quantified].
+  pub(crate) label: Option<String>,
+  /// Container to attach additional elements to any [Quantified].
+  pub(crate) extension_elements: Vec<ExtensionElement>,
+  /// Container to attach named extended attributes and model associations to any [Quantified].
+  pub(crate) extension_attributes: Vec<ExtensionAttribute>,
+  /// Optional base type of this [Quantified] identified by namespace-prefixed name.
+  pub(crate) type_ref: Option<String>,
+  /// Name of the iterator variable.
+  pub(crate) iterator_variable: String,
+  /// Iterated list.
+  pub(crate) in_expression: ChildExpression,
+  /// Condition evaluated for every element of the iterated list.
+  pub(crate) satisfies_expression: ChildExpression,
+}
+
+impl Quantified {
+  /// Returns the name of the iterator variable.
+  pub fn iterator_variable(&self) -> &str {
+    &self.iterator_variable
+  }
+  /// Returns a reference to the iterated list.
+  pub fn in_expression(&self) -> &ChildExpression {
+    &self.in_expression
+  }
+  /// Returns a reference to the condition evaluated for every element of the iterated list.
+  pub fn satisfies_expression(&self) -> &ChildExpression {
+    &self.satisfies_expression
+  }
+}
+
+impl Expression for Quantified {
+  fn type_ref(&self) -> &Option<String> {
+    &self.type_ref
+  }
+}
+
 /// Decision table.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DecisionTable {
@@ -46,6 +46,7 @@ const NODE_AUTHORITY_REQUIREMENT: &str = "authorityRequirement";
 const NODE_BINDING: &str = "binding";
 const NODE_BUSINESS_KNOWLEDGE_MODEL: &str = "businessKnowledgeModel";
 const NODE_COLUMN: &str = "column";
+const NODE_CONDITIONAL: &str = "conditional";
 const NODE_CONTEXT: &str = "context";
 const NODE_CONTEXT_ENTRY: &str = "contextEntry";
 const NODE_DEFAULT_OUTPUT_ENTRY: &str = "defaultOutputEntry";
@@ -72,13 +73,20 @@ const NODE_DMNDI_LABEL_VERTICAL_ALIGNMENT: &str = "labelVerticalAlignment";
 const NODE_DMNDI_LABEL: &str = "DMNLabel";
 const NODE_DMNDI_DECISION_SERVICE_DIVIDER_LINE: &str = "DMNDecisionServiceDividerLine";
 const NODE_DESCRIPTION: &str = "description";
+const NODE_ELSE: &str = "else";
 const NODE_ENCAPSULATED_DECISION: &str = "encapsulatedDecision";
 const NODE_ENCAPSULATED_LOGIC: &str = "encapsulatedLogic";
+const NODE_EVERY: &str = "every";
+const NODE_EXTENSION_ELEMENTS: &str = "extensionElements";
+const NODE_FILTER: &str = "filter";
+const NODE_FOR: &str = "for";
 const NODE_FUNCTION_DEFINITION: &str = "functionDefinition";
 const NODE_FORMAL_PARAMETER: &str = "formalParameter";
 const NODE_FUNCTION_ITEM: &str = "functionItem";
+const NODE_IF: &str = "if";
 const NODE_IMPACTING_DECISION: &str = "impactingDecision";
 const NODE_IMPORT: &str = "import";
+const NODE_IN: &str = "in";
 const NODE_INFORMATION_REQUIREMENT: &str = "informationRequirement";
 const NODE_INPUT_DATA: &str = "inputData";
 const NODE_INPUT: &str = "input";
@@ -91,7 +99,9 @@ const NODE_ITEM_DEFINITION: &str = "itemDefinition";
 const NODE_ITEM_COMPONENT: &str = "itemComponent";
 const NODE_KNOWLEDGE_REQUIREMENT: &str = "knowledgeRequirement";
 const NODE_KNOWLEDGE_SOURCE: &str = "knowledgeSource";
+const NODE_LIST: &str = "list";
 const NODE_LITERAL_EXPRESSION: &str = "literalExpression";
+const NODE_MATCH: &str = "match";
 const NODE_OUTPUT: &str = "output";
 const NODE_OUTPUT_DECISION: &str = "outputDecision";
 const NODE_OUTPUT_ENTRY: &str = "outputEntry";
@@ -106,9 +116,13 @@ const NODE_REQUIRED_AUTHORITY: &str = "requiredAuthority";
 const NODE_REQUIRED_DECISION: &str = "requiredDecision";
 const NODE_REQUIRED_KNOWLEDGE: &str = "requiredKnowledge";
 const NODE_REQUIRED_INPUT: &str = "requiredInput";
+const NODE_RETURN: &str = "return";
 const NODE_ROW: &str = "row";
 const NODE_RULE: &str = "rule";
+const NODE_SATISFIES: &str = "satisfies";
+const NODE_SOME: &str = "some";
 const NODE_TEXT: &str = "text";
+const NODE_THEN: &str = "then";
 const NODE_TYPE_REF: &str = "typeRef";
 const NODE_VARIABLE: &str = "variable";
 
@@ -134,6 +148,7 @@ const ATTR_ID: &str = "id";
 const ATTR_IMPORT_TYPE: &str = "importType";
 const ATTR_IS_COLLAPSED: &str = "isCollapsed";
 const ATTR_IS_COLLECTION: &str = "isCollection";
+const ATTR_ITERATOR_VARIABLE: &str = "iteratorVariable";
 const ATTR_KIND: &str = "kind";
 const ATTR_LABEL: &str = "label";
 const ATTR_LABEL_TEXT: &str = "Text";
@@ -145,6 +160,8 @@ const ATTR_OUTPUT_TYPE_REF: &str = "outputTypeRef";
 const ATTR_RED: &str = "red";
 const ATTR_RESOLUTION: &str = "resolution";
 const ATTR_SHARED_STYLE: &str = "sharedStyle";
+const ATTR_SOURCE_ELEMENT: &str = "sourceElement";
+const ATTR_TARGET_ELEMENT: &str = "targetElement";
 const ATTR_TYPE_LANGUAGE: &str = "typeLanguage";
 const ATTR_TYPE_REF: &str = "typeRef";
 const ATTR_URI: &str = "URI";
@@ -159,9 +176,13 @@ pub fn parse(xml: &str) -> Result<Definitions> {
     Ok(document) => {
       let definitions_node = document.root_element();
       if definitions_node.tag_name().name() != NODE_DEFINITIONS {
-        return Err(err_xml_unexpected_node(NODE_DEFINITIONS, definitions_node.tag_name().name()));
+        return Err(err_xml_unexpected_node(NODE_DEFINITIONS, &node_name_pos(&definitions_node)));
       }
-      let mut model_parser = ModelParser::new();
+      let dmn_version = match definitions_node.tag_name().namespace() {
+        Some(namespace) => DmnVersion::try_from(namespace).map_err(|_| err_unsupported_dmn_namespace_in_node(namespace, &node_name_pos(&definitions_node)))?,
+        None => DmnVersion::default(),
+      };
+      let mut model_parser = ModelParser::new(dmn_version);
       model_parser.parse_definitions(&definitions_node)
     }
     Err(reason) => Err(err_xml_parsing_model_failed(&reason.to_string())),
@@ -172,12 +193,17 @@ pub fn parse(xml: &str) -> Result<Definitions> {
 pub struct ModelParser {
   /// Namespace for parsed definitions.
   namespace: String,
+  /// Version of the DMN specification of parsed definitions.
+  dmn_version: DmnVersion,
 }
 
 impl ModelParser {
-  /// Creates new model parser.
-  fn new() -> Self {
-    Self { namespace: "".to_string() }
+  /// Creates new model parser for definitions in specified DMN version.
+  fn new(dmn_version: DmnVersion) -> Self {
+    Self {
+      namespace: "".to_string(),
+      dmn_version,
+    }
   }
 
   /// Parses model [Definitions].
@@ -201,6 +227,7 @@ impl ModelParser {
       business_context_elements: self.parse_business_context_elements(node)?,
       imports: self.parse_imports(node)?,
       dmndi: self.parse_dmndi(node)?,
+      dmn_version: self.dmn_version,
     };
     Ok(definitions)
   }
@@ -225,7 +252,7 @@ impl ModelParser {
       label: optional_attribute(node, ATTR_LABEL),
       extension_elements: self.parse_extension_elements(node),
       extension_attributes: self.parse_extension_attributes(node),
-      type_ref: optional_child_required_content(node, NODE_TYPE_REF)?,
+      type_ref: optional_child_required_content(node, NODE_TYPE_REF)?.map(|type_ref| self.type_ref_name(node, type_ref)),
       type_language: optional_attribute(node, ATTR_TYPE_LANGUAGE),
       feel_type: None,
       allowed_values: self.parse_unary_tests(node, NODE_ALLOWED_VALUES)?,
@@ -239,7 +266,7 @@ impl ModelParser {
   fn parse_function_item(&self, node: &Node) -> Result<Option<FunctionItem>> {
     if let Some(ref n) = node.children().find(|n| n.tag_name().name() == NODE_FUNCTION_ITEM) {
       Ok(Some(FunctionItem {
-        output_type_ref: optional_attribute(n, ATTR_OUTPUT_TYPE_REF),
+        output_type_ref: optional_attribute(n, ATTR_OUTPUT_TYPE_REF).map(|type_ref| self.type_ref_name(n, type_ref)),
         parameters: self.parse_information_items_from_child(n, NODE_PARAMETERS)?,
       }))
     } else {
@@ -387,9 +414,7 @@ impl ModelParser {
   fn required_hrefs_in_child_nodes(&self, node: &Node, child_name: &str) -> Result<Vec<HRef>> {
     let mut hrefs = vec![];
     for ref child_node in node.children().filter(|n| n.tag_name().name() == child_name) {
-      let text = required_attribute(child_node, ATTR_HREF)?;
-      let href = HRef::try_from(text.as_str())?;
-      hrefs.push(href);
+      hrefs.push(required_href(child_node)?);
     }
     Ok(hrefs)
   }
@@ -403,15 +428,6 @@ impl ModelParser {
     }
   }
 
-  ///
-  fn parse_optional_function_definition(&self, node: &Node) -> Result<Option<FunctionDefinition>> {
-    if let Some(child_node) = node.children().find(|n| n.tag_name().name() == NODE_FUNCTION_DEFINITION) {
-      Ok(Some(self.parse_function_definition(&child_node)?))
-    } else {
-      Ok(None)
-    }
-  }
-
   ///
   fn parse_function_definition(&self, node: &Node) -> Result<FunctionDefinition> {
     Ok(FunctionDefinition {
@@ -421,7 +437,7 @@ impl ModelParser {
       label: optional_attribute(node, ATTR_LABEL),
       extension_elements: self.parse_extension_elements(node),
       extension_attributes: self.parse_extension_attributes(node),
-      type_ref: optional_attribute(node, ATTR_TYPE_REF),
+      type_ref: self.optional_type_ref(node),
       formal_parameters: self.parse_information_items_from_child(node, NODE_FORMAL_PARAMETER)?,
       body: self.parse_optional_expression_instance(node)?,
       kind: self.parse_function_kind(node)?,
@@ -435,7 +451,7 @@ impl ModelParser {
         "FEEL" => Ok(FunctionKind::Feel),
         "Java" => Ok(FunctionKind::Java),
         "PMML" => Ok(FunctionKind::Pmml),
-        other => Err(err_invalid_function_kind(other)),
+        other => Err(err_invalid_function_kind(other, &node_name_pos(node))),
       }
     } else {
       Ok(FunctionKind::Feel)
@@ -537,7 +553,7 @@ impl ModelParser {
       extension_attributes: self.parse_extension_attributes(node),
       name: required_name(node)?,
       feel_name: required_feel_name(node)?,
-      type_ref: optional_attribute(node, ATTR_TYPE_REF).unwrap_or(FEEL_TYPE_NAME_ANY.to_string()),
+      type_ref: self.optional_type_ref(node).unwrap_or(FEEL_TYPE_NAME_ANY.to_string()),
       feel_type: None,
     })
   }
@@ -615,46 +631,54 @@ impl ModelParser {
   }
 
   fn parse_required_expression_instance(&self, node: &Node) -> Result<ExpressionInstance> {
-    self.parse_optional_expression_instance(node)?.ok_or_else(err_required_expression_instance_is_missing)
+    self
+      .parse_optional_expression_instance(node)?
+      .ok_or_else(|| err_required_expression_instance_is_missing(&node_name_pos(node)))
   }
 
+  /// Searches for the first child node of the specified `node` that is an expression instance.
+  /// When such node is found, then parses the expression instance and returns it; otherwise returns [None].
   fn parse_optional_expression_instance(&self, node: &Node) -> Result<Option<ExpressionInstance>> {
-    if let Some(context) = self.parse_optional_context(node)? {
-      return Ok(Some(ExpressionInstance::Context(Box::new(context))));
-    }
-    if let Some(decision_table) = self.parse_decision_table(node)? {
-      return Ok(Some(ExpressionInstance::DecisionTable(Box::new(decision_table))));
-    }
-    if let Some(function_definition) = self.parse_optional_function_definition(node)? {
-      return Ok(Some(ExpressionInstance::FunctionDefinition(Box::new(function_definition))));
-    }
-    if let Some(invocation) = self.parse_optional_invocation(node)? {
-      return Ok(Some(ExpressionInstance::Invocation(Box::new(invocation))));
-    }
-    if let Some(literal_expression) = self.parse_optional_literal_expression(node) {
-      return Ok(Some(ExpressionInstance::LiteralExpression(Box::new(literal_expression))));
-    }
-    if let Some(relation) = self.parse_optional_relation(node)? {
-      return Ok(Some(ExpressionInstance::Relation(Box::new(relation))));
+    for ref child_node in node.children().filter(|n| n.is_element()) {
+      if let Some(expression_instance) = self.parse_expression_instance(child_node)? {
+        return Ok(Some(expression_instance));
+      }
     }
     Ok(None)
   }
 
-  fn parse_decision_table(&self, node: &Node) -> Result<Option<DecisionTable>> {
-    if let Some(ref child_node) = node.children().find(|n| n.tag_name().name() == NODE_DECISION_TABLE) {
-      return Ok(Some(DecisionTable {
-        information_item_name: None,
-        input_clauses: self.parse_decision_table_inputs(child_node)?,
-        output_clauses: self.parse_decision_table_outputs(child_node)?,
-        annotations: vec![], // TODO implement parsing annotations
-        rules: self.parse_decision_table_rules(child_node)?,
-        hit_policy: self.parse_hit_policy_attribute(child_node)?,
-        aggregation: None,
-        preferred_orientation: self.parse_preferred_orientation_attribute(child_node)?,
-        output_label: optional_attribute(child_node, ATTR_OUTPUT_LABEL),
-      }));
-    }
-    Ok(None)
+  /// Parses [ExpressionInstance] directly from the specified node.
+  /// When the node does not represent any expression instance, then [None] is returned.
+  fn parse_expression_instance(&self, node: &Node) -> Result<Option<ExpressionInstance>> {
+    Ok(Some(match node.tag_name().name() {
+      NODE_CONDITIONAL => ExpressionInstance::Conditional(Box::new(self.parse_conditional(node)?)),
+      NODE_CONTEXT => ExpressionInstance::Context(Box::new(self.parse_context(node)?)),
+      NODE_DECISION_TABLE => ExpressionInstance::DecisionTable(Box::new(self.parse_decision_table(node)?)),
+      NODE_EVERY => ExpressionInstance::Every(Box::new(self.parse_quantified(node)?)),
+      NODE_FILTER => ExpressionInstance::Filter(Box::new(self.parse_filter(node)?)),
+      NODE_FOR => ExpressionInstance::For(Box::new(self.parse_for(node)?)),
+      NODE_FUNCTION_DEFINITION => ExpressionInstance::FunctionDefinition(Box::new(self.parse_function_definition(node)?)),
+      NODE_INVOCATION => ExpressionInstance::Invocation(Box::new(self.parse_invocation(node)?)),
+      NODE_LIST => ExpressionInstance::List(Box::new(self.parse_list(node)?)),
+      NODE_LITERAL_EXPRESSION => ExpressionInstance::LiteralExpression(Box::new(self.parse_literal_expression(node))),
+      NODE_RELATION => ExpressionInstance::Relation(Box::new(self.parse_relation(node)?)),
+      NODE_SOME => ExpressionInstance::Some(Box::new(self.parse_quantified(node)?)),
+      _ => return Ok(None),
+    }))
+  }
+
+  fn parse_decision_table(&self, node: &Node) -> Result<DecisionTable> {
+    Ok(DecisionTable {
+      information_item_name: None,
+      input_clauses: self.parse_decision_table_inputs(node)?,
+      output_clauses: self.parse_decision_table_outputs(node)?,
+      annotations: vec![], // TODO implement parsing annotations
+      rules: self.parse_decision_table_rules(node)?,
+      hit_policy: self.parse_hit_policy_attribute(node)?,
+      aggregation: None,
+      preferred_orientation: self.parse_preferred_orientation_attribute(node)?,
+      output_label: optional_attribute(node, ATTR_OUTPUT_LABEL),
+    })
   }
 
   fn parse_decision_table_inputs(&self, node: &Node) -> Result<Vec<InputClause>> {
@@ -669,7 +693,7 @@ impl ModelParser {
     let input_expression = if let Ok(ref child_node) = required_child(node, NODE_INPUT_EXPRESSION) {
       required_child_required_content(child_node, NODE_TEXT)?
     } else {
-      return Err(err_required_input_expression_is_missing());
+      return Err(err_required_input_expression_is_missing(&node_name_pos(node)));
     };
     let input_values = if let Some(ref child_node) = optional_child(node, NODE_INPUT_VALUES) {
       optional_child_required_content(child_node, NODE_TEXT)?
@@ -702,7 +726,7 @@ impl ModelParser {
       None
     };
     Ok(OutputClause {
-      type_ref: optional_attribute(node, ATTR_TYPE_REF),
+      type_ref: self.optional_type_ref(node),
       name: optional_attribute(node, ATTR_NAME),
       allowed_output_values: output_values,
       default_output_entry,
@@ -753,13 +777,10 @@ impl ModelParser {
     })
   }
 
-  fn parse_optional_context(&self, node: &Node) -> Result<Option<Context>> {
-    if let Some(ref child_node) = node.children().find(|n| n.tag_name().name() == NODE_CONTEXT) {
-      return Ok(Some(Context {
-        context_entries: self.parse_context_entries(child_node)?,
-      }));
-    }
-    Ok(None)
+  fn parse_context(&self, node: &Node) -> Result<Context> {
+    Ok(Context {
+      context_entries: self.parse_context_entries(node)?,
+    })
   }
 
   fn parse_context_entries(&self, node: &Node) -> Result<Vec<ContextEntry>> {
@@ -773,14 +794,11 @@ impl ModelParser {
     Ok(context_entries)
   }
 
-  fn parse_optional_invocation(&self, node: &Node) -> Result<Option<Invocation>> {
-    if let Some(ref child_node) = node.children().find(|n| n.tag_name().name() == NODE_INVOCATION) {
-      return Ok(Some(Invocation {
-        called_function: self.parse_required_expression_instance(child_node)?,
-        bindings: self.parse_bindings(child_node)?,
-      }));
-    }
-    Ok(None)
+  fn parse_invocation(&self, node: &Node) -> Result<Invocation> {
+    Ok(Invocation {
+      called_function: self.parse_required_expression_instance(node)?,
+      bindings: self.parse_bindings(node)?,
+    })
   }
 
   fn parse_bindings(&self, node: &Node) -> Result<Vec<Binding>> {
@@ -794,15 +812,6 @@ impl ModelParser {
     Ok(bindings)
   }
 
-  /// Searches for the first node named 'literalExpression' among children of the specified `node`.
-  /// When such node is found, then parses literal expression and returns it; otherwise returns [None].
-  fn parse_optional_literal_expression(&self, node: &Node) -> Option<LiteralExpression> {
-    if let Some(ref child_node) = node.children().find(|n| n.tag_name().name() == NODE_LITERAL_EXPRESSION) {
-      return Some(self.parse_literal_expression(child_node));
-    }
-    None
-  }
-
   /// Parses [LiteralExpression] directly from the specified node.
   /// The `literal_expression_node` must be a node named `literalExpression`.
   fn parse_literal_expression(&self, node: &Node) -> LiteralExpression {
@@ -813,67 +822,195 @@ impl ModelParser {
       label: optional_attribute(node, ATTR_LABEL),
       extension_elements: self.parse_extension_elements(node),
       extension_attributes: self.parse_extension_attributes(node),
-      type_ref: optional_attribute(node, ATTR_TYPE_REF),
+      type_ref: self.optional_type_ref(node),
       text: optional_child_optional_content(node, NODE_TEXT),
       expression_language: optional_attribute(node, ATTR_EXPRESSION_LANGUAGE),
       imported_values: None,
     }
   }
 
-  fn parse_optional_relation(&self, node: &Node) -> Result<Option<Relation>> {
-    if let Some(ref relation_node) = node.children().find(|n| n.tag_name().name() == NODE_RELATION) {
-      let mut columns = vec![];
-      for ref column_node in relation_node.children().filter(|n| n.tag_name().name() == NODE_COLUMN) {
-        columns.push(self.parse_information_item(column_node)?);
+  fn parse_relation(&self, node: &Node) -> Result<Relation> {
+    let mut columns = vec![];
+    for ref column_node in node.children().filter(|n| n.tag_name().name() == NODE_COLUMN) {
+      columns.push(self.parse_information_item(column_node)?);
+    }
+    let mut rows = vec![];
+    for ref row_node in node.children().filter(|n| n.tag_name().name() == NODE_ROW) {
+      let row = self.parse_list(row_node)?;
+      if row.elements.len() != columns.len() {
+        return Err(err_number_of_elements_in_row_differs_from_number_of_columns(&node_name_pos(row_node)));
       }
-      let mut rows = vec![];
-      for ref row_node in relation_node.children().filter(|n| n.tag_name().name() == NODE_ROW) {
-        let mut elements = vec![];
-        for ref expression_instance_node in row_node.children() {
-          if expression_instance_node.tag_name().name() == NODE_LITERAL_EXPRESSION {
-            let literal_expression = self.parse_literal_expression(expression_instance_node);
-            elements.push(ExpressionInstance::LiteralExpression(Box::new(literal_expression)));
-          }
-        }
-        if elements.len() != columns.len() {
-          return Err(err_number_of_elements_in_row_differs_from_number_of_columns());
-        }
-        rows.push(List {
-          namespace: self.namespace.clone(),
-          id: optional_id(row_node),
-          description: optional_child_optional_content(row_node, NODE_DESCRIPTION),
-          label: optional_attribute(row_node, ATTR_LABEL),
-          extension_elements: self.parse_extension_elements(row_node),
-          extension_attributes: self.parse_extension_attributes(row_node),
-          type_ref: optional_attribute(row_node, ATTR_TYPE_REF),
-          elements,
-        });
+      rows.push(row);
+    }
+    Ok(Relation {
+      namespace: self.namespace.clone(),
+      id: optional_id(node),
+      description: optional_child_optional_content(node, NODE_DESCRIPTION),
+      label: optional_attribute(node, ATTR_LABEL),
+      extension_elements: self.parse_extension_elements(node),
+      extension_attributes: self.parse_extension_attributes(node),
+      type_ref: self.optional_type_ref(node),
+      rows,
+      columns,
+    })
+  }
+
+  /// Parses [List] directly from the specified node.
+  /// Every child node being an expression instance becomes an element of the list.
+  fn parse_list(&self, node: &Node) -> Result<List> {
+    let mut elements = vec![];
+    for ref child_node in node.children().filter(|n| n.is_element()) {
+      if let Some(expression_instance) = self.parse_expression_instance(child_node)? {
+        elements.push(expression_instance);
       }
-      return Ok(Some(Relation {
-        namespace: self.namespace.clone(),
-        id: optional_id(relation_node),
-        description: optional_child_optional_content(relation_node, NODE_DESCRIPTION),
-        label: optional_attribute(relation_node, ATTR_LABEL),
-        extension_elements: self.parse_extension_elements(relation_node),
-        extension_attributes: self.parse_extension_attributes(relation_node),
-        type_ref: optional_attribute(relation_node, ATTR_TYPE_REF),
-        rows,
-        columns,
-      }));
     }
-    Ok(None)
+    Ok(List {
+      namespace: self.namespace.clone(),
+      id: optional_id(node),
+      description: optional_child_optional_content(node, NODE_DESCRIPTION),
+      label: optional_attribute(node, ATTR_LABEL),
+      extension_elements: self.parse_extension_elements(node),
+      extension_attributes: self.parse_extension_attributes(node),
+      type_ref: self.optional_type_ref(node),
+      elements,
+    })
+  }
+
+  fn parse_conditional(&self, node: &Node) -> Result<Conditional> {
+    self.check_dmn_version(node, DmnVersion::V1_4)?;
+    Ok(Conditional {
+      namespace: self.namespace.clone(),
+      id: optional_id(node),
+      description: optional_child_optional_content(node, NODE_DESCRIPTION),
+      label: optional_attribute(node, ATTR_LABEL),
+      extension_elements: self.parse_extension_elements(node),
+      extension_attributes: self.parse_extension_attributes(node),
+      type_ref: self.optional_type_ref(node),
+      if_expression: self.parse_child_expression(node, NODE_IF)?,
+      then_expression: self.parse_child_expression(node, NODE_THEN)?,
+      else_expression: self.parse_child_expression(node, NODE_ELSE)?,
+    })
+  }
+
+  fn parse_filter(&self, node: &Node) -> Result<Filter> {
+    self.check_dmn_version(node, DmnVersion::V1_4)?;
+    Ok(Filter {
+      namespace: self.namespace.clone(),
+      id: optional_id(node),
+      description: optional_child_optional_content(node, NODE_DESCRIPTION),
+      label: optional_attribute(node, ATTR_LABEL),
+      extension_elements: self.parse_extension_elements(node),
+      extension_attributes: self.parse_extension_attributes(node),
+      type_ref: self.optional_type_ref(node),
+      in_expression: self.parse_child_expression(node, NODE_IN)?,
+      match_expression: self.parse_child_expression(node, NODE_MATCH)?,
+    })
+  }
+
+  fn parse_for(&self, node: &Node) -> Result<For> {
+    self.check_dmn_version(node, DmnVersion::V1_4)?;
+    Ok(For {
+      namespace: self.namespace.clone(),
+      id: optional_id(node),
+      description: optional_child_optional_content(node, NODE_DESCRIPTION),
+      label: optional_attribute(node, ATTR_LABEL),
+      extension_elements: self.parse_extension_elements(node),
+      extension_attributes: self.parse_extension_attributes(node),
+      type_ref: self.optional_type_ref(node),
+      iterator_variable: required_attribute(node, ATTR_ITERATOR_VARIABLE)?,
+      in_expression: self.parse_child_expression(node, NODE_IN)?,
+      return_expression: self.parse_child_expression(node, NODE_RETURN)?,
+    })
+  }
+
+  /// Parses quantified expression, the specified node must be named `every` or `some`.
+  fn parse_quantified(&self, node: &Node) -> Result<Quantified> {
+    self.check_dmn_version(node, DmnVersion::V1_4)?;
+    Ok(Quantified {
+      namespace: self.namespace.clone(),
+      id: optional_id(node),
+      description: optional_child_optional_content(node, NODE_DESCRIPTION),
+      label: optional_attribute(node, ATTR_LABEL),
+      extension_elements: self.parse_extension_elements(node),
+      extension_attributes: self.parse_extension_attributes(node),
+      type_ref: self.optional_type_ref(node),
+      iterator_variable: required_attribute(node, ATTR_ITERATOR_VARIABLE)?,
+      in_expression: self.parse_child_expression(node, NODE_IN)?,
+      satisfies_expression: self.parse_child_expression(node, NODE_SATISFIES)?,
+    })
+  }
+
+  /// Parses the required child expression, like `if`, `in` or `return`, from the specified node.
+  fn parse_child_expression(&self, node: &Node, child_name: &str) -> Result<ChildExpression> {
+    let child_node = optional_child(node, child_name).ok_or_else(|| err_xml_expected_mandatory_child_node(&node_name_pos(node), child_name))?;
+    Ok(ChildExpression {
+      id: optional_id(&child_node),
+      type_ref: self.optional_type_ref(&child_node),
+      value: self.parse_required_expression_instance(&child_node)?,
+    })
+  }
+
+  /// Returns an error when the specified node is not available in the DMN version of parsed definitions.
+  fn check_dmn_version(&self, node: &Node, required_version: DmnVersion) -> Result<()> {
+    if self.dmn_version < required_version {
+      return Err(err_unsupported_in_dmn_version(
+        &node_name_pos(node),
+        &self.dmn_version.to_string(),
+        &required_version.to_string(),
+      ));
+    }
+    Ok(())
   }
 
-  /// Parses extension elements.
-  fn parse_extension_elements(&self, _node: &Node) -> Vec<ExtensionElement> {
-    // Currently ignored. Ready for future development when needed.
-    vec![]
+  /// Returns the optional type reference defined in `typeRef` attribute of the specified node.
+  fn optional_type_ref(&self, node: &Node) -> Option<String> {
+    optional_attribute(node, ATTR_TYPE_REF).map(|type_ref| self.type_ref_name(node, type_ref))
   }
 
-  /// Parses extension attributes.
-  fn parse_extension_attributes(&self, _node: &Node) -> Vec<ExtensionAttribute> {
-    // Currently ignored. Ready for future development when needed.
-    vec![]
+  /// Returns the name of the referenced type.
+  /// In DMN 1.1 type references are qualified names, the prefixes bound
+  /// to `FEEL` namespace or to the namespace of parsed definitions are removed.
+  fn type_ref_name(&self, node: &Node, type_ref: String) -> String {
+    if self.dmn_version == DmnVersion::V1_1 {
+      if let Some((prefix, local_name)) = type_ref.split_once(':') {
+        if node.lookup_namespace_uri(Some(prefix)).is_some_and(|uri| uri == URI_FEEL_1_1 || uri == self.namespace) {
+          return local_name.to_string();
+        }
+      }
+    }
+    type_ref
+  }
+
+  /// Parses extension elements, each child of `extensionElements` node becomes a separate XML fragment.
+  fn parse_extension_elements(&self, node: &Node) -> Vec<ExtensionElement> {
+    let mut extension_elements = vec![];
+    if let Some(child_node) = optional_child(node, NODE_EXTENSION_ELEMENTS) {
+      for ref extension_node in child_node.children().filter(|n| n.is_element()) {
+        extension_elements.push(ExtensionElement {
+          namespace: extension_node.tag_name().namespace().map(|namespace| namespace.to_string()),
+          name: extension_node.tag_name().name().to_string(),
+          xml: xml_fragment(extension_node),
+        });
+      }
+    }
+    extension_elements
+  }
+
+  /// Parses extension attributes, these are the attributes having namespace other than DMN namespace.
+  fn parse_extension_attributes(&self, node: &Node) -> Vec<ExtensionAttribute> {
+    let model_uri = self.dmn_version.model_uri().trim_end_matches('/');
+    let mut extension_attributes = vec![];
+    for attribute in node.attributes() {
+      if let Some(namespace) = attribute.namespace().filter(|namespace| namespace.trim_end_matches('/') != model_uri) {
+        extension_attributes.push(ExtensionAttribute {
+          namespace: namespace.to_string(),
+          prefix: node.lookup_prefix(namespace).unwrap_or_default().to_string(),
+          name: attribute.name().to_string(),
+          value: attribute.value().to_string(),
+        });
+      }
+    }
+    extension_attributes
   }
 
   /// Returns boolean value of the specified attribute.
@@ -896,7 +1033,7 @@ impl ModelParser {
         "RULE ORDER" => Ok(HitPolicy::RuleOrder),
         "OUTPUT ORDER" => Ok(HitPolicy::OutputOrder),
         "COLLECT" => Ok(HitPolicy::Collect(self.parse_aggregation_attribute(node)?)),
-        other => Err(err_invalid_hit_policy(other)),
+        other => Err(err_invalid_hit_policy(other, &node_name_pos(node))),
       }
     } else {
       Ok(HitPolicy::Unique)
@@ -911,7 +1048,7 @@ impl ModelParser {
         "SUM" => Ok(BuiltinAggregator::Sum),
         "MIN" => Ok(BuiltinAggregator::Min),
         "MAX" => Ok(BuiltinAggregator::Max),
-        other => Err(err_invalid_aggregation(other)),
+        other => Err(err_invalid_aggregation(other, &node_name_pos(node))),
       }
     } else {
       Ok(BuiltinAggregator::List)
@@ -921,7 +1058,7 @@ impl ModelParser {
   /// Returns the value of the preferred decision table orientation attribute.
   fn parse_preferred_orientation_attribute(&self, node: &Node) -> Result<DecisionTableOrientation> {
     if let Some(attr_value) = node.attribute(ATTR_PREFERRED_ORIENTATION) {
-      DecisionTableOrientation::try_from(attr_value)
+      DecisionTableOrientation::try_from(attr_value).map_err(|reason| err_invalid_attribute_value(&node_name_pos(node), ATTR_PREFERRED_ORIENTATION, &reason.to_string()))
     } else {
       Ok(DecisionTableOrientation::RuleAsRow)
     }
@@ -930,6 +1067,7 @@ impl ModelParser {
   /// Parse DMNDI part of the diagram definitions.
   fn parse_dmndi(&self, node: &Node) -> Result<Option<Dmndi>> {
     if let Some(child_node) = node.children().find(|n| n.tag_name().name() == NODE_DMNDI) {
+      self.check_dmn_version(&child_node, DmnVersion::V1_2)?;
       let dmndi = Dmndi {
         styles: self.parse_styles(&child_node)?,
         diagrams: self.parse_diagrams(&child_node)?,
@@ -1064,7 +1202,7 @@ impl ModelParser {
   fn parse_bounds(&self, node: &Node) -> Result<DcBounds> {
     match self.parse_optional_bounds(node) {
       Ok(Some(n)) => Ok(n),
-      _ => Err(err_required_child_node_is_missing(node.tag_name().name(), NODE_DMNDI_BOUNDS)),
+      _ => Err(err_required_child_node_is_missing(&node_name_pos(node), NODE_DMNDI_BOUNDS)),
     }
   }
 
@@ -1102,8 +1240,8 @@ impl ModelParser {
       id: optional_attribute(node, ATTR_ID),
       way_points: self.parse_way_points(node)?,
       dmn_element_ref: optional_attribute(node, ATTR_DMN_ELEMENT_REF),
-      source_element: None,
-      target_element: None,
+      source_element: optional_attribute(node, ATTR_SOURCE_ELEMENT),
+      target_element: optional_attribute(node, ATTR_TARGET_ELEMENT),
       shared_style: optional_attribute(node, ATTR_SHARED_STYLE),
       local_style: self.parse_optional_style(node, NODE_DMNDI_LOCAL_STYLE)?,
       label: self.parse_label(node)?,
@@ -1161,18 +1299,18 @@ fn required_feel_name(node: &Node) -> Result<Name> {
 
 /// Returns the required `href` attribute.
 pub fn required_href(node: &Node) -> Result<HRef> {
-  HRef::try_from(required_attribute(node, ATTR_HREF)?.as_str())
+  HRef::try_from(required_attribute(node, ATTR_HREF)?.as_str()).map_err(|reason| err_invalid_attribute_value(&node_name_pos(node), ATTR_HREF, &reason.to_string()))
 }
 
 /// Returns the required URI attribute.
 pub fn required_uri(node: &Node, attr_name: &str) -> Result<Uri> {
-  to_uri(required_attribute(node, attr_name)?.as_str())
+  to_uri(required_attribute(node, attr_name)?.as_str()).map_err(|reason| err_invalid_attribute_value(&node_name_pos(node), attr_name, &reason.to_string()))
 }
 
 /// Returns an optional URI attribute.
 pub fn optional_uri(node: &Node, attr_name: &str) -> Result<Option<Uri>> {
-  if let Some(value) = optional_attribute(node, attr_name) {
-    Ok(Some(to_uri(value.as_str())?))
+  if node.has_attribute(attr_name) {
+    Ok(Some(required_uri(node, attr_name)?))
   } else {
     Ok(None)
   }
@@ -1180,14 +1318,13 @@ pub fn optional_uri(node: &Node, attr_name: &str) -> Result<Option<Uri>> {
 
 /// Returns the required `href` attribute taken from required child node.
 pub fn required_child_required_href(node: &Node, child_name: &str) -> Result<HRef> {
-  let child_node = required_child(node, child_name)?;
-  HRef::try_from(required_attribute(&child_node, ATTR_HREF)?.as_str())
+  required_href(&required_child(node, child_name)?)
 }
 
 /// Returns the required `href` attribute of the optional child node.
 pub fn optional_child_required_href(node: &Node, child_name: &str) -> Result<Option<HRef>> {
   if let Some(child_node) = optional_child(node, child_name) {
-    Ok(Some(HRef::try_from(required_attribute(&child_node, ATTR_HREF)?.as_str())?))
+    Ok(Some(required_href(&child_node)?))
   } else {
     Ok(None)
   }
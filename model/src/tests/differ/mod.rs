@@ -0,0 +1,172 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! # Tests for semantic comparison of DMN models
+
+use crate::tests::parser::input_files::*;
+use crate::{diff, parse, ChangeKind, ModelChange};
+use dmntk_examples::*;
+
+/// Compares two models and returns changes with their details and changed rules, one item per line.
+fn changes(old: &str, new: &str, include_diagrams: bool) -> Vec<String> {
+  let changes = diff(&parse(old).unwrap(), &parse(new).unwrap(), include_diagrams);
+  let mut lines = vec![];
+  for change in &changes {
+    lines.push(change.to_string());
+    lines.extend(change.details().iter().map(|detail| format!("  {detail}")));
+    lines.extend(change.rule_changes().iter().map(|rule_change| format!("  {rule_change}")));
+  }
+  lines
+}
+
+/// Returns the change of the element with specified name.
+fn change<'a>(changes: &'a [ModelChange], element_name: &str) -> &'a ModelChange {
+  changes.iter().find(|change| change.element_name() == element_name).unwrap()
+}
+
+#[test]
+fn _0001() {
+  assert_eq!(Vec::<String>::new(), changes(DMN_3_0002, DMN_3_0002, true));
+}
+
+#[test]
+fn _0002() {
+  assert_eq!(
+    vec![
+      "changed item definition 'tApplicant'",
+      "  component 'income' added",
+      "added item definition 'tSegment'",
+      "removed item definition 'tRisk'",
+      "added input data 'Segment'",
+      "removed input data 'Region'",
+      "changed decision 'Eligibility'",
+      "  hit policy changed from 'U' to 'F'",
+      "  changed rule 1",
+      "  removed rule 3",
+      "  added rule 4",
+      "changed decision 'Discount'",
+      "  decision logic changed from `if Eligibility = \"ELIGIBLE\" then 0.1 else 0` to `if Eligibility = \"ELIGIBLE\" then 0.15 else 0`",
+      "added information requirement 'Eligibility -> Segment'",
+    ],
+    changes(T_DMN_0025, T_DMN_0026, false)
+  );
+}
+
+#[test]
+fn _0003() {
+  let changes = diff(&parse(T_DMN_0025).unwrap(), &parse(T_DMN_0026).unwrap(), false);
+  let rule_changes = change(&changes, "Eligibility").rule_changes();
+  let rule_change = &rule_changes[0];
+  assert_eq!(ChangeKind::Changed, rule_change.kind());
+  assert_eq!(Some(1), rule_change.old_number());
+  assert_eq!(Some(1), rule_change.new_number());
+  let cells = rule_change.cells();
+  assert_eq!(3, cells.len());
+  assert_eq!("Applicant.age", cells[0].column());
+  assert_eq!(Some("< 18"), cells[0].old_text());
+  assert_eq!(Some("< 21"), cells[0].new_text());
+  assert!(cells[0].is_changed());
+  assert_eq!("Applicant.status", cells[1].column());
+  assert!(!cells[1].is_changed());
+  assert_eq!("output", cells[2].column());
+  assert!(!cells[2].is_changed());
+  let rule_change = &rule_changes[1];
+  assert_eq!(ChangeKind::Removed, rule_change.kind());
+  assert_eq!(Some(3), rule_change.old_number());
+  assert_eq!(None, rule_change.new_number());
+  assert_eq!(Some("\"SILVER\""), rule_change.cells()[1].old_text());
+  assert_eq!(None, rule_change.cells()[1].new_text());
+  let rule_change = &rule_changes[2];
+  assert_eq!(ChangeKind::Added, rule_change.kind());
+  assert_eq!(None, rule_change.old_number());
+  assert_eq!(Some(4), rule_change.new_number());
+  assert_eq!(Some("\"PLATINUM\""), rule_change.cells()[1].new_text());
+}
+
+#[test]
+fn _0004() {
+  let changes = changes(T_DMN_0025, T_DMN_0026, true);
+  assert_eq!(
+    vec![
+      "changed diagram 'Eligibility'",
+      "  shape of 'Segment' added",
+      "  shape of 'Eligibility' moved",
+      "  shape of 'Region' removed",
+      "  edge '_ir_segment' added",
+    ],
+    changes[changes.len() - 5..]
+  );
+}
+
+#[test]
+fn _0005() {
+  // elements matched by identifier are reported as renamed, requirements follow renamed elements
+  let new = T_DMN_0025.replace(r#"name="Applicant""#, r#"name="Customer""#).replace("Applicant.", "Customer.");
+  assert_eq!(
+    vec![
+      "changed input data 'Customer'",
+      "  name changed from 'Applicant' to 'Customer'",
+      "changed decision 'Eligibility'",
+      "  input clause 1 changed from `Applicant.age` to `Customer.age`",
+      "  input clause 2 changed from `Applicant.status` to `Customer.status`",
+      "removed information requirement 'Eligibility -> Applicant'",
+      "added information requirement 'Eligibility -> Customer'",
+    ],
+    changes(T_DMN_0025, &new, false)
+  );
+}
+
+#[test]
+fn _0006() {
+  // rules are aligned by the texts of their cells, identifiers and surrounding whitespace are ignored
+  let new = T_DMN_0025
+    .replacen("<rule>", "<rule>\n                <inputEntry><text>-</text></inputEntry>\n                <inputEntry><text>\"PLATINUM\"</text></inputEntry>\n                <outputEntry><text>\"ELIGIBLE\"</text></outputEntry>\n            </rule>\n            <rule>", 1)
+    .replace("<inputEntry><text>\"GOLD\"</text></inputEntry>", "<inputEntry id=\"_gold\"><text> \"GOLD\" </text></inputEntry>");
+  assert_eq!(vec!["changed decision 'Eligibility'", "  added rule 1"], changes(T_DMN_0025, &new, false));
+}
+
+#[test]
+fn _0007() {
+  // boxed expressions nested in contexts and invocations are compared part by part
+  assert_eq!(
+    vec![
+      "changed decision 'Payment'",
+      "  decision logic entry 'Base' changed from `1200` to `1500`",
+      "  decision logic entry 'Rate' changed rule 2",
+      "  decision logic entry 'Bonus' binding 'factor' changed from `Rate` to `Rate * 2`",
+      "  decision logic entry 'Fee' removed",
+      "  decision logic result changed from `Base + Bonus - Fee` to `Base + Bonus - Tax[1]`",
+      "  decision logic entry 'Tax' added",
+    ],
+    changes(T_DMN_0032, T_DMN_0033, false)
+  );
+}
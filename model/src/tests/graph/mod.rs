@@ -0,0 +1,132 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! # Tests for decision requirements graph
+
+use crate::tests::parser::input_files::*;
+use crate::{parse, DrgGraph, GraphEdgeKind, GraphNodeKind};
+
+#[test]
+fn _0001() {
+  let graph = DrgGraph::new(&parse(T_DMN_0027).unwrap());
+  assert_eq!(7, graph.nodes().len());
+  assert_eq!("_policy", graph.nodes()[6].id());
+  assert_eq!("Policy \"2023\"", graph.nodes()[6].name());
+  assert_eq!(GraphNodeKind::KnowledgeSource, graph.nodes()[6].kind());
+  assert_eq!(8, graph.edges().len());
+  assert_eq!("_policy", graph.edges()[4].source());
+  assert_eq!("_rules", graph.edges()[4].target());
+  assert_eq!(GraphEdgeKind::Authority, graph.edges()[4].kind());
+}
+
+#[test]
+fn _0002() {
+  assert_eq!(
+    r#"digraph "model-0027" {
+  rankdir=BT;
+  n1 [label="Applicant", shape=ellipse];
+  n2 [label="Region", shape=ellipse];
+  n3 [label="Eligibility", shape=box];
+  n4 [label="Offer", shape=box];
+  n5 [label="Rules", shape=octagon];
+  n6 [label="Offer Service", shape=box, style="rounded,bold"];
+  n7 [label="Policy \"2023\"", shape=note];
+  n1 -> n3;
+  n5 -> n3 [style=dashed];
+  n3 -> n4;
+  n2 -> n4;
+  n7 -> n5 [style=dashed, arrowhead=dot];
+  n4 -> n6 [style=dotted, arrowhead=none];
+  n3 -> n6 [style=dotted, arrowhead=none];
+  n2 -> n7 [style=dashed, arrowhead=dot];
+}
+"#,
+    DrgGraph::new(&parse(T_DMN_0027).unwrap()).to_dot()
+  );
+}
+
+#[test]
+fn _0003() {
+  assert_eq!(
+    r#"flowchart BT
+  n1(["Applicant"])
+  n2(["Region"])
+  n3["Eligibility"]
+  n4["Offer"]
+  n5{{"Rules"}}
+  n6[["Offer Service"]]
+  n7>"Policy #quot;2023#quot;"]
+  n1 --> n3
+  n5 -.-> n3
+  n3 --> n4
+  n2 --> n4
+  n7 -.-o n5
+  n4 -.- n6
+  n3 -.- n6
+  n2 -.-o n7
+"#,
+    DrgGraph::new(&parse(T_DMN_0027).unwrap()).to_mermaid()
+  );
+}
+
+#[test]
+fn _0004() {
+  assert_eq!(
+    r#"flowchart BT
+  n1(["Applicant"])
+  n2(["Region"])
+  n3["Eligibility"]
+  n4{{"Rules"}}
+  n5>"Policy #quot;2023#quot;"]
+  n1 --> n3
+  n4 -.-> n3
+  n5 -.-o n4
+  n2 -.-o n5
+"#,
+    DrgGraph::for_invocable(&parse(T_DMN_0027).unwrap(), "Eligibility").unwrap().to_mermaid()
+  );
+}
+
+#[test]
+fn _0005() {
+  let graph = DrgGraph::for_invocable(&parse(T_DMN_0027).unwrap(), "Offer Service").unwrap();
+  assert_eq!(7, graph.nodes().len());
+  assert_eq!(8, graph.edges().len());
+}
+
+#[test]
+fn _0006() {
+  assert_eq!(
+    "<ModelError> invocable 'Applicant' not found",
+    DrgGraph::for_invocable(&parse(T_DMN_0027).unwrap(), "Applicant").unwrap_err().to_string()
+  );
+}
@@ -0,0 +1,116 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! # Tests for static checks of DMN models
+
+use crate::tests::parser::input_files::*;
+use crate::{element_positions, lint, parse, LintRule, LintSeverity};
+use dmntk_examples::*;
+
+/// Checks if linting the model reports exactly the expected issues.
+fn assert_diagnostics(xml: &str, expected: &[&str]) {
+  let definitions = parse(xml).unwrap();
+  let actual = lint(&definitions).iter().map(|diagnostic| diagnostic.to_string()).collect::<Vec<String>>();
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn _0001() {
+  assert_diagnostics(DMN_3_0002, &[]);
+}
+
+#[test]
+fn _0002() {
+  assert_diagnostics(
+    T_DMN_0024,
+    &[
+      "warning[unreferenced-input-data]: input data 'Age' is not required by any decision, decision service or knowledge source",
+      "warning[unreferenced-bkm]: business knowledge model 'Penalty' is not required by any decision or business knowledge model",
+      "error[unresolved-requirement]: information requirement of decision 'Yearly Income' refers to input data with identifier '_missing_input' that does not exist",
+      "error[unresolved-requirement]: edge '_edge_missing' in diagram 'Income' depicts requirement with identifier '_ir_removed' that does not exist",
+      "error[variable-name-mismatch]: variable name 'Scoring' differs from the name of decision 'Score'",
+      "error[requirement-cycle]: requirements of decision 'Loop A' form a cycle: Loop A -> Loop B -> Loop A",
+      "error[unknown-type-ref]: type reference 'tScore' in decision 'Score' names neither a built-in type nor an item definition",
+      "error[invalid-feel-expression]: literal expression in decision 'Score' is not a valid FEEL expression: <ParserError> syntax error: Yearly Income >, unexpected end of input at 1:16",
+    ],
+  );
+}
+
+#[test]
+fn _0003() {
+  let definitions = parse(T_DMN_0024).unwrap();
+  let diagnostics = lint(&definitions);
+  let diagnostic = &diagnostics[0];
+  assert_eq!(LintRule::UnreferencedInputData, diagnostic.rule());
+  assert_eq!(LintSeverity::Warning, diagnostic.severity());
+  assert_eq!("_age", diagnostic.element_id());
+  assert_eq!("Age", diagnostic.element_name());
+  assert_eq!(
+    "input data 'Age' is not required by any decision, decision service or knowledge source",
+    diagnostic.message()
+  );
+  let diagnostic = &diagnostics[5];
+  assert_eq!(LintRule::RequirementCycle, diagnostic.rule());
+  assert_eq!(LintSeverity::Error, diagnostic.severity());
+  assert_eq!("_loop_a", diagnostic.element_id());
+  assert_eq!("Loop A", diagnostic.element_name());
+}
+
+#[test]
+fn _0004() {
+  assert_eq!(
+    vec![
+      "unreferenced-input-data",
+      "unreferenced-bkm",
+      "unresolved-requirement",
+      "unknown-type-ref",
+      "variable-name-mismatch",
+      "requirement-cycle",
+      "invalid-feel-expression"
+    ],
+    LintRule::ALL.iter().map(|rule| rule.id()).collect::<Vec<&str>>()
+  );
+  assert!(LintRule::ALL.iter().all(|rule| !rule.description().is_empty()));
+  assert_eq!("warning", LintSeverity::Warning.to_string());
+  assert_eq!("error", LintSeverity::Error.to_string());
+}
+
+#[test]
+fn _0005() {
+  // positions of elements are used to locate issues reported by the linter
+  let positions = element_positions(T_DMN_0024);
+  assert_eq!(Some(&(86, 5)), positions.get("_age"));
+  for diagnostic in lint(&parse(T_DMN_0024).unwrap()) {
+    assert!(positions.contains_key(diagnostic.element_id()), "{}", diagnostic.element_id());
+  }
+  assert!(element_positions("<definitions").is_empty());
+}
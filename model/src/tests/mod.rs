@@ -32,5 +32,10 @@
 
 //! # Tests for DMN model components.
 
+mod differ;
+mod graph;
+mod linter;
 mod model;
 mod parser;
+mod validator;
+mod writer;
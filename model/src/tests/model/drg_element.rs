@@ -0,0 +1,56 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! DrgElement tests.
+
+use crate::model::{AuthorityRequirement, DrgElement};
+use crate::parse;
+use crate::tests::parser::input_files::T_DMN_0027;
+
+#[test]
+fn test_drg_element() {
+  let definitions = parse(T_DMN_0027).unwrap();
+  let policy = definitions.drg_elements().find(|drg_element| drg_element.id() == "_policy").unwrap();
+  assert_eq!("Policy \"2023\"", policy.name());
+  assert_eq!(Some(&"_policy".to_string()), policy.opt_id());
+  assert_eq!("knowledge source", policy.kind());
+  let DrgElement::KnowledgeSource(knowledge_source) = policy else {
+    panic!("expected knowledge source");
+  };
+  let references = knowledge_source
+    .authority_requirements()
+    .iter()
+    .flat_map(AuthorityRequirement::references)
+    .map(|href| href.id())
+    .collect::<Vec<&str>>();
+  assert_eq!(vec!["_region"], references);
+}
@@ -31,21 +31,28 @@
  */
 
 //! # Tests for extension elements and attributes.
-//!
-//! These elements are currently ignored, so tests are just for code coverage.
 
 use crate::model::{ExtensionAttribute, ExtensionElement};
 
 #[test]
 #[allow(clippy::redundant_clone)]
 fn test_extension_element() {
-  let actual = ExtensionElement;
-  assert_eq!("ExtensionElement", format!("{:?}", actual.clone()));
+  let actual = ExtensionElement {
+    namespace: None,
+    name: "note".to_string(),
+    xml: "<note/>".to_string(),
+  };
+  assert_eq!(r#"ExtensionElement { namespace: None, name: "note", xml: "<note/>" }"#, format!("{:?}", actual.clone()));
 }
 
 #[test]
 #[allow(clippy::redundant_clone)]
 fn test_extension_attribute() {
-  let actual = ExtensionAttribute;
-  assert_eq!("ExtensionAttribute", format!("{:?}", actual.clone()));
+  let actual = ExtensionAttribute {
+    namespace: "https://dmntk.io/vendor".to_string(),
+    prefix: "vendor".to_string(),
+    name: "note".to_string(),
+    value: "a < b".to_string(),
+  };
+  assert_eq!(r#"vendor:note="a &lt; b""#, actual.clone().xml());
 }
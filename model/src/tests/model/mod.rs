@@ -34,5 +34,6 @@
 
 mod builtin_aggregator;
 mod decision_table_orientation;
+mod drg_element;
 mod extensions;
 mod hit_policy;
@@ -0,0 +1,66 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::model::*;
+use crate::parse;
+use crate::tests::parser::input_files::*;
+
+#[test]
+fn _0001() {
+  let definitions = parse(T_DMN_0021).unwrap();
+  assert_eq!(DmnVersion::V1_1, definitions.dmn_version());
+  assert_eq!(&Some("number".to_string()), definitions.item_definitions()[0].type_ref());
+  assert_eq!("tAmount", definitions.input_data()[0].variable().type_ref());
+  assert_eq!("number", definitions.decisions()[0].variable().type_ref());
+}
+
+#[test]
+fn _0002() {
+  let definitions = parse(T_DMN_0022).unwrap();
+  assert_eq!(DmnVersion::V1_5, definitions.dmn_version());
+  assert!(matches!(definitions.decisions()[0].decision_logic(), Some(ExpressionInstance::Filter(_))));
+}
+
+#[test]
+fn _0003() {
+  let definitions = parse(T_DMN_0015).unwrap();
+  assert_eq!(DmnVersion::V1_3, definitions.dmn_version());
+}
+
+#[test]
+fn _0004() {
+  assert_eq!("DMN 1.2", DmnVersion::try_from("http://www.omg.org/spec/DMN/20180521/MODEL").unwrap().to_string());
+  assert_eq!("DMN 1.4", DmnVersion::try_from("https://www.omg.org/spec/DMN/20211108/MODEL/").unwrap().to_string());
+  assert_eq!(URI_MODEL_1_5, DmnVersion::V1_5.model_uri());
+  assert_eq!(URI_FEEL_1_1, DmnVersion::V1_1.feel_uri());
+  assert!(DmnVersion::V1_3 < DmnVersion::V1_4);
+}
@@ -0,0 +1,89 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::model::*;
+use crate::parse;
+use crate::tests::parser::input_files::*;
+
+#[test]
+fn _0001() {
+  let definitions = parse(T_DMN_0023).unwrap();
+  let extension_elements = definitions.extension_elements();
+  assert_eq!(2, extension_elements.len());
+  assert_eq!(&Some("https://dmntk.io/vendor".to_string()), extension_elements[0].namespace());
+  assert_eq!("scenario", extension_elements[0].name());
+  assert_eq!(
+    r#"<vendor:scenario xmlns:vendor="https://dmntk.io/vendor" name="adult" vendor:expected="true">
+            <vendor:input name="Age">18</vendor:input>
+        </vendor:scenario>"#,
+    extension_elements[0].xml()
+  );
+  assert_eq!("note", extension_elements[1].name());
+  assert_eq!(r#"<note xmlns="https://dmntk.io/notes">Reviewed &amp; approved</note>"#, extension_elements[1].xml());
+}
+
+#[test]
+fn _0002() {
+  let definitions = parse(T_DMN_0023).unwrap();
+  let extension_attributes = definitions.extension_attributes();
+  assert_eq!(1, extension_attributes.len());
+  assert_eq!("https://dmntk.io/vendor", extension_attributes[0].namespace());
+  assert_eq!("vendor", extension_attributes[0].prefix());
+  assert_eq!("owner", extension_attributes[0].name());
+  assert_eq!("Risk Team", extension_attributes[0].value());
+  assert_eq!(r#"vendor:owner="Risk Team""#, extension_attributes[0].xml());
+}
+
+#[test]
+fn _0003() {
+  let definitions = parse(T_DMN_0023).unwrap();
+  let decision = definitions.get_decision("_5c8e2a71-3f9b-4d16-a0e4-7b2d9f6c1a58").unwrap();
+  assert_eq!(1, decision.extension_elements().len());
+  assert_eq!(
+    r#"<vendor:scenario xmlns:vendor="https://dmntk.io/vendor" name="child" vendor:expected="false"/>"#,
+    decision.extension_elements()[0].xml()
+  );
+  let attributes = decision.extension_attributes().iter().map(|attribute| attribute.xml()).collect::<Vec<String>>();
+  assert_eq!(vec![r#"vendor:owner="Credit Team""#, r#"vendor:tag="age""#], attributes);
+  let Some(ExpressionInstance::LiteralExpression(literal_expression)) = decision.decision_logic() else {
+    panic!("expected literal expression");
+  };
+  assert_eq!(r#"vendor:generated="yes""#, literal_expression.extension_attributes()[0].xml());
+}
+
+#[test]
+fn _0004() {
+  let definitions = parse(T_DMN_0023).unwrap();
+  let input_data = definitions.get_input_data("_0a7d4f93-6e2b-48c1-b5f8-9e3c2a7d6b10").unwrap();
+  assert!(input_data.extension_elements().is_empty());
+  assert!(input_data.extension_attributes().is_empty());
+}
@@ -48,3 +48,20 @@ pub const T_DMN_0013: &str = include_str!("t_0013.dmn");
 pub const T_DMN_0014: &str = include_str!("t_0014.dmn");
 pub const T_DMN_0015: &str = include_str!("t_0015.dmn");
 pub const T_DMN_0016: &str = include_str!("t_0016.dmn");
+pub const T_DMN_0017: &str = include_str!("t_0017.dmn");
+pub const T_DMN_0018: &str = include_str!("t_0018.dmn");
+pub const T_DMN_0019: &str = include_str!("t_0019.dmn");
+pub const T_DMN_0020: &str = include_str!("t_0020.dmn");
+pub const T_DMN_0021: &str = include_str!("t_0021.dmn");
+pub const T_DMN_0022: &str = include_str!("t_0022.dmn");
+pub const T_DMN_0023: &str = include_str!("t_0023.dmn");
+pub const T_DMN_0024: &str = include_str!("t_0024.dmn");
+pub const T_DMN_0025: &str = include_str!("t_0025.dmn");
+pub const T_DMN_0026: &str = include_str!("t_0026.dmn");
+pub const T_DMN_0027: &str = include_str!("t_0027.dmn");
+pub const T_DMN_0028: &str = include_str!("t_0028.dmn");
+pub const T_DMN_0029: &str = include_str!("t_0029.dmn");
+pub const T_DMN_0030: &str = include_str!("t_0030.dmn");
+pub const T_DMN_0031: &str = include_str!("t_0031.dmn");
+pub const T_DMN_0032: &str = include_str!("t_0032.dmn");
+pub const T_DMN_0033: &str = include_str!("t_0033.dmn");
@@ -38,7 +38,7 @@ fn _0001() {
   let definitions = parse(T_DMN_0001);
   assert!(definitions.is_err());
   assert_eq!(
-    r#"<ModelParserError> 'Python' is not a valid function kind, accepted values are: 'FEEL', 'Java', 'PMML'"#,
+    r#"<ModelParserError> 'Python' is not a valid function kind in node 'functionDefinition' at [17:17], accepted values are: 'FEEL', 'Java', 'PMML'"#,
     format!("{}", definitions.err().unwrap())
   )
 }
@@ -48,7 +48,7 @@ fn _0002() {
   let definitions = parse(T_DMN_0002);
   assert!(definitions.is_err());
   assert_eq!(
-    r#"<ModelParserError> 'LAST' is not a valid hit policy, allowed values are: 'UNIQUE', 'FIRST', 'PRIORITY', 'ANY', 'COLLECT', 'RULE ORDER', 'OUTPUT ORDER'"#,
+    r#"<ModelParserError> 'LAST' is not a valid hit policy in node 'decisionTable' at [17:9], allowed values are: 'UNIQUE', 'FIRST', 'PRIORITY', 'ANY', 'COLLECT', 'RULE ORDER', 'OUTPUT ORDER'"#,
     format!("{}", definitions.err().unwrap())
   )
 }
@@ -58,7 +58,7 @@ fn _0003() {
   let definitions = parse(T_DMN_0003);
   assert!(definitions.is_err());
   assert_eq!(
-    r#"<ModelParserError> 'AVG' is not a valid aggregation, allowed values are: 'COUNT', 'SUM', 'MIN', 'MAX'"#,
+    r#"<ModelParserError> 'AVG' is not a valid aggregation in node 'decisionTable' at [17:9], allowed values are: 'COUNT', 'SUM', 'MIN', 'MAX'"#,
     format!("{}", definitions.err().unwrap())
   )
 }
@@ -68,7 +68,7 @@ fn _0004() {
   let definitions = parse(T_DMN_0004);
   assert!(definitions.is_err());
   assert_eq!(
-    r#"<ModelParserError> required input expression in decision table's input clause is missing"#,
+    r#"<ModelParserError> required input expression in decision table's input clause 'input' at [17:13] is missing"#,
     format!("{}", definitions.err().unwrap())
   )
 }
@@ -78,7 +78,7 @@ fn _0005() {
   let definitions = parse(T_DMN_0005);
   assert!(definitions.is_err());
   assert_eq!(
-    r#"<ModelParserError> required expression instance in context entry is missing"#,
+    r#"<ModelParserError> required expression instance in node 'contextEntry' at [14:13] is missing"#,
     format!("{}", definitions.err().unwrap())
   )
 }
@@ -88,7 +88,7 @@ fn _0006() {
   let definitions = parse(T_DMN_0006);
   assert!(definitions.is_err());
   assert_eq!(
-    r#"<ModelParserError> number of elements in a row differs from the number of columns defined in a relation"#,
+    r#"<ModelParserError> number of elements in a row 'row' at [18:13] differs from the number of columns defined in a relation"#,
     format!("{}", definitions.err().unwrap())
   )
 }
@@ -108,7 +108,7 @@ fn _0008() {
   let definitions = parse(T_DMN_0008);
   assert!(definitions.is_err());
   assert_eq!(
-    r#"<ModelParserError> unexpected XML node, expected: definitions, actual: definition"#,
+    r#"<ModelParserError> unexpected XML node, expected: definitions, actual: 'definition' at [2:1]"#,
     format!("{}", definitions.err().unwrap())
   )
 }
@@ -138,7 +138,7 @@ fn _0011() {
   let definitions = parse(T_DMN_0011);
   assert!(definitions.is_err());
   assert_eq!(
-    r#"<ModelParserError> expected mandatory text content in node 'text'"#,
+    r#"<ModelParserError> expected mandatory text content in node 'text' at [32:21]"#,
     format!("{}", definitions.err().unwrap())
   )
 }
@@ -148,7 +148,7 @@ fn _0012() {
   let definitions = parse(T_DMN_0012);
   assert!(definitions.is_err());
   assert_eq!(
-    r#"<ModelParserError> conversion to valid color value failed with reason: number too large to fit in target type"#,
+    r#"<ModelParserError> conversion to valid color value in node 'FillColor' at [28:13] failed with reason: number too large to fit in target type"#,
     format!("{}", definitions.err().unwrap())
   )
 }
@@ -158,7 +158,7 @@ fn _0013() {
   let definitions = parse(T_DMN_0013);
   assert!(definitions.is_err());
   assert_eq!(
-    r#"<ModelParserError> conversion to valid double value failed with reason: invalid float literal"#,
+    r#"<ModelParserError> conversion to valid double value in node 'Size' at [22:13] failed with reason: invalid float literal"#,
     format!("{}", definitions.err().unwrap())
   )
 }
@@ -184,7 +184,47 @@ fn _0016() {
   let definitions = parse(T_DMN_0016);
   assert!(definitions.is_err());
   assert_eq!(
-    r#"<ModelParserError> required child node 'Bounds' in parent node 'DMNShape' is missing"#,
+    r#"<ModelParserError> required child node 'Bounds' in parent node 'DMNShape' at [37:13] is missing"#,
+    format!("{}", definitions.err().unwrap())
+  )
+}
+
+#[test]
+fn _0017() {
+  let definitions = parse(T_DMN_0017);
+  assert!(definitions.is_err());
+  assert_eq!(
+    r#"<ModelParserError> expected mandatory child node 'else' in parent node 'conditional' at [12:9]"#,
+    format!("{}", definitions.err().unwrap())
+  )
+}
+
+#[test]
+fn _0018() {
+  let definitions = parse(T_DMN_0018);
+  assert!(definitions.is_err());
+  assert_eq!(
+    r#"<ModelParserError> expected value for mandatory attribute 'iteratorVariable' in node 'for' at [12:9]"#,
+    format!("{}", definitions.err().unwrap())
+  )
+}
+
+#[test]
+fn _0019() {
+  let definitions = parse(T_DMN_0019);
+  assert!(definitions.is_err());
+  assert_eq!(
+    r#"<ModelParserError> 'https://www.omg.org/spec/DMN/20991231/MODEL/' in node 'definitions' at [2:1] is not a supported DMN namespace, supported versions are: DMN 1.1, DMN 1.2, DMN 1.3, DMN 1.4, DMN 1.5"#,
+    format!("{}", definitions.err().unwrap())
+  )
+}
+
+#[test]
+fn _0020() {
+  let definitions = parse(T_DMN_0020);
+  assert!(definitions.is_err());
+  assert_eq!(
+    r#"<ModelParserError> 'filter' at [12:9] is not supported in DMN 1.3, it is available since DMN 1.4"#,
     format!("{}", definitions.err().unwrap())
   )
 }
@@ -32,6 +32,8 @@
 
 //! # Tests for DMN model parser
 
+mod dmn_versions;
+mod extensions;
 mod full_model;
-mod input_files;
+pub mod input_files;
 mod invalid_models;
@@ -0,0 +1,174 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! # Tests for validating DMN models against XML schema
+
+use crate::tests::parser::input_files::*;
+use crate::validate;
+use dmntk_examples::*;
+
+/// Checks if validating the model reports exactly the expected errors.
+fn assert_errors(xml: &str, expected: &[&str]) {
+  let actual = validate(xml).iter().map(|error| error.to_string()).collect::<Vec<String>>();
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn _0001() {
+  assert_errors(T_DMN_0015, &[]);
+  assert_errors(T_DMN_0023, &[]);
+}
+
+#[test]
+fn _0002() {
+  for xml in [
+    DMN_3_0001, DMN_3_0002, DMN_3_0003, DMN_3_0004, DMN_3_0005, DMN_3_0006, DMN_3_0007, DMN_3_0008, DMN_3_0009, DMN_3_0010,
+  ] {
+    assert_errors(xml, &[]);
+  }
+}
+
+#[test]
+fn _0003() {
+  assert_errors(
+    T_DMN_0001,
+    &["<ModelValidatorError> value 'Python' of attribute 'kind' in node 'functionDefinition' at [17:17] is not a valid 'tFunctionKind'"],
+  );
+}
+
+#[test]
+fn _0004() {
+  assert_errors(
+    T_DMN_0002,
+    &["<ModelValidatorError> value 'LAST' of attribute 'hitPolicy' in node 'decisionTable' at [17:9] is not a valid 'tHitPolicy'"],
+  );
+}
+
+#[test]
+fn _0005() {
+  assert_errors(
+    T_DMN_0004,
+    &["<ModelValidatorError> expected child node 'inputExpression' in parent node 'input' at [17:13]"],
+  );
+}
+
+#[test]
+fn _0006() {
+  assert_errors(
+    T_DMN_0005,
+    &["<ModelValidatorError> expected child node 'expression' in parent node 'contextEntry' at [14:13]"],
+  );
+}
+
+#[test]
+fn _0007() {
+  assert_errors(
+    T_DMN_0007,
+    &["<ModelParserError> parsing model from XML failed with reason: the root node was opened but never closed"],
+  );
+}
+
+#[test]
+fn _0008() {
+  assert_errors(T_DMN_0008, &["<ModelValidatorError> unexpected node 'definition' at [2:1]"]);
+}
+
+#[test]
+fn _0009() {
+  assert_errors(T_DMN_0009, &["<ModelValidatorError> missing required attribute 'namespace' in node 'definitions' at [2:1]"]);
+}
+
+#[test]
+fn _0010() {
+  assert_errors(
+    T_DMN_0012,
+    &["<ModelValidatorError> value '468' of attribute 'red' in node 'FillColor' at [28:13] is not a valid 'rgb'"],
+  );
+}
+
+#[test]
+fn _0011() {
+  assert_errors(T_DMN_0017, &["<ModelValidatorError> expected child node 'else' in parent node 'conditional' at [12:9]"]);
+}
+
+#[test]
+fn _0012() {
+  assert_errors(
+    T_DMN_0020,
+    &["<ModelValidatorError> unexpected child node 'filter' at [12:9] in parent node 'decision' at [10:5]"],
+  );
+}
+
+#[test]
+fn _0013() {
+  assert_errors(
+    DMN_3_1108,
+    &["<ModelValidatorError> expected child node 'requiredDecision' or 'requiredInput' or 'requiredAuthority' in parent node 'authorityRequirement' at [127:9]"],
+  );
+}
+
+#[test]
+fn _0014() {
+  // DMN 1.1
+  assert_errors(T_DMN_0021, &[]);
+  assert_errors(T_DMN_0029, &["<ModelValidatorError> unexpected attribute 'kind' in node 'encapsulatedLogic' at [11:9]"]);
+}
+
+#[test]
+fn _0015() {
+  assert_errors(
+    T_DMN_0019,
+    &["<ModelParserError> 'https://www.omg.org/spec/DMN/20991231/MODEL/' in node 'definitions' at [2:1] is not a supported DMN namespace, supported versions are: DMN 1.1, DMN 1.2, DMN 1.3, DMN 1.4, DMN 1.5"],
+  );
+}
+
+#[test]
+fn _0016() {
+  // DMN 1.2
+  assert_errors(T_DMN_0028, &[]);
+  assert_errors(
+    T_DMN_0030,
+    &["<ModelValidatorError> unexpected child node 'group' at [17:5] in parent node 'definitions' at [2:1]"],
+  );
+}
+
+#[test]
+fn _0017() {
+  // DMN 1.5
+  assert_errors(T_DMN_0022, &[]);
+  // models without namespace are validated as DMN 1.5 models
+  assert_errors(DMN_3_1130, &[]);
+  assert_errors(
+    T_DMN_0031,
+    &["<ModelValidatorError> unexpected child node 'allowedValues' at [15:9] in parent node 'itemDefinition' at [10:5]"],
+  );
+}
@@ -0,0 +1,145 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! # Tests for DMN model writer
+
+use crate::tests::parser::input_files::*;
+use crate::{parse, write, DmnElement};
+use dmntk_examples::*;
+
+/// Checks if parsing the written model produces the same document when written again.
+fn assert_idempotent(xml: &str) {
+  let written = write(&parse(xml).unwrap());
+  let rewritten = write(&parse(&written).unwrap());
+  assert_eq!(written, rewritten);
+}
+
+#[test]
+fn _0001() {
+  let expected = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<definitions xmlns="https://www.omg.org/spec/DMN/20230324/MODEL/" namespace="https://dmntk.io/versions" name="model-0022">
+    <description>
+        This model is valid, boxed filter is available in DMN 1.5.
+    </description>
+    <decision id="_8e4f2a9d-5c13-4b7e-a6d0-3f1b9c2e7d45" name="Positive">
+        <variable name="Positive" typeRef="Any"/>
+        <filter>
+            <in>
+                <literalExpression>
+                    <text>[1, -2, 3]</text>
+                </literalExpression>
+            </in>
+            <match>
+                <literalExpression>
+                    <text>item &gt; 0</text>
+                </literalExpression>
+            </match>
+        </filter>
+    </decision>
+</definitions>
+"#;
+  assert_eq!(expected, write(&parse(T_DMN_0022).unwrap()));
+}
+
+#[test]
+fn _0002() {
+  assert_idempotent(DMN_FULL);
+}
+
+#[test]
+fn _0003() {
+  for xml in [T_DMN_0015, T_DMN_0021, T_DMN_0022, T_DMN_0023] {
+    assert_idempotent(xml);
+  }
+}
+
+#[test]
+fn _0004() {
+  for xml in [
+    DMN_2_0001, DMN_2_0002, DMN_2_0003, DMN_2_0004, DMN_2_0005, DMN_2_0006, DMN_2_0007, DMN_2_0008, DMN_2_0009, DMN_2_0010,
+  ] {
+    assert_idempotent(xml);
+  }
+}
+
+#[test]
+fn _0005() {
+  for xml in [
+    DMN_3_0001,
+    DMN_3_0002,
+    DMN_3_0003,
+    DMN_3_0004,
+    DMN_3_0005,
+    DMN_3_0006,
+    DMN_3_0007,
+    DMN_3_0008,
+    DMN_3_0009,
+    DMN_3_0010,
+    DMN_3_0086,
+    DMN_3_0086_IMPORT,
+  ] {
+    assert_idempotent(xml);
+  }
+}
+
+/// Written model keeps the diagram interchange elements.
+#[test]
+fn _0006() {
+  let definitions = parse(DMN_3_0001).unwrap();
+  let written = write(&definitions);
+  assert!(written.contains(r#"xmlns:dmndi="https://www.omg.org/spec/DMN/20191111/DMNDI/""#));
+  let reparsed = parse(&written).unwrap();
+  let diagrams = &reparsed.dmndi().as_ref().unwrap().diagrams;
+  assert_eq!(definitions.dmndi().as_ref().unwrap().diagrams.len(), diagrams.len());
+  assert_eq!(definitions.drg_elements().count(), reparsed.drg_elements().count());
+}
+
+/// Written model keeps extension elements and extension attributes.
+#[test]
+fn _0007() {
+  let written = write(&parse(T_DMN_0023).unwrap());
+  assert!(
+    written.contains(r#"<decision id="_5c8e2a71-3f9b-4d16-a0e4-7b2d9f6c1a58" name="Is adult" xmlns:vendor="https://dmntk.io/vendor" vendor:owner="Credit Team" vendor:tag="age">"#)
+  );
+  assert!(written.contains(
+    r#"        <extensionElements>
+            <vendor:scenario xmlns:vendor="https://dmntk.io/vendor" name="child" vendor:expected="false"/>
+        </extensionElements>"#
+  ));
+  let definitions = parse(&written).unwrap();
+  assert_eq!(2, definitions.extension_elements().len());
+  assert_eq!(
+    r#"<note xmlns="https://dmntk.io/notes">Reviewed &amp; approved</note>"#,
+    definitions.extension_elements()[1].xml()
+  );
+  assert_eq!("Risk Team", definitions.extension_attributes()[0].value());
+}
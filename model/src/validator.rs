@@ -0,0 +1,687 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! # Validation of DMN models against XML schema
+//!
+//! Models are validated against the official XML schemas published
+//! with the DMN specification, bundled in `schemas` directory of this crate.
+//! Schemas are interpreted directly, only constructs used in DMN schemas are supported.
+//! Each schema is built once, when the first model in its DMN version is validated.
+//! Models without namespace are validated like models in the default DMN version.
+
+use crate::errors::*;
+use crate::model::DmnVersion;
+use crate::xml_utils::node_name_pos;
+use dmntk_common::DmntkError;
+use once_cell::sync::Lazy;
+use roxmltree::{Document, Node};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// Namespace of the XML schema definitions.
+const URI_XSD: &str = "http://www.w3.org/2001/XMLSchema";
+
+/// Namespace of the XML schema instance attributes.
+const URI_XSI: &str = "http://www.w3.org/2001/XMLSchema-instance";
+
+/// Schema for DMN 1.1 models, diagram interchange was introduced in DMN 1.2.
+const SCHEMAS_1_1: [&str; 1] = [include_str!("../schemas/1.1/DMN.xsd")];
+
+/// Schemas for DMN 1.2 models.
+const SCHEMAS_1_2: [&str; 4] = [
+  include_str!("../schemas/1.2/DMN.xsd"),
+  include_str!("../schemas/1.2/DMNDI12.xsd"),
+  include_str!("../schemas/1.2/DC.xsd"),
+  include_str!("../schemas/1.2/DI.xsd"),
+];
+
+/// Schemas for DMN 1.3 models.
+const SCHEMAS_1_3: [&str; 4] = [
+  include_str!("../schemas/1.3/DMN.xsd"),
+  include_str!("../schemas/1.3/DMNDI13.xsd"),
+  include_str!("../schemas/1.3/DC.xsd"),
+  include_str!("../schemas/1.3/DI.xsd"),
+];
+
+/// Schemas for DMN 1.4 models.
+const SCHEMAS_1_4: [&str; 4] = [
+  include_str!("../schemas/1.4/DMN.xsd"),
+  include_str!("../schemas/1.4/DMNDI13.xsd"),
+  include_str!("../schemas/1.4/DC.xsd"),
+  include_str!("../schemas/1.4/DI.xsd"),
+];
+
+/// Schemas for DMN 1.5 models.
+const SCHEMAS_1_5: [&str; 4] = [
+  include_str!("../schemas/1.5/DMN.xsd"),
+  include_str!("../schemas/1.5/DMNDI15.xsd"),
+  include_str!("../schemas/1.5/DC.xsd"),
+  include_str!("../schemas/1.5/DI.xsd"),
+];
+
+/// Schema for DMN 1.1 models, built on first use.
+static SCHEMA_1_1: Lazy<Result<Schema, String>> = Lazy::new(|| Schema::new(&SCHEMAS_1_1));
+
+/// Schema for DMN 1.2 models, built on first use.
+static SCHEMA_1_2: Lazy<Result<Schema, String>> = Lazy::new(|| Schema::new(&SCHEMAS_1_2));
+
+/// Schema for DMN 1.3 models, built on first use.
+static SCHEMA_1_3: Lazy<Result<Schema, String>> = Lazy::new(|| Schema::new(&SCHEMAS_1_3));
+
+/// Schema for DMN 1.4 models, built on first use.
+static SCHEMA_1_4: Lazy<Result<Schema, String>> = Lazy::new(|| Schema::new(&SCHEMAS_1_4));
+
+/// Schema for DMN 1.5 models, built on first use.
+static SCHEMA_1_5: Lazy<Result<Schema, String>> = Lazy::new(|| Schema::new(&SCHEMAS_1_5));
+
+/// Returns XML schema for specified DMN version, or the reason why building the schema failed.
+fn schema(dmn_version: DmnVersion) -> &'static Result<Schema, String> {
+  match dmn_version {
+    DmnVersion::V1_1 => &SCHEMA_1_1,
+    DmnVersion::V1_2 => &SCHEMA_1_2,
+    DmnVersion::V1_3 => &SCHEMA_1_3,
+    DmnVersion::V1_4 => &SCHEMA_1_4,
+    DmnVersion::V1_5 => &SCHEMA_1_5,
+  }
+}
+
+/// Validates the XML document containing DMN model against XML schema
+/// for the DMN version of the model. Returns all detected errors,
+/// the list of errors is empty when the model is valid.
+pub fn validate(xml: &str) -> Vec<DmntkError> {
+  let document = match Document::parse(xml) {
+    Ok(document) => document,
+    Err(reason) => return vec![err_xml_parsing_model_failed(&reason.to_string())],
+  };
+  let root_node = document.root_element();
+  let namespace = root_node.tag_name().namespace().unwrap_or_default();
+  let dmn_version = match namespace {
+    "" => DmnVersion::default(),
+    _ => match DmnVersion::try_from(namespace) {
+      Ok(dmn_version) => dmn_version,
+      Err(_) => return vec![err_unsupported_dmn_namespace_in_node(namespace, &node_name_pos(&root_node))],
+    },
+  };
+  let schema = match schema(dmn_version) {
+    Ok(schema) => schema,
+    Err(reason) => return vec![err_xml_parsing_model_failed(reason)],
+  };
+  // nodes of the model without namespace are validated as nodes in the namespace of the default DMN version
+  let namespace = if namespace.is_empty() { dmn_version.model_uri() } else { "" };
+  let mut validator = Validator {
+    schema,
+    namespace,
+    errors: vec![],
+  };
+  validator.validate_root(&root_node);
+  validator.errors
+}
+
+/// Qualified name, namespace and local name.
+type QName = (String, String);
+
+/// Element declaration.
+struct Element {
+  /// Qualified name of the element.
+  name: QName,
+  /// Type of the element.
+  type_ref: TypeRef,
+  /// Flag indicating if the element is abstract and may only be substituted.
+  is_abstract: bool,
+  /// Head of the substitution group this element belongs to.
+  substitution_group: Option<QName>,
+}
+
+/// Reference to the type of an element.
+enum TypeRef {
+  /// Named simple or complex type.
+  Named(QName),
+  /// Anonymous complex type, index in the list of anonymous types.
+  Anonymous(usize),
+  /// Type not specified, any content is accepted.
+  Any,
+}
+
+/// Complex type definition.
+#[derive(Default)]
+struct ComplexType {
+  /// Name of the extended base type.
+  base: Option<QName>,
+  /// Particles of the content, matched in sequence.
+  particles: Vec<Particle>,
+  /// Declared attributes.
+  attributes: Vec<Attribute>,
+  /// Namespace excluded from attribute wildcard, when any attributes are accepted.
+  any_attribute: Option<String>,
+}
+
+/// Attribute declaration.
+struct Attribute {
+  /// Name of the attribute.
+  name: String,
+  /// Name of the simple type of the attribute.
+  type_name: QName,
+  /// Flag indicating if the attribute is required.
+  is_required: bool,
+}
+
+/// Simple type definition.
+struct SimpleType {
+  /// Name of the restricted base type.
+  base: QName,
+  /// Enumerated allowed values.
+  enumeration: Vec<String>,
+  /// Minimal allowed value, inclusive.
+  min: Option<f64>,
+  /// Maximal allowed value, inclusive.
+  max: Option<f64>,
+}
+
+/// Particle of the content model with the number of occurrences.
+struct Particle {
+  term: Term,
+  min: usize,
+  max: Option<usize>,
+}
+
+/// Term of the content model.
+enum Term {
+  /// Local element declaration.
+  Element(Element),
+  /// Reference to global element, including elements from its substitution group.
+  Reference(QName),
+  /// Any element from namespace other than the specified one.
+  Any(String),
+  /// Particles matched in sequence.
+  Sequence(Vec<Particle>),
+  /// One of the particles.
+  Choice(Vec<Particle>),
+}
+
+/// XML schema built from schema documents.
+#[derive(Default)]
+struct Schema {
+  elements: HashMap<QName, Element>,
+  complex_types: HashMap<QName, ComplexType>,
+  anonymous_types: Vec<ComplexType>,
+  simple_types: HashMap<QName, SimpleType>,
+  substitutions: HashMap<QName, Vec<QName>>,
+}
+
+impl Schema {
+  /// Builds the schema from the XML schema documents.
+  fn new(sources: &[&str]) -> Result<Self, String> {
+    let mut schema = Schema::default();
+    for source in sources {
+      let document = Document::parse(source).map_err(|reason| reason.to_string())?;
+      schema.load(&document.root_element());
+    }
+    for element in schema.elements.values() {
+      if let Some(head) = &element.substitution_group {
+        schema.substitutions.entry(head.clone()).or_default().push(element.name.clone());
+      }
+    }
+    Ok(schema)
+  }
+
+  /// Loads global declarations from the schema document.
+  fn load(&mut self, schema_node: &Node) {
+    let target_namespace = schema_node.attribute("targetNamespace").unwrap_or_default();
+    for ref node in schema_node.children().filter(|n| n.is_element()) {
+      let Some(name) = node.attribute("name") else {
+        continue;
+      };
+      let name = (target_namespace.to_string(), name.to_string());
+      match node.tag_name().name() {
+        "element" => {
+          let element = self.parse_element(node, name.clone(), target_namespace);
+          self.elements.insert(name, element);
+        }
+        "complexType" => {
+          let complex_type = self.parse_complex_type(node, target_namespace);
+          self.complex_types.insert(name, complex_type);
+        }
+        "simpleType" => {
+          let simple_type = parse_simple_type(node);
+          self.simple_types.insert(name, simple_type);
+        }
+        _ => {}
+      }
+    }
+  }
+
+  /// Parses element declaration.
+  fn parse_element(&mut self, node: &Node, name: QName, target_namespace: &str) -> Element {
+    let type_ref = if let Some(type_name) = node.attribute("type") {
+      TypeRef::Named(qname(node, type_name))
+    } else if let Some(ref type_node) = xsd_children(node).find(|n| n.tag_name().name() == "complexType") {
+      let complex_type = self.parse_complex_type(type_node, target_namespace);
+      self.anonymous_types.push(complex_type);
+      TypeRef::Anonymous(self.anonymous_types.len() - 1)
+    } else {
+      TypeRef::Any
+    };
+    Element {
+      name,
+      type_ref,
+      is_abstract: node.attribute("abstract") == Some("true"),
+      substitution_group: node.attribute("substitutionGroup").map(|head| qname(node, head)),
+    }
+  }
+
+  /// Parses complex type definition.
+  fn parse_complex_type(&mut self, node: &Node, target_namespace: &str) -> ComplexType {
+    let mut complex_type = ComplexType::default();
+    self.parse_complex_content(node, target_namespace, &mut complex_type);
+    complex_type
+  }
+
+  /// Parses the content of complex type definition, including content extending the base type.
+  fn parse_complex_content(&mut self, node: &Node, target_namespace: &str, complex_type: &mut ComplexType) {
+    for ref child_node in xsd_children(node) {
+      match child_node.tag_name().name() {
+        "sequence" | "choice" => {
+          let particle = self.parse_particle(child_node, target_namespace);
+          complex_type.particles.push(particle);
+        }
+        "attribute" => complex_type.attributes.push(Attribute {
+          name: child_node.attribute("name").unwrap_or_default().to_string(),
+          type_name: child_node
+            .attribute("type")
+            .map_or((URI_XSD.to_string(), "string".to_string()), |type_name| qname(child_node, type_name)),
+          is_required: child_node.attribute("use") == Some("required"),
+        }),
+        "anyAttribute" => complex_type.any_attribute = Some(target_namespace.to_string()),
+        "complexContent" => self.parse_complex_content(child_node, target_namespace, complex_type),
+        "extension" => {
+          complex_type.base = child_node.attribute("base").map(|base| qname(child_node, base));
+          self.parse_complex_content(child_node, target_namespace, complex_type);
+        }
+        _ => {}
+      }
+    }
+  }
+
+  /// Parses a particle of the content model.
+  fn parse_particle(&mut self, node: &Node, target_namespace: &str) -> Particle {
+    let min = node.attribute("minOccurs").and_then(|value| usize::from_str(value).ok()).unwrap_or(1);
+    let max = match node.attribute("maxOccurs") {
+      Some("unbounded") => None,
+      Some(value) => Some(usize::from_str(value).unwrap_or(1)),
+      None => Some(1),
+    };
+    let term = match node.tag_name().name() {
+      "element" => {
+        if let Some(reference) = node.attribute("ref") {
+          Term::Reference(qname(node, reference))
+        } else {
+          let name = (target_namespace.to_string(), node.attribute("name").unwrap_or_default().to_string());
+          Term::Element(self.parse_element(node, name, target_namespace))
+        }
+      }
+      "any" => Term::Any(target_namespace.to_string()),
+      name => {
+        let mut particles = vec![];
+        for ref child_node in xsd_children(node).filter(|n| matches!(n.tag_name().name(), "element" | "any" | "sequence" | "choice")) {
+          particles.push(self.parse_particle(child_node, target_namespace));
+        }
+        if name == "choice" {
+          Term::Choice(particles)
+        } else {
+          Term::Sequence(particles)
+        }
+      }
+    };
+    Particle { term, min, max }
+  }
+
+  /// Returns the complex type referenced by element, if any.
+  fn complex_type(&self, type_ref: &TypeRef) -> Option<&ComplexType> {
+    match type_ref {
+      TypeRef::Named(name) => self.complex_types.get(name),
+      TypeRef::Anonymous(index) => self.anonymous_types.get(*index),
+      TypeRef::Any => None,
+    }
+  }
+
+  /// Returns the chain of complex types, starting from the most basic one.
+  fn type_chain<'a>(&'a self, complex_type: &'a ComplexType) -> Vec<&'a ComplexType> {
+    let mut chain = vec![complex_type];
+    let mut current = complex_type;
+    while let Some(base) = current.base.as_ref().and_then(|base| self.complex_types.get(base)) {
+      chain.insert(0, base);
+      current = base;
+    }
+    chain
+  }
+
+  /// Returns the global element declaration matching the node,
+  /// when the node is the referenced element or belongs to its substitution group.
+  fn substitute(&self, reference: &QName, node_name: &QName) -> Option<&Element> {
+    let element = self.elements.get(reference)?;
+    if reference == node_name && !element.is_abstract {
+      return Some(element);
+    }
+    self
+      .substitutions
+      .get(reference)
+      .and_then(|members| members.iter().find_map(|member| self.substitute(member, node_name)))
+  }
+
+  /// Checks if the value is valid for the specified simple type.
+  fn is_valid_value(&self, type_name: &QName, value: &str) -> bool {
+    if type_name.0 == URI_XSD {
+      let value = value.trim();
+      return match type_name.1.as_str() {
+        "boolean" => matches!(value, "true" | "false" | "1" | "0"),
+        "double" | "float" | "decimal" => f64::from_str(value).is_ok(),
+        "int" | "integer" | "long" => i64::from_str(value).is_ok(),
+        _ => true,
+      };
+    }
+    let Some(simple_type) = self.simple_types.get(type_name) else {
+      return true;
+    };
+    if !self.is_valid_value(&simple_type.base, value) {
+      return false;
+    }
+    if !simple_type.enumeration.is_empty() && !simple_type.enumeration.iter().any(|allowed| allowed == value) {
+      return false;
+    }
+    if simple_type.min.is_some() || simple_type.max.is_some() {
+      let Ok(number) = f64::from_str(value.trim()) else {
+        return false;
+      };
+      if simple_type.min.is_some_and(|min| number < min) || simple_type.max.is_some_and(|max| number > max) {
+        return false;
+      }
+    }
+    true
+  }
+}
+
+/// Parses simple type definition.
+fn parse_simple_type(node: &Node) -> SimpleType {
+  let mut simple_type = SimpleType {
+    base: (URI_XSD.to_string(), "string".to_string()),
+    enumeration: vec![],
+    min: None,
+    max: None,
+  };
+  if let Some(ref restriction_node) = xsd_children(node).find(|n| n.tag_name().name() == "restriction") {
+    if let Some(base) = restriction_node.attribute("base") {
+      simple_type.base = qname(restriction_node, base);
+    }
+    for ref facet_node in xsd_children(restriction_node) {
+      let value = facet_node.attribute("value").unwrap_or_default();
+      match facet_node.tag_name().name() {
+        "enumeration" => simple_type.enumeration.push(value.to_string()),
+        "minInclusive" => simple_type.min = f64::from_str(value).ok(),
+        "maxInclusive" => simple_type.max = f64::from_str(value).ok(),
+        _ => {}
+      }
+    }
+  }
+  simple_type
+}
+
+/// Returns child nodes of the schema node, that are XML schema elements.
+fn xsd_children<'a>(node: &Node<'a, 'a>) -> impl Iterator<Item = Node<'a, 'a>> {
+  node.children().filter(|n| n.is_element() && n.tag_name().namespace() == Some(URI_XSD))
+}
+
+/// Resolves the prefixed name used in schema node.
+fn qname(node: &Node, value: &str) -> QName {
+  let (prefix, name) = match value.split_once(':') {
+    Some((prefix, name)) => (Some(prefix), name),
+    None => (None, value),
+  };
+  (node.lookup_namespace_uri(prefix).unwrap_or_default().to_string(), name.to_string())
+}
+
+/// Returns the qualified name of the node, nodes without namespace are given the specified namespace.
+fn node_qname(node: &Node, namespace: &str) -> QName {
+  (node.tag_name().namespace().unwrap_or(namespace).to_string(), node.tag_name().name().to_string())
+}
+
+/// Formats the list of expected node names.
+fn expected_names(names: &[String]) -> String {
+  names.iter().map(|name| format!("'{name}'")).collect::<Vec<String>>().join(" or ")
+}
+
+/// Validator of the document against the schema.
+struct Validator<'a> {
+  schema: &'a Schema,
+  /// Namespace of the nodes without namespace.
+  namespace: &'a str,
+  errors: Vec<DmntkError>,
+}
+
+impl<'a> Validator<'a> {
+  /// Validates the root node of the document.
+  fn validate_root(&mut self, node: &Node) {
+    let name = node_qname(node, self.namespace);
+    match self.schema.elements.get(&name).filter(|element| !element.is_abstract) {
+      Some(element) => self.validate_element(node, &element.type_ref),
+      None => self.errors.push(err_unexpected_node(&node_name_pos(node))),
+    }
+  }
+
+  /// Validates the node against its type.
+  fn validate_element(&mut self, node: &Node, type_ref: &TypeRef) {
+    match (type_ref, self.schema.complex_type(type_ref)) {
+      (_, Some(complex_type)) => self.validate_complex_content(node, complex_type),
+      (TypeRef::Any, _) => {}
+      _ => {
+        if let Some(ref child_node) = node.children().find(|n| n.is_element()) {
+          self.errors.push(err_unexpected_child_node(&node_name_pos(child_node), &node_name_pos(node)));
+        }
+      }
+    }
+  }
+
+  /// Validates attributes and child nodes of the node having complex type.
+  fn validate_complex_content(&mut self, node: &Node, complex_type: &ComplexType) {
+    let schema = self.schema;
+    let chain = schema.type_chain(complex_type);
+    self.validate_attributes(node, &chain);
+    let particles = chain.iter().flat_map(|complex_type| complex_type.particles.iter()).collect::<Vec<&Particle>>();
+    let child_nodes = node.children().filter(|n| n.is_element()).collect::<Vec<Node>>();
+    let mut matcher = Matcher {
+      schema,
+      namespace: self.namespace,
+      furthest: 0,
+      missing: vec![],
+    };
+    let mut position = Some(0);
+    for particle in &particles {
+      position = position.and_then(|position| matcher.match_particle(particle, &child_nodes, position, true));
+    }
+    match position {
+      Some(position) if position == child_nodes.len() => {}
+      Some(position) => self.errors.push(err_unexpected_child_node(&node_name_pos(&child_nodes[position]), &node_name_pos(node))),
+      None => {
+        if matcher.missing.is_empty() {
+          let child_node = child_nodes.get(matcher.furthest).unwrap_or(node);
+          self.errors.push(err_unexpected_child_node(&node_name_pos(child_node), &node_name_pos(node)));
+        } else {
+          self.errors.push(err_expected_child_node(&node_name_pos(node), &expected_names(&matcher.missing)));
+        }
+      }
+    }
+    for ref child_node in child_nodes {
+      if let Some(element) = particles.iter().find_map(|particle| self.declaration(particle, child_node)) {
+        self.validate_element(child_node, &element.type_ref);
+      }
+    }
+  }
+
+  /// Validates attributes of the node.
+  fn validate_attributes(&mut self, node: &Node, chain: &[&ComplexType]) {
+    let attributes = chain.iter().flat_map(|complex_type| complex_type.attributes.iter()).collect::<Vec<&Attribute>>();
+    for attribute in node.attributes() {
+      match attribute.namespace() {
+        None => match attributes.iter().find(|declaration| declaration.name == attribute.name()) {
+          Some(declaration) => {
+            if !self.schema.is_valid_value(&declaration.type_name, attribute.value()) {
+              self.errors.push(err_invalid_attribute_type(
+                &node_name_pos(node),
+                attribute.name(),
+                attribute.value(),
+                &declaration.type_name.1,
+              ));
+            }
+          }
+          None => self.errors.push(err_unexpected_attribute(&node_name_pos(node), attribute.name())),
+        },
+        Some(URI_XSI) => {}
+        Some(namespace) => {
+          if !chain
+            .iter()
+            .any(|complex_type| complex_type.any_attribute.as_ref().is_some_and(|excluded| excluded != namespace))
+          {
+            self.errors.push(err_unexpected_attribute(&node_name_pos(node), attribute.name()));
+          }
+        }
+      }
+    }
+    for declaration in attributes.iter().filter(|declaration| declaration.is_required) {
+      if !node.has_attribute(declaration.name.as_str()) {
+        self.errors.push(err_missing_attribute(&node_name_pos(node), &declaration.name));
+      }
+    }
+  }
+
+  /// Returns the element declaration in particle, matching the node.
+  fn declaration(&self, particle: &'a Particle, node: &Node) -> Option<&'a Element> {
+    match &particle.term {
+      Term::Element(element) => (element.name == node_qname(node, self.namespace)).then_some(element),
+      Term::Reference(reference) => self.schema.substitute(reference, &node_qname(node, self.namespace)),
+      Term::Any(_) => None,
+      Term::Sequence(particles) | Term::Choice(particles) => particles.iter().find_map(|particle| self.declaration(particle, node)),
+    }
+  }
+}
+
+/// Matcher of child nodes against content model.
+struct Matcher<'a> {
+  schema: &'a Schema,
+  /// Namespace of the nodes without namespace.
+  namespace: &'a str,
+  /// The furthest position where the content model failed.
+  furthest: usize,
+  /// Names of required nodes missing at the furthest position.
+  missing: Vec<String>,
+}
+
+impl<'a> Matcher<'a> {
+  /// Matches the particle against child nodes starting at the specified position.
+  /// Returns the position after matched nodes or [None] when the particle is not satisfied.
+  /// `required` flag indicates that the enclosing content model can not be satisfied without this particle.
+  fn match_particle(&mut self, particle: &Particle, nodes: &[Node], position: usize, required: bool) -> Option<usize> {
+    let mut count = 0;
+    let mut position = position;
+    while count < particle.max.unwrap_or(usize::MAX) {
+      match self.match_term(&particle.term, nodes, position, required && count < particle.min) {
+        Some(next) if next > position => {
+          position = next;
+          count += 1;
+        }
+        Some(_) => {
+          count = count.max(particle.min);
+          break;
+        }
+        None => break,
+      }
+    }
+    (count >= particle.min).then_some(position)
+  }
+
+  /// Matches the term once.
+  fn match_term(&mut self, term: &Term, nodes: &[Node], position: usize, required: bool) -> Option<usize> {
+    match term {
+      Term::Element(element) => {
+        if nodes.get(position).is_some_and(|node| element.name == node_qname(node, self.namespace)) {
+          Some(position + 1)
+        } else {
+          self.fail(position, &element.name.1, required)
+        }
+      }
+      Term::Reference(reference) => {
+        if nodes
+          .get(position)
+          .is_some_and(|node| self.schema.substitute(reference, &node_qname(node, self.namespace)).is_some())
+        {
+          Some(position + 1)
+        } else {
+          self.fail(position, &reference.1, required)
+        }
+      }
+      Term::Any(excluded) => nodes
+        .get(position)
+        .filter(|node| node.tag_name().namespace().is_some_and(|namespace| namespace != excluded))
+        .map(|_| position + 1),
+      Term::Sequence(particles) => {
+        let mut position = position;
+        for particle in particles {
+          position = self.match_particle(particle, nodes, position, required)?;
+        }
+        Some(position)
+      }
+      Term::Choice(particles) => {
+        let mut satisfied = false;
+        for particle in particles {
+          match self.match_particle(particle, nodes, position, required) {
+            Some(next) if next > position => return Some(next),
+            Some(_) => satisfied = true,
+            None => {}
+          }
+        }
+        satisfied.then_some(position)
+      }
+    }
+  }
+
+  /// Records the missing node at the specified position.
+  fn fail(&mut self, position: usize, name: &str, required: bool) -> Option<usize> {
+    if required {
+      if position > self.furthest {
+        self.furthest = position;
+        self.missing.clear();
+      }
+      if position == self.furthest && !self.missing.iter().any(|missing| missing == name) {
+        self.missing.push(name.to_string());
+      }
+    }
+    None
+  }
+}
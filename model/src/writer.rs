@@ -0,0 +1,699 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! # XML writer for DMN model
+
+use crate::model::*;
+use crate::xml_utils::escape;
+use dmntk_common::HRef;
+use std::fmt::Write;
+
+// XML namespaces of diagram interchange elements
+const URI_DC: &str = "http://www.omg.org/spec/DMN/20180521/DC/";
+const URI_DI: &str = "http://www.omg.org/spec/DMN/20180521/DI/";
+
+/// Writes [Definitions] as DMN XML document.
+///
+/// Elements are written in the order required by the DMN XML schema,
+/// so writing the result of parsing a written document produces the same document.
+/// Identifiers generated during parsing are not written.
+pub fn write(definitions: &Definitions) -> String {
+  let mut buffer = String::new();
+  buffer.push_str(r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#);
+  buffer.push('\n');
+  write_definitions(definitions).write(&mut buffer, 0);
+  buffer
+}
+
+/// XML element built before being written into the output document.
+struct XmlElement {
+  /// Qualified name of the element.
+  name: &'static str,
+  /// Attributes of the element in written order.
+  attributes: Vec<(String, String)>,
+  /// Child elements.
+  children: Vec<XmlElement>,
+  /// Optional textual content, elements with content have no child elements.
+  content: Option<String>,
+  /// Optional XML fragment written verbatim instead of this element.
+  fragment: Option<String>,
+}
+
+impl XmlElement {
+  /// Creates a new element with the specified name.
+  fn new(name: &'static str) -> Self {
+    Self {
+      name,
+      attributes: vec![],
+      children: vec![],
+      content: None,
+      fragment: None,
+    }
+  }
+
+  /// Creates an element written as the specified XML fragment.
+  fn fragment(xml: &str) -> Self {
+    let mut element = Self::new("");
+    element.fragment = Some(xml.to_string());
+    element
+  }
+
+  /// Creates a new element with the specified name and textual content.
+  fn with_content(name: &'static str, content: &str) -> Self {
+    let mut element = Self::new(name);
+    element.content = Some(content.to_string());
+    element
+  }
+
+  /// Adds an attribute.
+  fn set_attribute(&mut self, name: &str, value: &str) {
+    self.attributes.push((name.to_string(), value.to_string()));
+  }
+
+  /// Returns `true` when the element has an attribute with the specified name.
+  fn has_attribute(&self, name: &str) -> bool {
+    self.attributes.iter().any(|(attribute_name, _)| attribute_name == name)
+  }
+
+  /// Adds an attribute when the value is present.
+  fn set_optional_attribute(&mut self, name: &'static str, value: &Option<String>) {
+    if let Some(value) = value {
+      self.set_attribute(name, value);
+    }
+  }
+
+  /// Adds a boolean attribute when its value differs from the default `false`.
+  fn set_flag_attribute(&mut self, name: &'static str, value: bool) {
+    if value {
+      self.set_attribute(name, "true");
+    }
+  }
+
+  /// Adds a child element.
+  fn add_child(&mut self, child: XmlElement) {
+    self.children.push(child);
+  }
+
+  /// Adds a child element when present.
+  fn add_optional_child(&mut self, child: Option<XmlElement>) {
+    if let Some(child) = child {
+      self.children.push(child);
+    }
+  }
+
+  /// Adds a child element with textual content when the content is present.
+  fn add_optional_content_child(&mut self, name: &'static str, content: &Option<String>) {
+    if let Some(content) = content {
+      self.add_child(XmlElement::with_content(name, content));
+    }
+  }
+
+  /// Adds child elements.
+  fn add_children(&mut self, children: impl IntoIterator<Item = XmlElement>) {
+    self.children.extend(children);
+  }
+
+  /// Writes this element into the buffer, indented with the specified number of spaces.
+  fn write(&self, buffer: &mut String, indent: usize) {
+    if let Some(fragment) = &self.fragment {
+      let _ = writeln!(buffer, "{:indent$}{fragment}", "");
+      return;
+    }
+    let _ = write!(buffer, "{:indent$}<{}", "", self.name);
+    for (name, value) in &self.attributes {
+      let _ = write!(buffer, r#" {name}="{}""#, escape(value));
+    }
+    if let Some(content) = &self.content {
+      let _ = writeln!(buffer, ">{}</{}>", escape(content), self.name);
+    } else if self.children.is_empty() {
+      buffer.push_str("/>\n");
+    } else {
+      buffer.push_str(">\n");
+      for child in &self.children {
+        child.write(buffer, indent + 4);
+      }
+      let _ = writeln!(buffer, "{:indent$}</{}>", "", self.name);
+    }
+  }
+}
+
+/// Creates an element with attributes and child elements common to all DMN elements.
+fn dmn_element(name: &'static str, model_element: &impl DmnElement) -> XmlElement {
+  let mut element = XmlElement::new(name);
+  element.set_optional_attribute("id", &model_element.opt_id().cloned());
+  write_dmn_element(&mut element, model_element);
+  element
+}
+
+/// Creates an element with attributes and child elements common to all named DMN elements.
+fn named_element(name: &'static str, model_element: &impl NamedElement) -> XmlElement {
+  let mut element = XmlElement::new(name);
+  element.set_optional_attribute("id", &model_element.opt_id().cloned());
+  element.set_attribute("name", model_element.name());
+  write_dmn_element(&mut element, model_element);
+  element
+}
+
+/// Writes label, extension attributes, description and extension elements of the DMN element.
+fn write_dmn_element(element: &mut XmlElement, model_element: &impl DmnElement) {
+  element.set_optional_attribute("label", model_element.label());
+  for extension_attribute in model_element.extension_attributes() {
+    let prefix = extension_attribute.prefix();
+    let declaration = format!("xmlns:{prefix}");
+    if prefix != "xml" && !element.has_attribute(&declaration) {
+      element.set_attribute(&declaration, extension_attribute.namespace());
+    }
+    element.set_attribute(&format!("{prefix}:{}", extension_attribute.name()), extension_attribute.value());
+  }
+  element.add_optional_content_child("description", model_element.description());
+  if !model_element.extension_elements().is_empty() {
+    let mut extension_elements = XmlElement::new("extensionElements");
+    extension_elements.add_children(
+      model_element
+        .extension_elements()
+        .iter()
+        .map(|extension_element| XmlElement::fragment(extension_element.xml())),
+    );
+    element.add_child(extension_elements);
+  }
+}
+
+/// Creates an element referencing another element using `href` attribute.
+fn href_element(name: &'static str, href: &HRef) -> XmlElement {
+  let mut element = XmlElement::new(name);
+  let namespace = href.namespace().map(|namespace| namespace.as_str()).unwrap_or_default();
+  element.set_attribute("href", &format!("{namespace}#{}", href.id()));
+  element
+}
+
+fn write_definitions(definitions: &Definitions) -> XmlElement {
+  let version = definitions.dmn_version;
+  let mut element = XmlElement::new("definitions");
+  element.set_attribute("xmlns", version.model_uri());
+  if definitions.dmndi.is_some() {
+    element.set_attribute("xmlns:dmndi", dmndi_uri(version));
+    element.set_attribute("xmlns:dc", URI_DC);
+    element.set_attribute("xmlns:di", URI_DI);
+  }
+  element.set_attribute("namespace", &definitions.namespace);
+  element.set_attribute("name", &definitions.name);
+  element.set_optional_attribute("id", &definitions.opt_id().cloned());
+  element.set_optional_attribute("expressionLanguage", &definitions.expression_language);
+  element.set_optional_attribute("typeLanguage", &definitions.type_language);
+  element.set_optional_attribute("exporter", &definitions.exporter);
+  element.set_optional_attribute("exporterVersion", &definitions.exporter_version);
+  write_dmn_element(&mut element, definitions);
+  element.add_children(definitions.imports.iter().map(write_import));
+  element.add_children(
+    definitions
+      .item_definitions
+      .iter()
+      .map(|item_definition| write_item_definition("itemDefinition", item_definition)),
+  );
+  element.add_children(definitions.drg_elements.iter().map(write_drg_element));
+  element.add_children(definitions.business_context_elements.iter().map(write_business_context_element));
+  element.add_optional_child(definitions.dmndi.as_ref().map(write_dmndi));
+  element
+}
+
+/// Returns the XML namespace of diagram interchange elements in the specified DMN version.
+fn dmndi_uri(version: DmnVersion) -> &'static str {
+  match version {
+    DmnVersion::V1_1 | DmnVersion::V1_2 => "http://www.omg.org/spec/DMN/20180521/DMNDI/",
+    DmnVersion::V1_3 | DmnVersion::V1_4 => "https://www.omg.org/spec/DMN/20191111/DMNDI/",
+    DmnVersion::V1_5 => "https://www.omg.org/spec/DMN/20230324/DMNDI/",
+  }
+}
+
+fn write_import(import: &Import) -> XmlElement {
+  let mut element = named_element("import", import);
+  element.set_attribute("namespace", &import.namespace);
+  element.set_optional_attribute("locationURI", &import.location_uri);
+  element.set_attribute("importType", &import.import_type);
+  element
+}
+
+fn write_item_definition(name: &'static str, item_definition: &ItemDefinition) -> XmlElement {
+  let mut element = named_element(name, item_definition);
+  element.set_optional_attribute("typeLanguage", &item_definition.type_language);
+  element.set_flag_attribute("isCollection", item_definition.is_collection);
+  element.add_optional_content_child("typeRef", &item_definition.type_ref);
+  element.add_optional_child(item_definition.allowed_values.as_ref().map(|unary_tests| write_unary_tests("allowedValues", unary_tests)));
+  element.add_children(
+    item_definition
+      .item_components
+      .iter()
+      .map(|item_component| write_item_definition("itemComponent", item_component)),
+  );
+  if let Some(function_item) = &item_definition.function_item {
+    let mut function_item_element = XmlElement::new("functionItem");
+    function_item_element.set_optional_attribute("outputTypeRef", &function_item.output_type_ref);
+    function_item_element.add_children(function_item.parameters.iter().map(|parameter| write_information_item("parameters", parameter)));
+    element.add_child(function_item_element);
+  }
+  element
+}
+
+fn write_unary_tests(name: &'static str, unary_tests: &UnaryTests) -> XmlElement {
+  let mut element = XmlElement::new(name);
+  element.set_optional_attribute("expressionLanguage", &unary_tests.expression_language);
+  element.add_optional_content_child("text", &unary_tests.text);
+  element
+}
+
+fn write_information_item(name: &'static str, information_item: &InformationItem) -> XmlElement {
+  let mut element = named_element(name, information_item);
+  element.set_attribute("typeRef", &information_item.type_ref);
+  element
+}
+
+fn write_drg_element(drg_element: &DrgElement) -> XmlElement {
+  match drg_element {
+    DrgElement::Decision(decision) => write_decision(decision),
+    DrgElement::InputData(input_data) => write_input_data(input_data),
+    DrgElement::BusinessKnowledgeModel(business_knowledge_model) => write_business_knowledge_model(business_knowledge_model),
+    DrgElement::DecisionService(decision_service) => write_decision_service(decision_service),
+    DrgElement::KnowledgeSource(knowledge_source) => write_knowledge_source(knowledge_source),
+  }
+}
+
+fn write_decision(decision: &Decision) -> XmlElement {
+  let mut element = named_element("decision", decision);
+  element.add_optional_content_child("question", &decision.question);
+  element.add_optional_content_child("allowedAnswers", &decision.allowed_answers);
+  element.add_child(write_information_item("variable", &decision.variable));
+  element.add_children(decision.information_requirements.iter().map(write_information_requirement));
+  element.add_children(decision.knowledge_requirements.iter().map(write_knowledge_requirement));
+  element.add_children(decision.authority_requirements.iter().map(write_authority_requirement));
+  element.add_optional_child(decision.decision_logic.as_ref().map(write_expression_instance));
+  element
+}
+
+fn write_input_data(input_data: &InputData) -> XmlElement {
+  let mut element = named_element("inputData", input_data);
+  element.add_child(write_information_item("variable", &input_data.variable));
+  element
+}
+
+fn write_business_knowledge_model(business_knowledge_model: &BusinessKnowledgeModel) -> XmlElement {
+  let mut element = named_element("businessKnowledgeModel", business_knowledge_model);
+  element.add_child(write_information_item("variable", &business_knowledge_model.variable));
+  element.add_optional_child(
+    business_knowledge_model
+      .encapsulated_logic
+      .as_ref()
+      .map(|function_definition| write_function_definition("encapsulatedLogic", function_definition)),
+  );
+  element.add_children(business_knowledge_model.knowledge_requirements.iter().map(write_knowledge_requirement));
+  element.add_children(business_knowledge_model.authority_requirements.iter().map(write_authority_requirement));
+  element
+}
+
+fn write_decision_service(decision_service: &DecisionService) -> XmlElement {
+  let mut element = named_element("decisionService", decision_service);
+  element.add_child(write_information_item("variable", &decision_service.variable));
+  element.add_children(decision_service.output_decisions.iter().map(|href| href_element("outputDecision", href)));
+  element.add_children(decision_service.encapsulated_decisions.iter().map(|href| href_element("encapsulatedDecision", href)));
+  element.add_children(decision_service.input_decisions.iter().map(|href| href_element("inputDecision", href)));
+  element.add_children(decision_service.input_data.iter().map(|href| href_element("inputData", href)));
+  element
+}
+
+fn write_knowledge_source(knowledge_source: &KnowledgeSource) -> XmlElement {
+  let mut element = named_element("knowledgeSource", knowledge_source);
+  element.add_children(knowledge_source.authority_requirements.iter().map(write_authority_requirement));
+  element
+}
+
+fn write_information_requirement(requirement: &InformationRequirement) -> XmlElement {
+  let mut element = dmn_element("informationRequirement", requirement);
+  element.add_optional_child(requirement.required_decision.as_ref().map(|href| href_element("requiredDecision", href)));
+  element.add_optional_child(requirement.required_input.as_ref().map(|href| href_element("requiredInput", href)));
+  element
+}
+
+fn write_knowledge_requirement(requirement: &KnowledgeRequirement) -> XmlElement {
+  let mut element = dmn_element("knowledgeRequirement", requirement);
+  element.add_child(href_element("requiredKnowledge", &requirement.required_knowledge));
+  element
+}
+
+fn write_authority_requirement(requirement: &AuthorityRequirement) -> XmlElement {
+  let mut element = dmn_element("authorityRequirement", requirement);
+  element.add_optional_child(requirement.required_decision.as_ref().map(|href| href_element("requiredDecision", href)));
+  element.add_optional_child(requirement.required_input.as_ref().map(|href| href_element("requiredInput", href)));
+  element.add_optional_child(requirement.required_authority.as_ref().map(|href| href_element("requiredAuthority", href)));
+  element
+}
+
+fn write_business_context_element(business_context_element: &BusinessContextElementInstance) -> XmlElement {
+  match business_context_element {
+    BusinessContextElementInstance::PerformanceIndicator(indicator) => {
+      let mut element = named_element("performanceIndicator", indicator);
+      element.set_optional_attribute("URI", &indicator.uri);
+      element.add_children(indicator.impacting_decisions.iter().map(|href| href_element("impactingDecision", href)));
+      element
+    }
+    BusinessContextElementInstance::OrganizationUnit(unit) => {
+      let mut element = named_element("organizationUnit", unit);
+      element.set_optional_attribute("URI", &unit.uri);
+      element.add_children(unit.decisions_made.iter().map(|href| href_element("decisionMade", href)));
+      element.add_children(unit.decisions_owned.iter().map(|href| href_element("decisionOwned", href)));
+      element
+    }
+  }
+}
+
+fn write_expression_instance(expression_instance: &ExpressionInstance) -> XmlElement {
+  match expression_instance {
+    ExpressionInstance::Conditional(conditional) => write_conditional(conditional),
+    ExpressionInstance::Context(context) => write_context(context),
+    ExpressionInstance::DecisionTable(decision_table) => write_decision_table(decision_table),
+    ExpressionInstance::Every(quantified) => write_quantified("every", quantified),
+    ExpressionInstance::Filter(filter) => write_filter(filter),
+    ExpressionInstance::For(for_expression) => write_for(for_expression),
+    ExpressionInstance::FunctionDefinition(function_definition) => write_function_definition("functionDefinition", function_definition),
+    ExpressionInstance::Invocation(invocation) => write_invocation(invocation),
+    ExpressionInstance::List(list) => write_list("list", list),
+    ExpressionInstance::LiteralExpression(literal_expression) => write_literal_expression(literal_expression),
+    ExpressionInstance::Relation(relation) => write_relation(relation),
+    ExpressionInstance::Some(quantified) => write_quantified("some", quantified),
+  }
+}
+
+/// Creates an element with attributes and child elements common to all expressions.
+fn expression_element(name: &'static str, expression: &impl Expression) -> XmlElement {
+  let mut element = dmn_element(name, expression);
+  element.set_optional_attribute("typeRef", expression.type_ref());
+  element
+}
+
+fn write_child_expression(name: &'static str, child_expression: &ChildExpression) -> XmlElement {
+  let mut element = XmlElement::new(name);
+  if let DmnId::Provided(id) = &child_expression.id {
+    element.set_attribute("id", id);
+  }
+  element.set_optional_attribute("typeRef", &child_expression.type_ref);
+  element.add_child(write_expression_instance(&child_expression.value));
+  element
+}
+
+fn write_conditional(conditional: &Conditional) -> XmlElement {
+  let mut element = expression_element("conditional", conditional);
+  element.add_child(write_child_expression("if", &conditional.if_expression));
+  element.add_child(write_child_expression("then", &conditional.then_expression));
+  element.add_child(write_child_expression("else", &conditional.else_expression));
+  element
+}
+
+fn write_context(context: &Context) -> XmlElement {
+  let mut element = XmlElement::new("context");
+  for context_entry in &context.context_entries {
+    let mut context_entry_element = XmlElement::new("contextEntry");
+    context_entry_element.add_optional_child(context_entry.variable.as_ref().map(|variable| write_information_item("variable", variable)));
+    context_entry_element.add_child(write_expression_instance(&context_entry.value));
+    element.add_child(context_entry_element);
+  }
+  element
+}
+
+fn write_decision_table(decision_table: &DecisionTable) -> XmlElement {
+  let mut element = XmlElement::new("decisionTable");
+  match decision_table.hit_policy {
+    HitPolicy::Unique => {}
+    HitPolicy::Any => element.set_attribute("hitPolicy", "ANY"),
+    HitPolicy::Priority => element.set_attribute("hitPolicy", "PRIORITY"),
+    HitPolicy::First => element.set_attribute("hitPolicy", "FIRST"),
+    HitPolicy::RuleOrder => element.set_attribute("hitPolicy", "RULE ORDER"),
+    HitPolicy::OutputOrder => element.set_attribute("hitPolicy", "OUTPUT ORDER"),
+    HitPolicy::Collect(aggregator) => {
+      element.set_attribute("hitPolicy", "COLLECT");
+      match aggregator {
+        BuiltinAggregator::List => {}
+        BuiltinAggregator::Count => element.set_attribute("aggregation", "COUNT"),
+        BuiltinAggregator::Sum => element.set_attribute("aggregation", "SUM"),
+        BuiltinAggregator::Min => element.set_attribute("aggregation", "MIN"),
+        BuiltinAggregator::Max => element.set_attribute("aggregation", "MAX"),
+      }
+    }
+  }
+  match decision_table.preferred_orientation {
+    DecisionTableOrientation::RuleAsRow => {}
+    DecisionTableOrientation::RuleAsColumn => element.set_attribute("preferredOrientation", "Rule-as-Column"),
+    DecisionTableOrientation::CrossTable => element.set_attribute("preferredOrientation", "CrossTable"),
+  }
+  element.set_optional_attribute("outputLabel", &decision_table.output_label);
+  for input_clause in &decision_table.input_clauses {
+    let mut input_element = XmlElement::new("input");
+    let mut input_expression_element = XmlElement::new("inputExpression");
+    input_expression_element.add_child(XmlElement::with_content("text", &input_clause.input_expression));
+    input_element.add_child(input_expression_element);
+    input_element.add_optional_child(input_clause.allowed_input_values.as_ref().map(|text| text_element("inputValues", text)));
+    element.add_child(input_element);
+  }
+  for output_clause in &decision_table.output_clauses {
+    let mut output_element = XmlElement::new("output");
+    output_element.set_optional_attribute("name", &output_clause.name);
+    output_element.set_optional_attribute("typeRef", &output_clause.type_ref);
+    output_element.add_optional_child(output_clause.allowed_output_values.as_ref().map(|text| text_element("outputValues", text)));
+    output_element.add_optional_child(output_clause.default_output_entry.as_ref().map(|text| text_element("defaultOutputEntry", text)));
+    element.add_child(output_element);
+  }
+  for rule in &decision_table.rules {
+    let mut rule_element = XmlElement::new("rule");
+    rule_element.add_children(rule.input_entries.iter().map(|input_entry| text_element("inputEntry", &input_entry.text)));
+    rule_element.add_children(rule.output_entries.iter().map(|output_entry| text_element("outputEntry", &output_entry.text)));
+    element.add_child(rule_element);
+  }
+  element
+}
+
+/// Creates an element containing a single `text` element.
+fn text_element(name: &'static str, text: &str) -> XmlElement {
+  let mut element = XmlElement::new(name);
+  element.add_child(XmlElement::with_content("text", text));
+  element
+}
+
+fn write_filter(filter: &Filter) -> XmlElement {
+  let mut element = expression_element("filter", filter);
+  element.add_child(write_child_expression("in", &filter.in_expression));
+  element.add_child(write_child_expression("match", &filter.match_expression));
+  element
+}
+
+fn write_for(for_expression: &For) -> XmlElement {
+  let mut element = expression_element("for", for_expression);
+  element.set_attribute("iteratorVariable", &for_expression.iterator_variable);
+  element.add_child(write_child_expression("in", &for_expression.in_expression));
+  element.add_child(write_child_expression("return", &for_expression.return_expression));
+  element
+}
+
+fn write_quantified(name: &'static str, quantified: &Quantified) -> XmlElement {
+  let mut element = expression_element(name, quantified);
+  element.set_attribute("iteratorVariable", &quantified.iterator_variable);
+  element.add_child(write_child_expression("in", &quantified.in_expression));
+  element.add_child(write_child_expression("satisfies", &quantified.satisfies_expression));
+  element
+}
+
+fn write_function_definition(name: &'static str, function_definition: &FunctionDefinition) -> XmlElement {
+  let mut element = expression_element(name, function_definition);
+  match function_definition.kind {
+    FunctionKind::Feel => {}
+    FunctionKind::Java => element.set_attribute("kind", "Java"),
+    FunctionKind::Pmml => element.set_attribute("kind", "PMML"),
+  }
+  element.add_children(
+    function_definition
+      .formal_parameters
+      .iter()
+      .map(|parameter| write_information_item("formalParameter", parameter)),
+  );
+  element.add_optional_child(function_definition.body.as_ref().map(write_expression_instance));
+  element
+}
+
+fn write_invocation(invocation: &Invocation) -> XmlElement {
+  let mut element = XmlElement::new("invocation");
+  element.add_child(write_expression_instance(&invocation.called_function));
+  for binding in &invocation.bindings {
+    let mut binding_element = XmlElement::new("binding");
+    binding_element.add_child(write_information_item("parameter", &binding.parameter));
+    binding_element.add_optional_child(binding.binding_formula.as_ref().map(write_expression_instance));
+    element.add_child(binding_element);
+  }
+  element
+}
+
+fn write_list(name: &'static str, list: &List) -> XmlElement {
+  let mut element = expression_element(name, list);
+  element.add_children(list.elements.iter().map(write_expression_instance));
+  element
+}
+
+fn write_literal_expression(literal_expression: &LiteralExpression) -> XmlElement {
+  let mut element = expression_element("literalExpression", literal_expression);
+  element.set_optional_attribute("expressionLanguage", &literal_expression.expression_language);
+  element.add_optional_content_child("text", &literal_expression.text);
+  element
+}
+
+fn write_relation(relation: &Relation) -> XmlElement {
+  let mut element = expression_element("relation", relation);
+  element.add_children(relation.columns.iter().map(|column| write_information_item("column", column)));
+  element.add_children(relation.rows.iter().map(|row| write_list("row", row)));
+  element
+}
+
+fn write_dmndi(dmndi: &Dmndi) -> XmlElement {
+  let mut element = XmlElement::new("dmndi:DMNDI");
+  element.add_children(dmndi.diagrams.iter().map(write_diagram));
+  element.add_children(dmndi.styles.iter().map(|style| write_style("dmndi:DMNStyle", style)));
+  element
+}
+
+fn write_diagram(diagram: &DmnDiagram) -> XmlElement {
+  let mut element = XmlElement::new("dmndi:DMNDiagram");
+  element.set_optional_attribute("id", &diagram.id);
+  if !diagram.name.is_empty() {
+    element.set_attribute("name", &diagram.name);
+  }
+  element.set_attribute("resolution", &diagram.resolution.to_string());
+  element.set_optional_attribute("sharedStyle", &diagram.shared_style);
+  element.add_optional_child(diagram.local_style.as_ref().map(|style| write_style("dmndi:localStyle", style)));
+  if let Some(size) = &diagram.size {
+    let mut size_element = XmlElement::new("dmndi:Size");
+    size_element.set_attribute("width", &size.width.to_string());
+    size_element.set_attribute("height", &size.height.to_string());
+    element.add_child(size_element);
+  }
+  for diagram_element in &diagram.diagram_elements {
+    element.add_child(match diagram_element {
+      DmnDiagramElement::DmnShape(shape) => write_shape(shape),
+      DmnDiagramElement::DmnEdge(edge) => write_edge(edge),
+    });
+  }
+  element
+}
+
+fn write_shape(shape: &DmnShape) -> XmlElement {
+  let mut element = XmlElement::new("dmndi:DMNShape");
+  element.set_optional_attribute("id", &shape.id);
+  element.set_optional_attribute("dmnElementRef", &shape.dmn_element_ref);
+  element.set_flag_attribute("isCollapsed", shape.is_collapsed);
+  element.set_optional_attribute("sharedStyle", &shape.shared_style);
+  element.add_optional_child(shape.local_style.as_ref().map(|style| write_style("dmndi:localStyle", style)));
+  element.add_child(write_bounds(&shape.bounds));
+  element.add_optional_child(shape.label.as_ref().map(write_label));
+  if let Some(divider_line) = &shape.decision_service_divider_line {
+    let mut divider_line_element = XmlElement::new("dmndi:DMNDecisionServiceDividerLine");
+    divider_line_element.set_optional_attribute("id", &divider_line.id);
+    divider_line_element.add_optional_child(divider_line.local_style.as_ref().map(|style| write_style("dmndi:localStyle", style)));
+    divider_line_element.add_children(divider_line.way_points.iter().map(write_way_point));
+    element.add_child(divider_line_element);
+  }
+  element
+}
+
+fn write_edge(edge: &DmnEdge) -> XmlElement {
+  let mut element = XmlElement::new("dmndi:DMNEdge");
+  element.set_optional_attribute("id", &edge.id);
+  element.set_optional_attribute("dmnElementRef", &edge.dmn_element_ref);
+  element.set_optional_attribute("sourceElement", &edge.source_element);
+  element.set_optional_attribute("targetElement", &edge.target_element);
+  element.set_optional_attribute("sharedStyle", &edge.shared_style);
+  element.add_optional_child(edge.local_style.as_ref().map(|style| write_style("dmndi:localStyle", style)));
+  element.add_children(edge.way_points.iter().map(write_way_point));
+  element.add_optional_child(edge.label.as_ref().map(write_label));
+  element
+}
+
+fn write_label(label: &DmnLabel) -> XmlElement {
+  let mut element = XmlElement::new("dmndi:DMNLabel");
+  element.set_optional_attribute("sharedStyle", &label.shared_style);
+  element.add_optional_child(label.bounds.as_ref().map(write_bounds));
+  element.add_optional_content_child("dmndi:Text", &label.text);
+  element
+}
+
+fn write_bounds(bounds: &DcBounds) -> XmlElement {
+  let mut element = XmlElement::new("dc:Bounds");
+  element.set_attribute("x", &bounds.x.to_string());
+  element.set_attribute("y", &bounds.y.to_string());
+  element.set_attribute("width", &bounds.width.to_string());
+  element.set_attribute("height", &bounds.height.to_string());
+  element
+}
+
+fn write_way_point(point: &DcPoint) -> XmlElement {
+  let mut element = XmlElement::new("di:waypoint");
+  element.set_attribute("x", &point.x.to_string());
+  element.set_attribute("y", &point.y.to_string());
+  element
+}
+
+fn write_style(name: &'static str, style: &DmnStyle) -> XmlElement {
+  let mut element = XmlElement::new(name);
+  element.set_optional_attribute("id", &style.id);
+  element.set_attribute("fontFamily", &style.font_family);
+  element.set_optional_attribute("fontSize", &style.font_size.map(|font_size| font_size.to_string()));
+  element.set_flag_attribute("fontItalic", style.font_italic);
+  element.set_flag_attribute("fontBold", style.font_bold);
+  element.set_flag_attribute("fontUnderline", style.font_underline);
+  element.set_flag_attribute("fontStrikeThrough", style.font_strike_through);
+  element.set_optional_attribute("labelHorizontalAlignment", &style.label_horizontal_alignment.map(alignment_kind));
+  element.set_optional_attribute("labelVerticalAlignment", &style.label_vertical_alignment.map(alignment_kind));
+  element.add_optional_child(style.fill_color.as_ref().map(|color| write_color("dmndi:FillColor", color)));
+  element.add_optional_child(style.stroke_color.as_ref().map(|color| write_color("dmndi:StrokeColor", color)));
+  element.add_optional_child(style.font_color.as_ref().map(|color| write_color("dmndi:FontColor", color)));
+  element
+}
+
+/// Returns the text representation of the alignment kind.
+fn alignment_kind(alignment_kind: DcAlignmentKind) -> String {
+  match alignment_kind {
+    DcAlignmentKind::Start => "start",
+    DcAlignmentKind::End => "end",
+    DcAlignmentKind::Center => "center",
+  }
+  .to_string()
+}
+
+fn write_color(name: &'static str, color: &DcColor) -> XmlElement {
+  let mut element = XmlElement::new(name);
+  element.set_attribute("red", &color.red.to_string());
+  element.set_attribute("green", &color.green.to_string());
+  element.set_attribute("blue", &color.blue.to_string());
+  element
+}
@@ -34,9 +34,14 @@
 
 use crate::errors::*;
 use dmntk_common::Result;
-use roxmltree::Node;
+use roxmltree::{Document, Node};
+use std::collections::HashMap;
+use std::fmt::Write;
 use std::str::FromStr;
 
+/// Namespace implicitly bound to `xml` prefix.
+const URI_XML: &str = "http://www.w3.org/XML/1998/namespace";
+
 /// Returns the value of the required attribute.
 pub fn required_attribute(node: &Node, attr_name: &str) -> Result<String> {
   if let Some(attr_value) = node.attribute(attr_name) {
@@ -48,12 +53,12 @@ pub fn required_attribute(node: &Node, attr_name: &str) -> Result<String> {
 
 /// Returns the value of the mandatory color attribute.
 pub fn required_color_part(node: &Node, attr_name: &str) -> Result<u8> {
-  u8::from_str(&required_attribute(node, attr_name)?).map_err(|e| err_invalid_color_value(&e.to_string()))
+  u8::from_str(&required_attribute(node, attr_name)?).map_err(|e| err_invalid_color_value(&e.to_string(), &node_name_pos(node)))
 }
 
 /// Returns the value of the mandatory double value.
 pub fn required_double(node: &Node, attr_name: &str) -> Result<f64> {
-  f64::from_str(&required_attribute(node, attr_name)?).map_err(|e| err_invalid_double_value(&e.to_string()))
+  f64::from_str(&required_attribute(node, attr_name)?).map_err(|e| err_invalid_double_value(&e.to_string(), &node_name_pos(node)))
 }
 
 /// Returns the value of the optional attribute.
@@ -86,7 +91,7 @@ pub fn required_content(node: &Node) -> Result<String> {
   if let Some(text) = node.text() {
     Ok(text.to_owned())
   } else {
-    Err(err_xml_expected_mandatory_text_content(node.tag_name().name()))
+    Err(err_xml_expected_mandatory_text_content(&node_name_pos(node)))
   }
 }
 
@@ -137,6 +142,115 @@ pub fn optional_child_optional_content(node: &Node, child_name: &str) -> Option<
 }
 
 /// Utility function that returns the node's name with its position in the original document.
+/// Returns the positions of elements in XML document, indexed by element identifiers.
+/// The position is the line and column (both starting from 1) where the element begins,
+/// no positions are returned when the document is not a well-formed XML.
+pub fn element_positions(xml: &str) -> HashMap<String, (u32, u32)> {
+  let Ok(document) = Document::parse(xml) else {
+    return HashMap::new();
+  };
+  document
+    .descendants()
+    .filter_map(|node| {
+      node.attribute("id").map(|id| {
+        let position = document.text_pos_at(node.range().start);
+        (id.to_string(), (position.row, position.col))
+      })
+    })
+    .collect()
+}
+
 pub fn node_name_pos(node: &Node) -> String {
   format!("'{}' at [{}]", node.tag_name().name(), node.document().text_pos_at(node.range().start))
 }
+
+/// Replaces characters that are not allowed in XML content and attribute values.
+pub fn escape(value: &str) -> String {
+  let mut escaped = String::with_capacity(value.len());
+  for ch in value.chars() {
+    match ch {
+      '&' => escaped.push_str("&amp;"),
+      '<' => escaped.push_str("&lt;"),
+      '>' => escaped.push_str("&gt;"),
+      '"' => escaped.push_str("&quot;"),
+      '\'' => escaped.push_str("&apos;"),
+      '\r' => escaped.push_str("&#13;"),
+      other => escaped.push(other),
+    }
+  }
+  escaped
+}
+
+/// Returns the XML fragment containing specified element node with all its descendants.
+/// All namespaces used in the fragment are declared in the fragment,
+/// so the fragment can be parsed without the enclosing document.
+pub fn xml_fragment(node: &Node) -> String {
+  let mut buffer = String::new();
+  let mut bindings = vec![(None, "".to_string()), (Some("xml".to_string()), URI_XML.to_string())];
+  write_fragment(node, &mut bindings, &mut buffer);
+  buffer
+}
+
+/// Writes the element node with its descendants, `bindings` contain prefixes already declared in the fragment.
+fn write_fragment(node: &Node, bindings: &mut Vec<(Option<String>, String)>, buffer: &mut String) {
+  let scope = bindings.len();
+  let mut declarations = String::new();
+  let mut attributes = String::new();
+  let element_namespace = node.tag_name().namespace().unwrap_or_default();
+  let element_prefix = node.lookup_prefix(element_namespace).filter(|_| !element_namespace.is_empty());
+  bind_prefix(element_prefix, element_namespace, bindings, &mut declarations);
+  let element_name = qualified_name(element_prefix, node.tag_name().name());
+  for attribute in node.attributes() {
+    let attribute_prefix = attribute.namespace().and_then(|namespace| {
+      let prefix = node.lookup_prefix(namespace);
+      bind_prefix(prefix, namespace, bindings, &mut declarations);
+      prefix
+    });
+    let _ = write!(attributes, r#" {}="{}""#, qualified_name(attribute_prefix, attribute.name()), escape(attribute.value()));
+  }
+  let _ = write!(buffer, "<{element_name}{declarations}{attributes}");
+  if node.has_children() {
+    buffer.push('>');
+    for child_node in node.children() {
+      if child_node.is_element() {
+        write_fragment(&child_node, bindings, buffer);
+      } else if child_node.is_comment() {
+        let _ = write!(buffer, "<!--{}-->", child_node.text().unwrap_or_default());
+      } else if child_node.is_text() {
+        buffer.push_str(&escape(child_node.text().unwrap_or_default()));
+      }
+    }
+    let _ = write!(buffer, "</{element_name}>");
+  } else {
+    buffer.push_str("/>");
+  }
+  bindings.truncate(scope);
+}
+
+/// Declares the namespace in the fragment, when the prefix is not bound to this namespace yet.
+fn bind_prefix(prefix: Option<&str>, namespace: &str, bindings: &mut Vec<(Option<String>, String)>, declarations: &mut String) {
+  let bound = bindings
+    .iter()
+    .rev()
+    .find(|(bound_prefix, _)| bound_prefix.as_deref() == prefix)
+    .is_some_and(|(_, bound_namespace)| bound_namespace == namespace);
+  if !bound {
+    match prefix {
+      Some(prefix) => {
+        let _ = write!(declarations, r#" xmlns:{prefix}="{}""#, escape(namespace));
+      }
+      None => {
+        let _ = write!(declarations, r#" xmlns="{}""#, escape(namespace));
+      }
+    }
+    bindings.push((prefix.map(|prefix| prefix.to_string()), namespace.to_string()));
+  }
+}
+
+/// Returns the name prefixed with optional namespace prefix.
+fn qualified_name(prefix: Option<&str>, name: &str) -> String {
+  match prefix {
+    Some(prefix) => format!("{prefix}:{name}"),
+    None => name.to_string(),
+  }
+}
@@ -30,11 +30,12 @@
  * limitations under the License.
  */
 
-#[cfg(feature = "tck")]
 #[macro_use]
 extern crate dmntk_macros;
 
 mod data;
+#[cfg_attr(feature = "tck", allow(dead_code))]
+mod errors;
 mod server;
 
 #[cfg(feature = "tck")]
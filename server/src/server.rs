@@ -31,10 +31,14 @@
  */
 
 use crate::data::ApplicationData;
+use crate::errors::*;
+use actix_web::http::StatusCode;
 use actix_web::{post, web, App, HttpResponse, HttpServer};
-use dmntk_common::{color_blue, color_reset, color_yellow, ColorMode, Jsonify};
-use dmntk_feel::FeelScope;
+use dmntk_common::{color_blue, color_reset, color_yellow, ColorMode, DmntkError, Jsonify, Result};
+use dmntk_feel::values::Value;
+use dmntk_feel::{FeelScope, QualifiedName};
 use dmntk_workspace::Workspace;
+use serde_json::json;
 use std::borrow::Borrow;
 use std::net::IpAddr;
 use std::path::{Path, PathBuf};
@@ -55,11 +59,13 @@ const CONTENT_TYPE: &str = "application/json";
 async fn evaluate_by_name(params: web::Path<(String, String)>, request_body: String, data: web::Data<ApplicationData>) -> HttpResponse {
   let workspace: &Workspace = data.workspace.borrow();
   let (rdnn, invocable_name) = params.into_inner();
-  let result =
-    dmntk_evaluator::evaluate_context(&FeelScope::default(), &request_body).and_then(|input_data| workspace.evaluate_invocable_by_name(&rdnn, &invocable_name, &input_data));
-  match result {
+  let input_data = match dmntk_evaluator::evaluate_context(&FeelScope::default(), &request_body) {
+    Ok(input_data) => input_data,
+    Err(reason) => return error_response(StatusCode::BAD_REQUEST, reason),
+  };
+  match workspace.evaluate_invocable_by_name(&rdnn, &invocable_name, &input_data) {
     Ok(value) => HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"data":{}}}"#, value.jsonify())),
-    Err(reason) => HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"errors":[{{"detail":"{reason}"}}]}}"#)),
+    Err(reason) => error_response(StatusCode::NOT_FOUND, reason),
   }
 }
 
@@ -69,13 +75,69 @@ async fn evaluate_by_name(params: web::Path<(String, String)>, request_body: Str
 async fn evaluate_by_id(params: web::Path<(String, String)>, request_body: String, data: web::Data<ApplicationData>) -> HttpResponse {
   let workspace: &Workspace = data.workspace.borrow();
   let (rdnn, invocable_id) = params.into_inner();
-  let result = dmntk_evaluator::evaluate_context(&FeelScope::default(), &request_body).and_then(|input_data| workspace.evaluate_invocable_by_id(&rdnn, &invocable_id, &input_data));
-  match result {
+  let input_data = match dmntk_evaluator::evaluate_context(&FeelScope::default(), &request_body) {
+    Ok(input_data) => input_data,
+    Err(reason) => return error_response(StatusCode::BAD_REQUEST, reason),
+  };
+  match workspace.evaluate_invocable_by_id(&rdnn, &invocable_id, &input_data) {
     Ok(value) => HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"data":{}}}"#, value.jsonify())),
-    Err(reason) => HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"errors":[{{"detail":"{reason}"}}]}}"#)),
+    Err(reason) => error_response(StatusCode::NOT_FOUND, reason),
+  }
+}
+
+/// Handler for discovering inputs missing in request body, that still affect
+/// the result of invocable identified by unique name in namespace represented by RDNN.
+#[post("/min/{rdnn}/{name}")]
+async fn missing_inputs_by_name(params: web::Path<(String, String)>, request_body: String, data: web::Data<ApplicationData>) -> HttpResponse {
+  let workspace: &Workspace = data.workspace.borrow();
+  let (rdnn, invocable_name) = params.into_inner();
+  let input_data = match dmntk_evaluator::evaluate_context(&FeelScope::default(), &request_body) {
+    Ok(input_data) => input_data,
+    Err(reason) => return error_response(StatusCode::BAD_REQUEST, reason),
+  };
+  match workspace.missing_inputs_by_name(&rdnn, &invocable_name, &input_data) {
+    Some(result) => missing_inputs_response(result),
+    None => error_response(StatusCode::NOT_FOUND, err_invocable_not_found(&rdnn, &invocable_name)),
   }
 }
 
+/// Handler for discovering inputs missing in request body, that still affect
+/// the result of invocable identified by unique identifier in namespace represented by RDNN.
+#[post("/min/id/{rdnn}/{id}")]
+async fn missing_inputs_by_id(params: web::Path<(String, String)>, request_body: String, data: web::Data<ApplicationData>) -> HttpResponse {
+  let workspace: &Workspace = data.workspace.borrow();
+  let (rdnn, invocable_id) = params.into_inner();
+  let input_data = match dmntk_evaluator::evaluate_context(&FeelScope::default(), &request_body) {
+    Ok(input_data) => input_data,
+    Err(reason) => return error_response(StatusCode::BAD_REQUEST, reason),
+  };
+  match workspace.missing_inputs_by_id(&rdnn, &invocable_id, &input_data) {
+    Some(result) => missing_inputs_response(result),
+    None => error_response(StatusCode::NOT_FOUND, err_invocable_not_found(&rdnn, &invocable_id)),
+  }
+}
+
+/// Returns the response with discovered missing inputs,
+/// failed discovery is reported as an internal server error.
+#[cfg_attr(feature = "tck", allow(dead_code))]
+fn missing_inputs_response(result: Result<Vec<QualifiedName>>) -> HttpResponse {
+  match result {
+    Ok(paths) => {
+      let value = Value::List(paths.iter().map(|path| Value::String(path.to_string())).collect());
+      HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"data":{}}}"#, value.jsonify()))
+    }
+    Err(reason) => error_response(StatusCode::INTERNAL_SERVER_ERROR, reason),
+  }
+}
+
+/// Returns the response with specified status and error details.
+#[cfg_attr(feature = "tck", allow(dead_code))]
+fn error_response(status: StatusCode, reason: DmntkError) -> HttpResponse {
+  HttpResponse::build(status)
+    .content_type(CONTENT_TYPE)
+    .body(json!({ "errors": [{ "detail": reason.to_string() }] }).to_string())
+}
+
 /// Handler for 404 errors.
 async fn not_found() -> HttpResponse {
   HttpResponse::NotFound().content_type(CONTENT_TYPE).body(r#"{"errors":[{"detail":"endpoint not found"}]}"#)
@@ -90,14 +152,16 @@ fn config(cfg: &mut web::ServiceConfig) {
 fn config(cfg: &mut web::ServiceConfig) {
   cfg.service(evaluate_by_name);
   cfg.service(evaluate_by_id);
+  cfg.service(missing_inputs_by_id);
+  cfg.service(missing_inputs_by_name);
 }
 
 /// Starts the server.
-pub async fn start_server(opt_host: Option<String>, opt_port: Option<String>, opt_dir: Option<String>, color_mode: ColorMode, verbose: bool) -> io::Result<()> {
+pub async fn start_server(opt_host: Option<String>, opt_port: Option<String>, opt_dir: Option<String>, color_mode: ColorMode, verbose: bool, validate: bool) -> io::Result<()> {
   let color_blue = color_blue!(color_mode);
   let color_yellow = color_yellow!(color_mode);
   let color_reset = color_reset!(color_mode);
-  match Workspace::new(get_workspace_dir(opt_dir), color_mode, verbose) {
+  match Workspace::new(get_workspace_dir(opt_dir), color_mode, verbose, validate) {
     Ok(workspace) => {
       let application_data = web::Data::new(ApplicationData { workspace: Arc::new(workspace) });
       let address = get_server_address(opt_host, opt_port);
@@ -42,6 +42,10 @@ pub fn err_evaluator_rdnn_not_found(rdnn: &str) -> DmntkError {
   WorkspaceError(format!("evaluator with namespace '{rdnn}' not found")).into()
 }
 
+pub fn err_reading_model(reason: &str) -> DmntkError {
+  WorkspaceError(format!("reading model failed with reason: {reason}")).into()
+}
+
 pub fn err_invalid_namespace(namespace: &str) -> DmntkError {
   WorkspaceError(format!("invalid namespace '{namespace}'")).into()
 }
@@ -49,3 +53,22 @@ pub fn err_invalid_namespace(namespace: &str) -> DmntkError {
 pub fn err_duplicated_namespace(namespace: &str, name: &str, existing_name: &str) -> DmntkError {
   WorkspaceError(format!("duplicated namespace '{namespace}' in model '{name}', already used in model '{existing_name}'")).into()
 }
+
+pub fn err_missing_import(name: &str, import_name: &str, namespace: &str) -> DmntkError {
+  WorkspaceError(format!("model '{name}' imports '{import_name}' with namespace '{namespace}' that was not found")).into()
+}
+
+pub fn err_imported_namespace_mismatch(import_name: &str, namespace: &str, location: &str, actual_namespace: &str) -> DmntkError {
+  WorkspaceError(format!(
+    "import '{import_name}' expects namespace '{namespace}', but model loaded from '{location}' has namespace '{actual_namespace}'"
+  ))
+  .into()
+}
+
+pub fn err_loading_imported_model(location: &str, reason: &str) -> DmntkError {
+  WorkspaceError(format!("loading imported model from '{location}' failed with reason: {reason}")).into()
+}
+
+pub fn err_circular_import(cycle: &str) -> DmntkError {
+  WorkspaceError(format!("circular import: {cycle}")).into()
+}
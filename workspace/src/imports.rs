@@ -0,0 +1,219 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! # Resolving imported models
+//!
+//! Models imported by a loaded model are searched by the location URI of the import,
+//! relative to the file containing the importing model. When the location is not given
+//! or the file does not exist, the model is searched by namespace in specified directories.
+
+use crate::errors::*;
+use crate::workspace::{parse_model, search_models_recursive};
+use dmntk_common::DmntkError;
+use dmntk_model::{Definitions, DmnVersion, NamedElement};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// DMN model loaded from file.
+pub struct LoadedModel {
+  /// Path of the file containing the model.
+  pub path: PathBuf,
+  /// Parsed model.
+  pub definitions: Definitions,
+}
+
+/// Resolves imports of loaded models. Imported models that are not loaded yet are loaded
+/// and appended to the returned list of models. Errors are reported together with
+/// the path of the file they relate to. When `validate` flag is set, imported models
+/// are validated against XML schema before loading.
+pub fn resolve_imports(models: Vec<LoadedModel>, search_dirs: &[PathBuf], validate: bool) -> (Vec<LoadedModel>, Vec<(PathBuf, DmntkError)>) {
+  let mut resolver = ImportResolver {
+    models,
+    search_dirs,
+    validate,
+    namespace_index: None,
+    errors: vec![],
+  };
+  resolver.resolve();
+  resolver.check_cycles();
+  (resolver.models, resolver.errors)
+}
+
+/// State of the import resolution.
+struct ImportResolver<'a> {
+  /// Loaded models.
+  models: Vec<LoadedModel>,
+  /// Directories where models are searched by namespace.
+  search_dirs: &'a [PathBuf],
+  /// Flag indicating if imported models should be validated against XML schema.
+  validate: bool,
+  /// Paths of model files in search directories indexed by namespace, built on first use.
+  namespace_index: Option<HashMap<String, PathBuf>>,
+  /// Errors reported during resolution.
+  errors: Vec<(PathBuf, DmntkError)>,
+}
+
+impl ImportResolver<'_> {
+  /// Resolves imports of all models, including models loaded during resolution.
+  fn resolve(&mut self) {
+    let mut index = 0;
+    while index < self.models.len() {
+      let importing_path = self.models[index].path.clone();
+      let imports = dmn_imports(&self.models[index].definitions);
+      for (import_name, namespace, location_uri) in imports {
+        if self.find_model(&namespace).is_some() {
+          continue;
+        }
+        let by_location = location_uri.as_deref().and_then(|location_uri| local_path(&importing_path, location_uri));
+        let path = by_location.filter(|path| path.is_file()).or_else(|| self.find_file(&namespace));
+        let Some(path) = path else {
+          let name = self.models[index].definitions.name().to_string();
+          self.errors.push((importing_path.clone(), err_missing_import(&name, &import_name, &namespace)));
+          continue;
+        };
+        let location = path.display().to_string();
+        match fs::read_to_string(&path) {
+          Ok(xml) => match parse_model(&xml, self.validate) {
+            Ok(definitions) if definitions.namespace() == namespace => self.models.push(LoadedModel { path, definitions }),
+            Ok(definitions) => self.errors.push((
+              importing_path.clone(),
+              err_imported_namespace_mismatch(&import_name, &namespace, &location, definitions.namespace()),
+            )),
+            Err(reasons) => {
+              for reason in reasons {
+                self.errors.push((path.clone(), err_loading_imported_model(&location, &reason.to_string())));
+              }
+            }
+          },
+          Err(reason) => self.errors.push((importing_path.clone(), err_loading_imported_model(&location, &reason.to_string()))),
+        }
+      }
+      index += 1;
+    }
+  }
+
+  /// Returns the index of the loaded model having specified namespace.
+  fn find_model(&self, namespace: &str) -> Option<usize> {
+    self.models.iter().position(|model| model.definitions.namespace() == namespace)
+  }
+
+  /// Returns the path of the file containing model with specified namespace,
+  /// searched in all model files placed in search directories.
+  fn find_file(&mut self, namespace: &str) -> Option<PathBuf> {
+    let search_dirs = self.search_dirs;
+    self
+      .namespace_index
+      .get_or_insert_with(|| {
+        let mut namespace_index = HashMap::new();
+        for path in search_dirs.iter().flat_map(search_models_recursive) {
+          if let Some(definitions) = fs::read_to_string(&path).ok().and_then(|xml| dmntk_model::parse(&xml).ok()) {
+            namespace_index.entry(definitions.namespace().to_string()).or_insert(path);
+          }
+        }
+        namespace_index
+      })
+      .get(namespace)
+      .cloned()
+  }
+
+  /// Reports cycles in imports between loaded models, every cycle is reported once.
+  fn check_cycles(&mut self) {
+    let edges = self
+      .models
+      .iter()
+      .map(|model| {
+        dmn_imports(&model.definitions)
+          .iter()
+          .filter_map(|(_, namespace, _)| self.find_model(namespace))
+          .collect::<Vec<usize>>()
+      })
+      .collect::<Vec<Vec<usize>>>();
+    let mut visited = HashSet::new();
+    let mut cycles = vec![];
+    for index in 0..self.models.len() {
+      find_cycles(index, &edges, &mut vec![], &mut visited, &mut cycles);
+    }
+    for cycle in cycles {
+      let description = cycle
+        .iter()
+        .chain(cycle.first())
+        .map(|index| format!("'{}'", self.models[*index].definitions.name()))
+        .collect::<Vec<String>>()
+        .join(" -> ");
+      self.errors.push((self.models[cycle[0]].path.clone(), err_circular_import(&description)));
+    }
+  }
+}
+
+/// Returns imports of DMN models (import name, namespace and optional location URI),
+/// imports of other types like XML schemas or PMML documents are skipped.
+fn dmn_imports(definitions: &Definitions) -> Vec<(String, String, Option<String>)> {
+  definitions
+    .imports()
+    .iter()
+    .filter(|import| DmnVersion::try_from(import.import_type()).is_ok())
+    .map(|import| (import.name().to_string(), import.namespace().to_string(), import.location_uri().clone()))
+    .collect()
+}
+
+/// Returns the path of the local file pointed by location URI,
+/// relative locations are resolved against the directory of the importing file.
+fn local_path(importing_path: &Path, location_uri: &str) -> Option<PathBuf> {
+  let location = location_uri.trim();
+  let location = location.strip_prefix("file://").unwrap_or(location);
+  if location.is_empty() || location.contains("://") {
+    return None;
+  }
+  let path = Path::new(location);
+  if path.is_absolute() {
+    Some(path.to_path_buf())
+  } else {
+    Some(importing_path.parent().unwrap_or(Path::new("")).join(path))
+  }
+}
+
+/// Finds cycles reachable from specified model using depth-first search.
+fn find_cycles(index: usize, edges: &[Vec<usize>], path: &mut Vec<usize>, visited: &mut HashSet<usize>, cycles: &mut Vec<Vec<usize>>) {
+  if let Some(position) = path.iter().position(|path_index| *path_index == index) {
+    cycles.push(path[position..].to_vec());
+    return;
+  }
+  if !visited.insert(index) {
+    return;
+  }
+  path.push(index);
+  for target_index in &edges[index] {
+    find_cycles(*target_index, edges, path, visited, cycles);
+  }
+  path.pop();
+}
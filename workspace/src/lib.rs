@@ -36,6 +36,11 @@
 extern crate dmntk_macros;
 
 mod errors;
+mod imports;
 mod workspace;
 
+#[cfg(test)]
+mod tests;
+
+pub use imports::{resolve_imports, LoadedModel};
 pub use workspace::Workspace;
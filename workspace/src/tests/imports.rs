@@ -0,0 +1,190 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::temp_dir;
+use crate::{resolve_imports, LoadedModel, Workspace};
+use dmntk_common::{to_rdnn, ColorMode};
+use dmntk_examples::{DMN_3_0086, DMN_3_0086_IMPORT};
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::value_number;
+use dmntk_feel::values::Value;
+use dmntk_feel::Name;
+use dmntk_model::NamedElement;
+use std::fs;
+use std::path::Path;
+
+/// Namespace of the importing model.
+const NAMESPACE: &str = "https://dmntk.io/3_0086";
+
+/// Writes the model to file and returns the model loaded from this file.
+fn write_model(path: &Path, xml: &str) -> LoadedModel {
+  fs::create_dir_all(path.parent().unwrap()).unwrap();
+  fs::write(path, xml).unwrap();
+  LoadedModel {
+    path: path.to_path_buf(),
+    definitions: dmntk_model::parse(xml).unwrap(),
+  }
+}
+
+/// Returns the importing model with import pointing to specified location.
+fn importing_model(location_uri: &str) -> String {
+  DMN_3_0086.replace(r#"name="myimport""#, &format!(r#"name="myimport" locationURI="{location_uri}""#))
+}
+
+/// Returns a minimal model with specified name and namespace, importing specified models.
+fn simple_model(name: &str, namespace: &str, imports: &[(&str, &str)]) -> String {
+  let imports = imports
+    .iter()
+    .map(|(import_name, location_uri)| {
+      format!(r#"<import namespace="https://dmntk.io/{import_name}" name="{import_name}" locationURI="{location_uri}" importType="https://www.omg.org/spec/DMN/20191111/MODEL/"/>"#)
+    })
+    .collect::<String>();
+  format!(
+    r#"<?xml version="1.0" encoding="UTF-8"?><definitions namespace="{namespace}" name="{name}" id="_{name}" xmlns="https://www.omg.org/spec/DMN/20191111/MODEL/">{imports}</definitions>"#
+  )
+}
+
+/// Returns the names of resolved models.
+fn model_names(models: &[LoadedModel]) -> Vec<String> {
+  models.iter().map(|model| model.definitions.name().to_string()).collect()
+}
+
+#[test]
+fn _0001() {
+  // imported model is found by location relative to the importing file
+  let dir = temp_dir("0001");
+  let model = write_model(&dir.join("main").join("main.dmn"), &importing_model("../lib/imported.dmn"));
+  write_model(&dir.join("lib").join("imported.dmn"), DMN_3_0086_IMPORT);
+  let (models, errors) = resolve_imports(vec![model], &[], false);
+  assert!(errors.is_empty());
+  assert_eq!(vec!["Import BKM and have a Decision Ctx with DT", "Imported Model"], model_names(&models));
+  assert_eq!(dir.join("main").join("../lib/imported.dmn"), models[1].path);
+  fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn _0002() {
+  // imported model is found by namespace in search directories
+  let dir = temp_dir("0002");
+  let model = write_model(&dir.join("main.dmn"), DMN_3_0086);
+  write_model(&dir.join("models").join("other.dmn"), DMN_3_0086_IMPORT);
+  let (models, errors) = resolve_imports(vec![model], std::slice::from_ref(&dir), false);
+  assert!(errors.is_empty());
+  assert_eq!(vec!["Import BKM and have a Decision Ctx with DT", "Imported Model"], model_names(&models));
+  fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn _0003() {
+  // imported model is already loaded
+  let dir = temp_dir("0003");
+  let model = write_model(&dir.join("main.dmn"), &importing_model("missing.dmn"));
+  let imported_model = write_model(&dir.join("imported.dmn"), DMN_3_0086_IMPORT);
+  let (models, errors) = resolve_imports(vec![model, imported_model], &[], false);
+  assert!(errors.is_empty());
+  assert_eq!(2, models.len());
+  fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn _0004() {
+  // imported model is not found
+  let dir = temp_dir("0004");
+  let model = write_model(&dir.join("main.dmn"), &importing_model("missing.dmn"));
+  let (models, errors) = resolve_imports(vec![model], std::slice::from_ref(&dir), false);
+  assert_eq!(1, models.len());
+  assert_eq!(1, errors.len());
+  assert_eq!(dir.join("main.dmn"), errors[0].0);
+  assert_eq!(
+    "<WorkspaceError> model 'Import BKM and have a Decision Ctx with DT' imports 'myimport' with namespace 'https://dmntk.io/3_0086/imported' that was not found",
+    errors[0].1.to_string()
+  );
+  fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn _0005() {
+  // model found by location has different namespace than the import
+  let dir = temp_dir("0005");
+  let model = write_model(&dir.join("main.dmn"), &importing_model("other.dmn"));
+  write_model(&dir.join("other.dmn"), &simple_model("other", "https://dmntk.io/other", &[]));
+  let (models, errors) = resolve_imports(vec![model], &[], false);
+  assert_eq!(1, models.len());
+  assert_eq!(1, errors.len());
+  assert!(errors[0].1.to_string().contains("https://dmntk.io/other"));
+  fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn _0006() {
+  // circular import is reported once
+  let dir = temp_dir("0006");
+  let model_a = write_model(&dir.join("a.dmn"), &simple_model("A", "https://dmntk.io/A", &[("B", "b.dmn")]));
+  write_model(&dir.join("b.dmn"), &simple_model("B", "https://dmntk.io/B", &[("C", "c.dmn")]));
+  write_model(&dir.join("c.dmn"), &simple_model("C", "https://dmntk.io/C", &[("A", "a.dmn")]));
+  let (models, errors) = resolve_imports(vec![model_a], &[], false);
+  assert_eq!(vec!["A", "B", "C"], model_names(&models));
+  assert_eq!(1, errors.len());
+  assert_eq!(dir.join("a.dmn"), errors[0].0);
+  assert_eq!("<WorkspaceError> circular import: 'A' -> 'B' -> 'C' -> 'A'", errors[0].1.to_string());
+  fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn _0007() {
+  // imports of other types than DMN models are skipped
+  let dir = temp_dir("0007");
+  let xml = simple_model("A", "https://dmntk.io/A", &[("B", "b.xsd")]).replace("https://www.omg.org/spec/DMN/20191111/MODEL/\"/>", "http://www.w3.org/2001/XMLSchema\"/>");
+  let model = write_model(&dir.join("a.dmn"), &xml);
+  let (models, errors) = resolve_imports(vec![model], &[], false);
+  assert_eq!(1, models.len());
+  assert!(errors.is_empty());
+  fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn _0008() {
+  // workspace evaluates model importing a model placed outside the workspace directory
+  let dir = temp_dir("0008");
+  write_model(&dir.join("workspace").join("main.dmn"), &importing_model("../lib/imported.dmn"));
+  write_model(&dir.join("lib").join("imported.dmn"), DMN_3_0086_IMPORT);
+  let workspace = Workspace::new(dir.join("workspace"), ColorMode::Off, false, false).unwrap();
+  let mut person = FeelContext::default();
+  person.set_entry(&Name::from("age"), value_number!(21));
+  person.set_entry(&Name::from("name"), Value::String("John Doe".to_string()));
+  let mut input_data = FeelContext::default();
+  input_data.set_entry(&Name::from("A Person"), Value::Context(person));
+  let rdnn = to_rdnn(NAMESPACE).unwrap();
+  let value = workspace.evaluate_invocable_by_name(&rdnn, "A Decision Ctx with DT", &input_data).unwrap();
+  assert_eq!(r#""Hello John Doe!""#, value.to_string());
+  fs::remove_dir_all(dir).unwrap();
+}
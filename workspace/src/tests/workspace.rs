@@ -0,0 +1,204 @@
+/*
+ * DMNTK - Decision Model and Notation Toolkit
+ *
+ * MIT license
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ *
+ * Apache license, Version 2.0
+ *
+ * Copyright (c) 2018-2023 Dariusz Depta, Engos Software
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::temp_dir;
+use crate::Workspace;
+use dmntk_common::{to_rdnn, ColorMode};
+use dmntk_feel::context::FeelContext;
+use std::fs;
+
+/// Model without namespace of the DMN specification, treated as DMN 1.5 model.
+const DMN_1_5_MODEL: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<definitions namespace="https://dmntk.io/dmn-1-5" name="Greetings" id="_greetings">
+    <decision name="Greeting" id="_greeting">
+        <variable typeRef="string" name="Greeting"/>
+        <literalExpression>
+            <text>"Hello"</text>
+        </literalExpression>
+    </decision>
+</definitions>
+"#;
+
+#[test]
+fn _0001() {
+  // model without namespace is validated as DMN 1.5 model and loaded
+  let dir = temp_dir("workspace-0001");
+  fs::write(dir.join("greetings.dmn"), DMN_1_5_MODEL).unwrap();
+  let workspace = Workspace::new(dir.clone(), ColorMode::Off, false, true).unwrap();
+  let rdnn = to_rdnn("https://dmntk.io/dmn-1-5").unwrap();
+  let value = workspace.evaluate_invocable_by_name(&rdnn, "Greeting", &FeelContext::default()).unwrap();
+  assert_eq!(r#""Hello""#, value.to_string());
+  fs::remove_dir_all(dir).unwrap();
+}
+
+/// Model using holiday calendar named `holidays`.
+#[cfg(feature = "calendar")]
+const HOLIDAY_MODEL: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<definitions namespace="https://dmntk.io/holidays" name="Holidays" id="_holidays">
+    <decision name="Is holiday" id="_is_holiday">
+        <variable typeRef="boolean" name="Is holiday"/>
+        <literalExpression>
+            <text>dmntk.is holiday(date("2023-12-25"), "holidays")</text>
+        </literalExpression>
+    </decision>
+</definitions>
+"#;
+
+#[test]
+#[cfg(feature = "calendar")]
+fn _0002() {
+  // calendars loaded into a workspace are available only in evaluations in this workspace
+  let dir_a = temp_dir("workspace-0002-a");
+  fs::write(dir_a.join("holidays.dmn"), HOLIDAY_MODEL).unwrap();
+  fs::create_dir_all(dir_a.join("calendars")).unwrap();
+  fs::write(dir_a.join("calendars").join("holidays.csv"), "date,name\n2023-12-25,Christmas Day\n").unwrap();
+  let dir_b = temp_dir("workspace-0002-b");
+  fs::write(dir_b.join("holidays.dmn"), HOLIDAY_MODEL).unwrap();
+  let workspace_a = Workspace::new(dir_a.clone(), ColorMode::Off, false, false).unwrap();
+  let workspace_b = Workspace::new(dir_b.clone(), ColorMode::Off, false, false).unwrap();
+  let rdnn = to_rdnn("https://dmntk.io/holidays").unwrap();
+  let value = workspace_a.evaluate_invocable_by_name(&rdnn, "Is holiday", &FeelContext::default()).unwrap();
+  assert_eq!("true", value.to_string());
+  let value = workspace_b.evaluate_invocable_by_name(&rdnn, "Is holiday", &FeelContext::default()).unwrap();
+  assert_eq!("null([dmntk.is holiday] calendar 'holidays' not found)", value.to_string());
+  fs::remove_dir_all(dir_a).unwrap();
+  fs::remove_dir_all(dir_b).unwrap();
+}
+
+/// Model with specified namespace and a single decision.
+fn greeting_model(namespace: &str, decision_name: &str) -> String {
+  DMN_1_5_MODEL.replace("https://dmntk.io/dmn-1-5", namespace).replace("Greeting", decision_name)
+}
+
+#[test]
+fn _0003() {
+  // model with duplicated namespace is reported and skipped, other models are deployed
+  let dir = temp_dir("workspace-0003");
+  fs::write(dir.join("a.dmn"), greeting_model("https://dmntk.io/duplicated", "Greeting A")).unwrap();
+  fs::write(dir.join("b.dmn"), greeting_model("https://dmntk.io/duplicated", "Greeting B")).unwrap();
+  fs::write(dir.join("c.dmn"), greeting_model("https://dmntk.io/unique", "Greeting C")).unwrap();
+  let workspace = Workspace::new(dir.clone(), ColorMode::Off, false, false).unwrap();
+  let rdnn = to_rdnn("https://dmntk.io/duplicated").unwrap();
+  let deployed = ["Greeting A", "Greeting B"]
+    .iter()
+    .filter(|name| workspace.evaluate_invocable_by_name(&rdnn, name, &FeelContext::default()).unwrap().to_string() == r#""Hello""#)
+    .count();
+  assert_eq!(1, deployed);
+  let rdnn = to_rdnn("https://dmntk.io/unique").unwrap();
+  let value = workspace.evaluate_invocable_by_name(&rdnn, "Greeting C", &FeelContext::default()).unwrap();
+  assert_eq!(r#""Hello""#, value.to_string());
+  fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn _0004() {
+  // model with namespace that can not be converted to RDNN is reported and skipped, other models are deployed
+  let dir = temp_dir("workspace-0004");
+  fs::write(dir.join("a.dmn"), greeting_model("urn:dmntk:invalid", "Greeting A")).unwrap();
+  fs::write(dir.join("b.dmn"), greeting_model("https://dmntk.io/valid", "Greeting B")).unwrap();
+  let workspace = Workspace::new(dir.clone(), ColorMode::Off, false, false).unwrap();
+  let rdnn = to_rdnn("https://dmntk.io/valid").unwrap();
+  let value = workspace.evaluate_invocable_by_name(&rdnn, "Greeting B", &FeelContext::default()).unwrap();
+  assert_eq!(r#""Hello""#, value.to_string());
+  fs::remove_dir_all(dir).unwrap();
+}
+
+/// Model with specified namespace and a decision with invalid FEEL expression.
+fn broken_model(namespace: &str) -> String {
+  greeting_model(namespace, "Broken").replace(r#""Hello""#, "1 + + *")
+}
+
+#[test]
+fn _0005() {
+  // model that failed to deploy is not reachable by its RDNN
+  let dir = temp_dir("workspace-0005");
+  fs::write(dir.join("a.dmn"), broken_model("https://dmntk.io/broken")).unwrap();
+  let workspace = Workspace::new(dir.clone(), ColorMode::Off, false, false).unwrap();
+  let rdnn = to_rdnn("https://dmntk.io/broken").unwrap();
+  let reason = workspace.evaluate_invocable_by_name(&rdnn, "Broken", &FeelContext::default()).unwrap_err();
+  assert_eq!(format!("<WorkspaceError> evaluator with namespace '{rdnn}' not found"), reason.to_string());
+  assert!(workspace.missing_inputs_by_name(&rdnn, "Broken", &FeelContext::default()).is_none());
+  fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn _0006() {
+  // reloaded model replaces previously deployed model, other models remain deployed
+  let dir = temp_dir("workspace-0006");
+  fs::write(dir.join("a.dmn"), greeting_model("https://dmntk.io/a", "Greeting A")).unwrap();
+  fs::write(dir.join("b.dmn"), greeting_model("https://dmntk.io/b", "Greeting B")).unwrap();
+  let mut workspace = Workspace::new(dir.clone(), ColorMode::Off, false, false).unwrap();
+  fs::write(dir.join("a.dmn"), greeting_model("https://dmntk.io/a", "Welcome A")).unwrap();
+  assert!(workspace.reload(&dir.join("a.dmn"), false).is_empty());
+  let rdnn = to_rdnn("https://dmntk.io/a").unwrap();
+  let value = workspace.evaluate_invocable_by_name(&rdnn, "Welcome A", &FeelContext::default()).unwrap();
+  assert_eq!(r#""Hello""#, value.to_string());
+  let value = workspace.evaluate_invocable_by_name(&rdnn, "Greeting A", &FeelContext::default()).unwrap();
+  assert_eq!("null(invocable 'Greeting A' not found in namespace 'https://dmntk.io/a')", value.to_string());
+  let rdnn = to_rdnn("https://dmntk.io/b").unwrap();
+  let value = workspace.evaluate_invocable_by_name(&rdnn, "Greeting B", &FeelContext::default()).unwrap();
+  assert_eq!(r#""Hello""#, value.to_string());
+  fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn _0007() {
+  // failed replacement keeps previously deployed model
+  let dir = temp_dir("workspace-0007");
+  fs::write(dir.join("a.dmn"), greeting_model("https://dmntk.io/a", "Greeting A")).unwrap();
+  let mut workspace = Workspace::new(dir.clone(), ColorMode::Off, false, false).unwrap();
+  let errors = workspace.replace(dmntk_model::parse(&broken_model("https://dmntk.io/a")).unwrap());
+  assert_eq!(1, errors.len());
+  assert_eq!("https://dmntk.io/a", errors[0].0);
+  let rdnn = to_rdnn("https://dmntk.io/a").unwrap();
+  let value = workspace.evaluate_invocable_by_name(&rdnn, "Greeting A", &FeelContext::default()).unwrap();
+  assert_eq!(r#""Hello""#, value.to_string());
+  fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn _0008() {
+  // replacing model with a new namespace deploys it, failed reload is reported with the file name
+  let dir = temp_dir("workspace-0008");
+  let mut workspace = Workspace::new(dir.clone(), ColorMode::Off, false, false).unwrap();
+  assert!(workspace
+    .replace(dmntk_model::parse(&greeting_model("https://dmntk.io/new", "Greeting")).unwrap())
+    .is_empty());
+  let rdnn = to_rdnn("https://dmntk.io/new").unwrap();
+  let value = workspace.evaluate_invocable_by_name(&rdnn, "Greeting", &FeelContext::default()).unwrap();
+  assert_eq!(r#""Hello""#, value.to_string());
+  let file = dir.join("missing.dmn");
+  let errors = workspace.reload(&file, false);
+  assert_eq!(1, errors.len());
+  assert_eq!(file.display().to_string(), errors[0].0);
+  fs::remove_dir_all(dir).unwrap();
+}
@@ -33,61 +33,134 @@
 //! # Container for DMN models
 
 use crate::errors::*;
-use dmntk_common::{color_blue, color_green, color_magenta, color_red, color_reset, to_rdnn, ColorMode, Result};
+use crate::imports::{resolve_imports, LoadedModel};
+use dmntk_common::{color_blue, color_green, color_magenta, color_red, color_reset, to_rdnn, ColorMode, DmntkError, Result};
 use dmntk_feel::context::FeelContext;
 use dmntk_feel::values::Value;
+use dmntk_feel::QualifiedName;
+#[cfg(feature = "calendar")]
+use dmntk_feel_evaluator::{with_calendars, HolidayCalendar, HolidayCalendars};
 use dmntk_model::{Definitions, NamedElement};
-use dmntk_model_evaluator::ModelEvaluator;
+use dmntk_model_evaluator::BundleEvaluator;
 use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+#[cfg(feature = "calendar")]
 use std::sync::Arc;
 use urlencoding::encode;
 use walkdir::WalkDir;
 
 const ERROR_TAG: &str = "error";
 
+/// Name of directories containing holiday calendars.
+#[cfg(feature = "calendar")]
+const CALENDARS_DIR: &str = "calendars";
+
 /// Structure representing the container for DMN models.
 pub struct Workspace {
-  ///
-  evaluator: Arc<ModelEvaluator>,
-  /// Namespaces indexed by RDNN.
+  /// Evaluator of deployed models, every model is deployed together with its imports.
+  evaluator: BundleEvaluator,
+  /// Namespaces of deployed models indexed by RDNN.
   namespace_by_rdnn: HashMap<String, String>,
+  /// Definitions of all deployable models, units are rebuilt from them when a model is replaced.
+  definitions: Vec<Definitions>,
+  /// Holiday calendars available to business calendar functions during evaluation.
+  #[cfg(feature = "calendar")]
+  calendars: Arc<HolidayCalendars>,
 }
 
 impl Workspace {
   /// Creates a new [Workspace] and loads DMN models from specified directory (recursive).
-  pub fn new(dir: PathBuf, color_mode: ColorMode, verbose: bool) -> Result<Self> {
-    let loaded_definitions = Self::load(dir, color_mode);
-    match ModelEvaluator::new(&loaded_definitions) {
-      Ok(evaluator) => {
-        let mut namespace_by_rdnn: HashMap<String, String> = HashMap::new();
-        let mut loaded_namespaces: HashMap<String, String> = HashMap::new();
-        for definitions in loaded_definitions {
-          let name = definitions.name();
-          let namespace = definitions.namespace();
-          if let Some(existing_name) = loaded_namespaces.get(namespace) {
-            return Err(err_duplicated_namespace(namespace, name, existing_name));
-          }
-          loaded_namespaces.insert(namespace.to_string(), name.to_string());
-          let Some(rdnn) = to_rdnn(namespace) else {
-              return Err(err_invalid_namespace(namespace));
-            };
-          namespace_by_rdnn.insert(rdnn, namespace.to_string());
-        }
-
-        display_deployed(&evaluator, &namespace_by_rdnn, color_mode, verbose);
+  /// When `validate` flag is set, models are validated against XML schema before loading.
+  /// Models that can not be deployed are reported and skipped, other models are deployed.
+  pub fn new(dir: PathBuf, color_mode: ColorMode, verbose: bool, validate: bool) -> Result<Self> {
+    #[cfg(feature = "calendar")]
+    let calendars = Arc::new(Self::load_calendars(&dir, color_mode));
+    let loaded_definitions = Self::load(dir, color_mode, validate);
+    let mut namespace_by_rdnn: HashMap<String, String> = HashMap::new();
+    let mut loaded_namespaces: HashMap<String, String> = HashMap::new();
+    let mut deployable_definitions = vec![];
+    let mut errors = vec![];
+    for definitions in loaded_definitions {
+      let name = definitions.name();
+      let namespace = definitions.namespace();
+      if let Some(existing_name) = loaded_namespaces.get(namespace) {
+        errors.push((namespace.to_string(), err_duplicated_namespace(namespace, name, existing_name)));
+        continue;
+      }
+      let Some(rdnn) = to_rdnn(namespace) else {
+        errors.push((namespace.to_string(), err_invalid_namespace(namespace)));
+        continue;
+      };
+      loaded_namespaces.insert(namespace.to_string(), name.to_string());
+      namespace_by_rdnn.insert(rdnn, namespace.to_string());
+      deployable_definitions.push(definitions);
+    }
+    let (evaluator, deployment_errors) = BundleEvaluator::new(&deployable_definitions);
+    // models that failed to deploy are not reachable by their RDNN
+    namespace_by_rdnn.retain(|_, namespace| deployment_errors.iter().all(|(failed_namespace, _)| failed_namespace != namespace));
+    errors.extend(deployment_errors);
+    display_failed(&errors, color_mode);
+    display_deployed(&evaluator, &namespace_by_rdnn, color_mode, verbose);
+    Ok(Self {
+      evaluator,
+      namespace_by_rdnn,
+      definitions: deployable_definitions,
+      #[cfg(feature = "calendar")]
+      calendars,
+    })
+  }
 
-        Ok(Self { evaluator, namespace_by_rdnn })
+  /// Deploys specified model in place of the model with the same namespace,
+  /// or deploys it as a new model when no model with this namespace was loaded.
+  /// Units of models importing the replaced model are redeployed.
+  /// When the model can not be deployed, previously deployed version remains available.
+  /// Returns the namespaces of models that could not be deployed together with failure reasons.
+  pub fn replace(&mut self, definitions: Definitions) -> Vec<(String, DmntkError)> {
+    let namespace = definitions.namespace().to_string();
+    let Some(rdnn) = to_rdnn(&namespace) else {
+      return vec![(namespace.clone(), err_invalid_namespace(&namespace))];
+    };
+    let position = self.definitions.iter().position(|loaded| loaded.namespace() == namespace);
+    let previous = match position {
+      Some(index) => Some(std::mem::replace(&mut self.definitions[index], definitions)),
+      None => {
+        self.definitions.push(definitions);
+        None
       }
-      Err(reason) => Err(reason),
+    };
+    let errors = self.evaluator.redeploy(&namespace, &self.definitions);
+    if errors.iter().any(|(failed_namespace, _)| *failed_namespace == namespace) {
+      match (position, previous) {
+        (Some(index), Some(previous)) => self.definitions[index] = previous,
+        _ => {
+          self.definitions.pop();
+        }
+      }
+    } else {
+      self.namespace_by_rdnn.insert(rdnn, namespace);
+    }
+    errors
+  }
+
+  /// Loads the model from specified file and deploys it in place of the model with the same namespace.
+  /// When `validate` flag is set, the model is validated against XML schema before loading.
+  /// Returns the failure reasons, models are identified by the file name when the model can not be loaded.
+  pub fn reload(&mut self, file: &Path, validate: bool) -> Vec<(String, DmntkError)> {
+    let location = file.display().to_string();
+    match fs::read_to_string(file) {
+      Ok(xml) => match parse_model(&xml, validate) {
+        Ok(definitions) => self.replace(definitions),
+        Err(reasons) => reasons.into_iter().map(|reason| (location.clone(), reason)).collect(),
+      },
+      Err(reason) => vec![(location, err_reading_model(&reason.to_string()))],
     }
   }
 
   /// Evaluates invocable identified by its name in model namespace.
   pub fn evaluate_invocable_by_name(&self, rdnn: &str, invocable_name: &str, input_data: &FeelContext) -> Result<Value> {
     if let Some(namespace) = self.namespace_by_rdnn.get(rdnn) {
-      Ok(self.evaluator.evaluate_invocable_by_name(namespace, invocable_name, input_data))
+      Ok(self.with_calendars(|| self.evaluator.evaluate_invocable_by_name(namespace, invocable_name, input_data)))
     } else {
       Err(err_evaluator_rdnn_not_found(rdnn))
     }
@@ -96,32 +169,48 @@ impl Workspace {
   /// Evaluates invocable identified by its identifier in model namespace.
   pub fn evaluate_invocable_by_id(&self, rdnn: &str, invocable_id: &str, input_data: &FeelContext) -> Result<Value> {
     if let Some(namespace) = self.namespace_by_rdnn.get(rdnn) {
-      Ok(self.evaluator.evaluate_invocable_by_id(namespace, invocable_id, input_data))
+      Ok(self.with_calendars(|| self.evaluator.evaluate_invocable_by_id(namespace, invocable_id, input_data)))
     } else {
       Err(err_evaluator_rdnn_not_found(rdnn))
     }
   }
 
-  /// Loads DMN models from specified directory (recursive).
-  fn load(dir: PathBuf, color_mode: ColorMode) -> Vec<Definitions> {
+  /// Returns input data and context paths missing in input data,
+  /// that still affect the result of invocable identified by its name in model namespace.
+  /// Returns `None` when the namespace or the invocable was not found.
+  pub fn missing_inputs_by_name(&self, rdnn: &str, invocable_name: &str, input_data: &FeelContext) -> Option<Result<Vec<QualifiedName>>> {
+    let namespace = self.namespace_by_rdnn.get(rdnn)?;
+    self.with_calendars(|| self.evaluator.missing_inputs_by_name(namespace, invocable_name, input_data))
+  }
+
+  /// Returns input data and context paths missing in input data,
+  /// that still affect the result of invocable identified by its identifier in model namespace.
+  /// Returns `None` when the namespace or the invocable was not found.
+  pub fn missing_inputs_by_id(&self, rdnn: &str, invocable_id: &str, input_data: &FeelContext) -> Option<Result<Vec<QualifiedName>>> {
+    let namespace = self.namespace_by_rdnn.get(rdnn)?;
+    self.with_calendars(|| self.evaluator.missing_inputs_by_id(namespace, invocable_id, input_data))
+  }
+
+  /// Loads DMN models from specified directory (recursive) together with the models they import.
+  fn load(dir: PathBuf, color_mode: ColorMode, validate: bool) -> Vec<Definitions> {
     let color_blue = color_blue!(color_mode);
     let color_green = color_green!(color_mode);
     let color_red = color_red!(color_mode);
     let color_reset = color_reset!(color_mode);
     let mut file_count = 0_usize;
-    let mut loaded_count = 0_usize;
     let mut failed_count = 0_usize;
-    let mut loaded_definitions = vec![];
+    let mut loaded_models = vec![];
     for file in &search_models_recursive(&dir) {
       file_count += 1;
       match fs::read_to_string(file) {
-        Ok(xml) => match dmntk_model::parse(&xml) {
+        Ok(xml) => match parse_model(&xml, validate) {
           Ok(definitions) => {
-            loaded_definitions.push(definitions);
-            loaded_count += 1;
+            loaded_models.push(LoadedModel { path: file.clone(), definitions });
           }
-          Err(reason) => {
-            eprintln!("[{1}{ERROR_TAG}{0}][{2}{3}{0}] {1}{4}{0}", color_reset, color_red, color_blue, file.display(), reason);
+          Err(reasons) => {
+            for reason in reasons {
+              eprintln!("[{1}{ERROR_TAG}{0}][{2}{3}{0}] {1}{4}{0}", color_reset, color_red, color_blue, file.display(), reason);
+            }
             failed_count += 1;
           }
         },
@@ -131,6 +220,14 @@ impl Workspace {
         }
       }
     }
+    // models imported from outside the directory are loaded while resolving imports
+    let parsed_count = loaded_models.len();
+    let (loaded_models, errors) = resolve_imports(loaded_models, &[dir], validate);
+    let loaded_count = loaded_models.len();
+    let file_count = file_count + loaded_count.saturating_sub(parsed_count);
+    for (file, reason) in errors {
+      eprintln!("[{1}{ERROR_TAG}{0}][{2}{3}{0}] {1}{4}{0}", color_reset, color_red, color_blue, file.display(), reason);
+    }
     let color = if file_count > 0 { &color_green } else { &color_red };
     println!("{1}Found {file_count} {2}.{0}", color_reset, color, plural("model", file_count),);
     if loaded_count > 0 {
@@ -139,13 +236,91 @@ impl Workspace {
     if failed_count > 0 {
       println!("{1}Failed to load {failed_count} {2}.{0}", color_reset, color_red, plural("model", failed_count));
     }
-    loaded_definitions
+    loaded_models.into_iter().map(|loaded_model| loaded_model.definitions).collect()
+  }
+
+  /// Runs the evaluation with holiday calendars loaded into this workspace.
+  #[cfg(feature = "calendar")]
+  fn with_calendars<R>(&self, evaluation: impl FnOnce() -> R) -> R {
+    with_calendars(&self.calendars, evaluation)
+  }
+
+  /// Runs the evaluation, holiday calendars are not available without `calendar` feature.
+  #[cfg(not(feature = "calendar"))]
+  fn with_calendars<R>(&self, evaluation: impl FnOnce() -> R) -> R {
+    evaluation()
   }
+
+  /// Loads holiday calendars used by business calendar extension functions.
+  ///
+  /// Calendars are loaded from iCalendar (`.ics`) and CSV (`.csv`) files placed
+  /// in directories named `calendars` (recursive), the name of the calendar
+  /// is the name of the file without extension.
+  #[cfg(feature = "calendar")]
+  fn load_calendars(dir: &Path, color_mode: ColorMode) -> HolidayCalendars {
+    let color_blue = color_blue!(color_mode);
+    let color_green = color_green!(color_mode);
+    let color_red = color_red!(color_mode);
+    let color_reset = color_reset!(color_mode);
+    let mut calendars = HolidayCalendars::default();
+    for file in &search_calendars_recursive(dir) {
+      let Some(name) = file.file_stem().map(|stem| stem.to_string_lossy().to_string()) else {
+        continue;
+      };
+      let calendar = fs::read_to_string(file).map_err(|reason| reason.to_string()).and_then(|text| {
+        if file.extension().is_some_and(|extension| extension == "ics") {
+          HolidayCalendar::from_icalendar(&text)
+        } else {
+          HolidayCalendar::from_csv(&text)
+        }
+        .map_err(|reason| reason.to_string())
+      });
+      match calendar {
+        Ok(calendar) => calendars.insert(&name, calendar),
+        Err(reason) => eprintln!("[{1}{ERROR_TAG}{0}][{2}{3}{0}] {1}{4}{0}", color_reset, color_red, color_blue, file.display(), reason),
+      }
+    }
+    if !calendars.is_empty() {
+      let loaded_count = calendars.len();
+      println!("{1}Loaded {loaded_count} {2}.{0}", color_reset, color_green, plural("calendar", loaded_count));
+    }
+    calendars
+  }
+}
+
+/// Parses the DMN model, when `validate` flag is set, the model is validated against XML schema before parsing.
+pub(crate) fn parse_model(xml: &str, validate: bool) -> Result<Definitions, Vec<DmntkError>> {
+  if validate {
+    let reasons = dmntk_model::validate(xml);
+    if !reasons.is_empty() {
+      return Err(reasons);
+    }
+  }
+  dmntk_model::parse(xml).map_err(|reason| vec![reason])
+}
+
+/// Searches all subdirectories starting from specified directory
+/// and searches for holiday calendar files (`.ics` and `.csv` extension)
+/// placed in directories named `calendars`.
+#[cfg(feature = "calendar")]
+fn search_calendars_recursive(dir: &Path) -> Vec<PathBuf> {
+  let mut paths = vec![];
+  for entry in WalkDir::new(dir).into_iter().filter_map(|entry| entry.ok()) {
+    let path = entry.path();
+    if path.is_file() && path.parent().and_then(|parent| parent.file_name()).is_some_and(|name| name == CALENDARS_DIR) {
+      if let Some(extension) = path.extension() {
+        if extension == "ics" || extension == "csv" {
+          paths.push(entry.path().into());
+        }
+      }
+    }
+  }
+  paths
 }
 
 /// Searches all subdirectories starting from specified directory
 /// and searches for files that have `.dmn` extension.
-fn search_models_recursive(dir: &PathBuf) -> Vec<PathBuf> {
+pub(crate) fn search_models_recursive(dir: &PathBuf) -> Vec<PathBuf> {
   let mut paths = vec![];
   for entry in WalkDir::new(dir).into_iter().filter_map(|entry| entry.ok()) {
     let path = entry.path();
@@ -160,12 +335,25 @@ fn search_models_recursive(dir: &PathBuf) -> Vec<PathBuf> {
   paths
 }
 
-fn display_deployed(evaluator: &ModelEvaluator, namespace_by_rdnn: &HashMap<String, String>, color_mode: ColorMode, verbose: bool) {
+/// Displays models that could not be deployed, together with failure reasons.
+fn display_failed(errors: &[(String, DmntkError)], color_mode: ColorMode) {
+  let color_reset = color_reset!(color_mode);
+  let color_blue = color_blue!(color_mode);
+  let color_red = color_red!(color_mode);
+  for (namespace, reason) in errors {
+    eprintln!("[{1}{ERROR_TAG}{0}][{2}{3}{0}] {1}{4}{0}", color_reset, color_red, color_blue, namespace, reason);
+  }
+  if !errors.is_empty() {
+    println!("{1}Failed to deploy {2} {3}.{0}", color_reset, color_red, errors.len(), plural("model", errors.len()));
+  }
+}
+
+fn display_deployed(evaluator: &BundleEvaluator, namespace_by_rdnn: &HashMap<String, String>, color_mode: ColorMode, verbose: bool) {
   let color_reset = color_reset!(color_mode);
   let color_blue = color_blue!(color_mode);
   let color_green = color_green!(color_mode);
   let color_magenta = color_magenta!(color_mode);
-  let deployed_count = evaluator.invocables().len();
+  let deployed_count = evaluator.namespace_name().len();
   if deployed_count > 0 {
     println!("{1}Deployed {deployed_count} {2}.{0}", color_reset, color_green, plural("invocable", deployed_count));
   }
@@ -175,13 +363,13 @@ fn display_deployed(evaluator: &ModelEvaluator, namespace_by_rdnn: &HashMap<Stri
       rdnn_by_namespace.insert(namespace.clone(), rdnn.clone());
     });
     println!("\n{1}Deployed endpoints (invocable name):{0}\n", color_reset, color_magenta);
-    for (namespace, name) in &evaluator.invocables().namespace_name() {
+    for (namespace, name) in &evaluator.namespace_name() {
       let encoded_rdnn = encode(rdnn_by_namespace.get(namespace).unwrap());
       let encoded_name = encode(name);
       println!("    {1}{3}{0}/{2}{4}{0}", color_reset, color_blue, color_green, encoded_rdnn, encoded_name);
     }
     println!("\n{1}Deployed endpoints (invocable identifier):{0}\n", color_reset, color_magenta);
-    for (namespace, id) in &evaluator.invocables().namespace_id() {
+    for (namespace, id) in &evaluator.namespace_id() {
       let encoded_rdnn = encode(rdnn_by_namespace.get(namespace).unwrap());
       let encoded_id = encode(id);
       println!("    {1}{3}{0}/{2}{4}{0}", color_reset, color_blue, color_green, encoded_rdnn, encoded_id);